@@ -1,5 +1,8 @@
 use excel_reader::{
-    excel::Excel, processed::spreadsheet::sheet::worksheet::cell::cell_value::CellValueType,
+    excel::Excel,
+    processed::spreadsheet::sheet::worksheet::cell::{
+        cell_value::CellValueType, HyperlinkTextMode,
+    },
 };
 
 /// Demo for basic usages
@@ -49,6 +52,14 @@ fn main() -> anyhow::Result<()> {
         "Calculation reference: {:?}",
         worksheet.calculation_reference_mode
     );
+    println!("frozen header: {:?}", worksheet.frozen_header_range());
+    for detected in worksheet.detect_tables() {
+        println!(
+            "detected table: {}, header row: {:?}",
+            detected.range.to_a1(),
+            detected.header_row
+        );
+    }
     if !worksheet.clone().merged_cells.is_empty() {
         println!("merged cells: ");
         for (index, merged_cell) in worksheet.clone().merged_cells.iter().enumerate() {
@@ -67,12 +78,26 @@ fn main() -> anyhow::Result<()> {
         println!("--------");
     }
 
+    // get cell values column by column
+    if let Some(columnar) = worksheet.get_columns(&mut excel)? {
+        for column in columnar.columns.iter() {
+            println!(
+                "column {}: {} values, sum: {:?}",
+                column.col,
+                column.validity.count(),
+                column.sum()
+            );
+        }
+        println!("--------");
+    }
+
     // get cells (value and style) in a worksheet
     let cells = worksheet.get_cells()?;
     for cell in cells {
         println!("--------");
         println!("coordinate: {:?}", cell.coordinate);
         println!("value {:?}.", cell.value);
+        println!("text: {}", cell.display_text(HyperlinkTextMode::Append));
         if let CellValueType::Numeric(_) = cell.value {
             println!(
                 "Numeric format: {:?}",
@@ -0,0 +1,146 @@
+//! Fixtures shared by the examples: copies of the sample packages with some of their parts edited.
+// each example uses a part of the helpers
+#![allow(dead_code)]
+
+use std::io::{Cursor, Read, Write};
+
+use excel_reader::excel::Excel;
+use zip::{write::SimpleFileOptions, ZipArchive, ZipWriter};
+
+pub const SAMPLE: &str = "examples/sample.xlsx";
+pub const SHEET1: &str = "xl/worksheets/sheet1.xml";
+pub const SHEET2: &str = "xl/worksheets/sheet2.xml";
+pub const SHEET1_RELS: &str = "xl/worksheets/_rels/sheet1.xml.rels";
+pub const STYLES: &str = "xl/styles.xml";
+pub const WORKBOOK: &str = "xl/workbook.xml";
+pub const WORKBOOK_RELS: &str = "xl/_rels/workbook.xml.rels";
+
+/// Workbook part of `sample_with_moved_workbook`.
+pub const MOVED_WORKBOOK: &str = "xl/main.xml";
+
+/// Worksheet without any cell.
+pub const EMPTY_SHEET: &str = r#"<?xml version="1.0" encoding="UTF-8"?><worksheet xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main"><sheetData/></worksheet>"#;
+
+/// Parts of a zip package, in the order of the archive.
+pub struct Package {
+    pub parts: Vec<(String, Vec<u8>)>,
+}
+
+impl Package {
+    pub fn open(path: &str) -> anyhow::Result<Self> {
+        Self::from_bytes(std::fs::read(path)?)
+    }
+
+    pub fn from_bytes(bytes: Vec<u8>) -> anyhow::Result<Self> {
+        let mut archive = ZipArchive::new(Cursor::new(bytes))?;
+        let mut parts = vec![];
+        for index in 0..archive.len() {
+            let mut file = archive.by_index(index)?;
+            let mut content = Vec::new();
+            file.read_to_end(&mut content)?;
+            parts.push((file.name().to_string(), content));
+        }
+        Ok(Self { parts })
+    }
+
+    /// Text of a part, which must exist.
+    pub fn text(&self, name: &str) -> String {
+        let Some((_, content)) = self.parts.iter().find(|(part, _)| part == name) else {
+            panic!("no part {} in the package", name);
+        };
+        String::from_utf8(content.clone()).expect("part is not text")
+    }
+
+    /// Replace the content of a part, added at the end of the archive if it does not exist.
+    pub fn replace(mut self, name: &str, content: impl AsRef<[u8]>) -> Self {
+        let content = content.as_ref().to_vec();
+        match self.parts.iter_mut().find(|(part, _)| part == name) {
+            Some(part) => part.1 = content,
+            None => self.parts.push((name.to_string(), content)),
+        }
+        self
+    }
+
+    /// Edit the text of an existing part.
+    pub fn edit(mut self, name: &str, edit: impl FnOnce(String) -> String) -> Self {
+        let Some(part) = self.parts.iter_mut().find(|(part, _)| part == name) else {
+            panic!("no part {} in the package", name);
+        };
+        let text = String::from_utf8(std::mem::take(&mut part.1)).expect("part is not text");
+        part.1 = edit(text).into_bytes();
+        self
+    }
+
+    /// Move a part, which must exist, keeping its place in the archive.
+    pub fn rename(mut self, name: &str, new_name: &str) -> Self {
+        let Some(part) = self.parts.iter_mut().find(|(part, _)| part == name) else {
+            panic!("no part {} in the package", name);
+        };
+        part.0 = new_name.to_string();
+        self
+    }
+
+    /// Replace the first occurrence of `from` in a part, which must be found.
+    pub fn replace_in(self, name: &str, from: &str, to: &str) -> Self {
+        self.edit(name, |text| {
+            assert!(text.contains(from), "{} not found in {}", from, name);
+            text.replacen(from, to, 1)
+        })
+    }
+
+    /// Rows added at the end of the `sheetData` of a worksheet.
+    pub fn append_rows(self, name: &str, rows: &str) -> Self {
+        self.replace_in(name, "</sheetData>", &format!("{}</sheetData>", rows))
+    }
+
+    /// Rows replacing the whole `sheetData` of a worksheet.
+    pub fn replace_rows(self, name: &str, rows: &str) -> Self {
+        self.edit(name, |sheet| {
+            let start = sheet.find("<sheetData>").unwrap() + "<sheetData>".len();
+            let end = sheet.find("</sheetData>").unwrap();
+            format!("{}{}{}", &sheet[..start], rows, &sheet[end..])
+        })
+    }
+
+    /// Cell formats added at the end of `cellXfs` of the stylesheet, from style index 28 in the sample.
+    pub fn append_cell_formats(self, xfs: &str) -> Self {
+        self.replace_in(STYLES, "</cellXfs>", &format!("{}</cellXfs>", xfs))
+    }
+
+    /// Zip archive of the parts.
+    pub fn bytes(&self) -> anyhow::Result<Vec<u8>> {
+        let mut writer = ZipWriter::new(Cursor::new(Vec::new()));
+        for (name, content) in self.parts.iter() {
+            writer.start_file(name.as_str(), SimpleFileOptions::default())?;
+            writer.write_all(content)?;
+        }
+        Ok(writer.finish()?.into_inner())
+    }
+
+    pub fn excel(&self) -> anyhow::Result<Excel<Cursor<Vec<u8>>>> {
+        Excel::from_reader(Cursor::new(self.bytes()?))
+    }
+}
+
+/// Sample whose workbook part is `MOVED_WORKBOOK`, with its relationships,
+/// as targeted by the relationships and declared by the content types of the package.
+pub fn sample_with_moved_workbook() -> anyhow::Result<Package> {
+    Ok(Package::open(SAMPLE)?
+        .rename(WORKBOOK, MOVED_WORKBOOK)
+        .rename(WORKBOOK_RELS, "xl/_rels/main.xml.rels")
+        .replace_in(
+            "_rels/.rels",
+            r#"Target="xl/workbook.xml""#,
+            r#"Target="xl/main.xml""#,
+        )
+        .replace_in(
+            "[Content_Types].xml",
+            r#"PartName="/xl/workbook.xml""#,
+            r#"PartName="/xl/main.xml""#,
+        ))
+}
+
+/// `examples/sample.xlsx` with rows added at the end of the first sheet.
+pub fn sample_with_rows(rows: &str) -> anyhow::Result<Vec<u8>> {
+    Package::open(SAMPLE)?.append_rows(SHEET1, rows).bytes()
+}
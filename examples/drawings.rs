@@ -21,5 +21,9 @@ fn main() -> anyhow::Result<()> {
         );
     }
 
+    let placeholders = worksheet.get_image_placeholders();
+    println!("---------------");
+    println!("image placeholders: {}", serde_json::to_string_pretty(&placeholders)?);
+
     Ok(())
 }
@@ -0,0 +1,65 @@
+mod common;
+
+use std::io::Cursor;
+
+use common::{Package, SAMPLE, SHEET1, SHEET2};
+use excel_reader::{
+    common_types::{Coordinate, Dimension},
+    excel::Excel,
+};
+
+/// Restore the position of the user from the selections of the sheet views.
+fn main() -> anyhow::Result<()> {
+    // frozen sheet: a selection per pane, the active pane holding the focus
+    let frozen = r#"<?xml version="1.0" encoding="UTF-8"?><worksheet xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main"><sheetViews><sheetView tabSelected="1" workbookViewId="0"><pane xSplit="1" ySplit="1" topLeftCell="B2" activePane="bottomRight" state="frozen"/><selection pane="topRight" activeCell="B1" sqref="B1"/><selection pane="bottomLeft" activeCell="A2" sqref="A2"/><selection pane="bottomRight" activeCell="D5" activeCellId="1" sqref="B2:C3 D5 F1:F4"/></sheetView></sheetViews><sheetData/></worksheet>"#;
+    // two views of the same sheet, the last one wins, without pane
+    let views = r#"<?xml version="1.0" encoding="UTF-8"?><worksheet xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main"><sheetViews><sheetView workbookViewId="0"><selection activeCell="A3" sqref="A3"/></sheetView><sheetView workbookViewId="1"><selection activeCell="E7" sqref="E7"></selection></sheetView></sheetViews><sheetData/></worksheet>"#;
+
+    let bytes = Package::open(SAMPLE)?
+        .replace(SHEET1, frozen)
+        .replace(SHEET2, views)
+        .bytes()?;
+
+    let mut excel = Excel::from_reader(Cursor::new(bytes))?;
+    let sheets = excel.get_sheets()?;
+
+    let worksheet = excel.get_worksheet_without_cells(&sheets[0])?;
+    assert_eq!(worksheet.active_cell(), Coordinate::from_a1(b"D5"));
+    assert_eq!(
+        worksheet.selected_ranges(),
+        vec![
+            Dimension {
+                start: Coordinate::from_point((2, 2)),
+                end: Coordinate::from_point((3, 3)),
+            },
+            Dimension {
+                start: Coordinate::from_point((5, 4)),
+                end: Coordinate::from_point((5, 4)),
+            },
+            Dimension {
+                start: Coordinate::from_point((1, 6)),
+                end: Coordinate::from_point((4, 6)),
+            },
+        ]
+    );
+    // the selections do not disturb the pane
+    assert_eq!(worksheet.frozen_row_count(), 1);
+
+    let worksheet = excel.get_worksheet_without_cells(&sheets[1])?;
+    assert_eq!(worksheet.active_cell(), Coordinate::from_a1(b"E7"));
+    assert_eq!(
+        worksheet.selected_ranges(),
+        vec![Dimension {
+            start: Coordinate::from_point((7, 5)),
+            end: Coordinate::from_point((7, 5)),
+        }]
+    );
+
+    // no selection stored
+    let worksheet = excel.get_worksheet_without_cells(&sheets[2])?;
+    assert_eq!(worksheet.active_cell(), None);
+    assert!(worksheet.selected_ranges().is_empty());
+
+    println!("active cell read successfully");
+    Ok(())
+}
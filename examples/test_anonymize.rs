@@ -0,0 +1,238 @@
+mod common;
+
+use std::io::Cursor;
+
+use common::{Package, SAMPLE, SHEET2};
+use excel_reader::prelude::*;
+
+fn cells(excel: &mut Excel<Cursor<Vec<u8>>>, name: &str) -> anyhow::Result<Vec<Cell>> {
+    let worksheet = excel.get_worksheet_with_name(name)?;
+    Ok(worksheet
+        .get_cells()?
+        .into_iter()
+        .filter(|c| c.value != CellValueType::Empty || c.style_index.is_some())
+        .collect())
+}
+
+fn value(cells: &[Cell], a1: &str) -> String {
+    let coordinate = Coordinate::from_a1(a1.as_bytes()).unwrap();
+    cells
+        .iter()
+        .find(|c| c.coordinate == coordinate)
+        .unwrap()
+        .value
+        .to_string()
+}
+
+fn worksheet_formula(excel: &mut Excel<Cursor<Vec<u8>>>) -> anyhow::Result<String> {
+    let worksheet = excel.get_worksheet_with_name("Sheet 2 - Custom grid lines")?;
+    Ok(worksheet.formula_cells().pop().unwrap().formula)
+}
+
+/// Replace the values of a workbook, keeping its structure, styles, formulas and validations.
+fn main() -> anyhow::Result<()> {
+    let rows = concat!(
+        r#"<row r="1"><c r="A1" s="2" t="inlineStr"><is><t>Jane Doe, 12 Main St.</t></is></c>"#,
+        r#"<c r="B1" t="inlineStr"><is><t>Jane Doe, 12 Main St.</t></is></c><c r="C1" s="4"><v>1234.5</v></c>"#,
+        r#"<c r="D1"><v>0.0042</v></c><c r="E1" t="b"><v>1</v></c><c r="F1" t="e"><v>#N/A</v></c></row>"#,
+        r#"<row r="2"><c r="A2"><f>SUM(C1:D1)</f><v>1234.5042</v></c><c r="B2" t="str"><f>UPPER(A1)</f><v>JANE</v></c>"#,
+        r#"<c r="C2" t="s"><v>0</v></c><c r="D2" t="s"><v>0</v></c><c r="E2" t="inlineStr"><is><t>a &amp; b</t></is></c></row>"#,
+        r#"<row r="3"><c r="A3" t="str"><f>IF(A1="Jane Doe, 12 Main St.","say ""hi""",'Sheet 1'!A1)</f><v>say "hi"</v></c></row>"#,
+    );
+    let core = concat!(
+        r#"<?xml version="1.0" encoding="UTF-8"?><cp:coreProperties xmlns:cp="http://schemas.openxmlformats.org/package/2006/metadata/core-properties" "#,
+        r#"xmlns:dc="http://purl.org/dc/elements/1.1/"><dc:title>Budget</dc:title><dc:creator>Jane Doe</dc:creator>"#,
+        r#"<cp:lastModifiedBy>John Roe</cp:lastModifiedBy></cp:coreProperties>"#,
+    );
+    let comments = concat!(
+        r#"<?xml version="1.0" encoding="UTF-8"?><comments xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main">"#,
+        r#"<authors><author>Jane Doe</author></authors><commentList><comment ref="A1" authorId="0"><text><t>Call John</t></text></comment></commentList></comments>"#,
+    );
+    let persons = concat!(
+        r#"<?xml version="1.0" encoding="UTF-8"?><personList xmlns="http://schemas.microsoft.com/office/spreadsheetml/2018/threadedcomments">"#,
+        r#"<person displayName="Jane Doe" id="{00000000-0000-0000-0000-000000000001}" userId="jane@example.com" providerId="AD"/></personList>"#,
+    );
+    let records = concat!(
+        r#"<?xml version="1.0" encoding="UTF-8"?><pivotCacheRecords xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main" count="1">"#,
+        r#"<r><s v="Jane Doe"/><n v="1234.5"/><x v="0"/></r></pivotCacheRecords>"#,
+    );
+    let validation = r#"<dataValidations count="1"><dataValidation type="list" sqref="C2"><formula1>"Basic,Other"</formula1></dataValidation></dataValidations>"#;
+
+    let bytes = Package::open(SAMPLE)?
+        .replace_rows(SHEET2, rows)
+        .replace_in(
+            SHEET2,
+            "<pageMargins",
+            &format!("{}<pageMargins", validation),
+        )
+        .replace("docProps/core.xml", core)
+        .replace("xl/comments1.xml", comments)
+        .replace("xl/persons/person.xml", persons)
+        .replace("xl/pivotCache/pivotCacheRecords1.xml", records)
+        .bytes()?;
+    let mut excel = Excel::from_bytes(bytes.clone())?;
+
+    let options = AnonymizeOptions {
+        seed: 7,
+        ..AnonymizeOptions::default()
+    };
+    let anonymized = excel
+        .anonymize(Cursor::new(Vec::new()), &options)?
+        .into_inner();
+    let mut copy = Excel::from_bytes(anonymized.clone())?;
+
+    // same seed, same replacements
+    let again = Excel::from_bytes(bytes)?
+        .anonymize(Cursor::new(Vec::new()), &options)?
+        .into_inner();
+    let again: Vec<Vec<Cell>> = {
+        let mut again = Excel::from_bytes(again)?;
+        let names: Vec<String> = again.get_sheets()?.into_iter().map(|s| s.name).collect();
+        names
+            .iter()
+            .map(|n| cells(&mut again, n))
+            .collect::<anyhow::Result<_>>()?
+    };
+
+    let sheets = excel.get_sheets()?;
+    assert_eq!(sheets, copy.get_sheets()?);
+    for (index, sheet) in sheets.iter().enumerate() {
+        let before = cells(&mut excel, &sheet.name)?;
+        let after = cells(&mut copy, &sheet.name)?;
+        assert_eq!(after, again[index]);
+        assert_eq!(before.len(), after.len());
+        for (before, after) in before.iter().zip(after.iter()) {
+            assert_eq!(before.coordinate, after.coordinate);
+            assert_eq!(before.property, after.property);
+            assert_eq!(before.raw_type, after.raw_type);
+            assert_eq!(
+                std::mem::discriminant(&before.value),
+                std::mem::discriminant(&after.value)
+            );
+        }
+    }
+
+    let before = cells(&mut excel, "Sheet 2 - Custom grid lines")?;
+    let after = cells(&mut copy, "Sheet 2 - Custom grid lines")?;
+    let scrambled = value(&after, "A1");
+    assert_ne!(scrambled, "Jane Doe, 12 Main St.");
+    assert_eq!(scrambled.len(), "Jane Doe, 12 Main St.".len());
+    assert!(scrambled.chars().next().unwrap().is_ascii_uppercase());
+    assert_eq!(&scrambled[4..5], " ");
+    assert_eq!(&scrambled[8..10], ", ");
+    // equal values stay equal
+    assert_eq!(value(&after, "B1"), scrambled);
+    assert_eq!(value(&after, "C2"), value(&after, "D2"));
+    assert_ne!(value(&after, "C2"), value(&before, "C2"));
+
+    // numbers keep their magnitude and decimals
+    let number = value(&after, "C1");
+    assert_ne!(number, "1234.5");
+    assert!((1000.0..10000.0).contains(&number.parse::<f64>()?));
+    assert_eq!(number.len(), 6);
+    let small = value(&after, "D1");
+    assert!(small.starts_with("0.00") && small.len() == 6 && !small.ends_with('0'));
+    assert_eq!(value(&after, "E1"), value(&before, "E1"));
+    assert_eq!(value(&after, "F1"), value(&before, "F1"));
+    let escaped = value(&after, "E2");
+    assert_eq!(escaped.len(), 5);
+    assert_eq!(&escaped[1..4], " & ");
+
+    // formulas are kept, their cached values and string literals are replaced as the cell values
+    let literal = worksheet_formula(&mut copy)?
+        .rsplit(',')
+        .nth(1)
+        .unwrap()
+        .to_string();
+    let literal = literal[1..literal.len() - 1].replace("\"\"", "\"");
+    assert_ne!(literal, "say \"hi\"");
+    assert_eq!(literal.len(), "say \"hi\"".len());
+    assert_eq!(&literal[3..5], " \"");
+    let worksheet = copy.get_worksheet_with_name("Sheet 2 - Custom grid lines")?;
+    let formulas: Vec<(String, bool)> = worksheet
+        .formula_cells()
+        .into_iter()
+        .map(|f| {
+            let changed = f.cached_value != Some("1234.5042".to_string())
+                && f.cached_value != Some("JANE".to_string())
+                && f.cached_value != Some("say \"hi\"".to_string());
+            (f.formula, changed)
+        })
+        .collect();
+    assert_eq!(
+        formulas,
+        vec![
+            ("SUM(C1:D1)".to_string(), true),
+            ("UPPER(A1)".to_string(), true),
+            (
+                format!(
+                    "IF(A1=\"{}\",\"{}\",'Sheet 1'!A1)",
+                    scrambled,
+                    literal.replace('"', "\"\"")
+                ),
+                true
+            )
+        ]
+    );
+    assert_eq!(
+        worksheet.data_validations,
+        excel
+            .get_worksheet_with_name("Sheet 2 - Custom grid lines")?
+            .data_validations
+    );
+    assert!(worksheet.data_validations.is_some());
+
+    // hashed texts hide their length
+    let options = AnonymizeOptions {
+        method: AnonymizeMethod::Hash,
+        seed: 7,
+        ..AnonymizeOptions::default()
+    };
+    let hashed = excel
+        .anonymize(Cursor::new(Vec::new()), &options)?
+        .into_inner();
+    let mut hashed = Excel::from_bytes(hashed)?;
+    let after = cells(&mut hashed, "Sheet 2 - Custom grid lines")?;
+    let text = value(&after, "A1");
+    assert_eq!(text.len(), 16);
+    assert!(text.chars().all(|c| c.is_ascii_hexdigit()));
+    assert_eq!(value(&after, "B1"), text);
+
+    // the copy is a workbook of its own
+    let package = Package::from_bytes(anonymized)?;
+    let shared_strings = package.text("xl/sharedStrings.xml");
+    assert!(!shared_strings.contains("Itsuki"));
+    assert!(shared_strings.contains("<rPr>"));
+
+    // so are the authors of the document, comments and pivot caches
+    for part in [
+        "docProps/core.xml",
+        "xl/comments1.xml",
+        "xl/persons/person.xml",
+        "xl/pivotCache/pivotCacheRecords1.xml",
+    ] {
+        let text = package.text(part);
+        assert!(
+            !text.contains("Jane Doe") && !text.contains("John"),
+            "{}",
+            text
+        );
+    }
+    assert!(package
+        .text("docProps/core.xml")
+        .contains("<dc:title>Budget</dc:title>"));
+    assert!(!package
+        .text("xl/persons/person.xml")
+        .contains("jane@example.com"));
+    assert!(package
+        .text("xl/persons/person.xml")
+        .contains(r#"providerId="AD""#));
+    let records = package.text("xl/pivotCache/pivotCacheRecords1.xml");
+    assert!(!records.contains("1234.5") && records.contains(r#"<x v="0"/>"#));
+    let name = records.split(r#"<s v=""#).nth(1).unwrap();
+    assert_eq!(&name[4..5], " ");
+    assert_eq!(&name[8..11], r#""/>"#);
+
+    println!("workbook anonymized successfully");
+    Ok(())
+}
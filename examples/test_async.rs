@@ -0,0 +1,46 @@
+use excel_reader::prelude::*;
+
+/// Read a workbook from async code, without blocking the runtime.
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    let file = tokio::fs::File::open("examples/sample.xlsx").await?;
+    let excel = Excel::from_async_reader(file).await?;
+
+    let sheets = excel.get_sheets().await?;
+    assert_eq!(sheets.len(), 3);
+    let worksheet = excel.get_worksheet(&sheets[0]).await?;
+    let by_name = excel.get_worksheet_with_name(&sheets[0].name).await?;
+    assert_eq!(worksheet.get_cells()?, by_name.get_cells()?);
+
+    // same cells as the synchronous API
+    let mut sync_excel = Excel::from_path("examples/sample.xlsx")?;
+    let expected = sync_excel.get_worksheet(&sheets[0])?;
+    assert_eq!(worksheet.get_cells()?, expected.get_cells()?);
+
+    // clones share the workbook, and load worksheets concurrently with other tasks
+    let tasks: Vec<_> = sheets
+        .iter()
+        .cloned()
+        .map(|sheet| {
+            let excel = excel.clone();
+            tokio::spawn(async move { excel.get_worksheet_without_cells(&sheet).await })
+        })
+        .collect();
+    for (task, sheet) in tasks.into_iter().zip(sheets.iter()) {
+        assert_eq!(task.await??.name, sheet.name);
+    }
+
+    // any other function through `run`
+    let names = excel.run(|e| e.defined_names()).await?;
+    assert!(names.is_empty());
+    let model = excel.to_processed_model().await?;
+    assert_eq!(model.sheets.len(), 3);
+
+    assert!(excel.into_inner().is_some());
+
+    // errors of the package are returned by the future
+    assert!(Excel::from_async_reader(&b"not a zip"[..]).await.is_err());
+
+    println!("async workbook read successfully");
+    Ok(())
+}
@@ -0,0 +1,127 @@
+mod common;
+
+use std::io::Cursor;
+
+use common::{Package, SAMPLE, SHEET1};
+use excel_reader::{
+    excel::Excel,
+    processed::spreadsheet::sheet::worksheet::auto_filter::{
+        CustomFilterCondition, FilterCriteria,
+    },
+};
+
+/// Read the AutoFilter of a sheet, with value, date, custom, dynamic, top 10 and color filters, and a sort.
+fn main() -> anyhow::Result<()> {
+    let sheet = r#"<?xml version="1.0" encoding="UTF-8"?><worksheet xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main"><dimension ref="B3:H20"/><sheetData><row r="3"><c r="B3"><v>1</v></c></row></sheetData>
+<autoFilter ref="B3:H20">
+<filterColumn colId="0"><filters blank="1"><filter val="North"/><filter val="South"/></filters></filterColumn>
+<filterColumn colId="1"><filters><dateGroupItem year="2024" month="3" dateTimeGrouping="month"/></filters></filterColumn>
+<filterColumn colId="2"><customFilters and="1"><customFilter operator="greaterThanOrEqual" val="0.2"/><customFilter operator="lessThanOrEqual" val="0.5"/></customFilters></filterColumn>
+<filterColumn colId="3"><dynamicFilter type="thisMonth" valIso="2024-03-01T00:00:00" maxValIso="2024-04-01T00:00:00"/></filterColumn>
+<filterColumn colId="4"><top10 top="0" percent="1" val="10" filterVal="3.5"/></filterColumn>
+<filterColumn colId="5"><colorFilter dxfId="2"/></filterColumn>
+<filterColumn colId="6" hiddenButton="1"/>
+<sortState ref="B4:H20"><sortCondition descending="1" ref="D4:D20"/><sortCondition ref="B4:B20" customList="North,South,East,West"/></sortState>
+</autoFilter>
+</worksheet>"#;
+
+    let bytes = Package::open(SAMPLE)?.replace(SHEET1, sheet).bytes()?;
+
+    let mut excel = Excel::from_reader(Cursor::new(bytes))?;
+    let sheets = excel.get_sheets()?;
+    let worksheet = excel.get_worksheet(&sheets[0])?;
+
+    let filter = worksheet.auto_filter.as_ref().unwrap();
+    assert_eq!(filter.dimension.to_a1(), "B3:H20");
+    assert_eq!(filter.columns.len(), 7);
+
+    let active: Vec<u64> = filter.active_columns().iter().map(|c| c.column).collect();
+    assert_eq!(active, vec![2, 3, 4, 5, 6, 7]);
+
+    assert_eq!(
+        filter.column(2).unwrap().criteria,
+        Some(FilterCriteria::Values {
+            values: vec!["North".to_string(), "South".to_string()],
+            date_groups: vec![],
+            blank: true,
+        })
+    );
+
+    let Some(FilterCriteria::Values { date_groups, .. }) = &filter.column(3).unwrap().criteria
+    else {
+        panic!("date filter expected");
+    };
+    assert_eq!(date_groups[0].grouping, "month");
+    assert_eq!(
+        (
+            date_groups[0].year,
+            date_groups[0].month,
+            date_groups[0].day
+        ),
+        (Some(2024), Some(3), None)
+    );
+
+    assert_eq!(
+        filter.column(4).unwrap().criteria,
+        Some(FilterCriteria::Custom {
+            and: true,
+            conditions: vec![
+                CustomFilterCondition {
+                    operator: "greaterThanOrEqual".to_string(),
+                    value: "0.2".to_string()
+                },
+                CustomFilterCondition {
+                    operator: "lessThanOrEqual".to_string(),
+                    value: "0.5".to_string()
+                },
+            ],
+        })
+    );
+    assert_eq!(
+        filter.column(5).unwrap().criteria,
+        Some(FilterCriteria::Dynamic {
+            r#type: "thisMonth".to_string(),
+            min: Some("2024-03-01T00:00:00".to_string()),
+            max: Some("2024-04-01T00:00:00".to_string()),
+        })
+    );
+    assert_eq!(
+        filter.column(6).unwrap().criteria,
+        Some(FilterCriteria::Top {
+            top: false,
+            percent: true,
+            value: 10.0,
+            threshold: Some(3.5),
+        })
+    );
+    assert_eq!(
+        filter.column(7).unwrap().criteria,
+        Some(FilterCriteria::Color {
+            dxf_id: Some(2),
+            cell_color: true,
+        })
+    );
+
+    let hidden = filter.column(8).unwrap();
+    assert_eq!(hidden.column_id, 6);
+    assert!(hidden.button_hidden);
+    assert!(hidden.criteria.is_none());
+    assert!(filter.column(9).is_none());
+
+    let sort = filter.sort_state.as_ref().unwrap();
+    assert_eq!(sort.dimension.map(|d| d.to_a1()).as_deref(), Some("B4:H20"));
+    assert_eq!(sort.conditions.len(), 2);
+    assert!(sort.conditions[0].descending);
+    assert_eq!(sort.conditions[0].sort_by, "value");
+    assert_eq!(
+        sort.conditions[1].custom_list.as_deref(),
+        Some("North,South,East,West")
+    );
+
+    // sheets without AutoFilter
+    let other = excel.get_worksheet(&sheets[1])?;
+    assert!(other.auto_filter.is_none());
+
+    println!("auto filter read successfully");
+    Ok(())
+}
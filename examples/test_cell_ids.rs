@@ -0,0 +1,76 @@
+use excel_reader::prelude::*;
+
+/// Assign deterministic ids to cells and rows, packing the sheet id, the row and the column.
+fn main() -> anyhow::Result<()> {
+    // off by default
+    let mut excel = Excel::from_path("examples/sample.xlsx")?;
+    let worksheet = excel.get_worksheet_with_name("Sheet 1 - Basic")?;
+    assert!(worksheet.get_cells()?.iter().all(|c| c.id.is_none()));
+
+    let options = ExcelOptions {
+        cell_ids: true,
+        ..ExcelOptions::default()
+    };
+    let mut excel = Excel::from_path_with_options("examples/sample.xlsx", options.clone())?;
+    let sheets = excel.get_sheets()?;
+    let worksheet = excel.get_worksheet(&sheets[1])?;
+    let cells = worksheet.get_cells()?;
+    for cell in cells.iter() {
+        let id = cell.id.unwrap();
+        assert_eq!(
+            id,
+            CellId::new(worksheet.sheet_id, cell.coordinate).unwrap()
+        );
+        assert_eq!(id.sheet_id(), worksheet.sheet_id);
+        assert_eq!(
+            (id.row(), id.col()),
+            (cell.coordinate.row, cell.coordinate.col)
+        );
+    }
+    // unique, and ordered as the cells
+    assert!(cells.windows(2).all(|w| w[0].id < w[1].id));
+
+    // same ids on every read
+    let mut again = Excel::from_path_with_options("examples/sample.xlsx", options)?;
+    let again = again.get_worksheet(&sheets[1])?.get_cells()?;
+    let ids = |cells: &[Cell]| cells.iter().map(|c| c.id).collect::<Vec<Option<CellId>>>();
+    assert_eq!(ids(&cells), ids(&again));
+
+    // rows, and cells read one row at a time
+    let rows: Vec<Row> = worksheet.rows(&mut excel)?.collect::<anyhow::Result<_>>()?;
+    for row in rows.iter() {
+        let id = row.id.unwrap();
+        assert_eq!(
+            (id.sheet_id(), id.row(), id.col()),
+            (worksheet.sheet_id, row.index, 0)
+        );
+        for cell in row.cells.iter() {
+            assert!(cell.id.unwrap() > id);
+            assert_eq!(cell.id, CellId::new(worksheet.sheet_id, cell.coordinate));
+        }
+    }
+
+    // processed model
+    let model = excel.to_processed_model()?;
+    for sheet in model.sheets.iter() {
+        assert!(sheet
+            .cells
+            .iter()
+            .all(|c| c.id.unwrap().sheet_id() == sheet.sheet_id));
+    }
+
+    // packed into 53 bits, serialized as a number
+    let last = CellId::new(131_071, Coordinate::from_point((1_048_576, 16_384))).unwrap();
+    assert!(last.get() < 1 << 53);
+    assert_eq!(u64::from(last), last.get());
+    assert_eq!(serde_json::to_string(&last)?, last.get().to_string());
+    assert_eq!(CellId::new(131_072, Coordinate::from_point((1, 1))), None);
+    assert_eq!(CellId::new(1, Coordinate::from_point((0, 1))), None);
+    assert!(
+        CellId::for_row(2, 1).unwrap()
+            > CellId::new(1, Coordinate::from_point((1_048_576, 16_384))).unwrap()
+    );
+
+    println!("cell ids assigned successfully");
+    Ok(())
+}
@@ -0,0 +1,90 @@
+mod common;
+
+use std::io::Cursor;
+
+use common::{Package, SAMPLE, SHEET1};
+use excel_reader::{
+    common_types::{CellReference, Coordinate, CoordinateError},
+    excel::Excel,
+};
+
+/// Sample workbook with a shared formula mixing anchored and relative references appended to the first sheet.
+fn sample_with_shared_formula() -> anyhow::Result<Vec<u8>> {
+    Package::open(SAMPLE)?
+        .append_rows(
+            SHEET1,
+            concat!(
+                r#"<row r="20"><c r="B20"><f t="shared" ref="B20:C21" si="90">$A20*A$1+$A$1+A1</f><v>0</v></c><c r="C20"><f t="shared" si="90"/><v>0</v></c></row>"#,
+                r#"<row r="21"><c r="B21"><f t="shared" si="90"/><v>0</v></c><c r="C21"><f t="shared" si="90"/><v>0</v></c></row>"#,
+            ),
+        )
+        .bytes()
+}
+
+/// Keep the `$` anchors of references, and move them the way copying a formula does.
+fn main() -> anyhow::Result<()> {
+    let b3 = Coordinate::from_point((3, 2));
+    for (a1, col_absolute, row_absolute) in [
+        ("B3", false, false),
+        ("$B3", true, false),
+        ("B$3", false, true),
+        ("$B$3", true, true),
+    ] {
+        let reference = CellReference::parse_a1(a1)?;
+        assert_eq!(reference.coordinate, b3);
+        assert_eq!(reference.col_absolute, col_absolute, "{}", a1);
+        assert_eq!(reference.row_absolute, row_absolute, "{}", a1);
+        // displayed as written
+        assert_eq!(reference.to_string(), a1);
+        assert_eq!(a1.parse::<CellReference>(), Ok(reference));
+    }
+    assert_eq!("$b$3".parse::<CellReference>()?.to_string(), "$B$3");
+    assert_eq!(CellReference::from(b3).to_string(), "B3");
+    assert_eq!(
+        CellReference::parse_a1("$B$"),
+        Err(CoordinateError::MissingRow)
+    );
+    assert_eq!(
+        CellReference::parse_a1("B$$3"),
+        Err(CoordinateError::UnexpectedCharacter {
+            position: 2,
+            character: '$'
+        })
+    );
+
+    // anchored parts are kept when moved
+    let moved = |a1: &str, rows: i64, cols: i64| -> Option<String> {
+        CellReference::parse_a1(a1)
+            .unwrap()
+            .offset(rows, cols)
+            .map(|reference| reference.to_string())
+    };
+    assert_eq!(moved("B3", 2, 1).as_deref(), Some("C5"));
+    assert_eq!(moved("$B3", 2, 1).as_deref(), Some("$B5"));
+    assert_eq!(moved("B$3", 2, 1).as_deref(), Some("C$3"));
+    assert_eq!(moved("$B$3", -2, -1).as_deref(), Some("$B$3"));
+    // moved outside of the worksheet
+    assert_eq!(moved("B3", -3, 0), None);
+    assert_eq!(moved("B3", 0, -2), None);
+    assert_eq!(moved("B$3", -3, 0).as_deref(), Some("B$3"));
+    assert_eq!(moved("XFD1", 0, 1), None);
+
+    // shared formulas expand with their anchors
+    let mut excel = Excel::from_reader(Cursor::new(sample_with_shared_formula()?))?;
+    let worksheet = excel.get_worksheet_with_name("Sheet 1 - Basic")?;
+    let formula = |a1: &str| -> Option<String> {
+        let coordinate = Coordinate::parse_a1(a1).unwrap();
+        worksheet
+            .formula_cells()
+            .into_iter()
+            .find(|c| c.coordinate == coordinate)
+            .map(|c| c.formula)
+    };
+    assert_eq!(formula("B20").as_deref(), Some("$A20*A$1+$A$1+A1"));
+    assert_eq!(formula("C20").as_deref(), Some("$A20*B$1+$A$1+B1"));
+    assert_eq!(formula("B21").as_deref(), Some("$A21*A$1+$A$1+A2"));
+    assert_eq!(formula("C21").as_deref(), Some("$A21*B$1+$A$1+B2"));
+
+    println!("cell references parsed successfully");
+    Ok(())
+}
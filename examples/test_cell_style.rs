@@ -0,0 +1,90 @@
+mod common;
+
+use std::io::Cursor;
+
+use common::{Package, SAMPLE, SHEET1};
+use excel_reader::{
+    common_types::Coordinate,
+    excel::Excel,
+    processed::spreadsheet::sheet::worksheet::cell::cell_property::{
+        border::BorderStyleValue,
+        font::UnderlineValue,
+        text_alignment::{HorizontalAlignementValue, VerticalAlignementValue},
+    },
+};
+
+/// Resolve the formatting of cells, stored or not, from the cell, row and column styles.
+fn main() -> anyhow::Result<()> {
+    let bytes = Package::open(SAMPLE)?
+        .append_rows(
+            SHEET1,
+            concat!(
+                r#"<row r="40" s="28" customFormat="1"><c r="A40" s="0"><v>1</v></c></row>"#,
+                r#"<row r="41" s="28"><c r="A41"><v>1</v></c></row>"#,
+            ),
+        )
+        .append_cell_formats(concat!(
+            r#"<xf numFmtId="10" fontId="4" fillId="5" borderId="1" applyNumberFormat="1" applyFont="1" applyFill="1" applyBorder="1" applyAlignment="1" applyProtection="1">"#,
+            r#"<alignment horizontal="center" vertical="center"/><protection locked="0" hidden="1"/></xf>"#,
+        ))
+        .bytes()?;
+
+    let mut excel = Excel::from_reader(Cursor::new(bytes))?;
+    let worksheet = excel.get_worksheet_with_name("Sheet 1 - Basic")?;
+    let style = |a1: &str| worksheet.cell_style(Coordinate::from_a1(a1.as_bytes()).unwrap());
+
+    // empty cell of a row with a custom format, outside of the dimension
+    let styled = style("E40")?;
+    assert_eq!(styled.style_index, Some(28));
+    assert!(styled.font.bold && styled.font.italic);
+    assert_eq!(styled.font.underline, UnderlineValue::Single);
+    assert!(styled.fill_color().is_some());
+    assert_eq!(styled.border.left.style, BorderStyleValue::Thin);
+    assert!(styled.border.left.color.is_some());
+    assert_eq!(
+        styled.alignment.horizontal_alignment,
+        HorizontalAlignementValue::Center
+    );
+    assert_eq!(
+        styled.alignment.vertical_alignment,
+        VerticalAlignementValue::Center
+    );
+    assert_eq!(styled.numbering_format.format_id, 10);
+    assert!(!styled.locked);
+    assert!(styled.formula_hidden);
+
+    // a cell style takes precedence over the row style
+    let cell = style("A40")?;
+    assert_eq!(cell.style_index, Some(0));
+    assert!(!cell.font.bold);
+
+    // without a custom format, empty cells of the row take the column style
+    for a1 in ["E41", "Z1000", "XFD1048576"] {
+        let column = style(a1)?;
+        assert_eq!(column.style_index, Some(1), "{}", a1);
+        assert!(column.alignment.wrap_text);
+        assert_eq!(
+            column.alignment.vertical_alignment,
+            VerticalAlignementValue::Top
+        );
+        assert!(column.fill_color().is_none());
+    }
+
+    // stored cells resolve as their properties
+    for cell in worksheet.cells_with_style(|_| true)? {
+        let resolved = worksheet.cell_style(cell.coordinate)?;
+        assert_eq!(resolved.font, cell.property.font);
+        assert_eq!(resolved.fill, cell.property.fill);
+        assert_eq!(resolved.border, cell.property.border);
+        assert_eq!(resolved.alignment, cell.property.alignment);
+        assert_eq!(resolved.numbering_format, cell.property.numbering_format);
+    }
+    assert_eq!(style("B3")?.style_index, Some(5));
+
+    assert!(worksheet
+        .cell_style(Coordinate::from_point((0, 1)))
+        .is_err());
+
+    println!("cell styles resolved successfully");
+    Ok(())
+}
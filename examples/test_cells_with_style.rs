@@ -0,0 +1,43 @@
+use excel_reader::{common_types::Coordinate, excel::Excel};
+
+fn main() -> anyhow::Result<()> {
+    let mut excel = Excel::from_path("examples/sample.xlsx")?;
+
+    let worksheet = excel.get_worksheet_with_name("Sheet 1 - Basic")?;
+    let gray = "#dbdbdbff".to_string();
+    let cells = worksheet.cells_with_style(|style| style.fill_color() == Some(&gray))?;
+    let coordinates: Vec<Coordinate> = cells.iter().map(|c| c.coordinate).collect();
+    assert_eq!(
+        coordinates,
+        (3..=7)
+            .map(|row| Coordinate::from_point((row, 1)))
+            .collect::<Vec<_>>()
+    );
+    // several style indexes resolve to the same fill
+    assert!(cells.iter().any(|c| c.style_index == Some(4)));
+    assert!(cells.iter().any(|c| c.style_index == Some(6)));
+
+    // same cells as filtering every cell of the sheets
+    for sheet in excel.get_sheets()? {
+        let worksheet = excel.get_worksheet(&sheet)?;
+        let expected: Vec<Coordinate> = worksheet
+            .get_cells()?
+            .into_iter()
+            .filter(|c| c.property.fill_color().is_some() || c.property.font.bold)
+            .map(|c| c.coordinate)
+            .collect();
+        let found: Vec<Coordinate> = worksheet
+            .cells_with_style(|style| style.fill_color().is_some() || style.font.bold)?
+            .into_iter()
+            .map(|c| c.coordinate)
+            .collect();
+        assert_eq!(found, expected, "{}", sheet.name);
+    }
+
+    let none =
+        worksheet.cells_with_style(|style| style.fill_color() == Some(&"#000000ff".to_string()))?;
+    assert!(none.is_empty());
+
+    println!("cells with style found successfully");
+    Ok(())
+}
@@ -0,0 +1,34 @@
+use excel_reader::{common_types::Dimension, excel::Excel};
+
+/// Turn whole columns and rows into the ranges of the cells actually written.
+fn main() -> anyhow::Result<()> {
+    let range = |a1: &str| Dimension::parse_a1(a1).unwrap();
+    let used_range = range("B2:D10");
+
+    assert_eq!(range("C:C").clamp_to(used_range), Some(range("C2:C10")));
+    assert_eq!(range("A:C").clamp_to(used_range), Some(range("B2:C10")));
+    assert_eq!(range("5:6").clamp_to(used_range), Some(range("B5:D6")));
+    assert_eq!(range("1:1048576").clamp_to(used_range), Some(used_range));
+    // finite ranges are clamped the same way
+    assert_eq!(range("A1:C3").clamp_to(used_range), Some(range("B2:C3")));
+    assert_eq!(range("C4").clamp_to(used_range), Some(range("C4")));
+    // outside of the used range
+    assert_eq!(range("E:F").clamp_to(used_range), None);
+    assert_eq!(range("11:12").clamp_to(used_range), None);
+    assert_eq!(range("A1").clamp_to(used_range), None);
+
+    // with the dimension of a worksheet
+    let mut excel = Excel::from_path("examples/sample.xlsx")?;
+    let worksheet = excel.get_worksheet_with_name("Sheet 1 - Basic")?;
+    let dimension = worksheet.dimension.unwrap();
+    let column = range("B:B").clamp_to(dimension).unwrap();
+    assert_eq!(column.start.row, dimension.start.row);
+    assert_eq!(column.end.row, dimension.end.row);
+    assert_eq!(
+        worksheet.get_range("B:B")?.len() as u64,
+        column.end.row - column.start.row + 1
+    );
+
+    println!("ranges clamped successfully");
+    Ok(())
+}
@@ -0,0 +1,72 @@
+use excel_reader::cli::run;
+
+fn run_command(args: &[&str]) -> anyhow::Result<(i32, String)> {
+    let args: Vec<String> = args.iter().map(|a| a.to_string()).collect();
+    let mut out: Vec<u8> = vec![];
+    let status = run(&args, &mut out)?;
+    Ok((status, String::from_utf8(out)?))
+}
+
+/// Inspect a workbook with the commands of the `excel-reader` tool.
+fn main() -> anyhow::Result<()> {
+    let path = "examples/sample.xlsx";
+
+    let (status, out) = run_command(&["sheets", path])?;
+    assert_eq!(status, 0);
+    assert_eq!(
+        out.lines().collect::<Vec<&str>>(),
+        vec![
+            "1\tSheet 1 - Basic\tWorkSheet\tVisible",
+            "2\tSheet 2 - Custom grid lines\tWorkSheet\tVisible",
+            "3\tSheet 3 - Custom Colors_Font\tWorkSheet\tVisible",
+        ]
+    );
+
+    // csv of the first worksheet by default, quoting values with line breaks
+    let (_, out) = run_command(&["dump", path])?;
+    assert!(out.starts_with("index,value\n1,Itsuki1\n"));
+    assert!(out.contains("4,\"itsuki4+\nItsuki5\"\n"));
+    assert!(out.ends_with("Dash left,100\n"));
+
+    // by index or name, as json
+    let (_, by_index) = run_command(&["dump", path, "--sheet", "2", "--format", "json"])?;
+    let (_, by_name) = run_command(&[
+        "dump",
+        path,
+        "--format",
+        "json",
+        "--sheet",
+        "Sheet 2 - Custom grid lines",
+    ])?;
+    assert_eq!(by_index, by_name);
+    let rows: Vec<Vec<String>> = serde_json::from_str(&by_index)?;
+    assert_eq!(rows[1], vec!["1", "Itsuki1-sheet2"]);
+
+    let (status, out) = run_command(&["validate", path])?;
+    assert_eq!((status, out.as_str()), (0, "no problems found\n"));
+
+    let (_, out) = run_command(&["meta", path])?;
+    assert!(out.starts_with("content type: Workbook\nsheets: 3\n"));
+    assert!(out.contains("defined names: 0\nmacro sheets: no\n"));
+    let (_, out) = run_command(&["meta", path, "--format", "json"])?;
+    let meta: serde_json::Value = serde_json::from_str(&out)?;
+    assert_eq!(
+        meta["summary"]["sheets"][2]["name"],
+        "Sheet 3 - Custom Colors_Font"
+    );
+
+    let (status, out) = run_command(&["--help"])?;
+    assert_eq!(status, 0);
+    assert!(out.starts_with("Usage: excel-reader"));
+
+    let error = |args: &[&str]| run_command(args).unwrap_err().to_string();
+    assert!(error(&["dump"]).starts_with("Missing command or file."));
+    assert!(error(&["dump", path, "--sheet", "4"]).contains("`4` not found"));
+    assert!(error(&["dump", path, "--format", "xml"]).contains("Unknown format `xml`"));
+    assert!(error(&["meta", path, "--format", "csv"]).contains("not available"));
+    assert!(error(&["dump", path, "--sheet"]).starts_with("--sheet expects a value."));
+    assert!(error(&["convert", path]).starts_with("Unknown command `convert`."));
+
+    println!("cli commands run successfully");
+    Ok(())
+}
@@ -0,0 +1,75 @@
+mod common;
+
+use common::{Package, SAMPLE, SHEET1};
+use excel_reader::common_types::Coordinate;
+
+fn text(a1: &str, text: &str) -> String {
+    format!(
+        r#"<c r="{}" t="inlineStr"><is><t>{}</t></is></c>"#,
+        a1, text
+    )
+}
+
+/// Read the cells of a column named by the header row.
+fn main() -> anyhow::Result<()> {
+    let mut rows = String::new();
+    // an empty row before the header is skipped
+    rows.push_str(r#"<row r="1"><c r="A1"/></row>"#);
+    rows.push_str(&format!(
+        r#"<row r="2">{}{}{}{}</row>"#,
+        text("B2", "Product"),
+        text("C2", " Amount "),
+        text("D2", "Amount"),
+        r#"<c r="A2"/>"#,
+    ));
+    rows.push_str(&format!(
+        r#"<row r="3">{}<c r="C3"><v>10</v></c></row>"#,
+        text("B3", "apple")
+    ));
+    // no amount on row 4, row 5 left out of the file
+    rows.push_str(&format!(r#"<row r="4">{}</row>"#, text("B4", "pear")));
+    rows.push_str(r#"<row r="6"><c r="C6"><f>C3*2</f><v>20</v></c></row>"#);
+
+    let mut excel = Package::open(SAMPLE)?
+        .replace_rows(SHEET1, &rows)
+        .replace_in(
+            SHEET1,
+            r#"<dimension ref="A2:B9"/>"#,
+            r#"<dimension ref="A1:D6"/>"#,
+        )
+        .excel()?;
+    let worksheet = excel.get_worksheet_with_name("Sheet 1 - Basic")?;
+
+    let amounts = worksheet
+        .column_by_header("Amount")?
+        .unwrap()
+        .collect::<anyhow::Result<Vec<_>>>()?;
+    // the leftmost matching header, its text trimmed
+    let coordinates: Vec<Coordinate> = amounts.iter().map(|c| c.coordinate).collect();
+    assert_eq!(
+        coordinates,
+        ["C3", "C4", "C6"]
+            .iter()
+            .map(|a1| Coordinate::parse_a1(a1).unwrap())
+            .collect::<Vec<_>>()
+    );
+    let values: Vec<Option<f64>> = amounts
+        .iter()
+        .map(|c| c.typed_value(worksheet.is_1904).as_f64())
+        .collect();
+    assert_eq!(values, [Some(10.0), None, Some(20.0)]);
+
+    let products: Vec<String> = worksheet
+        .column_by_header(" Product")?
+        .unwrap()
+        .map(|c| Ok(c?.typed_value(worksheet.is_1904).to_string()))
+        .collect::<anyhow::Result<_>>()?;
+    assert_eq!(products, ["apple", "pear", ""]);
+
+    // names are matched as written, and only in the header row
+    assert!(worksheet.column_by_header("amount")?.is_none());
+    assert!(worksheet.column_by_header("apple")?.is_none());
+
+    println!("columns read by header successfully");
+    Ok(())
+}
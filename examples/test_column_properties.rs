@@ -0,0 +1,55 @@
+mod common;
+
+use std::io::Cursor;
+
+use common::{Package, SAMPLE, SHEET1};
+use excel_reader::{excel::Excel, units::column_width_to_px};
+
+/// Read the width, visibility, outline level and style of columns.
+fn main() -> anyhow::Result<()> {
+    let sheet = r#"<?xml version="1.0" encoding="UTF-8"?><worksheet xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main"><dimension ref="A1:F1"/><cols>
+<col min="1" max="1" width="20.7109375" customWidth="1"/>
+<col min="2" max="4" width="9.140625" outlineLevel="1" hidden="1" style="3"/>
+<col min="5" max="5" width="12.5" bestFit="1" collapsed="1"/>
+</cols><sheetData>
+<row r="1"><c r="A1"><v>1</v></c><c r="F1"><v>6</v></c></row>
+</sheetData></worksheet>"#;
+
+    let bytes = Package::open(SAMPLE)?.replace(SHEET1, sheet).bytes()?;
+
+    let mut excel = Excel::from_reader(Cursor::new(bytes))?;
+    let sheets = excel.get_sheets()?;
+    let worksheet = excel.get_worksheet_without_cells(&sheets[0])?;
+    let columns = worksheet.column_properties();
+
+    // spans expanded, F without col element left out
+    let indexes: Vec<u64> = columns.iter().map(|c| c.column).collect();
+    assert_eq!(indexes, vec![1, 2, 3, 4, 5]);
+
+    let first = &columns[0];
+    assert_eq!(first.width, Some(20.7109375));
+    assert!(first.custom_width && !first.hidden);
+    assert_eq!(column_width_to_px(first.width.unwrap(), 7.0), 145.0);
+
+    for grouped in &columns[1..4] {
+        assert!(grouped.hidden);
+        assert_eq!(grouped.outline_level, 1);
+        assert_eq!(grouped.style_index, Some(3));
+        assert_eq!(grouped.width, Some(9.140625));
+    }
+
+    let last = &columns[4];
+    assert!(last.best_fit && last.collapsed);
+    assert_eq!(last.outline_level, 0);
+    assert_eq!(last.style_index, None);
+
+    // spans up to the last column of the sheet
+    let worksheet = excel.get_worksheet_without_cells(&sheets[1])?;
+    let columns = worksheet.column_properties();
+    assert_eq!(columns.len(), 16_384);
+    assert_eq!(columns.last().map(|c| c.column), Some(16_384));
+    assert!(columns.iter().all(|c| c.style_index == Some(13)));
+
+    println!("column properties read successfully");
+    Ok(())
+}
@@ -0,0 +1,135 @@
+mod common;
+
+use common::{Package, SAMPLE, SHEET1};
+use excel_reader::{
+    prelude::*, processed::spreadsheet::sheet::worksheet::cell::cell_value::plain_text::PlainText,
+};
+
+/// Read the cell values of a worksheet column by column.
+fn main() -> anyhow::Result<()> {
+    let mut excel = Excel::from_path(SAMPLE)?;
+    let sheets = excel.get_sheets()?;
+    let worksheet = excel.get_worksheet(&sheets[0])?;
+    let columnar = worksheet.get_columns(&mut excel)?.unwrap();
+    assert_eq!(Some(columnar.dimension), worksheet.dimension);
+
+    // same values as the cells, one slot per row of the dimension
+    let cells = worksheet.get_cells()?;
+    for cell in cells.iter() {
+        let column = columnar.column(cell.coordinate.col).unwrap();
+        assert_eq!(column.len(), columnar.dimension.end.row as usize);
+        assert_eq!(
+            column.is_valid(cell.coordinate.row),
+            cell.value != CellValueType::Empty
+        );
+    }
+    let index = columnar.column(1).unwrap();
+    assert!(matches!(index.data, ColumnData::Mixed(_)));
+    assert_eq!(
+        index.validity.count(),
+        cells
+            .iter()
+            .filter(|c| c.coordinate.col == 1 && c.value != CellValueType::Empty)
+            .count()
+    );
+    assert_eq!(
+        columnar,
+        ColumnarSheet::from_cells(columnar.dimension, cells)
+    );
+
+    // a dimension declared over the whole grid does not reserve a slot for each of its cells
+    let mut excel = Package::open(SAMPLE)?
+        .replace_in(
+            SHEET1,
+            r#"<dimension ref="A2:B9"/>"#,
+            r#"<dimension ref="A1:XFD1048576"/>"#,
+        )
+        .replace_rows(
+            SHEET1,
+            r#"<row r="1"><c r="A1"><v>1</v></c></row><row r="3"><c r="C3"><v>2</v></c></row>"#,
+        )
+        .excel()?;
+    let worksheet = excel.get_worksheet_shape(&sheets[0])?;
+    assert_eq!(
+        worksheet.dimension,
+        Some(Dimension::parse_a1("A1:XFD1048576")?)
+    );
+    let columnar = worksheet.get_columns(&mut excel)?.unwrap();
+    assert_eq!(columnar.dimension, Dimension::parse_a1("A1:C3")?);
+    assert_eq!(columnar.columns.len(), 3);
+    assert_eq!(columnar.column(1).unwrap().sum(), Some(1.0));
+    assert_eq!(columnar.column(3).unwrap().sum(), Some(2.0));
+    assert_eq!(columnar.column(2).unwrap().len(), 3);
+    assert_eq!(columnar.column(2).unwrap().validity.count(), 0);
+
+    // typed columns from shared strings, inline strings, booleans and formulas,
+    // widened to mixed values by a value of another type
+    let mut excel = Package::open(SAMPLE)?
+        .replace_in(
+            SHEET1,
+            r#"<dimension ref="A2:B9"/>"#,
+            r#"<dimension ref="A1:E3"/>"#,
+        )
+        .replace_rows(
+            SHEET1,
+            r#"<row r="1"><c r="A1" t="s"><v>1</v></c><c r="B1" t="b"><v>1</v></c><c r="C1"><v>5</v></c><c r="E1" t="s"><v>2</v></c></row><row r="2"><c r="A2" t="inlineStr"><is><t>inline</t></is></c><c r="B2" t="b"><v>0</v></c><c r="C2"><f>C1-2</f><v>3</v></c><c r="D2" t="e"><v>#DIV/0!</v></c><c r="E2" t="inlineStr"><is><t>text</t></is></c></row><row r="3"><c r="A3"><v>7</v></c><c r="D3" t="inlineStr"><is><t>after error</t></is></c></row>"#,
+        )
+        .excel()?;
+    let worksheet = excel.get_worksheet_shape(&sheets[0])?;
+    let columnar = worksheet.get_columns(&mut excel)?.unwrap();
+    assert_eq!(columnar.dimension, Dimension::parse_a1("A1:E3")?);
+    let text = |text: &str| {
+        CellValueType::PlainText(PlainText {
+            text: text.to_string(),
+            phonetic_properties: None,
+            phonetic_runs: None,
+        })
+    };
+    assert_eq!(
+        columnar.column(1).unwrap().data,
+        ColumnData::Mixed(vec![
+            text("index"),
+            text("inline"),
+            CellValueType::Numeric(7.0)
+        ])
+    );
+    let bools = columnar.column(2).unwrap();
+    assert_eq!(bools.data, ColumnData::Bool(vec![true, false, false]));
+    assert!(!bools.is_valid(3));
+    let numbers = columnar.column(3).unwrap();
+    assert_eq!(numbers.data, ColumnData::Numeric(vec![5.0, 3.0, 0.0]));
+    assert_eq!(numbers.sum(), Some(8.0));
+    assert_eq!(
+        columnar.column(4).unwrap().data,
+        ColumnData::Mixed(vec![
+            CellValueType::Empty,
+            CellValueType::Error(CellErrorType::Div0),
+            text("after error"),
+        ])
+    );
+    let texts = columnar.column(5).unwrap();
+    assert_eq!(
+        texts.data,
+        ColumnData::Text(vec!["value".to_string(), "text".to_string(), String::new()])
+    );
+    assert_eq!(texts.validity.count(), 2);
+    let cells = excel.get_worksheet(&sheets[0])?.get_cells()?;
+    assert_eq!(
+        columnar,
+        ColumnarSheet::from_cells(columnar.dimension, cells)
+    );
+
+    // binary worksheets
+    let mut excel = Excel::from_path("examples/sample.xlsb")?;
+    let sheets = excel.get_sheets()?;
+    let worksheet = excel.get_worksheet(&sheets[0])?;
+    let columnar = worksheet.get_columns(&mut excel)?.unwrap();
+    assert_eq!(
+        columnar,
+        ColumnarSheet::from_cells(columnar.dimension, worksheet.get_cells()?)
+    );
+    assert!(columnar.columns.iter().any(|c| c.validity.count() > 0));
+
+    println!("columns read successfully");
+    Ok(())
+}
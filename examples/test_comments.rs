@@ -0,0 +1,100 @@
+mod common;
+
+use std::io::Cursor;
+
+use common::{Package, SAMPLE, SHEET1_RELS};
+use excel_reader::prelude::*;
+
+/// Read the notes of a sheet: a rich text note shown on hover, and a plain text note always shown.
+fn main() -> anyhow::Result<()> {
+    let rels = r#"<?xml version="1.0" encoding="UTF-8"?>
+<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships"><Relationship Id="rId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/hyperlink" Target="http://www.google.com" TargetMode="External"/><Relationship Id="rId2" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/vmlDrawing" Target="../drawings/vmlDrawing1.vml"/><Relationship Id="rId3" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/comments" Target="../comments1.xml"/></Relationships>"#;
+    let comments = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<comments xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main"><authors><author>Reviewer</author><author>Auditor</author></authors><commentList>
+<comment ref="B2" authorId="0" shapeId="0"><text><r><rPr><b/><sz val="9"/><color indexed="81"/><rFont val="Tahoma"/></rPr><t>Reviewer:</t></r><r><rPr><sz val="9"/><color indexed="81"/><rFont val="Tahoma"/></rPr><t xml:space="preserve">
+Check this total &amp; the tax</t></r></text></comment>
+<comment ref="A3" authorId="1" shapeId="0"><text><t>Approved</t></text></comment>
+</commentList></comments>"#;
+    let vml = r##"<xml xmlns:v="urn:schemas-microsoft-com:vml" xmlns:o="urn:schemas-microsoft-com:office:office" xmlns:x="urn:schemas-microsoft-com:office:excel">
+ <o:shapelayout v:ext="edit"><o:idmap v:ext="edit" data="1"/></o:shapelayout>
+ <v:shape id="_x0000_s1025" type="#_x0000_t202" style="position:absolute;visibility:hidden" fillcolor="#ffffe1"><v:textbox><div style='text-align:left'></div></v:textbox>
+  <x:ClientData ObjectType="Note"><x:MoveWithCells/><x:SizeWithCells/><x:Anchor>2, 15, 0, 2, 4, 15, 4, 16</x:Anchor><x:AutoFill>False</x:AutoFill><x:Row>1</x:Row><x:Column>1</x:Column></x:ClientData>
+ </v:shape>
+ <v:shape id="_x0000_s1026" type="#_x0000_t202" style="position:absolute;visibility:visible" fillcolor="#ffffe1">
+  <x:ClientData ObjectType="Note"><x:Anchor>1, 15, 1, 2, 3, 15, 5, 16</x:Anchor><x:Row>2</x:Row><x:Column>0</x:Column><x:Visible/></x:ClientData>
+ </v:shape>
+</xml>"##;
+
+    let bytes = Package::open(SAMPLE)?
+        .replace(SHEET1_RELS, rels)
+        .replace("xl/comments1.xml", comments)
+        .replace("xl/drawings/vmlDrawing1.vml", vml)
+        .bytes()?;
+
+    let mut excel = Excel::from_reader(Cursor::new(bytes))?;
+    let sheets = excel.get_sheets()?;
+
+    let (raw_comments, raw_vml) = excel.get_raw_comments_for_worksheet(&sheets[0])?;
+    assert_eq!(raw_comments.authors, vec!["Reviewer", "Auditor"]);
+    assert_eq!(raw_vml.notes.len(), 2);
+
+    let worksheet = excel.get_worksheet(&sheets[0])?;
+    assert_eq!(worksheet.comments.len(), 2);
+
+    let note = &worksheet.comments[0];
+    assert_eq!(note.coordinate, Coordinate::from_point((2, 2)));
+    assert_eq!(note.author.as_deref(), Some("Reviewer"));
+    assert_eq!(note.text, "Reviewer:\nCheck this total & the tax");
+    assert_eq!(note.runs.len(), 2);
+    assert_eq!(note.runs[0].text, "Reviewer:");
+    assert!(note.runs[0].font.bold);
+    assert!(!note.runs[1].font.bold);
+    assert!(!note.visible);
+
+    let approved = &worksheet.comments[1];
+    assert_eq!(approved.coordinate, Coordinate::from_point((3, 1)));
+    assert_eq!(approved.author.as_deref(), Some("Auditor"));
+    assert_eq!(approved.text, "Approved");
+    assert!(approved.runs.is_empty());
+    assert!(approved.visible);
+
+    // exported along with the values of their row
+    let csv = worksheet.to_csv(
+        vec![],
+        &CsvOptions {
+            range: Some(Dimension::parse_a1("A2:B3")?),
+            include_comments: true,
+            ..CsvOptions::default()
+        },
+    )?;
+    assert_eq!(
+        String::from_utf8(csv)?,
+        "index,value,\"B2: Reviewer:\nCheck this total & the tax\"\n1,Itsuki1,A3: Approved\n"
+    );
+    let json = worksheet.to_json(&JsonOptions {
+        shape: JsonShape::Objects,
+        range: Some(Dimension::parse_a1("A2:B4")?),
+        include_comments: true,
+        ..JsonOptions::default()
+    })?;
+    assert_eq!(
+        json,
+        r#"[{"index":"1","value":"Itsuki1","comments":{"A3":"Approved"}},{"index":"2","value":"hlink_inside","comments":{}}]"#
+    );
+    let json = worksheet.to_json(&JsonOptions {
+        range: Some(Dimension::parse_a1("A2:B2")?),
+        include_comments: true,
+        ..JsonOptions::default()
+    })?;
+    assert_eq!(
+        json,
+        r#"[["index","value",{"B2":"Reviewer:\nCheck this total & the tax"}]]"#
+    );
+
+    // sheets without comments
+    let worksheet = excel.get_worksheet(&sheets[1])?;
+    assert!(worksheet.comments.is_empty());
+
+    println!("comments read successfully");
+    Ok(())
+}
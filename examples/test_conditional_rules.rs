@@ -0,0 +1,88 @@
+mod common;
+
+use std::io::Cursor;
+
+use common::{Package, SAMPLE, SHEET1};
+use excel_reader::{common_types::Coordinate, excel::Excel};
+
+/// Look up the conditional formatting rules of cells, with overlapping ranges and rules out of priority order.
+fn main() -> anyhow::Result<()> {
+    let sheet = r#"<?xml version="1.0" encoding="UTF-8"?><worksheet xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main"><dimension ref="A1"/><sheetData><row r="1"><c r="A1"><v>1</v></c></row></sheetData>
+<conditionalFormatting sqref="B2:D10"><cfRule type="cellIs" dxfId="0" priority="3" operator="between"><formula>1</formula><formula>10</formula></cfRule><cfRule type="colorScale" priority="1"><colorScale><cfvo type="min"/><cfvo type="max"/><color rgb="FFF8696B"/><color rgb="FF63BE7B"/></colorScale></cfRule></conditionalFormatting>
+<conditionalFormatting sqref="C5:F6 H1"><cfRule type="expression" dxfId="1" priority="2" stopIfTrue="1"><formula>AND($A5&gt;0,C5&lt;&gt;"")</formula></cfRule></conditionalFormatting>
+<conditionalFormatting sqref="A20:A30"><cfRule type="containsText" dxfId="2" priority="4" operator="containsText" text="late"><formula>NOT(ISERROR(SEARCH("late",A20)))</formula></cfRule></conditionalFormatting>
+<dataValidations count="1"><dataValidation type="list" sqref="A1"><formula1>"a,b"</formula1></dataValidation></dataValidations>
+</worksheet>"#;
+
+    let bytes = Package::open(SAMPLE)?.replace(SHEET1, sheet).bytes()?;
+
+    let mut excel = Excel::from_reader(Cursor::new(bytes))?;
+    let sheets = excel.get_sheets()?;
+    let worksheet = excel.get_worksheet(&sheets[0])?;
+
+    assert_eq!(worksheet.conditional_formatting_rules.len(), 4);
+    let between = &worksheet.conditional_formatting_rules[0];
+    assert_eq!(between.r#type, "cellIs");
+    assert_eq!(between.operator.as_deref(), Some("between"));
+    assert_eq!(between.formulas, vec!["1", "10"]);
+    assert_eq!(between.dxf_id, Some(0));
+    let expression = &worksheet.conditional_formatting_rules[2];
+    assert_eq!(expression.formulas, vec![r#"AND($A5>0,C5<>"")"#]);
+    assert!(expression.stop_if_true);
+    assert_eq!(
+        worksheet.conditional_formatting_rules[3].text.as_deref(),
+        Some("late")
+    );
+
+    let priorities = |a1: &str| -> Vec<u64> {
+        let coordinate = Coordinate::from_a1(a1.as_bytes()).unwrap();
+        worksheet
+            .conditional_rules_for(coordinate)
+            .iter()
+            .map(|r| r.priority)
+            .collect()
+    };
+    assert_eq!(priorities("B2"), vec![1, 3]);
+    assert_eq!(priorities("C5"), vec![1, 2, 3]);
+    assert_eq!(priorities("D6"), vec![1, 2, 3]);
+    assert_eq!(priorities("E5"), vec![2]);
+    assert_eq!(priorities("H1"), vec![2]);
+    assert_eq!(priorities("A25"), vec![4]);
+    assert!(priorities("A1").is_empty());
+    assert!(priorities("E7").is_empty());
+    assert!(priorities("G5").is_empty());
+
+    // every cell agrees with a scan of the rule ranges
+    for row in 1..=35 {
+        for col in 1..=12 {
+            let mut scanned: Vec<_> = worksheet
+                .conditional_formatting_rules
+                .iter()
+                .filter(|rule| {
+                    rule.sqref.split_whitespace().any(|r| {
+                        let (start, end) = r.split_once(':').unwrap_or((r, r));
+                        let start = Coordinate::from_a1(start.as_bytes()).unwrap();
+                        let end = Coordinate::from_a1(end.as_bytes()).unwrap();
+                        (start.row..=end.row).contains(&row) && (start.col..=end.col).contains(&col)
+                    })
+                })
+                .collect();
+            scanned.sort_by_key(|rule| rule.priority);
+            assert_eq!(
+                worksheet.conditional_rules_for(Coordinate::from_point((row, col))),
+                scanned
+            );
+        }
+    }
+
+    // validations are still looked up on their own
+    assert!(worksheet
+        .validation_for_cell(Coordinate::from_point((1, 1)))
+        .is_some());
+    assert!(worksheet
+        .validation_for_cell(Coordinate::from_point((2, 2)))
+        .is_none());
+
+    println!("conditional formatting rules looked up successfully");
+    Ok(())
+}
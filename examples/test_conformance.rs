@@ -0,0 +1,91 @@
+mod common;
+
+use common::{Package, SAMPLE, SHEET1};
+use excel_reader::prelude::*;
+
+/// Check the parts of a workbook against the schema, leaving out the extensions of other schemas.
+fn main() -> anyhow::Result<()> {
+    let mut excel = Package::open(SAMPLE)?.excel()?;
+    assert_eq!(excel.check_conformance()?, vec![]);
+
+    // the binary parts of a xlsb file are not xml, and are left out
+    let mut excel = Excel::from_path("examples/sample.xlsb")?;
+    assert_eq!(excel.check_conformance()?, vec![]);
+
+    // a rule and a validation of SpreadsheetML missing their required attributes
+    let invalid = concat!(
+        r#"<conditionalFormatting sqref="A1"><cfRule type="dataBar"/></conditionalFormatting>"#,
+        r#"<dataValidations count="1"><dataValidation type="list"><formula1>"a,b"</formula1></dataValidation></dataValidations>"#,
+    );
+    // the same in the elements of other schemas: x14 rules and validations have no priority nor sqref attributes
+    let alternate_content = concat!(
+        r#"<mc:AlternateContent xmlns:mc="http://schemas.openxmlformats.org/markup-compatibility/2006">"#,
+        r#"<mc:Choice Requires="x14"><cfRule type="dataBar"/></mc:Choice></mc:AlternateContent>"#,
+    );
+    let extensions = concat!(
+        r#"<extLst><ext uri="{78C0D931-6437-407d-A8EE-F0AAD7539E65}" xmlns:x14="http://schemas.microsoft.com/office/spreadsheetml/2009/9/main">"#,
+        r#"<x14:conditionalFormattings><x14:conditionalFormatting xmlns:xm="http://schemas.microsoft.com/office/excel/2006/main">"#,
+        r#"<x14:cfRule type="dataBar" id="{00000000-0000-0000-0000-000000000001}"><x14:dataBar minLength="0" maxLength="100"/></x14:cfRule>"#,
+        r#"<xm:sqref>A1:A9</xm:sqref></x14:conditionalFormatting></x14:conditionalFormattings></ext>"#,
+        r#"<ext uri="{CCE6A557-97BC-4b89-ADB6-D9C93CAAB3DF}" xmlns:x14="http://schemas.microsoft.com/office/spreadsheetml/2009/9/main">"#,
+        r#"<x14:dataValidations count="1" xmlns:xm="http://schemas.microsoft.com/office/excel/2006/main"><x14:dataValidation type="list">"#,
+        r#"<x14:formula1><xm:f>$A$1:$A$3</xm:f></x14:formula1><xm:sqref>B2</xm:sqref></x14:dataValidation></x14:dataValidations></ext></extLst>"#,
+    );
+    let mut excel = Package::open(SAMPLE)?
+        .replace_in(
+            SHEET1,
+            "<hyperlinks",
+            &format!("{}{}<hyperlinks", invalid, alternate_content),
+        )
+        .replace_in(
+            SHEET1,
+            "</worksheet>",
+            &format!("{}</worksheet>", extensions),
+        )
+        .excel()?;
+    let violations = excel.check_conformance()?;
+    let found: Vec<(&str, &str, &ConformanceViolationKind)> = violations
+        .iter()
+        .map(|v| (v.part.as_str(), v.element.as_str(), &v.kind))
+        .collect();
+    assert_eq!(
+        found,
+        vec![
+            (
+                SHEET1,
+                "worksheet/conditionalFormatting/cfRule",
+                &ConformanceViolationKind::MissingAttribute {
+                    attribute: "priority".to_string()
+                }
+            ),
+            (
+                SHEET1,
+                "worksheet/dataValidations/dataValidation",
+                &ConformanceViolationKind::MissingAttribute {
+                    attribute: "sqref".to_string()
+                }
+            ),
+        ]
+    );
+
+    // the workbook part is the one the package relationships point to
+    let mut excel = Package::open(SAMPLE)?
+        .replace_in(
+            "_rels/.rels",
+            r#"Target="xl/workbook.xml""#,
+            r#"Target="/xl/workbook.xml""#,
+        )
+        .replace_in(
+            "xl/workbook.xml",
+            r#"<sheet name="Sheet 1 - Basic""#,
+            "<sheet",
+        )
+        .excel()?;
+    let violations = excel.check_conformance()?;
+    assert_eq!(violations.len(), 1);
+    assert_eq!(violations[0].part, "xl/workbook.xml");
+    assert_eq!(violations[0].element, "workbook/sheets/sheet");
+
+    println!("conformance checked successfully");
+    Ok(())
+}
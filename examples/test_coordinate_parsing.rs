@@ -0,0 +1,143 @@
+use excel_reader::common_types::{
+    Coordinate, CoordinateError, OutOfRangePolicy, PackedCoordinate, MAX_COLUMN_COUNT,
+    MAX_ROW_COUNT,
+};
+use excel_reader::excel::Excel;
+use excel_reader::raw::spreadsheet::sheet::worksheet::cell::XlsxCell;
+
+/// Parse A1 references, with the reason malformed references fail.
+fn main() -> anyhow::Result<()> {
+    let b3 = Coordinate::from_point((3, 2));
+    assert_eq!(Coordinate::parse_a1("B3"), Ok(b3));
+    assert_eq!(Coordinate::parse_a1("$B$3"), Ok(b3));
+    assert_eq!(Coordinate::parse_a1("b3"), Ok(b3));
+    assert_eq!("B3".parse::<Coordinate>(), Ok(b3));
+    assert_eq!(
+        Coordinate::parse_a1("XFD1048576"),
+        Ok(Coordinate::from_point((MAX_ROW_COUNT, MAX_COLUMN_COUNT)))
+    );
+
+    // malformed references
+    assert_eq!(Coordinate::parse_a1(""), Err(CoordinateError::Empty));
+    assert_eq!(
+        Coordinate::parse_a1("12"),
+        Err(CoordinateError::MissingColumn)
+    );
+    assert_eq!(Coordinate::parse_a1("AB"), Err(CoordinateError::MissingRow));
+    assert_eq!(Coordinate::parse_a1("A0"), Err(CoordinateError::ZeroRow));
+    assert_eq!(
+        Coordinate::parse_a1("A-1"),
+        Err(CoordinateError::UnexpectedCharacter {
+            position: 1,
+            character: '-'
+        })
+    );
+    assert_eq!(
+        Coordinate::parse_a1("A1B"),
+        Err(CoordinateError::UnexpectedCharacter {
+            position: 2,
+            character: 'B'
+        })
+    );
+    assert_eq!(
+        Coordinate::parse_a1("A1:B2"),
+        Err(CoordinateError::UnexpectedCharacter {
+            position: 2,
+            character: ':'
+        })
+    );
+    assert_eq!(
+        Coordinate::parse_a1("é1"),
+        Err(CoordinateError::UnexpectedCharacter {
+            position: 0,
+            character: 'é'
+        })
+    );
+
+    // beyond the limits of a worksheet, and beyond u64
+    assert_eq!(
+        Coordinate::parse_a1("XFE1"),
+        Err(CoordinateError::ColumnOutOfRange("XFE".to_string()))
+    );
+    assert_eq!(
+        Coordinate::parse_a1("A1048577"),
+        Err(CoordinateError::RowOutOfRange("1048577".to_string()))
+    );
+    let huge_row = format!("A{}", "9".repeat(30));
+    assert_eq!(
+        Coordinate::parse_a1(&huge_row),
+        Err(CoordinateError::RowOutOfRange("9".repeat(30)))
+    );
+    assert_eq!(
+        Coordinate::parse_a1(&format!("{}1", "Z".repeat(20))),
+        Err(CoordinateError::ColumnOutOfRange("Z".repeat(20)))
+    );
+
+    // clamped to the last column and row
+    assert_eq!(
+        Coordinate::parse_a1_with_policy("XFE1", OutOfRangePolicy::Clamp),
+        Ok(Coordinate::from_point((1, MAX_COLUMN_COUNT)))
+    );
+    assert_eq!(
+        Coordinate::parse_a1_with_policy(&huge_row, OutOfRangePolicy::Clamp),
+        Ok(Coordinate::from_point((MAX_ROW_COUNT, 1)))
+    );
+    // malformed references fail all the same
+    assert_eq!(
+        Coordinate::parse_a1_with_policy("A0", OutOfRangePolicy::Clamp),
+        Err(CoordinateError::ZeroRow)
+    );
+
+    // errors convert to anyhow errors
+    let error = "A-1"
+        .parse::<Coordinate>()
+        .map_err(anyhow::Error::from)
+        .unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "unexpected character '-' at position 1 of cell reference"
+    );
+
+    // packed coordinates round trip up to the last cell of the grid
+    for coordinate in [
+        Coordinate::from_point((1, 1)),
+        b3,
+        Coordinate::from_point((MAX_ROW_COUNT, MAX_COLUMN_COUNT)),
+        Coordinate::from_point((u32::MAX as u64, u16::MAX as u64)),
+    ] {
+        let packed = PackedCoordinate::new(coordinate).unwrap();
+        assert_eq!(
+            (packed.row(), packed.col()),
+            (coordinate.row, coordinate.col)
+        );
+        assert_eq!(Coordinate::from(packed), coordinate);
+    }
+    let last = PackedCoordinate::new(Coordinate::from_point((MAX_ROW_COUNT, MAX_COLUMN_COUNT)));
+    assert!(PackedCoordinate::new(b3) < last);
+    assert_eq!(PackedCoordinate::new(Coordinate::from_point((0, 1))), None);
+    assert_eq!(
+        PackedCoordinate::new(Coordinate::from_point((1, u16::MAX as u64 + 1))),
+        None
+    );
+    assert_eq!(
+        PackedCoordinate::new(Coordinate::from_point((u32::MAX as u64 + 1, 1))),
+        None
+    );
+
+    // raw cells store their coordinate packed: no wider than a u64, with the None of the option for free
+    assert_eq!(
+        std::mem::size_of::<Option<PackedCoordinate>>(),
+        std::mem::size_of::<u64>()
+    );
+    let mut excel = Excel::from_path("examples/sample.xlsx")?;
+    let raw = excel.get_raw_worksheet_with_name("Sheet 1 - Basic")?;
+    let rows = raw.sheet_data.unwrap().rows.unwrap();
+    let cell = &rows[1].cells.as_ref().unwrap()[1];
+    assert_eq!(cell.coordinate(), Some(Coordinate::from_point((2, 2))));
+    let last = Coordinate::from_point((MAX_ROW_COUNT, MAX_COLUMN_COUNT));
+    assert_eq!(XlsxCell::new(last).coordinate(), Some(last));
+    assert_eq!(XlsxCell::default().coordinate(), None);
+
+    println!("coordinates parsed successfully");
+    Ok(())
+}
@@ -0,0 +1,115 @@
+mod common;
+
+use std::io::Cursor;
+
+use common::{Package, SAMPLE, SHEET1};
+use excel_reader::prelude::*;
+
+fn csv(worksheet: &Worksheet, options: CsvOptions) -> anyhow::Result<String> {
+    Ok(String::from_utf8(worksheet.to_csv(vec![], &options)?)?)
+}
+
+fn range(start: &str, end: &str) -> Option<Dimension> {
+    Some(Dimension {
+        start: Coordinate::from_a1(start.as_bytes())?,
+        end: Coordinate::from_a1(end.as_bytes())?,
+    })
+}
+
+/// Convert worksheets to CSV, with formatted or plain values.
+fn main() -> anyhow::Result<()> {
+    // formatted numbers and a text holding the delimiter and quotes, below the data of the sheet
+    let bytes = Package::open(SAMPLE)?
+        .append_rows(
+            SHEET1,
+            concat!(
+                r#"<row r="11"><c r="C11" s="28"><v>0.256</v></c><c r="D11" s="29"><v>45306</v></c><c r="E11" s="30"><v>1234567.891</v></c></row>"#,
+                r#"<row r="12"><c r="C12" t="b"><f>1=1</f><v>1</v></c><c r="E12" t="inlineStr"><is><t>say "hi"; bye</t></is></c></row>"#,
+            ),
+        )
+        .append_cell_formats(concat!(
+            r#"<xf numFmtId="10" fontId="0" applyNumberFormat="1"/>"#,
+            r#"<xf numFmtId="14" fontId="0" applyNumberFormat="1"/>"#,
+            r#"<xf numFmtId="4" fontId="0" applyNumberFormat="1"/>"#,
+        ))
+        .bytes()?;
+
+    let mut excel = Excel::from_reader(Cursor::new(bytes))?;
+    let worksheet = excel.get_worksheet_with_name("Sheet 1 - Basic")?;
+
+    // every cell of the sheet, empty cells and rows as empty fields
+    let all = csv(&worksheet, CsvOptions::default())?;
+    assert!(all.starts_with("Basic,,,,\nindex,value,,,\n1,Itsuki1,,,\n"));
+    assert!(all.contains("4,\"itsuki4+\nItsuki5\",,,\n"));
+    assert!(all.contains("Dash left,100,,,\n,,,,\n,,25.60%"));
+
+    // formatted values
+    let options = CsvOptions {
+        range: range("C11", "E12"),
+        ..CsvOptions::default()
+    };
+    assert_eq!(
+        csv(&worksheet, options.clone())?,
+        "25.60%,01-15-24,\"1,234,567.89\"\nTRUE,,\"say \"\"hi\"\"; bye\"\n"
+    );
+
+    // plain values, separated by semicolons
+    let plain = CsvOptions {
+        formatted: false,
+        delimiter: ';',
+        ..options.clone()
+    };
+    assert_eq!(
+        csv(&worksheet, plain.clone())?,
+        "0.256;45306;1234567.891\n1;;\"say \"\"hi\"\"; bye\"\n"
+    );
+
+    // quoting
+    let always = CsvOptions {
+        quoting: CsvQuoting::Always,
+        crlf: true,
+        ..plain.clone()
+    };
+    assert_eq!(
+        csv(&worksheet, always)?,
+        "\"0.256\";\"45306\";\"1234567.891\"\r\n\"1\";\"\";\"say \"\"hi\"\"; bye\"\r\n"
+    );
+    let non_numeric = CsvOptions {
+        quoting: CsvQuoting::NonNumeric,
+        ..options.clone()
+    };
+    assert_eq!(
+        csv(&worksheet, non_numeric)?,
+        "\"25.60%\",\"01-15-24\",\"1,234,567.89\"\n\"TRUE\",\"\",\"say \"\"hi\"\"; bye\"\n"
+    );
+    let never = CsvOptions {
+        quoting: CsvQuoting::Never,
+        ..plain
+    };
+    assert_eq!(
+        csv(&worksheet, never)?,
+        "0.256;45306;1234567.891\n1;;say \"hi\"; bye\n"
+    );
+
+    // range clamped to the data of the sheet, nothing outside of it
+    let options = CsvOptions {
+        range: range("E12", "Z100"),
+        ..CsvOptions::default()
+    };
+    assert_eq!(csv(&worksheet, options)?, "\"say \"\"hi\"\"; bye\"\n");
+    let options = CsvOptions {
+        range: range("X1", "Z3"),
+        ..CsvOptions::default()
+    };
+    assert_eq!(csv(&worksheet, options)?, "");
+
+    // written to a file
+    let path = std::env::temp_dir().join("excel_reader_test_csv_export.csv");
+    let file = std::fs::File::create(&path)?;
+    worksheet.to_csv(std::io::BufWriter::new(file), &CsvOptions::default())?;
+    assert_eq!(std::fs::read_to_string(&path)?, all);
+    std::fs::remove_file(path)?;
+
+    println!("csv exported successfully");
+    Ok(())
+}
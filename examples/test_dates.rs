@@ -0,0 +1,149 @@
+mod common;
+
+use std::io::Cursor;
+
+use chrono::{NaiveDate, NaiveDateTime};
+use common::{Package, SAMPLE, SHEET1, WORKBOOK};
+use excel_reader::{common_types::Coordinate, dates, excel::Excel};
+
+fn date_time(y: i32, m: u32, d: u32, h: u32, min: u32, s: u32) -> NaiveDateTime {
+    NaiveDate::from_ymd_opt(y, m, d)
+        .unwrap()
+        .and_hms_opt(h, min, s)
+        .unwrap()
+}
+
+/// Sample workbook with date cells appended to the first sheet.
+fn sample_with_dates(is_1904: bool) -> anyhow::Result<Vec<u8>> {
+    let package = Package::open(SAMPLE)?
+        .append_rows(
+            SHEET1,
+            concat!(
+                r#"<row r="10"><c r="D10" s="28"><v>43844.5</v></c><c r="E10" s="29"><v>0.75</v></c><c r="F10" s="30"><v>43844.5</v></c></row>"#,
+                r#"<row r="11"><c r="D11" s="28"><f>D10+1</f><v>43845.5</v></c><c r="E11" t="d"><v>2024-01-15T12:00:00</v></c><c r="F11" s="28" t="inlineStr"><is><t>2024-01-15</t></is></c></row>"#,
+            ),
+        )
+        .append_cell_formats(concat!(
+            r#"<xf numFmtId="22" fontId="0" applyNumberFormat="1"/>"#,
+            r#"<xf numFmtId="20" fontId="0" applyNumberFormat="1"/>"#,
+            r#"<xf numFmtId="4" fontId="0" applyNumberFormat="1"/>"#,
+        ));
+    let package = match is_1904 {
+        true => package.replace_in(
+            WORKBOOK,
+            "<bookViews>",
+            r#"<workbookPr date1904="1"/><bookViews>"#,
+        ),
+        false => package,
+    };
+    package.bytes()
+}
+
+/// Convert serial numbers of date cells to chrono date times, in both date systems.
+fn main() -> anyhow::Result<()> {
+    // 1900 date system, with its fictitious 1900-02-29
+    assert_eq!(
+        dates::from_serial(1.0, false),
+        Some(date_time(1900, 1, 1, 0, 0, 0))
+    );
+    assert_eq!(
+        dates::from_serial(59.0, false),
+        Some(date_time(1900, 2, 28, 0, 0, 0))
+    );
+    assert_eq!(dates::from_serial(60.0, false), None);
+    assert_eq!(dates::from_serial(60.5, false), None);
+    assert_eq!(
+        dates::from_serial(61.0, false),
+        Some(date_time(1900, 3, 1, 0, 0, 0))
+    );
+    assert_eq!(
+        dates::from_serial(45306.5, false),
+        Some(date_time(2024, 1, 15, 12, 0, 0))
+    );
+    // times without a date
+    assert_eq!(
+        dates::from_serial(0.75, false),
+        Some(date_time(1899, 12, 31, 18, 0, 0))
+    );
+    assert_eq!(dates::from_serial(-1.0, false), None);
+    assert_eq!(dates::from_serial(2_958_466.0, false), None);
+    assert_eq!(
+        dates::from_serial(2_958_465.0, false),
+        Some(date_time(9999, 12, 31, 0, 0, 0))
+    );
+    // rounded to the millisecond
+    let serial = 45306.0 + 1.0 / 86_400.0;
+    assert_eq!(
+        dates::from_serial(serial, false),
+        Some(date_time(2024, 1, 15, 0, 0, 1))
+    );
+
+    // 1904 date system
+    assert_eq!(
+        dates::from_serial(0.0, true),
+        Some(date_time(1904, 1, 1, 0, 0, 0))
+    );
+    assert_eq!(
+        dates::from_serial(43844.5, true),
+        Some(date_time(2024, 1, 15, 12, 0, 0))
+    );
+
+    // and back
+    for (date_time, is_1904, serial) in [
+        (date_time(2024, 1, 15, 12, 0, 0), false, 45306.5),
+        (date_time(2024, 1, 15, 12, 0, 0), true, 43844.5),
+        (date_time(1900, 2, 28, 0, 0, 0), false, 59.0),
+        (date_time(1900, 3, 1, 0, 0, 0), false, 61.0),
+        (date_time(1899, 12, 31, 6, 0, 0), false, 0.25),
+    ] {
+        assert_eq!(dates::to_serial(date_time, is_1904), Some(serial));
+        assert_eq!(dates::from_serial(serial, is_1904), Some(date_time));
+    }
+    assert_eq!(
+        dates::to_serial(date_time(1899, 12, 30, 0, 0, 0), false),
+        None
+    );
+    assert_eq!(
+        dates::to_serial(date_time(1903, 12, 31, 0, 0, 0), true),
+        None
+    );
+
+    // cells of a workbook in each date system
+    for is_1904 in [true, false] {
+        let mut excel = Excel::from_reader(Cursor::new(sample_with_dates(is_1904)?))?;
+        let worksheet = excel.get_worksheet_with_name("Sheet 1 - Basic")?;
+        assert_eq!(worksheet.is_1904, is_1904);
+        let cell_date_time = |a1: &str| -> anyhow::Result<Option<NaiveDateTime>> {
+            let cell = worksheet.get_cell(Coordinate::from_a1(a1.as_bytes()).unwrap())?;
+            Ok(cell.date_time(worksheet.is_1904))
+        };
+
+        let expected = if is_1904 {
+            date_time(2024, 1, 15, 12, 0, 0)
+        } else {
+            date_time(2020, 1, 14, 12, 0, 0)
+        };
+        assert_eq!(cell_date_time("D10")?, Some(expected));
+        assert_eq!(
+            cell_date_time("D11")?,
+            Some(expected + chrono::Duration::days(1))
+        );
+        // ISO 8601 dates are absolute
+        assert_eq!(
+            cell_date_time("E11")?,
+            Some(date_time(2024, 1, 15, 12, 0, 0))
+        );
+        // a time
+        assert_eq!(
+            cell_date_time("E10")?.map(|d| d.time()),
+            Some(date_time(2024, 1, 1, 18, 0, 0).time())
+        );
+        // not formatted as a date, and text
+        assert_eq!(cell_date_time("F10")?, None);
+        assert_eq!(cell_date_time("F11")?, None);
+        assert_eq!(cell_date_time("B3")?, None);
+    }
+
+    println!("dates converted successfully");
+    Ok(())
+}
@@ -0,0 +1,95 @@
+mod common;
+
+use std::io::Cursor;
+
+use common::{Package, SAMPLE, WORKBOOK};
+use excel_reader::{
+    common_types::{Coordinate, Dimension, MAX_COLUMN_COUNT},
+    excel::Excel,
+    processed::spreadsheet::defined_name::DefinedNameScope,
+};
+
+/// Read the defined names of a workbook and resolve them to ranges.
+fn main() -> anyhow::Result<()> {
+    let mut excel = Excel::from_path("examples/sample.xlsx")?;
+    assert!(excel.defined_names()?.is_empty());
+    assert!(excel.defined_name("Values", None)?.is_none());
+
+    let bytes = Package::open(SAMPLE)?
+        .replace_in(WORKBOOK, "</sheets>", concat!(
+                        "</sheets><definedNames>",
+                        r#"<definedName name="_xlnm.Print_Titles" localSheetId="0">'Sheet 1 - Basic'!$2:$2</definedName>"#,
+                        r#"<definedName name="Values" comment="values of the first sheet">'Sheet 1 - Basic'!$B$3:$B$6</definedName>"#,
+                        r#"<definedName name="Values" localSheetId="1">'Sheet 2 - Custom grid lines'!$B$3:$B$4</definedName>"#,
+                        r#"<definedName name="Header">'Sheet 1 - Basic'!$A$2,'Sheet 1 - Basic'!$B$2</definedName>"#,
+                        r#"<definedName name="Rate" hidden="1">0.2</definedName>"#,
+                        r#"<definedName name="Broken">#REF!</definedName>"#,
+                        "</definedNames>"
+                    ))
+        .bytes()?;
+    let mut excel = Excel::from_reader(Cursor::new(bytes))?;
+
+    let names = excel.defined_names()?;
+    assert_eq!(names.len(), 6);
+
+    // built-in name scoped to the first sheet, whole row extended to the last column
+    let print_titles = &names[0];
+    assert!(print_titles.is_built_in());
+    assert_eq!(
+        print_titles.scope,
+        DefinedNameScope::Sheet("Sheet 1 - Basic".to_string())
+    );
+    let titles = print_titles.range().unwrap();
+    assert_eq!(titles.range.start, Coordinate::from_point((2, 1)));
+    assert_eq!(
+        titles.range.end,
+        Coordinate::from_point((2, MAX_COLUMN_COUNT))
+    );
+
+    // the name of the sheet takes precedence over the workbook level name
+    let values = excel.defined_name("values", None)?.unwrap();
+    assert_eq!(values.scope, DefinedNameScope::Workbook);
+    assert_eq!(values.refers_to, "'Sheet 1 - Basic'!$B$3:$B$6");
+    assert_eq!(values.comment.as_deref(), Some("values of the first sheet"));
+    let local = excel
+        .defined_name("Values", Some("Sheet 2 - Custom grid lines"))?
+        .unwrap();
+    assert_eq!(
+        local.scope,
+        DefinedNameScope::Sheet("Sheet 2 - Custom grid lines".to_string())
+    );
+    let global = excel
+        .defined_name("Values", Some("Sheet 3 - Custom Colors_Font"))?
+        .unwrap();
+    assert_eq!(global, values);
+
+    // cells of a named range
+    let named_range = values.range().unwrap();
+    assert_eq!(named_range.sheet_name, "Sheet 1 - Basic");
+    let worksheet = excel.get_worksheet_with_name(&named_range.sheet_name)?;
+    let cells = worksheet.get_range_with_dimension(named_range.range)?;
+    let texts: Vec<String> = cells.iter().map(|c| c.value.to_string()).collect();
+    assert_eq!(texts[0], "Itsuki1");
+    assert_eq!(texts.len(), 4);
+
+    // several ranges
+    let header = excel.defined_name("Header", None)?.unwrap();
+    assert!(header.range().is_none());
+    let ranges: Vec<Dimension> = header.ranges().into_iter().map(|r| r.range).collect();
+    assert_eq!(ranges.len(), 2);
+    assert_eq!(ranges[1].start, Coordinate::from_point((2, 2)));
+
+    // constants and broken references are not ranges
+    let rate = excel.defined_name("Rate", None)?.unwrap();
+    assert!(rate.hidden);
+    assert_eq!(rate.refers_to, "0.2");
+    assert!(rate.ranges().is_empty());
+    assert!(excel
+        .defined_name("Broken", None)?
+        .unwrap()
+        .ranges()
+        .is_empty());
+
+    println!("defined names read successfully");
+    Ok(())
+}
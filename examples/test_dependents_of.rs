@@ -0,0 +1,111 @@
+mod common;
+
+use common::{Package, SAMPLE, SHEET1, SHEET2, WORKBOOK};
+use excel_reader::prelude::*;
+
+fn formula(a1: &str, formula: &str) -> String {
+    format!(r#"<c r="{}"><f>{}</f><v>0</v></c>"#, a1, formula)
+}
+
+/// Find the formulas reading a range, directly, from other sheets and through defined names.
+fn main() -> anyhow::Result<()> {
+    let first_sheet = format!(
+        r#"<row r="1"><c r="A1"><v>1</v></c><c r="B1"><v>2</v></c>{}{}</row><row r="2">{}{}{}</row><row r="3">{}{}</row>"#,
+        formula("C1", "A1+B1"),
+        formula("D1", r#"LEN("A1")"#),
+        formula("A2", "SUM($A:$A)"),
+        formula("B2", "Total*2"),
+        formula("C2", "SUM(B5:B9)"),
+        formula("A3", "SUM(1:1)"),
+        formula("B3", "'Sheet 2 - Custom grid lines'!A1"),
+    );
+    let second_sheet = format!(
+        r#"<row r="1">{}{}{}{}</row>"#,
+        formula("A1", "'Sheet 1 - Basic'!B1*Rate"),
+        formula("B1", "A1"),
+        formula(
+            "C1",
+            "SUM('Sheet 1 - Basic:Sheet 3 - Custom Colors_Font'!B1)"
+        ),
+        formula("D1", "[1]Sheet1!B1"),
+    );
+
+    let mut excel = Package::open(SAMPLE)?
+        .replace_rows(SHEET1, &first_sheet)
+        .replace_rows(SHEET2, &second_sheet)
+        .replace_in(
+            WORKBOOK,
+            "</sheets>",
+            concat!(
+                "</sheets><definedNames>",
+                r#"<definedName name="Values">'Sheet 1 - Basic'!$A$1:$B$1</definedName>"#,
+                r#"<definedName name="Total">SUM(Values)</definedName>"#,
+                r#"<definedName name="Rate">0.2</definedName>"#,
+                "</definedNames>"
+            ),
+        )
+        .excel()?;
+
+    let dependents = excel.dependents_of("Sheet 1 - Basic", Dimension::parse_a1("B1")?)?;
+    let found: Vec<(String, String, Vec<String>)> = dependents
+        .iter()
+        .map(|d| {
+            (
+                d.sheet_name.clone(),
+                d.formula_cell.coordinate.to_a1(),
+                d.via.clone(),
+            )
+        })
+        .collect();
+    let expected = |sheet: &str, a1: &str, via: &[&str]| {
+        (
+            sheet.to_string(),
+            a1.to_string(),
+            via.iter().map(|v| v.to_string()).collect::<Vec<String>>(),
+        )
+    };
+    assert_eq!(
+        found,
+        vec![
+            expected("Sheet 1 - Basic", "C1", &[]),
+            // through a name referring to another name
+            expected("Sheet 1 - Basic", "B2", &["Total", "Values"]),
+            // whole row
+            expected("Sheet 1 - Basic", "A3", &[]),
+            // cross-sheet and 3D references, not the unqualified `A1` of the second sheet nor the external reference
+            expected("Sheet 2 - Custom grid lines", "A1", &[]),
+            expected("Sheet 2 - Custom grid lines", "C1", &[]),
+        ]
+    );
+    assert_eq!(dependents[0].formula_cell.formula, "A1+B1");
+
+    // whole column, and a range overlapping the end of another
+    let dependents = excel.dependents_of("Sheet 1 - Basic", Dimension::parse_a1("A7:B12")?)?;
+    let cells: Vec<String> = dependents
+        .iter()
+        .map(|d| d.formula_cell.coordinate.to_a1())
+        .collect();
+    assert_eq!(cells, vec!["A2", "C2"]);
+
+    // the cell of the second sheet read from the first sheet
+    let dependents =
+        excel.dependents_of("Sheet 2 - Custom grid lines", Dimension::parse_a1("A1")?)?;
+    let cells: Vec<(String, String)> = dependents
+        .iter()
+        .map(|d| (d.sheet_name.clone(), d.formula_cell.coordinate.to_a1()))
+        .collect();
+    assert_eq!(
+        cells,
+        vec![
+            ("Sheet 1 - Basic".to_string(), "B3".to_string()),
+            ("Sheet 2 - Custom grid lines".to_string(), "B1".to_string()),
+        ]
+    );
+
+    assert!(excel
+        .dependents_of("Missing", Dimension::parse_a1("A1")?)
+        .is_err());
+
+    println!("dependents found successfully");
+    Ok(())
+}
@@ -0,0 +1,140 @@
+mod common;
+
+use chrono::NaiveDateTime;
+use common::{Package, SAMPLE, SHEET1};
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+enum Category {
+    Fruit,
+    Vegetable,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+struct Sale {
+    product: String,
+    category: Category,
+    #[serde(rename = "Unit Price")]
+    unit_price: f64,
+    quantity: Option<u32>,
+    sold_at: NaiveDateTime,
+    organic: bool,
+    note: Option<String>,
+}
+
+fn text(a1: &str, text: &str) -> String {
+    format!(
+        r#"<c r="{}" t="inlineStr"><is><t>{}</t></is></c>"#,
+        a1, text
+    )
+}
+
+/// Deserialize the rows of a sheet into structs, the header row naming the fields.
+fn main() -> anyhow::Result<()> {
+    let mut rows = String::new();
+    // an empty row before the header is skipped
+    rows.push_str(r#"<row r="1"><c r="A1"/></row>"#);
+    rows.push_str(&format!(
+        r#"<row r="2">{}{}{}{}{}{}{}{}</row>"#,
+        text("A2", "product"),
+        text("B2", "category"),
+        text("C2", " Unit Price "),
+        text("D2", "quantity"),
+        text("E2", "sold_at"),
+        text("F2", "organic"),
+        text("G2", "note"),
+        text("H2", "ignored"),
+    ));
+    rows.push_str(&format!(
+        r#"<row r="3">{}{}<c r="C3"><v>1.5</v></c><c r="D3"><v>12</v></c><c r="E3" s="28"><v>45306.5</v></c><c r="F3" t="b"><v>1</v></c>{}</row>"#,
+        text("A3", "apple"),
+        text("B3", "fruit"),
+        text("H3", "x"),
+    ));
+    // numbers stored as text, a formula, a missing quantity, a date as ISO 8601 text
+    rows.push_str(&format!(
+        r#"<row r="5"><c r="A5"><v>42</v></c>{}{}<c r="D5" t="str"><f>""</f><v></v></c>{}{}{}</row>"#,
+        text("B5", "vegetable"),
+        text("C5", "2.25"),
+        text("E5", "2024-01-16T08:30:00"),
+        text("F5", "false"),
+        text("G5", "on sale"),
+    ));
+
+    let mut excel = Package::open(SAMPLE)?
+        .replace_rows(SHEET1, &rows)
+        .append_cell_formats(r#"<xf numFmtId="22" fontId="0" applyNumberFormat="1"/>"#)
+        .excel()?;
+    let sheets = excel.get_sheets()?;
+    let worksheet = excel.get_worksheet_without_cells(&sheets[0])?;
+
+    let mut records = worksheet.deserialize::<Sale, _>(&mut excel)?;
+    assert_eq!(records.header(), None);
+    let apple = records.next().unwrap()?;
+    assert_eq!(
+        records.header(),
+        Some(vec![
+            "product",
+            "category",
+            "Unit Price",
+            "quantity",
+            "sold_at",
+            "organic",
+            "note",
+            "ignored"
+        ])
+    );
+    assert_eq!(
+        apple,
+        Sale {
+            product: "apple".to_string(),
+            category: Category::Fruit,
+            unit_price: 1.5,
+            quantity: Some(12),
+            sold_at: "2024-01-15T12:00:00".parse()?,
+            organic: true,
+            note: None,
+        }
+    );
+    let second = records.next().unwrap()?;
+    assert_eq!(second.product, "42");
+    assert_eq!(second.category, Category::Vegetable);
+    assert_eq!(second.unit_price, 2.25);
+    assert_eq!(second.quantity, None);
+    assert_eq!(second.sold_at, "2024-01-16T08:30:00".parse()?);
+    assert!(!second.organic);
+    assert_eq!(second.note.as_deref(), Some("on sale"));
+    assert!(records.next().is_none());
+    drop(records);
+
+    // tuples take the values in column order
+    let tuples: Vec<(String, String, f64)> = worksheet
+        .deserialize(&mut excel)?
+        .collect::<anyhow::Result<_>>()?;
+    assert_eq!(tuples[0], ("apple".to_string(), "fruit".to_string(), 1.5));
+
+    // maps keep every column, blank cells as null
+    let maps: Vec<std::collections::HashMap<String, serde_json::Value>> = worksheet
+        .deserialize(&mut excel)?
+        .collect::<anyhow::Result<_>>()?;
+    assert_eq!(maps[0]["quantity"], serde_json::json!(12));
+    assert_eq!(maps[0]["sold_at"], serde_json::json!("2024-01-15T12:00:00"));
+    assert_eq!(maps[0]["note"], serde_json::Value::Null);
+
+    // errors name the row
+    #[derive(Debug, Deserialize)]
+    #[allow(dead_code)]
+    struct Strict {
+        quantity: u32,
+    }
+    let error = worksheet
+        .deserialize::<Strict, _>(&mut excel)?
+        .nth(1)
+        .unwrap()
+        .unwrap_err();
+    assert!(error.to_string().starts_with("row 5: "), "{}", error);
+
+    println!("rows deserialized successfully");
+    Ok(())
+}
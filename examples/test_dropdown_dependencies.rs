@@ -0,0 +1,96 @@
+mod common;
+
+use std::io::Cursor;
+
+use common::{Package, SAMPLE, SHEET1};
+use excel_reader::{
+    common_types::{Coordinate, Dimension},
+    excel::Excel,
+    processed::spreadsheet::sheet::worksheet::dropdown_dependency::DropdownFunction,
+};
+
+fn range(a1: (u64, u64), b1: (u64, u64)) -> Dimension {
+    Dimension {
+        start: Coordinate::from_point(a1),
+        end: Coordinate::from_point(b1),
+    }
+}
+
+/// Map cascading dropdowns: a country in column A selects the cities listed in column B, a city in column B selects the districts of column C.
+fn main() -> anyhow::Result<()> {
+    let sheet = r#"<?xml version="1.0" encoding="UTF-8"?><worksheet xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main"><dimension ref="A1:D10"/><sheetData><row r="1"><c r="A1"><v>1</v></c></row></sheetData><dataValidations count="5">
+<dataValidation type="list" allowBlank="1" sqref="A2:A10"><formula1>Countries</formula1></dataValidation>
+<dataValidation type="list" allowBlank="1" sqref="B2:B10"><formula1>INDIRECT(SUBSTITUTE($A2,&quot; &quot;,&quot;_&quot;))</formula1></dataValidation>
+<dataValidation type="list" allowBlank="1" sqref="C2:C5 C8"><formula1>OFFSET(Lists!$C$1,MATCH(B2,Lists!$B:$B,0)-1,1,COUNTIF(Lists!$B:$B,B2),1)</formula1></dataValidation>
+<dataValidation type="list" allowBlank="1" sqref="D2:D10"><formula1>INDIRECT("'Lists'!A1:A3")</formula1></dataValidation>
+<dataValidation type="list" allowBlank="1" sqref="E2:F2"><formula1>INDIRECT($A$1&amp;"_"&amp;E$1)</formula1></dataValidation>
+</dataValidations></worksheet>"#;
+
+    let bytes = Package::open(SAMPLE)?.replace(SHEET1, sheet).bytes()?;
+
+    let mut excel = Excel::from_reader(Cursor::new(bytes))?;
+    let sheets = excel.get_sheets()?;
+    let worksheet = excel.get_worksheet(&sheets[0])?;
+    let dependencies = worksheet.dropdown_dependencies();
+
+    let found: Vec<(Dimension, Dimension, DropdownFunction)> = dependencies
+        .iter()
+        .map(|d| (d.dependent, d.controller, d.function))
+        .collect();
+    assert_eq!(
+        found,
+        vec![
+            // plain named list and constant INDIRECT are not keyed by a cell
+            (
+                range((2, 2), (10, 2)),
+                range((2, 1), (10, 1)),
+                DropdownFunction::Indirect
+            ),
+            (
+                range((2, 3), (5, 3)),
+                range((2, 2), (5, 2)),
+                DropdownFunction::Offset
+            ),
+            (
+                range((8, 3), (8, 3)),
+                range((8, 2), (8, 2)),
+                DropdownFunction::Offset
+            ),
+            (
+                range((2, 5), (2, 6)),
+                range((1, 1), (1, 1)),
+                DropdownFunction::Indirect
+            ),
+            (
+                range((2, 5), (2, 6)),
+                range((1, 5), (1, 6)),
+                DropdownFunction::Indirect
+            ),
+        ]
+    );
+    assert_eq!(
+        dependencies[0].formula,
+        r#"INDIRECT(SUBSTITUTE($A2," ","_"))"#
+    );
+
+    // controller of single dependent cells
+    assert_eq!(
+        dependencies[0].controller_for(Coordinate::from_point((7, 2))),
+        Some(Coordinate::from_point((7, 1)))
+    );
+    assert_eq!(
+        dependencies[3].controller_for(Coordinate::from_point((2, 6))),
+        Some(Coordinate::from_point((1, 1)))
+    );
+    assert_eq!(
+        dependencies[4].controller_for(Coordinate::from_point((2, 6))),
+        Some(Coordinate::from_point((1, 6)))
+    );
+    assert_eq!(
+        dependencies[0].controller_for(Coordinate::from_point((11, 2))),
+        None
+    );
+
+    println!("dropdown dependencies mapped successfully");
+    Ok(())
+}
@@ -0,0 +1,41 @@
+use excel_reader::excel::Excel;
+
+/// Open workbooks encrypted with agile encryption (AES-256, SHA-512) and standard encryption (AES-128),
+/// both holding `sample.xlsx` with the password `excel_reader`.
+fn main() -> anyhow::Result<()> {
+    let mut plain = Excel::from_path("examples/sample.xlsx")?;
+    let expected_sheets = plain.get_sheets()?;
+    let expected_cells = plain.get_worksheet(&expected_sheets[0])?.get_cells()?;
+
+    for path in [
+        "examples/encrypted_agile.xlsx",
+        "examples/encrypted_standard.xlsx",
+    ] {
+        let mut excel = Excel::from_path_with_password(path, "excel_reader")?;
+        let sheets = excel.get_sheets()?;
+        assert_eq!(sheets, expected_sheets);
+        let cells = excel.get_worksheet(&sheets[0])?.get_cells()?;
+        assert_eq!(cells, expected_cells);
+
+        let error = Excel::from_path_with_password(path, "wrong password")
+            .err()
+            .expect("wrong password accepted");
+        assert_eq!(error.to_string(), "The password is incorrect.");
+
+        // opening without a password tells the file is encrypted instead of failing on the zip format
+        let error = Excel::from_path(path).err().expect("encrypted file opened");
+        assert!(error.to_string().contains("from_path_with_password"));
+    }
+
+    // workbooks that are not encrypted are opened as is
+    let mut excel = Excel::from_path_with_password("examples/sample.xlsx", "excel_reader")?;
+    assert_eq!(excel.get_sheets()?, expected_sheets);
+
+    // a FAT sector count larger than the file is an error, not an allocation of the announced size
+    let mut data = std::fs::read("examples/encrypted_agile.xlsx")?;
+    data[0x2C..0x30].copy_from_slice(&[0xFF; 4]);
+    let _ = Excel::from_reader_with_password(std::io::Cursor::new(data), "excel_reader");
+
+    println!("encrypted workbooks read successfully");
+    Ok(())
+}
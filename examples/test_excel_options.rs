@@ -0,0 +1,95 @@
+mod common;
+
+use common::{Package, SAMPLE, SHEET1};
+use excel_reader::prelude::*;
+
+/// Capacity of the rows of the first sheet, reserved from its dimension.
+fn row_capacity(excel: &mut Excel<std::io::Cursor<Vec<u8>>>) -> anyhow::Result<usize> {
+    let sheets = excel.get_sheets()?;
+    let raw = excel.get_raw_worksheet(&sheets[0])?;
+    Ok(raw.sheet_data.unwrap().rows.unwrap().capacity())
+}
+
+/// Buffer sizes and preallocation do not change what is read, and declared counts cannot reserve arbitrary memory.
+fn main() -> anyhow::Result<()> {
+    let mut excel = Excel::from_path(SAMPLE)?;
+    let expected = excel.to_processed_model()?;
+
+    let small_buffers = ExcelOptions {
+        xml_buffer_size: 0,
+        zip_read_buffer_size: 1,
+        capacity_from_counts: false,
+        ..Default::default()
+    };
+    let large_buffers = ExcelOptions {
+        xml_buffer_size: 1 << 20,
+        zip_read_buffer_size: 1 << 20,
+        max_capacity_from_counts: 1 << 20,
+        ..Default::default()
+    };
+    for options in [small_buffers, large_buffers] {
+        let mut excel = Excel::from_path_with_options(SAMPLE, options)?;
+        assert_eq!(excel.to_processed_model()?, expected);
+    }
+
+    // bogus counts: the whole grid as dimension, every column as spans and billions of shared strings
+    let package = Package::open(SAMPLE)?
+        .replace_in(
+            SHEET1,
+            r#"<dimension ref="A2:B9"/>"#,
+            r#"<dimension ref="A1:XFD1048576"/>"#,
+        )
+        .edit(SHEET1, |text| {
+            text.replace(" ht=", r#" spans="1:16384" ht="#)
+        })
+        .replace_in(
+            "xl/sharedStrings.xml",
+            r#"uniqueCount="20""#,
+            r#"uniqueCount="18446744073709551615""#,
+        );
+    let bytes = package.bytes()?;
+
+    let mut excel = Excel::from_bytes(bytes.clone())?;
+    let capacity = row_capacity(&mut excel)?;
+    assert!((16_384..1_048_576).contains(&capacity));
+    let sheets = excel.get_sheets()?;
+    let raw = excel.get_raw_worksheet(&sheets[0])?;
+    let rows = raw.sheet_data.unwrap().rows.unwrap();
+    assert!(rows[0].cells.as_ref().unwrap().capacity() >= 16_384);
+    assert!(excel.get_raw_shared_strings()?.is_some());
+
+    let options = ExcelOptions {
+        max_capacity_from_counts: 100,
+        ..Default::default()
+    };
+    let mut excel = Excel::from_bytes_with_options(bytes.clone(), options)?;
+    assert!((100..16_384).contains(&row_capacity(&mut excel)?));
+
+    let options = ExcelOptions {
+        capacity_from_counts: false,
+        ..Default::default()
+    };
+    let mut excel = Excel::from_bytes_with_options(bytes.clone(), options)?;
+    assert!(row_capacity(&mut excel)? < 100);
+
+    // the values are the same whatever is reserved
+    for options in [options, ExcelOptions::default()] {
+        let mut excel = Excel::from_bytes_with_options(bytes.clone(), options)?;
+        let sheets = excel.get_sheets()?;
+        let raw = excel.get_raw_worksheet(&sheets[0])?;
+        assert_eq!(raw.sheet_data.unwrap().rows.unwrap().len(), 9);
+        // cells of the first rows, the dimension spans the whole grid
+        let worksheet = excel.get_worksheet(&sheets[0])?;
+        let range = worksheet.get_range_with_dimension(Dimension::parse_a1("A1:B9")?)?;
+        let expected_range = Excel::from_path(SAMPLE)?
+            .get_worksheet(&sheets[0])?
+            .get_range_with_dimension(Dimension::parse_a1("A1:B9")?)?;
+        assert_eq!(
+            range.iter().map(|c| &c.value).collect::<Vec<_>>(),
+            expected_range.iter().map(|c| &c.value).collect::<Vec<_>>()
+        );
+    }
+
+    println!("options applied successfully");
+    Ok(())
+}
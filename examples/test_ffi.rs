@@ -0,0 +1,103 @@
+use std::{
+    ffi::{CStr, CString},
+    ptr,
+};
+
+use excel_reader::ffi::*;
+
+unsafe fn take_string(string: *mut std::ffi::c_char) -> String {
+    assert!(!string.is_null());
+    let text = CStr::from_ptr(string).to_str().unwrap().to_string();
+    excel_reader_string_free(string);
+    text
+}
+
+/// Read the cells of a sheet, in a process of its own to check what is written to stdout.
+unsafe fn read_cells() -> anyhow::Result<()> {
+    let path = CString::new("examples/sample.xlsx")?;
+    let name = CString::new("Sheet 1 - Basic")?;
+    let workbook = excel_reader_open(path.as_ptr());
+    take_string(excel_reader_sheet_rows(workbook, name.as_ptr()));
+    let cells = excel_reader_cells(workbook, name.as_ptr());
+    assert!(!cells.is_null());
+    excel_reader_cells_free(cells);
+    excel_reader_close(workbook);
+    Ok(())
+}
+
+/// Read a workbook through the C bindings.
+fn main() -> anyhow::Result<()> {
+    if std::env::args().nth(1).as_deref() == Some("read-cells") {
+        return unsafe { read_cells() };
+    }
+    // the stdout of the host program is left alone
+    let output = std::process::Command::new(std::env::current_exe()?)
+        .arg("read-cells")
+        .output()?;
+    assert!(output.status.success());
+    assert_eq!(String::from_utf8(output.stdout)?, "");
+
+    unsafe {
+        let missing = CString::new("examples/missing.xlsx")?;
+        assert!(excel_reader_open(missing.as_ptr()).is_null());
+        assert!(!excel_reader_last_error().is_null());
+        assert!(excel_reader_open(ptr::null()).is_null());
+        assert_eq!(
+            CStr::from_ptr(excel_reader_last_error()).to_str()?,
+            "string is NULL."
+        );
+
+        let path = CString::new("examples/sample.xlsx")?;
+        let workbook = excel_reader_open(path.as_ptr());
+        assert!(!workbook.is_null());
+        let names = take_string(excel_reader_sheet_names(workbook));
+        assert_eq!(
+            names,
+            r#"["Sheet 1 - Basic","Sheet 2 - Custom grid lines","Sheet 3 - Custom Colors_Font"]"#
+        );
+
+        let name = CString::new("Sheet 1 - Basic")?;
+        let rows: Vec<Vec<String>> = serde_json::from_str(&take_string(excel_reader_sheet_rows(
+            workbook,
+            name.as_ptr(),
+        )))?;
+        assert_eq!(rows[0], vec!["Basic", ""]);
+        assert_eq!(rows[1], vec!["index", "value"]);
+
+        let cells = excel_reader_cells(workbook, name.as_ptr());
+        assert!(!cells.is_null());
+        let mut cell = ExcelReaderCell {
+            row: 0,
+            column: 0,
+            value: ptr::null(),
+            json: ptr::null(),
+        };
+        let mut found: Vec<(u64, u64, String)> = vec![];
+        while excel_reader_cells_next(cells, &mut cell) {
+            let value = CStr::from_ptr(cell.value).to_str()?.to_string();
+            let json: serde_json::Value =
+                serde_json::from_str(CStr::from_ptr(cell.json).to_str()?)?;
+            assert_eq!(json["coordinate"]["row"], cell.row);
+            found.push((cell.row, cell.column, value));
+        }
+        excel_reader_cells_free(cells);
+        assert_eq!(found[0], (1, 1, "Basic".to_string()));
+        assert_eq!(found[1], (2, 1, "index".to_string()));
+        // empty cells are skipped
+        assert!(found.iter().all(|(_, _, value)| !value.is_empty()));
+
+        let unknown = CString::new("Unknown")?;
+        assert!(excel_reader_cells(workbook, unknown.as_ptr()).is_null());
+        excel_reader_close(workbook);
+
+        let bytes = std::fs::read("examples/sample.xlsx")?;
+        let workbook = excel_reader_open_bytes(bytes.as_ptr(), bytes.len());
+        assert!(!workbook.is_null());
+        assert_eq!(take_string(excel_reader_sheet_names(workbook)), names);
+        excel_reader_close(workbook);
+        assert!(excel_reader_open_bytes(bytes.as_ptr(), 10).is_null());
+    }
+
+    println!("ffi bindings used successfully");
+    Ok(())
+}
@@ -0,0 +1,74 @@
+use excel_reader::{
+    excel::Excel,
+    font_metrics::{estimate_column_width, estimate_max_digit_width, estimate_text_width},
+    units::column_width_to_px,
+};
+
+/// Estimate text and column widths from the embedded font metrics.
+fn main() -> anyhow::Result<()> {
+    let mut excel = Excel::from_path("examples/sample.xlsx")?;
+
+    // Helvetica Neue 10 pt, measured with the metrics of Arial
+    let font = excel.get_default_font()?;
+    assert_eq!(estimate_max_digit_width(&font), 7.0);
+    assert!((estimate_text_width("Hello", &font) - 22.78).abs() < 1e-9);
+    assert_eq!(estimate_text_width("", &font), 0.0);
+
+    // longest line of a multiline text
+    assert_eq!(
+        estimate_text_width("Hi\nHello", &font),
+        estimate_text_width("Hello", &font)
+    );
+
+    // wide characters take a full em, combining marks take no space
+    assert!((estimate_text_width("日本", &font) - 20.0).abs() < 1e-9);
+    assert_eq!(
+        estimate_text_width("e\u{301}", &font),
+        estimate_text_width("e", &font)
+    );
+
+    let mut bold = font.clone();
+    bold.bold = true;
+    assert!(estimate_text_width("Hello", &bold) > estimate_text_width("Hello", &font));
+
+    // Calibri 11 pt, the default font of recent workbooks
+    let mut calibri = font.clone();
+    calibri.name = "Calibri".to_string();
+    calibri.size = 11.0;
+    assert_eq!(estimate_max_digit_width(&calibri), 7.0);
+    assert!(estimate_text_width("Hello", &calibri) < estimate_text_width("Hello", &font) * 1.1);
+
+    // unknown fonts use the metrics of Calibri
+    let mut unknown = calibri.clone();
+    unknown.name = "Some Font".to_string();
+    assert_eq!(
+        estimate_text_width("Hello", &unknown),
+        estimate_text_width("Hello", &calibri)
+    );
+
+    // column fitting the longest text, including the padding
+    let width = estimate_column_width(["Hi", "Hello"], &font);
+    assert_eq!(column_width_to_px(width, 7.0), 36.0);
+    println!("column width: {}", width);
+
+    #[cfg(feature = "fonts")]
+    {
+        use excel_reader::font_metrics::FontFile;
+
+        assert!(FontFile::from_bytes(vec![0; 16], 0).is_err());
+
+        let path = "/usr/share/fonts/truetype/dejavu/DejaVuSans.ttf";
+        if std::path::Path::new(path).exists() {
+            let font_file = FontFile::from_path(path)?;
+            assert_eq!(font_file.family_name().as_deref(), Some("DejaVu Sans"));
+            assert!(font_file.text_width("Hello", 10.0) > 0.0);
+            assert_eq!(
+                font_file.text_width("Hi\nHello", 10.0),
+                font_file.text_width("Hello", 10.0)
+            );
+            println!("max digit width: {}", font_file.max_digit_width(11.0));
+        }
+    }
+
+    return Ok(());
+}
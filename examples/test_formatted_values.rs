@@ -0,0 +1,129 @@
+mod common;
+
+use std::io::Cursor;
+
+use common::{Package, SAMPLE, SHEET1};
+use excel_reader::{
+    common_types::Coordinate, excel::Excel,
+    processed::spreadsheet::sheet::worksheet::cell::cell_property::numbering_format::NumberingFormat,
+};
+
+fn format(code: &str, value: f64) -> String {
+    let format = NumberingFormat {
+        format_code: Some(code.to_string()),
+        format_id: 164,
+    };
+    format.format_number(value, false)
+}
+
+/// Display cell values the way Excel does, applying their number format.
+fn main() -> anyhow::Result<()> {
+    // general
+    assert_eq!(format("General", 1234.5), "1234.5");
+    assert_eq!(format("General", 1.0 / 3.0), "0.333333333");
+    assert_eq!(format("General", -2.0), "-2");
+    assert_eq!(format("General", 123456789012.0), "1.23457E+11");
+    assert_eq!(format("General", 0.00001), "1E-05");
+
+    // digits, rounding half away from zero on the decimal value
+    assert_eq!(format("0.00", 1.005), "1.01");
+    assert_eq!(format("0", 2.5), "3");
+    assert_eq!(format("0", -0.5), "-1");
+    assert_eq!(format("#.##", 0.5), ".5");
+    assert_eq!(format("0.0#", 1.5), "1.5");
+    assert_eq!(format("00000", 123.0), "00123");
+    assert_eq!(format("000-0000", 5551234.0), "555-1234");
+
+    // thousands separators and scaling
+    assert_eq!(format("#,##0.00", 1234567.891), "1,234,567.89");
+    assert_eq!(format("#,##0", 999.5), "1,000");
+    assert_eq!(format("#,##0.00", 0.0), "0.00");
+    assert_eq!(format("0.0,,\"M\"", 1234567.0), "1.2M");
+
+    // percentages and scientific notation
+    assert_eq!(format("0.0%", 0.256), "25.6%");
+    assert_eq!(format("0.00E+00", 12345.0), "1.23E+04");
+    assert_eq!(format("0.00E+00", 0.000123), "1.23E-04");
+    assert_eq!(format("##0.0E+0", 12345.0), "12.3E+3");
+
+    // fractions
+    assert_eq!(format("# ?/?", 1.5), "1 1/2");
+    assert_eq!(format("# ?/?", 2.0), "2    ");
+    assert_eq!(format("?/8", 0.3), "2/8");
+
+    // currencies and sections
+    assert_eq!(format("[$€-407] #,##0.00", 1234.5), "€ 1,234.50");
+    assert_eq!(format("$#,##0.00;[Red]($#,##0.00)", -1234.5), "($1,234.50)");
+    let accounting = r#"_("$"* #,##0.00_);_("$"* \(#,##0.00\);_("$"* "-"??_);_(@_)"#;
+    assert_eq!(format(accounting, 1234.5), " $1,234.50 ");
+    assert_eq!(format(accounting, -1234.5), " $(1,234.50)");
+    assert_eq!(format(accounting, 0.0), " $-   ");
+    assert_eq!(format("0;-0;;@", 0.0), "");
+
+    // conditions
+    let abbreviated = r#"[>=1000000]0.0,,"M";[>=1000]0.0,"K";0"#;
+    assert_eq!(format(abbreviated, 1234567.0), "1.2M");
+    assert_eq!(format(abbreviated, 12345.0), "12.3K");
+    assert_eq!(format(abbreviated, 12.0), "12");
+
+    // dates and times
+    assert_eq!(format("yyyy-mm-dd", 45306.0), "2024-01-15");
+    assert_eq!(format("d-mmm-yy", 45306.0), "15-Jan-24");
+    assert_eq!(
+        format("dddd, mmmm d, yyyy", 45306.0),
+        "Monday, January 15, 2024"
+    );
+    assert_eq!(format("m/d/yy h:mm", 45306.5), "1/15/24 12:00");
+    assert_eq!(format("h:mm AM/PM", 45306.75), "6:00 PM");
+    assert_eq!(format("hh:mm:ss", 0.5), "12:00:00");
+    assert_eq!(format("[h]:mm:ss", 1.5), "36:00:00");
+    assert_eq!(format("mm:ss.0", 1.04 / 86400.0), "00:01.0");
+    assert_eq!(format("yyyy-mm-dd", 60.0), "1900-02-29");
+    assert_eq!(format("yyyy-mm-dd", -1.0), "-1");
+    let date = NumberingFormat {
+        format_code: Some("yyyy-mm-dd".to_string()),
+        format_id: 164,
+    };
+    assert_eq!(date.format_number(0.0, true), "1904-01-01");
+
+    // text
+    let text = NumberingFormat {
+        format_code: Some(r#""Name: "@"#.to_string()),
+        format_id: 164,
+    };
+    assert_eq!(text.format_text("abc"), "Name: abc");
+    assert_eq!(text.format_number(1.5, false), "1.5");
+
+    // cells of a workbook: percentage, built-in date, thousands, boolean formula and text
+    let bytes = Package::open(SAMPLE)?
+        .append_rows(
+            SHEET1,
+            concat!(
+                r#"<row r="10"><c r="D10" s="28"><v>0.256</v></c><c r="E10" s="29"><v>45306</v></c><c r="F10" s="30"><v>1234567.891</v></c></row>"#,
+                r#"<row r="11"><c r="D11" t="b"><f>1=1</f><v>1</v></c><c r="E11" s="30"><f>E10*2</f><v>90612</v></c><c r="F11" t="inlineStr" s="30"><is><t>n/a</t></is></c></row>"#,
+            ),
+        )
+        .append_cell_formats(concat!(
+            r#"<xf numFmtId="10" fontId="0" applyNumberFormat="1"/>"#,
+            r#"<xf numFmtId="14" fontId="0" applyNumberFormat="1"/>"#,
+            r#"<xf numFmtId="4" fontId="0" applyNumberFormat="1"/>"#,
+        ))
+        .bytes()?;
+
+    let mut excel = Excel::from_reader(Cursor::new(bytes))?;
+    let worksheet = excel.get_worksheet_with_name("Sheet 1 - Basic")?;
+    let formatted = |a1: &str| -> anyhow::Result<String> {
+        let cell = worksheet.get_cell(Coordinate::from_a1(a1.as_bytes()).unwrap())?;
+        Ok(cell.formatted_value(worksheet.is_1904))
+    };
+    assert_eq!(formatted("D10")?, "25.60%");
+    assert_eq!(formatted("E10")?, "01-15-24");
+    assert_eq!(formatted("F10")?, "1,234,567.89");
+    assert_eq!(formatted("D11")?, "TRUE");
+    assert_eq!(formatted("E11")?, "90,612.00");
+    assert_eq!(formatted("F11")?, "n/a");
+    assert_eq!(formatted("B3")?, "Itsuki1");
+
+    println!("formatted values displayed successfully");
+    Ok(())
+}
@@ -0,0 +1,95 @@
+mod common;
+
+use std::io::Cursor;
+
+use common::{Package, SAMPLE, SHEET1};
+use excel_reader::{
+    common_types::{Coordinate, Dimension},
+    excel::Excel,
+    processed::spreadsheet::sheet::worksheet::formula_cell::FormulaKind,
+};
+
+/// List the formulas of a sheet with a shared formula, an array formula and single cell formulas.
+fn main() -> anyhow::Result<()> {
+    let sheet = r#"<?xml version="1.0" encoding="UTF-8"?><worksheet xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main"><dimension ref="A1:D4"/><sheetData>
+<row r="1"><c r="A1"><v>1</v></c><c r="B1"><f t="shared" ref="B1:B3" si="0">A1*2</f><v>2</v></c><c r="C1" t="str"><f>"total"</f><v>total</v></c></row>
+<row r="2"><c r="A2"><v>2</v></c><c r="B2"><f t="shared" si="0"/><v>4</v></c><c r="C2" s="5"><f>SUM(B1:B3)</f><v>12</v></c></row>
+<row r="3"><c r="A3"><v>3</v></c><c r="B3"><f t="shared" si="0"/><v>6</v></c></row>
+<row r="4"><c r="A4"><f t="array" ref="A4:B4">A1:B1*10</f><v>10</v></c><c r="B4"><v>20</v></c></row>
+</sheetData></worksheet>"#;
+
+    let bytes = Package::open(SAMPLE)?.replace(SHEET1, sheet).bytes()?;
+
+    let mut excel = Excel::from_reader(Cursor::new(bytes))?;
+    let worksheet = excel.get_worksheet_with_name("Sheet 1 - Basic")?;
+
+    let cells = worksheet.formula_cells();
+    let listed: Vec<(String, String, Option<String>)> = cells
+        .iter()
+        .map(|c| {
+            (
+                c.coordinate.to_a1(),
+                c.formula.clone(),
+                c.cached_value.clone(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        listed,
+        vec![
+            ("B1".into(), "A1*2".into(), Some("2".into())),
+            ("C1".into(), "\"total\"".into(), Some("total".into())),
+            ("B2".into(), "A2*2".into(), Some("4".into())),
+            ("C2".into(), "SUM(B1:B3)".into(), Some("12".into())),
+            ("B3".into(), "A3*2".into(), Some("6".into())),
+            ("A4".into(), "A1:B1*10".into(), Some("10".into())),
+        ]
+    );
+    assert_eq!(cells[0].kind, FormulaKind::Shared(0));
+    assert_eq!(cells[1].kind, FormulaKind::Normal);
+    assert_eq!(cells[5].kind, FormulaKind::Array);
+    assert_eq!(
+        cells[5].range,
+        Some(Dimension {
+            start: Coordinate::from_point((4, 1)),
+            end: Coordinate::from_point((4, 2)),
+        })
+    );
+    assert_eq!(cells[3].numbering_format.format_id, 49);
+    assert_eq!(cells[3].numbering_format.format_code.as_deref(), Some("@"));
+
+    // shared formula cells grouped together, ordered by their master cell
+    let families = worksheet.formula_families();
+    let grouped: Vec<(FormulaKind, Vec<String>)> = families
+        .iter()
+        .map(|f| {
+            (
+                f.kind.clone(),
+                f.cells.iter().map(|c| c.coordinate.to_a1()).collect(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        grouped,
+        vec![
+            (
+                FormulaKind::Shared(0),
+                vec!["B1".into(), "B2".into(), "B3".into()]
+            ),
+            (FormulaKind::Normal, vec!["C1".into()]),
+            (FormulaKind::Normal, vec!["C2".into()]),
+            (FormulaKind::Array, vec!["A4".into()]),
+        ]
+    );
+    assert_eq!(families[0].formula, "A1*2");
+    assert_eq!(
+        families[0].range,
+        Some(Dimension {
+            start: Coordinate::from_point((1, 2)),
+            end: Coordinate::from_point((3, 2)),
+        })
+    );
+
+    println!("formula cells listed successfully");
+    Ok(())
+}
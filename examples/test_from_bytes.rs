@@ -0,0 +1,31 @@
+use std::io::Cursor;
+
+use excel_reader::prelude::*;
+
+/// Open workbooks from bytes in memory and from generic readers, without a file.
+fn main() -> anyhow::Result<()> {
+    let bytes = std::fs::read("examples/sample.xlsx")?;
+    let mut from_path = Excel::from_path("examples/sample.xlsx")?;
+    let expected = from_path.to_processed_model()?;
+
+    let mut from_bytes = Excel::from_bytes(bytes.clone())?;
+    assert_eq!(from_bytes.get_sheets()?, from_path.get_sheets()?);
+    assert_eq!(from_bytes.to_processed_model()?, expected);
+
+    // borrowed bytes
+    let mut from_slice = Excel::from_reader(Cursor::new(bytes.as_slice()))?;
+    assert_eq!(from_slice.to_processed_model()?, expected);
+
+    let options = ExcelOptions {
+        lazy_shared_strings: true,
+        ..Default::default()
+    };
+    let mut with_options = Excel::from_bytes_with_options(bytes.clone(), options)?;
+    assert_eq!(with_options.to_processed_model()?, expected);
+
+    assert!(Excel::from_bytes(b"not a workbook".to_vec()).is_err());
+    assert!(Excel::from_bytes(vec![]).is_err());
+
+    println!("workbook read from bytes successfully");
+    Ok(())
+}
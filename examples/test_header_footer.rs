@@ -0,0 +1,98 @@
+mod common;
+
+use std::io::Cursor;
+
+use common::{Package, SAMPLE, SHEET1};
+use excel_reader::{
+    excel::Excel,
+    processed::spreadsheet::sheet::worksheet::header_footer::{
+        HeaderFooterText, HeaderFooterToken, HeaderFooterValues,
+    },
+};
+
+/// Decode the headers and footers of a sheet with a different first page.
+fn main() -> anyhow::Result<()> {
+    let sheet = r#"<?xml version="1.0" encoding="UTF-8"?><worksheet xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main"><dimension ref="A1"/><sheetData>
+<row r="1"><c r="A1"><v>1</v></c></row>
+</sheetData><headerFooter differentFirst="1"><oddHeader>&amp;L&amp;"Arial,Bold"&amp;14&amp;KFF0000R&amp;&amp;D report&amp;C&amp;A&amp;R&amp;D &amp;T</oddHeader><oddFooter>Page &amp;P of &amp;N</oddFooter><firstHeader>&amp;C&amp;BConfidential&amp;B</firstHeader></headerFooter></worksheet>"#;
+
+    let bytes = Package::open(SAMPLE)?.replace(SHEET1, sheet).bytes()?;
+
+    let mut excel = Excel::from_reader(Cursor::new(bytes))?;
+    let sheets = excel.get_sheets()?;
+    let worksheet = excel.get_worksheet_without_cells(&sheets[0])?;
+    let header_footer = worksheet.header_footer().unwrap();
+    assert!(header_footer.different_first);
+    assert!(!header_footer.different_odd_even);
+
+    let header = header_footer.odd_header.as_ref().unwrap();
+    assert_eq!(
+        header.left,
+        vec![
+            HeaderFooterToken::Font {
+                name: "Arial".to_string(),
+                style: "Bold".to_string()
+            },
+            HeaderFooterToken::FontSize(14),
+            HeaderFooterToken::Color("FF0000".to_string()),
+            HeaderFooterToken::Text("R&D report".to_string()),
+        ]
+    );
+    assert_eq!(header.center, vec![HeaderFooterToken::SheetName]);
+    assert_eq!(
+        header.right,
+        vec![
+            HeaderFooterToken::Date,
+            HeaderFooterToken::Text(" ".to_string()),
+            HeaderFooterToken::Time
+        ]
+    );
+
+    // text without section code is centered
+    let footer = header_footer.odd_footer.as_ref().unwrap();
+    assert!(footer.left.is_empty() && footer.right.is_empty());
+    let values = HeaderFooterValues {
+        page_number: 2,
+        page_count: 5,
+        sheet_name: "Sheet1".to_string(),
+        date: "2024-01-31".to_string(),
+        time: "09:30".to_string(),
+        ..Default::default()
+    };
+    assert_eq!(
+        footer.render(&values),
+        (String::new(), "Page 2 of 5".to_string(), String::new())
+    );
+    assert_eq!(
+        header.render(&values),
+        (
+            "R&D report".to_string(),
+            "Sheet1".to_string(),
+            "2024-01-31 09:30".to_string()
+        )
+    );
+
+    // first page has its own header, and no footer
+    let first = header_footer.header_for_page(1).unwrap();
+    assert_eq!(
+        first.center,
+        vec![
+            HeaderFooterToken::Bold,
+            HeaderFooterToken::Text("Confidential".to_string()),
+            HeaderFooterToken::Bold
+        ]
+    );
+    assert!(header_footer.footer_for_page(1).is_none());
+    assert_eq!(header_footer.header_for_page(2), Some(header));
+    assert_eq!(header_footer.footer_for_page(3), Some(footer));
+
+    // unknown codes dropped, trailing ampersand ignored
+    let decoded = HeaderFooterText::decode("&Qa&&b&");
+    assert_eq!(
+        decoded.center,
+        vec![HeaderFooterToken::Text("a&b".to_string())]
+    );
+
+    println!("headers and footers decoded successfully");
+    Ok(())
+}
@@ -0,0 +1,75 @@
+mod common;
+
+use common::{Package, SAMPLE, SHEET1, STYLES, WORKBOOK};
+use excel_reader::prelude::*;
+
+fn text(a1: &str, style: u64, text: &str) -> String {
+    format!(
+        r#"<c r="{}" s="{}" t="inlineStr"><is><t>{}</t></is></c>"#,
+        a1, style, text
+    )
+}
+
+/// Report hidden sheets, rows, columns and text written in the color of its background.
+fn main() -> anyhow::Result<()> {
+    let mut excel = Excel::from_path("examples/sample.xlsx")?;
+    assert!(excel.hidden_data_report()?.is_empty());
+
+    // 28: white text, 29: black text on white, 30: white text on white, 31: white text on another color
+    let rows = format!(
+        r#"<row r="1">{}{}{}{}<c r="E1" s="28"/>{}</row><row r="2" hidden="1"><c r="A2"><v>1</v></c></row><row r="3" hidden="1"/><row r="4" ht="0" customHeight="1"/><row r="6" hidden="1"/>"#,
+        text("A1", 28, "secret"),
+        text("B1", 29, "visible"),
+        text("C1", 30, "also secret"),
+        text("D1", 31, "visible"),
+        text("F1", 28, " "),
+    );
+
+    let mut excel = Package::open(SAMPLE)?
+        .replace_rows(SHEET1, &rows)
+        .replace_in(SHEET1, r#"<col min="3" max="16384" width="16.3516" style="1" customWidth="1"/>"#, r#"<col min="3" max="3" width="0" customWidth="1"/><col min="4" max="4" width="16.3516" customWidth="1"/><col min="5" max="16384" width="16.3516" hidden="1" customWidth="1"/>"#)
+        .replace_in(WORKBOOK, r#"sheetId="3""#, r#"sheetId="3" state="veryHidden""#)
+        .replace_in(STYLES, "</fonts>", r#"<font><sz val="10"/><color rgb="FFFFFFFF"/><name val="Helvetica Neue"/></font></fonts>"#)
+        .replace_in(STYLES, "</fills>", r#"<fill><patternFill patternType="solid"><fgColor rgb="FFFFFFFF"/><bgColor auto="1"/></patternFill></fill></fills>"#)
+        .append_cell_formats(concat!(
+            r#"<xf numFmtId="0" fontId="6" fillId="0" borderId="0" applyFont="1"/>"#,
+            r#"<xf numFmtId="0" fontId="0" fillId="6" borderId="0" applyFill="1"/>"#,
+            r#"<xf numFmtId="0" fontId="6" fillId="6" borderId="0" applyFont="1" applyFill="1"/>"#,
+            r#"<xf numFmtId="0" fontId="6" fillId="3" borderId="0" applyFont="1" applyFill="1"/>"#,
+        ))
+        .excel()?;
+
+    let report = excel.hidden_data_report()?;
+    assert!(!report.is_empty());
+    let hidden_sheets: Vec<(&str, &SheetVisibleState)> = report
+        .hidden_sheets
+        .iter()
+        .map(|s| (s.name.as_str(), &s.visible_state))
+        .collect();
+    assert_eq!(
+        hidden_sheets,
+        vec![(
+            "Sheet 3 - Custom Colors_Font",
+            &SheetVisibleState::VeryHidden
+        )]
+    );
+
+    assert_eq!(report.worksheets.len(), 1);
+    let sheet = &report.worksheets[0];
+    assert_eq!(sheet.sheet_name, "Sheet 1 - Basic");
+    let a1 = |ranges: &[Dimension]| ranges.iter().map(|r| r.to_a1()).collect::<Vec<String>>();
+    assert_eq!(a1(&sheet.hidden_rows), vec!["2:3", "6:6"]);
+    assert_eq!(a1(&sheet.zero_height_rows), vec!["4:4"]);
+    assert_eq!(a1(&sheet.hidden_columns), vec!["E:XFD"]);
+    assert_eq!(a1(&sheet.zero_width_columns), vec!["C:C"]);
+    // blank cells are not reported
+    let cells: Vec<String> = sheet
+        .invisible_text_cells
+        .iter()
+        .map(|c| c.to_a1())
+        .collect();
+    assert_eq!(cells, vec!["A1", "C1"]);
+
+    println!("hidden data reported successfully");
+    Ok(())
+}
@@ -0,0 +1,92 @@
+mod common;
+
+use common::{Package, SAMPLE, SHEET1};
+use excel_reader::{prelude::*, processed::spreadsheet::sheet_reference::quote_sheet_name};
+
+/// Link targets of hyperlinks and `HYPERLINK()` formulas, with sheet names quoted as Excel does.
+fn main() -> anyhow::Result<()> {
+    // names quoted when they are not identifiers or read as cell references, quotes doubled
+    for (name, quoted) in [
+        ("Sheet1", "Sheet1"),
+        ("Données_2024", "Données_2024"),
+        ("Rock", "Rock"),
+        ("XFE1", "XFE1"),
+        ("Sheet 1", "'Sheet 1'"),
+        ("Q1-Q2", "'Q1-Q2'"),
+        ("2024", "'2024'"),
+        ("Bob's", "'Bob''s'"),
+        ("A1", "'A1'"),
+        ("xfd1048576", "'xfd1048576'"),
+        ("R", "'R'"),
+        ("C", "'C'"),
+        ("rc", "'rc'"),
+        ("R1C1", "'R1C1'"),
+        ("C12", "'C12'"),
+        ("", "''"),
+    ] {
+        assert_eq!(quote_sheet_name(name), quoted, "{}", name);
+    }
+
+    let mut excel = Package::open(SAMPLE)?
+        .append_rows(
+            SHEET1,
+            concat!(
+                r##"<row r="10"><c r="A10" t="str"><f>HYPERLINK("#'Bob''s'!A1", "go")</f><v>go</v></c>"##,
+                r#"<c r="B10" t="str"><f>hyperlink( "https://example.com/?q=""x""" )</f><v>x</v></c>"#,
+                r#"<c r="C10" t="str"><f>HYPERLINK(A1)</f><v>Basic</v></c></row>"#,
+            ),
+        )
+        .replace_in(
+            SHEET1,
+            "</hyperlinks>",
+            r#"<hyperlink ref="A9" location="'Bob''s'!B2:C3" display="quoted"/><hyperlink ref="A5" location="R1C1!A1" display="reference"/></hyperlinks>"#,
+        )
+        .excel()?;
+    let sheets = excel.get_sheets()?;
+    let worksheet = excel.get_worksheet(&sheets[0])?;
+    let cell = |a1: &str| worksheet.get_cell(Coordinate::from_a1(a1.as_bytes()).unwrap());
+
+    // hyperlinks of the cells
+    assert_eq!(
+        cell("B4")?.hyperlink_target().as_deref(),
+        Some("'Sheet 2 - Custom grid lines'!A2")
+    );
+    assert_eq!(
+        cell("B5")?.hyperlink_target().as_deref(),
+        Some("http://www.google.com")
+    );
+    assert_eq!(
+        cell("A9")?.hyperlink_target().as_deref(),
+        Some("'Bob''s'!B2:C3")
+    );
+    assert_eq!(cell("A5")?.hyperlink_target().as_deref(), Some("'R1C1'!A1"));
+    assert_eq!(cell("A3")?.hyperlink_target(), None);
+
+    // literal locations of formulas, quotes of the string literal undoubled
+    assert_eq!(
+        cell("A10")?.hyperlink_target().as_deref(),
+        Some("#'Bob''s'!A1")
+    );
+    assert_eq!(
+        cell("B10")?.hyperlink_target().as_deref(),
+        Some(r#"https://example.com/?q="x""#)
+    );
+    assert_eq!(cell("C10")?.hyperlink_target(), None);
+
+    // text with the target
+    assert_eq!(
+        cell("B5")?.display_text(HyperlinkTextMode::Append),
+        "hlink_outside (http://www.google.com)"
+    );
+    assert_eq!(
+        cell("B5")?.display_text(HyperlinkTextMode::Substitute),
+        "http://www.google.com"
+    );
+    assert_eq!(
+        cell("B5")?.display_text(HyperlinkTextMode::Ignore),
+        "hlink_outside"
+    );
+
+    println!("hyperlink targets read successfully");
+    Ok(())
+}
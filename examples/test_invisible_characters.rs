@@ -0,0 +1,64 @@
+mod common;
+
+use common::{Package, SAMPLE, SHEET1};
+use excel_reader::{
+    prelude::*, processed::spreadsheet::sheet::worksheet::invisible_character::InvisibleCharacter,
+};
+
+/// Report non-breaking spaces, zero width characters and stray whitespace in text cells.
+fn main() -> anyhow::Result<()> {
+    // the text of the sample is clean, line breaks within the text included
+    let mut excel = Excel::from_path(SAMPLE)?;
+    let sheets = excel.get_sheets()?;
+    assert_eq!(
+        excel
+            .get_worksheet(&sheets[0])?
+            .find_invisible_characters()?,
+        vec![]
+    );
+
+    let mut excel = Package::open(SAMPLE)?
+        .append_rows(
+            SHEET1,
+            concat!(
+                "<row r=\"10\"><c r=\"A10\" t=\"inlineStr\"><is><t>10\u{00A0}000</t></is></c>",
+                "<c r=\"B10\" t=\"inlineStr\"><is><t xml:space=\"preserve\"> padded </t></is></c>",
+                "<c r=\"C10\" t=\"inlineStr\"><is><t>\u{FEFF}id\u{200B}</t></is></c>",
+                "<c r=\"D10\"><v>10</v></c></row>",
+            ),
+        )
+        .excel()?;
+    let worksheet = excel.get_worksheet(&sheets[0])?;
+    let found = worksheet.find_invisible_characters()?;
+    assert_eq!(found.len(), 3);
+
+    assert_eq!(found[0].coordinate.to_a1(), "A10");
+    assert_eq!(found[0].text, "10\u{00A0}000");
+    assert_eq!(
+        found[0].characters,
+        vec![InvisibleCharacter {
+            code_point: 0xA0,
+            position: 2
+        }]
+    );
+    assert!(!found[0].leading_whitespace && !found[0].trailing_whitespace);
+
+    assert_eq!(found[1].coordinate.to_a1(), "B10");
+    assert!(found[1].characters.is_empty());
+    assert!(found[1].leading_whitespace && found[1].trailing_whitespace);
+
+    // positions are in characters, not bytes
+    assert_eq!(found[2].coordinate.to_a1(), "C10");
+    assert_eq!(
+        found[2]
+            .characters
+            .iter()
+            .map(|c| (c.code_point, c.position))
+            .collect::<Vec<_>>(),
+        vec![(0xFEFF, 0), (0x200B, 3)]
+    );
+    assert!(!found[2].leading_whitespace);
+
+    println!("invisible characters found successfully");
+    Ok(())
+}
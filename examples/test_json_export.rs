@@ -0,0 +1,134 @@
+mod common;
+
+use std::io::Cursor;
+
+use common::{Package, SAMPLE, SHEET1};
+use excel_reader::prelude::*;
+use serde_json::{json, Value};
+
+fn to_json(worksheet: &Worksheet, options: JsonOptions) -> anyhow::Result<Value> {
+    Ok(serde_json::from_str(&worksheet.to_json(&options)?)?)
+}
+
+fn range(start: &str, end: &str) -> Option<Dimension> {
+    Some(Dimension {
+        start: Coordinate::from_a1(start.as_bytes())?,
+        end: Coordinate::from_a1(end.as_bytes())?,
+    })
+}
+
+/// Convert worksheets to JSON, as arrays of values or objects keyed by a header row.
+fn main() -> anyhow::Result<()> {
+    // a header with a repeated and an empty name, formatted numbers, and a row with an error, below the data of the sheet
+    let bytes = Package::open(SAMPLE)?
+        .append_rows(
+            SHEET1,
+            concat!(
+                r#"<row r="10"><c r="C10" t="inlineStr"><is><t>name</t></is></c><c r="D10" t="inlineStr"><is><t> name </t></is></c></row>"#,
+                r#"<row r="11"><c r="C11" s="28"><v>0.256</v></c><c r="D11" s="29"><v>45306</v></c><c r="E11" s="30"><v>1234567.891</v></c></row>"#,
+                r#"<row r="12"/>"#,
+                r#"<row r="13"><c r="C13" t="b"><f>1=1</f><v>1</v></c><c r="D13" t="e"><v>#DIV/0!</v></c><c r="E13"><v>0</v></c></row>"#,
+            ),
+        )
+        .append_cell_formats(concat!(
+            r#"<xf numFmtId="10" fontId="0" applyNumberFormat="1"/>"#,
+            r#"<xf numFmtId="14" fontId="0" applyNumberFormat="1"/>"#,
+            r#"<xf numFmtId="4" fontId="0" applyNumberFormat="1"/>"#,
+        ))
+        .bytes()?;
+
+    let mut excel = Excel::from_reader(Cursor::new(bytes))?;
+    let worksheet = excel.get_worksheet_with_name("Sheet 1 - Basic")?;
+
+    // arrays of formatted values by default, every cell of the sheet
+    let all = to_json(&worksheet, JsonOptions::default())?;
+    let rows = all.as_array().unwrap();
+    assert_eq!(rows.len(), 13);
+    assert_eq!(rows[0], json!(["Basic", null, null, null, null]));
+    assert_eq!(rows[2], json!(["1", "Itsuki1", null, null, null]));
+    assert_eq!(rows[3][1], "hlink_inside");
+    assert_eq!(rows[5][1], "itsuki4+\nItsuki5");
+
+    let options = JsonOptions {
+        range: range("C11", "E13"),
+        ..JsonOptions::default()
+    };
+    assert_eq!(
+        to_json(&worksheet, options.clone())?,
+        json!([
+            ["25.60%", "01-15-24", "1,234,567.89"],
+            [null, null, null],
+            ["TRUE", "#DIV/0!", "0"],
+        ])
+    );
+
+    // typed values, without empty rows
+    let typed = JsonOptions {
+        formatted: false,
+        skip_empty_rows: true,
+        ..options
+    };
+    assert_eq!(
+        to_json(&worksheet, typed)?,
+        json!([
+            [0.256, "2024-01-15T00:00:00", 1234567.891],
+            [true, "#DIV/0!", 0],
+        ])
+    );
+
+    // objects keyed by the header, keeping the order of the columns
+    let objects = JsonOptions {
+        shape: JsonShape::Objects,
+        range: range("C10", "E13"),
+        ..JsonOptions::default()
+    };
+    let text = worksheet.to_json(&objects)?;
+    assert!(text.starts_with(r#"[{"name":"25.60%","name_2":"01-15-24","E":"1,234,567.89"},"#));
+    assert_eq!(
+        serde_json::from_str::<Value>(&text)?,
+        json!([
+            {"name": "25.60%", "name_2": "01-15-24", "E": "1,234,567.89"},
+            {"name": null, "name_2": null, "E": null},
+            {"name": "TRUE", "name_2": "#DIV/0!", "E": "0"},
+        ])
+    );
+
+    // null handling
+    let omitted = JsonOptions {
+        nulls: JsonNulls::Omit,
+        ..objects.clone()
+    };
+    assert_eq!(to_json(&worksheet, omitted)?[1], json!({}));
+    let empty_strings = JsonOptions {
+        nulls: JsonNulls::EmptyString,
+        ..objects.clone()
+    };
+    assert_eq!(
+        to_json(&worksheet, empty_strings)?[1],
+        json!({"name": "", "name_2": "", "E": ""})
+    );
+    let omitted_in_arrays = JsonOptions {
+        nulls: JsonNulls::Omit,
+        range: range("C12", "E12"),
+        ..JsonOptions::default()
+    };
+    assert_eq!(
+        to_json(&worksheet, omitted_in_arrays)?,
+        json!([[null, null, null]])
+    );
+
+    // header only, and a range outside of the data
+    let header_only = JsonOptions {
+        range: range("C10", "E10"),
+        ..objects
+    };
+    assert_eq!(worksheet.to_json(&header_only)?, "[]");
+    let outside = JsonOptions {
+        range: range("X1", "Z3"),
+        ..JsonOptions::default()
+    };
+    assert_eq!(worksheet.to_json(&outside)?, "[]");
+
+    println!("json exported successfully");
+    Ok(())
+}
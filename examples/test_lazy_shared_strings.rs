@@ -0,0 +1,82 @@
+mod common;
+
+use std::io::Cursor;
+
+use common::{Package, SAMPLE, SHEET1};
+use excel_reader::{
+    excel::{Excel, ExcelOptions},
+    processed::spreadsheet::sheet_basic_info::SheetType,
+};
+
+/// Sample workbook with a cell referring to a shared string beyond the table appended to the first sheet.
+fn sample_with_bad_index() -> anyhow::Result<Vec<u8>> {
+    Package::open(SAMPLE)?
+        .append_rows(
+            SHEET1,
+            r#"<row r="30"><c r="B30" t="s"><v>100000</v></c></row>"#,
+        )
+        .bytes()
+}
+
+/// Read shared strings when cells refer to them instead of parsing the whole table upfront.
+fn main() -> anyhow::Result<()> {
+    let lazy_options = ExcelOptions {
+        lazy_shared_strings: true,
+        ..Default::default()
+    };
+    let mut eager = Excel::from_path("examples/sample.xlsx")?;
+    let mut lazy = Excel::from_path_with_options("examples/sample.xlsx", lazy_options)?;
+
+    // the index matches the parsed table, item by item
+    let table = eager.get_raw_shared_strings()?.unwrap();
+    let items = table.string_item.clone().unwrap();
+    let index = lazy.get_raw_lazy_shared_strings()?;
+    assert!(!items.is_empty());
+    assert_eq!(index.len(), items.len());
+    assert_eq!(index.unique_count, table.unique_count);
+    assert_eq!(index.count, table.count);
+    for (position, item) in items.iter().enumerate() {
+        assert_eq!(index.get(position)?.as_ref(), Some(item));
+    }
+    assert_eq!(index.get(items.len())?, None);
+
+    // worksheets read the same values either way
+    let sheets = eager.get_sheets()?;
+    for sheet in sheets.iter().filter(|s| s.r#type == SheetType::WorkSheet) {
+        let expected = eager.get_worksheet(sheet)?.get_cells()?;
+        let actual = lazy.get_worksheet(sheet)?.get_cells()?;
+        assert_eq!(
+            expected.iter().map(|c| &c.value).collect::<Vec<_>>(),
+            actual.iter().map(|c| &c.value).collect::<Vec<_>>(),
+            "{}",
+            sheet.name
+        );
+    }
+    // and streaming rows as well
+    let sheet = &sheets[0];
+    let worksheet = lazy.get_worksheet_without_cells(sheet)?;
+    let streamed = worksheet
+        .rows(&mut lazy)?
+        .collect::<anyhow::Result<Vec<_>>>()?;
+    assert!(streamed
+        .iter()
+        .flat_map(|r| r.cells.iter())
+        .any(|c| !c.value.to_string().is_empty()));
+
+    // the index is counted in the footprint
+    assert!(lazy.memory_footprint().shared_strings > 0);
+
+    // out of range indexes fail the same way
+    let bytes = sample_with_bad_index()?;
+    let mut lazy = Excel::from_reader_with_options(Cursor::new(bytes), lazy_options)?;
+    let sheets = lazy.get_sheets()?;
+    let worksheet = lazy.get_worksheet_without_cells(&sheets[0])?;
+    let error = worksheet
+        .rows(&mut lazy)?
+        .find_map(|row| row.err())
+        .unwrap();
+    assert_eq!(error.to_string(), "Shared string index out of range.");
+
+    println!("lazy shared strings read successfully");
+    Ok(())
+}
@@ -0,0 +1,77 @@
+mod common;
+
+use std::io::Cursor;
+
+use common::{Package, SAMPLE, SHEET1, STYLES};
+use excel_reader::{
+    common_types::Coordinate, excel::Excel,
+    processed::spreadsheet::sheet::worksheet::link_candidate::LinkKind,
+};
+
+/// Detect URL-like and email-like text in cells, with or without hyperlink records.
+fn main() -> anyhow::Result<()> {
+    // hyperlinks of the sample show labels rather than addresses
+    let mut excel = Excel::from_path("examples/sample.xlsx")?;
+    let worksheet = excel.get_worksheet_with_name("Sheet 1 - Basic")?;
+    assert!(worksheet.find_link_candidates()?.is_empty());
+
+    // links typed in cells: C10 uses the Hyperlink cell style (cellXfs 28), C11 a plain style
+    let bytes = Package::open(SAMPLE)?
+        .append_rows(
+            SHEET1,
+            concat!(
+                r#"<row r="10"><c r="C10" t="inlineStr" s="28"><is><t>Docs: www.example.com/docs.</t></is></c></row>"#,
+                r#"<row r="11"><c r="C11" t="inlineStr" s="1"><is><t>mail jane.doe@example.org or see https://example.com/a?b=1 (ftp://user@files.example.com)</t></is></c></row>"#,
+                r#"<row r="12"><c r="C12" t="inlineStr" s="1"><is><t>version 1.2.3, example.com, @handle</t></is></c></row>"#,
+            ),
+        )
+        .replace_in(STYLES, r#"<cellStyleXfs count="1">"#, r#"<cellStyleXfs count="2"><xf numFmtId="0" fontId="0"/>"#)
+        .append_cell_formats(r#"<xf numFmtId="0" fontId="0" xfId="1"/>"#)
+        .replace_in(STYLES, r#"<cellStyles count="1">"#, r#"<cellStyles count="2"><cellStyle name="Hyperlink" xfId="1" builtinId="8"/>"#)
+        .bytes()?;
+
+    let mut excel = Excel::from_reader(Cursor::new(bytes))?;
+    let worksheet = excel.get_worksheet_with_name("Sheet 1 - Basic")?;
+    let candidates = worksheet.find_link_candidates()?;
+    let found: Vec<(Coordinate, &str, &str, LinkKind)> = candidates
+        .iter()
+        .map(|c| (c.coordinate, c.matched.as_str(), c.target.as_str(), c.kind))
+        .collect();
+    assert_eq!(
+        found,
+        vec![
+            (
+                Coordinate::from_a1(b"C10").unwrap(),
+                "www.example.com/docs",
+                "http://www.example.com/docs",
+                LinkKind::Url
+            ),
+            (
+                Coordinate::from_a1(b"C11").unwrap(),
+                "jane.doe@example.org",
+                "mailto:jane.doe@example.org",
+                LinkKind::Email
+            ),
+            (
+                Coordinate::from_a1(b"C11").unwrap(),
+                "https://example.com/a?b=1",
+                "https://example.com/a?b=1",
+                LinkKind::Url
+            ),
+            (
+                Coordinate::from_a1(b"C11").unwrap(),
+                "ftp://user@files.example.com",
+                "ftp://user@files.example.com",
+                LinkKind::Url
+            ),
+        ]
+    );
+
+    assert!(candidates[0].styled_as_hyperlink);
+    assert!(!candidates[1].styled_as_hyperlink);
+    assert!(candidates.iter().all(|c| !c.has_hyperlink));
+    assert_eq!(candidates[0].text, "Docs: www.example.com/docs.");
+
+    println!("link candidates found successfully");
+    Ok(())
+}
@@ -0,0 +1,199 @@
+mod common;
+
+use std::io::Cursor;
+
+use common::Package;
+use excel_reader::{
+    excel::Excel, ods::Ods, processed::spreadsheet::sheet::worksheet::Worksheet, xls::Xls,
+};
+
+/// Layout of a sample file, telling how it is mutated and read.
+#[derive(Clone, Copy)]
+enum Format {
+    /// Zip package of a xlsx or xlsb file
+    Package,
+    /// Zip package of an OpenDocument spreadsheet
+    Ods,
+    /// Compound file of a legacy xls workbook
+    Xls,
+    /// Compound file of a password protected workbook, the password is `excel_reader`
+    Encrypted,
+}
+
+/// Fuzz the parsers with corrupted copies of the sample files.
+///
+/// Every xml part of the zip packages, and every record part of binary workbooks,
+/// is mutated with random byte flips, insertions and truncations.
+/// Compound files, legacy xls and encrypted workbooks, are mutated as a whole.
+/// Loading the result may fail, but it should never panic.
+///
+/// Each file is mutated 20 times, set `MALFORMED_INPUT_ITERATIONS` for a longer run, ie: 500.
+/// Encrypted workbooks derive their key on every load and are mutated a tenth as many times.
+fn main() -> anyhow::Result<()> {
+    let files = [
+        ("examples/sample.xlsx", Format::Package),
+        ("examples/table.xlsx", Format::Package),
+        ("examples/workbook_drawing.xlsx", Format::Package),
+        ("examples/sample.xlsb", Format::Package),
+        ("examples/sample.ods", Format::Ods),
+        ("examples/sample.xls", Format::Xls),
+        ("examples/encrypted_agile.xlsx", Format::Encrypted),
+        ("examples/encrypted_standard.xlsx", Format::Encrypted),
+    ];
+    let iterations: usize = match std::env::var("MALFORMED_INPUT_ITERATIONS") {
+        Ok(iterations) => iterations.parse()?,
+        Err(_) => 20,
+    };
+
+    // silence the default hook, panics are reported below instead
+    std::panic::set_hook(Box::new(|_| {}));
+
+    let mut rng = XorShift(0x2545_f491_4f6c_dd1d);
+    let mut panics = 0;
+
+    for (path, format) in files {
+        let original = std::fs::read(path)?;
+        let iterations = match format {
+            Format::Encrypted => (iterations / 10).max(1),
+            _ => iterations,
+        };
+        for iteration in 0..iterations {
+            let mutated = match format {
+                Format::Package | Format::Ods => mutate_package(&original, &mut rng)?,
+                Format::Xls | Format::Encrypted => {
+                    let mut bytes = original.clone();
+                    mutate_bytes(&mut bytes, &mut rng);
+                    bytes
+                }
+            };
+            let result = std::panic::catch_unwind(|| load_everything(format, mutated));
+            if result.is_err() {
+                panics += 1;
+                println!("panic while loading {} (iteration {})", path, iteration);
+            }
+        }
+    }
+
+    if panics > 0 {
+        anyhow::bail!("{} mutated files caused a panic", panics);
+    }
+    println!("no panics");
+    Ok(())
+}
+
+/// Load every part of the file, ignoring errors.
+fn load_everything(format: Format, bytes: Vec<u8>) {
+    match format {
+        Format::Package => {
+            if let Ok(excel) = Excel::from_reader(Cursor::new(bytes)) {
+                load_excel(excel);
+            }
+        }
+        Format::Encrypted => {
+            if let Ok(excel) = Excel::from_reader_with_password(Cursor::new(bytes), "excel_reader")
+            {
+                load_excel(excel);
+            }
+        }
+        Format::Ods => {
+            let Ok(ods) = Ods::from_reader(Cursor::new(bytes)) else {
+                return;
+            };
+            let _ = ods.get_raw_stylesheet();
+            for sheet in ods.get_sheets().unwrap_or_default() {
+                if let Ok(worksheet) = ods.get_worksheet(&sheet) {
+                    load_cells(&worksheet);
+                }
+            }
+        }
+        Format::Xls => {
+            let Ok(xls) = Xls::from_bytes(&bytes) else {
+                return;
+            };
+            let _ = xls.get_raw_stylesheet();
+            let _ = xls.get_raw_shared_strings();
+            for sheet in xls.get_sheets().unwrap_or_default() {
+                if let Ok(worksheet) = xls.get_worksheet(&sheet) {
+                    load_cells(&worksheet);
+                }
+            }
+        }
+    }
+}
+
+fn load_excel(mut excel: Excel<Cursor<Vec<u8>>>) {
+    let _ = excel.get_raw_stylesheet();
+    let _ = excel.get_raw_theme();
+    let _ = excel.get_raw_shared_strings();
+    let Ok(sheets) = excel.get_sheets() else {
+        return;
+    };
+    for sheet in sheets {
+        let Ok(worksheet) = excel.get_worksheet(&sheet) else {
+            continue;
+        };
+        load_cells(&worksheet);
+        let _ = worksheet.get_drawings();
+    }
+}
+
+/// Cells of the first rows and columns of the worksheet.
+///
+/// `get_cells` returns every cell of the dimension, and a corrupted dimension may span the whole grid.
+fn load_cells(worksheet: &Worksheet) {
+    let Some(mut dimension) = worksheet.dimension else {
+        return;
+    };
+    dimension.end.row = dimension.end.row.min(dimension.start.row + 200);
+    dimension.end.col = dimension.end.col.min(dimension.start.col + 200);
+    let _ = worksheet.get_range_with_dimension(dimension);
+}
+
+/// Rewrite the package with one of its xml or binary parts corrupted.
+fn mutate_package(bytes: &[u8], rng: &mut XorShift) -> anyhow::Result<Vec<u8>> {
+    let mut package = Package::from_bytes(bytes.to_vec())?;
+    let target = rng.below(package.parts.len());
+    let (name, content) = &mut package.parts[target];
+    if name.ends_with(".xml") || name.ends_with(".rels") || name.ends_with(".bin") {
+        mutate_bytes(content, rng);
+    }
+    package.bytes()
+}
+
+fn mutate_bytes(content: &mut Vec<u8>, rng: &mut XorShift) {
+    // characters that are most likely to break numbers, addresses and markup,
+    // and bytes that break the sizes and lengths of binary records
+    const INTERESTING: &[u8] = b"0123456789:$-.%#AZaz<>/\"=\x00\x80\xff";
+
+    let count = 1 + rng.below(8);
+    for _ in 0..count {
+        if content.is_empty() {
+            return;
+        }
+        let position = rng.below(content.len());
+        match rng.below(4) {
+            0 => content[position] = INTERESTING[rng.below(INTERESTING.len())],
+            1 => content.insert(position, INTERESTING[rng.below(INTERESTING.len())]),
+            2 => {
+                content.remove(position);
+            }
+            _ => content.truncate(position),
+        }
+    }
+}
+
+/// Small deterministic random number generator so that failures are reproducible.
+struct XorShift(u64);
+
+impl XorShift {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn below(&mut self, bound: usize) -> usize {
+        (self.next() % bound as u64) as usize
+    }
+}
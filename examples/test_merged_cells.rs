@@ -0,0 +1,45 @@
+use excel_reader::{
+    common_types::{Coordinate, Dimension},
+    excel::Excel,
+};
+
+/// Find the merged regions of the first sheet of the sample: `A1:B1` (title) and `B6:B7`.
+fn main() -> anyhow::Result<()> {
+    let mut excel = Excel::from_path("examples/sample.xlsx")?;
+    let sheets = excel.get_sheets()?;
+    let worksheet = excel.get_worksheet(&sheets[0])?;
+
+    let title = Dimension {
+        start: Coordinate::from_point((1, 1)),
+        end: Coordinate::from_point((1, 2)),
+    };
+    let vertical = Dimension {
+        start: Coordinate::from_point((6, 2)),
+        end: Coordinate::from_point((7, 2)),
+    };
+    assert_eq!(worksheet.merged_cells, vec![title, vertical]);
+
+    assert_eq!(
+        worksheet.merged_region_for(Coordinate::from_point((1, 1))),
+        Some(title)
+    );
+    assert_eq!(
+        worksheet.merged_region_for(Coordinate::from_point((1, 2))),
+        Some(title)
+    );
+    assert_eq!(
+        worksheet.merged_region_for(Coordinate::from_point((7, 2))),
+        Some(vertical)
+    );
+    assert_eq!(
+        worksheet.merged_region_for(Coordinate::from_point((6, 1))),
+        None
+    );
+    assert_eq!(
+        worksheet.merged_region_for(Coordinate::from_point((2, 1))),
+        None
+    );
+
+    println!("merged cells found successfully");
+    Ok(())
+}
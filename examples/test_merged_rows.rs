@@ -0,0 +1,94 @@
+mod common;
+
+use std::io::Cursor;
+
+use common::{Package, SAMPLE, SHEET1};
+use excel_reader::{excel::Excel, processed::spreadsheet::sheet::worksheet::row::Row};
+
+/// Read the rows of a grouped report whose categories are merged down the rows of their items,
+/// with and without propagating the merged values.
+fn main() -> anyhow::Result<()> {
+    let sheet = r#"<?xml version="1.0" encoding="UTF-8"?><worksheet xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main"><dimension ref="A1:D7"/><sheetData>
+<row r="1"><c r="A1" t="inlineStr"><is><t>Category</t></is></c><c r="B1" t="inlineStr"><is><t>Item</t></is></c><c r="C1" t="inlineStr"><is><t>Totals</t></is></c><c r="D1"/></row>
+<row r="2"><c r="A2" t="inlineStr"><is><t>Fruit</t></is></c><c r="B2" t="inlineStr"><is><t>Apple</t></is></c><c r="C2"><v>3</v></c></row>
+<row r="3"><c r="A3"/><c r="B3" t="inlineStr"><is><t>Pear</t></is></c><c r="C3"><v>5</v></c></row>
+<row r="4"><c r="B4" t="inlineStr"><is><t>Plum</t></is></c><c r="C4"><v>2</v></c></row>
+<row r="5"><c r="A5" t="inlineStr"><is><t>Vegetable</t></is></c><c r="B5" t="inlineStr"><is><t>Leek</t></is></c><c r="C5"><v>7</v></c></row>
+<row r="7"><c r="B7" t="inlineStr"><is><t>Kale</t></is></c><c r="C7"><v>1</v></c></row>
+</sheetData><mergeCells count="3"><mergeCell ref="A2:A4"/><mergeCell ref="A5:A7"/><mergeCell ref="C1:D1"/></mergeCells></worksheet>"#;
+
+    let bytes = Package::open(SAMPLE)?.replace(SHEET1, sheet).bytes()?;
+
+    let mut excel = Excel::from_reader(Cursor::new(bytes))?;
+    let sheets = excel.get_sheets()?;
+    let worksheet = excel.get_worksheet_without_cells(&sheets[0])?;
+
+    let records = |rows: &[Row]| -> Vec<Vec<(String, String)>> {
+        rows.iter()
+            .map(|row| {
+                row.cells
+                    .iter()
+                    .map(|c| (c.coordinate.to_a1(), c.value.to_string()))
+                    .collect()
+            })
+            .collect()
+    };
+    let record = |cells: &[(&str, &str)]| -> Vec<(String, String)> {
+        cells
+            .iter()
+            .map(|(a1, value)| (a1.to_string(), value.to_string()))
+            .collect()
+    };
+
+    // by default, only the first row of a merged region holds its value
+    let rows = worksheet
+        .rows(&mut excel)?
+        .collect::<anyhow::Result<Vec<Row>>>()?;
+    let plain = records(&rows);
+    assert_eq!(plain[2], record(&[("A3", ""), ("B3", "Pear"), ("C3", "5")]));
+    assert_eq!(plain[3], record(&[("B4", "Plum"), ("C4", "2")]));
+
+    let rows = worksheet
+        .rows(&mut excel)?
+        .propagate_merged_values()
+        .collect::<anyhow::Result<Vec<Row>>>()?;
+    let filled = records(&rows);
+    assert_eq!(filled.len(), 6);
+    // horizontally merged header left as is
+    assert_eq!(
+        filled[0],
+        record(&[
+            ("A1", "Category"),
+            ("B1", "Item"),
+            ("C1", "Totals"),
+            ("D1", "")
+        ])
+    );
+    assert_eq!(
+        filled[1],
+        record(&[("A2", "Fruit"), ("B2", "Apple"), ("C2", "3")])
+    );
+    // covered cell written in the file, replaced
+    assert_eq!(
+        filled[2],
+        record(&[("A3", "Fruit"), ("B3", "Pear"), ("C3", "5")])
+    );
+    // covered cell left out of the file, inserted before the other cells
+    assert_eq!(
+        filled[3],
+        record(&[("A4", "Fruit"), ("B4", "Plum"), ("C4", "2")])
+    );
+    assert_eq!(
+        filled[4],
+        record(&[("A5", "Vegetable"), ("B5", "Leek"), ("C5", "7")])
+    );
+    // row 6 is not in the file, row 7 still gets the value of A5:A7
+    assert_eq!(rows[5].index, 7);
+    assert_eq!(
+        filled[5],
+        record(&[("A7", "Vegetable"), ("B7", "Kale"), ("C7", "1")])
+    );
+
+    println!("merged values propagated successfully");
+    Ok(())
+}
@@ -0,0 +1,40 @@
+use std::io::Cursor;
+
+use excel_reader::excel::{Excel, FileReader, MappedFile};
+
+/// Open a workbook mapped in memory with the `mmap` feature: parts are decompressed from the file mapping.
+fn main() -> anyhow::Result<()> {
+    let path = std::env::temp_dir().join(format!("excel_reader_mmap_{}.xlsx", std::process::id()));
+    std::fs::copy("examples/sample.xlsx", &path)?;
+
+    // SAFETY: the file is only replaced below, never modified in place
+    let mut mapped: Excel<MappedFile> = unsafe { Excel::from_path_mmap(&path)? };
+    let mut buffered = Excel::from_reader(Cursor::new(std::fs::read(&path)?))?;
+    // the feature leaves files opened from a path read through a buffer
+    let mut from_path: Excel<FileReader> = Excel::from_path(&path)?;
+    assert_eq!(from_path.get_sheets()?, buffered.get_sheets()?);
+
+    let sheets = mapped.get_sheets()?;
+    assert_eq!(sheets, buffered.get_sheets()?);
+    for sheet in &sheets {
+        let Ok(expected) = buffered.get_worksheet(sheet) else {
+            continue;
+        };
+        let actual = mapped.get_worksheet(sheet)?;
+        assert_eq!(
+            actual.get_cells()?.iter().map(|c| &c.value).collect::<Vec<_>>(),
+            expected.get_cells()?.iter().map(|c| &c.value).collect::<Vec<_>>()
+        );
+    }
+
+    // replaced files are mapped again on reload
+    let replacement = path.with_extension("new.xlsx");
+    std::fs::copy("examples/sample.xlsx", &replacement)?;
+    std::fs::rename(&replacement, &path)?;
+    assert!(mapped.reload_if_changed()?.is_empty());
+    assert_eq!(mapped.get_sheets()?, sheets);
+
+    std::fs::remove_file(&path)?;
+    println!("mapped file read successfully");
+    Ok(())
+}
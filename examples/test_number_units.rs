@@ -0,0 +1,78 @@
+mod common;
+
+use common::{Package, SAMPLE, SHEET1, STYLES};
+use excel_reader::{
+    prelude::*,
+    processed::spreadsheet::sheet::worksheet::cell::cell_property::numbering_format::NumberUnit,
+};
+
+fn unit(code: &str) -> Option<NumberUnit> {
+    let format = NumberingFormat {
+        format_code: Some(code.to_string()),
+        format_id: 164,
+    };
+    format.unit()
+}
+
+/// Units implied by number formats: percentages and currencies.
+fn main() -> anyhow::Result<()> {
+    assert_eq!(unit("General"), None);
+    assert_eq!(unit("0.00"), None);
+    assert_eq!(unit("0.0%"), Some(NumberUnit::Percentage));
+    // quoted or escaped percent signs are literals
+    assert_eq!(unit(r#"0.0"%""#), None);
+    assert_eq!(unit(r"0.0\%"), None);
+    assert_eq!(
+        unit("$#,##0.00"),
+        Some(NumberUnit::Currency("$".to_string()))
+    );
+    assert_eq!(unit("#,##0 €"), Some(NumberUnit::Currency("€".to_string())));
+    assert_eq!(
+        unit("[$€-407] #,##0.00"),
+        Some(NumberUnit::Currency("€".to_string()))
+    );
+    assert_eq!(
+        unit("[$USD-409] #,##0.00"),
+        Some(NumberUnit::Currency("USD".to_string()))
+    );
+    // only the section of positive numbers is read
+    assert_eq!(unit("0.00;[Red]-$0.00"), None);
+    // a percentage takes precedence over the currency
+    assert_eq!(unit("$0.0%"), Some(NumberUnit::Percentage));
+    // padding and repeated characters are not displayed as is
+    assert_eq!(unit("0_$"), None);
+    assert_eq!(unit("*$0"), None);
+
+    // unit of the cells, numbers only
+    let mut excel = Package::open(SAMPLE)?
+        .append_cell_formats(concat!(
+            r#"<xf numFmtId="10" fontId="0" applyNumberFormat="1"/>"#,
+            r#"<xf numFmtId="200" fontId="0" applyNumberFormat="1"/>"#,
+        ))
+        .replace_in(
+            STYLES,
+            "</numFmts>",
+            r#"<numFmt numFmtId="200" formatCode="[$£-809]#,##0.00"/></numFmts>"#,
+        )
+        .append_rows(
+            SHEET1,
+            concat!(
+                r#"<row r="10"><c r="A10" s="28"><v>0.25</v></c><c r="B10" s="29"><v>12.5</v></c>"#,
+                r#"<c r="C10" s="29" t="inlineStr"><is><t>12.5</t></is></c></row>"#,
+            ),
+        )
+        .excel()?;
+    let sheets = excel.get_sheets()?;
+    let worksheet = excel.get_worksheet(&sheets[0])?;
+    let cell = |a1: &str| worksheet.get_cell(Coordinate::from_a1(a1.as_bytes()).unwrap());
+    assert_eq!(cell("A10")?.number_unit(), Some(NumberUnit::Percentage));
+    assert_eq!(
+        cell("B10")?.number_unit(),
+        Some(NumberUnit::Currency("£".to_string()))
+    );
+    assert_eq!(cell("C10")?.number_unit(), None);
+    assert_eq!(cell("A3")?.number_unit(), None);
+
+    println!("number units read successfully");
+    Ok(())
+}
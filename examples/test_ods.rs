@@ -0,0 +1,172 @@
+mod common;
+
+use std::io::Cursor;
+
+use common::Package;
+use excel_reader::{
+    common_types::{Coordinate, Dimension},
+    ods::Ods,
+    processed::spreadsheet::{
+        sheet::worksheet::cell::cell_value::CellValueType, sheet_basic_info::SheetVisibleState,
+    },
+};
+
+fn text(value: &CellValueType) -> String {
+    match value {
+        CellValueType::PlainText(text) => text.text.clone(),
+        other => panic!("expected text, got {:?}", other),
+    }
+}
+
+fn formula(value: &CellValueType) -> (String, Option<String>) {
+    match value {
+        CellValueType::Formula(formula) => (
+            formula.formula.clone(),
+            formula.last_calculated_value.clone(),
+        ),
+        other => panic!("expected formula, got {:?}", other),
+    }
+}
+
+fn main() -> anyhow::Result<()> {
+    let ods = Ods::from_path("examples/sample.ods")?;
+
+    let sheets = ods.get_sheets()?;
+    assert_eq!(sheets.len(), 2);
+    assert_eq!(sheets[0].name, "Data");
+    assert_eq!(sheets[1].name, "Other");
+    assert_eq!(sheets[1].visible_state, SheetVisibleState::Hidden);
+
+    let worksheet = ods.get_worksheet_with_name("Data")?;
+    let cell = |row: u64, col: u64| worksheet.get_cell(Coordinate::from_point((row, col)));
+
+    // empty rows and columns repeated up to the end of the sheet are not part of the dimension
+    assert_eq!(
+        worksheet.dimension,
+        Some(Dimension {
+            start: Coordinate::from_point((1, 1)),
+            end: Coordinate::from_point((5, 4)),
+        })
+    );
+    assert_eq!(worksheet.get_cells()?.len(), 20);
+
+    // header style inherited from a named style, with a fill of its own
+    let a1 = cell(1, 1)?;
+    assert_eq!(text(&a1.value), "Name");
+    assert!(a1.property.font.bold);
+    assert_eq!(a1.property.font.size, 12.0);
+    assert_eq!(
+        a1.property.fill_color().map(|c| c.to_lowercase()),
+        Some("#ffff00ff".to_string())
+    );
+    assert!(!cell(2, 1)?.property.font.bold);
+
+    // paragraphs, spaces and annotations
+    assert_eq!(text(&cell(2, 1)?.value), "Alpha  one\nline two");
+
+    // numbers, dates and booleans with their data styles
+    let b2 = cell(2, 2)?;
+    assert_eq!(b2.value, CellValueType::Numeric(1.5));
+    assert_eq!(
+        b2.property.numbering_format.format_code.as_deref(),
+        Some("0.00")
+    );
+    let c2 = cell(2, 3)?;
+    assert_eq!(c2.value, CellValueType::Numeric(45306.0));
+    assert_eq!(
+        c2.property.numbering_format.format_code.as_deref(),
+        Some("yyyy-mm-dd")
+    );
+    assert_eq!(cell(2, 4)?.value, CellValueType::Bool(true));
+
+    // repeated rows, formulas converted to the A1 syntax
+    for row in [3, 4] {
+        assert_eq!(text(&cell(row, 1)?.value), "Same");
+        assert_eq!(
+            formula(&cell(row, 2)?.value),
+            ("SUM(B2:B2,1)".to_string(), Some("2.5".to_string()))
+        );
+    }
+    assert_eq!(
+        formula(&cell(5, 3)?.value),
+        ("1/0".to_string(), Some("#DIV/0!".to_string()))
+    );
+    assert_eq!(
+        formula(&cell(5, 4)?.value),
+        ("Other!A1&\";x\"".to_string(), Some("7;x".to_string()))
+    );
+    let raw_worksheet = ods.get_raw_worksheet(&sheets[0])?;
+    let raw_rows = raw_worksheet.sheet_data.unwrap().rows.unwrap();
+    let raw_types: Vec<Option<String>> = raw_rows[4]
+        .cells
+        .iter()
+        .flatten()
+        .map(|c| c.r#type.clone())
+        .collect();
+    assert_eq!(
+        raw_types,
+        vec![
+            Some("inlineStr".to_string()),
+            Some("e".to_string()),
+            Some("str".to_string())
+        ]
+    );
+
+    // merged cells, row heights and column widths
+    assert_eq!(text(&cell(5, 1)?.value), "Merged");
+    assert_eq!(
+        worksheet.merged_cells,
+        vec![Dimension {
+            start: Coordinate::from_point((5, 1)),
+            end: Coordinate::from_point((5, 2)),
+        }]
+    );
+    assert_eq!(worksheet.row_height(1), 30.0);
+    assert!((worksheet.row_height(2) - 12.816).abs() < 0.01);
+    assert!((worksheet.column_width(1) - 64.0).abs() < 1.0);
+    assert!((worksheet.column_width(3) - 64.0).abs() < 1.0);
+
+    // time values are fractions of a day
+    let other = ods.get_worksheet_with_sheet_id(&2)?;
+    let a1 = other.get_cell(Coordinate::from_point((1, 1)))?;
+    assert_eq!(a1.value, CellValueType::Numeric(12.5 / 24.0));
+
+    assert!(Ods::from_path("examples/sample.xlsx").is_err());
+
+    // a table left open inside another one ends with the file
+    let unclosed = Package::open("examples/sample.ods")?
+        .replace_in(
+            "content.xml",
+            "<table:table-row table:style-name=\"ro2\">",
+            "<table:table table:name=\"Nested\"><table:table-row table:style-name=\"ro2\">",
+        )
+        .bytes()?;
+    assert!(Ods::from_reader(Cursor::new(unclosed)).is_err());
+
+    // a value repeated over every cell of the sheet is placed up to a limit
+    let repeated = Package::open("examples/sample.ods")?
+        .replace_in(
+            "content.xml",
+            "<table:table-row table:style-name=\"ro2\">",
+            concat!(
+                r#"<table:table-row table:number-rows-repeated="1048576">"#,
+                r#"<table:table-cell office:value-type="float" office:value="1" table:number-columns-repeated="16384"/>"#,
+                r#"</table:table-row><table:table-row table:style-name="ro2">"#,
+            ),
+        )
+        .bytes()?;
+    let ods = Ods::from_reader(Cursor::new(repeated))?;
+    let raw_worksheet = ods.get_raw_worksheet(&ods.get_sheets()?[0])?;
+    let raw_cells: usize = raw_worksheet
+        .sheet_data
+        .unwrap()
+        .rows
+        .unwrap()
+        .iter()
+        .map(|row| row.cells.iter().flatten().count())
+        .sum();
+    assert!(raw_cells > 1 << 19 && raw_cells <= (1 << 20) + 20);
+
+    println!("ods sample read successfully");
+    Ok(())
+}
@@ -0,0 +1,68 @@
+mod common;
+
+use std::io::Cursor;
+
+use common::{Package, EMPTY_SHEET, SAMPLE, SHEET1, SHEET2};
+use excel_reader::{
+    excel::Excel,
+    processed::spreadsheet::sheet::worksheet::page_setup::{PageOrder, PageOrientation},
+    units::inch_to_pt,
+};
+
+/// Read the print settings of a sheet fitted on one page wide, in landscape A4, and of a sheet without any.
+fn main() -> anyhow::Result<()> {
+    let sheet = r#"<?xml version="1.0" encoding="UTF-8"?><worksheet xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main"><sheetPr><pageSetUpPr fitToPage="1"/></sheetPr><dimension ref="A1"/><sheetData>
+<row r="1"><c r="A1"><v>1</v></c></row>
+</sheetData><printOptions horizontalCentered="1" headings="1" gridLines="1" gridLinesSet="1"/><pageMargins left="0.25" right="0.25" top="0.75" bottom="0.75" header="0.3" footer="0.3"/><pageSetup paperSize="9" scale="85" fitToHeight="0" orientation="landscape" pageOrder="overThenDown" firstPageNumber="3" useFirstPageNumber="1" blackAndWhite="1" cellComments="atEnd"/></worksheet>"#;
+
+    let bytes = Package::open(SAMPLE)?
+        .replace(SHEET1, sheet)
+        .replace(SHEET2, EMPTY_SHEET)
+        .bytes()?;
+
+    let mut excel = Excel::from_reader(Cursor::new(bytes))?;
+    let sheets = excel.get_sheets()?;
+    let worksheet = excel.get_worksheet_without_cells(&sheets[0])?;
+
+    let setup = worksheet.page_setup();
+    assert_eq!(setup.paper_size, Some(9));
+    assert!((setup.paper_width - inch_to_pt(210.0 / 25.4)).abs() < 1e-9);
+    assert!((setup.paper_height - inch_to_pt(297.0 / 25.4)).abs() < 1e-9);
+    assert_eq!(setup.orientation, PageOrientation::Landscape);
+    assert_eq!(setup.page_size(), (setup.paper_height, setup.paper_width));
+    assert_eq!(setup.scale, 85);
+    assert!(setup.fit_to_page);
+    assert_eq!(setup.fit_to_width, 1);
+    assert_eq!(setup.fit_to_height, 0);
+    assert_eq!(setup.margins.left, 0.25);
+    assert_eq!(setup.margins.top, 0.75);
+    assert_eq!(setup.margins.footer, 0.3);
+    assert_eq!(setup.page_order, PageOrder::OverThenDown);
+    assert_eq!(setup.first_page_number, Some(3));
+    assert!(setup.black_and_white);
+    assert!(!setup.draft);
+    assert_eq!(setup.copies, 1);
+    assert_eq!(setup.cell_comments, "atEnd");
+    assert_eq!(setup.errors, "displayed");
+    assert!(setup.print_grid_lines);
+    assert!(setup.print_headings);
+    assert!(setup.horizontal_centered);
+    assert!(!setup.vertical_centered);
+
+    // defaults of Excel
+    let worksheet = excel.get_worksheet_without_cells(&sheets[1])?;
+    let setup = worksheet.page_setup();
+    assert_eq!(setup.paper_size, Some(1));
+    assert_eq!(setup.page_size(), (inch_to_pt(8.5), inch_to_pt(11.0)));
+    assert_eq!(setup.orientation, PageOrientation::Default);
+    assert_eq!(setup.scale, 100);
+    assert!(!setup.fit_to_page);
+    assert_eq!(setup.margins.left, 0.7);
+    assert_eq!(setup.margins.header, 0.3);
+    assert_eq!(setup.page_order, PageOrder::DownThenOver);
+    assert_eq!(setup.first_page_number, None);
+    assert!(!setup.print_grid_lines);
+
+    println!("page setup read successfully");
+    Ok(())
+}
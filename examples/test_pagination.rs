@@ -0,0 +1,135 @@
+mod common;
+
+use std::io::Cursor;
+
+use common::{Package, SAMPLE, SHEET1, WORKBOOK};
+use excel_reader::{
+    common_types::Dimension, excel::Excel,
+    processed::spreadsheet::sheet::worksheet::pagination::PrintPage,
+};
+
+/// Paginate a 40 rows x 3 columns sheet, rows being 100pt high.
+///
+/// On Letter paper with the default margins, a portrait page holds 6 rows at 100%: (792 - 108) / 100.
+fn main() -> anyhow::Result<()> {
+    // scale and manual breaks: page 1-6, break after 10, then 6 rows per page
+    let pages = paginate(
+        r#"<rowBreaks count="1" manualBreakCount="1"><brk id="10" max="16383" man="1"/></rowBreaks>"#,
+        r#"<pageSetup scale="100" orientation="portrait"/>"#,
+        "",
+        "",
+    )?;
+    assert_eq!(
+        ranges(&pages),
+        vec![
+            (1, 1, 6, 3),
+            (7, 1, 10, 3),
+            (11, 1, 16, 3),
+            (17, 1, 22, 3),
+            (23, 1, 28, 3),
+            (29, 1, 34, 3),
+            (35, 1, 40, 3),
+        ]
+    );
+    assert_eq!(pages[0].page_number, 1);
+    assert_eq!(pages[0].scale, 100);
+    assert!((pages[0].height - 600.0).abs() < 1e-6);
+    assert!((pages[1].height - 400.0).abs() < 1e-6);
+
+    // print area limited to the first 20 rows and 2 columns, first page number
+    let pages = paginate(
+        r#"<rowBreaks count="1" manualBreakCount="1"><brk id="10" max="16383" man="1"/></rowBreaks>"#,
+        r#"<pageSetup scale="100" firstPageNumber="5" useFirstPageNumber="1"/>"#,
+        "",
+        r#"<definedNames><definedName name="_xlnm.Print_Area" localSheetId="0">'Sheet 1 - Basic'!$A$1:$B$20</definedName></definedNames>"#,
+    )?;
+    assert_eq!(
+        ranges(&pages),
+        vec![(1, 1, 6, 2), (7, 1, 10, 2), (11, 1, 16, 2), (17, 1, 20, 2)]
+    );
+    assert_eq!(
+        pages.iter().map(|p| p.page_number).collect::<Vec<_>>(),
+        vec![5, 6, 7, 8]
+    );
+
+    // landscape: (612 - 108) / 100, 5 rows per page
+    let pages = paginate("", r#"<pageSetup orientation="landscape"/>"#, "", "")?;
+    assert_eq!(pages.len(), 8);
+    assert_eq!(ranges(&pages)[1], (6, 1, 10, 3));
+
+    // fit to one page: manual breaks are ignored, the scale is reduced
+    let pages = paginate(
+        r#"<rowBreaks count="1" manualBreakCount="1"><brk id="10" max="16383" man="1"/></rowBreaks>"#,
+        r#"<pageSetup fitToWidth="1" fitToHeight="1"/>"#,
+        r#"<sheetPr><pageSetUpPr fitToPage="1"/></sheetPr>"#,
+        "",
+    )?;
+    assert_eq!(ranges(&pages), vec![(1, 1, 40, 3)]);
+    assert_eq!(pages[0].scale, 17);
+    assert!(pages[0].height <= 684.0);
+
+    // column breaks, printed over then down
+    let pages = paginate(
+        r#"<rowBreaks count="1" manualBreakCount="1"><brk id="6" max="16383" man="1"/></rowBreaks><colBreaks count="1" manualBreakCount="1"><brk id="1" max="1048575" man="1"/></colBreaks>"#,
+        r#"<pageSetup pageOrder="overThenDown"/>"#,
+        "",
+        r#"<definedNames><definedName name="_xlnm.Print_Area" localSheetId="0">'Sheet 1 - Basic'!$A$1:$C$12</definedName></definedNames>"#,
+    )?;
+    assert_eq!(
+        ranges(&pages),
+        vec![(1, 1, 6, 1), (1, 2, 6, 3), (7, 1, 12, 1), (7, 2, 12, 3)]
+    );
+
+    println!("pagination computed successfully");
+    Ok(())
+}
+
+/// (first row, first column, last row, last column) of each page.
+fn ranges(pages: &[PrintPage]) -> Vec<(u64, u64, u64, u64)> {
+    pages
+        .iter()
+        .map(|p| {
+            let Dimension { start, end } = p.range;
+            (start.row, start.col, end.row, end.col)
+        })
+        .collect()
+}
+
+/// Replace the first sheet of the sample and paginate it.
+fn paginate(
+    breaks: &str,
+    page_setup: &str,
+    sheet_properties: &str,
+    defined_names: &str,
+) -> anyhow::Result<Vec<PrintPage>> {
+    let mut rows = String::new();
+    for row in 1..=40 {
+        rows.push_str(&format!(r#"<row r="{}" ht="100" customHeight="1">"#, row));
+        for (col, column) in ["A", "B", "C"].iter().enumerate() {
+            rows.push_str(&format!(
+                r#"<c r="{}{}"><v>{}</v></c>"#,
+                column,
+                row,
+                row * 10 + col
+            ));
+        }
+        rows.push_str("</row>");
+    }
+    let sheet = format!(
+        r#"<?xml version="1.0" encoding="UTF-8"?><worksheet xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main">{}<dimension ref="A1:C40"/><cols><col min="1" max="3" width="10" customWidth="1"/></cols><sheetData>{}</sheetData><pageMargins left="0.7" right="0.7" top="0.75" bottom="0.75" header="0.3" footer="0.3"/>{}{}</worksheet>"#,
+        sheet_properties, rows, page_setup, breaks
+    );
+    let workbook = format!(
+        r#"<?xml version="1.0" encoding="UTF-8"?><workbook xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships" xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main"><sheets><sheet name="Sheet 1 - Basic" sheetId="1" r:id="rId4"/><sheet name="Sheet 2 - Custom grid lines" sheetId="2" r:id="rId5"/><sheet name="Sheet 3 - Custom Colors_Font" sheetId="3" r:id="rId6"/></sheets>{}</workbook>"#,
+        defined_names
+    );
+
+    let bytes = Package::open(SAMPLE)?
+        .replace(SHEET1, &sheet)
+        .replace(WORKBOOK, &workbook)
+        .bytes()?;
+
+    let mut excel = Excel::from_reader(Cursor::new(bytes))?;
+    let worksheet = excel.get_worksheet_with_name("Sheet 1 - Basic")?;
+    Ok(worksheet.paginate())
+}
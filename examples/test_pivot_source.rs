@@ -0,0 +1,199 @@
+mod common;
+
+use common::{Package, SAMPLE};
+use excel_reader::processed::spreadsheet::{
+    pivot_table::PivotSource, sheet::worksheet::cell::cell_value::CellValueType,
+};
+
+const MAIN: &str = "http://schemas.openxmlformats.org/spreadsheetml/2006/main";
+const RELATIONSHIPS: &str = "http://schemas.openxmlformats.org/officeDocument/2006/relationships";
+
+fn cache_definition(source: &str, fields: &[&str]) -> String {
+    let fields: String = fields
+        .iter()
+        .map(|f| {
+            format!(
+                r#"<cacheField name="{}" numFmtId="0"><sharedItems/></cacheField>"#,
+                f
+            )
+        })
+        .collect();
+    format!(
+        r#"<?xml version="1.0" encoding="UTF-8"?><pivotCacheDefinition xmlns="{}" xmlns:r="{}" r:id="rId1">{}<cacheFields count="1">{}</cacheFields></pivotCacheDefinition>"#,
+        MAIN, RELATIONSHIPS, source, fields
+    )
+}
+
+fn pivot_table(name: &str, cache_id: u64, location: &str) -> String {
+    format!(
+        r#"<?xml version="1.0" encoding="UTF-8"?><pivotTableDefinition xmlns="{}" name="{}" cacheId="{}" dataCaption="Values"><location ref="{}" firstHeaderRow="1" firstDataRow="1" firstDataCol="1"/><pivotFields count="1"><pivotField axis="axisRow" showAll="0"><items count="1"><item t="default"/></items></pivotField></pivotFields><rowFields count="1"><field x="0"/></rowFields></pivotTableDefinition>"#,
+        MAIN, name, cache_id, location
+    )
+}
+
+fn relationships(targets: &[(&str, &str)]) -> String {
+    let relationships: String = targets
+        .iter()
+        .enumerate()
+        .map(|(index, (r#type, target))| {
+            format!(
+                r#"<Relationship Id="rId{}" Type="{}/{}" Target="{}"/>"#,
+                index + 1,
+                RELATIONSHIPS,
+                r#type,
+                target
+            )
+        })
+        .collect();
+    format!(
+        r#"<?xml version="1.0" encoding="UTF-8"?><Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships">{}</Relationships>"#,
+        relationships
+    )
+}
+
+/// List the pivot tables of a workbook, and read the rows of their source.
+fn main() -> anyhow::Result<()> {
+    let sheet1 = format!(
+        r#"<?xml version="1.0" encoding="UTF-8"?><worksheet xmlns="{}"><dimension ref="A1:C4"/><sheetData>
+<row r="1"><c r="A1" t="inlineStr"><is><t>Region</t></is></c><c r="B1" t="inlineStr"><is><t>Product</t></is></c><c r="C1" t="inlineStr"><is><t>Sales</t></is></c></row>
+<row r="2"><c r="A2" t="inlineStr"><is><t>East</t></is></c><c r="B2" t="inlineStr"><is><t>Pen</t></is></c><c r="C2"><v>10</v></c></row>
+<row r="3"><c r="A3" t="inlineStr"><is><t>West</t></is></c><c r="B3" t="inlineStr"><is><t>Ink</t></is></c><c r="C3"><v>20</v></c></row>
+<row r="4"><c r="A4" t="inlineStr"><is><t>East</t></is></c><c r="B4" t="inlineStr"><is><t>Ink</t></is></c><c r="C4"><v>30</v></c></row>
+</sheetData></worksheet>"#,
+        MAIN
+    );
+    let workbook = format!(
+        r#"<?xml version="1.0" encoding="UTF-8"?><workbook xmlns:r="{}" xmlns="{}"><sheets><sheet name="Sheet 1 - Basic" sheetId="1" r:id="rId4"/><sheet name="Sheet 2 - Custom grid lines" sheetId="2" r:id="rId5"/><sheet name="Sheet 3 - Custom Colors_Font" sheetId="3" r:id="rId6"/></sheets><definedNames><definedName name="SalesData">'Sheet 1 - Basic'!$A$1:$B$3</definedName></definedNames><pivotCaches><pivotCache cacheId="1" r:id="rId7"/><pivotCache cacheId="2" r:id="rId8"/><pivotCache cacheId="3" r:id="rId9"/></pivotCaches></workbook>"#,
+        RELATIONSHIPS, MAIN
+    );
+    let workbook_rels = relationships(&[
+        ("sharedStrings", "sharedStrings.xml"),
+        ("styles", "styles.xml"),
+        ("theme", "theme/theme1.xml"),
+        ("worksheet", "worksheets/sheet1.xml"),
+        ("worksheet", "worksheets/sheet2.xml"),
+        ("worksheet", "worksheets/sheet3.xml"),
+        (
+            "pivotCacheDefinition",
+            "pivotCache/pivotCacheDefinition1.xml",
+        ),
+        (
+            "pivotCacheDefinition",
+            "pivotCache/pivotCacheDefinition2.xml",
+        ),
+        (
+            "pivotCacheDefinition",
+            "pivotCache/pivotCacheDefinition3.xml",
+        ),
+    ]);
+
+    let parts: Vec<(String, String)> = vec![
+        ("xl/workbook.xml".to_string(), workbook),
+        ("xl/_rels/workbook.xml.rels".to_string(), workbook_rels),
+        ("xl/worksheets/sheet1.xml".to_string(), sheet1),
+        (
+            "xl/worksheets/_rels/sheet2.xml.rels".to_string(),
+            relationships(&[
+                ("pivotTable", "../pivotTables/pivotTable1.xml"),
+                ("pivotTable", "../pivotTables/pivotTable2.xml"),
+            ]),
+        ),
+        (
+            "xl/worksheets/_rels/sheet3.xml.rels".to_string(),
+            relationships(&[("pivotTable", "../pivotTables/pivotTable3.xml")]),
+        ),
+        (
+            "xl/pivotCache/pivotCacheDefinition1.xml".to_string(),
+            cache_definition(
+                r#"<cacheSource type="worksheet"><worksheetSource ref="A1:C4" sheet="Sheet 1 - Basic"/></cacheSource>"#,
+                &["Region", "Product", "Sales"],
+            ),
+        ),
+        (
+            "xl/pivotCache/pivotCacheDefinition2.xml".to_string(),
+            cache_definition(
+                r#"<cacheSource type="worksheet"><worksheetSource name="SalesData"/></cacheSource>"#,
+                &["Region", "Product"],
+            ),
+        ),
+        (
+            "xl/pivotCache/pivotCacheDefinition3.xml".to_string(),
+            cache_definition(
+                r#"<cacheSource type="external" connectionId="1"/>"#,
+                &["Amount"],
+            ),
+        ),
+        (
+            "xl/pivotTables/pivotTable1.xml".to_string(),
+            pivot_table("BySales", 1, "A3:B6"),
+        ),
+        (
+            "xl/pivotTables/pivotTable2.xml".to_string(),
+            pivot_table("ByName", 2, "E3:F5"),
+        ),
+        (
+            "xl/pivotTables/pivotTable3.xml".to_string(),
+            pivot_table("External", 3, "A1:B2"),
+        ),
+    ];
+
+    let package = parts
+        .into_iter()
+        .fold(Package::open(SAMPLE)?, |package, (name, content)| {
+            package.replace(&name, content)
+        });
+
+    let mut excel = package.excel()?;
+    let pivot_tables = excel.get_pivot_tables()?;
+    let names: Vec<&str> = pivot_tables.iter().map(|p| p.name.as_str()).collect();
+    assert_eq!(names, vec!["BySales", "ByName", "External"]);
+
+    // source given as a range
+    let by_sales = &pivot_tables[0];
+    assert_eq!(by_sales.sheet_name, "Sheet 2 - Custom grid lines");
+    assert_eq!(by_sales.cache_id, Some(1));
+    assert_eq!(by_sales.fields, vec!["Region", "Product", "Sales"]);
+    assert_eq!(by_sales.location.map(|l| l.start.row), Some(3));
+    let PivotSource::Range { sheet, range } = &by_sales.source else {
+        panic!("expected a range source, got {:?}", by_sales.source);
+    };
+    assert_eq!(sheet, "Sheet 1 - Basic");
+    assert_eq!((range.start.row, range.end.row, range.end.col), (1, 4, 3));
+
+    let records = by_sales.source_records(&mut excel)?;
+    assert_eq!(records.len(), 3);
+    assert!(records.iter().all(|r| r.len() == 3));
+    assert_eq!(records[1][0].value.to_string(), "West");
+    assert_eq!(records[2][2].value, CellValueType::Numeric(30.0));
+    assert_eq!(records[0][0].coordinate.row, 2);
+
+    // source given as a defined name
+    let by_name = &pivot_tables[1];
+    assert_eq!(
+        by_name.source,
+        PivotSource::Name {
+            name: "SalesData".to_string(),
+            sheet: None
+        }
+    );
+    let (sheet, range) = by_name.source_range(&mut excel)?;
+    assert_eq!(sheet, "Sheet 1 - Basic");
+    assert_eq!((range.end.row, range.end.col), (3, 2));
+    let records = by_name.source_records(&mut excel)?;
+    assert_eq!(records.len(), 2);
+    assert_eq!(records[1][1].value.to_string(), "Ink");
+
+    // source outside of the workbook
+    let external = &pivot_tables[2];
+    assert_eq!(external.sheet_name, "Sheet 3 - Custom Colors_Font");
+    assert_eq!(
+        external.source,
+        PivotSource::Connection {
+            connection_id: Some(1)
+        }
+    );
+    assert!(external.source_records(&mut excel).is_err());
+
+    println!("pivot table sources read successfully");
+    Ok(())
+}
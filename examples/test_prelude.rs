@@ -0,0 +1,30 @@
+use excel_reader::prelude::*;
+
+/// Read a workbook with the types of the prelude only.
+fn main() -> Result<()> {
+    let mut excel = Excel::from_path("examples/sample.xlsx")?;
+    let sheets: Vec<SheetBasicInfo> = excel.get_sheets()?;
+    assert_eq!(sheets.len(), 3);
+
+    let worksheet: Worksheet = excel.get_worksheet(&sheets[0])?;
+    let cells: Vec<Cell> = worksheet.get_cells()?;
+    assert!(!cells.is_empty());
+
+    let dimension: Option<Dimension> = worksheet.dimension;
+    assert!(dimension.is_some());
+    let first: &Cell = &cells[0];
+    let coordinate: Coordinate = first.coordinate;
+    assert_eq!(worksheet.get_cell(coordinate)?.value, first.value);
+    let _value: &CellValueType = &first.value;
+    let _property: &CellProperty = &first.property;
+
+    // raw structures stay apart from the processed ones
+    let _workbook: Option<Box<raw::XlsxWorkbook>> = excel.get_raw_workbook()?;
+    let _worksheet: raw::XlsxWorksheet = excel.get_raw_worksheet(&sheets[0])?;
+
+    let error: Error = anyhow::anyhow!("not a workbook");
+    assert_eq!(error.to_string(), "not a workbook");
+
+    println!("prelude used successfully");
+    Ok(())
+}
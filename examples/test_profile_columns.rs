@@ -0,0 +1,180 @@
+mod common;
+
+use common::{Package, SAMPLE, SHEET1, STYLES};
+use excel_reader::processed::spreadsheet::sheet::worksheet::column_profile::{
+    ColumnUnit, DetectedType, SheetProfile, UnitKind,
+};
+
+/// Profile the first sheet of the sample workbook with its sheet data replaced by some rows.
+fn profile(rows: &str, has_header: bool) -> anyhow::Result<SheetProfile> {
+    let mut excel = Package::open(SAMPLE)?
+        .replace_rows(SHEET1, rows)
+        .append_cell_formats(concat!(
+            r#"<xf numFmtId="14" fontId="0" applyNumberFormat="1"/>"#,
+            r#"<xf numFmtId="201" fontId="0" applyNumberFormat="1"/>"#,
+            r#"<xf numFmtId="200" fontId="0" applyNumberFormat="1"/>"#,
+            r#"<xf numFmtId="10" fontId="0" applyNumberFormat="1"/>"#
+        ))
+        .replace_in(
+            STYLES,
+            "</numFmts>",
+            concat!(
+                r#"<numFmt numFmtId="200" formatCode="0.0&quot; kg&quot;"/>"#,
+                r#"<numFmt numFmtId="201" formatCode="[$$-409]#,##0.00"/></numFmts>"#
+            ),
+        )
+        .excel()?;
+    let sheets = excel.get_sheets()?;
+    let worksheet = excel.get_worksheet_without_cells(&sheets[0])?;
+    worksheet.profile_columns(&mut excel, has_header)
+}
+
+fn text(a1: &str, text: &str) -> String {
+    format!(
+        r#"<c r="{}" t="inlineStr"><is><t>{}</t></is></c>"#,
+        a1, text
+    )
+}
+
+/// Compute column statistics of a sheet in one streaming pass.
+fn main() -> anyhow::Result<()> {
+    let mut rows = format!(
+        r#"<row r="1">{}{}{}{}{}</row>"#,
+        text("A1", "id"),
+        text("B1", "amount"),
+        text("C1", "category"),
+        text("D1", "date"),
+        text("E1", "note")
+    );
+    // rows 2 to 9, row 6 left out of the file
+    for index in [2, 3, 4, 5, 7, 8, 9] {
+        rows.push_str(&format!(
+            r#"<row r="{0}"><c r="A{0}"><v>{0}</v></c><c r="B{0}"><v>{1}</v></c>{2}<c r="D{0}" s="28"><v>{3}</v></c></row>"#,
+            index,
+            index as f64 * 1.5,
+            text(&format!("C{}", index), if index % 2 == 0 { "even" } else { " odd" }),
+            45306 + index,
+        ));
+    }
+    rows.push_str(concat!(
+        r#"<row r="10"><c r="A10"><v>10</v></c><c r="B10" t="inlineStr"><is><t>n/a</t></is></c>"#,
+        r#"<c r="C10" t="inlineStr"><is><t>odd</t></is></c><c r="E10" t="b"><v>1</v></c></row>"#,
+        r#"<row r="11"><c r="A11"><f>A10+1</f><v>11</v></c><c r="B11" t="e"><v>#DIV/0!</v></c></row>"#,
+    ));
+
+    let report = profile(&rows, true)?;
+    assert_eq!(report.row_count, 10);
+    assert_eq!(report.columns.len(), 5);
+
+    // numbers, a formula among them, with the left out row counted as empty
+    let id = report.column(1).unwrap();
+    assert_eq!(id.name.as_deref(), Some("id"));
+    assert_eq!(id.detected_type, DetectedType::Numeric);
+    assert_eq!(id.type_counts.numeric, 9);
+    assert_eq!((id.null_count, id.null_rate), (1, 0.1));
+    assert_eq!(id.distinct_count, 9);
+    assert!(id.distinct_exact);
+    assert_eq!((id.min, id.max), (Some(2.0), Some(11.0)));
+    assert_eq!(id.mean, Some(59.0 / 9.0));
+
+    // statistics of the numeric values only
+    let amount = report.column(2).unwrap();
+    assert_eq!(amount.detected_type, DetectedType::Mixed);
+    assert_eq!(
+        (
+            amount.type_counts.numeric,
+            amount.type_counts.text,
+            amount.type_counts.error
+        ),
+        (7, 1, 1)
+    );
+    assert_eq!((amount.min, amount.max), (Some(3.0), Some(13.5)));
+    assert_eq!(amount.mean, Some(1.5 * 38.0 / 7.0));
+
+    // texts are compared trimmed
+    let category = report.column(3).unwrap();
+    assert_eq!(category.detected_type, DetectedType::Text);
+    assert_eq!(category.distinct_count, 2);
+    assert_eq!(category.null_count, 2);
+    assert_eq!(category.min, None);
+
+    let date = report.column(4).unwrap();
+    assert_eq!(date.detected_type, DetectedType::DateTime);
+    assert_eq!(date.type_counts.date_time, 7);
+    assert_eq!(date.mean, None);
+
+    let note = report.column(5).unwrap();
+    assert_eq!(note.detected_type, DetectedType::Bool);
+    assert_eq!(note.null_count, 9);
+
+    // without header, the header row is profiled as values
+    let report = profile(&rows, false)?;
+    assert_eq!(report.row_count, 11);
+    assert_eq!(report.column(1).unwrap().name, None);
+    assert_eq!(report.column(1).unwrap().detected_type, DetectedType::Mixed);
+
+    // a header alone
+    let report = profile(&rows[..rows.find("</row>").unwrap() + 6], true)?;
+    assert_eq!(report.row_count, 0);
+    assert_eq!(report.columns.len(), 5);
+    assert_eq!(report.columns[0].detected_type, DetectedType::Empty);
+    assert_eq!(report.columns[0].null_rate, 0.0);
+    assert!(profile("", true)?.columns.is_empty());
+
+    // many distinct values are estimated
+    let count = 70_000;
+    let mut rows = String::new();
+    for index in 1..=count {
+        rows.push_str(&format!(
+            r#"<row r="{0}"><c r="A{0}"><v>{0}</v></c></row>"#,
+            index
+        ));
+    }
+    let report = profile(&rows, false)?;
+    let estimated = report.column(1).unwrap();
+    assert!(!estimated.distinct_exact);
+    let error = (estimated.distinct_count as f64 - count as f64).abs() / count as f64;
+    assert!(error < 0.03, "{}", estimated.distinct_count);
+    assert_eq!(estimated.mean, Some((count + 1) as f64 / 2.0));
+
+    #[cfg(feature = "serde")]
+    {
+        let json = serde_json::to_value(&report)?;
+        assert_eq!(json["columns"][0]["detected_type"], "numeric");
+    }
+
+    // currency and unit symbols, from number formats or texts
+    let rows = concat!(
+        r#"<row r="1"><c r="A1" s="29"><v>12.5</v></c><c r="B1" s="30"><v>2.5</v></c><c r="C1" s="31"><v>0.25</v></c><c r="D1" t="inlineStr"><is><t>12 kg</t></is></c></row>"#,
+        r#"<row r="2"><c r="A2" t="inlineStr"><is><t>$1,200.00</t></is></c><c r="B2" t="inlineStr"><is><t>3.5 kg</t></is></c><c r="C2" t="inlineStr"><is><t>30 %</t></is></c><c r="D2" t="inlineStr"><is><t>7 lb</t></is></c></row>"#,
+        r#"<row r="3"><c r="A3" t="inlineStr"><is><t>-$3</t></is></c><c r="B3"><v>4</v></c><c r="C3" t="inlineStr"><is><t>n/a</t></is></c><c r="D3" t="inlineStr"><is><t>v1.2</t></is></c></row>"#,
+    );
+    let units = profile(rows, false)?;
+    assert_eq!(
+        units.column(1).unwrap().unit,
+        Some(ColumnUnit {
+            kind: UnitKind::Currency,
+            symbol: "$".to_string(),
+            count: 3
+        })
+    );
+    // numbers without symbol do not break consistency
+    assert_eq!(
+        units.column(2).unwrap().unit,
+        Some(ColumnUnit {
+            kind: UnitKind::Unit,
+            symbol: "kg".to_string(),
+            count: 2
+        })
+    );
+    let percentage = units.column(3).unwrap().unit.clone().unwrap();
+    assert_eq!(
+        (percentage.kind, percentage.count),
+        (UnitKind::Percentage, 2)
+    );
+    // different symbols
+    assert_eq!(units.column(4).unwrap().unit, None);
+
+    println!("columns profiled successfully");
+    Ok(())
+}
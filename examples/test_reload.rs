@@ -0,0 +1,135 @@
+mod common;
+
+use common::{sample_with_moved_workbook, Package, MOVED_WORKBOOK, SAMPLE, SHEET2};
+use excel_reader::prelude::*;
+
+/// Reload a workbook whose file changed, parsing again only the parts that changed,
+/// and estimate the memory held by the loaded parts.
+fn main() -> anyhow::Result<()> {
+    let path =
+        std::env::temp_dir().join(format!("excel_reader_reload_{}.xlsx", std::process::id()));
+    std::fs::copy(SAMPLE, &path)?;
+    let a3 = Coordinate::from_point((3, 1));
+    let b3 = Coordinate::from_point((3, 2));
+
+    // nothing is held before the parts are read
+    let mut excel = Excel::from_path(&path)?;
+    let footprint = excel.memory_footprint();
+    assert_eq!(footprint.shared_strings, 0);
+    assert_eq!(footprint.stylesheet, 0);
+    assert_eq!(footprint.processed_sheets, 0);
+
+    let model = excel.to_processed_model()?;
+    let footprint = excel.memory_footprint();
+    assert!(footprint.shared_strings > 0);
+    assert!(footprint.stylesheet > 0);
+    assert!(footprint.theme > 0);
+    assert!(footprint.workbook > 0);
+    assert!(footprint.processed_sheets > 0);
+    assert!(excel.reload_if_changed()?.is_empty());
+    assert_eq!(excel.memory_footprint(), footprint);
+
+    // a changed sheet is processed again, the other ones are kept
+    let changed_sheet = Package::open(SAMPLE)?.replace_in(
+        SHEET2,
+        r#"<c r="A3" s="16"><v>1</v></c>"#,
+        r#"<c r="A3" s="16"><v>10</v></c>"#,
+    );
+    std::fs::write(&path, changed_sheet.bytes()?)?;
+    assert_eq!(excel.reload_if_changed()?, vec![SHEET2.to_string()]);
+    let reloaded = excel.memory_footprint();
+    assert!(reloaded.processed_sheets > 0);
+    assert!(reloaded.processed_sheets < footprint.processed_sheets);
+    assert_eq!(reloaded.shared_strings, footprint.shared_strings);
+    let updated = excel.to_processed_model()?;
+    assert_eq!(updated.sheets[0], model.sheets[0]);
+    assert_eq!(
+        updated.sheets[1].get_cell(a3).map(|c| &c.value),
+        Some(&CellValueType::Numeric(10.0))
+    );
+    assert_eq!(excel.memory_footprint(), footprint);
+
+    // parts every sheet depends on drop all processed sheets
+    let mut changed_strings = changed_sheet.replace_in(
+        "xl/sharedStrings.xml",
+        "<t>Itsuki1-sheet2</t>",
+        "<t>Renamed</t>",
+    );
+    changed_strings
+        .parts
+        .retain(|(name, _)| name != "docProps/app.xml");
+    std::fs::write(&path, changed_strings.bytes()?)?;
+    assert_eq!(
+        excel.reload_if_changed()?,
+        vec!["docProps/app.xml", "xl/sharedStrings.xml"]
+    );
+    let reloaded = excel.memory_footprint();
+    assert_eq!(reloaded.processed_sheets, 0);
+    assert_eq!(reloaded.shared_strings, 0);
+    assert!(reloaded.stylesheet > 0);
+    let updated = excel.to_processed_model()?;
+    assert_eq!(
+        updated.sheets[1].get_cell(b3).map(|c| c.value.to_string()),
+        Some("Renamed".to_string())
+    );
+    assert_eq!(
+        updated.sheets[1].get_cell(a3).map(|c| &c.value),
+        Some(&CellValueType::Numeric(10.0))
+    );
+
+    // the workbook part is the target of the package relationships, not always xl/workbook.xml
+    let moved_workbook = sample_with_moved_workbook()?;
+    std::fs::write(&path, moved_workbook.bytes()?)?;
+    let mut excel = Excel::from_path(&path)?;
+    excel.to_processed_model()?;
+    let renamed = moved_workbook.replace_in(MOVED_WORKBOOK, "Sheet 1 - Basic", "Renamed");
+    std::fs::write(&path, renamed.bytes()?)?;
+    assert_eq!(excel.reload_if_changed()?, vec![MOVED_WORKBOOK]);
+    assert_eq!(excel.memory_footprint().processed_sheets, 0);
+    assert_eq!(excel.get_sheets()?[0].name, "Renamed");
+    assert_eq!(excel.to_processed_model()?.sheets[0].name, "Renamed");
+
+    // so are the stylesheet and the shared strings
+    let moved_parts = renamed
+        .rename("xl/styles.xml", "xl/main_styles.xml")
+        .rename("xl/sharedStrings.xml", "xl/main_strings.xml")
+        .replace_in(
+            "xl/_rels/main.xml.rels",
+            r#"Target="styles.xml""#,
+            r#"Target="main_styles.xml""#,
+        )
+        .replace_in(
+            "xl/_rels/main.xml.rels",
+            r#"Target="sharedStrings.xml""#,
+            r#"Target="main_strings.xml""#,
+        );
+    std::fs::write(&path, moved_parts.bytes()?)?;
+    excel.reload_if_changed()?;
+    let model = excel.to_processed_model()?;
+    let changed_strings = moved_parts.replace_in(
+        "xl/main_strings.xml",
+        "<t>Itsuki1-sheet2</t>",
+        "<t>Renamed</t>",
+    );
+    std::fs::write(&path, changed_strings.bytes()?)?;
+    assert_eq!(excel.reload_if_changed()?, vec!["xl/main_strings.xml"]);
+    assert_eq!(excel.memory_footprint().shared_strings, 0);
+    let updated = excel.to_processed_model()?;
+    assert_ne!(updated.sheets[1], model.sheets[1]);
+    assert_eq!(
+        updated.sheets[1].get_cell(b3).map(|c| c.value.to_string()),
+        Some("Renamed".to_string())
+    );
+    let changed_styles = changed_strings.edit("xl/main_styles.xml", |text| {
+        text.replacen("<fonts", "<fonts ", 1)
+    });
+    std::fs::write(&path, changed_styles.bytes()?)?;
+    assert_eq!(excel.reload_if_changed()?, vec!["xl/main_styles.xml"]);
+    let footprint = excel.memory_footprint();
+    assert_eq!(footprint.stylesheet, 0);
+    assert_eq!(footprint.processed_sheets, 0);
+
+    std::fs::remove_file(&path)?;
+    println!("workbook reloaded successfully");
+    Ok(())
+}
@@ -0,0 +1,80 @@
+mod common;
+
+use std::io::Cursor;
+
+use common::{Package, SAMPLE, SHEET1};
+use excel_reader::{
+    common_types::Coordinate,
+    excel::Excel,
+    processed::spreadsheet::sheet::worksheet::cell::{
+        cell_property::font::{UnderlineValue, VerticalAlignmentRunValue},
+        cell_value::CellValueType,
+    },
+};
+
+/// Read the formatted runs of rich text cells, from shared strings and inline strings.
+fn main() -> anyhow::Result<()> {
+    let bytes = Package::open(SAMPLE)?
+        .append_rows(
+            SHEET1,
+            concat!(
+                r#"<row r="10"><c r="D10" s="28" t="inlineStr"><is>"#,
+                r#"<r><t xml:space="preserve">Total: </t></r>"#,
+                r#"<r><rPr><b/><color rgb="FFFF0000"/></rPr><t>42</t></r>"#,
+                r#"<r><rPr><i/><vertAlign val="superscript"/><sz val="8"/><rFont val="Arial"/></rPr><t>*</t></r>"#,
+                r#"</is></c></row>"#,
+            ),
+        )
+        .append_cell_formats(r#"<xf numFmtId="0" fontId="5" applyFont="1"/>"#)
+        .bytes()?;
+
+    let mut excel = Excel::from_reader(Cursor::new(bytes))?;
+    let worksheet = excel.get_worksheet_with_name("Sheet 1 - Basic")?;
+    let cell = worksheet.get_cell(Coordinate::from_a1(b"D10").unwrap())?;
+    let CellValueType::RichText(rich_text) = &cell.value else {
+        panic!("{:?} is not rich text", cell.value);
+    };
+    assert_eq!(cell.value.to_string(), "Total: 42*");
+    let runs = &rich_text.runs;
+    assert_eq!(runs.len(), 3);
+
+    // without properties, the font of the cell
+    assert_eq!(runs[0].text, "Total: ");
+    assert_eq!(runs[0].font, cell.property.font);
+    assert_eq!(runs[0].font.size, 20.0);
+
+    // bold and red, with the name and size of the cell font
+    assert_eq!(runs[1].text, "42");
+    assert!(runs[1].font.bold && !runs[1].font.italic);
+    assert_eq!(runs[1].font.color, "#FF0000FF");
+    assert_eq!(runs[1].font.name, "Helvetica Neue");
+    assert_eq!(runs[1].font.size, 20.0);
+
+    assert_eq!(runs[2].text, "*");
+    assert!(runs[2].font.italic && !runs[2].font.bold);
+    assert_eq!(
+        runs[2].font.vertical_alignment,
+        VerticalAlignmentRunValue::Superscript
+    );
+    assert_eq!(
+        (runs[2].font.name.as_str(), runs[2].font.size),
+        ("Arial", 8.0)
+    );
+    assert_eq!(runs[2].font.color, cell.property.font.color);
+
+    // shared strings with runs
+    let mut found = false;
+    for cell in worksheet.get_cells()? {
+        let CellValueType::RichText(rich_text) = &cell.value else {
+            continue;
+        };
+        if cell.value.to_string() == "hlink_inside" {
+            assert_eq!(rich_text.runs[0].font.underline, UnderlineValue::Single);
+            found = true;
+        }
+    }
+    assert!(found);
+
+    println!("rich text runs read successfully");
+    Ok(())
+}
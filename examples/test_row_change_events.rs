@@ -0,0 +1,82 @@
+mod common;
+
+use std::io::Cursor;
+
+use common::sample_with_rows;
+use excel_reader::{
+    common_types::{Coordinate, Dimension},
+    excel::Excel,
+    processed::spreadsheet::sheet::worksheet::{
+        cell::cell_value::{plain_text::PlainText, CellValueType},
+        row_change::RowChangeEvent,
+    },
+};
+
+/// Compare two versions of a sheet by key and stream insert, update and delete events.
+fn main() -> anyhow::Result<()> {
+    let old = concat!(
+        r#"<row r="20"><c r="A20"><v>1</v></c><c r="B20"><v>10</v></c></row>"#,
+        r#"<row r="21"><c r="A21"><v>2</v></c><c r="B21"><v>20</v></c></row>"#,
+        r#"<row r="22"><c r="A22"><v>3</v></c><c r="B22"><v>30</v></c></row>"#,
+        r#"<row r="23"><c r="B23"><v>99</v></c></row>"#,
+    );
+    // 1: same value written differently. 2: deleted. 3: key stored as text, moved and updated. 4: inserted
+    let new = concat!(
+        r#"<row r="20"><c r="A20"><v>1</v></c><c r="B20"><v>1.0E1</v></c></row>"#,
+        r#"<row r="21"><c r="A21"><v>4</v></c><c r="B21"><v>40</v></c></row>"#,
+        r#"<row r="22"><c r="A22" t="inlineStr"><is><t>3</t></is></c><c r="B22"><v>31</v></c></row>"#,
+    );
+
+    let mut previous_excel = Excel::from_reader(Cursor::new(sample_with_rows(old)?))?;
+    let previous = previous_excel.get_worksheet_with_name("Sheet 1 - Basic")?;
+    let mut current_excel = Excel::from_reader(Cursor::new(sample_with_rows(new)?))?;
+    let current = current_excel.get_worksheet_with_name("Sheet 1 - Basic")?;
+
+    let range = Dimension {
+        start: Coordinate::from_point((20, 1)),
+        end: Coordinate::from_point((30, 2)),
+    };
+    let events: Vec<RowChangeEvent> = current
+        .changes_by_key(&previous, range, 1)?
+        .collect::<anyhow::Result<_>>()?;
+    assert_eq!(
+        events,
+        vec![
+            RowChangeEvent::Insert {
+                key: "4".to_string(),
+                row: 21,
+                values: vec![CellValueType::Numeric(4.0), CellValueType::Numeric(40.0)],
+            },
+            RowChangeEvent::Update {
+                key: "3".to_string(),
+                previous_row: 22,
+                row: 22,
+                previous_values: vec![CellValueType::Numeric(3.0), CellValueType::Numeric(30.0)],
+                values: vec![
+                    CellValueType::PlainText(PlainText {
+                        phonetic_properties: None,
+                        phonetic_runs: None,
+                        text: "3".to_string(),
+                    }),
+                    CellValueType::Numeric(31.0),
+                ],
+            },
+            RowChangeEvent::Delete {
+                key: "2".to_string(),
+                previous_row: 21,
+                previous_values: vec![CellValueType::Numeric(2.0), CellValueType::Numeric(20.0)],
+            },
+        ]
+    );
+    let keys: Vec<&str> = events.iter().map(|e| e.key()).collect();
+    assert_eq!(keys, vec!["4", "3", "2"]);
+
+    // no change against itself
+    assert_eq!(current.changes_by_key(&current, range, 1)?.count(), 0);
+
+    // the key column must be within the range
+    assert!(current.changes_by_key(&previous, range, 3).is_err());
+
+    println!("row change events streamed successfully");
+    Ok(())
+}
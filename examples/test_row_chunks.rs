@@ -0,0 +1,69 @@
+use excel_reader::{
+    excel::Excel,
+    processed::spreadsheet::sheet::worksheet::row_stream::{ChunkFraming, RowChunkWriter},
+};
+use serde_json::Value;
+
+/// Stream the rows of a worksheet as length prefixed JSON chunks and as server-sent events.
+fn main() -> anyhow::Result<()> {
+    let mut excel = Excel::from_path("examples/sample.xlsx")?;
+    let sheets = excel.get_sheets()?;
+    let worksheet = excel.get_worksheet_without_cells(&sheets[0])?;
+    let rows = worksheet
+        .rows(&mut excel)?
+        .collect::<anyhow::Result<Vec<_>>>()?;
+    assert!(rows.len() > 2);
+
+    // length prefixed, 2 rows per chunk
+    let bytes = worksheet.rows(&mut excel)?.write_json_chunks(
+        Vec::new(),
+        ChunkFraming::LengthPrefixed,
+        2,
+    )?;
+    let mut chunks: Vec<Value> = vec![];
+    let mut rest = bytes.as_slice();
+    while !rest.is_empty() {
+        let length = u32::from_be_bytes(rest[..4].try_into()?) as usize;
+        chunks.push(serde_json::from_slice(&rest[4..4 + length])?);
+        rest = &rest[4 + length..];
+    }
+    assert_eq!(chunks.len(), rows.len().div_ceil(2));
+    let mut streamed: Vec<Value> = vec![];
+    for (index, chunk) in chunks.iter().enumerate() {
+        assert_eq!(chunk["chunk"], index as u64);
+        let chunk_rows = chunk["rows"].as_array().unwrap();
+        assert!(chunk_rows.len() <= 2);
+        streamed.extend(chunk_rows.iter().cloned());
+    }
+    // same rows as serialized one by one
+    assert_eq!(streamed.len(), rows.len());
+    for (streamed, row) in streamed.iter().zip(&rows) {
+        assert_eq!(streamed, &serde_json::to_value(row)?);
+    }
+
+    // server-sent events, with an end frame
+    let bytes = worksheet.rows(&mut excel)?.write_json_chunks(
+        Vec::new(),
+        ChunkFraming::ServerSentEvents,
+        100,
+    )?;
+    let text = String::from_utf8(bytes)?;
+    let frames: Vec<&str> = text.split_terminator("\n\n").collect();
+    assert_eq!(frames.len(), 2);
+    assert!(frames[0].starts_with("event: rows\ndata: {\"chunk\":0,"));
+    assert_eq!(frames[0].lines().count(), 2);
+    let end: Value = serde_json::from_str(frames[1].strip_prefix("event: end\ndata: ").unwrap())?;
+    assert_eq!(end["chunks"], 1);
+    assert_eq!(end["rows"], rows.len() as u64);
+
+    // rows pushed one at a time
+    let mut writer = RowChunkWriter::new(Vec::new(), ChunkFraming::LengthPrefixed, 0);
+    for row in rows.iter().take(3) {
+        writer.push(row.clone())?;
+    }
+    assert_eq!(writer.chunk_count(), 3);
+    assert!(!writer.finish()?.is_empty());
+
+    println!("rows streamed as JSON chunks successfully");
+    Ok(())
+}
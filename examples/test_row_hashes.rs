@@ -0,0 +1,73 @@
+mod common;
+
+use std::io::Cursor;
+
+use common::sample_with_rows;
+use excel_reader::{
+    common_types::{Coordinate, Dimension},
+    excel::Excel,
+    processed::spreadsheet::sheet::worksheet::row_hash::{RowChanges, RowHash},
+};
+
+fn row_hashes(rows: &str, columns: &[u64]) -> anyhow::Result<Vec<RowHash>> {
+    let mut excel = Excel::from_reader(Cursor::new(sample_with_rows(rows)?))?;
+    let worksheet = excel.get_worksheet_with_name("Sheet 1 - Basic")?;
+    let range = Dimension {
+        start: Coordinate::from_point((20, 1)),
+        end: Coordinate::from_point((30, 3)),
+    };
+    worksheet.row_hashes(range, columns)
+}
+
+/// Detect rows inserted, changed and deleted between two versions of a sheet.
+fn main() -> anyhow::Result<()> {
+    let old = concat!(
+        r#"<row r="20"><c r="A20" t="inlineStr"><is><t>id1</t></is></c><c r="B20"><v>10</v></c><c r="C20" t="inlineStr"><is><t>x</t></is></c></row>"#,
+        r#"<row r="21"><c r="A21" t="inlineStr"><is><t>id2</t></is></c><c r="B21"><v>20</v></c><c r="C21" t="inlineStr"><is><t>y</t></is></c></row>"#,
+        r#"<row r="22"><c r="A22" t="inlineStr"><is><t>id3</t></is></c><c r="B22"><v>30</v></c><c r="C22" t="inlineStr"><is><t>z</t></is></c></row>"#,
+        r#"<row r="23"><c r="A23" t="inlineStr"><is><t>id4</t></is></c><c r="B23"><v>40</v></c><c r="C23" t="inlineStr"><is><t>w</t></is></c></row>"#,
+    );
+    // id1: same values written differently, id2: deleted, id3: moved, id4: changed, id5: inserted
+    let new = concat!(
+        r#"<row r="20"><c r="A20" t="inlineStr"><is><t>id1</t></is></c><c r="B20" s="1"><f>5*2</f><v>1.0E1</v></c><c r="C20" t="inlineStr"><is><t>x </t></is></c></row>"#,
+        r#"<row r="21"><c r="A21" t="inlineStr"><is><t>id3</t></is></c><c r="B21"><v>30</v></c><c r="C21" t="inlineStr"><is><t>z</t></is></c></row>"#,
+        r#"<row r="22"><c r="A22" t="inlineStr"><is><t>id4</t></is></c><c r="B22"><v>41</v></c><c r="C22" t="inlineStr"><is><t>w</t></is></c></row>"#,
+        r#"<row r="23"><c r="A23" t="inlineStr"><is><t>id5</t></is></c><c r="B23"><v>50</v></c><c r="C23" t="inlineStr"><is><t>v</t></is></c></row>"#,
+        r#"<row r="24"><c r="D24"><v>1</v></c></row>"#,
+    );
+
+    let old_hashes = row_hashes(old, &[])?;
+    let new_hashes = row_hashes(new, &[])?;
+    assert_eq!(old_hashes.len(), 4);
+    // row 24 has no value within the range
+    assert_eq!(new_hashes.len(), 4);
+    assert_eq!(old_hashes[0].hash, new_hashes[0].hash);
+    assert_eq!(old_hashes[2].hash, new_hashes[1].hash);
+
+    // hashes are stable across runs and versions
+    assert_eq!(row_hashes(old, &[])?, old_hashes);
+    assert_eq!(old_hashes[0].hash, 9898719118974414361);
+
+    let changes = RowChanges::between(&old_hashes, &new_hashes);
+    assert_eq!(changes.unchanged, vec![(20, 20), (22, 21)]);
+    assert_eq!(changes.deleted, vec![21]);
+    assert_eq!(changes.changed, vec![(23, 22)]);
+    assert_eq!(changes.inserted, vec![23]);
+    assert!(!changes.is_empty());
+
+    // hashing the identifier column only: id4 is unchanged
+    let changes = RowChanges::between(&row_hashes(old, &[1])?, &row_hashes(new, &[1])?);
+    assert_eq!(changes.unchanged, vec![(20, 20), (22, 21), (23, 22)]);
+    assert_eq!(changes.deleted, vec![21]);
+    assert_eq!(changes.inserted, vec![23]);
+    assert!(changes.changed.is_empty());
+
+    // the order of the columns matters
+    let swapped = row_hashes(old, &[3, 1])?;
+    assert_ne!(swapped[0].hash, old_hashes[0].hash);
+
+    assert!(RowChanges::between(&old_hashes, &old_hashes).is_empty());
+
+    println!("row hashes compared successfully");
+    Ok(())
+}
@@ -0,0 +1,56 @@
+mod common;
+
+use std::io::Cursor;
+
+use common::{Package, SAMPLE, SHEET1};
+use excel_reader::excel::Excel;
+
+/// Read the height, visibility, outline level and formatting of rows.
+fn main() -> anyhow::Result<()> {
+    let sheet = r#"<?xml version="1.0" encoding="UTF-8"?><worksheet xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main"><dimension ref="A1:A6"/><sheetData>
+<row r="1" spans="1:1"><c r="A1"><v>1</v></c></row>
+<row r="2" ht="30" customHeight="1"><c r="A2"><v>2</v></c></row>
+<row r="3" outlineLevel="1" hidden="1"><c r="A3"><v>3</v></c></row>
+<row r="4" outlineLevel="1" hidden="1"><c r="A4"><v>4</v></c></row>
+<row r="5" collapsed="1" s="2" customFormat="1" thickBot="1"/>
+<row r="6"><c r="A6"><v>6</v></c></row>
+</sheetData></worksheet>"#;
+
+    let bytes = Package::open(SAMPLE)?.replace(SHEET1, sheet).bytes()?;
+
+    let mut excel = Excel::from_reader(Cursor::new(bytes))?;
+    let sheets = excel.get_sheets()?;
+    let worksheet = excel.get_worksheet(&sheets[0])?;
+
+    // written only to hold cells
+    let first = worksheet.row_properties(1).unwrap();
+    assert_eq!(first.height, None);
+    assert!(!first.hidden && first.outline_level == 0);
+    assert!(!first.is_customized());
+
+    let tall = worksheet.row_properties(2).unwrap();
+    assert_eq!(tall.height, Some(30.0));
+    assert!(tall.custom_height);
+
+    let grouped = worksheet.row_properties(3).unwrap();
+    assert!(grouped.hidden);
+    assert_eq!(grouped.outline_level, 1);
+
+    let summary = worksheet.row_properties(5).unwrap();
+    assert!(summary.collapsed && summary.custom_format && summary.thick_bottom);
+    assert_eq!(summary.style_index, Some(2));
+
+    // not written in the file
+    assert!(worksheet.row_properties(7).is_none());
+
+    let customized: Vec<u64> = worksheet.customized_rows().map(|r| r.row).collect();
+    assert_eq!(customized, vec![2, 3, 4, 5]);
+
+    // row attributes are read without the cells too
+    let shape = excel.get_worksheet_shape(&sheets[0])?;
+    assert_eq!(shape.row_properties(2), Some(tall));
+    assert_eq!(shape.customized_rows().count(), 4);
+
+    println!("row properties read successfully");
+    Ok(())
+}
@@ -0,0 +1,114 @@
+mod common;
+
+use std::collections::HashMap;
+use std::io::Cursor;
+
+use common::{Package, SAMPLE, SHEET1};
+use excel_reader::{
+    excel::Excel,
+    processed::spreadsheet::sheet::worksheet::{row::Row, row_sample::SampleStrategy},
+};
+
+/// Sample workbook whose first sheet holds 100 rows: a category in A (70 a, 25 b, 5 c) and the row index in B.
+fn sample_with_categories() -> anyhow::Result<Vec<u8>> {
+    let mut rows = String::new();
+    for index in 1..=100 {
+        let category = match index % 20 {
+            0 => "c",
+            1..=5 => "b",
+            _ => "a",
+        };
+        rows.push_str(&format!(
+            r#"<row r="{0}"><c r="A{0}" t="inlineStr"><is><t>{1}</t></is></c><c r="B{0}"><v>{0}</v></c></row>"#,
+            index, category
+        ));
+    }
+
+    Package::open(SAMPLE)?.replace_rows(SHEET1, &rows).bytes()
+}
+
+fn indexes(rows: &[Row]) -> Vec<u64> {
+    rows.iter().map(|r| r.index).collect()
+}
+
+fn category_counts(rows: &[Row]) -> HashMap<String, usize> {
+    let mut counts = HashMap::new();
+    for row in rows {
+        *counts.entry(row.cells[0].value.to_string()).or_default() += 1;
+    }
+    counts
+}
+
+/// Pick rows of a large sheet without reading all its cells.
+fn main() -> anyhow::Result<()> {
+    let mut excel = Excel::from_reader(Cursor::new(sample_with_categories()?))?;
+    let sheets = excel.get_sheets()?;
+    let worksheet = excel.get_worksheet_without_cells(&sheets[0])?;
+
+    // head
+    let head = worksheet.sample_rows(&mut excel, 3, SampleStrategy::Head)?;
+    assert_eq!(indexes(&head), vec![1, 2, 3]);
+    assert_eq!(head[0].cells.len(), 2);
+
+    // random: reproducible with a seed, ordered and without duplicates
+    let random = worksheet.sample_rows(&mut excel, 10, SampleStrategy::Random { seed: 42 })?;
+    assert_eq!(random.len(), 10);
+    let random_indexes = indexes(&random);
+    assert!(random_indexes.windows(2).all(|w| w[0] < w[1]));
+    assert_eq!(
+        indexes(&worksheet.sample_rows(&mut excel, 10, SampleStrategy::Random { seed: 42 })?),
+        random_indexes
+    );
+    assert_ne!(
+        indexes(&worksheet.sample_rows(&mut excel, 10, SampleStrategy::Random { seed: 7 })?),
+        random_indexes
+    );
+    assert_ne!(random_indexes, (1..=10).collect::<Vec<u64>>());
+    // values are read as with `rows`
+    for row in &random {
+        assert_eq!(row.cells[1].value.to_string(), row.index.to_string());
+    }
+
+    // stratified: every category is represented, in proportion to its rows
+    let stratified = SampleStrategy::Stratified {
+        column: 1,
+        seed: 42,
+    };
+    let sample = worksheet.sample_rows(&mut excel, 20, stratified)?;
+    assert_eq!(sample.len(), 20);
+    let counts = category_counts(&sample);
+    assert_eq!(counts["a"], 14);
+    assert_eq!(counts["b"], 5);
+    assert_eq!(counts["c"], 1);
+    assert_eq!(
+        indexes(&worksheet.sample_rows(&mut excel, 20, stratified)?),
+        indexes(&sample)
+    );
+
+    // rare values are kept even in small samples
+    let counts = category_counts(&worksheet.sample_rows(&mut excel, 3, stratified)?);
+    assert_eq!((counts["a"], counts["b"], counts["c"]), (1, 1, 1));
+    let counts = category_counts(&worksheet.sample_rows(&mut excel, 2, stratified)?);
+    assert_eq!((counts["a"], counts["b"]), (1, 1));
+
+    // more rows asked than available
+    for strategy in [
+        SampleStrategy::Head,
+        SampleStrategy::Random { seed: 1 },
+        stratified,
+    ] {
+        let all = worksheet.sample_rows(&mut excel, 1000, strategy)?;
+        assert_eq!(indexes(&all), (1..=100).collect::<Vec<u64>>());
+        assert!(worksheet.sample_rows(&mut excel, 0, strategy)?.is_empty());
+    }
+
+    // sampling an iterator of rows
+    let merged = worksheet
+        .rows(&mut excel)?
+        .propagate_merged_values()
+        .sample(5, SampleStrategy::Random { seed: 42 })?;
+    assert_eq!(merged.len(), 5);
+
+    println!("rows sampled successfully");
+    Ok(())
+}
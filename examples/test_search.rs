@@ -0,0 +1,209 @@
+mod common;
+
+use common::{Package, SAMPLE, SHEET1, SHEET1_RELS, WORKBOOK};
+use excel_reader::prelude::*;
+
+fn texts(found: &[SearchMatch]) -> Vec<&str> {
+    found.iter().map(|m| m.text.as_str()).collect()
+}
+
+/// Search the cells of every sheet, and optionally formulas, defined names, headers and footers.
+fn main() -> anyhow::Result<()> {
+    let mut excel = Excel::from_path(SAMPLE)?;
+
+    // plain text, case insensitive by default
+    let found = excel.search_all("itsuki1", &SearchOptions::default())?;
+    assert_eq!(
+        texts(&found),
+        vec!["Itsuki1", "Itsuki1-sheet2", "Itsuki1-sheet3"]
+    );
+    assert_eq!(found[0].sheet_name.as_deref(), Some("Sheet 1 - Basic"));
+    assert_eq!(
+        found[0].location,
+        SearchLocation::Cell(Coordinate::from_point((3, 2)))
+    );
+    assert_eq!(
+        found[2].sheet_name.as_deref(),
+        Some("Sheet 3 - Custom Colors_Font")
+    );
+
+    let options = SearchOptions {
+        case_sensitive: true,
+        ..Default::default()
+    };
+    assert!(excel.search_all("ITSUKI", &options)?.is_empty());
+    assert_eq!(excel.search_all("itsuki", &options)?.len(), 7);
+
+    // regular expressions, plain text patterns are escaped
+    let options = SearchOptions {
+        regex: true,
+        case_sensitive: true,
+        ..Default::default()
+    };
+    assert_eq!(
+        texts(&excel.search_all(r"^itsuki\d-sheet2$", &options)?),
+        vec!["itsuki2-sheet2", "itsuki3-sheet2", "itsuki4-sheet2"]
+    );
+    assert!(excel
+        .search_all(r"^itsuki\d", &SearchOptions::default())?
+        .is_empty());
+    assert!(excel
+        .search_all(
+            "(",
+            &SearchOptions {
+                regex: true,
+                ..Default::default()
+            }
+        )
+        .is_err());
+
+    // numbers are matched as displayed by `CellValueType::to_string`
+    let found = excel.search_all("100", &SearchOptions::default())?;
+    assert_eq!(
+        found[0].location,
+        SearchLocation::Cell(Coordinate::from_point((9, 2)))
+    );
+
+    // headers and footers
+    let options = SearchOptions {
+        include_headers_footers: true,
+        ..Default::default()
+    };
+    let found = excel.search_all("helvetica", &options)?;
+    assert_eq!(found.len(), excel.get_sheets()?.len());
+    assert_eq!(
+        found[0].location,
+        SearchLocation::HeaderFooter {
+            kind: "oddFooter".to_string()
+        }
+    );
+    assert!(excel
+        .search_all("helvetica", &SearchOptions::default())?
+        .is_empty());
+
+    // formulas and defined names
+    let mut excel = Package::open(SAMPLE)?
+        .append_rows(
+            SHEET1,
+            r#"<row r="10"><c r="A10"><f>SUM(A3:A7)</f><v>15</v></c></row>"#,
+        )
+        .replace_in(
+            WORKBOOK,
+            "</sheets>",
+            r#"</sheets><definedNames><definedName name="Total">SUM('Sheet 1 - Basic'!$A$3:$A$7)</definedName><definedName name="Local" localSheetId="1">SUM('Sheet 2 - Custom grid lines'!$A$1)</definedName></definedNames>"#,
+        )
+        .excel()?;
+    assert!(excel
+        .search_all("sum", &SearchOptions::default())?
+        .is_empty());
+    let options = SearchOptions {
+        include_formulas: true,
+        include_defined_names: true,
+        parallel: false,
+        ..Default::default()
+    };
+    let found = excel.search_all("sum", &options)?;
+    assert_eq!(
+        found,
+        vec![
+            SearchMatch {
+                sheet_name: Some("Sheet 1 - Basic".to_string()),
+                location: SearchLocation::Formula(Coordinate::from_point((10, 1))),
+                text: "SUM(A3:A7)".to_string(),
+            },
+            SearchMatch {
+                sheet_name: None,
+                location: SearchLocation::DefinedName {
+                    name: "Total".to_string()
+                },
+                text: "SUM('Sheet 1 - Basic'!$A$3:$A$7)".to_string(),
+            },
+            SearchMatch {
+                sheet_name: Some("Sheet 2 - Custom grid lines".to_string()),
+                location: SearchLocation::DefinedName {
+                    name: "Local".to_string()
+                },
+                text: "SUM('Sheet 2 - Custom grid lines'!$A$1)".to_string(),
+            },
+        ]
+    );
+    // the calculated value of a formula is a cell value
+    let found = excel.search_all("15", &options)?;
+    assert_eq!(
+        found[0].location,
+        SearchLocation::Cell(Coordinate::from_point((10, 1)))
+    );
+
+    // a single worksheet, in parallel or not
+    let sheets = excel.get_sheets()?;
+    let worksheet = excel.get_worksheet(&sheets[1])?;
+    let pattern = regex::Regex::new("sheet2")?;
+    let sequential = worksheet.search(
+        &pattern,
+        &SearchOptions {
+            parallel: false,
+            ..Default::default()
+        },
+    )?;
+    assert_eq!(sequential.len(), 5);
+    assert_eq!(
+        worksheet.search(&pattern, &SearchOptions::default())?,
+        sequential
+    );
+
+    // comments, ordered by commented cell
+    let rels = r#"<?xml version="1.0" encoding="UTF-8"?>
+<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships"><Relationship Id="rId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/hyperlink" Target="http://www.google.com" TargetMode="External"/><Relationship Id="rId3" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/comments" Target="../comments1.xml"/></Relationships>"#;
+    let comments = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<comments xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main"><authors><author>Reviewer</author></authors><commentList>
+<comment ref="B4" authorId="0"><text><t>Check the tax</t></text></comment>
+<comment ref="A3" authorId="0"><text><t>Tax included</t></text></comment>
+</commentList></comments>"#;
+    let mut excel = Package::open(SAMPLE)?
+        .replace(SHEET1_RELS, rels)
+        .replace("xl/comments1.xml", comments)
+        .excel()?;
+    assert!(excel
+        .search_all("tax", &SearchOptions::default())?
+        .is_empty());
+    let options = SearchOptions {
+        include_comments: true,
+        ..Default::default()
+    };
+    let found = excel.search_all("tax", &options)?;
+    assert_eq!(
+        found,
+        vec![
+            SearchMatch {
+                sheet_name: Some("Sheet 1 - Basic".to_string()),
+                location: SearchLocation::Comment(Coordinate::from_point((3, 1))),
+                text: "Tax included".to_string(),
+            },
+            SearchMatch {
+                sheet_name: Some("Sheet 1 - Basic".to_string()),
+                location: SearchLocation::Comment(Coordinate::from_point((4, 2))),
+                text: "Check the tax".to_string(),
+            },
+        ]
+    );
+
+    // worksheets loaded concurrently, with the same matches
+    assert_eq!(excel.search_all_parallel("tax", &options)?, found);
+    let options = SearchOptions {
+        include_comments: true,
+        include_headers_footers: true,
+        include_defined_names: true,
+        ..Default::default()
+    };
+    let found = excel.search_all("itsuki", &options)?;
+    assert!(!found.is_empty());
+    assert_eq!(excel.search_all_parallel("itsuki", &options)?, found);
+    let sequential = SearchOptions {
+        parallel: false,
+        ..options
+    };
+    assert_eq!(excel.search_all_parallel("itsuki", &sequential)?, found);
+
+    println!("search successfully");
+    Ok(())
+}
@@ -0,0 +1,145 @@
+mod common;
+
+use common::{Package, SAMPLE, SHEET1, WORKBOOK};
+use excel_reader::{
+    prelude::*,
+    processed::spreadsheet::{
+        sheet_name::{
+            normalize_sheet_name, sanitize_sheet_name, sheet_names_equal, validate_sheet_name,
+        },
+        sheet_reference::SheetReferenceLocation,
+    },
+};
+
+/// Compare, validate and sanitize sheet names, and find the references to sheets that are renamed.
+fn main() -> anyhow::Result<()> {
+    // compared case insensitively, regardless of how the characters are composed
+    assert_eq!(normalize_sheet_name("Sheet 1 - Basic"), "sheet 1 - basic");
+    assert!(sheet_names_equal("Café", "CAFE\u{301}"));
+    assert!(!sheet_names_equal("Sheet1", "Sheet 1"));
+
+    assert!(validate_sheet_name("Sheet 1 - Basic").is_ok());
+    assert!(validate_sheet_name(&"a".repeat(31)).is_ok());
+    assert!(validate_sheet_name("").is_err());
+    assert!(validate_sheet_name(&"a".repeat(32)).is_err());
+    assert!(validate_sheet_name("Q1/Q2").is_err());
+    assert!(validate_sheet_name("[Data]").is_err());
+    assert!(validate_sheet_name("'Data").is_err());
+    assert!(validate_sheet_name("history").is_err());
+    // a composed character counts once
+    assert!(validate_sheet_name(&format!("{}e\u{301}", "a".repeat(30))).is_ok());
+
+    assert_eq!(sanitize_sheet_name("Q1/Q2: *draft*"), "Q1_Q2_ _draft_");
+    assert_eq!(sanitize_sheet_name("'Data'"), "Data");
+    assert_eq!(sanitize_sheet_name(&"a".repeat(40)), "a".repeat(31));
+    assert_eq!(sanitize_sheet_name(""), "Sheet");
+    assert_eq!(sanitize_sheet_name("History"), "Sheet");
+    for name in ["Q1/Q2: *draft*", "'''", "History", "[Data]"] {
+        assert!(validate_sheet_name(&sanitize_sheet_name(name)).is_ok());
+    }
+
+    // sheets are retrieved by name as Excel compares them
+    let mut excel = Excel::from_path(SAMPLE)?;
+    let worksheet = excel.get_worksheet_with_name("SHEET 1 - BASIC")?;
+    assert_eq!(worksheet.name, "Sheet 1 - Basic");
+    assert!(excel.get_duplicate_sheet_names()?.is_empty());
+
+    // sheets sharing a name are listed, but cannot be retrieved by that name
+    let mut excel = Package::open(SAMPLE)?
+        .replace_in(
+            WORKBOOK,
+            r#"name="Sheet 2 - Custom grid lines""#,
+            r#"name="sheet 1 - basic""#,
+        )
+        .excel()?;
+    let duplicates = excel.get_duplicate_sheet_names()?;
+    assert_eq!(duplicates.len(), 1);
+    assert_eq!(
+        duplicates[0].iter().map(|s| s.sheet_id).collect::<Vec<_>>(),
+        vec![1, 2]
+    );
+    let Err(error) = excel.get_worksheet_with_name("Sheet 1 - Basic") else {
+        panic!("sheets sharing a name are retrieved by name");
+    };
+    assert!(error.to_string().contains("sheet ids: 1, 2"));
+    assert_eq!(
+        excel
+            .get_worksheet_with_name("Sheet 3 - Custom Colors_Font")?
+            .name,
+        "Sheet 3 - Custom Colors_Font"
+    );
+
+    // formulas, data validations and defined names referring to a renamed sheet
+    let mut excel = Package::open(SAMPLE)?
+        .append_rows(
+            SHEET1,
+            r#"<row r="10"><c r="A10"><f>SUM('Sheet 2 - Custom grid lines'!A1:A3)+"Sheet 2 - Custom grid lines!A1"</f><v>0</v></c><c r="B10"><f>B9*2</f><v>200</v></c></row>"#,
+        )
+        .replace_in(
+            SHEET1,
+            "<hyperlinks>",
+            r#"<dataValidations count="1"><dataValidation type="list" sqref="C2:C9"><formula1>'SHEET 2 - CUSTOM GRID LINES'!$A$1:$A$3</formula1></dataValidation></dataValidations><hyperlinks>"#,
+        )
+        .replace_in(
+            WORKBOOK,
+            "</sheets>",
+            r#"</sheets><definedNames><definedName name="Grid">'Sheet 2 - Custom grid lines'!$A$1</definedName><definedName name="Basic">'Sheet 1 - Basic'!$A$2</definedName></definedNames>"#,
+        )
+        .excel()?;
+    let renames = vec![(
+        "Sheet 2 - Custom grid lines".to_string(),
+        "Grid".to_string(),
+    )];
+    let references = excel.find_sheet_rename_references(&renames)?;
+    assert_eq!(references.len(), 3);
+
+    assert_eq!(
+        references[0].location,
+        SheetReferenceLocation::Cell {
+            sheet_name: "Sheet 1 - Basic".to_string(),
+            coordinate: Coordinate::from_point((10, 1)),
+        }
+    );
+    assert_eq!(
+        references[0].referenced_sheets,
+        vec!["Sheet 2 - Custom grid lines"]
+    );
+    // string literals are left untouched
+    assert_eq!(
+        references[0].renamed_text,
+        r#"SUM(Grid!A1:A3)+"Sheet 2 - Custom grid lines!A1""#
+    );
+
+    assert_eq!(
+        references[1].location,
+        SheetReferenceLocation::DataValidation {
+            sheet_name: "Sheet 1 - Basic".to_string(),
+            sqref: "C2:C9".to_string(),
+        }
+    );
+    assert_eq!(
+        references[1].referenced_sheets,
+        vec!["SHEET 2 - CUSTOM GRID LINES"]
+    );
+    assert_eq!(references[1].renamed_text, "Grid!$A$1:$A$3");
+
+    assert_eq!(
+        references[2].location,
+        SheetReferenceLocation::DefinedName {
+            name: "Grid".to_string()
+        }
+    );
+    assert_eq!(references[2].text, "'Sheet 2 - Custom grid lines'!$A$1");
+    assert_eq!(references[2].renamed_text, "Grid!$A$1");
+
+    // new names are quoted when needed
+    let renames = vec![(
+        "Sheet 2 - Custom grid lines".to_string(),
+        "Bob's R1C1".to_string(),
+    )];
+    let references = excel.find_sheet_rename_references(&renames)?;
+    assert_eq!(references[2].renamed_text, "'Bob''s R1C1'!$A$1");
+
+    println!("sheet names checked successfully");
+    Ok(())
+}
@@ -0,0 +1,55 @@
+mod common;
+
+use std::io::Cursor;
+
+use common::{Package, SAMPLE, SHEET1};
+use excel_reader::excel::Excel;
+
+/// Read the protection of a password protected sheet allowing some operations, and of a sheet without protection.
+fn main() -> anyhow::Result<()> {
+    let sheet = r#"<?xml version="1.0" encoding="UTF-8"?><worksheet xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main"><dimension ref="A1"/><sheetData>
+<row r="1"><c r="A1"><v>1</v></c></row>
+</sheetData><sheetProtection algorithmName="SHA-512" hashValue="Zm9vYmFy" saltValue="c2FsdA==" spinCount="100000" sheet="1" objects="1" scenarios="1" formatColumns="0" sort="0" autoFilter="0"/></worksheet>"#;
+
+    let bytes = Package::open(SAMPLE)?.replace(SHEET1, sheet).bytes()?;
+
+    let mut excel = Excel::from_reader(Cursor::new(bytes))?;
+    let sheets = excel.get_sheets()?;
+    let worksheet = excel.get_worksheet_without_cells(&sheets[0])?;
+
+    let protection = worksheet.protection.as_ref().unwrap();
+    assert!(protection.sheet);
+    assert!(protection.has_password());
+    assert_eq!(protection.algorithm_name.as_deref(), Some("SHA-512"));
+    assert_eq!(protection.hash_value.as_deref(), Some("Zm9vYmFy"));
+    assert_eq!(protection.salt_value.as_deref(), Some("c2FsdA=="));
+    assert_eq!(protection.spin_count, Some(100000));
+    assert_eq!(protection.legacy_password, None);
+
+    // explicitly set
+    assert!(protection.objects);
+    assert!(!protection.format_columns);
+    assert!(!protection.sort);
+    // schema defaults
+    assert!(protection.format_cells);
+    assert!(protection.delete_rows);
+    assert!(!protection.select_locked_cells);
+    assert_eq!(
+        protection.locked_operations(),
+        vec![
+            "objects",
+            "scenarios",
+            "formatCells",
+            "formatRows",
+            "insertColumns",
+            "insertRows",
+            "insertHyperlinks",
+            "deleteColumns",
+            "deleteRows",
+            "pivotTables"
+        ]
+    );
+
+    println!("sheet protection read successfully");
+    Ok(())
+}
@@ -0,0 +1,97 @@
+mod common;
+
+use std::io::Cursor;
+
+use common::{Package, EMPTY_SHEET, SAMPLE, SHEET1, SHEET2};
+use excel_reader::{
+    common_types::Coordinate,
+    excel::Excel,
+    processed::spreadsheet::sheet::worksheet::{csv_export::CsvOptions, sheet_view::SheetViewType},
+};
+
+/// Read the display settings of a right to left sheet, and of a sheet without any.
+fn main() -> anyhow::Result<()> {
+    let sheet = r#"<?xml version="1.0" encoding="UTF-8"?><worksheet xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main"><dimension ref="B1:D2"/><sheetViews><sheetView rightToLeft="1" showGridLines="0" showZeros="0" tabSelected="1" topLeftCell="B1" view="pageLayout" zoomScale="85" zoomScaleNormal="85" workbookViewId="0"><pane ySplit="1" topLeftCell="B2" activePane="bottomLeft" state="frozen"/><selection pane="bottomLeft" activeCell="C2" sqref="C2"/></sheetView></sheetViews><sheetFormatPr defaultRowHeight="15"/><cols><col min="2" max="2" width="20" customWidth="1"/></cols><sheetData>
+<row r="1"><c r="B1" t="inlineStr"><is><t>اسم</t></is></c><c r="C1" t="inlineStr"><is><t>عمر</t></is></c><c r="D1" t="inlineStr"><is><t>مدينة</t></is></c></row>
+<row r="2"><c r="B2"><v>1</v></c><c r="C2"><v>2</v></c><c r="D2"><v>3</v></c></row>
+<row r="3"><c r="B3"><v>0</v></c><c r="C3"><f>B3*2</f><v>0</v></c><c r="D3" t="str"><f>"0"</f><v>0</v></c></row>
+</sheetData></worksheet>"#;
+
+    let bytes = Package::open(SAMPLE)?
+        .replace(SHEET1, sheet)
+        .replace(SHEET2, EMPTY_SHEET)
+        .bytes()?;
+
+    let mut excel = Excel::from_reader(Cursor::new(bytes))?;
+    let sheets = excel.get_sheets()?;
+    let worksheet = excel.get_worksheet_without_cells(&sheets[0])?;
+
+    let view = worksheet.sheet_view();
+    assert!(view.right_to_left);
+    assert!(worksheet.is_right_to_left());
+    assert!(!view.show_grid_lines);
+    assert!(view.show_headings);
+    assert!(!view.show_zeros);
+    assert!(!view.show_formulas);
+    assert!(view.tab_selected);
+    assert_eq!(view.top_left_cell, Coordinate::from_a1(b"B1"));
+    assert_eq!(view.view, SheetViewType::PageLayout);
+    assert_eq!(view.zoom_scale, 85);
+    assert!(!worksheet.show_grid_lines());
+    assert!(worksheet.show_row_col_headers());
+    assert!(!worksheet.show_zeros());
+
+    // zeros written blank along formatted values, texts kept
+    let worksheet_with_cells = excel.get_worksheet(&sheets[0])?;
+    let csv = worksheet_with_cells.to_csv(vec![], &CsvOptions::default())?;
+    assert!(String::from_utf8(csv)?.ends_with("1,2,3\n,,0\n"));
+    let plain = CsvOptions {
+        formatted: false,
+        ..CsvOptions::default()
+    };
+    let csv = worksheet_with_cells.to_csv(vec![], &plain)?;
+    assert!(String::from_utf8(csv)?.ends_with("1,2,3\n0,0,0\n"));
+
+    // the pane is still read along the attributes
+    assert_eq!(worksheet.frozen_row_count(), 1);
+
+    // column B, the first column holding data, is displayed on the right
+    assert_eq!(worksheet.visual_column_order(), vec![4, 3, 2]);
+    assert_eq!(view.visual_column_order(1, 2), vec![2, 1]);
+
+    // mirrored positions of cells within columns A to D
+    let total_width: f64 = (1..=4).map(|col| worksheet.column_width(col)).sum();
+    let (x, _) = worksheet.cell_offset(Coordinate::from_a1(b"A1").unwrap());
+    let width_a = worksheet.column_width(1);
+    assert_eq!(
+        view.visual_x(x, width_a, total_width),
+        total_width - width_a
+    );
+    let (x, _) = worksheet.cell_offset(Coordinate::from_a1(b"D1").unwrap());
+    assert_eq!(
+        view.visual_x(x, worksheet.column_width(4), total_width),
+        0.0
+    );
+
+    // the layout read without cells holds the view as well
+    let shape = excel.get_worksheet_shape(&sheets[0])?;
+    assert!(shape.is_right_to_left());
+
+    // defaults of Excel
+    let worksheet = excel.get_worksheet_without_cells(&sheets[1])?;
+    let view = worksheet.sheet_view();
+    assert!(!view.right_to_left);
+    assert!(view.show_grid_lines);
+    assert!(view.show_zeros);
+    assert!(worksheet.show_grid_lines());
+    assert!(worksheet.show_zeros());
+    assert_eq!(view.top_left_cell, None);
+    assert_eq!(view.view, SheetViewType::Normal);
+    assert_eq!(view.zoom_scale, 100);
+    assert_eq!(view.visual_column_order(1, 3), vec![1, 2, 3]);
+    assert_eq!(view.visual_x(10.0, 5.0, 100.0), 10.0);
+    assert!(worksheet.visual_column_order().is_empty());
+
+    println!("sheet view read successfully");
+    Ok(())
+}
@@ -0,0 +1,96 @@
+mod common;
+
+use common::{Package, SAMPLE, SHEET1};
+use excel_reader::prelude::*;
+
+/// Find, and optionally convert, numbers and dates stored as text.
+fn main() -> anyhow::Result<()> {
+    let mut excel = Package::open(SAMPLE)?
+        .append_rows(
+            SHEET1,
+            concat!(
+                r#"<row r="10"><c r="A10" t="inlineStr"><is><t>1,234.5</t></is></c>"#,
+                r#"<c r="B10" t="inlineStr"><is><t>12%</t></is></c>"#,
+                r#"<c r="C10" t="inlineStr"><is><t>2024-01-31</t></is></c>"#,
+                r#"<c r="D10" t="inlineStr"><is><t>00123</t></is></c>"#,
+                r#"<c r="E10" t="inlineStr"><is><t>12,34</t></is></c>"#,
+                r#"<c r="F10" t="str"><f>"7"</f><v>7</v></c></row>"#,
+            ),
+        )
+        .replace_in(
+            SHEET1,
+            "</worksheet>",
+            r#"<ignoredErrors><ignoredError sqref="D10" numberStoredAsText="1"/></ignoredErrors></worksheet>"#,
+        )
+        .excel()?;
+    let sheets = excel.get_sheets()?;
+    let mut worksheet = excel.get_worksheet(&sheets[0])?;
+
+    // text that reads as a number or a date, formulas and misplaced separators left out
+    let found = worksheet.find_numbers_stored_as_text()?;
+    let found: Vec<(String, String, CellValueType, bool)> = found
+        .into_iter()
+        .map(|f| (f.coordinate.to_a1(), f.text, f.value, f.ignored))
+        .collect();
+    assert_eq!(
+        found,
+        vec![
+            (
+                "A10".to_string(),
+                "1,234.5".to_string(),
+                CellValueType::Numeric(1234.5),
+                false
+            ),
+            (
+                "B10".to_string(),
+                "12%".to_string(),
+                CellValueType::Numeric(0.12),
+                false
+            ),
+            (
+                "C10".to_string(),
+                "2024-01-31".to_string(),
+                CellValueType::DateTime("2024-01-31".to_string()),
+                false
+            ),
+            (
+                "D10".to_string(),
+                "00123".to_string(),
+                CellValueType::Numeric(123.0),
+                true
+            ),
+        ]
+    );
+
+    // text of the sample is not a number
+    assert_eq!(
+        worksheet
+            .get_cell(Coordinate::from_point((3, 2)))?
+            .value
+            .stored_as_text_value(),
+        None
+    );
+
+    // converted when getting cells, except where the error is ignored
+    let cell = |worksheet: &Worksheet, a1: &str| {
+        worksheet
+            .get_cell(Coordinate::from_a1(a1.as_bytes()).unwrap())
+            .map(|c| c.value)
+    };
+    assert!(matches!(
+        cell(&worksheet, "A10")?,
+        CellValueType::PlainText(_)
+    ));
+    worksheet.coerce_numbers_stored_as_text = true;
+    assert_eq!(cell(&worksheet, "A10")?, CellValueType::Numeric(1234.5));
+    assert_eq!(
+        cell(&worksheet, "C10")?,
+        CellValueType::DateTime("2024-01-31".to_string())
+    );
+    assert_eq!(cell(&worksheet, "D10")?.to_string(), "00123");
+    assert_eq!(cell(&worksheet, "E10")?.to_string(), "12,34");
+    assert_eq!(cell(&worksheet, "B3")?.to_string(), "Itsuki1");
+
+    println!("numbers stored as text read successfully");
+    Ok(())
+}
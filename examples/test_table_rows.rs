@@ -0,0 +1,65 @@
+mod common;
+
+use std::io::Cursor;
+
+use common::{Package, SAMPLE, SHEET1, SHEET1_RELS};
+use excel_reader::{
+    excel::Excel, processed::spreadsheet::sheet::worksheet::cell::cell_value::CellValueType,
+};
+
+/// Read the rows of a table keyed by column name, with a totals row and an empty cell.
+fn main() -> anyhow::Result<()> {
+    let sheet = r#"<?xml version="1.0" encoding="UTF-8"?><worksheet xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships"><dimension ref="B2:D6"/><sheetData>
+<row r="2"><c r="B2" t="inlineStr"><is><t>Region</t></is></c><c r="C2" t="inlineStr"><is><t>Amount</t></is></c><c r="D2" t="inlineStr"><is><t>Note</t></is></c></row>
+<row r="3"><c r="B3" t="inlineStr"><is><t>North</t></is></c><c r="C3"><v>120</v></c><c r="D3" t="inlineStr"><is><t>late</t></is></c></row>
+<row r="4"><c r="B4" t="inlineStr"><is><t>South</t></is></c><c r="C4"><v>80</v></c></row>
+<row r="5"><c r="B5" t="inlineStr"><is><t>East</t></is></c><c r="C5"><v>45.5</v></c></row>
+<row r="6"><c r="B6" t="inlineStr"><is><t>Total</t></is></c><c r="C6"><f>SUBTOTAL(109,Sales[Amount])</f><v>245.5</v></c></row>
+</sheetData><tableParts count="1"><tablePart r:id="rId2"/></tableParts></worksheet>"#;
+    let rels = r#"<?xml version="1.0" encoding="UTF-8"?>
+<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships"><Relationship Id="rId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/hyperlink" Target="http://www.google.com" TargetMode="External"/><Relationship Id="rId2" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/table" Target="../tables/table1.xml"/></Relationships>"#;
+    let table = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<table xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main" id="1" name="Sales" displayName="Sales" ref="B2:D6" totalsRowCount="1"><autoFilter ref="B2:D5"/><tableColumns count="3"><tableColumn id="1" name="Region" totalsRowLabel="Total"/><tableColumn id="2" name="Amount" totalsRowFunction="sum"/><tableColumn id="3" name="Note"/></tableColumns><tableStyleInfo name="TableStyleMedium2" showFirstColumn="0" showLastColumn="0" showRowStripes="1" showColumnStripes="0"/></table>"#;
+
+    let bytes = Package::open(SAMPLE)?
+        .replace(SHEET1, sheet)
+        .replace(SHEET1_RELS, rels)
+        .replace("xl/tables/table1.xml", table)
+        .bytes()?;
+
+    let mut excel = Excel::from_reader(Cursor::new(bytes))?;
+    let sheets = excel.get_sheets()?;
+    let worksheet = excel.get_worksheet(&sheets[0])?;
+
+    assert_eq!(worksheet.tables.len(), 1);
+    let sales = worksheet.table("sales").unwrap();
+    assert_eq!(sales.display_name, "Sales");
+    assert_eq!(sales.dimension.to_a1(), "B2:D6");
+    assert_eq!(sales.columns, vec!["Region", "Amount", "Note"]);
+    assert_eq!(sales.header_row_count, 1);
+    assert_eq!(sales.totals_row_count, 1);
+    assert_eq!(
+        sales.data_range().map(|d| d.to_a1()).as_deref(),
+        Some("B3:D5")
+    );
+
+    assert_eq!(sales.totals.len(), 2);
+    assert_eq!(sales.totals[0].column, "Region");
+    assert_eq!(sales.totals[0].label.as_deref(), Some("Total"));
+    assert_eq!(sales.totals[1].column, "Amount");
+    assert_eq!(sales.totals[1].function.as_deref(), Some("sum"));
+    assert!(worksheet.table("Missing").is_none());
+
+    let rows = sales.rows_as_maps(&worksheet)?;
+    assert_eq!(rows.len(), 3);
+    assert_eq!(rows[0]["Region"].to_string(), "North");
+    assert_eq!(rows[0]["Amount"], CellValueType::Numeric(120.0));
+    assert_eq!(rows[0]["Note"].to_string(), "late");
+    assert_eq!(rows[1]["Region"].to_string(), "South");
+    assert_eq!(rows[1]["Note"], CellValueType::Empty);
+    assert_eq!(rows[2]["Amount"], CellValueType::Numeric(45.5));
+    assert!(rows.iter().all(|row| row.len() == 3));
+
+    println!("table rows read successfully");
+    Ok(())
+}
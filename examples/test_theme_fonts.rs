@@ -0,0 +1,48 @@
+mod common;
+
+use std::io::Cursor;
+
+use common::{Package, SAMPLE, STYLES};
+use excel_reader::excel::Excel;
+
+/// Read the fonts of the theme and the default font of the workbook.
+fn main() -> anyhow::Result<()> {
+    // fonts named in the stylesheet
+    let mut excel = Excel::from_path("examples/sample.xlsx")?;
+    let theme_fonts = excel.get_theme_fonts()?.unwrap();
+    assert_eq!(theme_fonts.name.as_deref(), Some("Blank"));
+    assert_eq!(theme_fonts.minor.latin.as_deref(), Some("Helvetica Neue"));
+    assert_eq!(
+        theme_fonts.major.east_asian.as_deref(),
+        Some("Helvetica Neue")
+    );
+    assert!(theme_fonts.minor.scripts.is_empty());
+
+    let default_font = excel.get_default_font()?;
+    assert_eq!(default_font.name, "Helvetica Neue");
+    assert_eq!(default_font.size, 10.0);
+
+    // default font following the theme
+    let bytes = Package::open(SAMPLE)?
+        .replace_in("xl/theme/theme1.xml", r#"<a:minorFont><a:latin typeface="Helvetica Neue"/><a:ea typeface="Helvetica Neue"/><a:cs typeface="Helvetica Neue"/></a:minorFont>"#, r#"<a:minorFont><a:latin typeface="Aptos Narrow"/><a:ea typeface=""/><a:cs typeface=""/><a:font script="Jpan" typeface="Yu Gothic"/><a:font script="Arab" typeface=""/></a:minorFont>"#)
+        .replace_in(STYLES, r#"<font><sz val="10"/><color indexed="8"/><name val="Helvetica Neue"/></font>"#, r#"<font><sz val="11"/><color theme="1"/><name val="Calibri"/><family val="2"/><scheme val="minor"/></font>"#)
+        .bytes()?;
+
+    let mut excel = Excel::from_reader(Cursor::new(bytes))?;
+    let theme_fonts = excel.get_theme_fonts()?.unwrap();
+    let minor = &theme_fonts.minor;
+    assert_eq!(minor.latin.as_deref(), Some("Aptos Narrow"));
+    assert_eq!(minor.typeface_for_script("Jpan"), Some("Yu Gothic"));
+    // empty or missing typefaces fall back to latin
+    assert_eq!(minor.typeface_for_script("Arab"), Some("Aptos Narrow"));
+    assert_eq!(minor.typeface_for_script("Hang"), Some("Aptos Narrow"));
+    assert_eq!(theme_fonts.major.latin.as_deref(), Some("Helvetica Neue"));
+
+    // the theme typeface takes precedence over the name stored in the stylesheet
+    let default_font = excel.get_default_font()?;
+    assert_eq!(default_font.name, "Aptos Narrow");
+    assert_eq!(default_font.size, 11.0);
+
+    println!("theme fonts read successfully");
+    Ok(())
+}
@@ -0,0 +1,106 @@
+mod common;
+
+use std::io::Cursor;
+
+use common::{Package, SAMPLE, SHEET1_RELS, WORKBOOK_RELS};
+use excel_reader::prelude::*;
+
+/// Read the threaded comments of a sheet: a resolved conversation with replies, a single comment with a mention,
+/// and a reply whose parent is missing.
+fn main() -> anyhow::Result<()> {
+    let rels = r#"<?xml version="1.0" encoding="UTF-8"?>
+<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships"><Relationship Id="rId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/hyperlink" Target="http://www.google.com" TargetMode="External"/><Relationship Id="rId2" Type="http://schemas.microsoft.com/office/2017/10/relationships/threadedComment" Target="../threadedComments/threadedComment1.xml"/></Relationships>"#;
+    let threaded_comments = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<ThreadedComments xmlns="http://schemas.microsoft.com/office/spreadsheetml/2018/threadedcomments" xmlns:x="http://schemas.openxmlformats.org/spreadsheetml/2006/main">
+<threadedComment ref="B2" dT="2024-03-01T09:15:00.00" personId="{P-1}" id="{C-1}" done="1"><text>Is this total right?</text></threadedComment>
+<threadedComment ref="D5" dT="2024-03-02T14:00:00.00" personId="{P-2}" id="{C-2}"><text>@Jane Doe please check &amp; confirm</text><mentions><mention mentionpersonId="{P-1}" mentionId="{M-1}" startIndex="0" length="9"/></mentions></threadedComment>
+<threadedComment ref="B2" dT="2024-03-01T10:02:41.35" personId="{P-2}" id="{C-3}" parentId="{C-1}"><text>Yes, checked against the invoice.</text></threadedComment>
+<threadedComment ref="B2" dT="2024-03-01T11:30:00.00" personId="{P-9}" id="{C-4}" parentId="{C-1}"><text>Thanks!</text></threadedComment>
+<threadedComment ref="F7" dT="2024-03-03T08:00:00.00" personId="{P-1}" id="{C-5}" parentId="{C-404}"><text>Reply to a deleted comment</text></threadedComment>
+</ThreadedComments>"#;
+    let persons = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<personList xmlns="http://schemas.microsoft.com/office/spreadsheetml/2018/threadedcomments" xmlns:x="http://schemas.openxmlformats.org/spreadsheetml/2006/main">
+<person displayName="Jane Doe" id="{P-1}" userId="jane@example.com" providerId="AD"/>
+<person displayName="Sean O&apos;Brien" id="{P-2}" userId="S::sean@example.com::1234" providerId="AD"/>
+</personList>"#;
+
+    let bytes = Package::open(SAMPLE)?
+        .replace(SHEET1_RELS, rels)
+        .replace_in(WORKBOOK_RELS, "</Relationships>", r#"<Relationship Id="rId99" Type="http://schemas.microsoft.com/office/2017/10/relationships/person" Target="persons/person.xml"/></Relationships>"#)
+        .replace("xl/threadedComments/threadedComment1.xml", threaded_comments)
+        .replace("xl/persons/person.xml", persons)
+        .bytes()?;
+
+    let mut excel = Excel::from_reader(Cursor::new(bytes))?;
+    let sheets = excel.get_sheets()?;
+
+    let raw_persons = excel.get_raw_persons()?;
+    assert_eq!(raw_persons.persons.len(), 2);
+    let raw_comments = excel.get_raw_threaded_comments_for_worksheet(&sheets[0])?;
+    assert_eq!(raw_comments.comments.len(), 5);
+    assert_eq!(raw_comments.comments[2].parent_id.as_deref(), Some("{C-1}"));
+
+    let worksheet = excel.get_worksheet(&sheets[0])?;
+    assert_eq!(worksheet.comment_threads.len(), 3);
+
+    let resolved = &worksheet.comment_threads[0];
+    assert_eq!(resolved.coordinate, Coordinate::from_point((2, 2)));
+    assert!(resolved.resolved);
+    assert_eq!(resolved.comment.id, "{C-1}");
+    assert_eq!(resolved.comment.text, "Is this total right?");
+    assert_eq!(
+        resolved.comment.date_time.as_deref(),
+        Some("2024-03-01T09:15:00.00")
+    );
+    let jane = resolved.comment.author.as_ref().unwrap();
+    assert_eq!(jane.display_name, "Jane Doe");
+    assert_eq!(jane.user_id.as_deref(), Some("jane@example.com"));
+    assert_eq!(jane.provider_id.as_deref(), Some("AD"));
+
+    assert_eq!(resolved.replies.len(), 2);
+    assert_eq!(
+        resolved.replies[0].text,
+        "Yes, checked against the invoice."
+    );
+    assert_eq!(
+        resolved.replies[0]
+            .author
+            .as_ref()
+            .map(|p| p.display_name.as_str()),
+        Some("Sean O'Brien")
+    );
+    // author missing from the persons part
+    assert_eq!(resolved.replies[1].text, "Thanks!");
+    assert!(resolved.replies[1].author.is_none());
+
+    let mention = &worksheet.comment_threads[1];
+    assert_eq!(mention.coordinate, Coordinate::from_point((5, 4)));
+    assert!(!mention.resolved);
+    assert_eq!(mention.comment.text, "@Jane Doe please check & confirm");
+    assert!(mention.replies.is_empty());
+
+    let orphan = &worksheet.comment_threads[2];
+    assert_eq!(orphan.coordinate, Coordinate::from_point((7, 6)));
+    assert_eq!(orphan.comment.text, "Reply to a deleted comment");
+
+    // a conversation is exported a comment per line
+    let csv = worksheet.to_csv(
+        vec![],
+        &CsvOptions {
+            range: Some(Dimension::parse_a1("B2")?),
+            include_comments: true,
+            ..CsvOptions::default()
+        },
+    )?;
+    assert_eq!(
+        String::from_utf8(csv)?,
+        "value,\"B2: Jane Doe: Is this total right?\nSean O'Brien: Yes, checked against the invoice.\nThanks!\"\n"
+    );
+
+    // sheets without threaded comments
+    let other = excel.get_worksheet(&sheets[1])?;
+    assert!(other.comment_threads.is_empty());
+
+    println!("threaded comments read successfully");
+    Ok(())
+}
@@ -0,0 +1,84 @@
+mod common;
+
+use std::io::Cursor;
+
+use chrono::NaiveDate;
+use common::{Package, SAMPLE, SHEET1};
+use excel_reader::{
+    common_types::Coordinate,
+    excel::Excel,
+    processed::spreadsheet::sheet::worksheet::cell::cell_value::{
+        error_value::CellErrorType, typed_value::CellValue,
+    },
+};
+
+/// Sample workbook with cells of every type appended to the first sheet.
+fn sample_with_typed_cells() -> anyhow::Result<Vec<u8>> {
+    Package::open(SAMPLE)?
+        .append_rows(
+            SHEET1,
+            concat!(
+                r#"<row r="20"><c r="A20"><v>12.5</v></c><c r="B20" t="inlineStr"><is><t>text</t></is></c><c r="C20" t="b"><v>1</v></c><c r="D20" t="e"><v>#N/A</v></c><c r="E20" s="28"><v>45306.5</v></c><c r="F20" t="d"><v>2024-01-15T12:00:00</v></c></row>"#,
+                r#"<row r="21"><c r="A21"><f>A20*2</f><v>25</v></c><c r="B21" t="str"><f>B20&amp;"!"</f><v>text!</v></c><c r="C21" t="b"><f>C20</f><v>0</v></c><c r="D21" t="e"><f>1/0</f><v>#DIV/0!</v></c><c r="E21" s="28"><f>E20+1</f><v>45307.5</v></c><c r="F21"><f>A99</f></c></row>"#,
+                r#"<row r="22"><c r="A22" s="28"><v>3000000</v></c><c r="B22" t="str"><f>"1"</f><v>1</v></c><c r="F22" t="d"><v>not a date</v></c></row>"#,
+            ),
+        )
+        .append_cell_formats(r#"<xf numFmtId="22" fontId="0" applyNumberFormat="1"/>"#)
+        .bytes()
+}
+
+/// Read cell values resolved to their type, formulas by their last calculated value.
+fn main() -> anyhow::Result<()> {
+    let mut excel = Excel::from_reader(Cursor::new(sample_with_typed_cells()?))?;
+    let worksheet = excel.get_worksheet_with_name("Sheet 1 - Basic")?;
+    let value = |a1: &str| -> anyhow::Result<CellValue> {
+        let cell = worksheet.get_cell(Coordinate::from_a1(a1.as_bytes()).unwrap())?;
+        Ok(cell.typed_value(worksheet.is_1904))
+    };
+    let noon = NaiveDate::from_ymd_opt(2024, 1, 15)
+        .unwrap()
+        .and_hms_opt(12, 0, 0)
+        .unwrap();
+
+    // stored values
+    assert_eq!(value("A20")?, CellValue::Number(12.5));
+    assert_eq!(value("A20")?.as_f64(), Some(12.5));
+    assert_eq!(value("B20")?, CellValue::Text("text".to_string()));
+    assert_eq!(value("B20")?.as_str(), Some("text"));
+    assert_eq!(value("B20")?.as_f64(), None);
+    assert_eq!(value("C20")?.as_bool(), Some(true));
+    assert_eq!(value("D20")?.as_error(), Some(&CellErrorType::NA));
+    assert_eq!(value("E20")?, CellValue::DateTime(noon));
+    assert_eq!(value("E20")?.as_f64(), None);
+    assert_eq!(value("F20")?.as_date_time(), Some(noon));
+    assert!(value("E22")?.is_blank());
+
+    // formulas take the type of their result
+    assert_eq!(value("A21")?, CellValue::Number(25.0));
+    assert_eq!(value("B21")?, CellValue::Text("text!".to_string()));
+    assert_eq!(value("C21")?, CellValue::Bool(false));
+    assert_eq!(value("D21")?, CellValue::Error(CellErrorType::Div0));
+    assert_eq!(
+        value("E21")?,
+        CellValue::DateTime(noon + chrono::Duration::days(1))
+    );
+    assert_eq!(value("F21")?, CellValue::Blank);
+    // a string result reading as a number stays text
+    assert_eq!(value("B22")?.as_str(), Some("1"));
+
+    // numbers beyond 9999-12-31 and invalid dates are kept
+    assert_eq!(value("A22")?, CellValue::Number(3_000_000.0));
+    assert_eq!(value("F22")?.as_str(), Some("not a date"));
+
+    assert_eq!(value("C20")?.to_string(), "TRUE");
+    assert_eq!(value("E20")?.to_string(), "2024-01-15T12:00:00");
+
+    #[cfg(feature = "serde")]
+    {
+        let json = serde_json::to_value(value("A20")?)?;
+        assert_eq!(json, serde_json::json!({ "number": 12.5 }));
+    }
+
+    println!("typed values read successfully");
+    Ok(())
+}
@@ -0,0 +1,56 @@
+use excel_reader::units::*;
+
+fn assert_close(actual: f64, expected: f64) {
+    assert!(
+        (actual - expected).abs() < 1e-9,
+        "expected {}, got {}",
+        expected,
+        actual
+    );
+}
+
+fn main() -> anyhow::Result<()> {
+    // points, EMUs, inches and centimeters
+    assert_close(emu_to_pt(12_700), 1.0);
+    assert_eq!(pt_to_emu(1.5), 19_050);
+    assert_close(emu_to_inch(914_400), 1.0);
+    assert_eq!(inch_to_emu(0.5), 457_200);
+    assert_close(emu_to_cm(360_000), 1.0);
+    assert_eq!(cm_to_emu(2.54), 914_400);
+    assert_close(pt_to_inch(36.0), 0.5);
+    assert_close(inch_to_pt(2.0), 144.0);
+
+    // twips
+    assert_close(twips_to_pt(240), 12.0);
+    assert_eq!(pt_to_twips(11.25), 225);
+
+    // pixels
+    assert_close(pt_to_px(72.0, DEFAULT_DPI), 96.0);
+    assert_close(px_to_pt(20.0, DEFAULT_DPI), 15.0);
+    assert_close(pt_to_px(72.0, 144.0), 144.0);
+    assert_close(emu_to_px(914_400, DEFAULT_DPI), 96.0);
+    assert_eq!(px_to_emu(96.0, DEFAULT_DPI), 914_400);
+    for px in [0.0, 1.0, 64.0, 1920.0] {
+        assert_close(pt_to_px(px_to_pt(px, DEFAULT_DPI), DEFAULT_DPI), px);
+    }
+
+    // column widths, Calibri of font size 11 pt has a maximum digit width of 7 pixels
+    assert_close(characters_to_column_width(8.0, 7.0), 8.7109375);
+    assert_close(column_width_to_px(8.7109375, 7.0), 61.0);
+    assert_close(column_width_to_characters(8.7109375, 7.0), 8.0);
+    assert_close(px_to_column_width(61.0, 7.0), 8.7109375);
+    assert_close(px_to_column_width(0.0, 7.0), 0.0);
+    assert_close(column_width_to_pt(8.7109375, 7.0), 45.75);
+    for characters in [1.0, 8.43, 10.0, 25.0] {
+        let width = characters_to_column_width(characters, 7.0);
+        assert_close(column_width_to_characters(width, 7.0), characters);
+        assert_close(
+            px_to_column_width(column_width_to_px(width, 7.0), 7.0),
+            width,
+        );
+    }
+    assert_close(max_digit_width(12.0), DEFAULT_MAX_DIGIT_WIDTH);
+
+    println!("all unit conversions passed");
+    Ok(())
+}
@@ -0,0 +1,104 @@
+mod common;
+
+use std::io::Cursor;
+
+use common::{sample_with_moved_workbook, Package, MOVED_WORKBOOK, SAMPLE, SHEET1, STYLES};
+use excel_reader::{excel::Excel, packaging::unsupported_feature::UnsupportedFeatureKind};
+
+/// Report a chart, a pivot table, a VBA project and the extensions of a worksheet, none of which are read.
+fn main() -> anyhow::Result<()> {
+    let sheet = r#"<?xml version="1.0" encoding="UTF-8"?><worksheet xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main" xmlns:x14="http://schemas.microsoft.com/office/spreadsheetml/2009/9/main" xmlns:xm="http://schemas.microsoft.com/office/excel/2006/main"><dimension ref="A1:C1"/><sheetData>
+<row r="1"><c r="A1"><v>1</v></c><c r="B1"><v>2</v></c><c r="C1"><v>3</v></c></row>
+</sheetData><extLst><ext uri="{05C60535-1F16-4fd2-B633-F4F36F0B64E0}"><x14:sparklineGroups><x14:sparklineGroup><x14:sparklines><x14:sparkline><xm:f>Sheet1!A1:C1</xm:f><xm:sqref>D1</xm:sqref></x14:sparkline></x14:sparklines></x14:sparklineGroup></x14:sparklineGroups></ext><ext uri="{12345678-0000-0000-0000-000000000000}"><x14:unknown/></ext><ext uri="{05C60535-1F16-4fd2-B633-F4F36F0B64E0}"><x14:sparklineGroups/></ext></extLst></worksheet>"#;
+    let chart = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?><c:chartSpace xmlns:c="http://schemas.openxmlformats.org/drawingml/2006/chart"/>"#;
+    let pivot_table = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?><pivotTableDefinition xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main" name="PivotTable1" cacheId="1" dataCaption="Values"><location ref="A3:B5" firstHeaderRow="1" firstDataRow="1" firstDataCol="1"/></pivotTableDefinition>"#;
+
+    let bytes = Package::open(SAMPLE)?
+        .replace(SHEET1, sheet)
+        .replace("xl/charts/chart1.xml", chart)
+        .replace("xl/charts/_rels/chart1.xml.rels", r#"<?xml version="1.0" encoding="UTF-8"?><Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships"/>"#)
+        .replace("xl/pivotTables/pivotTable1.xml", pivot_table)
+        .replace("xl/vbaProject.bin", [0xd0, 0xcf, 0x11, 0xe0])
+        .bytes()?;
+
+    let mut excel = Excel::from_reader(Cursor::new(bytes))?;
+    let features = excel.unsupported_features()?;
+    for feature in &features {
+        println!("{}", feature);
+    }
+
+    let parts: Vec<(&str, &UnsupportedFeatureKind)> = features
+        .iter()
+        .map(|f| (f.part.as_str(), &f.kind))
+        .collect();
+    assert_eq!(features.len(), 5);
+    // parts in the order of the package, relationships of the chart left out
+    assert_eq!(
+        parts[0],
+        ("xl/charts/chart1.xml", &UnsupportedFeatureKind::Chart)
+    );
+    assert_eq!(
+        parts[1],
+        (
+            "xl/pivotTables/pivotTable1.xml",
+            &UnsupportedFeatureKind::PivotTable
+        )
+    );
+    assert_eq!(
+        parts[2],
+        ("xl/vbaProject.bin", &UnsupportedFeatureKind::VbaProject)
+    );
+    // repeated extension reported once
+    assert_eq!(
+        parts[3],
+        (
+            "xl/worksheets/sheet1.xml",
+            &UnsupportedFeatureKind::Extension {
+                uri: "{05C60535-1F16-4fd2-B633-F4F36F0B64E0}".to_string(),
+                name: Some("sparkline groups".to_string())
+            }
+        )
+    );
+    assert_eq!(
+        parts[4],
+        (
+            "xl/worksheets/sheet1.xml",
+            &UnsupportedFeatureKind::Extension {
+                uri: "{12345678-0000-0000-0000-000000000000}".to_string(),
+                name: None
+            }
+        )
+    );
+    assert_eq!(
+        features[3].to_string(),
+        "xl/worksheets/sheet1.xml: extension `{05C60535-1F16-4fd2-B633-F4F36F0B64E0}` (sparkline groups)"
+    );
+
+    // cells are still read
+    let sheets = excel.get_sheets()?;
+    let worksheet = excel.get_worksheet(&sheets[0])?;
+    assert_eq!(worksheet.get_cells()?.len(), 3);
+
+    // extensions of the workbook part targeted by the package relationships, and of the stylesheet
+    let mut excel = sample_with_moved_workbook()?
+        .replace_in(
+            MOVED_WORKBOOK,
+            "</workbook>",
+            r#"<extLst><ext uri="{79F54976-1DA5-4618-B147-4CDE4B953A38}"/></extLst></workbook>"#,
+        )
+        .replace_in(
+            STYLES,
+            "</styleSheet>",
+            r#"<extLst><ext uri="{EB79DEF2-80B8-43e5-95BD-54CBDDF9020C}"/></extLst></styleSheet>"#,
+        )
+        .excel()?;
+    let parts: Vec<String> = excel
+        .unsupported_features()?
+        .into_iter()
+        .map(|f| f.part)
+        .collect();
+    assert_eq!(parts, vec![MOVED_WORKBOOK, STYLES]);
+
+    println!("unsupported features reported successfully");
+    Ok(())
+}
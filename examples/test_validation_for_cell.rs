@@ -0,0 +1,66 @@
+mod common;
+
+use std::io::Cursor;
+
+use common::{Package, SAMPLE, SHEET1};
+use excel_reader::{common_types::Coordinate, excel::Excel};
+
+/// Look up the data validation of cells, with validations on several ranges, a single cell and whole rows of a table.
+fn main() -> anyhow::Result<()> {
+    let sheet = r#"<?xml version="1.0" encoding="UTF-8"?><worksheet xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main"><dimension ref="A1"/><sheetData><row r="1"><c r="A1"><v>1</v></c></row></sheetData><dataValidations count="4">
+<dataValidation type="list" sqref="B2:B10 D2:D10"><formula1>"Yes,No"</formula1></dataValidation>
+<dataValidation type="whole" operator="between" sqref="C5:C20"><formula1>0</formula1><formula2>100</formula2></dataValidation>
+<dataValidation type="date" operator="greaterThan" sqref="$F$1"><formula1>45000</formula1></dataValidation>
+<dataValidation type="textLength" operator="lessThan" sqref="A25:Z30"><formula1>10</formula1></dataValidation>
+</dataValidations></worksheet>"#;
+
+    let bytes = Package::open(SAMPLE)?.replace(SHEET1, sheet).bytes()?;
+
+    let mut excel = Excel::from_reader(Cursor::new(bytes))?;
+    let sheets = excel.get_sheets()?;
+    let worksheet = excel.get_worksheet(&sheets[0])?;
+
+    let validation_type = |a1: &str| {
+        let coordinate = Coordinate::from_a1(a1.as_bytes()).unwrap();
+        worksheet
+            .validation_for_cell(coordinate)
+            .map(|v| v.r#type.clone())
+    };
+    assert_eq!(validation_type("B2").as_deref(), Some("list"));
+    assert_eq!(validation_type("B10").as_deref(), Some("list"));
+    assert_eq!(validation_type("D7").as_deref(), Some("list"));
+    assert_eq!(validation_type("C5").as_deref(), Some("whole"));
+    assert_eq!(validation_type("C20").as_deref(), Some("whole"));
+    assert_eq!(validation_type("F1").as_deref(), Some("date"));
+    assert_eq!(validation_type("M27").as_deref(), Some("textLength"));
+
+    assert_eq!(validation_type("A1"), None);
+    assert_eq!(validation_type("B11"), None);
+    assert_eq!(validation_type("C4"), None);
+    assert_eq!(validation_type("C21"), None);
+    assert_eq!(validation_type("E5"), None);
+    assert_eq!(validation_type("F2"), None);
+    assert_eq!(validation_type("AA27"), None);
+    assert_eq!(validation_type("A31"), None);
+
+    // every cell agrees with a scan of the validation ranges
+    let validations = worksheet.data_validations.clone().unwrap_or_default();
+    for row in 1..=35 {
+        for col in 1..=30 {
+            let coordinate = Coordinate::from_point((row, col));
+            let scanned = validations.iter().find(|v| {
+                v.sqref.split_whitespace().any(|r| {
+                    let r = r.replace('$', "");
+                    let (start, end) = r.split_once(':').unwrap_or((&r, &r));
+                    let start = Coordinate::from_a1(start.as_bytes()).unwrap();
+                    let end = Coordinate::from_a1(end.as_bytes()).unwrap();
+                    (start.row..=end.row).contains(&row) && (start.col..=end.col).contains(&col)
+                })
+            });
+            assert_eq!(worksheet.validation_for_cell(coordinate), scanned);
+        }
+    }
+
+    println!("validations looked up successfully");
+    Ok(())
+}
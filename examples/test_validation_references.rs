@@ -0,0 +1,92 @@
+mod common;
+
+use std::io::Cursor;
+
+use common::{Package, SAMPLE, SHEET1, WORKBOOK};
+use excel_reader::{
+    excel::Excel, processed::spreadsheet::validation_reference::ValidationReferenceProblem,
+};
+
+/// Check data validation formulas referring to existing and missing sheets and names.
+fn main() -> anyhow::Result<()> {
+    let validations = [
+        // valid: other sheet, quoted sheet, workbook and sheet level names, literal list, cell reference
+        ("list", "A1", "'Sheet 2 - Custom grid lines'!$A$1:$A$5"),
+        ("list", "A2", "Colors"),
+        ("list", "A3", "LocalList"),
+        ("list", "A4", r#""Yes,No,Maybe""#),
+        ("whole", "A5", "$B$1+SUM(B2:B3)"),
+        // broken: missing sheet, missing name, name to a deleted sheet, deleted cells
+        ("list", "B1", "Deleted!$A$1:$A$5"),
+        ("list", "B2", "Sizes"),
+        ("list", "B3", "Broken"),
+        ("list", "B4", "#REF!$A$1:$A$3"),
+        ("list", "B5", "INDIRECT(\"Deleted!A1\")"),
+    ];
+    let data_validations: String = validations
+        .iter()
+        .map(|(t, sqref, formula)| {
+            format!(
+                r#"<dataValidation type="{}" allowBlank="1" showErrorMessage="1" sqref="{}"><formula1>{}</formula1></dataValidation>"#,
+                t,
+                sqref,
+                formula.replace('&', "&amp;").replace('"', "&quot;")
+            )
+        })
+        .collect();
+    let sheet = format!(
+        r#"<?xml version="1.0" encoding="UTF-8"?><worksheet xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main"><dimension ref="A1"/><sheetData><row r="1"><c r="A1"><v>1</v></c></row></sheetData><dataValidations count="{}">{}</dataValidations></worksheet>"#,
+        validations.len(),
+        data_validations
+    );
+    let workbook = r#"<?xml version="1.0" encoding="UTF-8"?><workbook xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships" xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main"><sheets><sheet name="Sheet 1 - Basic" sheetId="1" r:id="rId4"/><sheet name="Sheet 2 - Custom grid lines" sheetId="2" r:id="rId5"/><sheet name="Sheet 3 - Custom Colors_Font" sheetId="3" r:id="rId6"/></sheets><definedNames><definedName name="Broken">Removed!$A$1:$A$3</definedName><definedName name="Colors">'Sheet 3 - Custom Colors_Font'!$A$1:$A$3</definedName><definedName name="LocalList" localSheetId="0">'Sheet 1 - Basic'!$C$1:$C$3</definedName><definedName name="Sizes" localSheetId="1">'Sheet 2 - Custom grid lines'!$A$1:$A$3</definedName></definedNames></workbook>"#;
+
+    let bytes = Package::open(SAMPLE)?
+        .replace(SHEET1, &sheet)
+        .replace(WORKBOOK, workbook)
+        .bytes()?;
+
+    let mut excel = Excel::from_reader(Cursor::new(bytes))?;
+    let issues = excel.check_data_validation_references()?;
+    let found: Vec<(String, ValidationReferenceProblem)> = issues
+        .iter()
+        .map(|i| (i.sqref.clone(), i.problem.clone()))
+        .collect();
+    assert_eq!(
+        found,
+        vec![
+            (
+                "B1".to_string(),
+                ValidationReferenceProblem::MissingSheet {
+                    sheet_name: "Deleted".to_string()
+                }
+            ),
+            // `Sizes` is only visible from the second sheet
+            (
+                "B2".to_string(),
+                ValidationReferenceProblem::MissingName {
+                    name: "Sizes".to_string()
+                }
+            ),
+            (
+                "B3".to_string(),
+                ValidationReferenceProblem::BrokenName {
+                    name: "Broken".to_string()
+                }
+            ),
+            (
+                "B4".to_string(),
+                ValidationReferenceProblem::InvalidReference
+            ),
+        ]
+    );
+    assert!(issues.iter().all(|i| i.sheet_name == "Sheet 1 - Basic"));
+    assert_eq!(issues[0].formula, "Deleted!$A$1:$A$5");
+
+    // validations of the templates refer to existing sheets
+    let mut template = Excel::from_path("examples/template.xlsx")?;
+    assert!(template.check_data_validation_references()?.is_empty());
+
+    println!("validation references checked successfully");
+    Ok(())
+}
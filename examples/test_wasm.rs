@@ -0,0 +1,67 @@
+mod common;
+
+use common::{Package, EMPTY_SHEET, SAMPLE, WORKBOOK, WORKBOOK_RELS};
+use excel_reader::{prelude::*, wasm::WasmWorkbook};
+
+/// Entry points of the browser bindings, called natively: only their success paths,
+/// errors are JavaScript values that cannot be created outside of wasm.
+fn main() -> anyhow::Result<()> {
+    let bytes = std::fs::read(SAMPLE)?;
+    let mut excel = Excel::from_path(SAMPLE)?;
+    let mut workbook = WasmWorkbook::new(&bytes).unwrap();
+
+    let names: Vec<String> = excel.get_sheets()?.into_iter().map(|s| s.name).collect();
+    assert_eq!(workbook.sheet_names().unwrap(), names);
+
+    // rows of formatted values, from the top left cell of the data
+    let rows: Vec<Vec<String>> =
+        serde_json::from_str(&workbook.sheet_rows("Sheet 1 - Basic").unwrap())?;
+    let worksheet = excel.get_worksheet_with_name("Sheet 1 - Basic")?;
+    let dimension = worksheet.dimension.unwrap();
+    assert_eq!(
+        rows.len() as u64,
+        dimension.end.row - dimension.start.row + 1
+    );
+    assert!(rows.iter().all(|row| row.len() == 2));
+    assert_eq!(rows[0], vec!["Basic", ""]);
+    assert_eq!(rows[1], vec!["index", "value"]);
+    let b9 = worksheet.get_cell(Coordinate::from_point((9, 2)))?;
+    assert_eq!(
+        rows.last().unwrap()[1],
+        b9.formatted_value(worksheet.is_1904)
+    );
+    // names are compared as Excel does
+    assert_eq!(
+        workbook.sheet_rows("SHEET 1 - BASIC").unwrap(),
+        workbook.sheet_rows("Sheet 1 - Basic").unwrap()
+    );
+
+    assert_eq!(
+        workbook.to_json().unwrap(),
+        excel.to_processed_model()?.to_json()?
+    );
+
+    // sheets without cells, and sheets other than worksheets left out of the names
+    let bytes = Package::open(SAMPLE)?
+        .replace("xl/worksheets/sheet4.xml", EMPTY_SHEET)
+        .replace("xl/chartsheets/sheet1.xml", r#"<?xml version="1.0" encoding="UTF-8"?><chartsheet xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main"/>"#)
+        .replace_in(
+            WORKBOOK_RELS,
+            "</Relationships>",
+            r#"<Relationship Id="rId7" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/worksheet" Target="worksheets/sheet4.xml"/><Relationship Id="rId8" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/chartsheet" Target="chartsheets/sheet1.xml"/></Relationships>"#,
+        )
+        .replace_in(
+            WORKBOOK,
+            "</sheets>",
+            r#"<sheet name="Empty" sheetId="4" r:id="rId7"/><sheet name="Chart1" sheetId="5" r:id="rId8"/></sheets>"#,
+        )
+        .bytes()?;
+    let mut workbook = WasmWorkbook::new(&bytes).unwrap();
+    let sheet_names = workbook.sheet_names().unwrap();
+    assert_eq!(sheet_names.len(), 4);
+    assert_eq!(sheet_names[3], "Empty");
+    assert_eq!(workbook.sheet_rows("Empty").unwrap(), "[]");
+
+    println!("wasm bindings called successfully");
+    Ok(())
+}
@@ -0,0 +1,77 @@
+mod common;
+
+use common::{Package, SAMPLE, SHEET1};
+use excel_reader::common_types::{
+    Coordinate, CoordinateError, Dimension, MAX_COLUMN_COUNT, MAX_ROW_COUNT,
+};
+
+/// Parse whole column and whole row references, and use them in validations, conditional formats and ranges.
+fn main() -> anyhow::Result<()> {
+    let range = |a1: &str| Dimension::parse_a1(a1);
+    let dimension = |start: (u64, u64), end: (u64, u64)| Dimension {
+        start: Coordinate::from_point(start),
+        end: Coordinate::from_point(end),
+    };
+
+    assert_eq!(range("A1:C3"), Ok(dimension((1, 1), (3, 3))));
+    assert_eq!(range("$C$3:$A$1"), Ok(dimension((1, 1), (3, 3))));
+    assert_eq!(range("B2"), Ok(dimension((2, 2), (2, 2))));
+    // whole columns and rows span the worksheet
+    assert_eq!(range("A:C"), Ok(dimension((1, 1), (MAX_ROW_COUNT, 3))));
+    assert_eq!(range("$B:$B"), Ok(dimension((1, 2), (MAX_ROW_COUNT, 2))));
+    assert_eq!(range("3:7"), Ok(dimension((3, 1), (7, MAX_COLUMN_COUNT))));
+    assert_eq!(range("$7:$3"), Ok(dimension((3, 1), (7, MAX_COLUMN_COUNT))));
+    assert_eq!("a:a".parse::<Dimension>(), range("A:A"));
+
+    let columns = range("A:C")?;
+    assert!(columns.is_whole_column() && !columns.is_whole_row());
+    let rows = range("3:7")?;
+    assert!(rows.is_whole_row() && !rows.is_whole_column());
+    assert!(!range("A1:C3")?.is_whole_column());
+    // written back as parsed
+    for a1 in ["A:C", "B:B", "3:7", "1:1048576", "A1:C3", "B2"] {
+        assert_eq!(range(a1)?.to_a1(), a1);
+    }
+
+    // malformed ranges
+    assert_eq!(range("A1:C"), Err(CoordinateError::MismatchedRange));
+    assert_eq!(range("A:3"), Err(CoordinateError::MismatchedRange));
+    assert_eq!(range("A:"), Err(CoordinateError::Empty));
+    assert_eq!(range("0:3"), Err(CoordinateError::ZeroRow));
+    assert_eq!(
+        range("A:XFE"),
+        Err(CoordinateError::ColumnOutOfRange("XFE".to_string()))
+    );
+    assert_eq!(
+        range("A1:B-2"),
+        Err(CoordinateError::UnexpectedCharacter {
+            position: 4,
+            character: '-'
+        })
+    );
+
+    // whole columns and rows in sqref of validations and conditional formats
+    let sheet = r#"<?xml version="1.0" encoding="UTF-8"?><worksheet xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main"><dimension ref="A1:C4"/><sheetData><row r="1"><c r="A1"><v>1</v></c><c r="B1"><v>2</v></c><c r="C1"><v>3</v></c></row><row r="4"><c r="B4"><v>4</v></c></row></sheetData>
+<conditionalFormatting sqref="2:3"><cfRule type="expression" priority="1"><formula>TRUE</formula></cfRule></conditionalFormatting>
+<dataValidations count="1"><dataValidation type="whole" operator="between" sqref="B:B D5"><formula1>0</formula1><formula2>100</formula2></dataValidation></dataValidations></worksheet>"#;
+    let mut excel = Package::open(SAMPLE)?.replace(SHEET1, sheet).excel()?;
+    let sheets = excel.get_sheets()?;
+    let worksheet = excel.get_worksheet(&sheets[0])?;
+
+    let cell = |a1: &str| Coordinate::parse_a1(a1).unwrap();
+    assert!(worksheet.validation_for_cell(cell("B1")).is_some());
+    assert!(worksheet.validation_for_cell(cell("B1048576")).is_some());
+    assert!(worksheet.validation_for_cell(cell("D5")).is_some());
+    assert!(worksheet.validation_for_cell(cell("C1")).is_none());
+    assert_eq!(worksheet.conditional_rules_for(cell("XFD2")).len(), 1);
+    assert!(worksheet.conditional_rules_for(cell("A4")).is_empty());
+
+    // only the part within the worksheet dimension is read
+    let cells = worksheet.get_range("B:B")?;
+    let coordinates: Vec<String> = cells.iter().map(|c| c.coordinate.to_a1()).collect();
+    assert_eq!(coordinates, ["B1", "B2", "B3", "B4"]);
+    assert_eq!(worksheet.get_range("$4:$4")?.len(), 3);
+
+    println!("whole ranges parsed successfully");
+    Ok(())
+}
@@ -0,0 +1,74 @@
+mod common;
+
+use common::{Package, SAMPLE, SHEET1};
+use excel_reader::prelude::*;
+
+fn model(rows: &str) -> anyhow::Result<Workbook> {
+    let mut excel = Package::open(SAMPLE)?.replace_rows(SHEET1, rows).excel()?;
+    excel.to_processed_model()
+}
+
+/// Compare workbooks for regression testing.
+fn main() -> anyhow::Result<()> {
+    let expected = model(
+        r#"<row r="1"><c r="A1"><v>0.3</v></c><c r="B1" t="inlineStr"><is><t>text</t></is></c><c r="C1"><f>NOW()</f><v>45000.1</v></c></row>"#,
+    )?;
+
+    // same content written differently: a rounding error, an empty cell, a new cached value of a volatile formula
+    let rewritten = model(
+        r#"<row r="1"><c r="A1"><v>0.30000000000000004</v></c><c r="B1" t="inlineStr"><is><t>text</t></is></c><c r="C1"><f>NOW()</f><v>45001.7</v></c><c r="D1" t="inlineStr"><is><t></t></is></c></row>"#,
+    )?;
+    assert_workbook_eq(&expected, &expected, Tolerance::exact());
+    assert_workbook_eq(&expected, &rewritten, Tolerance::default());
+
+    let differences = expected.differences(&rewritten, Tolerance::exact());
+    let cells: Vec<String> = differences
+        .iter()
+        .filter_map(|d| d.coordinate.map(|c| c.to_a1()))
+        .collect();
+    assert!(cells.contains(&"A1".to_string()), "{:?}", differences);
+    assert!(cells.contains(&"C1".to_string()), "{:?}", differences);
+
+    // changed content is reported
+    let changed = model(
+        r#"<row r="1"><c r="A1"><v>0.5</v></c><c r="B1" t="inlineStr"><is><t>other</t></is></c></row>"#,
+    )?;
+    let differences = expected.differences(&changed, Tolerance::default());
+    assert_eq!(differences.len(), 3, "{:?}", differences);
+    assert_eq!(
+        differences[0].sheet.as_deref(),
+        Some(expected.sheets[0].name.as_str())
+    );
+    assert_eq!(
+        differences[0].to_string(),
+        format!(
+            "{}!A1: value: Numeric(0.3) != Numeric(0.5)",
+            expected.sheets[0].name
+        )
+    );
+
+    std::panic::set_hook(Box::new(|_| {}));
+    let panic =
+        std::panic::catch_unwind(|| assert_workbook_eq(&expected, &changed, Tolerance::default()))
+            .unwrap_err();
+    let _ = std::panic::take_hook();
+    let message = panic.downcast_ref::<String>().unwrap();
+    assert!(
+        message.starts_with("workbooks differ (3 differences):"),
+        "{}",
+        message
+    );
+
+    // missing sheets
+    let mut fewer = expected.clone();
+    let removed = fewer.sheets.pop().unwrap();
+    let differences = expected.differences(&fewer, Tolerance::default());
+    assert_eq!(differences.len(), 1);
+    assert_eq!(
+        differences[0].to_string(),
+        format!("sheet `{}` missing in the second workbook", removed.name)
+    );
+
+    println!("workbooks compared successfully");
+    Ok(())
+}
@@ -0,0 +1,241 @@
+mod common;
+
+use common::{Package, SAMPLE, SHEET1, SHEET1_RELS, WORKBOOK, WORKBOOK_RELS};
+use excel_reader::{packaging::content_type::WorkbookContentType, prelude::*};
+
+const MAIN: &str = "http://schemas.openxmlformats.org/spreadsheetml/2006/main";
+const RELATIONSHIPS: &str = "http://schemas.openxmlformats.org/officeDocument/2006/relationships";
+const X14: &str = "http://schemas.microsoft.com/office/spreadsheetml/2009/9/main";
+const X15: &str = "http://schemas.microsoft.com/office/spreadsheetml/2010/11/main";
+const WEB_EXTENSION: &str =
+    "http://schemas.microsoft.com/office/webextensions/webextension/2010/11";
+
+fn web_extension(id: &str, add_in_id: &str) -> String {
+    format!(
+        r#"<?xml version="1.0" encoding="UTF-8"?><we:webextension xmlns:we="{}" id="{}"><we:reference id="{}" version="1.1.0.0" store="en-US" storeType="OMEX"/><we:alternateReferences><we:reference id="alternate" version="1.0.0.0" store="omex" storeType="OMEX"/></we:alternateReferences><we:properties/><we:bindings/></we:webextension>"#,
+        WEB_EXTENSION, id, add_in_id
+    )
+}
+
+fn utf16le(text: &str) -> Vec<u8> {
+    text.encode_utf16().flat_map(|u| u.to_le_bytes()).collect()
+}
+
+/// Parts added by add-ins and Power Pivot: worksheet custom properties, web extensions, data model,
+/// slicers and timelines, and the summary of the workbook.
+fn main() -> anyhow::Result<()> {
+    let mut excel = Excel::from_path(SAMPLE)?;
+    let sheets = excel.get_sheets()?;
+    assert!(excel
+        .get_worksheet_custom_properties(&sheets[0])?
+        .is_empty());
+    assert!(excel.get_web_extensions()?.is_empty());
+    assert!(!excel.has_data_model());
+    assert_eq!(excel.get_data_model()?, None);
+    assert!(excel.get_slicer_states()?.is_empty());
+    assert!(excel.get_timeline_states()?.is_empty());
+    assert_eq!(
+        excel.get_summary()?,
+        WorkbookSummary {
+            content_type: Some(WorkbookContentType::Workbook),
+            sheets: sheets.clone(),
+            has_macro_sheets: false,
+            web_extensions: vec![],
+            has_data_model: false,
+        }
+    );
+
+    let connections = format!(
+        r#"<?xml version="1.0" encoding="UTF-8"?><connections xmlns="{}"><connection id="1" name="Query - Sales" type="5"><dbPr connection="Provider=Microsoft.Mashup.OleDb.1" command="SELECT * FROM [Sales]"/></connection><connection id="2" name="ThisWorkbookDataModel" type="5"><dbPr connection="Data Model Connection" command="Model" commandType="1"/><extLst><ext uri="{{DE250136-89BD-433C-8126-D09CA5730AF9}}" xmlns:x15="{}"><x15:connection id="" model="1"/></ext></extLst></connection></connections>"#,
+        MAIN, X15
+    );
+    let cache_definition = |source: &str, cache_id: u64| {
+        format!(
+            r#"<?xml version="1.0" encoding="UTF-8"?><pivotCacheDefinition xmlns="{}" xmlns:r="{}">{}<cacheFields count="1"><cacheField name="Region" numFmtId="0"><sharedItems count="3"><s v="East"/><s v="West"/><m/></sharedItems></cacheField></cacheFields><extLst><ext uri="{{725AE2AE-9491-48be-B2B4-4EB974FC3084}}" xmlns:x14="{}"><x14:pivotCacheDefinition pivotCacheId="{}"/></ext></extLst></pivotCacheDefinition>"#,
+            MAIN, RELATIONSHIPS, source, X14, cache_id
+        )
+    };
+    let tabular_slicer = format!(
+        r#"<?xml version="1.0" encoding="UTF-8"?><slicerCacheDefinition xmlns="{}" name="Slicer_Region" sourceName="Region"><pivotTables><pivotTable tabId="1" name="PivotTable1"/></pivotTables><data><tabular pivotCacheId="11"><items count="4"><i x="0" s="1"/><i x="1"/><i x="2" s="1" nd="1"/><i x="7"/></items></tabular></data></slicerCacheDefinition>"#,
+        X14
+    );
+    let olap_slicer = format!(
+        r#"<?xml version="1.0" encoding="UTF-8"?><slicerCacheDefinition xmlns="{}" name="Slicer_Product" sourceName="[Sales].[Product]"><data><olap pivotCacheId="12"><levels count="1"><level uniqueName="[Sales].[Product].[Product]" sourceCaption="Product" count="2"><ranges><range startItem="0"><i n="[Sales].[Product].&amp;[Ink]" c="Ink" s="1"/><i n="[Sales].[Product].&amp;[Pen]" c="Pen" s="1"/></range></ranges></level></levels><selections count="1"><selection n="[Sales].[Product].[All]"/></selections></olap></data></slicerCacheDefinition>"#,
+        X14
+    );
+    let timeline = format!(
+        r#"<?xml version="1.0" encoding="UTF-8"?><timelineCacheDefinition xmlns="{}" name="NativeTimeline_Date" sourceName="Date"><pivotTables><pivotTable tabId="1" name="PivotTable1"/></pivotTables><state minimalRefreshVersion="6" lastRefreshVersion="6" pivotCacheId="11" filterType="dateBetween"><selection startDate="2019-01-01T00:00:00" endDate="2019-12-31T00:00:00"/><bounds startDate="2018-01-01T00:00:00" endDate="2021-01-01T00:00:00"/></state></timelineCacheDefinition>"#,
+        X15
+    );
+    let unfiltered_timeline = format!(
+        r#"<?xml version="1.0" encoding="UTF-8"?><timelineCacheDefinition xmlns="{}" name="NativeTimeline_Shipped" sourceName="Shipped"><state pivotCacheId="11" filterType="none"><bounds startDate="2018-01-01T00:00:00" endDate="2021-01-01T00:00:00"/></state></timelineCacheDefinition>"#,
+        X15
+    );
+
+    let mut excel = Package::open(SAMPLE)?
+        // custom properties of the first sheet, the second one refers to a missing part
+        .replace_in(
+            SHEET1,
+            "</worksheet>",
+            r#"<customProperties><customPr name="_pios_id" r:id="rId2"/><customPr name="missing" r:id="rId3"/></customProperties></worksheet>"#,
+        )
+        .replace_in(
+            SHEET1_RELS,
+            "</Relationships>",
+            &format!(
+                r#"<Relationship Id="rId2" Type="{0}/customProperty" Target="../customProperty1.bin"/><Relationship Id="rId3" Type="{0}/customProperty" Target="../customProperty2.bin"/></Relationships>"#,
+                RELATIONSHIPS
+            ),
+        )
+        .replace("xl/customProperty1.bin", utf16le("4f2c9a"))
+        // two add-ins, the first one opened in a task pane
+        .replace(
+            "xl/webextensions/webextension1.xml",
+            web_extension("{52811C31-4593-43B8-A697-EB873422D156}", "wa104380862"),
+        )
+        .replace(
+            "xl/webextensions/webextension2.xml",
+            web_extension("{7C2E2A1B-0000-4000-8000-000000000002}", "wa200000113"),
+        )
+        .replace(
+            "xl/webextensions/taskpanes.xml",
+            r#"<?xml version="1.0" encoding="UTF-8"?><wetp:taskpanes xmlns:wetp="http://schemas.microsoft.com/office/webextensions/taskpanes/2010/11"><wetp:taskpane dockstate="right" visibility="0" width="350" row="4"><wetp:webextensionref xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships" r:id="rId1"/></wetp:taskpane></wetp:taskpanes>"#,
+        )
+        .replace(
+            "xl/webextensions/_rels/taskpanes.xml.rels",
+            r#"<?xml version="1.0" encoding="UTF-8"?><Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships"><Relationship Id="rId1" Type="http://schemas.microsoft.com/office/2011/relationships/webextension" Target="webextension1.xml"/></Relationships>"#,
+        )
+        // data model, backing the first pivot cache through the model connection
+        .replace("xl/model/item.data", vec![0u8; 4096])
+        .replace("xl/connections.xml", connections)
+        .replace(
+            "xl/pivotCache/pivotCacheDefinition1.xml",
+            cache_definition(r#"<cacheSource type="external" connectionId="2"/>"#, 11),
+        )
+        .replace(
+            "xl/pivotCache/pivotCacheDefinition2.xml",
+            cache_definition(r#"<cacheSource type="external" connectionId="1"/>"#, 12),
+        )
+        .replace_in(
+            WORKBOOK,
+            "</sheets>",
+            r#"</sheets><pivotCaches><pivotCache cacheId="3" r:id="rId7"/><pivotCache cacheId="4" r:id="rId8"/></pivotCaches>"#,
+        )
+        // slicers and timelines
+        .replace("xl/slicerCaches/slicerCache1.xml", tabular_slicer)
+        .replace("xl/slicerCaches/slicerCache2.xml", olap_slicer)
+        .replace("xl/timelineCaches/timelineCache1.xml", timeline)
+        .replace("xl/timelineCaches/timelineCache2.xml", unfiltered_timeline)
+        .replace_in(
+            WORKBOOK_RELS,
+            "</Relationships>",
+            &format!(
+                r#"<Relationship Id="rId7" Type="{0}/pivotCacheDefinition" Target="pivotCache/pivotCacheDefinition1.xml"/><Relationship Id="rId8" Type="{0}/pivotCacheDefinition" Target="pivotCache/pivotCacheDefinition2.xml"/><Relationship Id="rId9" Type="http://schemas.microsoft.com/office/2011/relationships/powerPivotData" Target="model/item.data"/><Relationship Id="rId10" Type="http://schemas.microsoft.com/office/2007/relationships/slicerCache" Target="slicerCaches/slicerCache1.xml"/><Relationship Id="rId11" Type="http://schemas.microsoft.com/office/2007/relationships/slicerCache" Target="slicerCaches/slicerCache2.xml"/><Relationship Id="rId12" Type="http://schemas.microsoft.com/office/2011/relationships/timelineCache" Target="timelineCaches/timelineCache1.xml"/><Relationship Id="rId13" Type="http://schemas.microsoft.com/office/2011/relationships/timelineCache" Target="timelineCaches/timelineCache2.xml"/></Relationships>"#,
+                RELATIONSHIPS
+            ),
+        )
+        .excel()?;
+
+    // custom properties, whose part is missing are skipped
+    let properties = excel.get_worksheet_custom_properties(&sheets[0])?;
+    assert_eq!(
+        properties,
+        vec![CustomProperty {
+            name: "_pios_id".to_string(),
+            path: "xl/customProperty1.bin".to_string(),
+            data: utf16le("4f2c9a"),
+        }]
+    );
+    assert!(excel
+        .get_worksheet_custom_properties(&sheets[1])?
+        .is_empty());
+
+    // add-ins, alternate references are not taken as the primary one
+    let extensions = excel.get_web_extensions()?;
+    assert_eq!(extensions.len(), 2);
+    assert_eq!(
+        extensions[0].id.as_deref(),
+        Some("{52811C31-4593-43B8-A697-EB873422D156}")
+    );
+    assert_eq!(extensions[0].add_in_id.as_deref(), Some("wa104380862"));
+    assert_eq!(extensions[0].store_version.as_deref(), Some("1.1.0.0"));
+    assert_eq!(extensions[0].store.as_deref(), Some("en-US"));
+    assert_eq!(extensions[0].store_type.as_deref(), Some("OMEX"));
+    assert!(extensions[0].in_taskpane);
+    assert_eq!(extensions[1].add_in_id.as_deref(), Some("wa200000113"));
+    assert!(!extensions[1].in_taskpane);
+
+    // data model, backing the pivot cache whose connection is the model connection
+    assert!(excel.has_data_model());
+    let data_model = excel.get_data_model()?.unwrap();
+    assert_eq!(data_model.path, "xl/model/item.data");
+    assert_eq!(data_model.size, 4096);
+    assert!(data_model.compressed_size < data_model.size);
+    assert_eq!(data_model.pivot_cache_ids, vec![3]);
+
+    // selection of the slicers, items resolved against the pivot cache
+    let slicers = excel.get_slicer_states()?;
+    assert_eq!(slicers.len(), 2);
+    assert_eq!(slicers[0].name.as_deref(), Some("Slicer_Region"));
+    assert_eq!(slicers[0].source_name.as_deref(), Some("Region"));
+    assert_eq!(
+        slicers[0].items,
+        vec![
+            SlicerItem {
+                value: "East".to_string(),
+                selected: true,
+                has_data: true,
+            },
+            SlicerItem {
+                value: "West".to_string(),
+                selected: false,
+                has_data: true,
+            },
+            // missing value in the source
+            SlicerItem {
+                value: String::new(),
+                selected: true,
+                has_data: false,
+            },
+            // unresolved index
+            SlicerItem {
+                value: "7".to_string(),
+                selected: false,
+                has_data: true,
+            },
+        ]
+    );
+    assert_eq!(slicers[0].selected_items(), vec!["East", ""]);
+    assert!(slicers[0].is_filtered());
+    // OLAP slicers are listed by caption
+    assert_eq!(slicers[1].name.as_deref(), Some("Slicer_Product"));
+    assert_eq!(slicers[1].selected_items(), vec!["Ink", "Pen"]);
+    assert!(!slicers[1].is_filtered());
+
+    let timelines = excel.get_timeline_states()?;
+    assert_eq!(
+        timelines[0],
+        TimelineState {
+            name: Some("NativeTimeline_Date".to_string()),
+            source_name: Some("Date".to_string()),
+            filter_type: Some("dateBetween".to_string()),
+            selection_start: Some("2019-01-01T00:00:00".to_string()),
+            selection_end: Some("2019-12-31T00:00:00".to_string()),
+            bounds_start: Some("2018-01-01T00:00:00".to_string()),
+            bounds_end: Some("2021-01-01T00:00:00".to_string()),
+        }
+    );
+    assert!(timelines[0].is_filtered());
+    assert_eq!(timelines[1].selection_start, None);
+    assert!(!timelines[1].is_filtered());
+
+    let summary = excel.get_summary()?;
+    assert_eq!(summary.content_type, Some(WorkbookContentType::Workbook));
+    assert_eq!(summary.sheets, sheets);
+    assert!(!summary.has_macro_sheets);
+    assert_eq!(summary.web_extensions, extensions);
+    assert!(summary.has_data_model);
+
+    println!("workbook features read successfully");
+    Ok(())
+}
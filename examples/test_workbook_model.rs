@@ -0,0 +1,64 @@
+mod common;
+
+use std::{sync::Arc, thread};
+
+use common::SAMPLE;
+use excel_reader::prelude::*;
+
+/// Cache the processed model of a workbook and load it back, and share it between threads.
+fn main() -> anyhow::Result<()> {
+    let mut excel = Excel::from_path(SAMPLE)?;
+    let model = excel.to_processed_model()?;
+
+    let json = model.to_json()?;
+    assert_eq!(Workbook::from_json(&json)?, model);
+    let bytes = model.to_bincode()?;
+    assert!(bytes.len() < json.len());
+    assert_eq!(Workbook::from_bincode(&bytes)?, model);
+    assert!(Workbook::from_json("{}").is_err());
+    assert!(Workbook::from_bincode(&bytes[..bytes.len() / 2]).is_err());
+
+    // cells are put back in order, so that lookups work on models edited or produced elsewhere
+    let mut shuffled = model.clone();
+    shuffled.sheets[0].cells.reverse();
+    let coordinate = Coordinate::from_point((9, 2));
+    let reloaded = Workbook::from_json(&shuffled.to_json()?)?;
+    assert_eq!(reloaded, model);
+    assert_eq!(
+        reloaded.sheets[0].get_cell(coordinate).map(|c| &c.value),
+        Some(&CellValueType::Numeric(100.0))
+    );
+    let reloaded = Workbook::from_bincode(&shuffled.to_bincode()?)?;
+    assert_eq!(reloaded, model);
+
+    // read only handle, queried from several threads
+    let shared = Excel::from_path(SAMPLE)?.into_shared()?;
+    assert_eq!(shared.model, model);
+    assert_eq!(shared.sheets, excel.get_sheets()?);
+    assert_eq!(
+        shared
+            .get_sheet("sheet 2 - custom grid lines")
+            .map(|s| s.sheet_id),
+        Some(2)
+    );
+    assert!(shared.get_sheet("Sheet 4").is_none());
+    assert!(shared.get_cell("Sheet 4", coordinate).is_none());
+
+    let handles: Vec<thread::JoinHandle<Option<CellValueType>>> = (0..4)
+        .map(|_| {
+            let shared = Arc::clone(&shared);
+            thread::spawn(move || {
+                shared
+                    .get_cell("Sheet 1 - Basic", coordinate)
+                    .map(|c| c.value.clone())
+            })
+        })
+        .collect();
+    for handle in handles {
+        assert_eq!(handle.join().unwrap(), Some(CellValueType::Numeric(100.0)));
+    }
+    assert_eq!(Arc::strong_count(&shared), 1);
+
+    println!("workbook model reloaded and shared successfully");
+    Ok(())
+}
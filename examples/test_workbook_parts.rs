@@ -0,0 +1,182 @@
+mod common;
+
+use common::{sample_with_moved_workbook, Package, EMPTY_SHEET, SAMPLE, WORKBOOK, WORKBOOK_RELS};
+use excel_reader::{packaging::content_type::WorkbookContentType, prelude::*};
+
+const CONTENT_TYPES: &str = "[Content_Types].xml";
+const WORKBOOK_MAIN: &str =
+    "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet.main+xml";
+const MACRO_SHEET: &str = r#"<?xml version="1.0" encoding="UTF-8"?><xm:macrosheet xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main" xmlns:xm="http://schemas.microsoft.com/office/excel/2006/main"><sheetData><row r="1"><c r="A1"><f>EXEC("calc.exe")</f></c></row></sheetData></xm:macrosheet>"#;
+
+/// Relationships and content types of the workbook parts: worksheet dependencies, dangling or orphaned sheets,
+/// macro sheets and the declared type of the workbook.
+fn main() -> anyhow::Result<()> {
+    // dependent parts of the worksheets, relative targets resolved to paths within the package
+    let mut excel = Excel::from_path(SAMPLE)?;
+    let sheets = excel.get_sheets()?;
+    let relationships = excel.get_worksheet(&sheets[0])?.relationships();
+    assert_eq!(relationships.hyperlinks().len(), 1);
+    assert_eq!(
+        relationships.get("rId1").map(|r| r.target),
+        Some("http://www.google.com".to_string())
+    );
+    assert!(relationships.get("rId2").is_none());
+    assert!(relationships.drawing().is_none());
+    assert!(relationships.table_parts().is_empty());
+    assert!(excel
+        .get_worksheet(&sheets[1])?
+        .relationships()
+        .relationships
+        .is_empty());
+
+    let mut excel = Excel::from_path("examples/table.xlsx")?;
+    let sheets = excel.get_sheets()?;
+    let relationships = excel.get_worksheet(&sheets[0])?.relationships();
+    assert_eq!(
+        relationships
+            .table_parts()
+            .iter()
+            .map(|r| r.target.as_str())
+            .collect::<Vec<_>>(),
+        vec!["xl/tables/table1.xml"]
+    );
+    assert!(relationships.hyperlinks().is_empty());
+
+    let mut excel = Excel::from_path("examples/workbook_drawing.xlsx")?;
+    let sheets = excel.get_sheets()?;
+    let relationships = excel.get_worksheet(&sheets[0])?.relationships();
+    assert_eq!(
+        relationships.drawing().map(|r| r.target),
+        Some("xl/drawings/drawing1.xml".to_string())
+    );
+    // matched on the whole last segment of the type
+    assert!(relationships.vml_drawings().is_empty());
+    assert_eq!(relationships.of_type("DRAWING").len(), 1);
+
+    // sheets and workbook relationships resolve to existing parts
+    let mut excel = Excel::from_path(SAMPLE)?;
+    assert!(excel.check_workbook_relationships()?.is_empty());
+    let mut excel = Package::open(SAMPLE)?
+        .replace_in(
+            WORKBOOK,
+            r#"sheetId="2" r:id="rId5""#,
+            r#"sheetId="2" r:id="rId9""#,
+        )
+        .replace_in(WORKBOOK_RELS, "theme/theme1.xml", "theme/theme2.xml")
+        .replace("xl/worksheets/sheet9.xml", EMPTY_SHEET)
+        .excel()?;
+    assert_eq!(
+        excel.check_workbook_relationships()?,
+        vec![
+            RelationshipIssue::MissingSheetRelationship {
+                sheet_name: "Sheet 2 - Custom grid lines".to_string(),
+                r_id: "rId9".to_string(),
+            },
+            RelationshipIssue::MissingPart {
+                r_id: "rId3".to_string(),
+                path: "xl/theme/theme2.xml".to_string(),
+            },
+            RelationshipIssue::OrphanedSheetPart {
+                path: "xl/worksheets/sheet9.xml".to_string(),
+            },
+        ]
+    );
+
+    // XLM macro sheets, hidden from the user
+    let mut excel = Excel::from_path(SAMPLE)?;
+    assert!(excel.get_macro_sheets()?.is_empty());
+    let mut excel = Package::open(SAMPLE)?
+        .replace("xl/macrosheets/sheet1.xml", MACRO_SHEET)
+        .replace_in(
+            WORKBOOK_RELS,
+            "</Relationships>",
+            r#"<Relationship Id="rId7" Type="http://schemas.microsoft.com/office/2006/relationships/xlMacrosheet" Target="macrosheets/sheet1.xml"/></Relationships>"#,
+        )
+        .replace_in(
+            WORKBOOK,
+            "</sheets>",
+            r#"<sheet name="Macro1" sheetId="4" state="veryHidden" r:id="rId7"/></sheets>"#,
+        )
+        .excel()?;
+    let macro_sheets = excel.get_macro_sheets()?;
+    assert_eq!(macro_sheets.len(), 1);
+    assert_eq!(macro_sheets[0].name, "Macro1");
+    assert_eq!(macro_sheets[0].r#type, SheetType::MacroSheet);
+    assert_eq!(macro_sheets[0].visible_state, SheetVisibleState::VeryHidden);
+    assert!(excel
+        .get_raw_sheet_xml(&macro_sheets[0])?
+        .contains(r#"<f>EXEC("calc.exe")</f>"#));
+    assert!(excel.check_workbook_relationships()?.is_empty());
+    let sheets = excel.get_sheets()?;
+    assert!(excel
+        .get_raw_sheet_xml(&sheets[0])?
+        .contains(r#"<dimension ref="A2:B9"/>"#));
+
+    // declared type of the workbook part
+    let mut excel = Excel::from_path(SAMPLE)?;
+    let content_type = excel.content_type()?;
+    assert_eq!(content_type, WorkbookContentType::Workbook);
+    assert_eq!(
+        content_type.mime_type(),
+        Some("application/vnd.openxmlformats-officedocument.spreadsheetml.sheet")
+    );
+    assert!(!content_type.is_macro_enabled());
+
+    for (declared, expected, mime_type) in [
+        (
+            "application/vnd.ms-excel.sheet.macroEnabled.main+xml",
+            WorkbookContentType::MacroEnabledWorkbook,
+            Some("application/vnd.ms-excel.sheet.macroEnabled.12"),
+        ),
+        (
+            "application/vnd.openxmlformats-officedocument.spreadsheetml.template.main+xml",
+            WorkbookContentType::Template,
+            Some("application/vnd.openxmlformats-officedocument.spreadsheetml.template"),
+        ),
+        (
+            "application/vnd.ms-excel.addin.macroEnabled.main+xml",
+            WorkbookContentType::AddIn,
+            Some("application/vnd.ms-excel.addin.macroEnabled.12"),
+        ),
+        (
+            "application/xml",
+            WorkbookContentType::Other("application/xml".to_string()),
+            None,
+        ),
+    ] {
+        let mut excel = Package::open(SAMPLE)?
+            .replace_in(CONTENT_TYPES, WORKBOOK_MAIN, declared)
+            .excel()?;
+        let content_type = excel.content_type()?;
+        assert_eq!(content_type.mime_type(), mime_type);
+        assert_eq!(
+            content_type.is_macro_enabled(),
+            declared.contains("macroEnabled")
+        );
+        assert_eq!(content_type, expected);
+    }
+
+    // the workbook part is the target of the officeDocument relationship of the package, not always xl/workbook.xml
+    let mut excel = sample_with_moved_workbook()?
+        .replace_in(
+            CONTENT_TYPES,
+            WORKBOOK_MAIN,
+            "application/vnd.ms-excel.sheet.macroEnabled.main+xml",
+        )
+        .excel()?;
+    assert_eq!(
+        excel.content_type()?,
+        WorkbookContentType::MacroEnabledWorkbook
+    );
+
+    let content_type = Excel::from_path("examples/sample.xlsb")?.content_type()?;
+    assert_eq!(content_type, WorkbookContentType::BinaryWorkbook);
+    assert_eq!(
+        content_type.mime_type(),
+        Some("application/vnd.ms-excel.sheet.binary.macroEnabled.12")
+    );
+    assert!(content_type.is_macro_enabled());
+
+    println!("workbook parts checked successfully");
+    Ok(())
+}
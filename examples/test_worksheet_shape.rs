@@ -0,0 +1,103 @@
+mod common;
+
+use std::io::Cursor;
+
+use common::{Package, SAMPLE, SHEET1};
+use excel_reader::{
+    common_types::Coordinate, excel::Excel,
+    processed::drawing::worksheet_drawing::cell_marker::CellMarker,
+    processed::spreadsheet::sheet::worksheet::cell::cell_value::CellValueType,
+};
+
+/// Read the layout of a worksheet without its cells, then stream the cells.
+fn main() -> anyhow::Result<()> {
+    let sheet = r#"<?xml version="1.0" encoding="UTF-8"?><worksheet xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main"><dimension ref="A1:C4"/><sheetViews><sheetView workbookViewId="0"><pane ySplit="1" topLeftCell="A2" activePane="bottomLeft" state="frozen"/></sheetView></sheetViews><sheetFormatPr defaultRowHeight="15"/><cols><col min="2" max="2" width="30" customWidth="1"/><col min="3" max="3" width="10" hidden="1"/></cols><sheetData>
+<row r="1" ht="24" customHeight="1"><c r="A1" t="inlineStr"><is><t>Name</t></is></c><c r="B1" t="inlineStr"><is><t>Comment</t></is></c></row>
+<row r="2"><c r="A2" t="inlineStr"><is><t>North</t></is></c><c r="B2"><v>2</v></c></row>
+<row r="3" hidden="1"><c r="A3" t="inlineStr"><is><t>South</t></is></c></row>
+<row r="4"><c r="A4" t="inlineStr"><is><t>East</t></is></c><c r="B4"><v>4</v></c><c r="C4"><v>5</v></c></row>
+</sheetData><mergeCells count="1"><mergeCell ref="B2:C2"/></mergeCells><dataValidations count="1"><dataValidation type="whole" allowBlank="1" sqref="B2:B4"><formula1>0</formula1><formula2>10</formula2></dataValidation></dataValidations></worksheet>"#;
+
+    let bytes = Package::open(SAMPLE)?.replace(SHEET1, sheet).bytes()?;
+
+    let mut excel = Excel::from_reader(Cursor::new(bytes))?;
+    let sheets = excel.get_sheets()?;
+    let shape = excel.get_worksheet_shape(&sheets[0])?;
+    let full = excel.get_worksheet(&sheets[0])?;
+
+    // no cells parsed, the cells of the dimension are all empty
+    assert!(shape
+        .get_cells()?
+        .iter()
+        .all(|c| c.value == CellValueType::Empty));
+    assert_eq!(shape.dimension.map(|d| d.to_a1()).as_deref(), Some("A1:C4"));
+
+    // same layout as the fully loaded worksheet
+    for row in 1..=5 {
+        assert_eq!(shape.row_height(row), full.row_height(row));
+    }
+    for col in 1..=4 {
+        assert_eq!(shape.column_width(col), full.column_width(col));
+    }
+    assert_eq!(shape.row_height(1), 24.0);
+    assert_eq!(shape.row_height(2), 15.0);
+    assert_eq!(shape.row_height(3), 0.0);
+    assert_eq!(shape.column_width(3), 0.0);
+    assert_eq!(
+        shape.cell_offset(Coordinate::from_point((4, 4))),
+        full.cell_offset(Coordinate::from_point((4, 4)))
+    );
+    // coordinates beyond the last row and column, ie: read from a drawing anchor, are at the end of the sheet
+    assert_eq!(
+        full.cell_offset(Coordinate::from_point((u64::MAX, u64::MAX))),
+        full.cell_offset(Coordinate::from_point((1_048_577, 16_385)))
+    );
+
+    assert_eq!(shape.merged_cells.len(), 1);
+    assert_eq!(shape.merged_cells[0].to_a1(), "B2:C2");
+    assert_eq!(shape.frozen_row_count(), 1);
+    assert_eq!(shape.frozen_column_count(), 0);
+    assert!(shape
+        .validation_for_cell(Coordinate::from_point((3, 2)))
+        .is_some());
+    assert!(shape
+        .validation_for_cell(Coordinate::from_point((3, 1)))
+        .is_none());
+
+    // without cells, the rows are not read at all and take the default height
+    let without_cells = excel.get_worksheet_without_cells(&sheets[0])?;
+    assert_eq!(without_cells.row_height(1), 15.0);
+
+    // cells are then streamed
+    let rows = shape
+        .rows(&mut excel)?
+        .collect::<anyhow::Result<Vec<_>>>()?;
+    assert_eq!(rows.len(), 4);
+    assert_eq!(rows[3].cells.len(), 3);
+    assert!(rows[2].hidden);
+
+    // a drawing anchored to a bogus column ends at the end of the sheet
+    let mut excel = Package::open("examples/workbook_drawing.xlsx")?
+        .replace_in(
+            "xl/drawings/drawing2.xml",
+            "<xdr:to><xdr:col>4</xdr:col>",
+            "<xdr:to><xdr:col>4294967295</xdr:col>",
+        )
+        .excel()?;
+    let links = excel.get_worksheet_with_name("links")?;
+    let bounds = links.get_drawings()[0].bounds.clone().unwrap();
+    assert_eq!(
+        bounds.x + bounds.width,
+        links
+            .marker_position(&CellMarker {
+                col: 16_385,
+                col_offset: 30.0,
+                row: 1,
+                row_offset: 0.0,
+            })
+            .0
+    );
+
+    println!("worksheet shape read successfully");
+    Ok(())
+}
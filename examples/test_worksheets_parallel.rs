@@ -0,0 +1,80 @@
+use std::{
+    fs::File,
+    io::{BufReader, Cursor},
+};
+
+use excel_reader::prelude::*;
+use excel_reader::processed::spreadsheet::sheet_basic_info::SheetType;
+
+/// Parse all worksheets of a workbook concurrently, each thread with its own zip reader.
+fn main() -> anyhow::Result<()> {
+    let mut excel = Excel::from_path("examples/sample.xlsx")?;
+    let worksheets = excel.get_worksheets_parallel()?;
+
+    // same worksheets, in the same order, as parsed one after the other
+    let sheets = excel.get_sheets()?;
+    let expected: Vec<_> = sheets
+        .iter()
+        .filter(|s| s.r#type == SheetType::WorkSheet)
+        .collect();
+    assert!(expected.len() > 1);
+    assert_eq!(worksheets.len(), expected.len());
+    for (worksheet, sheet) in worksheets.iter().zip(expected) {
+        assert_eq!(worksheet.name, sheet.name);
+        let serial = excel.get_worksheet(sheet)?;
+        assert_eq!(worksheet.dimension, serial.dimension);
+        assert_eq!(
+            worksheet
+                .get_cells()?
+                .iter()
+                .map(|c| &c.value)
+                .collect::<Vec<_>>(),
+            serial
+                .get_cells()?
+                .iter()
+                .map(|c| &c.value)
+                .collect::<Vec<_>>()
+        );
+    }
+
+    // with lazily indexed shared strings as well
+    let options = ExcelOptions {
+        lazy_shared_strings: true,
+        ..Default::default()
+    };
+    let mut lazy = Excel::from_path_with_options("examples/sample.xlsx", options)?;
+    let lazy_worksheets = lazy.get_worksheets_parallel()?;
+    assert_eq!(
+        lazy_worksheets[0]
+            .get_cells()?
+            .iter()
+            .map(|c| &c.value)
+            .collect::<Vec<_>>(),
+        worksheets[0]
+            .get_cells()?
+            .iter()
+            .map(|c| &c.value)
+            .collect::<Vec<_>>()
+    );
+
+    // from bytes in memory, copied or borrowed by each thread
+    let bytes = std::fs::read("examples/sample.xlsx")?;
+    let values = |worksheets: &[Worksheet]| -> anyhow::Result<Vec<Vec<CellValueType>>> {
+        worksheets
+            .iter()
+            .map(|w| Ok(w.get_cells()?.into_iter().map(|c| c.value).collect()))
+            .collect()
+    };
+    let expected = values(&worksheets)?;
+    let mut owned = Excel::from_bytes(bytes.clone())?;
+    assert_eq!(values(&owned.get_worksheets_parallel()?)?, expected);
+    let mut borrowed = Excel::from_reader(Cursor::new(bytes.as_slice()))?;
+    assert_eq!(values(&borrowed.get_worksheets_parallel()?)?, expected);
+
+    // a file read through a reader has no path to open again
+    let mut reader = Excel::from_reader(BufReader::new(File::open("examples/sample.xlsx")?))?;
+    assert!(reader.get_worksheets_parallel().is_err());
+
+    println!("worksheets parsed in parallel successfully");
+    Ok(())
+}
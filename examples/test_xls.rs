@@ -0,0 +1,84 @@
+use excel_reader::{
+    common_types::Coordinate,
+    processed::spreadsheet::{
+        sheet::worksheet::cell::cell_value::{error_value::CellErrorType, CellValueType},
+        sheet_basic_info::SheetVisibleState,
+    },
+    xls::Xls,
+};
+
+fn text(value: &CellValueType) -> String {
+    match value {
+        CellValueType::PlainText(text) => text.text.clone(),
+        other => panic!("expected text, got {:?}", other),
+    }
+}
+
+fn main() -> anyhow::Result<()> {
+    let xls = Xls::from_path("examples/sample.xls")?;
+
+    let sheets = xls.get_sheets()?;
+    assert_eq!(sheets.len(), 2);
+    assert_eq!(sheets[0].name, "Data");
+    assert_eq!(sheets[1].name, "Hidden é");
+    assert_eq!(sheets[1].visible_state, SheetVisibleState::Hidden);
+
+    let worksheet = xls.get_worksheet_with_name("Data")?;
+    let cell = |row: u64, col: u64| worksheet.get_cell(Coordinate::from_point((row, col)));
+
+    // shared strings, one of them split across a CONTINUE record
+    let a1 = cell(1, 1)?;
+    assert_eq!(text(&a1.value), "Hello");
+    assert_eq!(a1.property.font.name, "Times");
+    assert!(a1.property.font.italic);
+    assert!(a1.property.alignment.wrap_text);
+    assert_eq!(text(&cell(1, 2)?.value), "Woréld");
+    assert_eq!(
+        xls.get_raw_shared_strings().string_item.unwrap()[2]
+            .text
+            .as_deref(),
+        Some("xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxyyyyyyyyyyyyyyyyyyyyyyyyyyyyyy")
+    );
+
+    // font inherited from the style format
+    assert_eq!(cell(1, 2)?.property.font.name, "Arial");
+
+    // RK numbers, floating point numbers with a number format
+    assert_eq!(cell(2, 1)?.value, CellValueType::Numeric(123.0));
+    assert_eq!(cell(2, 2)?.value, CellValueType::Numeric(123.45));
+    let c2 = cell(2, 3)?;
+    assert_eq!(c2.value, CellValueType::Numeric(45000.0));
+    assert_eq!(
+        c2.property.numbering_format.format_code.as_deref(),
+        Some("yyyy-mm-dd")
+    );
+
+    // booleans, errors and labels
+    assert_eq!(cell(3, 1)?.value, CellValueType::Bool(true));
+    assert_eq!(cell(3, 2)?.value, CellValueType::Error(CellErrorType::Div0));
+    assert_eq!(text(&cell(3, 3)?.value), "label");
+
+    // formulas hold their last calculated value
+    assert_eq!(text(&cell(3, 4)?.value), "fstring");
+    assert_eq!(cell(4, 1)?.value, CellValueType::Numeric(7.5));
+    assert_eq!(cell(4, 2)?.value, CellValueType::Bool(true));
+
+    // cells of the chart embedded in the sheet are skipped
+    assert_eq!(worksheet.get_cells()?.len(), 20);
+
+    assert_eq!(worksheet.merged_cells.len(), 1);
+    assert_eq!(worksheet.row_height(1), 30.0);
+
+    let hidden = xls.get_worksheet_with_sheet_id(&2)?;
+    assert!(hidden.get_cells()?.is_empty());
+
+    // a FAT sector count larger than the file is an error, not an allocation of the announced size
+    let mut data = std::fs::read("examples/sample.xls")?;
+    data[0x2C..0x30].copy_from_slice(&[0xFF; 4]);
+    if let Ok(xls) = Xls::from_reader(std::io::Cursor::new(data)) {
+        let _ = xls.get_sheets();
+    }
+
+    println!("xls sample read successfully");
+    Ok(())
+}
@@ -0,0 +1,76 @@
+mod common;
+
+use common::SAMPLE;
+use excel_reader::prelude::*;
+
+const SAMPLE_XLSB: &str = "examples/sample.xlsb";
+
+/// Read the binary counterpart of the sample: the same sheets, values and cell formats as the xlsx file.
+fn main() -> anyhow::Result<()> {
+    let mut xlsx = Excel::from_path(SAMPLE)?;
+    let mut xlsb = Excel::from_path(SAMPLE_XLSB)?;
+
+    let sheets = xlsb.get_sheets()?;
+    assert_eq!(
+        sheets.iter().map(|s| &s.name).collect::<Vec<_>>(),
+        xlsx.get_sheets()?
+            .iter()
+            .map(|s| &s.name)
+            .collect::<Vec<_>>()
+    );
+
+    for (index, sheet) in sheets.iter().enumerate() {
+        let expected = xlsx.get_worksheet_with_name(&sheet.name)?;
+        let worksheet = xlsb.get_worksheet(sheet)?;
+        assert_eq!(worksheet.dimension, expected.dimension);
+        assert_eq!(worksheet.merged_cells, expected.merged_cells);
+
+        let cells = worksheet.get_cells()?;
+        let expected_cells = expected.get_cells()?;
+        assert_eq!(cells.len(), expected_cells.len());
+        for (cell, expected) in cells.iter().zip(expected_cells.iter()) {
+            assert_eq!(cell.coordinate, expected.coordinate);
+            assert_eq!(cell.value.to_string(), expected.value.to_string());
+            assert_eq!(cell.typed_value(false), expected.typed_value(false));
+            assert_eq!(cell.formatted_value(false), expected.formatted_value(false));
+            assert_eq!(cell.style_index, expected.style_index);
+            assert_eq!(
+                cell.property.numbering_format,
+                expected.property.numbering_format
+            );
+            assert_eq!(cell.property.font.name, expected.property.font.name);
+            assert_eq!(cell.property.font.size, expected.property.font.size);
+            assert_eq!(cell.property.font.bold, expected.property.font.bold);
+            assert_eq!(cell.property.font.italic, expected.property.font.italic);
+
+            // numbers are typed `n`, the default type of xlsx cells,
+            // and B7 of the third sheet is the string result of a formula
+            let raw_type = match (index, cell.coordinate.to_a1().as_str()) {
+                (2, "B7") => Some("str"),
+                _ => expected.raw_type.as_deref().or(Some("n")),
+            };
+            assert_eq!(cell.raw_type.as_deref().or(Some("n")), raw_type);
+        }
+    }
+
+    // string result of a formula, as `t="str"` cells of xlsx files
+    let worksheet = xlsb.get_worksheet(&sheets[2])?;
+    let b7 = worksheet.get_cell(Coordinate::from_point((7, 2)))?;
+    assert_eq!(b7.raw_type.as_deref(), Some("str"));
+    assert_eq!(
+        b7.typed_value(false),
+        CellValue::Text("Itsuki5-sheet3-bg-green".to_string())
+    );
+    let raw = xlsb.get_raw_worksheet(&sheets[2])?;
+    let rows = raw.sheet_data.unwrap().rows.unwrap();
+    let raw_b7 = rows[6].cells.as_ref().unwrap()[1].clone();
+    assert_eq!(raw_b7.r#type.as_deref(), Some("str"));
+    assert!(raw_b7.inline_string.is_none());
+    assert_eq!(
+        raw_b7.cell_value.map(|v| v.raw_value).as_deref(),
+        Some("Itsuki5-sheet3-bg-green")
+    );
+
+    println!("xlsb sample read successfully");
+    Ok(())
+}
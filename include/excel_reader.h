@@ -0,0 +1,93 @@
+#ifndef EXCEL_READER_H
+#define EXCEL_READER_H
+
+/* Generated from src/ffi.rs with cbindgen, see cbindgen.toml. Do not edit by hand. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+// Cells of a worksheet being iterated, from `excel_reader_cells`, freed with `excel_reader_cells_free`.
+typedef struct ExcelReaderCells ExcelReaderCells;
+
+// A workbook opened with `excel_reader_open` or `excel_reader_open_bytes`, closed with `excel_reader_close`.
+typedef struct ExcelReaderWorkbook ExcelReaderWorkbook;
+
+// A cell returned by `excel_reader_cells_next`.
+//
+// Strings belong to the iterator and are valid until the next call on it.
+typedef struct ExcelReaderCell {
+  // 1 based row index
+  uint64_t row;
+  // 1 based column index
+  uint64_t column;
+  // value as displayed by Excel, with the number format of the cell applied
+  const char *value;
+  // the cell as JSON: typed value, formula, style
+  const char *json;
+} ExcelReaderCell;
+
+// Open a workbook from a path. NULL on failure.
+//
+// # Safety
+// path must be a nul terminated UTF-8 string.
+struct ExcelReaderWorkbook *excel_reader_open(const char *path);
+
+// Open a workbook from the content of a file, copied. NULL on failure.
+//
+// # Safety
+// data must point to len readable bytes.
+struct ExcelReaderWorkbook *excel_reader_open_bytes(const uint8_t *data, size_t len);
+
+// Close a workbook. NULL is ignored.
+//
+// # Safety
+// workbook must come from `excel_reader_open` or `excel_reader_open_bytes`, and not be used afterwards.
+void excel_reader_close(struct ExcelReaderWorkbook *workbook);
+
+// Names of the worksheets as a JSON array of strings, in workbook order. NULL on failure.
+//
+// # Safety
+// workbook must be an open workbook.
+char *excel_reader_sheet_names(struct ExcelReaderWorkbook *workbook);
+
+// Values of a worksheet as displayed by Excel, as a JSON array of rows of strings. NULL on failure.
+//
+// Rows and columns start at the top left cell of the data of the worksheet, ie: `[["Name", "Price"], ["apple", "1.50"]]`.
+//
+// # Safety
+// workbook must be an open workbook, name a nul terminated UTF-8 string.
+char *excel_reader_sheet_rows(struct ExcelReaderWorkbook *workbook, const char *name);
+
+// Iterate the cells of a worksheet holding a value, row by row. NULL on failure.
+//
+// # Safety
+// workbook must be an open workbook, name a nul terminated UTF-8 string.
+struct ExcelReaderCells *excel_reader_cells(struct ExcelReaderWorkbook *workbook, const char *name);
+
+// Write the next cell into cell. False at the end of the worksheet or on failure.
+//
+// # Safety
+// cells must come from `excel_reader_cells`, cell must point to a writable `ExcelReaderCell`.
+bool excel_reader_cells_next(struct ExcelReaderCells *cells, struct ExcelReaderCell *cell);
+
+// Free an iterator of cells. NULL is ignored.
+//
+// # Safety
+// cells must come from `excel_reader_cells`, and not be used afterwards.
+void excel_reader_cells_free(struct ExcelReaderCells *cells);
+
+// Free a string returned by the library. NULL is ignored.
+//
+// # Safety
+// string must be returned as `char *` by a function of the library, and not be used afterwards.
+void excel_reader_string_free(char *string);
+
+// Message of the last failure on the calling thread, NULL if none.
+//
+// Owned by the library and valid until the next failing call on the thread.
+const char *excel_reader_last_error(void);
+
+#endif  /* EXCEL_READER_H */
@@ -0,0 +1,107 @@
+//! Reading workbooks from async code, enabled with the `async` feature.
+//!
+//! Parsing is CPU bound and the zip package needs random access, so the package is read into memory
+//! asynchronously, and every call then runs on the blocking thread pool of tokio.
+use anyhow::anyhow;
+use std::{
+    io::Cursor,
+    sync::{Arc, Mutex},
+};
+use tokio::io::{AsyncRead, AsyncReadExt};
+
+use crate::{
+    excel::{Excel, ExcelOptions},
+    processed::spreadsheet::{
+        sheet::worksheet::Worksheet, sheet_basic_info::SheetBasicInfo, workbook::Workbook,
+    },
+};
+
+impl Excel<Cursor<Vec<u8>>> {
+    /// Read a workbook from an async reader, ie: a `tokio::fs::File` or the body of a request.
+    pub async fn from_async_reader<R: AsyncRead + Unpin>(reader: R) -> anyhow::Result<AsyncExcel> {
+        return Self::from_async_reader_with_options(reader, ExcelOptions::default()).await;
+    }
+
+    pub async fn from_async_reader_with_options<R: AsyncRead + Unpin>(
+        mut reader: R,
+        options: ExcelOptions,
+    ) -> anyhow::Result<AsyncExcel> {
+        let mut data: Vec<u8> = vec![];
+        reader.read_to_end(&mut data).await?;
+        let excel =
+            tokio::task::spawn_blocking(move || Excel::from_bytes_with_options(data, options))
+                .await??;
+        return Ok(AsyncExcel::new(excel));
+    }
+}
+
+/// Workbook used from async code, see `Excel::from_async_reader`.
+///
+/// Calls run on the blocking thread pool of tokio, one at a time, and must be made within a tokio runtime.
+/// Clones share the same workbook and its cached parts.
+#[derive(Clone)]
+pub struct AsyncExcel {
+    excel: Arc<Mutex<Excel<Cursor<Vec<u8>>>>>,
+}
+
+impl AsyncExcel {
+    pub fn new(excel: Excel<Cursor<Vec<u8>>>) -> Self {
+        return Self {
+            excel: Arc::new(Mutex::new(excel)),
+        };
+    }
+
+    /// Run any function of `Excel` on the blocking thread pool, ie: `excel.run(|e| e.defined_names()).await`.
+    pub async fn run<T, F>(&self, f: F) -> anyhow::Result<T>
+    where
+        F: FnOnce(&mut Excel<Cursor<Vec<u8>>>) -> anyhow::Result<T> + Send + 'static,
+        T: Send + 'static,
+    {
+        let excel = self.excel.clone();
+        return tokio::task::spawn_blocking(move || {
+            let mut excel = excel
+                .lock()
+                .map_err(|_| anyhow!("A previous call on the workbook panicked."))?;
+            f(&mut excel)
+        })
+        .await?;
+    }
+
+    /// See `Excel::get_sheets`.
+    pub async fn get_sheets(&self) -> anyhow::Result<Vec<SheetBasicInfo>> {
+        return self.run(|excel| excel.get_sheets()).await;
+    }
+
+    /// See `Excel::get_worksheet`.
+    pub async fn get_worksheet(&self, sheet: &SheetBasicInfo) -> anyhow::Result<Worksheet> {
+        let sheet = sheet.clone();
+        return self.run(move |excel| excel.get_worksheet(&sheet)).await;
+    }
+
+    /// See `Excel::get_worksheet_without_cells`.
+    pub async fn get_worksheet_without_cells(&self, sheet: &SheetBasicInfo) -> anyhow::Result<Worksheet> {
+        let sheet = sheet.clone();
+        return self
+            .run(move |excel| excel.get_worksheet_without_cells(&sheet))
+            .await;
+    }
+
+    /// See `Excel::get_worksheet_with_name`.
+    pub async fn get_worksheet_with_name(&self, name: &str) -> anyhow::Result<Worksheet> {
+        let name = name.to_string();
+        return self
+            .run(move |excel| excel.get_worksheet_with_name(&name))
+            .await;
+    }
+
+    /// See `Excel::to_processed_model`.
+    pub async fn to_processed_model(&self) -> anyhow::Result<Workbook> {
+        return self.run(|excel| excel.to_processed_model()).await;
+    }
+
+    /// The workbook, None while clones of this handle exist.
+    pub fn into_inner(self) -> Option<Excel<Cursor<Vec<u8>>>> {
+        let excel = Arc::into_inner(self.excel)?;
+        return Some(excel.into_inner().unwrap_or_else(|e| e.into_inner()));
+    }
+}
@@ -0,0 +1,22 @@
+//! `excel-reader` command line tool, see `excel_reader::cli`.
+use std::{io::ErrorKind, process::exit};
+
+fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let mut out = std::io::stdout().lock();
+    match excel_reader::cli::run(&args, &mut out) {
+        Ok(status) => exit(status),
+        Err(error) => {
+            // output piped into a command that exited early, ie: `head`
+            let broken_pipe = error
+                .root_cause()
+                .downcast_ref::<std::io::Error>()
+                .is_some_and(|e| e.kind() == ErrorKind::BrokenPipe);
+            if broken_pipe {
+                exit(0);
+            }
+            eprintln!("error: {:#}", error);
+            exit(1);
+        }
+    }
+}
@@ -0,0 +1,274 @@
+//! Command line tool inspecting workbooks, enabled with the `cli` feature and installed as `excel-reader`:
+//! ```text
+//! excel-reader sheets book.xlsx
+//! excel-reader dump book.xlsx --sheet 2 --format json
+//! excel-reader validate book.xlsx
+//! excel-reader meta book.xlsx
+//! ```
+//!
+//! Commands write to the writer given to `run`, so that they can be used and tested without spawning a process.
+use anyhow::{anyhow, bail};
+use std::io::{Read, Seek, Write};
+
+use crate::{
+    excel::Excel,
+    processed::spreadsheet::{
+        sheet::worksheet::{cell::Cell, csv_export::CsvOptions, Worksheet},
+        sheet_basic_info::{SheetBasicInfo, SheetType},
+    },
+};
+
+pub const USAGE: &str = "\
+Usage: excel-reader <command> <file> [options]
+
+Commands:
+  sheets <file>                                 List the sheets with their type and visibility
+  dump <file> [--sheet <n|name>] [--format csv|json]
+                                                Write the values of a worksheet as displayed by Excel,
+                                                the first worksheet and csv by default
+  validate <file>                               Check the package, the schema and data validation references,
+                                                exit with status 1 if problems are found
+  meta <file> [--format text|json]              Describe the workbook: sheets, defined names, macros, add-ins";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Format {
+    Text,
+    Csv,
+    Json,
+}
+
+/// Options following the file of a command.
+#[derive(Debug, Default)]
+struct Options {
+    sheet: Option<String>,
+    format: Option<Format>,
+}
+
+impl Options {
+    fn parse(args: &[String]) -> anyhow::Result<Self> {
+        let mut options = Self::default();
+        let mut args = args.iter();
+        while let Some(arg) = args.next() {
+            let mut value = || {
+                args.next()
+                    .ok_or_else(|| anyhow!("{} expects a value.\n\n{}", arg, USAGE))
+            };
+            match arg.as_str() {
+                "--sheet" => options.sheet = Some(value()?.clone()),
+                "--format" => {
+                    options.format = Some(match value()?.as_str() {
+                        "text" => Format::Text,
+                        "csv" => Format::Csv,
+                        "json" => Format::Json,
+                        format => bail!("Unknown format `{}`, expected text, csv or json.", format),
+                    })
+                }
+                _ => bail!("Unexpected argument `{}`.\n\n{}", arg, USAGE),
+            }
+        }
+        return Ok(options);
+    }
+
+    fn format(&self, default: Format, allowed: &[Format]) -> anyhow::Result<Format> {
+        let format = self.format.unwrap_or(default);
+        if !allowed.contains(&format) {
+            bail!("Format {:?} is not available for this command.", format);
+        }
+        return Ok(format);
+    }
+}
+
+/// Run a command, args excluding the program name, ie: `["dump", "book.xlsx", "--format", "json"]`.
+///
+/// Returns the exit status: 0 on success, 1 when `validate` finds problems.
+pub fn run(args: &[String], out: &mut impl Write) -> anyhow::Result<i32> {
+    let (Some(command), Some(path)) = (args.first(), args.get(1)) else {
+        if args
+            .first()
+            .is_some_and(|a| a == "--help" || a == "-h" || a == "help")
+        {
+            writeln!(out, "{}", USAGE)?;
+            return Ok(0);
+        }
+        bail!("Missing command or file.\n\n{}", USAGE);
+    };
+    let options = Options::parse(&args[2..])?;
+    let mut excel = Excel::from_path(path)?;
+    return match command.as_str() {
+        "sheets" => sheets(&mut excel, out).map(|_| 0),
+        "dump" => dump(&mut excel, &options, out).map(|_| 0),
+        "validate" => validate(&mut excel, out),
+        "meta" => meta(&mut excel, &options, out).map(|_| 0),
+        command => bail!("Unknown command `{}`.\n\n{}", command, USAGE),
+    };
+}
+
+fn sheets<RS: Read + Seek>(excel: &mut Excel<RS>, out: &mut impl Write) -> anyhow::Result<()> {
+    for (index, sheet) in excel.get_sheets()?.iter().enumerate() {
+        writeln!(
+            out,
+            "{}\t{}\t{:?}\t{:?}",
+            index + 1,
+            sheet.name,
+            sheet.r#type,
+            sheet.visible_state
+        )?;
+    }
+    return Ok(());
+}
+
+/// Worksheet by 1 based index among the worksheets, or by name, the first worksheet if None.
+fn find_worksheet<RS: Read + Seek>(
+    excel: &mut Excel<RS>,
+    sheet: Option<&str>,
+) -> anyhow::Result<SheetBasicInfo> {
+    let worksheets: Vec<SheetBasicInfo> = excel
+        .get_sheets()?
+        .into_iter()
+        .filter(|s| s.r#type == SheetType::WorkSheet)
+        .collect();
+    let found = match sheet {
+        None => worksheets.first(),
+        Some(sheet) => match sheet.parse::<usize>() {
+            Ok(index) => index.checked_sub(1).and_then(|i| worksheets.get(i)),
+            Err(_) => worksheets.iter().find(|s| s.name == sheet),
+        },
+    };
+    return found
+        .cloned()
+        .ok_or_else(|| anyhow!("Worksheet `{}` not found.", sheet.unwrap_or("1")));
+}
+
+fn dump<RS: Read + Seek>(
+    excel: &mut Excel<RS>,
+    options: &Options,
+    out: &mut impl Write,
+) -> anyhow::Result<()> {
+    let format = options.format(Format::Csv, &[Format::Csv, Format::Json])?;
+    let sheet = find_worksheet(excel, options.sheet.as_deref())?;
+    let rows = worksheet_rows(excel, &sheet)?;
+    match format {
+        Format::Json => writeln!(out, "{}", serde_json::to_string(&rows)?)?,
+        _ => {
+            let options = CsvOptions::default();
+            for row in rows {
+                out.write_all(options.record(&row).as_bytes())?;
+            }
+        }
+    }
+    return Ok(());
+}
+
+/// Values as displayed, from the top left cell of the data of the worksheet, rows without cells included.
+///
+/// Rows are read one at a time, binary workbooks have all their cells loaded at once.
+fn worksheet_rows<RS: Read + Seek>(
+    excel: &mut Excel<RS>,
+    sheet: &SheetBasicInfo,
+) -> anyhow::Result<Vec<Vec<String>>> {
+    let worksheet = excel.get_worksheet_without_cells(sheet)?;
+    let Some(dimension) = worksheet.dimension else {
+        return Ok(vec![]);
+    };
+    let width = (dimension.end.col - dimension.start.col + 1) as usize;
+    let mut rows: Vec<Vec<String>> = vec![];
+    let mut add = |worksheet: &Worksheet, index: u64, cells: &[Cell]| {
+        if index < dimension.start.row || index > dimension.end.row {
+            return;
+        }
+        let position = (index - dimension.start.row) as usize;
+        if rows.len() <= position {
+            rows.resize(position + 1, vec![String::new(); width]);
+        }
+        for cell in cells {
+            let Some(col) = cell.coordinate.col.checked_sub(dimension.start.col) else {
+                continue;
+            };
+            if let Some(value) = rows[position].get_mut(col as usize) {
+                *value = cell.formatted_value(worksheet.is_1904);
+            }
+        }
+    };
+    if excel.is_binary() {
+        let worksheet = excel.get_worksheet(sheet)?;
+        let cells = worksheet.get_cells()?;
+        for row in cells.chunk_by(|a, b| a.coordinate.row == b.coordinate.row) {
+            add(&worksheet, row[0].coordinate.row, row);
+        }
+    } else {
+        for row in worksheet.rows(excel)? {
+            let row = row?;
+            add(&worksheet, row.index, &row.cells);
+        }
+    }
+    return Ok(rows);
+}
+
+fn validate<RS: Read + Seek>(excel: &mut Excel<RS>, out: &mut impl Write) -> anyhow::Result<i32> {
+    let mut problems = 0;
+    for issue in excel.check_workbook_relationships()? {
+        writeln!(out, "package: {:?}", issue)?;
+        problems += 1;
+    }
+    for violation in excel.check_conformance()? {
+        writeln!(out, "schema: {}", violation)?;
+        problems += 1;
+    }
+    for issue in excel.check_data_validation_references()? {
+        writeln!(
+            out,
+            "data validation: {}!{} `{}`: {:?}",
+            issue.sheet_name, issue.sqref, issue.formula, issue.problem
+        )?;
+        problems += 1;
+    }
+    if problems == 0 {
+        writeln!(out, "no problems found")?;
+        return Ok(0);
+    }
+    writeln!(out, "{} problem(s) found", problems)?;
+    return Ok(1);
+}
+
+fn meta<RS: Read + Seek>(
+    excel: &mut Excel<RS>,
+    options: &Options,
+    out: &mut impl Write,
+) -> anyhow::Result<()> {
+    let format = options.format(Format::Text, &[Format::Text, Format::Json])?;
+    let summary = excel.get_summary()?;
+    let defined_names = excel.defined_names()?;
+    if format == Format::Json {
+        let meta = serde_json::json!({
+            "summary": summary,
+            "defined_names": defined_names,
+        });
+        writeln!(out, "{}", serde_json::to_string_pretty(&meta)?)?;
+        return Ok(());
+    }
+
+    let content_type = summary
+        .content_type
+        .map_or("undeclared".to_string(), |c| format!("{:?}", c));
+    writeln!(out, "content type: {}", content_type)?;
+    writeln!(out, "sheets: {}", summary.sheets.len())?;
+    for sheet in summary.sheets.iter() {
+        writeln!(
+            out,
+            "  {} ({:?}, {:?})",
+            sheet.name, sheet.r#type, sheet.visible_state
+        )?;
+    }
+    writeln!(out, "defined names: {}", defined_names.len())?;
+    for name in defined_names.iter() {
+        writeln!(out, "  {} = {}", name.name, name.refers_to)?;
+    }
+    writeln!(out, "macro sheets: {}", yes_no(summary.has_macro_sheets))?;
+    writeln!(out, "data model: {}", yes_no(summary.has_data_model))?;
+    writeln!(out, "add-ins: {}", summary.web_extensions.len())?;
+    return Ok(());
+}
+
+fn yes_no(value: bool) -> &'static str {
+    return if value { "yes" } else { "no" };
+}
@@ -1,14 +1,18 @@
 use chrono::DateTime;
 use chrono::FixedOffset;
 use chrono::NaiveDateTime;
+use std::fmt;
+use std::num::NonZeroU64;
+use std::str::FromStr;
 
 use crate::helper::a1_address_to_row_col;
+use crate::helper::col_to_letters;
 use crate::helper::a1_dimension_to_row_col;
 use crate::helper::r1c1_address_to_row_col;
 use crate::helper::r1c1_dimension_to_row_col;
 
 #[cfg(feature = "serde")]
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 /// Hex representation of RGBA (alpha last)
 ///
@@ -17,8 +21,14 @@ pub type HexColor = String;
 
 pub type Text = String;
 
+/// Maximum number of rows in a worksheet
+pub const MAX_ROW_COUNT: u64 = 1_048_576;
+
+/// Maximum number of columns in a worksheet
+pub const MAX_COLUMN_COUNT: u64 = 16_384;
+
 /// row, col: 1 based index
-#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Debug, Default, PartialEq, Eq, Hash, Ord, PartialOrd, Copy, Clone)]
 pub struct Coordinate {
     pub row: u64,
@@ -49,9 +59,353 @@ impl Coordinate {
         }
         return None;
     }
+
+    /// A1 style reference, ie: `B3`.
+    pub fn to_a1(&self) -> String {
+        format!("{}{}", col_to_letters(self.col), self.row)
+    }
+
+    /// Parse an A1 style reference, ie: `B3`, `$B$3`, failing with the reason the reference is malformed.
+    ///
+    /// Unlike `from_a1`, columns beyond `XFD` and rows beyond 1048576 fail, see `parse_a1_with_policy` to clamp them instead.
+    pub fn parse_a1(a1: &str) -> Result<Self, CoordinateError> {
+        Self::parse_a1_with_policy(a1, OutOfRangePolicy::Error)
+    }
+
+    /// Parse an A1 style reference, handling columns and rows beyond the limits of a worksheet according to `policy`.
+    ///
+    /// `$` anchors are accepted and dropped, see `CellReference` to keep them.
+    pub fn parse_a1_with_policy(a1: &str, policy: OutOfRangePolicy) -> Result<Self, CoordinateError> {
+        CellReference::parse_a1_with_policy(a1, policy).map(|reference| reference.coordinate)
+    }
+}
+
+/// Strict A1 style reference, see `Coordinate::parse_a1`.
+impl FromStr for Coordinate {
+    type Err = CoordinateError;
+
+    fn from_str(a1: &str) -> Result<Self, Self::Err> {
+        Self::parse_a1(a1)
+    }
 }
 
-#[cfg_attr(feature = "serde", derive(Serialize))]
+/// A1 style reference with its `$` anchors, ie: `$A$1`, `A$1`, as written in formulas.
+///
+/// Displays as written, ie: `$B3`.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Default, PartialEq, Eq, Hash, Copy, Clone)]
+pub struct CellReference {
+    pub coordinate: Coordinate,
+
+    /// `$` before the column letters: the column is kept when the formula is copied.
+    pub col_absolute: bool,
+
+    /// `$` before the row number: the row is kept when the formula is copied.
+    pub row_absolute: bool,
+}
+
+impl CellReference {
+    /// Parse an A1 style reference with its anchors, failing with the reason the reference is malformed, see `Coordinate::parse_a1`.
+    pub fn parse_a1(a1: &str) -> Result<Self, CoordinateError> {
+        Self::parse_a1_with_policy(a1, OutOfRangePolicy::Error)
+    }
+
+    /// Parse an A1 style reference with its anchors, handling columns and rows beyond the limits of a worksheet according to `policy`.
+    pub fn parse_a1_with_policy(a1: &str, policy: OutOfRangePolicy) -> Result<Self, CoordinateError> {
+        if a1.is_empty() {
+            return Err(CoordinateError::Empty);
+        }
+        let bytes = a1.as_bytes();
+        let skip_dollar = |index: usize| index + usize::from(bytes.get(index) == Some(&b'$'));
+        let unexpected = |position: usize| CoordinateError::UnexpectedCharacter {
+            position,
+            character: a1[position..].chars().next().unwrap_or_default(),
+        };
+
+        let col_start = skip_dollar(0);
+        let col_absolute = col_start > 0;
+        let col_end = col_start
+            + bytes[col_start..]
+                .iter()
+                .take_while(|b| b.is_ascii_alphabetic())
+                .count();
+        if col_end == col_start {
+            return Err(match bytes.get(col_start) {
+                Some(b) if b.is_ascii_digit() => CoordinateError::MissingColumn,
+                Some(_) => unexpected(col_start),
+                None => CoordinateError::MissingColumn,
+            });
+        }
+        let row_start = skip_dollar(col_end);
+        let row_absolute = row_start > col_end;
+        let row_end = row_start
+            + bytes[row_start..]
+                .iter()
+                .take_while(|b| b.is_ascii_digit())
+                .count();
+        if row_end == row_start {
+            return Err(match bytes.get(row_start) {
+                Some(_) => unexpected(row_start),
+                None => CoordinateError::MissingRow,
+            });
+        }
+        if row_end < bytes.len() {
+            return Err(unexpected(row_end));
+        }
+
+        let letters = &a1[col_start..col_end];
+        let digits = &a1[row_start..row_end];
+        // None when too large for u64, out of range all the same
+        let col = letters.bytes().try_fold(0_u64, |col, letter| {
+            col.checked_mul(26)?
+                .checked_add((letter.to_ascii_uppercase() - b'A') as u64 + 1)
+        });
+        let row = digits.parse::<u64>().ok();
+        if row == Some(0) {
+            return Err(CoordinateError::ZeroRow);
+        }
+
+        let col = match col.filter(|col| *col <= MAX_COLUMN_COUNT) {
+            Some(col) => col,
+            None if policy == OutOfRangePolicy::Clamp => MAX_COLUMN_COUNT,
+            None => return Err(CoordinateError::ColumnOutOfRange(letters.to_string())),
+        };
+        let row = match row.filter(|row| *row <= MAX_ROW_COUNT) {
+            Some(row) => row,
+            None if policy == OutOfRangePolicy::Clamp => MAX_ROW_COUNT,
+            None => return Err(CoordinateError::RowOutOfRange(digits.to_string())),
+        };
+        Ok(Self {
+            coordinate: Coordinate { row, col },
+            col_absolute,
+            row_absolute,
+        })
+    }
+
+    /// Reference as it reads in a formula copied `rows` down and `cols` right, ie: `A$1` copied 1 row down and 1 column right -> `B$1`.
+    ///
+    /// Anchored columns and rows are kept. None when the reference moves outside of the worksheet.
+    pub fn offset(&self, rows: i64, cols: i64) -> Option<Self> {
+        let shift = |value: u64, offset: i64, absolute: bool, max: u64| -> Option<u64> {
+            if absolute {
+                return Some(value);
+            }
+            value
+                .checked_add_signed(offset)
+                .filter(|value| (1..=max).contains(value))
+        };
+        Some(Self {
+            coordinate: Coordinate {
+                row: shift(self.coordinate.row, rows, self.row_absolute, MAX_ROW_COUNT)?,
+                col: shift(self.coordinate.col, cols, self.col_absolute, MAX_COLUMN_COUNT)?,
+            },
+            col_absolute: self.col_absolute,
+            row_absolute: self.row_absolute,
+        })
+    }
+}
+
+impl From<Coordinate> for CellReference {
+    /// Relative reference to a coordinate, ie: `B3`.
+    fn from(coordinate: Coordinate) -> Self {
+        Self {
+            coordinate,
+            col_absolute: false,
+            row_absolute: false,
+        }
+    }
+}
+
+impl fmt::Display for CellReference {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let anchor = |absolute: bool| if absolute { "$" } else { "" };
+        write!(
+            f,
+            "{}{}{}{}",
+            anchor(self.col_absolute),
+            col_to_letters(self.coordinate.col),
+            anchor(self.row_absolute),
+            self.coordinate.row
+        )
+    }
+}
+
+impl FromStr for CellReference {
+    type Err = CoordinateError;
+
+    fn from_str(a1: &str) -> Result<Self, Self::Err> {
+        Self::parse_a1(a1)
+    }
+}
+
+/// How `Coordinate::parse_a1_with_policy` and `CellReference::parse_a1_with_policy` handle columns beyond `XFD` and rows beyond 1048576.
+#[derive(Debug, Default, PartialEq, Eq, Copy, Clone)]
+pub enum OutOfRangePolicy {
+    /// Fail with `CoordinateError::ColumnOutOfRange` or `CoordinateError::RowOutOfRange`.
+    #[default]
+    Error,
+    /// Replace by the last column or row of a worksheet.
+    Clamp,
+}
+
+/// Why an A1 style reference is malformed.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum CoordinateError {
+    Empty,
+    /// No column letters before the row number, ie: `12`.
+    MissingColumn,
+    /// No row number after the column letters, ie: `AB`.
+    MissingRow,
+    /// Row number 0, ie: `A0`.
+    ZeroRow,
+    /// Column letters beyond `XFD`, numbers too large for a `u64` included, ie: `XFE1`.
+    ColumnOutOfRange(String),
+    /// Row number beyond 1048576, numbers too large for a `u64` included, ie: `A1048577`.
+    RowOutOfRange(String),
+    /// Character that is not part of a reference, with its byte position, ie: `-` of `A-1`, `B` of `A1B`, ` ` of `A1 `.
+    UnexpectedCharacter { position: usize, character: char },
+    /// Range from a cell, a whole column or a whole row to another kind, ie: `A1:C`, `A:3`.
+    MismatchedRange,
+}
+
+impl fmt::Display for CoordinateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CoordinateError::Empty => write!(f, "empty cell reference"),
+            CoordinateError::MissingColumn => write!(f, "cell reference without column letters"),
+            CoordinateError::MissingRow => write!(f, "cell reference without row number"),
+            CoordinateError::ZeroRow => write!(f, "cell reference with row 0"),
+            CoordinateError::ColumnOutOfRange(letters) => {
+                write!(f, "column {} is beyond the last column XFD", letters)
+            }
+            CoordinateError::RowOutOfRange(digits) => {
+                write!(f, "row {} is beyond the last row {}", digits, MAX_ROW_COUNT)
+            }
+            CoordinateError::UnexpectedCharacter {
+                position,
+                character,
+            } => write!(
+                f,
+                "unexpected character {:?} at position {} of cell reference",
+                character, position
+            ),
+            CoordinateError::MismatchedRange => {
+                write!(f, "range does not join two cells, two columns or two rows")
+            }
+        }
+    }
+}
+
+impl std::error::Error for CoordinateError {}
+
+impl From<PackedCoordinate> for Coordinate {
+    fn from(packed: PackedCoordinate) -> Self {
+        Self {
+            row: packed.row(),
+            col: packed.col(),
+        }
+    }
+}
+
+/// Compact representation of a `Coordinate` used for the storage of raw cells (`XlsxCell`).
+///
+/// row (up to u32) is stored in the upper bits, col (up to u16) in the lower 16 bits of a single u64.
+/// Ordering is the same as `Coordinate`: row first, then column.
+///
+/// Zero is never a valid 1 based coordinate, so `Option<PackedCoordinate>` takes no extra space.
+#[derive(PartialEq, Eq, Hash, Ord, PartialOrd, Copy, Clone)]
+pub struct PackedCoordinate(NonZeroU64);
+
+impl PackedCoordinate {
+    /// None if row or col is zero, or exceeds the packed range.
+    pub fn new(coordinate: Coordinate) -> Option<Self> {
+        if coordinate.row == 0 || coordinate.col == 0 {
+            return None;
+        }
+        let row = u32::try_from(coordinate.row).ok()?;
+        let col = u16::try_from(coordinate.col).ok()?;
+        NonZeroU64::new(((row as u64) << 16) | col as u64).map(Self)
+    }
+
+    pub fn row(&self) -> u64 {
+        self.0.get() >> 16
+    }
+
+    pub fn col(&self) -> u64 {
+        self.0.get() & 0xffff
+    }
+}
+
+impl fmt::Debug for PackedCoordinate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PackedCoordinate")
+            .field("row", &self.row())
+            .field("col", &self.col())
+            .finish()
+    }
+}
+
+/// Deterministic id of a cell or a row, assigned with `ExcelOptions::cell_ids`.
+///
+/// `sheetId` of the sheet in bits 36 to 52, row in bits 15 to 35 and column in the lower 15 bits, 0 for the id of a row.
+/// Ids fit in 53 bits, so they stay exact as JSON numbers read by JavaScript,
+/// and order by sheet id, then row, then column, a row coming before its cells.
+///
+/// The same cell gets the same id on every read, ids follow the position of a cell, not its content:
+/// inserting a row changes the ids of the rows below.
+#[derive(Debug, PartialEq, Eq, Hash, Ord, PartialOrd, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(transparent))]
+pub struct CellId(u64);
+
+impl CellId {
+    const COL_BITS: u32 = 15;
+    const ROW_BITS: u32 = 21;
+    const SHEET_ID_BITS: u32 = 17;
+
+    /// None if sheet_id, row or col exceeds the packed range, or row or col is zero.
+    pub fn new(sheet_id: u64, coordinate: Coordinate) -> Option<Self> {
+        if coordinate.col == 0 || coordinate.col >> Self::COL_BITS != 0 {
+            return None;
+        }
+        let row = Self::for_row(sheet_id, coordinate.row)?;
+        Some(Self(row.0 | coordinate.col))
+    }
+
+    /// Id of a row, None if sheet_id or row exceeds the packed range, or row is zero.
+    pub fn for_row(sheet_id: u64, row: u64) -> Option<Self> {
+        if row == 0 || row >> Self::ROW_BITS != 0 || sheet_id >> Self::SHEET_ID_BITS != 0 {
+            return None;
+        }
+        Some(Self(
+            sheet_id << (Self::ROW_BITS + Self::COL_BITS) | row << Self::COL_BITS,
+        ))
+    }
+
+    pub fn get(&self) -> u64 {
+        self.0
+    }
+
+    pub fn sheet_id(&self) -> u64 {
+        self.0 >> (Self::ROW_BITS + Self::COL_BITS)
+    }
+
+    pub fn row(&self) -> u64 {
+        (self.0 >> Self::COL_BITS) & ((1 << Self::ROW_BITS) - 1)
+    }
+
+    /// 0 for the id of a row.
+    pub fn col(&self) -> u64 {
+        self.0 & ((1 << Self::COL_BITS) - 1)
+    }
+}
+
+impl From<CellId> for u64 {
+    fn from(id: CellId) -> Self {
+        id.0
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Debug, Default, PartialEq, Eq, Hash, Ord, PartialOrd, Copy, Clone)]
 pub struct Dimension {
     pub start: Coordinate,
@@ -59,25 +413,182 @@ pub struct Dimension {
 }
 
 impl Dimension {
+    /// Parse an A1 style range, failing with the reason the range is malformed, ie:
+    /// - `A1:B3`, `$A$1:$B$3`, or `B2` for a single cell
+    /// - `A:C` for whole columns, spanning all the rows of a worksheet
+    /// - `3:7` for whole rows, spanning all the columns of a worksheet
+    ///
+    /// Whole columns and rows are open ended: they stay whole columns and rows, see `is_whole_column` and `is_whole_row`,
+    /// whatever the extent of the data of the worksheet.
+    pub fn parse_a1(a1: &str) -> Result<Self, CoordinateError> {
+        let Some((start, end)) = a1.split_once(':') else {
+            let coordinate = Coordinate::parse_a1(a1)?;
+            return Ok(Self {
+                start: coordinate,
+                end: coordinate,
+            });
+        };
+        // positions of the errors of the end are within the whole range
+        let offset = start.len() + 1;
+        let end = RangeEnd::parse(end).map_err(|error| match error {
+            CoordinateError::UnexpectedCharacter {
+                position,
+                character,
+            } => CoordinateError::UnexpectedCharacter {
+                position: position + offset,
+                character,
+            },
+            error => error,
+        })?;
+        return match (RangeEnd::parse(start)?, end) {
+            (RangeEnd::Cell(a), RangeEnd::Cell(b)) => {
+                Ok(Self::from_corners((a.row, a.col), (b.row, b.col)))
+            }
+            (RangeEnd::Column(a), RangeEnd::Column(b)) => {
+                Ok(Self::from_corners((1, a), (MAX_ROW_COUNT, b)))
+            }
+            (RangeEnd::Row(a), RangeEnd::Row(b)) => {
+                Ok(Self::from_corners((a, 1), (b, MAX_COLUMN_COUNT)))
+            }
+            _ => Err(CoordinateError::MismatchedRange),
+        };
+    }
+
+    /// Whether the dimension spans all the rows of a worksheet, ie: `A:C`.
+    pub fn is_whole_column(&self) -> bool {
+        self.start.row == 1 && self.end.row == MAX_ROW_COUNT
+    }
+
+    /// Whether the dimension spans all the columns of a worksheet, ie: `3:7`.
+    pub fn is_whole_row(&self) -> bool {
+        self.start.col == 1 && self.end.col == MAX_COLUMN_COUNT
+    }
+
     pub(crate) fn from_a1(a1_address: &[u8]) -> Option<Self> {
         if let Ok((start, end)) = a1_dimension_to_row_col(a1_address) {
-            return Some(Self {
-                start: Coordinate::from_point(start),
-                end: Coordinate::from_point(end),
-            });
+            return Some(Self::from_corners(start, end));
         }
         return None;
     }
 
     pub(crate) fn from_r1c1(r1c1: &str) -> Option<Self> {
         if let Ok((start, end)) = r1c1_dimension_to_row_col(r1c1) {
-            return Some(Self {
-                start: Coordinate::from_point(start),
-                end: Coordinate::from_point(end),
-            });
+            return Some(Self::from_corners(start, end));
         }
         return None;
     }
+
+    /// A1 style reference, ie: `A1:B3`, `A1` for a single cell, `A:C` for whole columns and `3:7` for whole rows.
+    ///
+    /// The whole worksheet is written as whole rows, `1:1048576`.
+    pub fn to_a1(&self) -> String {
+        if self.is_whole_row() {
+            return format!("{}:{}", self.start.row, self.end.row);
+        }
+        if self.is_whole_column() {
+            return format!(
+                "{}:{}",
+                col_to_letters(self.start.col),
+                col_to_letters(self.end.col)
+            );
+        }
+        if self.start == self.end {
+            return self.start.to_a1();
+        }
+        format!("{}:{}", self.start.to_a1(), self.end.to_a1())
+    }
+
+    /// Whether a coordinate is within the dimension.
+    pub fn contains(&self, coordinate: &Coordinate) -> bool {
+        (self.start.row..=self.end.row).contains(&coordinate.row)
+            && (self.start.col..=self.end.col).contains(&coordinate.col)
+    }
+
+    /// Part of the dimension within the used range of a worksheet, ie: `B1:B4` for `B:B` within `A1:C4`.
+    ///
+    /// Meant to turn whole columns and rows into ranges of the cells actually written.
+    /// None if the dimension lies outside of the used range.
+    pub fn clamp_to(&self, used_range: Dimension) -> Option<Self> {
+        let start = Coordinate::from_point((
+            self.start.row.max(used_range.start.row),
+            self.start.col.max(used_range.start.col),
+        ));
+        let end = Coordinate::from_point((
+            self.end.row.min(used_range.end.row),
+            self.end.col.min(used_range.end.col),
+        ));
+        if start.row > end.row || start.col > end.col {
+            return None;
+        }
+        return Some(Self { start, end });
+    }
+
+    /// Parse a sequence of references, ie: `A1:B3 D5 F:F`.
+    ///
+    /// Single cells result in a dimension of one cell, whole columns and rows in dimensions spanning the worksheet,
+    /// invalid references are skipped.
+    pub(crate) fn from_sqref(sqref: &str) -> Vec<Self> {
+        sqref
+            .split_whitespace()
+            .filter_map(|reference| {
+                let reference = reference.replace("$", "");
+                if reference.contains(':') {
+                    return Self::from_a1(reference.as_bytes());
+                }
+                let coordinate = Coordinate::from_a1(reference.as_bytes())?;
+                Some(Self {
+                    start: coordinate,
+                    end: coordinate,
+                })
+            })
+            .collect()
+    }
+
+    /// Build a dimension from two opposite corners.
+    ///
+    /// `B2:A1` is a valid way of writing `A1:B2`, so the corners are normalized to top left and bottom right.
+    fn from_corners(a: (u64, u64), b: (u64, u64)) -> Self {
+        Self {
+            start: Coordinate::from_point((a.0.min(b.0), a.1.min(b.1))),
+            end: Coordinate::from_point((a.0.max(b.0), a.1.max(b.1))),
+        }
+    }
+}
+
+impl FromStr for Dimension {
+    type Err = CoordinateError;
+
+    fn from_str(a1: &str) -> Result<Self, Self::Err> {
+        Self::parse_a1(a1)
+    }
+}
+
+/// Either end of an A1 style range.
+enum RangeEnd {
+    Cell(Coordinate),
+    /// column index, ie: `C` of `A:C`
+    Column(u64),
+    /// row index, ie: `7` of `3:7`
+    Row(u64),
+}
+
+impl RangeEnd {
+    fn parse(a1: &str) -> Result<Self, CoordinateError> {
+        let unanchored = a1.strip_prefix('$').unwrap_or(a1);
+        if unanchored.is_empty() {
+            return Err(CoordinateError::Empty);
+        }
+        // parsed as the cell in the first row or column, for the same errors as cells
+        if unanchored.bytes().all(|b| b.is_ascii_alphabetic()) {
+            let coordinate = Coordinate::parse_a1(&format!("{}1", a1))?;
+            return Ok(Self::Column(coordinate.col));
+        }
+        if unanchored.bytes().all(|b| b.is_ascii_digit()) {
+            let coordinate = Coordinate::parse_a1(&format!("A{}", a1))?;
+            return Ok(Self::Row(coordinate.row));
+        }
+        return Ok(Self::Cell(Coordinate::parse_a1(a1)?));
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Hash, Copy, Clone)]
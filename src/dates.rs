@@ -0,0 +1,72 @@
+//! Conversions between the serial numbers dates and times are stored as, and `chrono` date times.
+//!
+//! A serial number counts days, the fraction being the time of the day.
+//! - 1900 date system: day 1 is 1900-01-01. Day 60 is 1900-02-29, a day that does not exist,
+//!   kept by Excel for compatibility with Lotus 1-2-3, so that dates from 1900-03-01 on are one day off.
+//! - 1904 date system (`date1904` of the workbook, see `Worksheet::is_1904`): day 0 is 1904-01-01.
+//!
+//! Serial numbers below 1 are times without a date. In the 1900 date system they convert to day 0, 1899-12-31,
+//! ie: `0.5` -> `1899-12-31 12:00:00`.
+
+use chrono::{Duration, NaiveDate, NaiveDateTime};
+
+/// Serial number of 10000-01-01 in the 1900 date system, larger serial numbers are not dates.
+static MAX_SERIAL_1900: f64 = 2_958_466.0;
+
+/// Serial number of 10000-01-01 in the 1904 date system.
+static MAX_SERIAL_1904: f64 = 2_957_004.0;
+
+/// Serial number of 1900-03-01 in the 1900 date system, dates before count the fictitious 1900-02-29.
+static FIRST_SERIAL_AFTER_LEAP_BUG: f64 = 61.0;
+
+static MILLISECONDS_PER_DAY: f64 = 86_400_000.0;
+
+/// Date time of a serial number, rounded to the millisecond.
+///
+/// None for negative serial numbers, serial numbers after 9999-12-31,
+/// and the fictitious 1900-02-29 (serial numbers from 60 to 61 in the 1900 date system).
+///
+/// * is_1904: whether serial numbers count from 1904-01-01 instead of 1900-01-01
+pub fn from_serial(serial: f64, is_1904: bool) -> Option<NaiveDateTime> {
+    let max = if is_1904 {
+        MAX_SERIAL_1904
+    } else {
+        MAX_SERIAL_1900
+    };
+    if !serial.is_finite() || serial < 0.0 || serial >= max {
+        return None;
+    }
+    let epoch = if is_1904 {
+        NaiveDate::from_ymd_opt(1904, 1, 1)?
+    } else if serial < 60.0 {
+        NaiveDate::from_ymd_opt(1899, 12, 31)?
+    } else if serial < FIRST_SERIAL_AFTER_LEAP_BUG {
+        return None;
+    } else {
+        NaiveDate::from_ymd_opt(1899, 12, 30)?
+    };
+    let milliseconds = (serial * MILLISECONDS_PER_DAY).round() as i64;
+    return epoch
+        .and_hms_opt(0, 0, 0)?
+        .checked_add_signed(Duration::milliseconds(milliseconds));
+}
+
+/// Serial number of a date time, the inverse of `from_serial`.
+///
+/// None for date times before day 0 of the date system: 1899-12-31 or 1904-01-01.
+///
+/// * is_1904: whether serial numbers count from 1904-01-01 instead of 1900-01-01
+pub fn to_serial(date_time: NaiveDateTime, is_1904: bool) -> Option<f64> {
+    let epoch = if is_1904 {
+        NaiveDate::from_ymd_opt(1904, 1, 1)?
+    } else if date_time.date() < NaiveDate::from_ymd_opt(1900, 3, 1)? {
+        NaiveDate::from_ymd_opt(1899, 12, 31)?
+    } else {
+        NaiveDate::from_ymd_opt(1899, 12, 30)?
+    };
+    let milliseconds = (date_time - epoch.and_hms_opt(0, 0, 0)?).num_milliseconds();
+    if milliseconds < 0 {
+        return None;
+    }
+    return Some(milliseconds as f64 / MILLISECONDS_PER_DAY);
+}
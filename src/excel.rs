@@ -1,73 +1,589 @@
-#[cfg(feature = "drawing")]
-use std::collections::BTreeMap;
-
 use anyhow::{bail, Context};
-use quick_xml::Reader;
+use quick_xml::{reader::Config, Reader};
+use regex::Regex;
+#[cfg(feature = "rayon")]
+use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
 use std::{
-    fs::File,
-    io::{BufReader, Read, Seek},
-    path::Path,
+    collections::BTreeMap,
+    io::{BufReader, Read, Seek, SeekFrom, Write},
+    path::PathBuf,
+    sync::Arc,
 };
+// files are not available in the browser
+#[cfg(not(target_arch = "wasm32"))]
+use std::{fs::File, path::Path};
 
 use zip::{read::ZipFile, ZipArchive};
 
-#[cfg(feature = "drawing")]
-use crate::packaging::relationship::load_drawing_relationships;
-
 #[cfg(feature = "drawing")]
 use crate::raw::drawing::worksheet_drawing::XlsxWorksheetDrawing;
 
+#[cfg(feature = "encryption")]
+use crate::raw::encryption::decrypt_package;
+use std::io::Cursor;
+
 use crate::{
+    common_types::Dimension,
+    packaging::anonymize::{anonymize_package, AnonymizeOptions, AnonymizedPart, AnonymizedValue},
+    packaging::conformance::{check_part_conformance, ConformanceViolation},
+    packaging::consistency::{check_workbook_relationships, RelationshipIssue},
+    packaging::content_type::{WorkbookContentType, XlsxContentTypes},
+    packaging::unsupported_feature::{
+        find_part_extensions, UnsupportedFeature, UnsupportedFeatureKind,
+    },
     packaging::relationship::{
-        load_sheet_relationships, load_workbook_relationships, zip_path_for_id, zip_path_for_type,
-        XlsxRelationships,
+        format_target_path, load_drawing_relationships, load_sheet_relationships,
+        load_workbook_path, load_workbook_relationships, relationships_path, EXTERNAL_TARGET_MODE, zip_path_for_id, zip_path_for_type,
+        WorksheetRelationships, XlsxRelationships,
     },
     processed::spreadsheet::{
-        sheet::worksheet::{calculation_reference::CalculationReferenceMode, Worksheet},
-        sheet_basic_info::{SheetBasicInfo, SheetType},
+        data_model::DataModel,
+        defined_name::{DefinedName, DefinedNameScope},
+        memory_footprint::{
+            shared_strings_size, stylesheet_size, theme_size, workbook_sheet_size, workbook_size,
+            MemoryFootprint,
+        },
+        formula_dependent::{FormulaDependent, PrecedentTargets},
+        hidden_data::{HiddenDataReport, WorksheetHiddenData},
+        pivot_table::PivotTable,
+        search::{SearchLocation, SearchMatch, SearchOptions},
+        sheet::worksheet::{
+            calculation_reference::CalculationReferenceMode,
+            cell::cell_property::font::{Font, FontSchemeValue},
+            custom_property::CustomProperty,
+            Worksheet,
+        },
+        sheet_basic_info::{SheetBasicInfo, SheetType, SheetVisibleState},
+        sheet_name::sheet_names_equal,
+        sheet_reference::{SheetNameReference, SheetReferenceLocation},
+        slicer::{SlicerState, TimelineState},
+        theme_font::ThemeFonts,
+        validation_reference::{ReferenceTargets, ValidationReferenceIssue},
+        web_extension::WebExtension,
+        workbook::{Workbook, WorkbookData, WorkbookSheet},
+        workbook_summary::WorkbookSummary,
     },
     raw::{
+        binary::{
+            is_binary_workbook, shared_strings::load_binary_shared_strings,
+            stylesheet::load_binary_stylesheet, workbook::load_binary_workbook,
+            worksheet::load_binary_worksheet,
+        },
         drawing::theme::XlsxTheme,
         spreadsheet::{
-            shared_string::shared_string_table::XlsxSharedStringTable,
-            sheet::worksheet::XlsxWorksheet, stylesheet::XlsxStyleSheet, table::XlsxTable,
-            workbook::XlsxWorkbook,
+            comment::XlsxComments,
+            person::XlsxPersons,
+            connection::load_connections, pivot_cache_definition::XlsxPivotCacheDefinition,
+            pivot_table::XlsxPivotTableDefinition,
+            shared_string::{
+                lazy_shared_string_table::XlsxLazySharedStringTable,
+                shared_string_table::{SharedStrings, XlsxSharedStringTable},
+            },
+            slicer_cache::XlsxSlicerCacheDefinition,
+            timeline_cache::XlsxTimelineCacheDefinition,
+            sheet::worksheet::{sheet_data::SheetDataMode, XlsxWorksheet}, stylesheet::XlsxStyleSheet, table::XlsxTable,
+            threaded_comment::XlsxThreadedComments,
+            vml_drawing::XlsxVmlDrawing, web_extension::XlsxWebExtension, workbook::XlsxWorkbook,
         },
     },
 };
 
 pub(crate) type XmlReader<'a, R> = Reader<BufReader<ZipFile<'a, R>>>;
 
+/// Buffer sizes and preallocation used while parsing, see `Excel::from_path_with_options`.
+///
+/// Defaults suit typical files. For very wide rows or large shared string tables,
+/// raising the buffer sizes and the capacity limit avoids repeated reallocation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExcelOptions {
+    /// Initial capacity in bytes of the buffer xml events of worksheets and shared strings are read into.
+    pub xml_buffer_size: usize,
+
+    /// Capacity in bytes of the buffered reader over a decompressed part.
+    pub zip_read_buffer_size: usize,
+
+    /// Reserve vectors from the counts declared in the file:
+    /// `uniqueCount` of the shared string table, `spans` of a row and the rows of the sheet dimension.
+    pub capacity_from_counts: bool,
+
+    /// Upper bound of the number of elements reserved from a declared count,
+    /// so that a bogus count in a malformed file cannot allocate arbitrary amounts of memory.
+    pub max_capacity_from_counts: usize,
+
+    /// Refuse to open files that do not conform to the schema, see `Excel::check_conformance`.
+    ///
+    /// Meant for validating the output of software producing xlsx files. Default to false.
+    pub strict: bool,
+
+    /// Index the shared string table instead of parsing it, an item being parsed when a cell refers to it.
+    ///
+    /// Meant for workbooks with many unique strings: opening a worksheet no longer parses the whole table,
+    /// and the table is held once as decompressed instead of once per worksheet as parsed.
+    /// Binary workbooks (.xlsb) are parsed all the same. Default to false.
+    pub lazy_shared_strings: bool,
+
+    /// Assign `Cell::id` and `Row::id`, deterministic ids packing the `sheetId` of the sheet, the row and the column,
+    /// as natural keys for systems syncing spreadsheet data to databases, see `CellId`. Default to false.
+    pub cell_ids: bool,
+}
+
+impl Default for ExcelOptions {
+    fn default() -> Self {
+        Self {
+            xml_buffer_size: 1024,
+            zip_read_buffer_size: 8 * 1024,
+            capacity_from_counts: true,
+            max_capacity_from_counts: 16_384,
+            strict: false,
+            lazy_shared_strings: false,
+            cell_ids: false,
+        }
+    }
+}
+
+impl ExcelOptions {
+    /// Number of elements to reserve for a declared count, 0 if unknown or disabled.
+    pub(crate) fn capacity_for(&self, count: Option<u64>) -> usize {
+        if !self.capacity_from_counts {
+            return 0;
+        }
+        count.map_or(0, |c| {
+            usize::try_from(c)
+                .unwrap_or(usize::MAX)
+                .min(self.max_capacity_from_counts)
+        })
+    }
+}
+
 /// A struct representing xml zipped excel file
+///
+/// Binary workbooks (.xlsb) are opened the same way, see [crate::raw::binary] for what is read from them.
 pub struct Excel<RS> {
     zip: ZipArchive<RS>,
+    /// the workbook, shared strings, styles and worksheets are binary parts (.xlsb)
+    binary: bool,
     workbook_relationships: XlsxRelationships,
-    stylesheet: Option<Box<XlsxStyleSheet>>,
-    theme: Option<Box<XlsxTheme>>,
-    shared_strings: Option<Box<XlsxSharedStringTable>>,
-    workbook: Option<Box<XlsxWorkbook>>,
+    // shared with the worksheets, and with the copies reading the file on other threads
+    stylesheet: Option<Arc<XlsxStyleSheet>>,
+    theme: Option<Arc<XlsxTheme>>,
+    shared_strings: Option<Arc<XlsxSharedStringTable>>,
+    /// shared strings indexed with `ExcelOptions::lazy_shared_strings`
+    lazy_shared_strings: Option<Arc<XlsxLazySharedStringTable>>,
+    workbook: Option<Arc<XlsxWorkbook>>,
+    content_types: Option<Arc<XlsxContentTypes>>,
+    options: ExcelOptions,
+
+    /// path the file is opened from, used by `reload_if_changed`
+    #[cfg_attr(target_arch = "wasm32", allow(dead_code))]
+    path: Option<PathBuf>,
+    /// part name: (crc32, uncompressed size) at the time of opening
+    #[cfg_attr(target_arch = "wasm32", allow(dead_code))]
+    part_checksums: BTreeMap<String, (u32, u64)>,
+    /// sheet path: (parts the sheet depends on, processed sheet), filled by `to_processed_model`
+    sheet_models: BTreeMap<String, (Vec<String>, WorkbookSheet)>,
 }
 
+/// Reader of the files opened with `Excel::from_path`.
+#[cfg(not(target_arch = "wasm32"))]
+pub type FileReader = BufReader<File>;
+
+/// Reader of the files mapped in memory by `Excel::from_path_mmap`.
+#[cfg(all(feature = "mmap", not(target_arch = "wasm32")))]
+pub type MappedFile = Cursor<memmap2::Mmap>;
+
+/// Readers of the files opened from a path, opened again by `Excel::reload_if_changed`: `FileReader` and `MappedFile`.
+#[cfg(not(target_arch = "wasm32"))]
+pub trait PathReader: sealed::OpenPath + Send + Sync {}
+
+#[cfg(not(target_arch = "wasm32"))]
+mod sealed {
+    use super::*;
+
+    /// Private: a mapped file may only be opened by the unsafe `Excel::from_path_mmap`.
+    pub trait OpenPath: Read + Seek + Sized {
+        fn open(path: &Path) -> anyhow::Result<Self>;
+    }
+
+    impl OpenPath for FileReader {
+        fn open(path: &Path) -> anyhow::Result<Self> {
+            return Ok(BufReader::new(File::open(path)?));
+        }
+    }
+
+    #[cfg(feature = "mmap")]
+    impl OpenPath for MappedFile {
+        fn open(path: &Path) -> anyhow::Result<Self> {
+            let file = File::open(path)?;
+            // SAFETY: only reached for an `Excel<MappedFile>`, whose creator guarantees with `from_path_mmap`
+            // that the file is not modified in place while it is open
+            let mmap = unsafe { memmap2::Mmap::map(&file)? };
+            return Ok(Cursor::new(mmap));
+        }
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl PathReader for FileReader {}
+
+/// Readers of the workbooks `Excel::get_worksheets_parallel` is available on, each thread reading the workbook with a reader of its own:
+/// files opened from a path (`PathReader`) are opened again, and readers over bytes in memory are cloned,
+/// `Cursor<&[u8]>` sharing the bytes and `Cursor<Vec<u8>>` copying them for each thread.
+#[cfg(feature = "rayon")]
+pub trait ParallelReader: parallel::ThreadReader {}
+
+#[cfg(feature = "rayon")]
+mod parallel {
+    use super::*;
+
+    /// Private: the reader of a thread is made from the private fields of the workbook.
+    pub trait ThreadReader: Read + Seek + Send + Sync + Sized {
+        fn zip_for_thread(excel: &Excel<Self>) -> anyhow::Result<ZipArchive<Self>>;
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    impl<RS: PathReader> ThreadReader for RS {
+        fn zip_for_thread(excel: &Excel<Self>) -> anyhow::Result<ZipArchive<Self>> {
+            let Some(path) = excel.path.as_deref() else {
+                bail!("The file was not opened from a path.")
+            };
+            return Ok(ZipArchive::new(RS::open(path)?)?);
+        }
+    }
+
+    impl ThreadReader for Cursor<Vec<u8>> {
+        fn zip_for_thread(excel: &Excel<Self>) -> anyhow::Result<ZipArchive<Self>> {
+            return Ok(excel.zip.clone());
+        }
+    }
+
+    impl ThreadReader for Cursor<&[u8]> {
+        fn zip_for_thread(excel: &Excel<Self>) -> anyhow::Result<ZipArchive<Self>> {
+            return Ok(excel.zip.clone());
+        }
+    }
+}
+
+#[cfg(all(feature = "rayon", not(target_arch = "wasm32")))]
+impl<RS: PathReader> ParallelReader for RS {}
+
+#[cfg(feature = "rayon")]
+impl ParallelReader for Cursor<Vec<u8>> {}
+
+#[cfg(feature = "rayon")]
+impl ParallelReader for Cursor<&[u8]> {}
+
+#[cfg(all(feature = "mmap", not(target_arch = "wasm32")))]
+impl PathReader for MappedFile {}
+
 // initialization
-impl Excel<BufReader<File>> {
-    pub fn from_path<P: AsRef<Path>>(path: P) -> anyhow::Result<Excel<BufReader<File>>> {
-        let reader = BufReader::new(File::open(path)?);
-        return Self::from_reader(reader);
+#[cfg(not(target_arch = "wasm32"))]
+impl Excel<FileReader> {
+    pub fn from_path<P: AsRef<Path>>(path: P) -> anyhow::Result<Excel<FileReader>> {
+        return Self::from_path_with_options(path, ExcelOptions::default());
+    }
+
+    pub fn from_path_with_options<P: AsRef<Path>>(
+        path: P,
+        options: ExcelOptions,
+    ) -> anyhow::Result<Excel<FileReader>> {
+        return Self::open_path(path.as_ref(), options);
+    }
+}
+
+#[cfg(all(feature = "mmap", not(target_arch = "wasm32")))]
+impl Excel<MappedFile> {
+    /// Open a file mapped in memory instead of read through a buffer, parts are decompressed from the mapping.
+    ///
+    /// Shortens the time to open very large workbooks and lowers the peak memory.
+    ///
+    /// # Safety
+    ///
+    /// The file must not be modified in place, by this or another process, while the workbook is open,
+    /// the mapped memory changing under the reader being undefined behavior, see `memmap2::Mmap`.
+    /// Replace the file instead, ie: write a new file and rename it, then `reload_if_changed` maps it again.
+    pub unsafe fn from_path_mmap<P: AsRef<Path>>(path: P) -> anyhow::Result<Excel<MappedFile>> {
+        return Self::from_path_mmap_with_options(path, ExcelOptions::default());
+    }
+
+    /// See `from_path_mmap`.
+    ///
+    /// # Safety
+    ///
+    /// The file must not be modified in place while the workbook is open, see `from_path_mmap`.
+    pub unsafe fn from_path_mmap_with_options<P: AsRef<Path>>(
+        path: P,
+        options: ExcelOptions,
+    ) -> anyhow::Result<Excel<MappedFile>> {
+        return Self::open_path(path.as_ref(), options);
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl<RS: PathReader> Excel<RS> {
+    fn open_path(path: &Path, options: ExcelOptions) -> anyhow::Result<Self> {
+        let reader = RS::open(path)?;
+        let mut excel = Self::from_reader_with_options(reader, options)?;
+        excel.path = Some(path.to_path_buf());
+        return Ok(excel);
+    }
+
+    /// Re-open the file if any part changed since it was opened or last reloaded.
+    ///
+    /// Parts are compared by CRC and size. Only cached parts that changed are parsed again,
+    /// and processed sheets built by `to_processed_model` are kept unless the sheet or a part it depends on changed.
+    ///
+    /// Returns the names of the parts that were added, removed or modified, empty if nothing changed.
+    pub fn reload_if_changed(&mut self) -> anyhow::Result<Vec<String>> {
+        let Some(path) = self.path.clone() else {
+            bail!("The file was not opened from a path.")
+        };
+        let mut zip = ZipArchive::new(RS::open(&path)?)?;
+        let checksums = part_checksums(&mut zip)?;
+
+        let mut changed: Vec<String> = checksums
+            .iter()
+            .filter(|(name, checksum)| self.part_checksums.get(*name) != Some(checksum))
+            .map(|(name, _)| name.to_string())
+            .collect();
+        changed.extend(
+            self.part_checksums
+                .keys()
+                .filter(|name| !checksums.contains_key(*name))
+                .cloned(),
+        );
+        changed.sort();
+        if changed.is_empty() {
+            return Ok(changed);
+        }
+
+        let is_changed = |part: &str| {
+            // xml part or its binary counterpart, ie: xl/styles.xml or xl/styles.bin
+            let binary_part = part.replace(".xml", ".bin");
+            changed
+                .iter()
+                .any(|c| c.eq_ignore_ascii_case(part) || c.eq_ignore_ascii_case(&binary_part))
+        };
+        // the workbook part is the target of the officeDocument relationship of the package
+        let workbook_path = load_workbook_path(&mut zip);
+        let moved = is_changed("_rels/.rels");
+        let workbook_changed = moved || is_changed(&workbook_path);
+        let relationships_changed = moved || is_changed(&relationships_path(&workbook_path));
+        if relationships_changed {
+            self.workbook_relationships = load_workbook_relationships(&mut zip)?;
+        }
+        let related_parts = |r#type: &str| -> Vec<String> {
+            zip_path_for_type(&self.workbook_relationships, r#type)
+                .into_iter()
+                .map(|(_, path)| path)
+                .collect()
+        };
+        // parts related through relationships that changed may be other parts
+        let styles_changed = relationships_changed || related_parts("styles").iter().any(|p| is_changed(p));
+        let shared_strings_changed =
+            relationships_changed || related_parts("sharedStrings").iter().any(|p| is_changed(p));
+        let theme_changed = relationships_changed || related_parts("theme").iter().any(|p| is_changed(p));
+
+        if styles_changed {
+            self.stylesheet = None;
+        }
+        if theme_changed {
+            self.theme = None;
+        }
+        if shared_strings_changed {
+            self.shared_strings = None;
+            self.lazy_shared_strings = None;
+        }
+        if workbook_changed {
+            self.workbook = None;
+        }
+        if is_changed("[Content_Types].xml") {
+            self.content_types = None;
+        }
+
+        // parts every processed sheet depends on
+        let shared_changed = workbook_changed || styles_changed || shared_strings_changed || theme_changed;
+        if shared_changed {
+            self.sheet_models.clear();
+        } else {
+            self.sheet_models
+                .retain(|_, (dependencies, _)| !dependencies.iter().any(|d| is_changed(d)));
+        }
+
+        self.binary = is_binary_workbook(&mut zip);
+        self.zip = zip;
+        self.part_checksums = checksums;
+        return Ok(changed);
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<RS: ParallelReader> Excel<RS> {
+    /// Get all worksheets (processed), in the order of `get_sheets`, parsing their parts concurrently on the rayon thread pool.
+    ///
+    /// Each thread reads the workbook with its own reader, see `ParallelReader`. The workbook, styles, theme and shared strings
+    /// are parsed once beforehand and shared by the threads. Sheets other than worksheets, ie: chart sheets, are left out.
+    pub fn get_worksheets_parallel(&mut self) -> anyhow::Result<Vec<Worksheet>> {
+        let sheets: Vec<SheetBasicInfo> = self
+            .get_sheets()?
+            .into_iter()
+            .filter(|s| s.r#type == SheetType::WorkSheet)
+            .collect();
+
+        // parts every worksheet is processed with
+        self.load_workbook()?;
+        self.load_stylesheet()?;
+        self.load_theme()?;
+        if self.options.lazy_shared_strings && !self.binary {
+            self.get_raw_lazy_shared_strings()?;
+        } else {
+            self.load_shared_strings()?;
+        }
+
+        let excel = &*self;
+        return sheets
+            .par_iter()
+            .map_init(
+                || excel.for_thread(),
+                |worker, sheet| match worker {
+                    Ok(worker) => worker.get_worksheet(sheet),
+                    Err(error) => bail!("{}", error),
+                },
+            )
+            .collect();
+    }
+
+    /// Same as `search_all`, loading the worksheets concurrently with `get_worksheets_parallel` when `options.parallel` is set.
+    ///
+    /// Matches are in the same order as `search_all`.
+    pub fn search_all_parallel(
+        &mut self,
+        pattern: &str,
+        options: &SearchOptions,
+    ) -> anyhow::Result<Vec<SearchMatch>> {
+        if !options.parallel {
+            return self.search_all(pattern, options);
+        }
+        let pattern = options.build_regex(pattern)?;
+        let sheets = self.get_sheets()?;
+        let worksheets = self.get_worksheets_parallel()?;
+        let found: anyhow::Result<Vec<Vec<SearchMatch>>> = worksheets
+            .par_iter()
+            .map(|worksheet| worksheet.search(&pattern, options))
+            .collect();
+        let mut found: Vec<SearchMatch> = found?.into_iter().flatten().collect();
+
+        if options.include_defined_names {
+            found.extend(self.search_defined_names(&pattern, &sheets)?);
+        }
+
+        Ok(found)
+    }
+
+    /// Copy of the workbook reading with a new reader, sharing the parts parsed so far.
+    fn for_thread(&self) -> anyhow::Result<Self> {
+        return Ok(Self {
+            zip: RS::zip_for_thread(self)?,
+            binary: self.binary,
+            workbook_relationships: self.workbook_relationships.clone(),
+            stylesheet: self.stylesheet.clone(),
+            theme: self.theme.clone(),
+            shared_strings: self.shared_strings.clone(),
+            lazy_shared_strings: self.lazy_shared_strings.clone(),
+            workbook: self.workbook.clone(),
+            content_types: self.content_types.clone(),
+            options: self.options,
+            path: None,
+            part_checksums: BTreeMap::new(),
+            sheet_models: BTreeMap::new(),
+        });
+    }
+}
+
+impl Excel<Cursor<Vec<u8>>> {
+    /// Read a workbook held in memory, ie: received over HTTP or stored in a database, without writing it to a file.
+    pub fn from_bytes(data: Vec<u8>) -> anyhow::Result<Excel<Cursor<Vec<u8>>>> {
+        return Self::from_bytes_with_options(data, ExcelOptions::default());
+    }
+
+    pub fn from_bytes_with_options(data: Vec<u8>, options: ExcelOptions) -> anyhow::Result<Excel<Cursor<Vec<u8>>>> {
+        return Self::from_reader_with_options(Cursor::new(data), options);
+    }
+}
+
+#[cfg(feature = "encryption")]
+impl Excel<Cursor<Vec<u8>>> {
+    /// Open a password protected (encrypted) workbook, the decrypted package is kept in memory.
+    ///
+    /// Workbooks that are not encrypted are opened as is.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn from_path_with_password<P: AsRef<Path>>(
+        path: P,
+        password: &str,
+    ) -> anyhow::Result<Excel<Cursor<Vec<u8>>>> {
+        let data = std::fs::read(path)?;
+        return Self::from_bytes_with_password(data, password);
+    }
+
+    /// Read a password protected (encrypted) workbook from a reader.
+    pub fn from_reader_with_password<R: Read>(
+        mut reader: R,
+        password: &str,
+    ) -> anyhow::Result<Excel<Cursor<Vec<u8>>>> {
+        let mut data: Vec<u8> = vec![];
+        reader.read_to_end(&mut data)?;
+        return Self::from_bytes_with_password(data, password);
+    }
+
+    fn from_bytes_with_password(data: Vec<u8>, password: &str) -> anyhow::Result<Excel<Cursor<Vec<u8>>>> {
+        if !data.starts_with(&COMPOUND_FILE_SIGNATURE) {
+            return Self::from_bytes(data);
+        }
+        let package = decrypt_package(&data, password)?;
+        return Self::from_bytes(package);
     }
 }
 
 impl<RS: Read + Seek> Excel<RS> {
+    /// Read a workbook from any seekable source, ie: a `File`, or a `Cursor<&[u8]>` over borrowed bytes.
     pub fn from_reader(reader: RS) -> anyhow::Result<Excel<RS>> {
+        return Self::from_reader_with_options(reader, ExcelOptions::default());
+    }
+
+    pub fn from_reader_with_options(mut reader: RS, options: ExcelOptions) -> anyhow::Result<Excel<RS>> {
+        if is_compound_file(&mut reader)? {
+            bail!("The file is not a zip package but a compound file: an encrypted workbook (see `Excel::from_path_with_password`) or a legacy xls workbook (see `Xls`).")
+        }
         let mut zip = ZipArchive::new(reader)?;
+        let binary = is_binary_workbook(&mut zip);
         let relationships = load_workbook_relationships(&mut zip)?;
-        Ok(Self {
+        let part_checksums = part_checksums(&mut zip)?;
+        let mut excel = Self {
             zip,
+            binary,
             workbook_relationships: relationships,
             stylesheet: None,
             theme: None,
             shared_strings: None,
+            lazy_shared_strings: None,
             workbook: None,
-        })
+            content_types: None,
+            options,
+            path: None,
+            part_checksums,
+            sheet_models: BTreeMap::new(),
+        };
+
+        if options.strict {
+            let violations = excel.check_conformance()?;
+            if let Some(first) = violations.first() {
+                bail!(
+                    "File does not conform to the schema, {} violation(s) found. First: {}",
+                    violations.len(),
+                    first
+                )
+            }
+        }
+        Ok(excel)
     }
 }
 
@@ -78,10 +594,20 @@ impl<RS: Read + Seek> Excel<RS> {
         return self.workbook_relationships.clone();
     }
 
-    /// Get stylesheet parsed from xl/styles.xml
+    /// Get stylesheet parsed from xl/styles.xml, or xl/styles.bin for binary workbooks
     pub fn get_raw_stylesheet(&mut self) -> anyhow::Result<Option<Box<XlsxStyleSheet>>> {
+        return Ok(self.load_stylesheet()?.map(|s| Box::new(XlsxStyleSheet::clone(&s))));
+    }
+
+    fn load_stylesheet(&mut self) -> anyhow::Result<Option<Arc<XlsxStyleSheet>>> {
         if self.stylesheet.is_none() {
-            self.stylesheet = Some(Box::new(XlsxStyleSheet::load(&mut self.zip)?));
+            let stylesheet = if self.binary {
+                load_binary_stylesheet(&mut self.zip, &self.options)?
+            } else {
+                let path = self.workbook_part_path("styles", "xl/styles.xml");
+                XlsxStyleSheet::load(&mut self.zip, &path)?
+            };
+            self.stylesheet = Some(Arc::new(stylesheet));
         }
         return Ok(self.stylesheet.clone());
     }
@@ -89,30 +615,82 @@ impl<RS: Read + Seek> Excel<RS> {
     /// Get theme used.
     /// Parsed from get stylesheet parsed from xl/theme/theme{}.xml
     pub fn get_raw_theme(&mut self) -> anyhow::Result<Option<Box<XlsxTheme>>> {
+        return Ok(self.load_theme()?.map(|t| Box::new(XlsxTheme::clone(&t))));
+    }
+
+    fn load_theme(&mut self) -> anyhow::Result<Option<Arc<XlsxTheme>>> {
         if self.theme.is_none() {
             let path = zip_path_for_type(&self.workbook_relationships, "theme");
             let path = path.iter().map(|p| p.1.to_string()).collect();
-            self.theme = Some(Box::new(XlsxTheme::load(&mut self.zip, path)?));
+            self.theme = Some(Arc::new(XlsxTheme::load(&mut self.zip, path)?));
         }
         return Ok(self.theme.clone());
     }
 
-    /// Get shared string parsed from xl/sharedStrings.xml
+    /// Get the authors of threaded comments parsed from xl/persons/person.xml
+    pub fn get_raw_persons(&mut self) -> anyhow::Result<XlsxPersons> {
+        let Some((_, path)) = zip_path_for_type(&self.workbook_relationships, "relationships/person")
+            .into_iter()
+            .next()
+        else {
+            return Ok(XlsxPersons::default());
+        };
+        return XlsxPersons::load(&mut self.zip, &path);
+    }
+
+    /// Get shared string parsed from xl/sharedStrings.xml, or xl/sharedStrings.bin for binary workbooks
     pub fn get_raw_shared_strings(&mut self) -> anyhow::Result<Option<Box<XlsxSharedStringTable>>> {
+        return Ok(self.load_shared_strings()?.map(|t| Box::new(XlsxSharedStringTable::clone(&t))));
+    }
+
+    fn load_shared_strings(&mut self) -> anyhow::Result<Option<Arc<XlsxSharedStringTable>>> {
         if self.shared_strings.is_none() {
-            self.shared_strings = Some(Box::new(XlsxSharedStringTable::load(&mut self.zip)?));
+            let shared_strings = if self.binary {
+                load_binary_shared_strings(&mut self.zip, &self.options)?
+            } else {
+                let path = self.workbook_part_path("sharedStrings", "xl/sharedStrings.xml");
+                XlsxSharedStringTable::load(&mut self.zip, &path, &self.options)?
+            };
+            self.shared_strings = Some(Arc::new(shared_strings));
         }
         return Ok(self.shared_strings.clone());
     }
 
-    /// Get workbook parsed from xl/workbook.xml
+    /// Get shared string indexed from xl/sharedStrings.xml without parsing its items, see `ExcelOptions::lazy_shared_strings`.
+    pub fn get_raw_lazy_shared_strings(&mut self) -> anyhow::Result<Arc<XlsxLazySharedStringTable>> {
+        if self.lazy_shared_strings.is_none() {
+            let path = self.workbook_part_path("sharedStrings", "xl/sharedStrings.xml");
+            let table = XlsxLazySharedStringTable::load(&mut self.zip, &path, &self.options)?;
+            self.lazy_shared_strings = Some(Arc::new(table));
+        }
+        return Ok(self.lazy_shared_strings.clone().unwrap_or_default());
+    }
+
+    /// Get workbook parsed from xl/workbook.xml, or xl/workbook.bin for binary workbooks
     pub fn get_raw_workbook(&mut self) -> anyhow::Result<Option<Box<XlsxWorkbook>>> {
+        return Ok(self.load_workbook()?.map(|w| Box::new(XlsxWorkbook::clone(&w))));
+    }
+
+    fn load_workbook(&mut self) -> anyhow::Result<Option<Arc<XlsxWorkbook>>> {
         if self.workbook.is_none() {
-            self.workbook = Some(Box::new(XlsxWorkbook::load(&mut self.zip)?));
+            let workbook = if self.binary {
+                load_binary_workbook(&mut self.zip, &self.options)?
+            } else {
+                XlsxWorkbook::load(&mut self.zip)?
+            };
+            self.workbook = Some(Arc::new(workbook));
         }
         return Ok(self.workbook.clone());
     }
 
+    /// Get content types of the package parts parsed from [Content_Types].xml
+    pub fn get_raw_content_types(&mut self) -> anyhow::Result<Option<Box<XlsxContentTypes>>> {
+        if self.content_types.is_none() {
+            self.content_types = Some(Arc::new(XlsxContentTypes::load(&mut self.zip)?));
+        }
+        return Ok(self.content_types.as_deref().cloned().map(Box::new));
+    }
+
     /// Get a specific worksheet parsed from xl/worksheets/sheet{}.xml
     ///
     /// * name: worksheet name
@@ -131,10 +709,39 @@ impl<RS: Read + Seek> Excel<RS> {
 
     /// Get a specific worksheet parsed from xl/worksheets/sheet{}.xml
     pub fn get_raw_worksheet(&mut self, sheet: &SheetBasicInfo) -> anyhow::Result<XlsxWorksheet> {
+        return self.load_raw_worksheet(sheet, SheetDataMode::Cells);
+    }
+
+    fn load_raw_worksheet(
+        &mut self,
+        sheet: &SheetBasicInfo,
+        sheet_data: SheetDataMode,
+    ) -> anyhow::Result<XlsxWorksheet> {
         if sheet.r#type != SheetType::WorkSheet {
             bail!("Sheet specified is not a worksheet")
         };
-        return XlsxWorksheet::load(&mut self.zip, &sheet.path);
+        if get_actual_path(&mut self.zip, &sheet.path).is_none() {
+            bail!(
+                "Worksheet `{}` refers to part {} that does not exist in the file.",
+                sheet.name,
+                sheet.path
+            )
+        }
+        if self.binary {
+            return load_binary_worksheet(&mut self.zip, &sheet.path, &self.options, sheet_data);
+        }
+        return XlsxWorksheet::load(&mut self.zip, &sheet.path, &self.options, sheet_data);
+    }
+
+    /// Get the unparsed xml of a sheet part of any type, ie: xl/macrosheets/sheet{}.xml.
+    ///
+    /// Macro and dialog sheets are not parsed by this crate, use this to inspect their content.
+    pub fn get_raw_sheet_xml(&mut self, sheet: &SheetBasicInfo) -> anyhow::Result<String> {
+        let bytes = self.get_bytes_for_path(&sheet.path).context(format!(
+            "Sheet `{}` refers to part {} that does not exist in the file.",
+            sheet.name, sheet.path
+        ))?;
+        return Ok(String::from_utf8(bytes)?);
     }
 
     /// Get relationships for a sheet parsed from xl/worksheets/_rels/sheet{}.xml.rels
@@ -200,6 +807,54 @@ impl<RS: Read + Seek> Excel<RS> {
         return self.get_raw_tables(raw_worksheet, worksheet_rels);
     }
 
+    /// Get the comments (legacy notes) of a worksheet parsed from xl/comments{}.xml,
+    /// along with the note shapes of its VML drawing parsed from xl/drawings/vmlDrawing{}.vml
+    pub fn get_raw_comments_for_worksheet(
+        &mut self,
+        sheet: &SheetBasicInfo,
+    ) -> anyhow::Result<(XlsxComments, XlsxVmlDrawing)> {
+        let worksheet_rels = self.get_raw_sheet_relationship(&sheet).unwrap_or(vec![]);
+        return self.get_raw_comments(worksheet_rels);
+    }
+
+    /// Get the threaded comments of a worksheet parsed from xl/threadedComments/threadedComment{}.xml
+    pub fn get_raw_threaded_comments_for_worksheet(
+        &mut self,
+        sheet: &SheetBasicInfo,
+    ) -> anyhow::Result<XlsxThreadedComments> {
+        let worksheet_rels = self.get_raw_sheet_relationship(&sheet).unwrap_or(vec![]);
+        return self.get_raw_threaded_comments(worksheet_rels);
+    }
+
+    /// Get custom properties of a worksheet along with the data parsed from xl/customProperty{}.bin
+    ///
+    /// Properties whose part is missing are skipped.
+    pub fn get_worksheet_custom_properties(
+        &mut self,
+        sheet: &SheetBasicInfo,
+    ) -> anyhow::Result<Vec<CustomProperty>> {
+        let raw_worksheet = self.get_raw_worksheet(&sheet)?;
+        let Some(raw_properties) = raw_worksheet.custom_properties else {
+            return Ok(vec![]);
+        };
+        let worksheet_rels = self.get_raw_sheet_relationship(&sheet).unwrap_or(vec![]);
+
+        let mut properties: Vec<CustomProperty> = vec![];
+        for property in raw_properties {
+            let (Some(name), Some(id)) = (property.name, property.id) else {
+                continue;
+            };
+            let Some(path) = zip_path_for_id(&worksheet_rels, &id) else {
+                continue;
+            };
+            let Ok(data) = self.get_bytes_for_path(&path) else {
+                continue;
+            };
+            properties.push(CustomProperty { name, path, data });
+        }
+        return Ok(properties);
+    }
+
     /// Get XlsxWorksheetDrawing that defines all drawing objects within the worksheet parsed from xl/drawings/drawing{}.xml
     #[cfg(feature = "drawing")]
     pub fn get_raw_drawing_for_worksheet(
@@ -214,6 +869,266 @@ impl<RS: Read + Seek> Excel<RS> {
 
 /// functions for getting processed parsed results
 impl<RS: Read + Seek> Excel<RS> {
+    /// Get the declared content type of the main part, the target of the officeDocument relationship (`_rels/.rels`),
+    /// usually xl/workbook.xml, or xl/workbook.bin for binary workbooks.
+    ///
+    /// Tells workbooks, macro enabled workbooks, templates, add-ins and binary workbooks apart.
+    /// The content types are read once and cached.
+    pub fn content_type(&mut self) -> anyhow::Result<WorkbookContentType> {
+        let Some(content_types) = self.get_raw_content_types()? else {
+            bail!("Failed to get content types.")
+        };
+        let workbook_path = load_workbook_path(&mut self.zip);
+        let Some(content_type) = content_types.for_part(&workbook_path) else {
+            bail!("Content type of {} is not declared.", workbook_path)
+        };
+        return Ok(WorkbookContentType::from_content_type(&content_type));
+    }
+
+    /// Get Office add-ins embedded in the workbook, parsed from xl/webextensions/webextension{}.xml
+    pub fn get_web_extensions(&mut self) -> anyhow::Result<Vec<WebExtension>> {
+        let mut paths: Vec<String> = self
+            .zip
+            .file_names()
+            .filter(|n| {
+                let lower = n.to_ascii_lowercase();
+                lower.starts_with("xl/webextensions/webextension") && lower.ends_with(".xml")
+            })
+            .map(|n| n.to_string())
+            .collect();
+        paths.sort();
+
+        let taskpane_targets: Vec<String> =
+            load_drawing_relationships(&mut self.zip, "xl/webextensions/taskpanes.xml")
+                .unwrap_or(vec![])
+                .into_iter()
+                .map(|r| format!("xl/webextensions/{}", r.target.trim_start_matches("./")))
+                .collect();
+
+        let mut extensions: Vec<WebExtension> = vec![];
+        for path in paths {
+            let raw = XlsxWebExtension::load(&mut self.zip, &path)?;
+            let in_taskpane = taskpane_targets.iter().any(|t| t.eq_ignore_ascii_case(&path));
+            extensions.push(WebExtension::from_raw(raw, path, in_taskpane));
+        }
+        return Ok(extensions);
+    }
+
+    /// Whether the workbook contains a data model (Power Pivot) part under xl/model/.
+    ///
+    /// Such workbooks hold their data in the model rather than in the sheets.
+    pub fn has_data_model(&mut self) -> bool {
+        return self.get_data_model_path().is_some();
+    }
+
+    /// Get the data model (Power Pivot) of the workbook, and the pivot caches backed by it.
+    ///
+    /// A pivot cache is backed by the model when its external connection in xl/connections.xml is the model connection.
+    pub fn get_data_model(&mut self) -> anyhow::Result<Option<DataModel>> {
+        let Some(path) = self.get_data_model_path() else {
+            return Ok(None);
+        };
+        let (size, compressed_size) = {
+            let file = self.zip.by_name(&path)?;
+            (file.size(), file.compressed_size())
+        };
+
+        let model_connection_ids: Vec<u64> = load_connections(&mut self.zip)?
+            .into_iter()
+            .filter(|c| c.model == Some(true) || c.name.as_deref() == Some("ThisWorkbookDataModel"))
+            .filter_map(|c| c.id)
+            .collect();
+
+        let pivot_caches = match self.get_raw_workbook()? {
+            Some(workbook) => workbook.pivot_caches.unwrap_or(vec![]),
+            None => vec![],
+        };
+        let mut pivot_cache_ids: Vec<u64> = vec![];
+        for cache in pivot_caches {
+            let (Some(cache_id), Some(id)) = (cache.cache_id, cache.id) else {
+                continue;
+            };
+            let Some(definition_path) = zip_path_for_id(&self.workbook_relationships, &id) else {
+                continue;
+            };
+            let definition = XlsxPivotCacheDefinition::load(&mut self.zip, &definition_path)?;
+            let Some(source) = definition.cache_source else {
+                continue;
+            };
+            if source.r#type.as_deref() == Some("external")
+                && source
+                    .connection_id
+                    .is_some_and(|c| model_connection_ids.contains(&c))
+            {
+                pivot_cache_ids.push(cache_id);
+            }
+        }
+
+        return Ok(Some(DataModel {
+            path,
+            size,
+            compressed_size,
+            pivot_cache_ids,
+        }));
+    }
+
+    /// Get the items of every slicer in the workbook and their selection state,
+    /// parsed from xl/slicerCaches/slicerCache{}.xml
+    pub fn get_slicer_states(&mut self) -> anyhow::Result<Vec<SlicerState>> {
+        let paths = zip_path_for_type(&self.workbook_relationships, "slicerCache");
+        if paths.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let mut pivot_caches: Vec<XlsxPivotCacheDefinition> = vec![];
+        for (_, path) in zip_path_for_type(&self.workbook_relationships, "pivotCacheDefinition") {
+            pivot_caches.push(XlsxPivotCacheDefinition::load(&mut self.zip, &path)?);
+        }
+
+        let mut states: Vec<SlicerState> = vec![];
+        for (_, path) in paths {
+            let raw = XlsxSlicerCacheDefinition::load(&mut self.zip, &path)?;
+            states.push(SlicerState::from_raw(raw, &pivot_caches));
+        }
+        return Ok(states);
+    }
+
+    /// Get the pivot tables of every worksheet, parsed from xl/pivotTables/pivotTable{}.xml,
+    /// with the source of their pivot cache.
+    ///
+    /// Use `PivotTable::source_records` to read the data the pivot tables summarize.
+    pub fn get_pivot_tables(&mut self) -> anyhow::Result<Vec<PivotTable>> {
+        let pivot_caches = match self.get_raw_workbook()? {
+            Some(workbook) => workbook.pivot_caches.unwrap_or(vec![]),
+            None => vec![],
+        };
+        let mut definitions: Vec<(u64, XlsxPivotCacheDefinition)> = vec![];
+        for cache in pivot_caches {
+            let (Some(cache_id), Some(id)) = (cache.cache_id, cache.id) else {
+                continue;
+            };
+            let Some(definition_path) = zip_path_for_id(&self.workbook_relationships, &id) else {
+                continue;
+            };
+            definitions.push((cache_id, XlsxPivotCacheDefinition::load(&mut self.zip, &definition_path)?));
+        }
+
+        let mut pivot_tables: Vec<PivotTable> = vec![];
+        for sheet in self.get_sheets()? {
+            if sheet.r#type != SheetType::WorkSheet {
+                continue;
+            }
+            let worksheet_rels = self.get_raw_sheet_relationship(&sheet).unwrap_or(vec![]);
+            for (_, path) in zip_path_for_type(&worksheet_rels, "pivotTable") {
+                let raw = XlsxPivotTableDefinition::load(&mut self.zip, &path)?;
+                let pivot_cache = definitions
+                    .iter()
+                    .find(|(cache_id, _)| raw.cache_id == Some(*cache_id))
+                    .map(|(_, definition)| definition);
+                pivot_tables.push(PivotTable::from_raw(raw, &sheet.name, pivot_cache));
+            }
+        }
+        return Ok(pivot_tables);
+    }
+
+    /// Get the date range selected in every timeline in the workbook,
+    /// parsed from xl/timelineCaches/timelineCache{}.xml
+    pub fn get_timeline_states(&mut self) -> anyhow::Result<Vec<TimelineState>> {
+        let mut states: Vec<TimelineState> = vec![];
+        for (_, path) in zip_path_for_type(&self.workbook_relationships, "timelineCache") {
+            let raw = XlsxTimelineCacheDefinition::load(&mut self.zip, &path)?;
+            states.push(TimelineState::from_raw(raw));
+        }
+        return Ok(states);
+    }
+
+    /// Get the major and minor fonts of the theme, parsed from xl/theme/theme{}.xml
+    ///
+    /// None if the workbook has no theme, or its theme has no font scheme.
+    pub fn get_theme_fonts(&mut self) -> anyhow::Result<Option<ThemeFonts>> {
+        let Some(theme) = self.get_raw_theme()? else {
+            return Ok(None);
+        };
+        let font_scheme = theme
+            .theme_elements
+            .as_ref()
+            .and_then(|e| e.font_scheme.as_ref());
+        return Ok(font_scheme.map(ThemeFonts::from_raw));
+    }
+
+    /// Get the default font of the workbook: the font of the `Normal` cell style, used by cells without a font of their own.
+    ///
+    /// A font following the theme (`scheme` of `minor` or `major`) is named after the latin typeface of the theme.
+    /// Column widths are measured in the maximum digit width of this font, see `units::max_digit_width`.
+    pub fn get_default_font(&mut self) -> anyhow::Result<Font> {
+        let theme_fonts = self.get_theme_fonts()?;
+        let color_scheme = match self.get_raw_theme()? {
+            Some(theme) => theme.theme_elements.and_then(|e| e.color_scheme),
+            None => None,
+        };
+        let Some(stylesheet) = self.get_raw_stylesheet()? else {
+            return Ok(Font::from_raw_font(None, None, color_scheme));
+        };
+
+        // the Normal cell style is the first cellStyleXfs
+        let font_id = stylesheet
+            .cell_style_xfs
+            .as_ref()
+            .and_then(|formats| formats.first())
+            .and_then(|format| format.font_id)
+            .unwrap_or(0);
+        let raw_font = usize::try_from(font_id).ok().and_then(|i| stylesheet.get_font(i));
+        let mut font = Font::from_raw_font(raw_font, stylesheet.colors.clone(), color_scheme);
+
+        let typeface = match (&font.scheme, theme_fonts) {
+            (FontSchemeValue::Minor, Some(theme_fonts)) => theme_fonts.minor.latin,
+            (FontSchemeValue::Major, Some(theme_fonts)) => theme_fonts.major.latin,
+            _ => None,
+        };
+        if let Some(typeface) = typeface.filter(|t| !t.is_empty()) {
+            font.name = typeface;
+        }
+        return Ok(font);
+    }
+
+    /// Get an overview of the workbook: content type, sheets, macro sheets, add-ins and data model.
+    pub fn get_summary(&mut self) -> anyhow::Result<WorkbookSummary> {
+        let content_type = self.content_type().ok();
+        let sheets = self.get_sheets()?;
+        let has_macro_sheets = sheets.iter().any(|s| s.r#type == SheetType::MacroSheet);
+        let web_extensions = self.get_web_extensions()?;
+        let has_data_model = self.has_data_model();
+        return Ok(WorkbookSummary {
+            content_type,
+            sheets,
+            has_macro_sheets,
+            web_extensions,
+            has_data_model,
+        });
+    }
+
+    /// Estimate the bytes held by the parts loaded so far: shared strings, styles, theme, workbook and the sheets cached by `to_processed_model`.
+    ///
+    /// Nothing is loaded by this function, a workbook that has not been read yet reports (close to) zero.
+    pub fn memory_footprint(&self) -> MemoryFootprint {
+        MemoryFootprint {
+            shared_strings: self.shared_strings.as_deref().map_or(0, shared_strings_size)
+                + self.lazy_shared_strings.as_deref().map_or(0, |table| table.size()),
+            stylesheet: self.stylesheet.as_deref().map_or(0, stylesheet_size),
+            theme: self.theme.as_deref().map_or(0, theme_size),
+            workbook: self.workbook.as_deref().map_or(0, workbook_size),
+            processed_sheets: self
+                .sheet_models
+                .iter()
+                .map(|(path, (dependencies, model))| {
+                    path.len()
+                        + dependencies.iter().map(|d| d.len()).sum::<usize>()
+                        + workbook_sheet_size(model)
+                })
+                .sum(),
+        }
+    }
+
     /// Get a list of sheets in the workbook
     pub fn get_sheets(&mut self) -> anyhow::Result<Vec<SheetBasicInfo>> {
         let Some(workbook) = self.get_raw_workbook()?.clone() else {
@@ -230,6 +1145,145 @@ impl<RS: Read + Seek> Excel<RS> {
         return sheets;
     }
 
+    /// Get the defined names of the workbook, parsed from the `definedNames` of xl/workbook.xml
+    ///
+    /// Built-in names, ie: `_xlnm.Print_Area`, are included, see `DefinedName::is_built_in`.
+    pub fn defined_names(&mut self) -> anyhow::Result<Vec<DefinedName>> {
+        let Some(workbook) = self.get_raw_workbook()? else {
+            return Ok(vec![]);
+        };
+        let sheet_names: Vec<String> = self.get_sheets()?.into_iter().map(|s| s.name).collect();
+        return Ok(workbook
+            .defined_names
+            .as_deref()
+            .unwrap_or(&[])
+            .iter()
+            .filter_map(|d| DefinedName::from_raw(d, &sheet_names))
+            .collect());
+    }
+
+    /// Find a defined name, case insensitive, as seen from a sheet: the name scoped to the sheet if any, the workbook level name otherwise.
+    ///
+    /// * sheet_name: None to only look up workbook level names
+    pub fn defined_name(&mut self, name: &str, sheet_name: Option<&str>) -> anyhow::Result<Option<DefinedName>> {
+        let defined_name = self
+            .defined_names()?
+            .into_iter()
+            .filter(|d| d.name.eq_ignore_ascii_case(name) && d.is_visible_from(sheet_name))
+            .max_by_key(|d| d.scope != DefinedNameScope::Workbook);
+        return Ok(defined_name);
+    }
+
+    /// Get the Excel 4.0 (XLM) macro sheets of the workbook, including hidden ones.
+    ///
+    /// XLM macros are a known malware vector, a non empty result means the workbook contains macros
+    /// even if it has no VBA project.
+    pub fn get_macro_sheets(&mut self) -> anyhow::Result<Vec<SheetBasicInfo>> {
+        let sheets = self.get_sheets()?;
+        return Ok(sheets
+            .into_iter()
+            .filter(|s| s.r#type == SheetType::MacroSheet)
+            .collect());
+    }
+
+    /// Data users may have tried to hide, for compliance reviews: hidden and very hidden sheets,
+    /// hidden rows and columns, rows and columns shrunk to nothing, and text written in the color of its background.
+    ///
+    /// Every worksheet is loaded with its cells.
+    pub fn hidden_data_report(&mut self) -> anyhow::Result<HiddenDataReport> {
+        let sheets = self.get_sheets()?;
+        let mut worksheets: Vec<WorksheetHiddenData> = vec![];
+        for sheet in sheets.iter().filter(|s| s.r#type == SheetType::WorkSheet) {
+            let worksheet = self.get_worksheet(sheet)?;
+            worksheets.extend(WorksheetHiddenData::from_worksheet(&worksheet)?);
+        }
+        return Ok(HiddenDataReport {
+            hidden_sheets: sheets
+                .into_iter()
+                .filter(|s| s.visible_state != SheetVisibleState::Visible)
+                .collect(),
+            worksheets,
+        });
+    }
+
+    /// Write a copy of the workbook with its values replaced, to share files reproducing a bug without leaking data.
+    ///
+    /// Shared strings, inline strings, numbers, cached results and string literals of formulas, the values of pivot caches,
+    /// the authors, manager and company of the document properties and, with `AnonymizeOptions::comments`, the text and authors of comments
+    /// are replaced. Structure, styles, formulas, data validations, sheet and defined names are kept,
+    /// and equal values stay equal, so lookups between sheets still match.
+    ///
+    /// Other parts are copied as is: headers and footers, and the caches of charts and external links still hold the original data.
+    /// The copy of an encrypted workbook is not encrypted.
+    pub fn anonymize<W: Write + Seek>(&mut self, writer: W, options: &AnonymizeOptions) -> anyhow::Result<W> {
+        if self.binary {
+            bail!("Anonymizing binary workbooks (.xlsb) is not supported.");
+        }
+        let worksheets: Vec<String> = zip_path_for_type(&self.workbook_relationships, "worksheet")
+            .into_iter()
+            .map(|(_, path)| path)
+            .collect();
+        let shared_strings = self.workbook_part_path("sharedStrings", "xl/sharedStrings.xml");
+        let part_kind = |part: &str| {
+            if worksheets.iter().any(|w| w == part) {
+                return Some(AnonymizedPart::Worksheet);
+            }
+            if !part.ends_with(".xml") {
+                return None;
+            }
+            if part == shared_strings {
+                return Some(AnonymizedPart::Text(&[b"t"]));
+            }
+            if part == "docProps/core.xml" {
+                return Some(AnonymizedPart::Text(&[b"creator", b"lastModifiedBy"]));
+            }
+            if part == "docProps/app.xml" {
+                return Some(AnonymizedPart::Text(&[b"Manager", b"Company"]));
+            }
+            if part.starts_with("xl/pivotCache/") {
+                // records of pivotCacheRecords*.xml, and the distinct values and ranges of the fields of pivotCacheDefinition*.xml
+                return Some(AnonymizedPart::Attributes(&[
+                    (b"s", b"v", AnonymizedValue::Text),
+                    (b"n", b"v", AnonymizedValue::Number),
+                    (b"sharedItems", b"minValue", AnonymizedValue::Number),
+                    (b"sharedItems", b"maxValue", AnonymizedValue::Number),
+                ]));
+            }
+            if options.comments && part.starts_with("xl/comments") {
+                return Some(AnonymizedPart::Text(&[b"t", b"author"]));
+            }
+            if options.comments && part.starts_with("xl/threadedComments/") {
+                return Some(AnonymizedPart::Text(&[b"text"]));
+            }
+            if options.comments && part.starts_with("xl/persons/") {
+                return Some(AnonymizedPart::Attributes(&[
+                    (b"person", b"displayName", AnonymizedValue::Text),
+                    (b"person", b"userId", AnonymizedValue::Text),
+                ]));
+            }
+            return None;
+        };
+        return anonymize_package(&mut self.zip, part_kind, writer, options);
+    }
+
+    /// Get groups of sheets sharing the same name.
+    ///
+    /// Excel forbids duplicate names, but some generators produce them.
+    /// Such sheets are all listed by `get_sheets`, but cannot be retrieved by name.
+    pub fn get_duplicate_sheet_names(&mut self) -> anyhow::Result<Vec<Vec<SheetBasicInfo>>> {
+        let mut groups: Vec<Vec<SheetBasicInfo>> = vec![];
+        for sheet in self.get_sheets()? {
+            match groups
+                .iter_mut()
+                .find(|g| sheet_names_equal(&g[0].name, &sheet.name))
+            {
+                Some(group) => group.push(sheet),
+                None => groups.push(vec![sheet]),
+            }
+        }
+        Ok(groups.into_iter().filter(|g| g.len() > 1).collect())
+    }
+
     /// Get worksheet (processed)
     ///
     /// name: Worksheet name
@@ -248,23 +1302,58 @@ impl<RS: Read + Seek> Excel<RS> {
 
     /// Get worksheet (processed)
     pub fn get_worksheet(&mut self, sheet: &SheetBasicInfo) -> anyhow::Result<Worksheet> {
-        let raw_workbook = self.get_raw_workbook()?.context("workbook not available")?;
-        let raw_worksheet = self.get_raw_worksheet(&sheet)?;
+        return self.load_worksheet(sheet, SheetDataMode::Cells);
+    }
+
+    /// Get worksheet (processed) without loading its cells, to read them row by row with `Worksheet::rows`.
+    ///
+    /// Keeps memory usage independent of the size of the sheet.
+    /// Functions working on all cells at once, ie: `get_cell`, `get_cells`, `search`, find no cells on the returned worksheet.
+    pub fn get_worksheet_without_cells(&mut self, sheet: &SheetBasicInfo) -> anyhow::Result<Worksheet> {
+        return self.load_worksheet(sheet, SheetDataMode::Skip);
+    }
+
+    /// Get the layout of a worksheet without parsing its cells: dimension, row heights and hidden rows,
+    /// columns, merged cells, frozen panes and data validations.
+    ///
+    /// Meant to render an empty grid before streaming the cells with `Worksheet::rows`.
+    /// Like `get_worksheet_without_cells`, functions working on all cells at once find no cells on the returned worksheet,
+    /// but rows written in the file keep their properties, ie: for `Worksheet::row_height`.
+    pub fn get_worksheet_shape(&mut self, sheet: &SheetBasicInfo) -> anyhow::Result<Worksheet> {
+        return self.load_worksheet(sheet, SheetDataMode::Rows);
+    }
+
+    fn load_worksheet(
+        &mut self,
+        sheet: &SheetBasicInfo,
+        sheet_data: SheetDataMode,
+    ) -> anyhow::Result<Worksheet> {
+        let raw_workbook = self.load_workbook()?.context("workbook not available")?;
+        let raw_worksheet = self.load_raw_worksheet(sheet, sheet_data)?;
         let worksheet_rels = self.get_raw_sheet_relationship(&sheet).unwrap_or(vec![]);
 
-        let shared_strings = if let Some(table) = self.get_raw_shared_strings()? {
-            table.string_item.unwrap_or(vec![])
+        let shared_strings = if self.options.lazy_shared_strings && !self.binary {
+            SharedStrings::Lazy(self.get_raw_lazy_shared_strings()?)
+        } else if let Some(table) = self.load_shared_strings()? {
+            SharedStrings::Loaded(table)
         } else {
-            vec![]
+            SharedStrings::default()
         };
 
         let stylesheet = self
-            .get_raw_stylesheet()?
+            .load_stylesheet()?
             .context("Style sheet not availalble")?;
 
-        let theme = self.get_raw_theme()?;
+        let theme = self.load_theme()?;
 
         let tables = self.get_raw_tables(raw_worksheet.clone(), worksheet_rels.clone())?;
+        let (comments, vml_drawing) = self.get_raw_comments(worksheet_rels.clone())?;
+        let threaded_comments = self.get_raw_threaded_comments(worksheet_rels.clone())?;
+        let persons = if threaded_comments.comments.is_empty() {
+            XlsxPersons::default()
+        } else {
+            self.get_raw_persons()?
+        };
 
         #[cfg(feature = "drawing")]
         let mut drawing_rel: XlsxRelationships = vec![];
@@ -281,18 +1370,23 @@ impl<RS: Read + Seek> Excel<RS> {
         #[cfg(feature = "drawing")]
         let bytes = self.get_image_bytes_in_rel(drawing_rel.clone());
 
-        let worksheet = Worksheet::from_raw(
+        let mut worksheet = Worksheet::from_raw(
             sheet.clone().name,
             sheet.sheet_id,
+            sheet.path.clone(),
             Box::new(raw_worksheet),
             Box::new(worksheet_rels),
             Box::new(tables),
-            Box::new(raw_workbook.clone().defined_names.unwrap_or(vec![])),
-            self.is_1904(*raw_workbook.clone()),
-            self.calculation_mode(*raw_workbook.clone()),
-            Box::new(shared_strings),
-            stylesheet.clone(),
-            theme.clone(),
+            Box::new(comments),
+            Box::new(vml_drawing),
+            Box::new(threaded_comments),
+            Box::new(persons),
+            Box::new(raw_workbook.defined_names.clone().unwrap_or(vec![])),
+            self.is_1904(XlsxWorkbook::clone(&raw_workbook)),
+            self.calculation_mode(XlsxWorkbook::clone(&raw_workbook)),
+            shared_strings,
+            stylesheet,
+            theme,
             #[cfg(feature = "drawing")]
             Box::new(drawing_rel),
             #[cfg(feature = "drawing")]
@@ -300,9 +1394,338 @@ impl<RS: Read + Seek> Excel<RS> {
             #[cfg(feature = "drawing")]
             Box::new(bytes),
         );
+        worksheet.cell_ids = self.options.cell_ids;
 
         Ok(worksheet)
     }
+
+    /// Build a fully owned snapshot of all worksheets: cells with their styles, merged cells, tables and data validations.
+    ///
+    /// The result no longer depends on the file, and can be serialized with the `serde` feature to be cached.
+    ///
+    /// Processed sheets are cached, and reused by later calls until changed parts are reloaded with `reload_if_changed`.
+    pub fn to_processed_model(&mut self) -> anyhow::Result<Workbook> {
+        let mut sheets: Vec<WorkbookSheet> = vec![];
+        for sheet in self.get_sheets()? {
+            if sheet.r#type != SheetType::WorkSheet {
+                continue;
+            }
+            if let Some((_, model)) = self.sheet_models.get(&sheet.path) {
+                sheets.push(model.clone());
+                continue;
+            }
+            let worksheet = self.get_worksheet(&sheet)?;
+            let model = WorkbookSheet::from_worksheet(&sheet, worksheet)?;
+            let dependencies = self.get_sheet_dependencies(&sheet);
+            self.sheet_models
+                .insert(sheet.path.clone(), (dependencies, model.clone()));
+            sheets.push(model);
+        }
+        Ok(Workbook { sheets })
+    }
+
+    /// Process all worksheets and hand them over as a read only, thread safe handle.
+    ///
+    /// The file is no longer needed afterwards, clone the `Arc` to share the workbook between threads.
+    pub fn into_shared(mut self) -> anyhow::Result<Arc<WorkbookData>> {
+        let sheets = self.get_sheets()?;
+        let model = self.to_processed_model()?;
+        Ok(Arc::new(WorkbookData { sheets, model }))
+    }
+
+    /// Check the workbook part, worksheets, stylesheet and shared strings against the schema:
+    /// order of child elements, required elements and attributes, and ascending order of rows and cells.
+    ///
+    /// Only the constraints readers commonly rely on are checked, this is not a full schema validation.
+    /// Elements of other schemas, ie: the extensions of `extLst` and `mc:AlternateContent`, are not checked,
+    /// nor are the binary parts of a xlsb file.
+    pub fn check_conformance(&mut self) -> anyhow::Result<Vec<ConformanceViolation>> {
+        let mut parts: Vec<String> = vec![load_workbook_path(&mut self.zip)];
+        for r#type in ["styles", "sharedStrings", "worksheet"] {
+            parts.extend(
+                zip_path_for_type(&self.workbook_relationships, r#type)
+                    .into_iter()
+                    .map(|(_, path)| path),
+            );
+        }
+
+        // binary parts of a xlsb file are not xml
+        parts.retain(|part| !part.to_ascii_lowercase().ends_with(".bin"));
+
+        let mut violations: Vec<ConformanceViolation> = vec![];
+        for part in parts {
+            violations.extend(check_part_conformance(&mut self.zip, &part)?);
+        }
+        Ok(violations)
+    }
+
+    /// List the parts and extensions of the file that are known but not read, ie: charts, pivot tables, macros
+    /// or the `extLst` extensions of workbook.xml, styles.xml and the worksheets, such as sparklines.
+    ///
+    /// Parts are listed in the order of the package, followed by the extensions of each part.
+    /// An empty list does not guarantee full fidelity, only that none of the known unread features are present.
+    pub fn unsupported_features(&mut self) -> anyhow::Result<Vec<UnsupportedFeature>> {
+        let mut features: Vec<UnsupportedFeature> = self
+            .zip
+            .file_names()
+            .filter_map(|part| {
+                UnsupportedFeatureKind::from_part_name(part).map(|kind| UnsupportedFeature {
+                    part: part.to_string(),
+                    kind,
+                })
+            })
+            .collect();
+
+        let mut parts: Vec<String> = vec![load_workbook_path(&mut self.zip)];
+        for r#type in ["styles", "worksheet"] {
+            parts.extend(
+                zip_path_for_type(&self.workbook_relationships, r#type)
+                    .into_iter()
+                    .map(|(_, path)| path),
+            );
+        }
+        // binary parts of a xlsb file are not xml
+        parts.retain(|part| !part.to_ascii_lowercase().ends_with(".bin"));
+
+        for part in parts {
+            features.extend(find_part_extensions(&mut self.zip, &part)?);
+        }
+        Ok(features)
+    }
+
+    /// Check that every sheet in xl/workbook.xml resolves to an existing part through xl/_rels/workbook.xml.rels,
+    /// and report sheet parts that are not referenced.
+    pub fn check_workbook_relationships(&mut self) -> anyhow::Result<Vec<RelationshipIssue>> {
+        let sheets = match self.get_raw_workbook()? {
+            Some(workbook) => workbook.sheets.unwrap_or(vec![]),
+            None => vec![],
+        };
+        let part_names: Vec<String> = self.zip.file_names().map(|n| n.to_string()).collect();
+        Ok(check_workbook_relationships(
+            &sheets,
+            &self.workbook_relationships,
+            &part_names,
+        ))
+    }
+
+    /// Search every worksheet for cells matching a pattern, and optionally comments, defined names, headers and footers.
+    ///
+    /// Worksheets are loaded one at a time, see `search_all_parallel` to load them concurrently.
+    /// Matches are ordered by sheet, followed by defined names.
+    pub fn search_all(
+        &mut self,
+        pattern: &str,
+        options: &SearchOptions,
+    ) -> anyhow::Result<Vec<SearchMatch>> {
+        let pattern = options.build_regex(pattern)?;
+        let sheets = self.get_sheets()?;
+        let mut found: Vec<SearchMatch> = vec![];
+
+        for sheet in sheets.iter() {
+            if sheet.r#type != SheetType::WorkSheet {
+                continue;
+            }
+            let worksheet = self.get_worksheet(sheet)?;
+            found.extend(worksheet.search(&pattern, options)?);
+        }
+
+        if options.include_defined_names {
+            found.extend(self.search_defined_names(&pattern, &sheets)?);
+        }
+
+        Ok(found)
+    }
+
+    /// Defined names whose value matches a pattern, for `search_all`.
+    fn search_defined_names(
+        &mut self,
+        pattern: &Regex,
+        sheets: &[SheetBasicInfo],
+    ) -> anyhow::Result<Vec<SearchMatch>> {
+        let defined_names = match self.get_raw_workbook()? {
+            Some(workbook) => workbook.defined_names.unwrap_or(vec![]),
+            None => vec![],
+        };
+        let mut found: Vec<SearchMatch> = vec![];
+        for defined_name in defined_names {
+            let (Some(name), Some(value)) = (defined_name.name, defined_name.value) else {
+                continue;
+            };
+            if !pattern.is_match(&value) {
+                continue;
+            }
+            // localSheetId is the index of the sheet within the workbook
+            let sheet_name = defined_name
+                .local_sheet_id
+                .and_then(|i| usize::try_from(i).ok())
+                .and_then(|i| sheets.get(i))
+                .map(|s| s.name.clone());
+            found.push(SearchMatch {
+                sheet_name,
+                location: SearchLocation::DefinedName { name },
+                text: value,
+            });
+        }
+        Ok(found)
+    }
+
+    /// Find all formulas, data validations and defined names referring to sheets that are to be renamed.
+    ///
+    /// renames: (old name, new name)
+    pub fn find_sheet_rename_references(
+        &mut self,
+        renames: &[(String, String)],
+    ) -> anyhow::Result<Vec<SheetNameReference>> {
+        let mut references: Vec<SheetNameReference> = vec![];
+
+        for sheet in self.get_sheets()? {
+            if sheet.r#type != SheetType::WorkSheet {
+                continue;
+            }
+            let raw_worksheet = self.get_raw_worksheet(&sheet)?;
+
+            let rows = match raw_worksheet.sheet_data.as_ref() {
+                Some(sheet_data) => sheet_data.rows.as_deref().unwrap_or(&[]),
+                None => &[],
+            };
+            for cell in rows.iter().flat_map(|r| r.cells.as_deref().unwrap_or(&[])) {
+                let (Some(formula), Some(coordinate)) = (cell.formula.as_ref(), cell.coordinate()) else {
+                    continue;
+                };
+                let location = SheetReferenceLocation::Cell {
+                    sheet_name: sheet.name.clone(),
+                    coordinate,
+                };
+                references.extend(SheetNameReference::find(location, &formula.raw_value, renames));
+            }
+
+            let validations = match raw_worksheet.data_validations.as_ref() {
+                Some(validations) => validations.data_validations.as_slice(),
+                None => &[],
+            };
+            for validation in validations {
+                for formula in [&validation.formula1, &validation.formula2].into_iter().flatten() {
+                    let location = SheetReferenceLocation::DataValidation {
+                        sheet_name: sheet.name.clone(),
+                        sqref: validation.sqref.clone().unwrap_or_default(),
+                    };
+                    references.extend(SheetNameReference::find(location, formula, renames));
+                }
+            }
+        }
+
+        let defined_names = match self.get_raw_workbook()? {
+            Some(workbook) => workbook.defined_names.unwrap_or(vec![]),
+            None => vec![],
+        };
+        for defined_name in defined_names {
+            let (Some(name), Some(value)) = (defined_name.name, defined_name.value) else {
+                continue;
+            };
+            let location = SheetReferenceLocation::DefinedName { name };
+            references.extend(SheetNameReference::find(location, &value, renames));
+        }
+
+        Ok(references)
+    }
+
+    /// Check that data validation formulas refer to existing sheets, defined names and tables.
+    ///
+    /// Dropdown lists whose source sheet or name was deleted or renamed are reported this way.
+    /// Formulas are checked as stored, references to other workbooks are not checked.
+    pub fn check_data_validation_references(&mut self) -> anyhow::Result<Vec<ValidationReferenceIssue>> {
+        let sheets = self.get_sheets()?;
+        let defined_names = match self.get_raw_workbook()? {
+            Some(workbook) => workbook.defined_names.unwrap_or(vec![]),
+            None => vec![],
+        };
+        let mut targets = ReferenceTargets {
+            sheet_names: sheets.iter().map(|s| s.name.clone()).collect(),
+            defined_names: defined_names
+                .into_iter()
+                .filter_map(|d| Some((d.name?, d.local_sheet_id.map(|i| i as usize), d.value.unwrap_or_default())))
+                .collect(),
+            table_names: vec![],
+        };
+        for sheet in sheets.iter().filter(|s| s.r#type == SheetType::WorkSheet) {
+            for table in self.get_raw_tables_for_worksheet(sheet)? {
+                targets.table_names.extend(table.name);
+                targets.table_names.extend(table.display_name);
+            }
+        }
+
+        let mut issues: Vec<ValidationReferenceIssue> = vec![];
+        for (sheet_index, sheet) in sheets.iter().enumerate() {
+            if sheet.r#type != SheetType::WorkSheet {
+                continue;
+            }
+            let raw_worksheet = self.get_raw_worksheet(sheet)?;
+            let validations = match raw_worksheet.data_validations.as_ref() {
+                Some(validations) => validations.data_validations.as_slice(),
+                None => &[],
+            };
+            for validation in validations {
+                for formula in [&validation.formula1, &validation.formula2].into_iter().flatten() {
+                    // formulas are kept escaped by the raw data validations
+                    let formula = quick_xml::escape::unescape(formula).map(|f| f.to_string()).unwrap_or(formula.clone());
+                    for problem in targets.check_formula(&formula, sheet_index) {
+                        issues.push(ValidationReferenceIssue {
+                            sheet_name: sheet.name.clone(),
+                            sqref: validation.sqref.clone().unwrap_or_default(),
+                            formula: formula.clone(),
+                            problem,
+                        });
+                    }
+                }
+            }
+        }
+
+        Ok(issues)
+    }
+
+    /// Formula cells reading a range of a worksheet, ie: impact analysis before editing a template.
+    ///
+    /// Formulas are matched when they refer to a range overlapping `range`, directly, from another sheet,
+    /// through a 3D reference spanning the sheet, or through defined names and tables.
+    /// Only cells reading the range themselves are listed, call again with a listed cell to follow the chain.
+    /// References to other workbooks are skipped.
+    ///
+    /// Cells are ordered by sheet, in workbook order, then row by row.
+    pub fn dependents_of(&mut self, sheet_name: &str, range: Dimension) -> anyhow::Result<Vec<FormulaDependent>> {
+        let target = self.get_sheet_with_name(sheet_name)?;
+        let sheets = self.get_sheets()?;
+        let mut targets = PrecedentTargets {
+            sheet_names: sheets.iter().map(|s| s.name.clone()).collect(),
+            defined_names: self.defined_names()?,
+            tables: vec![],
+        };
+        let mut formula_cells = vec![];
+        for sheet in sheets.iter().filter(|s| s.r#type == SheetType::WorkSheet) {
+            let worksheet = self.get_worksheet(sheet)?;
+            targets.tables.extend(
+                worksheet
+                    .tables
+                    .iter()
+                    .map(|t| (t.display_name.clone(), sheet.name.clone(), t.dimension)),
+            );
+            formula_cells.push((sheet.name.clone(), worksheet.formula_cells()));
+        }
+
+        let mut dependents: Vec<FormulaDependent> = vec![];
+        for (sheet_name, cells) in formula_cells {
+            for cell in cells {
+                let Some(via) = targets.reads(&cell.formula, &sheet_name, &target.name, range) else {
+                    continue;
+                };
+                dependents.push(FormulaDependent {
+                    sheet_name: sheet_name.clone(),
+                    formula_cell: cell,
+                    via,
+                });
+            }
+        }
+        Ok(dependents)
+    }
 }
 
 /// private helper functions
@@ -335,6 +1758,48 @@ impl<RS: Read + Seek> Excel<RS> {
         };
     }
 
+    fn get_raw_comments(
+        &mut self,
+        worksheet_rels: XlsxRelationships,
+    ) -> anyhow::Result<(XlsxComments, XlsxVmlDrawing)> {
+        let relationships = WorksheetRelationships::new(worksheet_rels.clone());
+        let Some(comments_part) = relationships.comments_part() else {
+            return Ok((XlsxComments::default(), XlsxVmlDrawing::default()));
+        };
+        let Some(path) = zip_path_for_id(&worksheet_rels, &comments_part.id) else {
+            return Ok((XlsxComments::default(), XlsxVmlDrawing::default()));
+        };
+        let comments = XlsxComments::load(&mut self.zip, &path)?;
+
+        let mut vml_drawing = XlsxVmlDrawing::default();
+        for vml in relationships.vml_drawings() {
+            let Some(path) = zip_path_for_id(&worksheet_rels, &vml.id) else {
+                continue;
+            };
+            vml_drawing
+                .notes
+                .extend(XlsxVmlDrawing::load(&mut self.zip, &path)?.notes);
+        }
+
+        return Ok((comments, vml_drawing));
+    }
+
+    fn get_raw_threaded_comments(
+        &mut self,
+        worksheet_rels: XlsxRelationships,
+    ) -> anyhow::Result<XlsxThreadedComments> {
+        let mut threaded_comments = XlsxThreadedComments::default();
+        for part in WorksheetRelationships::new(worksheet_rels.clone()).threaded_comments() {
+            let Some(path) = zip_path_for_id(&worksheet_rels, &part.id) else {
+                continue;
+            };
+            threaded_comments
+                .comments
+                .extend(XlsxThreadedComments::load(&mut self.zip, &path)?.comments);
+        }
+        return Ok(threaded_comments);
+    }
+
     /// get
     /// - `XlsxWorksheetDrawing` parsed from xl/drawings/drawing{}.xml that defines all drawing objects within the worksheet
     /// - `Relationship` from the xl/drawings/_rels/drawing{}.xml.rel
@@ -375,7 +1840,6 @@ impl<RS: Read + Seek> Excel<RS> {
         return bytes;
     }
 
-    #[cfg(feature = "drawing")]
     fn get_bytes_for_path(&mut self, path: &str) -> anyhow::Result<Vec<u8>> {
         let zip = &mut self.zip;
         let path = get_actual_path(zip, path)
@@ -386,12 +1850,59 @@ impl<RS: Read + Seek> Excel<RS> {
         Ok(buf)
     }
 
+    /// Path of the first part related to the workbook with a relationship of a type, ie: xl/styles.xml for `styles`.
+    fn workbook_part_path(&self, r#type: &str, default_path: &str) -> String {
+        zip_path_for_type(&self.workbook_relationships, r#type)
+            .into_iter()
+            .next()
+            .map_or(default_path.to_string(), |(_, path)| path)
+    }
+
+    /// Parts a processed sheet is built from: the sheet, its relationships and their targets.
+    fn get_sheet_dependencies(&mut self, sheet: &SheetBasicInfo) -> Vec<String> {
+        let mut dependencies = vec![sheet.path.clone(), relationships_path(&sheet.path)];
+        let rels = self.get_raw_sheet_relationship(sheet).unwrap_or(vec![]);
+        dependencies.extend(
+            rels.iter()
+                .filter(|r| r.target_mode.as_deref() != Some(EXTERNAL_TARGET_MODE))
+                .map(|r| format_target_path(&r.target)),
+        );
+        dependencies
+    }
+
+    /// Path of the data model part, from the workbook relationships or any part under xl/model/.
+    fn get_data_model_path(&mut self) -> Option<String> {
+        if let Some((_, path)) = zip_path_for_type(&self.workbook_relationships, "powerPivotData")
+            .into_iter()
+            .next()
+        {
+            if let Some(path) = get_actual_path(&mut self.zip, &path) {
+                return Some(path);
+            }
+        }
+        let mut paths: Vec<&str> = self
+            .zip
+            .file_names()
+            .filter(|n| n.to_ascii_lowercase().starts_with("xl/model/"))
+            .collect();
+        paths.sort();
+        return paths.first().map(|p| p.to_string());
+    }
+
     fn get_sheet_with_name(&mut self, name: &str) -> anyhow::Result<SheetBasicInfo> {
         let sheets = self.get_sheets()?;
         let target: Vec<SheetBasicInfo> = sheets
             .into_iter()
-            .filter(|s| s.name.eq_ignore_ascii_case(name))
+            .filter(|s| sheet_names_equal(&s.name, name))
             .collect();
+        if target.len() > 1 {
+            let ids: Vec<String> = target.iter().map(|s| s.sheet_id.to_string()).collect();
+            bail!(
+                "Sheet name `{}` is used by multiple sheets (sheet ids: {}), get the sheet by id instead.",
+                name,
+                ids.join(", ")
+            )
+        }
         let Some(target) = target.first() else {
             bail!("Sheet with name: `{}` does not exist.", name)
         };
@@ -427,9 +1938,44 @@ impl<RS: Read + Seek> Excel<RS> {
     }
 }
 
+impl<RS: Read + Seek> Excel<RS> {
+    /// Reader over a part with the options the file is opened with, None if the part does not exist.
+    pub(crate) fn part_xml_reader(&mut self, path: &str) -> Option<XmlReader<'_, RS>> {
+        return xml_reader_with_options(&mut self.zip, path, &self.options);
+    }
+
+    pub(crate) fn is_binary(&self) -> bool {
+        return self.binary;
+    }
+
+    pub(crate) fn options(&self) -> ExcelOptions {
+        return self.options;
+    }
+}
+
+/// Signature of compound files, the container of encrypted workbooks and legacy xls workbooks.
+const COMPOUND_FILE_SIGNATURE: [u8; 8] = [0xD0, 0xCF, 0x11, 0xE0, 0xA1, 0xB1, 0x1A, 0xE1];
+
+/// Whether the reader holds a compound file, the position of the reader is left unchanged.
+fn is_compound_file<RS: Read + Seek>(reader: &mut RS) -> anyhow::Result<bool> {
+    let position = reader.stream_position()?;
+    let mut signature = [0u8; 8];
+    let is_compound_file = reader.read_exact(&mut signature).is_ok() && signature == COMPOUND_FILE_SIGNATURE;
+    reader.seek(SeekFrom::Start(position))?;
+    return Ok(is_compound_file);
+}
+
 pub(crate) fn xml_reader<'a, RS: Read + Seek>(
     zip: &'a mut ZipArchive<RS>,
     path: &str,
+) -> Option<XmlReader<'a, RS>> {
+    return xml_reader_with_options(zip, path, &ExcelOptions::default());
+}
+
+pub(crate) fn xml_reader_with_options<'a, RS: Read + Seek>(
+    zip: &'a mut ZipArchive<RS>,
+    path: &str,
+    options: &ExcelOptions,
 ) -> Option<XmlReader<'a, RS>> {
     let Some(path) = get_actual_path(zip, path) else {
         return None;
@@ -437,19 +1983,41 @@ pub(crate) fn xml_reader<'a, RS: Read + Seek>(
     let Ok(zip) = zip.by_name(&path) else {
         return None;
     };
-    let mut xml_reader = Reader::from_reader(BufReader::new(zip));
+    let mut xml_reader = Reader::from_reader(BufReader::with_capacity(options.zip_read_buffer_size, zip));
+    configure_xml_reader(xml_reader.config_mut());
+
+    return Some(xml_reader);
+}
+
+/// Reader over a part already decompressed, configured as `xml_reader_with_options` does.
+pub(crate) fn xml_reader_from_bytes(bytes: &[u8]) -> Reader<&[u8]> {
+    let mut xml_reader = Reader::from_reader(bytes);
+    configure_xml_reader(xml_reader.config_mut());
+
+    return xml_reader;
+}
 
-    let config = xml_reader.config_mut();
+fn configure_xml_reader(config: &mut Config) {
     config.allow_unmatched_ends = false; // default false
     config.check_comments = false; // default false
     config.check_end_names = false; // default true
     config.trim_text(false); // default false
     config.expand_empty_elements = true; // default false
+}
 
-    return Some(xml_reader);
+/// (crc32, uncompressed size) of every part, read from the central directory without decompressing.
+fn part_checksums<RS: Read + Seek>(
+    zip: &mut ZipArchive<RS>,
+) -> anyhow::Result<BTreeMap<String, (u32, u64)>> {
+    let mut checksums: BTreeMap<String, (u32, u64)> = BTreeMap::new();
+    for index in 0..zip.len() {
+        let file = zip.by_index_raw(index)?;
+        checksums.insert(file.name().to_string(), (file.crc32(), file.size()));
+    }
+    Ok(checksums)
 }
 
-fn get_actual_path<'a, RS: Read + Seek>(zip: &'a mut ZipArchive<RS>, path: &str) -> Option<String> {
+pub(crate) fn get_actual_path<'a, RS: Read + Seek>(zip: &'a mut ZipArchive<RS>, path: &str) -> Option<String> {
     return zip
         .file_names()
         .find(|n| n.eq_ignore_ascii_case(path))
@@ -0,0 +1,310 @@
+//! C bindings, enabled with the `ffi` feature, to read workbooks from C, C++ or any language with a C FFI.
+//!
+//! Declarations are in `include/excel_reader.h`. Built as a shared or static library with
+//! `cargo rustc --release --features ffi --crate-type cdylib` (or `staticlib`):
+//! ```c
+//! #include "excel_reader.h"
+//!
+//! ExcelReaderWorkbook *workbook = excel_reader_open("sample.xlsx");
+//! if (workbook == NULL) {
+//!     fprintf(stderr, "%s\n", excel_reader_last_error());
+//!     return 1;
+//! }
+//! ExcelReaderCells *cells = excel_reader_cells(workbook, "Sheet1");
+//! ExcelReaderCell cell;
+//! while (excel_reader_cells_next(cells, &cell)) {
+//!     printf("%llu:%llu %s\n", cell.row, cell.column, cell.value);
+//! }
+//! excel_reader_cells_free(cells);
+//! excel_reader_close(workbook);
+//! ```
+//!
+//! Functions failing return NULL or false, the reason being available from `excel_reader_last_error`.
+//! Strings are UTF-8 and nul terminated, those returned as `char *` are owned by the caller and freed with `excel_reader_string_free`.
+use anyhow::anyhow;
+use std::{
+    cell::RefCell,
+    ffi::{c_char, CStr, CString},
+    io::Cursor,
+    panic::{catch_unwind, AssertUnwindSafe},
+    ptr,
+};
+
+use crate::{
+    excel::{Excel, FileReader},
+    processed::spreadsheet::sheet::worksheet::{
+        cell::{cell_value::CellValueType, Cell},
+        Worksheet,
+    },
+    processed::spreadsheet::sheet_basic_info::{SheetBasicInfo, SheetType},
+};
+
+thread_local! {
+    static LAST_ERROR: RefCell<Option<CString>> = const { RefCell::new(None) };
+}
+
+/// A workbook opened with `excel_reader_open` or `excel_reader_open_bytes`, closed with `excel_reader_close`.
+pub struct ExcelReaderWorkbook {
+    excel: WorkbookSource,
+}
+
+enum WorkbookSource {
+    File(Excel<FileReader>),
+    Bytes(Excel<Cursor<Vec<u8>>>),
+}
+
+impl ExcelReaderWorkbook {
+    fn sheets(&mut self) -> anyhow::Result<Vec<SheetBasicInfo>> {
+        return match &mut self.excel {
+            WorkbookSource::File(excel) => excel.get_sheets(),
+            WorkbookSource::Bytes(excel) => excel.get_sheets(),
+        };
+    }
+
+    fn worksheet(&mut self, name: &str) -> anyhow::Result<Worksheet> {
+        return match &mut self.excel {
+            WorkbookSource::File(excel) => excel.get_worksheet_with_name(name),
+            WorkbookSource::Bytes(excel) => excel.get_worksheet_with_name(name),
+        };
+    }
+}
+
+/// Cells of a worksheet being iterated, from `excel_reader_cells`, freed with `excel_reader_cells_free`.
+pub struct ExcelReaderCells {
+    cells: std::vec::IntoIter<Cell>,
+    is_1904: bool,
+    /// strings of the cell last returned
+    value: CString,
+    json: CString,
+}
+
+/// A cell returned by `excel_reader_cells_next`.
+///
+/// Strings belong to the iterator and are valid until the next call on it.
+#[repr(C)]
+pub struct ExcelReaderCell {
+    /// 1 based row index
+    pub row: u64,
+    /// 1 based column index
+    pub column: u64,
+    /// value as displayed by Excel, with the number format of the cell applied
+    pub value: *const c_char,
+    /// the cell as JSON: typed value, formula, style
+    pub json: *const c_char,
+}
+
+/// Open a workbook from a path. NULL on failure.
+///
+/// # Safety
+/// path must be a nul terminated UTF-8 string.
+#[no_mangle]
+pub unsafe extern "C" fn excel_reader_open(path: *const c_char) -> *mut ExcelReaderWorkbook {
+    return guard(ptr::null_mut(), || {
+        let path = to_str(path)?;
+        let excel = Excel::from_path(path)?;
+        return Ok(Box::into_raw(Box::new(ExcelReaderWorkbook {
+            excel: WorkbookSource::File(excel),
+        })));
+    });
+}
+
+/// Open a workbook from the content of a file, copied. NULL on failure.
+///
+/// # Safety
+/// data must point to len readable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn excel_reader_open_bytes(
+    data: *const u8,
+    len: usize,
+) -> *mut ExcelReaderWorkbook {
+    return guard(ptr::null_mut(), || {
+        if data.is_null() {
+            return Err(anyhow!("data is NULL."));
+        }
+        let bytes = std::slice::from_raw_parts(data, len).to_vec();
+        let excel = Excel::from_bytes(bytes)?;
+        return Ok(Box::into_raw(Box::new(ExcelReaderWorkbook {
+            excel: WorkbookSource::Bytes(excel),
+        })));
+    });
+}
+
+/// Close a workbook. NULL is ignored.
+///
+/// # Safety
+/// workbook must come from `excel_reader_open` or `excel_reader_open_bytes`, and not be used afterwards.
+#[no_mangle]
+pub unsafe extern "C" fn excel_reader_close(workbook: *mut ExcelReaderWorkbook) {
+    if !workbook.is_null() {
+        drop(Box::from_raw(workbook));
+    }
+}
+
+/// Names of the worksheets as a JSON array of strings, in workbook order. NULL on failure.
+///
+/// # Safety
+/// workbook must be an open workbook.
+#[no_mangle]
+pub unsafe extern "C" fn excel_reader_sheet_names(
+    workbook: *mut ExcelReaderWorkbook,
+) -> *mut c_char {
+    return guard(ptr::null_mut(), || {
+        let workbook = to_workbook(workbook)?;
+        let names: Vec<String> = workbook
+            .sheets()?
+            .into_iter()
+            .filter(|s| s.r#type == SheetType::WorkSheet)
+            .map(|s| s.name)
+            .collect();
+        return Ok(to_c_string(serde_json::to_string(&names)?)?.into_raw());
+    });
+}
+
+/// Values of a worksheet as displayed by Excel, as a JSON array of rows of strings. NULL on failure.
+///
+/// Rows and columns start at the top left cell of the data of the worksheet, ie: `[["Name", "Price"], ["apple", "1.50"]]`.
+///
+/// # Safety
+/// workbook must be an open workbook, name a nul terminated UTF-8 string.
+#[no_mangle]
+pub unsafe extern "C" fn excel_reader_sheet_rows(
+    workbook: *mut ExcelReaderWorkbook,
+    name: *const c_char,
+) -> *mut c_char {
+    return guard(ptr::null_mut(), || {
+        let workbook = to_workbook(workbook)?;
+        let worksheet = workbook.worksheet(to_str(name)?)?;
+        let rows: Vec<Vec<String>> = match worksheet.dimension {
+            Some(dimension) => {
+                let width = (dimension.end.col - dimension.start.col + 1) as usize;
+                worksheet
+                    .get_cells()?
+                    .chunks(width)
+                    .map(|row| {
+                        row.iter()
+                            .map(|c| c.formatted_value(worksheet.is_1904))
+                            .collect()
+                    })
+                    .collect()
+            }
+            None => vec![],
+        };
+        return Ok(to_c_string(serde_json::to_string(&rows)?)?.into_raw());
+    });
+}
+
+/// Iterate the cells of a worksheet holding a value, row by row. NULL on failure.
+///
+/// # Safety
+/// workbook must be an open workbook, name a nul terminated UTF-8 string.
+#[no_mangle]
+pub unsafe extern "C" fn excel_reader_cells(
+    workbook: *mut ExcelReaderWorkbook,
+    name: *const c_char,
+) -> *mut ExcelReaderCells {
+    return guard(ptr::null_mut(), || {
+        let workbook = to_workbook(workbook)?;
+        let worksheet = workbook.worksheet(to_str(name)?)?;
+        let cells: Vec<Cell> = worksheet
+            .get_cells()?
+            .into_iter()
+            .filter(|c| c.value != CellValueType::Empty)
+            .collect();
+        return Ok(Box::into_raw(Box::new(ExcelReaderCells {
+            cells: cells.into_iter(),
+            is_1904: worksheet.is_1904,
+            value: CString::default(),
+            json: CString::default(),
+        })));
+    });
+}
+
+/// Write the next cell into cell. False at the end of the worksheet or on failure.
+///
+/// # Safety
+/// cells must come from `excel_reader_cells`, cell must point to a writable `ExcelReaderCell`.
+#[no_mangle]
+pub unsafe extern "C" fn excel_reader_cells_next(
+    cells: *mut ExcelReaderCells,
+    cell: *mut ExcelReaderCell,
+) -> bool {
+    return guard(false, || {
+        let (Some(cells), false) = (cells.as_mut(), cell.is_null()) else {
+            return Err(anyhow!("cells or cell is NULL."));
+        };
+        let Some(next) = cells.cells.next() else {
+            return Ok(false);
+        };
+        cells.value = to_c_string(next.formatted_value(cells.is_1904))?;
+        cells.json = to_c_string(serde_json::to_string(&next)?)?;
+        *cell = ExcelReaderCell {
+            row: next.coordinate.row,
+            column: next.coordinate.col,
+            value: cells.value.as_ptr(),
+            json: cells.json.as_ptr(),
+        };
+        return Ok(true);
+    });
+}
+
+/// Free an iterator of cells. NULL is ignored.
+///
+/// # Safety
+/// cells must come from `excel_reader_cells`, and not be used afterwards.
+#[no_mangle]
+pub unsafe extern "C" fn excel_reader_cells_free(cells: *mut ExcelReaderCells) {
+    if !cells.is_null() {
+        drop(Box::from_raw(cells));
+    }
+}
+
+/// Free a string returned by the library. NULL is ignored.
+///
+/// # Safety
+/// string must be returned as `char *` by a function of the library, and not be used afterwards.
+#[no_mangle]
+pub unsafe extern "C" fn excel_reader_string_free(string: *mut c_char) {
+    if !string.is_null() {
+        drop(CString::from_raw(string));
+    }
+}
+
+/// Message of the last failure on the calling thread, NULL if none.
+///
+/// Owned by the library and valid until the next failing call on the thread.
+#[no_mangle]
+pub extern "C" fn excel_reader_last_error() -> *const c_char {
+    return LAST_ERROR.with(|e| e.borrow().as_ref().map_or(ptr::null(), |e| e.as_ptr()));
+}
+
+/// Run f, recording its error or panic as the last error and returning failed instead.
+fn guard<T>(failed: T, f: impl FnOnce() -> anyhow::Result<T>) -> T {
+    let error = match catch_unwind(AssertUnwindSafe(f)) {
+        Ok(Ok(value)) => return value,
+        Ok(Err(error)) => format!("{:#}", error),
+        Err(_) => "The library panicked.".to_string(),
+    };
+    let error = CString::new(error.replace('\0', "")).unwrap_or_default();
+    LAST_ERROR.with(|e| *e.borrow_mut() = Some(error));
+    return failed;
+}
+
+unsafe fn to_str<'a>(string: *const c_char) -> anyhow::Result<&'a str> {
+    if string.is_null() {
+        return Err(anyhow!("string is NULL."));
+    }
+    return Ok(CStr::from_ptr(string).to_str()?);
+}
+
+unsafe fn to_workbook<'a>(
+    workbook: *mut ExcelReaderWorkbook,
+) -> anyhow::Result<&'a mut ExcelReaderWorkbook> {
+    return workbook
+        .as_mut()
+        .ok_or_else(|| anyhow!("workbook is NULL."));
+}
+
+/// Nul characters within cell values cannot be represented, they are removed.
+fn to_c_string(string: String) -> anyhow::Result<CString> {
+    return Ok(CString::new(string.replace('\0', ""))?);
+}
@@ -0,0 +1,235 @@
+//! Text width estimation for autofit calculations and column widths, without depending on the fonts installed on the system.
+//!
+//! Advance widths of the printable ASCII characters are embedded for Calibri and Arial, as well as for the fonts sharing their metrics
+//! (Carlito, Liberation Sans, Arimo, Helvetica). Other fonts are measured with the metrics of Calibri.
+//! East Asian wide characters take a full em, combining marks take no space.
+//!
+//! With the `fonts` feature, `FontFile` measures text with the advance widths of an actual TrueType or OpenType font.
+
+#[cfg(feature = "fonts")]
+use anyhow::bail;
+#[cfg(all(feature = "fonts", not(target_arch = "wasm32")))]
+use std::path::Path;
+
+use crate::{
+    processed::spreadsheet::sheet::worksheet::cell::cell_property::font::Font,
+    units::{pt_to_px, px_to_column_width, COLUMN_PADDING, DEFAULT_DPI},
+};
+
+/// Advance widths of a font in 1/1000 em.
+struct EmbeddedMetrics {
+    /// characters from ` ` (0x20) to `~` (0x7E)
+    ascii: [u16; 95],
+
+    /// Width of the bold face relative to the regular face, for fonts without bold metrics.
+    bold_scale: f64,
+}
+
+/// Calibri, converted from 2048 units per em.
+static CALIBRI: EmbeddedMetrics = EmbeddedMetrics {
+    ascii: [
+        226, 266, 401, 507, 507, 717, 683, 221, 303, 303, 507, 507, 250, 306, 252, 386, //
+        507, 507, 507, 507, 507, 507, 507, 507, 507, 507, 268, 268, 507, 507, 507, 459, //
+        890, 579, 544, 533, 615, 488, 459, 631, 623, 252, 319, 520, 420, 855, 646, 662, //
+        517, 673, 543, 459, 487, 642, 567, 890, 519, 487, 468, 306, 386, 306, 507, 499, //
+        282, 479, 525, 423, 525, 498, 305, 471, 525, 229, 239, 455, 229, 799, 525, 527, //
+        525, 525, 349, 391, 335, 525, 452, 715, 433, 453, 395, 312, 459, 312, 507,
+    ],
+    bold_scale: 1.05,
+};
+
+static ARIAL: EmbeddedMetrics = EmbeddedMetrics {
+    ascii: [
+        278, 278, 355, 556, 556, 889, 667, 191, 333, 333, 389, 584, 278, 333, 278, 278, //
+        556, 556, 556, 556, 556, 556, 556, 556, 556, 556, 278, 278, 584, 584, 584, 556, //
+        1015, 667, 667, 722, 722, 667, 611, 778, 722, 278, 500, 667, 556, 833, 722, 778, //
+        667, 778, 722, 667, 611, 722, 667, 944, 667, 667, 611, 278, 278, 278, 469, 556, //
+        333, 556, 556, 500, 556, 556, 278, 556, 556, 222, 222, 500, 222, 833, 556, 556, //
+        556, 556, 333, 500, 278, 556, 500, 722, 500, 500, 500, 334, 260, 334, 584,
+    ],
+    bold_scale: 1.0,
+};
+
+static ARIAL_BOLD: EmbeddedMetrics = EmbeddedMetrics {
+    ascii: [
+        278, 333, 474, 556, 556, 889, 722, 238, 333, 333, 389, 584, 278, 333, 278, 278, //
+        556, 556, 556, 556, 556, 556, 556, 556, 556, 556, 333, 333, 584, 584, 584, 611, //
+        975, 722, 722, 722, 722, 667, 611, 778, 722, 278, 556, 722, 611, 833, 722, 778, //
+        667, 778, 722, 667, 611, 722, 667, 944, 667, 667, 611, 333, 278, 333, 584, 556, //
+        333, 556, 611, 556, 611, 556, 333, 611, 611, 278, 278, 556, 278, 889, 611, 611, //
+        611, 611, 389, 556, 333, 611, 556, 778, 556, 556, 500, 389, 280, 389, 584,
+    ],
+    bold_scale: 1.0,
+};
+
+/// Estimate the width in points of a text rendered with a font, from the embedded metrics.
+///
+/// Multiline text is as wide as its longest line.
+/// Kerning, hinting and the rendering of the application are not taken into account, expect a few percent of difference.
+pub fn estimate_text_width(text: &str, font: &Font) -> f64 {
+    let (metrics, bold_scale) = embedded_metrics(font);
+    return text_width(text, font.size, |c| {
+        estimated_advance(c, metrics) * bold_scale
+    });
+}
+
+/// Estimate the maximum digit width in pixels of a font, from the embedded metrics.
+///
+/// Unlike `units::max_digit_width`, the font name is taken into account, ie: 7 pixels for Calibri 11 pt and Arial 10 pt.
+pub fn estimate_max_digit_width(font: &Font) -> f64 {
+    let (metrics, _) = embedded_metrics(font);
+    let digit_width = ('0'..='9')
+        .map(|digit| estimated_advance(digit, metrics))
+        .fold(0.0, f64::max);
+    return (pt_to_px(font.size, DEFAULT_DPI) * digit_width).round();
+}
+
+/// Estimate the width of a column, as stored in the file, fitting every text without wrapping.
+///
+/// * font: the font of the texts, usually the default font of the workbook (`Excel::get_default_font`)
+pub fn estimate_column_width<'a>(texts: impl IntoIterator<Item = &'a str>, font: &Font) -> f64 {
+    let text_px = texts
+        .into_iter()
+        .map(|text| pt_to_px(estimate_text_width(text, font), DEFAULT_DPI).ceil())
+        .fold(0.0, f64::max);
+    return px_to_column_width(text_px + COLUMN_PADDING, estimate_max_digit_width(font));
+}
+
+/// A TrueType or OpenType font file to measure text with its own advance widths.
+///
+/// Characters the font has no glyph for are estimated with the embedded metrics of Calibri.
+#[cfg(feature = "fonts")]
+#[derive(Debug, Clone)]
+pub struct FontFile {
+    data: Vec<u8>,
+    index: u32,
+}
+
+#[cfg(feature = "fonts")]
+impl FontFile {
+    /// Load a `.ttf` or `.otf` file, or the first font of a `.ttc` collection.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn from_path<P: AsRef<Path>>(path: P) -> anyhow::Result<Self> {
+        return Self::from_bytes(std::fs::read(path)?, 0);
+    }
+
+    /// * index: index of the font within a font collection, 0 otherwise
+    pub fn from_bytes(data: Vec<u8>, index: u32) -> anyhow::Result<Self> {
+        if let Err(error) = ttf_parser::Face::parse(&data, index) {
+            bail!("Invalid font file: {}.", error);
+        }
+        return Ok(Self { data, index });
+    }
+
+    /// Family name of the font, ie: `Calibri`.
+    pub fn family_name(&self) -> Option<String> {
+        let face = self.face();
+        face.names()
+            .into_iter()
+            .filter(|name| name.name_id == ttf_parser::name_id::FAMILY && name.is_unicode())
+            .find_map(|name| name.to_string())
+    }
+
+    /// Width in points of a text rendered with the font at a size in points.
+    ///
+    /// Multiline text is as wide as its longest line. Kerning is not applied.
+    pub fn text_width(&self, text: &str, size: f64) -> f64 {
+        let face = self.face();
+        let units_per_em = face.units_per_em() as f64;
+        return text_width(text, size, |c| {
+            match face
+                .glyph_index(c)
+                .and_then(|glyph| face.glyph_hor_advance(glyph))
+            {
+                Some(advance) => advance as f64 / units_per_em,
+                None => estimated_advance(c, &CALIBRI),
+            }
+        });
+    }
+
+    /// Maximum digit width in pixels of the font at a size in points.
+    pub fn max_digit_width(&self, size: f64) -> f64 {
+        let digit_width = ('0'..='9')
+            .map(|digit| self.text_width(&digit.to_string(), size))
+            .fold(0.0, f64::max);
+        return pt_to_px(digit_width, DEFAULT_DPI).round();
+    }
+
+    fn face(&self) -> ttf_parser::Face<'_> {
+        // validated when loading
+        ttf_parser::Face::parse(&self.data, self.index).expect("font validated when loaded")
+    }
+}
+
+/// Metrics of a font and the scale applied for its bold face.
+fn embedded_metrics(font: &Font) -> (&'static EmbeddedMetrics, f64) {
+    let name = font.name.trim().to_lowercase();
+    let arial_like = [
+        "arial",
+        "helvetica",
+        "helvetica neue",
+        "liberation sans",
+        "arimo",
+    ]
+    .contains(&name.as_str());
+
+    if arial_like {
+        return if font.bold {
+            (&ARIAL_BOLD, 1.0)
+        } else {
+            (&ARIAL, 1.0)
+        };
+    }
+    return if font.bold {
+        (&CALIBRI, CALIBRI.bold_scale)
+    } else {
+        (&CALIBRI, 1.0)
+    };
+}
+
+/// Advance width of a character in em.
+fn estimated_advance(c: char, metrics: &EmbeddedMetrics) -> f64 {
+    let code = c as u32;
+    if (0x20..=0x7E).contains(&code) {
+        return metrics.ascii[(code - 0x20) as usize] as f64 / 1000.0;
+    }
+    if c.is_control() || is_combining_mark(code) {
+        return 0.0;
+    }
+    if is_wide(code) {
+        return 1.0;
+    }
+    // other letters, approximated with the width of `n`
+    return metrics.ascii[('n' as u32 - 0x20) as usize] as f64 / 1000.0;
+}
+
+/// Width in points of the longest line, from the advance widths of the characters in em.
+fn text_width(text: &str, size: f64, advance: impl Fn(char) -> f64) -> f64 {
+    return text
+        .lines()
+        .map(|line| line.chars().map(&advance).sum::<f64>() * size)
+        .fold(0.0, f64::max);
+}
+
+fn is_combining_mark(code: u32) -> bool {
+    return matches!(code, 0x0300..=0x036F | 0x1AB0..=0x1AFF | 0x1DC0..=0x1DFF | 0x20D0..=0x20FF | 0xFE20..=0xFE2F);
+}
+
+/// East Asian wide and fullwidth characters: Hangul, CJK, Hiragana, Katakana and fullwidth forms.
+fn is_wide(code: u32) -> bool {
+    return matches!(
+        code,
+        0x1100..=0x115F
+            | 0x2E80..=0x303E
+            | 0x3041..=0x33FF
+            | 0x3400..=0x4DBF
+            | 0x4E00..=0x9FFF
+            | 0xA000..=0xA4CF
+            | 0xAC00..=0xD7A3
+            | 0xF900..=0xFAFF
+            | 0xFE30..=0xFE4F
+            | 0xFF00..=0xFF60
+            | 0xFFE0..=0xFFE6
+            | 0x20000..=0x3FFFD
+    );
+}
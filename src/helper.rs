@@ -1,10 +1,17 @@
-use std::io::Read;
+use std::io::BufRead;
 
 use anyhow::bail;
-use quick_xml::events::{BytesStart, Event};
+use quick_xml::{
+    events::{BytesStart, Event},
+    Reader,
+};
 use regex::Regex;
 
-use crate::{excel::XmlReader, raw::drawing::st_types::st_percentage_to_float};
+use crate::{
+    common_types::{MAX_COLUMN_COUNT, MAX_ROW_COUNT},
+    raw::drawing::st_types::st_percentage_to_float,
+    units::inch_to_pt,
+};
 
 /// Converting Attributes string to boolean
 pub(crate) fn string_to_bool(str: &str) -> Option<bool> {
@@ -78,7 +85,7 @@ pub(crate) fn extract_val_attribute(e: &BytesStart) -> anyhow::Result<Option<Str
 }
 
 pub(crate) fn extract_text_contents(
-    reader: &mut XmlReader<impl Read>,
+    reader: &mut Reader<impl BufRead>,
     tag: &[u8],
 ) -> anyhow::Result<String> {
     let mut text = String::new();
@@ -104,6 +111,8 @@ pub(crate) fn extract_text_contents(
 ///
 /// - top left (row, column),
 /// - bottom right (row, column)
+///
+/// Whole columns, ie: `A:C`, span all the rows of a worksheet, and whole rows, ie: `3:7`, all its columns.
 pub(crate) fn a1_dimension_to_row_col(
     a1_dimension: &[u8],
 ) -> anyhow::Result<((u64, u64), (u64, u64))> {
@@ -111,14 +120,20 @@ pub(crate) fn a1_dimension_to_row_col(
     if parts.len() != 2 {
         bail!("Invalid reference dimension.")
     }
-    let (Some(start_row), Some(start_col)) = a1_address_to_row_col(parts[0])? else {
-        bail!("Invalid reference start address.")
-    };
-    let (Some(end_row), Some(end_col)) = a1_address_to_row_col(parts[1])? else {
-        bail!("Invalid reference end address.")
+    return match (a1_address_to_row_col(parts[0])?, a1_address_to_row_col(parts[1])?) {
+        ((Some(start_row), Some(start_col)), (Some(end_row), Some(end_col))) => {
+            Ok(((start_row, start_col), (end_row, end_col)))
+        }
+        // whole columns, ie: A:C
+        ((None, Some(start_col)), (None, Some(end_col))) => {
+            Ok(((1, start_col), (MAX_ROW_COUNT, end_col)))
+        }
+        // whole rows, ie: 3:7
+        ((Some(start_row), None), (Some(end_row), None)) => {
+            Ok(((start_row, 1), (end_row, MAX_COLUMN_COUNT)))
+        }
+        _ => bail!("Invalid reference address."),
     };
-
-    Ok(((start_row, start_col), (end_row, end_col)))
 }
 
 /// Convert R1C1 reference dimension to (row, col) (1 based index).
@@ -168,8 +183,8 @@ pub(crate) fn a1_address_to_row_col(
         match *c {
             c @ b'0'..=b'9' => {
                 if reading_row {
-                    row += ((c - b'0') as u64) * power;
-                    power *= 10;
+                    row = checked_digit_add(row, (c - b'0') as u64, power)?;
+                    power = power.saturating_mul(10);
                 } else {
                     bail!("Cell address contains numeric column.")
                 }
@@ -179,16 +194,16 @@ pub(crate) fn a1_address_to_row_col(
                     power = 1;
                     reading_row = false;
                 }
-                col += ((c - b'A') as u64 + 1) * power;
-                power *= 26;
+                col = checked_digit_add(col, (c - b'A') as u64 + 1, power)?;
+                power = power.saturating_mul(26);
             }
             c @ b'a'..=b'z' => {
                 if reading_row {
                     power = 1;
                     reading_row = false;
                 }
-                col += ((c - b'a') as u64 + 1) * power;
-                power *= 26;
+                col = checked_digit_add(col, (c - b'a') as u64 + 1, power)?;
+                power = power.saturating_mul(26);
             }
             _ => bail!("Cell address is not alphaNumeric."),
         }
@@ -199,6 +214,47 @@ pub(crate) fn a1_address_to_row_col(
     Ok((row, col))
 }
 
+/// `total + digit * power`, failing instead of overflowing on oversized addresses.
+fn checked_digit_add(total: u64, digit: u64, power: u64) -> anyhow::Result<u64> {
+    let Some(total) = digit
+        .checked_mul(power)
+        .and_then(|value| total.checked_add(value))
+    else {
+        bail!("Cell address out of range.")
+    };
+    Ok(total)
+}
+
+/// Length with its unit (mm, cm, in, pt or pc) in points, ie: `210mm`, `0.178in`.
+pub(crate) fn length_to_pt(length: &str) -> Option<f64> {
+    let length = length.trim();
+    let split = length.find(|c: char| !(c.is_ascii_digit() || c == '.' || c == '-'))?;
+    let (value, unit) = length.split_at(split);
+    let value: f64 = value.parse().ok()?;
+    match unit.trim() {
+        "mm" => Some(inch_to_pt(value / 25.4)),
+        "cm" => Some(inch_to_pt(value / 2.54)),
+        "in" => Some(inch_to_pt(value)),
+        "pt" => Some(value),
+        "pc" => Some(value * 12.0),
+        _ => None,
+    }
+}
+
+/// Convert column (1 based index) to letters.
+/// 1 -> A, 27 -> AA
+pub(crate) fn col_to_letters(col: u64) -> String {
+    let mut letters: Vec<u8> = vec![];
+    let mut num = col;
+    while num > 0 {
+        let remainder = (num - 1) % 26;
+        letters.push(b'A' + remainder as u8);
+        num = (num - 1) / 26;
+    }
+    letters.reverse();
+    String::from_utf8(letters).unwrap_or_default()
+}
+
 /// Format hex string to RGBA hex string, ie: #960d52ff
 pub(crate) fn format_hex_string(hex: &str, alpha_first: Option<bool>) -> anyhow::Result<String> {
     let mut s = hex;
@@ -208,16 +264,19 @@ pub(crate) fn format_hex_string(hex: &str, alpha_first: Option<bool>) -> anyhow:
     if s.len() != 6 && s.len() != 8 {
         bail!("invalid hex.")
     }
+    if !s.bytes().all(|b| b.is_ascii_hexdigit()) {
+        bail!("invalid hex.")
+    }
     let alpha_first = alpha_first.unwrap_or(false);
     if !alpha_first && s.len() == 8 {
-        return Ok(format!("#{}", hex.to_ascii_lowercase()));
+        return Ok(format!("#{}", s.to_ascii_lowercase()));
     }
 
     if s.len() == 6 {
-        return Ok(format!("#{}{}", hex.to_ascii_lowercase(), "ff"));
+        return Ok(format!("#{}{}", s.to_ascii_lowercase(), "ff"));
     }
-    let alpha_hex = &hex[..=1];
-    let rgb_hex = &hex[2..];
+    let alpha_hex = &s[..=1];
+    let rgb_hex = &s[2..];
 
     return Ok(format!("#{}{}", rgb_hex, alpha_hex));
 }
@@ -1,6 +1,27 @@
+#[cfg(feature = "async")]
+pub mod async_excel;
+#[cfg(all(feature = "cli", not(target_arch = "wasm32")))]
+pub mod cli;
 pub mod common_types;
+#[cfg(feature = "dates")]
+pub mod dates;
+// serial numbers of typed cell values are converted to dates without the feature as well
+#[cfg(not(feature = "dates"))]
+pub(crate) mod dates;
 pub mod excel;
+// C bindings open files by path, not available in the browser
+#[cfg(all(feature = "ffi", not(target_arch = "wasm32")))]
+pub mod ffi;
+pub mod font_metrics;
 pub mod helper;
+#[cfg(feature = "ods")]
+pub mod ods;
 pub mod packaging;
+pub mod prelude;
 pub mod processed;
 pub mod raw;
+pub mod units;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+#[cfg(feature = "xls")]
+pub mod xls;
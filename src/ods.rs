@@ -0,0 +1,187 @@
+//! OpenDocument spreadsheets (.ods), enabled with the `ods` feature.
+//!
+//! See `raw::ods` for the parts that are parsed.
+use anyhow::bail;
+use quick_xml::events::Event;
+use std::{
+    io::{Read, Seek},
+    sync::Arc,
+};
+#[cfg(not(target_arch = "wasm32"))]
+use std::{fs::File, io::BufReader, path::Path};
+use zip::ZipArchive;
+
+use crate::{
+    excel::xml_reader,
+    processed::spreadsheet::{
+        sheet::worksheet::Worksheet,
+        sheet_basic_info::{SheetBasicInfo, SheetType, SheetVisibleState},
+        sheet_name::sheet_names_equal,
+    },
+    raw::{
+        ods::{
+            content::{load_ods_content, OdsTable},
+            styles::OdsStyles,
+        },
+        spreadsheet::{
+            shared_string::shared_string_table::SharedStrings, sheet::worksheet::XlsxWorksheet,
+            stylesheet::XlsxStyleSheet,
+        },
+    },
+};
+
+/// Media type stored in the `mimetype` file of ods packages.
+const ODS_MIME_TYPE: &str = "application/vnd.oasis.opendocument.spreadsheet";
+
+/// An OpenDocument spreadsheet (.ods).
+///
+/// `content.xml` and `styles.xml` are parsed when the file is opened.
+pub struct Ods {
+    tables: Vec<OdsTable>,
+    stylesheet: XlsxStyleSheet,
+}
+
+impl Ods {
+    /// Open an ods file.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn from_path<P: AsRef<Path>>(path: P) -> anyhow::Result<Ods> {
+        let file = File::open(path)?;
+        return Self::from_reader(BufReader::new(file));
+    }
+
+    /// Read an ods file from a reader.
+    pub fn from_reader<RS: Read + Seek>(reader: RS) -> anyhow::Result<Ods> {
+        let mut zip = ZipArchive::new(reader)?;
+
+        let mut mime_type = String::new();
+        if let Ok(mut file) = zip.by_name("mimetype") {
+            file.read_to_string(&mut mime_type)?;
+        }
+        if mime_type.trim() != ODS_MIME_TYPE {
+            bail!("Not an ods file: mimetype `{}`.", mime_type.trim())
+        }
+
+        let mut styles = OdsStyles::default();
+        if let Some(mut reader) = xml_reader(&mut zip, "styles.xml") {
+            let mut buf = Vec::new();
+            loop {
+                buf.clear();
+
+                match reader.read_event_into(&mut buf) {
+                    Ok(Event::Start(ref e)) if e.name().as_ref() == b"office:styles" => {
+                        styles.load(&mut reader, b"office:styles")?;
+                    }
+                    Ok(Event::Eof) => break,
+                    Err(e) => bail!(e.to_string()),
+                    _ => (),
+                }
+            }
+        }
+
+        let Some(mut reader) = xml_reader(&mut zip, "content.xml") else {
+            bail!("Not an ods file: content.xml does not exist.")
+        };
+        let tables = load_ods_content(&mut reader, &mut styles)?;
+        let stylesheet = styles.stylesheet();
+
+        return Ok(Self { tables, stylesheet });
+    }
+
+    /// Get raw stylesheet: number formats, fonts, fills, borders and cell formats of the styles used by the cells.
+    pub fn get_raw_stylesheet(&self) -> XlsxStyleSheet {
+        return self.stylesheet.clone();
+    }
+
+    /// Get raw worksheet
+    pub fn get_raw_worksheet(&self, sheet: &SheetBasicInfo) -> anyhow::Result<XlsxWorksheet> {
+        let table = self.get_table(sheet)?;
+        return Ok(table.worksheet.clone());
+    }
+
+    /// Get a list of sheets in the workbook.
+    ///
+    /// Sheet ids are the 1-based positions of the tables.
+    pub fn get_sheets(&self) -> anyhow::Result<Vec<SheetBasicInfo>> {
+        return Ok(self
+            .tables
+            .iter()
+            .enumerate()
+            .map(|(index, table)| SheetBasicInfo {
+                r_id: String::new(),
+                name: table.name.clone(),
+                sheet_id: index as u64 + 1,
+                visible_state: if table.hidden {
+                    SheetVisibleState::Hidden
+                } else {
+                    SheetVisibleState::Visible
+                },
+                r#type: SheetType::WorkSheet,
+                path: format!("content.xml#{}", index),
+            })
+            .collect());
+    }
+
+    /// Get worksheet (processed)
+    ///
+    /// name: Worksheet name
+    pub fn get_worksheet_with_name(&self, name: &str) -> anyhow::Result<Worksheet> {
+        let sheets = self.get_sheets()?;
+        let Some(sheet) = sheets.iter().find(|s| sheet_names_equal(&s.name, name)) else {
+            bail!("Sheet with name: `{}` does not exist.", name)
+        };
+        return self.get_worksheet(sheet);
+    }
+
+    /// Get worksheet (processed)
+    ///
+    /// id: Worksheet sheet id
+    pub fn get_worksheet_with_sheet_id(&self, id: &u64) -> anyhow::Result<Worksheet> {
+        let sheets = self.get_sheets()?;
+        let Some(sheet) = sheets.iter().find(|s| s.sheet_id.eq(id)) else {
+            bail!("Worksheet with id: `{}` does not exist.", id)
+        };
+        return self.get_worksheet(sheet);
+    }
+
+    /// Get worksheet (processed)
+    ///
+    /// Tables, data validations, drawings and other parts that are not parsed from ods files are empty.
+    pub fn get_worksheet(&self, sheet: &SheetBasicInfo) -> anyhow::Result<Worksheet> {
+        let raw_worksheet = self.get_raw_worksheet(sheet)?;
+
+        let worksheet = Worksheet::from_raw(
+            sheet.name.clone(),
+            sheet.sheet_id,
+            sheet.path.clone(),
+            Box::new(raw_worksheet),
+            Box::default(),
+            Box::default(),
+            Box::default(),
+            Box::default(),
+            Box::default(),
+            Box::default(),
+            Box::default(),
+            false,
+            None,
+            SharedStrings::default(),
+            Arc::new(self.stylesheet.clone()),
+            None,
+            #[cfg(feature = "drawing")]
+            Box::default(),
+            #[cfg(feature = "drawing")]
+            None,
+            #[cfg(feature = "drawing")]
+            Box::default(),
+        );
+
+        Ok(worksheet)
+    }
+
+    fn get_table(&self, sheet: &SheetBasicInfo) -> anyhow::Result<&OdsTable> {
+        let index = sheet.sheet_id.checked_sub(1).map(|i| i as usize);
+        let Some(table) = index.and_then(|i| self.tables.get(i)) else {
+            bail!("Worksheet with id: `{}` does not exist.", sheet.sheet_id)
+        };
+        return Ok(table);
+    }
+}
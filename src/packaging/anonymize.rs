@@ -0,0 +1,382 @@
+use quick_xml::{
+    events::{BytesStart, BytesText, Event},
+    Reader, Writer,
+};
+use std::{
+    collections::hash_map::RandomState,
+    hash::BuildHasher,
+    io::{Read, Seek, Write},
+};
+use zip::{write::SimpleFileOptions, CompressionMethod, ZipArchive, ZipWriter};
+
+use crate::processed::spreadsheet::sheet::worksheet::row_hash::hash_values;
+
+/// How texts are replaced by `Excel::anonymize`, numbers have their digits scrambled either way.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AnonymizeMethod {
+    /// Letters and digits are replaced by random ones of the same kind, keeping case, punctuation, spaces and lengths,
+    /// ie: `Jane Doe, 12 Main St.` becomes `Qvre Xta, 58 Kpuo Ub.`
+    Scramble,
+
+    /// Texts are replaced by 16 hexadecimal digits of their hash, hiding their length as well.
+    Hash,
+}
+
+/// Options of `Excel::anonymize`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AnonymizeOptions {
+    pub method: AnonymizeMethod,
+
+    /// Seed of the replacements: the same value is replaced the same way within a workbook, and across workbooks with the same seed.
+    ///
+    /// Default to a random seed, so that replaced values cannot be matched against candidates hashed with a known seed.
+    pub seed: u64,
+
+    /// Replace the text and authors of comments and threaded comments, and the names of their persons, as well. Default to true.
+    pub comments: bool,
+}
+
+impl Default for AnonymizeOptions {
+    fn default() -> Self {
+        Self {
+            method: AnonymizeMethod::Scramble,
+            seed: RandomState::new().hash_one("seed"),
+            comments: true,
+        }
+    }
+}
+
+/// Part and how its values are replaced.
+pub(crate) enum AnonymizedPart {
+    /// Values of `<c>` and the string literals of their formulas, styles and cell types are kept.
+    Worksheet,
+
+    /// Text of the elements with the local names, ie: `t` of the shared strings.
+    Text(&'static [&'static [u8]]),
+
+    /// Attributes of the elements with the local names: (element, attribute, kind of value).
+    Attributes(&'static [(&'static [u8], &'static [u8], AnonymizedValue)]),
+}
+
+/// How an attribute value is replaced, see `AnonymizedPart::Attributes`.
+#[derive(Clone, Copy)]
+pub(crate) enum AnonymizedValue {
+    Text,
+    Number,
+}
+
+/// Copy the package into the writer, replacing the values of the parts given.
+///
+/// Other parts are copied without being decompressed.
+pub(crate) fn anonymize_package<RS: Read + Seek, W: Write + Seek>(
+    zip: &mut ZipArchive<RS>,
+    part_kind: impl Fn(&str) -> Option<AnonymizedPart>,
+    writer: W,
+    options: &AnonymizeOptions,
+) -> anyhow::Result<W> {
+    let mut writer = ZipWriter::new(writer);
+    for index in 0..zip.len() {
+        let name = zip.name_for_index(index).unwrap_or_default().to_string();
+        let Some(kind) = part_kind(&name) else {
+            writer.raw_copy_file(zip.by_index_raw(index)?)?;
+            continue;
+        };
+
+        let mut content = Vec::new();
+        zip.by_index(index)?.read_to_end(&mut content)?;
+        let content = match kind {
+            AnonymizedPart::Worksheet => anonymize_worksheet(&content, options)?,
+            AnonymizedPart::Text(elements) => anonymize_text_elements(&content, elements, options)?,
+            AnonymizedPart::Attributes(attributes) => {
+                anonymize_attributes(&content, attributes, options)?
+            }
+        };
+        writer.start_file(
+            name,
+            SimpleFileOptions::default().compression_method(CompressionMethod::Deflated),
+        )?;
+        writer.write_all(&content)?;
+    }
+    return Ok(writer.finish()?);
+}
+
+fn anonymize_text_elements(
+    content: &[u8],
+    elements: &[&[u8]],
+    options: &AnonymizeOptions,
+) -> anyhow::Result<Vec<u8>> {
+    let mut reader = Reader::from_reader(content);
+    let mut writer = Writer::new(Vec::with_capacity(content.len()));
+    let mut depth_in_element: usize = 0;
+    loop {
+        match reader.read_event()? {
+            Event::Start(e) => {
+                if elements.contains(&e.local_name().as_ref()) || depth_in_element > 0 {
+                    depth_in_element += 1;
+                }
+                writer.write_event(Event::Start(e))?;
+            }
+            Event::End(e) => {
+                depth_in_element = depth_in_element.saturating_sub(1);
+                writer.write_event(Event::End(e))?;
+            }
+            Event::Text(e) if depth_in_element > 0 => {
+                write_text(&mut writer, &anonymize_text(&e.unescape()?, options))?;
+            }
+            Event::CData(e) if depth_in_element > 0 => {
+                let text = String::from_utf8_lossy(&e).to_string();
+                write_text(&mut writer, &anonymize_text(&text, options))?;
+            }
+            Event::Eof => break,
+            e => writer.write_event(e)?,
+        }
+    }
+    return Ok(writer.into_inner());
+}
+
+fn anonymize_attributes(
+    content: &[u8],
+    attributes: &[(&[u8], &[u8], AnonymizedValue)],
+    options: &AnonymizeOptions,
+) -> anyhow::Result<Vec<u8>> {
+    let mut reader = Reader::from_reader(content);
+    let mut writer = Writer::new(Vec::with_capacity(content.len()));
+    loop {
+        match reader.read_event()? {
+            Event::Start(e) => {
+                writer.write_event(Event::Start(replace_attributes(&e, attributes, options)?))?
+            }
+            Event::Empty(e) => {
+                writer.write_event(Event::Empty(replace_attributes(&e, attributes, options)?))?
+            }
+            Event::Eof => break,
+            e => writer.write_event(e)?,
+        }
+    }
+    return Ok(writer.into_inner());
+}
+
+fn replace_attributes<'a>(
+    e: &BytesStart<'a>,
+    attributes: &[(&[u8], &[u8], AnonymizedValue)],
+    options: &AnonymizeOptions,
+) -> anyhow::Result<BytesStart<'a>> {
+    let element = e.local_name();
+    if !attributes
+        .iter()
+        .any(|(name, _, _)| *name == element.as_ref())
+    {
+        return Ok(e.clone());
+    }
+    let mut replaced = e.clone();
+    replaced.clear_attributes();
+    for attribute in e.attributes() {
+        let attribute = attribute?;
+        let kind = attributes
+            .iter()
+            .find(|(name, key, _)| {
+                *name == element.as_ref() && *key == attribute.key.local_name().as_ref()
+            })
+            .map(|(_, _, kind)| *kind);
+        let Some(kind) = kind else {
+            replaced.push_attribute(attribute);
+            continue;
+        };
+        let value = quick_xml::escape::unescape(std::str::from_utf8(&attribute.value)?)?;
+        let value = match kind {
+            AnonymizedValue::Text => anonymize_text(&value, options),
+            AnonymizedValue::Number => scramble_number(&value, options.seed),
+        };
+        replaced.push_attribute((std::str::from_utf8(attribute.key.as_ref())?, value.as_str()));
+    }
+    return Ok(replaced);
+}
+
+/// Value of the cell being read in a worksheet.
+#[derive(PartialEq)]
+enum CellValue {
+    /// `<v>` of a number, or of the cached result of a formula when it is a number.
+    Number,
+    /// `<v>` of the cached text result of a formula, or `<t>` of an inline string.
+    Text,
+    /// Index of a shared string, boolean, error or ISO 8601 date, left as is.
+    Kept,
+    /// `<f>`, whose string literals are replaced.
+    Formula,
+}
+
+fn anonymize_worksheet(content: &[u8], options: &AnonymizeOptions) -> anyhow::Result<Vec<u8>> {
+    let mut reader = Reader::from_reader(content);
+    let mut writer = Writer::new(Vec::with_capacity(content.len()));
+    let mut cell_type: Option<Vec<u8>> = None;
+    let mut value: Option<CellValue> = None;
+    loop {
+        match reader.read_event()? {
+            Event::Start(e) => {
+                match e.local_name().as_ref() {
+                    b"c" => cell_type = cell_type_of(&e)?,
+                    b"v" => {
+                        value = Some(match cell_type.as_deref() {
+                            None | Some(b"n") => CellValue::Number,
+                            Some(b"str") => CellValue::Text,
+                            _ => CellValue::Kept,
+                        })
+                    }
+                    b"t" if cell_type.as_deref() == Some(b"inlineStr") => {
+                        value = Some(CellValue::Text)
+                    }
+                    b"f" => value = Some(CellValue::Formula),
+                    _ => (),
+                }
+                writer.write_event(Event::Start(e))?;
+            }
+            Event::End(e) => {
+                match e.local_name().as_ref() {
+                    b"c" => cell_type = None,
+                    b"v" | b"t" | b"f" => value = None,
+                    _ => (),
+                }
+                writer.write_event(Event::End(e))?;
+            }
+            Event::Text(e)
+                if matches!(
+                    value,
+                    Some(CellValue::Number | CellValue::Text | CellValue::Formula)
+                ) =>
+            {
+                let text = e.unescape()?;
+                let replaced = match value {
+                    Some(CellValue::Number) => scramble_number(&text, options.seed),
+                    Some(CellValue::Formula) => anonymize_string_literals(&text, options),
+                    _ => anonymize_text(&text, options),
+                };
+                write_text(&mut writer, &replaced)?;
+            }
+            Event::Eof => break,
+            e => writer.write_event(e)?,
+        }
+    }
+    return Ok(writer.into_inner());
+}
+
+fn cell_type_of(e: &BytesStart) -> anyhow::Result<Option<Vec<u8>>> {
+    for attribute in e.attributes() {
+        let attribute = attribute?;
+        if attribute.key.local_name().as_ref() == b"t" {
+            return Ok(Some(attribute.value.to_vec()));
+        }
+    }
+    return Ok(None);
+}
+
+fn write_text(writer: &mut Writer<Vec<u8>>, text: &str) -> anyhow::Result<()> {
+    writer.write_event(Event::Text(BytesText::new(text)))?;
+    return Ok(());
+}
+
+fn anonymize_text(text: &str, options: &AnonymizeOptions) -> String {
+    if text.is_empty() {
+        return String::new();
+    }
+    return match options.method {
+        AnonymizeMethod::Scramble => scramble_text(text, options.seed),
+        AnonymizeMethod::Hash => format!("{:016x}", seeded_hash(text, options.seed)),
+    };
+}
+
+/// String literals of a formula replaced as the texts of cells, so that a formula comparing a cell to a literal still matches,
+/// ie: `IF(A1="Jane","yes","")`. Sheet names, quoted with `'`, are kept.
+fn anonymize_string_literals(formula: &str, options: &AnonymizeOptions) -> String {
+    let mut replaced = String::with_capacity(formula.len());
+    let mut rest = formula;
+    while let Some(start) = rest.find('"') {
+        replaced.push_str(&rest[..=start]);
+        rest = &rest[start + 1..];
+        // quotes within the literal are doubled
+        let mut literal = String::new();
+        loop {
+            match rest.find('"') {
+                Some(end) if rest[end + 1..].starts_with('"') => {
+                    literal.push_str(&rest[..=end]);
+                    rest = &rest[end + 2..];
+                }
+                Some(end) => {
+                    literal.push_str(&rest[..end]);
+                    rest = &rest[end..];
+                    break;
+                }
+                None => {
+                    literal.push_str(rest);
+                    rest = "";
+                    break;
+                }
+            }
+        }
+        replaced.push_str(&anonymize_text(&literal, options).replace('"', "\"\""));
+        if let Some(after) = rest.strip_prefix('"') {
+            replaced.push('"');
+            rest = after;
+        }
+    }
+    replaced.push_str(rest);
+    return replaced;
+}
+
+/// Letters other than ascii ones are replaced by ascii lowercase letters.
+fn scramble_text(text: &str, seed: u64) -> String {
+    let mut random = Random(seeded_hash(text, seed));
+    return text
+        .chars()
+        .map(|c| match c {
+            'A'..='Z' => random.char_in(b'A', 26),
+            '0'..='9' => random.char_in(b'0', 10),
+            c if c.is_alphabetic() => random.char_in(b'a', 26),
+            c => c,
+        })
+        .collect();
+}
+
+/// Digits of the mantissa are scrambled, keeping leading zeros and the first significant digit above zero,
+/// so that the number keeps its sign, magnitude and decimal places, ie: `1234.5` becomes `8071.3`.
+fn scramble_number(text: &str, seed: u64) -> String {
+    let mut random = Random(seeded_hash(text, seed));
+    let mut significant = false;
+    let mut exponent = false;
+    return text
+        .chars()
+        .map(|c| match c {
+            'e' | 'E' => {
+                exponent = true;
+                c
+            }
+            '0' if !significant => c,
+            '1'..='9' if !significant && !exponent => {
+                significant = true;
+                random.char_in(b'1', 9)
+            }
+            '0'..='9' if !exponent => random.char_in(b'0', 10),
+            c => c,
+        })
+        .collect();
+}
+
+fn seeded_hash(text: &str, seed: u64) -> u64 {
+    return hash_values(&[(0, seed.to_string()), (b's', text.to_string())]);
+}
+
+/// SplitMix64, seeded with the hash of the replaced value.
+struct Random(u64);
+
+impl Random {
+    fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        return z ^ (z >> 31);
+    }
+
+    fn char_in(&mut self, first: u8, count: u64) -> char {
+        return (first + (self.next() % count) as u8) as char;
+    }
+}
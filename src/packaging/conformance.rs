@@ -0,0 +1,461 @@
+use anyhow::bail;
+use quick_xml::{
+    events::{BytesStart, Event},
+    name::PrefixDeclaration,
+};
+use std::{
+    fmt,
+    io::{Read, Seek},
+};
+use zip::ZipArchive;
+
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+use crate::{common_types::Coordinate, excel::xml_reader};
+
+/// A place where a part does not follow the SpreadsheetML schema, found by `Excel::check_conformance`.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct ConformanceViolation {
+    /// Part the violation is found in, ie: `xl/worksheets/sheet1.xml`.
+    pub part: String,
+
+    /// Local names of the element and its ancestors, ie: `worksheet/sheetData/row`.
+    pub element: String,
+
+    /// Byte offset within the (decompressed) part after the start tag of the element,
+    /// or after its end tag for missing child elements.
+    pub position: u64,
+
+    pub kind: ConformanceViolationKind,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum ConformanceViolationKind {
+    /// A required attribute is not set.
+    MissingAttribute { attribute: String },
+
+    /// A required child element is not present.
+    MissingElement { child: String },
+
+    /// The element appears after a sibling that the schema places after it.
+    OutOfOrder { after: String },
+
+    /// Row index is not greater than the index of the preceding row.
+    UnorderedRow { row: u64, previous_row: u64 },
+
+    /// Cell is not to the right of the preceding cell of the row.
+    UnorderedCell {
+        coordinate: Coordinate,
+        previous_coordinate: Coordinate,
+    },
+}
+
+impl fmt::Display for ConformanceViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} `{}` (offset {}): ", self.part, self.element, self.position)?;
+        match &self.kind {
+            ConformanceViolationKind::MissingAttribute { attribute } => {
+                write!(f, "missing required attribute `{}`", attribute)
+            }
+            ConformanceViolationKind::MissingElement { child } => {
+                write!(f, "missing required child element `{}`", child)
+            }
+            ConformanceViolationKind::OutOfOrder { after } => {
+                write!(f, "must come before its sibling `{}`", after)
+            }
+            ConformanceViolationKind::UnorderedRow { row, previous_row } => {
+                write!(f, "row {} follows row {}", row, previous_row)
+            }
+            ConformanceViolationKind::UnorderedCell {
+                coordinate,
+                previous_coordinate,
+            } => write!(
+                f,
+                "cell {} follows cell {}",
+                coordinate.to_a1(),
+                previous_coordinate.to_a1()
+            ),
+        }
+    }
+}
+
+/// Schema constraints of an element, identified by local name.
+struct ElementRule {
+    name: &'static str,
+    /// Child elements in the order of the schema sequence. Children not listed are not checked.
+    children: &'static [&'static str],
+    required_children: &'static [&'static str],
+    /// Local names, ie: `id` for `r:id`
+    required_attributes: &'static [&'static str],
+}
+
+const fn rule(
+    name: &'static str,
+    children: &'static [&'static str],
+    required_children: &'static [&'static str],
+    required_attributes: &'static [&'static str],
+) -> ElementRule {
+    ElementRule {
+        name,
+        children,
+        required_children,
+        required_attributes,
+    }
+}
+
+/// Constraints of the elements of workbook.xml, worksheets, styles.xml and sharedStrings.xml (ECMA-376 Part 1, §18).
+static RULES: &[ElementRule] = &[
+    // workbook
+    rule(
+        "workbook",
+        &[
+            "fileVersion", "fileSharing", "workbookPr", "workbookProtection", "bookViews",
+            "sheets", "functionGroups", "externalReferences", "definedNames", "calcPr",
+            "oleSize", "customWorkbookViews", "pivotCaches", "smartTagPr", "smartTagTypes",
+            "webPublishing", "fileRecoveryPr", "webPublishObjects", "extLst",
+        ],
+        &["sheets"],
+        &[],
+    ),
+    rule("sheets", &["sheet"], &["sheet"], &[]),
+    rule("sheet", &[], &[], &["name", "sheetId", "id"]),
+    rule("definedNames", &["definedName"], &[], &[]),
+    rule("definedName", &[], &[], &["name"]),
+    rule("pivotCaches", &["pivotCache"], &["pivotCache"], &[]),
+    rule("pivotCache", &[], &[], &["cacheId", "id"]),
+    // worksheet
+    rule(
+        "worksheet",
+        &[
+            "sheetPr", "dimension", "sheetViews", "sheetFormatPr", "cols", "sheetData",
+            "sheetCalcPr", "sheetProtection", "protectedRanges", "scenarios", "autoFilter",
+            "sortState", "dataConsolidate", "customSheetViews", "mergeCells", "phoneticPr",
+            "conditionalFormatting", "dataValidations", "hyperlinks", "printOptions",
+            "pageMargins", "pageSetup", "headerFooter", "rowBreaks", "colBreaks",
+            "customProperties", "cellWatches", "ignoredErrors", "smartTags", "drawing",
+            "legacyDrawing", "legacyDrawingHF", "drawingHF", "picture", "oleObjects",
+            "controls", "webPublishItems", "tableParts", "extLst",
+        ],
+        &["sheetData"],
+        &[],
+    ),
+    rule("sheetPr", &["tabColor", "outlinePr", "pageSetUpPr"], &[], &[]),
+    rule("dimension", &[], &[], &["ref"]),
+    rule("sheetViews", &["sheetView", "extLst"], &["sheetView"], &[]),
+    rule(
+        "sheetView",
+        &["pane", "selection", "pivotSelection", "extLst"],
+        &[],
+        &["workbookViewId"],
+    ),
+    rule("sheetFormatPr", &[], &[], &["defaultRowHeight"]),
+    rule("cols", &["col"], &["col"], &[]),
+    rule("col", &[], &[], &["min", "max"]),
+    rule("sheetData", &["row"], &[], &[]),
+    rule("row", &["c", "extLst"], &[], &[]),
+    rule("c", &["f", "v", "is", "extLst"], &[], &[]),
+    rule("mergeCells", &["mergeCell"], &["mergeCell"], &[]),
+    rule("mergeCell", &[], &[], &["ref"]),
+    rule("conditionalFormatting", &["cfRule", "extLst"], &["cfRule"], &[]),
+    rule("cfRule", &[], &[], &["priority"]),
+    rule("dataValidations", &["dataValidation"], &["dataValidation"], &[]),
+    rule("dataValidation", &["formula1", "formula2"], &[], &["sqref"]),
+    rule("hyperlinks", &["hyperlink"], &["hyperlink"], &[]),
+    rule("hyperlink", &[], &[], &["ref"]),
+    rule(
+        "pageMargins",
+        &[],
+        &[],
+        &["left", "right", "top", "bottom", "header", "footer"],
+    ),
+    rule(
+        "headerFooter",
+        &["oddHeader", "oddFooter", "evenHeader", "evenFooter", "firstHeader", "firstFooter"],
+        &[],
+        &[],
+    ),
+    rule("ignoredErrors", &["ignoredError", "extLst"], &["ignoredError"], &[]),
+    rule("ignoredError", &[], &[], &["sqref"]),
+    rule("drawing", &[], &[], &["id"]),
+    rule("legacyDrawing", &[], &[], &["id"]),
+    rule("tableParts", &["tablePart"], &[], &[]),
+    rule("tablePart", &[], &[], &["id"]),
+    // styles
+    rule(
+        "styleSheet",
+        &[
+            "numFmts", "fonts", "fills", "borders", "cellStyleXfs", "cellXfs", "cellStyles",
+            "dxfs", "tableStyles", "colors", "extLst",
+        ],
+        &[],
+        &[],
+    ),
+    rule("numFmts", &["numFmt"], &[], &[]),
+    rule("numFmt", &[], &[], &["numFmtId", "formatCode"]),
+    rule("fonts", &["font"], &[], &[]),
+    rule("fills", &["fill"], &[], &[]),
+    rule("borders", &["border"], &[], &[]),
+    rule(
+        "border",
+        &["start", "end", "left", "right", "top", "bottom", "diagonal", "vertical", "horizontal"],
+        &[],
+        &[],
+    ),
+    rule("cellStyleXfs", &["xf"], &["xf"], &[]),
+    rule("cellXfs", &["xf"], &["xf"], &[]),
+    rule("xf", &["alignment", "protection", "extLst"], &[], &[]),
+    rule("cellStyles", &["cellStyle"], &["cellStyle"], &[]),
+    rule("cellStyle", &[], &[], &["xfId"]),
+    rule("dxfs", &["dxf"], &[], &[]),
+    rule(
+        "dxf",
+        &["font", "numFmt", "fill", "alignment", "border", "protection", "extLst"],
+        &[],
+        &[],
+    ),
+    // shared strings
+    rule("sst", &["si", "extLst"], &[], &[]),
+    rule("si", &["t", "r", "rPh", "phoneticPr"], &[], &[]),
+    rule("r", &["rPr", "t"], &[], &[]),
+];
+
+/// Namespaces of the elements the rules apply to, transitional and strict.
+const SPREADSHEETML_NAMESPACES: [&[u8]; 2] = [
+    b"http://schemas.openxmlformats.org/spreadsheetml/2006/main",
+    b"http://purl.oclc.org/ooxml/spreadsheetml/main",
+];
+
+/// Elements whose content belongs to other schemas, ie: the x14 rules of an `extLst`, and is not checked.
+const EXTENSION_ELEMENTS: [&str; 2] = ["extLst", "AlternateContent"];
+
+/// An open element while walking a part.
+struct Frame {
+    name: String,
+    /// Prefixes declared on the element, `""` for the default namespace
+    namespaces: Vec<(Vec<u8>, Vec<u8>)>,
+    /// Element of another schema, or within one: neither it nor its content is checked
+    foreign: bool,
+    rule: Option<&'static ElementRule>,
+    /// Schema index and name of the last child checked for order
+    last_child: Option<(usize, String)>,
+    children: Vec<String>,
+    /// sheetData: index of the last row, row: coordinate of the last cell
+    last_row: Option<u64>,
+    last_cell: Option<Coordinate>,
+}
+
+/// Check a part against the element order, required elements and required attributes of the schema,
+/// and that rows and cells of worksheets are in ascending order.
+///
+/// Nothing is reported for a part that does not exist.
+pub(crate) fn check_part_conformance(
+    zip: &mut ZipArchive<impl Read + Seek>,
+    part: &str,
+) -> anyhow::Result<Vec<ConformanceViolation>> {
+    let Some(mut reader) = xml_reader(zip, part) else {
+        return Ok(vec![]);
+    };
+
+    let mut violations: Vec<ConformanceViolation> = vec![];
+    let mut stack: Vec<Frame> = vec![];
+    let mut buf = Vec::new();
+
+    loop {
+        buf.clear();
+
+        match reader.read_event_into(&mut buf) {
+            Ok(Event::Start(ref e)) => {
+                let name = String::from_utf8_lossy(e.local_name().as_ref()).to_string();
+                let namespaces = namespace_declarations(e)?;
+                let foreign = match stack.last() {
+                    Some(parent) if parent.foreign || EXTENSION_ELEMENTS.contains(&parent.name.as_str()) => true,
+                    _ => !is_spreadsheetml(e, &namespaces, &stack),
+                };
+                if foreign {
+                    stack.push(Frame {
+                        name,
+                        namespaces,
+                        foreign,
+                        rule: None,
+                        last_child: None,
+                        children: vec![],
+                        last_row: None,
+                        last_cell: None,
+                    });
+                    continue;
+                }
+                let position = reader.buffer_position();
+                let path = element_path(&stack, &name);
+                let mut report = |kind: ConformanceViolationKind| {
+                    violations.push(ConformanceViolation {
+                        part: part.to_string(),
+                        element: path.clone(),
+                        position,
+                        kind,
+                    })
+                };
+
+                if let Some(parent) = stack.last_mut() {
+                    parent.children.push(name.clone());
+                    if let Some(index) = parent
+                        .rule
+                        .and_then(|r| r.children.iter().position(|c| *c == name))
+                    {
+                        match &parent.last_child {
+                            Some((last_index, last_name)) if index < *last_index => {
+                                report(ConformanceViolationKind::OutOfOrder {
+                                    after: last_name.clone(),
+                                });
+                            }
+                            _ => parent.last_child = Some((index, name.clone())),
+                        }
+                    }
+                    check_order(parent, &name, e, &mut report)?;
+                }
+
+                let rule = RULES.iter().find(|r| r.name == name);
+                if let Some(rule) = rule {
+                    for attribute in rule.required_attributes {
+                        if !has_attribute(e, attribute)? {
+                            report(ConformanceViolationKind::MissingAttribute {
+                                attribute: attribute.to_string(),
+                            });
+                        }
+                    }
+                }
+
+                stack.push(Frame {
+                    name,
+                    namespaces,
+                    foreign,
+                    rule,
+                    last_child: None,
+                    children: vec![],
+                    last_row: None,
+                    last_cell: None,
+                });
+            }
+            Ok(Event::End(_)) => {
+                let path = element_path(&stack, "");
+                let Some(frame) = stack.pop() else {
+                    continue;
+                };
+                let Some(rule) = frame.rule else {
+                    continue;
+                };
+                for child in rule.required_children {
+                    if !frame.children.iter().any(|c| c == child) {
+                        violations.push(ConformanceViolation {
+                            part: part.to_string(),
+                            element: path.trim_end_matches('/').to_string(),
+                            position: reader.buffer_position(),
+                            kind: ConformanceViolationKind::MissingElement {
+                                child: child.to_string(),
+                            },
+                        });
+                    }
+                }
+            }
+            Ok(Event::Eof) => break,
+            Err(e) => bail!(e.to_string()),
+            _ => (),
+        }
+    }
+
+    Ok(violations)
+}
+
+/// Rows within sheetData and cells within a row are to be written in ascending order.
+fn check_order(
+    parent: &mut Frame,
+    name: &str,
+    e: &BytesStart,
+    report: &mut impl FnMut(ConformanceViolationKind),
+) -> anyhow::Result<()> {
+    match (parent.name.as_str(), name) {
+        ("sheetData", "row") => {
+            let Some(row) = attribute_value(e, "r")?.and_then(|r| r.parse::<u64>().ok()) else {
+                return Ok(());
+            };
+            if let Some(previous_row) = parent.last_row.filter(|previous| row <= *previous) {
+                report(ConformanceViolationKind::UnorderedRow { row, previous_row });
+            }
+            parent.last_row = Some(row);
+        }
+        ("row", "c") => {
+            let Some(coordinate) =
+                attribute_value(e, "r")?.and_then(|r| Coordinate::from_a1(r.as_bytes()))
+            else {
+                return Ok(());
+            };
+            if let Some(previous_coordinate) = parent.last_cell.filter(|previous| coordinate <= *previous) {
+                report(ConformanceViolationKind::UnorderedCell {
+                    coordinate,
+                    previous_coordinate,
+                });
+            }
+            parent.last_cell = Some(coordinate);
+        }
+        _ => (),
+    }
+    Ok(())
+}
+
+/// Prefixes bound by the `xmlns` attributes of an element.
+fn namespace_declarations(e: &BytesStart) -> anyhow::Result<Vec<(Vec<u8>, Vec<u8>)>> {
+    let mut namespaces = vec![];
+    for a in e.attributes() {
+        let a = a?;
+        match a.key.as_namespace_binding() {
+            Some(PrefixDeclaration::Default) => namespaces.push((vec![], a.value.to_vec())),
+            Some(PrefixDeclaration::Named(prefix)) => namespaces.push((prefix.to_vec(), a.value.to_vec())),
+            None => (),
+        }
+    }
+    Ok(namespaces)
+}
+
+/// Whether the element is in the SpreadsheetML namespace, elements without any namespace declared included.
+fn is_spreadsheetml(e: &BytesStart, namespaces: &[(Vec<u8>, Vec<u8>)], stack: &[Frame]) -> bool {
+    let name = e.name();
+    let prefix = name.prefix().map_or(&[][..], |p| p.into_inner());
+    let namespace = namespaces
+        .iter()
+        .chain(stack.iter().rev().flat_map(|f| f.namespaces.iter()))
+        .find(|(declared, _)| declared == prefix)
+        .map(|(_, namespace)| namespace.as_slice());
+    match namespace {
+        Some(namespace) => SPREADSHEETML_NAMESPACES.contains(&namespace),
+        None => prefix.is_empty(),
+    }
+}
+
+fn element_path(stack: &[Frame], name: &str) -> String {
+    let mut names: Vec<&str> = stack.iter().map(|f| f.name.as_str()).collect();
+    names.push(name);
+    names.join("/")
+}
+
+fn has_attribute(e: &BytesStart, local_name: &str) -> anyhow::Result<bool> {
+    Ok(attribute_value(e, local_name)?.is_some())
+}
+
+fn attribute_value(e: &BytesStart, local_name: &str) -> anyhow::Result<Option<String>> {
+    for a in e.attributes() {
+        match a {
+            Ok(a) => {
+                if a.key.local_name().as_ref() == local_name.as_bytes() {
+                    return Ok(Some(String::from_utf8(a.value.to_vec())?));
+                }
+            }
+            Err(error) => {
+                bail!(error.to_string())
+            }
+        }
+    }
+    Ok(None)
+}
@@ -0,0 +1,99 @@
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+use super::relationship::{rel_for_id, XlsxRelationships, EXTERNAL_TARGET_MODE};
+use crate::raw::spreadsheet::workbook::sheet::XlsxSheet;
+
+/// Folders holding sheet parts that are expected to be referenced from xl/_rels/workbook.xml.rels
+static SHEET_PART_FOLDERS: &[&str] = &[
+    "xl/worksheets/",
+    "xl/chartsheets/",
+    "xl/dialogsheets/",
+    "xl/macrosheets/",
+];
+
+/// A problem found between xl/workbook.xml, xl/_rels/workbook.xml.rels and the parts within the package.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum RelationshipIssue {
+    /// `<sheet>` in workbook.xml without `r:id`.
+    SheetWithoutRelationshipId { sheet_name: String },
+
+    /// `<sheet r:id>` does not match any relationship.
+    MissingSheetRelationship { sheet_name: String, r_id: String },
+
+    /// Relationship target does not exist within the package.
+    MissingPart { r_id: String, path: String },
+
+    /// Sheet part within the package that no relationship refers to.
+    OrphanedSheetPart { path: String },
+}
+
+/// Check that sheets and workbook relationships resolve to existing parts.
+///
+/// part_names: names of all files within the package.
+pub(crate) fn check_workbook_relationships(
+    sheets: &[XlsxSheet],
+    relationships: &XlsxRelationships,
+    part_names: &[String],
+) -> Vec<RelationshipIssue> {
+    let mut issues: Vec<RelationshipIssue> = vec![];
+    let exists = |path: &str| part_names.iter().any(|n| n.eq_ignore_ascii_case(path));
+
+    for sheet in sheets {
+        let sheet_name = sheet.name.clone().unwrap_or_default();
+        let Some(r_id) = sheet.id.clone() else {
+            issues.push(RelationshipIssue::SheetWithoutRelationshipId { sheet_name });
+            continue;
+        };
+        if rel_for_id(relationships, &r_id).is_none() {
+            issues.push(RelationshipIssue::MissingSheetRelationship { sheet_name, r_id });
+        }
+    }
+
+    let mut referenced: Vec<String> = vec![];
+    for relationship in relationships {
+        if relationship.target_mode.as_deref() == Some(EXTERNAL_TARGET_MODE) {
+            continue;
+        }
+        let path = resolve_workbook_target(&relationship.target);
+        if !exists(&path) {
+            issues.push(RelationshipIssue::MissingPart {
+                r_id: relationship.id.clone(),
+                path: path.clone(),
+            });
+        }
+        referenced.push(path);
+    }
+
+    for name in part_names {
+        let is_sheet_part = SHEET_PART_FOLDERS.iter().any(|f| name.starts_with(f))
+            && name.ends_with(".xml")
+            && !name.contains("/_rels/");
+        if is_sheet_part && !referenced.iter().any(|r| r.eq_ignore_ascii_case(name)) {
+            issues.push(RelationshipIssue::OrphanedSheetPart { path: name.clone() });
+        }
+    }
+
+    issues
+}
+
+/// Path within the package of a target relative to xl/workbook.xml, ie: `../customXml/item1.xml` -> `customXml/item1.xml`.
+fn resolve_workbook_target(target: &str) -> String {
+    let full = match target.strip_prefix('/') {
+        Some(absolute) => absolute.to_string(),
+        None => format!("xl/{}", target),
+    };
+    let mut segments: Vec<&str> = vec![];
+    for segment in full.split('/') {
+        match segment {
+            "" | "." => {}
+            ".." => {
+                segments.pop();
+            }
+            s => segments.push(s),
+        }
+    }
+    segments.join("/")
+}
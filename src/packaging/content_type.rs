@@ -0,0 +1,203 @@
+use anyhow::bail;
+use quick_xml::events::{BytesStart, Event};
+use std::io::{Read, Seek};
+use zip::ZipArchive;
+
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+use crate::excel::xml_reader;
+
+/// Content types of the package parts parsed from [Content_Types].xml
+///
+/// Example
+/// ```xml
+/// <Types xmlns="http://schemas.openxmlformats.org/package/2006/content-types">
+///   <Default Extension="rels" ContentType="application/vnd.openxmlformats-package.relationships+xml" />
+///   <Default Extension="xml" ContentType="application/xml" />
+///   <Override PartName="/xl/workbook.xml" ContentType="application/vnd.openxmlformats-officedocument.spreadsheetml.sheet.main+xml" />
+/// </Types>
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct XlsxContentTypes {
+    /// Default: content type by file extension
+    pub defaults: Vec<XlsxDefaultContentType>,
+
+    /// Override: content type of a specific part
+    pub overrides: Vec<XlsxOverrideContentType>,
+}
+
+impl XlsxContentTypes {
+    pub(crate) fn load(zip: &mut ZipArchive<impl Read + Seek>) -> anyhow::Result<Self> {
+        let path = "[Content_Types].xml";
+        let Some(mut reader) = xml_reader(zip, path) else {
+            bail!("Failed to get content types.");
+        };
+
+        let mut buf = Vec::new();
+        let mut content_types = Self::default();
+
+        loop {
+            buf.clear();
+
+            match reader.read_event_into(&mut buf) {
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"Default" => {
+                    let (Some(extension), Some(content_type)) = load_attributes(e, b"Extension")?
+                    else {
+                        continue;
+                    };
+                    content_types.defaults.push(XlsxDefaultContentType {
+                        extension,
+                        content_type,
+                    });
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"Override" => {
+                    let (Some(part_name), Some(content_type)) = load_attributes(e, b"PartName")?
+                    else {
+                        continue;
+                    };
+                    content_types.overrides.push(XlsxOverrideContentType {
+                        part_name,
+                        content_type,
+                    });
+                }
+                Ok(Event::End(ref e)) if e.local_name().as_ref() == b"Types" => break,
+                Ok(Event::Eof) => break,
+                Err(e) => bail!(e.to_string()),
+                _ => (),
+            }
+        }
+
+        Ok(content_types)
+    }
+
+    /// Content type of a part, ie: `xl/workbook.xml`.
+    ///
+    /// Overrides take precedence over the default for the extension. Part names are compared case insensitively.
+    pub fn for_part(&self, path: &str) -> Option<String> {
+        let path = path.trim_start_matches('/');
+        if let Some(o) = self.overrides.iter().find(|o| {
+            o.part_name
+                .trim_start_matches('/')
+                .eq_ignore_ascii_case(path)
+        }) {
+            return Some(o.content_type.clone());
+        }
+
+        let (_, extension) = path.rsplit_once('.')?;
+        return self
+            .defaults
+            .iter()
+            .find(|d| d.extension.eq_ignore_ascii_case(extension))
+            .map(|d| d.content_type.clone());
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct XlsxDefaultContentType {
+    /// Extension
+    pub extension: String,
+    /// ContentType
+    pub content_type: String,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct XlsxOverrideContentType {
+    /// PartName, ie: `/xl/workbook.xml`
+    pub part_name: String,
+    /// ContentType
+    pub content_type: String,
+}
+
+/// key attribute (Extension or PartName) and ContentType
+fn load_attributes(e: &BytesStart, key: &[u8]) -> anyhow::Result<(Option<String>, Option<String>)> {
+    let mut value: Option<String> = None;
+    let mut content_type: Option<String> = None;
+
+    for a in e.attributes() {
+        match a {
+            Ok(a) => {
+                let string_value = String::from_utf8(a.value.to_vec())?;
+                match a.key.local_name().as_ref() {
+                    b"ContentType" => content_type = Some(string_value),
+                    k if k == key => value = Some(string_value),
+                    _ => (),
+                }
+            }
+            Err(error) => {
+                bail!(error.to_string())
+            }
+        }
+    }
+
+    Ok((value, content_type))
+}
+
+/// Kind of document declared by the content type of the main part (xl/workbook.xml or xl/workbook.bin).
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum WorkbookContentType {
+    /// .xlsx
+    Workbook,
+    /// .xlsm
+    MacroEnabledWorkbook,
+    /// .xltx
+    Template,
+    /// .xltm
+    MacroEnabledTemplate,
+    /// .xlam
+    AddIn,
+    /// .xlsb
+    BinaryWorkbook,
+    /// Any other declared content type.
+    Other(String),
+}
+
+impl WorkbookContentType {
+    pub(crate) fn from_content_type(content_type: &str) -> Self {
+        match content_type {
+            "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet.main+xml" => {
+                Self::Workbook
+            }
+            "application/vnd.ms-excel.sheet.macroEnabled.main+xml" => Self::MacroEnabledWorkbook,
+            "application/vnd.openxmlformats-officedocument.spreadsheetml.template.main+xml" => {
+                Self::Template
+            }
+            "application/vnd.ms-excel.template.macroEnabled.main+xml" => Self::MacroEnabledTemplate,
+            "application/vnd.ms-excel.addin.macroEnabled.main+xml" => Self::AddIn,
+            "application/vnd.ms-excel.sheet.binary.macroEnabled.main" => Self::BinaryWorkbook,
+            other => Self::Other(other.to_string()),
+        }
+    }
+
+    /// MIME type of the file as a whole, ie: `application/vnd.ms-excel.sheet.macroEnabled.12` for a macro enabled workbook.
+    ///
+    /// None for unrecognized content types.
+    pub fn mime_type(&self) -> Option<&'static str> {
+        match self {
+            Self::Workbook => {
+                Some("application/vnd.openxmlformats-officedocument.spreadsheetml.sheet")
+            }
+            Self::MacroEnabledWorkbook => Some("application/vnd.ms-excel.sheet.macroEnabled.12"),
+            Self::Template => {
+                Some("application/vnd.openxmlformats-officedocument.spreadsheetml.template")
+            }
+            Self::MacroEnabledTemplate => Some("application/vnd.ms-excel.template.macroEnabled.12"),
+            Self::AddIn => Some("application/vnd.ms-excel.addin.macroEnabled.12"),
+            Self::BinaryWorkbook => Some("application/vnd.ms-excel.sheet.binary.macroEnabled.12"),
+            Self::Other(_) => None,
+        }
+    }
+
+    /// Whether the declared content type allows macros (VBA or XLM).
+    pub fn is_macro_enabled(&self) -> bool {
+        matches!(
+            self,
+            Self::MacroEnabledWorkbook
+                | Self::MacroEnabledTemplate
+                | Self::AddIn
+                | Self::BinaryWorkbook
+        )
+    }
+}
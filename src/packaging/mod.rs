@@ -1 +1,6 @@
+pub mod anonymize;
+pub mod conformance;
+pub mod consistency;
+pub mod content_type;
 pub mod relationship;
+pub mod unsupported_feature;
@@ -3,7 +3,10 @@ use quick_xml::events::{BytesStart, Event};
 use std::io::{Read, Seek};
 use zip::ZipArchive;
 
-use crate::excel::xml_reader;
+use crate::{
+    excel::xml_reader,
+    raw::binary::{is_binary_workbook, BINARY_WORKBOOK_PATH},
+};
 
 pub(crate) const EXTERNAL_TARGET_MODE: &str = "External";
 
@@ -22,8 +25,8 @@ pub type XlsxRelationships = Vec<XlsxRelationship>;
 pub(crate) fn load_workbook_relationships(
     zip: &mut ZipArchive<impl Read + Seek>,
 ) -> anyhow::Result<XlsxRelationships> {
-    let path = "xl/_rels/workbook.xml.rels";
-    let Some(mut reader) = xml_reader(zip, path) else {
+    let path = relationships_path(&load_workbook_path(zip));
+    let Some(mut reader) = xml_reader(zip, &path) else {
         bail!("Failed to get relationships.");
     };
 
@@ -51,6 +54,40 @@ pub(crate) fn load_workbook_relationships(
     Ok(relationships)
 }
 
+/// Path of the workbook part, from the `officeDocument` relationship of the package (`_rels/.rels`).
+///
+/// Falls back to `xl/workbook.xml`, or `xl/workbook.bin` for binary workbooks, if the package relationships do not tell.
+pub(crate) fn load_workbook_path(zip: &mut ZipArchive<impl Read + Seek>) -> String {
+    let default_path = match is_binary_workbook(zip) {
+        true => BINARY_WORKBOOK_PATH,
+        false => "xl/workbook.xml",
+    };
+    let Some(mut reader) = xml_reader(zip, "_rels/.rels") else {
+        return default_path.to_string();
+    };
+
+    let mut buf = Vec::new();
+    loop {
+        buf.clear();
+
+        match reader.read_event_into(&mut buf) {
+            Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"Relationship" => {
+                let Ok(Some(rel)) = XlsxRelationship::load(e) else {
+                    continue;
+                };
+                // transitional and strict relationship types
+                if rel.r#type.ends_with("/officeDocument") {
+                    return rel.target.trim_start_matches('/').to_string();
+                }
+            }
+            Ok(Event::Eof) | Err(_) => break,
+            _ => (),
+        }
+    }
+
+    return default_path.to_string();
+}
+
 /// get relationships of a specific sheet within a workbook
 pub(crate) fn load_sheet_relationships(
     zip: &mut ZipArchive<impl Read + Seek>,
@@ -199,7 +236,83 @@ impl XlsxRelationship {
     }
 }
 
+/// Relationships of a worksheet (xl/worksheets/_rels/sheet{}.xml.rels) with typed accessors for the dependent parts.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WorksheetRelationships {
+    pub relationships: XlsxRelationships,
+}
+
+impl WorksheetRelationships {
+    pub(crate) fn new(relationships: XlsxRelationships) -> Self {
+        Self { relationships }
+    }
+
+    /// Relationships whose type ends with the given name, ie: `drawing` for `.../relationships/drawing`.
+    ///
+    /// Matching is on the last segment of the type, so `drawing` does not match `vmlDrawing`.
+    pub fn of_type(&self, name: &str) -> Vec<XlsxRelationship> {
+        self.relationships
+            .iter()
+            .filter(|r| {
+                r.r#type
+                    .rsplit('/')
+                    .next()
+                    .is_some_and(|t| t.eq_ignore_ascii_case(name))
+            })
+            .cloned()
+            .collect()
+    }
+
+    /// Relationship for a given id, ie: `rId1`.
+    pub fn get(&self, id: &str) -> Option<XlsxRelationship> {
+        rel_for_id(&self.relationships, id)
+    }
+
+    /// Drawing part (xl/drawings/drawing{}.xml) holding shapes, pictures and charts.
+    pub fn drawing(&self) -> Option<XlsxRelationship> {
+        self.of_type("drawing").into_iter().next()
+    }
+
+    /// Legacy comments (notes) part (xl/comments{}.xml).
+    pub fn comments_part(&self) -> Option<XlsxRelationship> {
+        self.of_type("comments").into_iter().next()
+    }
+
+    /// Threaded comments parts (xl/threadedComments/threadedComment{}.xml).
+    pub fn threaded_comments(&self) -> Vec<XlsxRelationship> {
+        self.of_type("threadedComment")
+    }
+
+    /// VML drawing parts, used for legacy comment boxes and form controls.
+    pub fn vml_drawings(&self) -> Vec<XlsxRelationship> {
+        self.of_type("vmlDrawing")
+    }
+
+    /// Pivot table parts (xl/pivotTables/pivotTable{}.xml).
+    pub fn pivot_tables(&self) -> Vec<XlsxRelationship> {
+        self.of_type("pivotTable")
+    }
+
+    /// Table parts (xl/tables/table{}.xml).
+    pub fn table_parts(&self) -> Vec<XlsxRelationship> {
+        self.of_type("table")
+    }
+
+    /// External hyperlink targets.
+    pub fn hyperlinks(&self) -> Vec<XlsxRelationship> {
+        self.of_type("hyperlink")
+    }
+}
+
 /// (id, formated path)
+/// Path of the relationships of a part, ie: xl/_rels/workbook.xml.rels for xl/workbook.xml.
+pub(crate) fn relationships_path(part: &str) -> String {
+    match part.rsplit_once('/') {
+        Some((folder, file_name)) => format!("{}/_rels/{}.rels", folder, file_name),
+        None => format!("_rels/{}.rels", part),
+    }
+}
+
 pub(crate) fn zip_path_for_type(
     relationships: &Vec<XlsxRelationship>,
     r#type: &str,
@@ -0,0 +1,212 @@
+use anyhow::bail;
+use quick_xml::events::Event;
+use std::{
+    fmt,
+    io::{Read, Seek},
+};
+use zip::ZipArchive;
+
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+use crate::excel::xml_reader;
+
+/// A part or element present in the file that the crate knows of but does not read, found by `Excel::unsupported_features`.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct UnsupportedFeature {
+    /// Part the feature is found in, ie: `xl/charts/chart1.xml`, or `xl/worksheets/sheet1.xml` for an extension.
+    pub part: String,
+
+    pub kind: UnsupportedFeatureKind,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum UnsupportedFeatureKind {
+    /// Chart part (xl/charts/), only its anchor is read with the `drawing` feature.
+    Chart,
+
+    /// Chart sheet part (xl/chartsheets/), listed by `Excel::get_sheets` but not loaded.
+    ChartSheet,
+
+    /// Drawing part (xl/drawings/), read only with the `drawing` feature.
+    Drawing,
+
+    /// Pivot table part (xl/pivotTables/), pivot caches and the source of pivot tables are read but not their layout.
+    PivotTable,
+
+    /// External workbook link part (xl/externalLinks/).
+    ExternalLink,
+
+    /// VBA project holding the macros of the workbook (xl/vbaProject.bin).
+    VbaProject,
+
+    /// ActiveX control part (xl/activeX/).
+    ActiveXControl,
+
+    /// Form control properties part (xl/ctrlProps/).
+    FormControl,
+
+    /// Embedded object or package (xl/embeddings/).
+    EmbeddedObject,
+
+    /// Query table part (xl/queryTables/).
+    QueryTable,
+
+    /// SmartArt diagram part (xl/diagrams/).
+    Diagram,
+
+    /// Revision log of a shared workbook (xl/revisions/).
+    RevisionLog,
+
+    /// Custom XML data part (customXml/).
+    CustomXml,
+
+    /// Child of an `extLst` element, identified by its `uri` attribute.
+    ///
+    /// `name` describes the extension for the URIs known to the crate, ie: `sparkline groups`.
+    Extension { uri: String, name: Option<String> },
+}
+
+impl UnsupportedFeatureKind {
+    /// Kind of the features held by a part, from the part name.
+    pub(crate) fn from_part_name(part: &str) -> Option<Self> {
+        let part = part.to_ascii_lowercase();
+        if part.ends_with(".rels") {
+            return None;
+        }
+        let kind = if part.starts_with("xl/charts/chart") {
+            Self::Chart
+        } else if part.starts_with("xl/chartsheets/") {
+            Self::ChartSheet
+        } else if cfg!(not(feature = "drawing")) && part.starts_with("xl/drawings/drawing") {
+            Self::Drawing
+        } else if part.starts_with("xl/pivottables/") {
+            Self::PivotTable
+        } else if part.starts_with("xl/externallinks/") {
+            Self::ExternalLink
+        } else if part == "xl/vbaproject.bin" {
+            Self::VbaProject
+        } else if part.starts_with("xl/activex/") {
+            Self::ActiveXControl
+        } else if part.starts_with("xl/ctrlprops/") {
+            Self::FormControl
+        } else if part.starts_with("xl/embeddings/") {
+            Self::EmbeddedObject
+        } else if part.starts_with("xl/querytables/") {
+            Self::QueryTable
+        } else if part.starts_with("xl/diagrams/") {
+            Self::Diagram
+        } else if part.starts_with("xl/revisions/") {
+            Self::RevisionLog
+        } else if part.starts_with("customxml/item") && !part.contains("props") {
+            Self::CustomXml
+        } else {
+            return None;
+        };
+        return Some(kind);
+    }
+
+    /// Description of the extensions most commonly written by Excel.
+    pub(crate) fn extension_name(uri: &str) -> Option<&'static str> {
+        let name = match uri.to_ascii_uppercase().as_str() {
+            "{78C0D931-6437-407D-A8EE-F0AAD7539E65}" => "conditional formattings",
+            "{CCE6A557-97BC-4B89-ADB6-D9C93CAAB3DF}" => "data validations",
+            "{05C60535-1F16-4FD2-B633-F4F36F0B64E0}" => "sparkline groups",
+            "{A8765BA9-456A-4DAB-B4F3-ACF838C121DE}" => "slicer list",
+            "{3A4CF648-6AED-40F4-86FF-DC5316D8AED3}" => "slicer list",
+            "{140A7094-0E35-4892-8432-C4D2E57EDEB5}" => "workbook properties",
+            "{FCE2AD5D-F65C-4FA6-A056-5C36A1767C68}" => "data model",
+            "{7523E5D3-25F3-A5E0-1632-64F254C22452}" => "mac workbook properties",
+            _ => return None,
+        };
+        return Some(name);
+    }
+}
+
+impl fmt::Display for UnsupportedFeature {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: ", self.part)?;
+        match &self.kind {
+            UnsupportedFeatureKind::Chart => write!(f, "chart"),
+            UnsupportedFeatureKind::ChartSheet => write!(f, "chart sheet"),
+            UnsupportedFeatureKind::Drawing => write!(f, "drawing"),
+            UnsupportedFeatureKind::PivotTable => write!(f, "pivot table"),
+            UnsupportedFeatureKind::ExternalLink => write!(f, "external link"),
+            UnsupportedFeatureKind::VbaProject => write!(f, "VBA project"),
+            UnsupportedFeatureKind::ActiveXControl => write!(f, "ActiveX control"),
+            UnsupportedFeatureKind::FormControl => write!(f, "form control"),
+            UnsupportedFeatureKind::EmbeddedObject => write!(f, "embedded object"),
+            UnsupportedFeatureKind::QueryTable => write!(f, "query table"),
+            UnsupportedFeatureKind::Diagram => write!(f, "SmartArt diagram"),
+            UnsupportedFeatureKind::RevisionLog => write!(f, "revision log"),
+            UnsupportedFeatureKind::CustomXml => write!(f, "custom XML data"),
+            UnsupportedFeatureKind::Extension { uri, name } => match name {
+                Some(name) => write!(f, "extension `{}` ({})", uri, name),
+                None => write!(f, "extension `{}`", uri),
+            },
+        }
+    }
+}
+
+/// List the distinct extensions (`ext` children of `extLst` elements) of a part, in order of first appearance.
+///
+/// Missing parts have no extensions.
+pub(crate) fn find_part_extensions<RS: Read + Seek>(
+    zip: &mut ZipArchive<RS>,
+    part: &str,
+) -> anyhow::Result<Vec<UnsupportedFeature>> {
+    let Some(mut reader) = xml_reader(zip, part) else {
+        return Ok(vec![]);
+    };
+
+    let mut features: Vec<UnsupportedFeature> = vec![];
+    let mut buf: Vec<u8> = Vec::new();
+    // extLst elements currently open, extensions may hold extLst of their own
+    let mut ext_lst_depth: usize = 0;
+
+    loop {
+        buf.clear();
+
+        match reader.read_event_into(&mut buf) {
+            Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"extLst" => {
+                ext_lst_depth += 1;
+            }
+            Ok(Event::End(ref e)) if e.local_name().as_ref() == b"extLst" => {
+                ext_lst_depth = ext_lst_depth.saturating_sub(1);
+            }
+            Ok(Event::Start(ref e)) if ext_lst_depth > 0 && e.local_name().as_ref() == b"ext" => {
+                let mut uri: Option<String> = None;
+                for a in e.attributes() {
+                    match a {
+                        Ok(a) if a.key.local_name().as_ref() == b"uri" => {
+                            uri = Some(String::from_utf8(a.value.to_vec())?);
+                        }
+                        Ok(_) => {}
+                        Err(error) => bail!(error.to_string()),
+                    }
+                }
+                let Some(uri) = uri else {
+                    continue;
+                };
+                let kind = UnsupportedFeatureKind::Extension {
+                    name: UnsupportedFeatureKind::extension_name(&uri).map(|n| n.to_string()),
+                    uri,
+                };
+                if !features.iter().any(|f| f.kind == kind) {
+                    features.push(UnsupportedFeature {
+                        part: part.to_string(),
+                        kind,
+                    });
+                }
+            }
+            Ok(Event::Eof) => break,
+            Err(e) => bail!(e.to_string()),
+            _ => (),
+        }
+    }
+
+    Ok(features)
+}
@@ -0,0 +1,155 @@
+//! Commonly used types under stable names, so that user code does not depend on the module layout of the crate.
+//!
+//! ```no_run
+//! use excel_reader::prelude::*;
+//!
+//! fn main() -> Result<()> {
+//!     let mut excel = Excel::from_path("examples/sample.xlsx")?;
+//!     let sheets: Vec<SheetBasicInfo> = excel.get_sheets()?;
+//!     let worksheet: Worksheet = excel.get_worksheet(&sheets[0])?;
+//!     let cell: Cell = worksheet.get_cell(Coordinate::from_a1("A1".as_bytes()).unwrap())?;
+//!     if let CellValueType::Numeric(number) = cell.value {
+//!         println!("{}", number);
+//!     }
+//!     Ok(())
+//! }
+//! ```
+//!
+//! Structures parsed from the XML without additional processing, returned by the `Excel::get_raw_*` functions,
+//! are re-exported separately in [`raw`], as they follow the schema more closely and change more often.
+
+/// Error returned by every fallible function of the crate.
+pub use anyhow::{Error, Result};
+
+pub use crate::common_types::{
+    CellId, CellReference, Coordinate, CoordinateError, Dimension, HexColor, OutOfRangePolicy, PackedCoordinate, Text,
+};
+pub use crate::excel::{Excel, ExcelOptions};
+#[cfg(not(target_arch = "wasm32"))]
+pub use crate::excel::FileReader;
+#[cfg(all(feature = "mmap", not(target_arch = "wasm32")))]
+pub use crate::excel::MappedFile;
+#[cfg(feature = "rayon")]
+pub use crate::excel::ParallelReader;
+#[cfg(feature = "async")]
+pub use crate::async_excel::AsyncExcel;
+#[cfg(feature = "ods")]
+pub use crate::ods::Ods;
+#[cfg(feature = "xls")]
+pub use crate::xls::Xls;
+
+pub use crate::packaging::{
+    anonymize::{AnonymizeMethod, AnonymizeOptions},
+    conformance::{ConformanceViolation, ConformanceViolationKind},
+    consistency::RelationshipIssue,
+    unsupported_feature::{UnsupportedFeature, UnsupportedFeatureKind},
+};
+
+pub use crate::processed::shared::hyperlink::{
+    EmailHyperlink, ExternalHyperlink, Hyperlink, InternalHyperlink,
+};
+pub use crate::processed::spreadsheet::{
+    data_model::DataModel,
+    defined_name::{DefinedName, DefinedNameScope, NamedRange},
+    formula_dependent::FormulaDependent,
+    hidden_data::{HiddenDataReport, WorksheetHiddenData},
+    memory_footprint::MemoryFootprint,
+    pivot_table::{PivotSource, PivotTable},
+    search::{SearchLocation, SearchMatch, SearchOptions},
+    sheet_basic_info::{SheetBasicInfo, SheetType, SheetVisibleState},
+    slicer::{SlicerItem, SlicerState, TimelineState},
+    theme_font::{ThemeFont, ThemeFonts},
+    validation_reference::{ValidationReferenceIssue, ValidationReferenceProblem},
+    web_extension::WebExtension,
+    workbook::{Workbook, WorkbookData, WorkbookSheet},
+    workbook_comparison::{assert_workbook_eq, Tolerance, WorkbookDifference},
+    workbook_summary::WorkbookSummary,
+};
+
+pub use crate::processed::spreadsheet::sheet::worksheet::{
+    auto_filter::{AutoFilter, FilterColumn, FilterCriteria, SortCondition, SortState},
+    calculation_reference::CalculationReferenceMode,
+    column_profile::{ColumnProfile, ColumnUnit, DetectedType, SheetProfile, TypeCounts, UnitKind},
+    column_properties::ColumnProperties,
+    columnar::{Column, ColumnData, ColumnarSheet},
+    comment::Comment,
+    conditional_formatting::ConditionalFormattingRule,
+    csv_export::{CsvOptions, CsvQuoting},
+    custom_property::CustomProperty,
+    data_validation::DataValidation,
+    detected_table::DetectedTable,
+    formula_cell::{FormulaCell, FormulaFamily, FormulaKind},
+    header_footer::{HeaderFooter, HeaderFooterText, HeaderFooterToken, HeaderFooterValues},
+    link_candidate::{LinkCandidate, LinkKind},
+    page_setup::{PageMargins, PageOrder, PageOrientation, PageSetup},
+    pagination::PrintPage,
+    resolved_style::ResolvedStyle,
+    row::{Row, Rows},
+    row_change::{RowChangeEvent, RowChangeEvents},
+    row_hash::{RowChanges, RowHash},
+    row_properties::RowProperties,
+    row_sample::SampleStrategy,
+    sheet_protection::SheetProtection,
+    sheet_view::{SheetView, SheetViewType},
+    table::{Table, TableColumnTotal},
+    threaded_comment::{CommentThread, Person, ThreadedComment},
+    Worksheet,
+};
+
+pub use crate::processed::spreadsheet::sheet::worksheet::cell::{
+    cell_property::{
+        border::{Border, BorderStyle, BorderStyleValue},
+        fill::{Fill, GradientFill, PatternFill},
+        font::Font,
+        numbering_format::NumberingFormat,
+        text_alignment::TextAlignment,
+        CellProperty,
+    },
+    cell_value::{
+        error_value::CellErrorType,
+        formula::Formula,
+        rich_text::{RichText, RichTextRun},
+        typed_value::CellValue,
+        CellValueType,
+    },
+    Cell, HyperlinkTextMode,
+};
+
+#[cfg(feature = "serde")]
+pub use crate::processed::spreadsheet::sheet::worksheet::row_deserializer::DeserializeRows;
+#[cfg(feature = "serde")]
+pub use crate::processed::spreadsheet::sheet::worksheet::json_export::{
+    JsonNulls, JsonOptions, JsonShape,
+};
+#[cfg(feature = "serde")]
+pub use crate::processed::spreadsheet::sheet::worksheet::row_stream::{
+    ChunkFraming, RowChunkWriter,
+};
+
+#[cfg(feature = "drawing")]
+pub use crate::processed::drawing::worksheet_drawing::WorksheetDrawing;
+
+/// Structures directly parsed from the XML parts, returned by the `Excel::get_raw_*` functions.
+pub mod raw {
+    pub use crate::packaging::{
+        content_type::XlsxContentTypes,
+        relationship::{XlsxRelationship, XlsxRelationships},
+    };
+    #[cfg(feature = "drawing")]
+    pub use crate::raw::drawing::worksheet_drawing::XlsxWorksheetDrawing;
+    pub use crate::raw::{
+        drawing::theme::XlsxTheme,
+        spreadsheet::{
+            comment::XlsxComments, person::XlsxPersons,
+            pivot_cache_definition::XlsxPivotCacheDefinition,
+            pivot_table::XlsxPivotTableDefinition,
+            shared_string::{
+                lazy_shared_string_table::XlsxLazySharedStringTable,
+                shared_string_table::XlsxSharedStringTable,
+            },
+            sheet::worksheet::XlsxWorksheet, stylesheet::XlsxStyleSheet, table::XlsxTable,
+            threaded_comment::XlsxThreadedComments, vml_drawing::XlsxVmlDrawing,
+            workbook::XlsxWorkbook,
+        },
+    };
+}
@@ -0,0 +1,69 @@
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+use crate::{
+    raw::drawing::worksheet_drawing::{
+        spreadsheet_extent::XlsxSpreadsheetExtent, spreadsheet_position::XlsxSpreadsheetPosition,
+    },
+    units::{self, DEFAULT_DPI},
+};
+
+/// Position and size of a drawing object in pixels (96 dpi), measured from the top left corner of the worksheet.
+///
+/// Anchor cells are resolved with the column widths and row heights of the worksheet, hidden rows and columns take no space.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct DrawingBounds {
+    pub x: f64,
+    pub y: f64,
+    pub width: f64,
+    pub height: f64,
+}
+
+impl DrawingBounds {
+    /// from: position of the from marker in pixels
+    pub(crate) fn from_one_cell(
+        from: (f64, f64),
+        extent: Option<&XlsxSpreadsheetExtent>,
+    ) -> Option<Self> {
+        let (width, height) = extent_to_px(extent?);
+        Some(Self {
+            x: from.0,
+            y: from.1,
+            width,
+            height,
+        })
+    }
+
+    /// from, to: positions of the markers in pixels
+    pub(crate) fn from_two_cell(from: (f64, f64), to: (f64, f64)) -> Self {
+        Self {
+            x: from.0,
+            y: from.1,
+            width: (to.0 - from.0).max(0.0),
+            height: (to.1 - from.1).max(0.0),
+        }
+    }
+
+    pub(crate) fn from_absolute(
+        position: Option<&XlsxSpreadsheetPosition>,
+        extent: Option<&XlsxSpreadsheetExtent>,
+    ) -> Option<Self> {
+        let position = position?;
+        let (width, height) = extent_to_px(extent?);
+        Some(Self {
+            x: emu_to_px(position.x),
+            y: emu_to_px(position.y),
+            width,
+            height,
+        })
+    }
+}
+
+fn extent_to_px(extent: &XlsxSpreadsheetExtent) -> (f64, f64) {
+    (emu_to_px(extent.cx), emu_to_px(extent.cy))
+}
+
+fn emu_to_px(emu: Option<i64>) -> f64 {
+    units::emu_to_px(emu.unwrap_or(0), DEFAULT_DPI)
+}
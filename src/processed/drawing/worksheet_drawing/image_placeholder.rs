@@ -0,0 +1,63 @@
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+use crate::{
+    common_types::{Coordinate, Dimension},
+    processed::drawing::image::blip::BlipSourceType,
+};
+
+use super::{anchor_type::DrawingAnchorType, content_type::DrawingContentType, WorksheetDrawing};
+
+/// Position of an anchored image, without the image itself.
+///
+/// Allows renderers to re-attach extracted media to the cells it covers.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct ImagePlaceholder {
+    /// id of the drawing object (`cNvPr` id), unique within the worksheet
+    pub id: u64,
+
+    /// name of the drawing object
+    pub name: String,
+
+    /// file name of the embedded image, ie: `image1.png`, or the url of a linked image.
+    pub source: String,
+
+    /// Cells covered by the image.
+    ///
+    /// - one cell anchor: the anchor cell only
+    /// - absolute anchor: None
+    pub range: Option<Dimension>,
+}
+
+impl ImagePlaceholder {
+    pub(crate) fn from_drawing(drawing: &WorksheetDrawing) -> Option<Self> {
+        let DrawingContentType::Picture(picture) = &drawing.content else {
+            return None;
+        };
+
+        let source = match &picture.blip_fill.blip.source {
+            BlipSourceType::Internal(internal) => internal.name.clone(),
+            BlipSourceType::External(url) => url.clone(),
+        };
+
+        let range = match &drawing.anchor {
+            DrawingAnchorType::OneCellAnchor(from) => {
+                let start = Coordinate::from_point((from.row, from.col));
+                Some(Dimension { start, end: start })
+            }
+            DrawingAnchorType::TwoCellAnchor(from, to) => Some(Dimension {
+                start: Coordinate::from_point((from.row, from.col)),
+                end: Coordinate::from_point((to.row, to.col)),
+            }),
+            DrawingAnchorType::AbsoluteAnchor => None,
+        };
+
+        Some(Self {
+            id: picture.non_visual_properties.id,
+            name: picture.non_visual_properties.name.clone(),
+            source,
+            range,
+        })
+    }
+}
@@ -1,12 +1,15 @@
 pub mod anchor_type;
+pub mod bounds;
 pub mod cell_marker;
 pub mod content_type;
 pub mod group_shape;
+pub mod image_placeholder;
 pub mod lock_type;
 pub mod non_visual_properties;
 pub mod spreadsheet_shape;
 
 use anchor_type::DrawingAnchorType;
+use bounds::DrawingBounds;
 use content_type::DrawingContentType;
 
 #[cfg(feature = "serde")]
@@ -17,4 +20,9 @@ use serde::Serialize;
 pub struct WorksheetDrawing {
     pub anchor: DrawingAnchorType,
     pub content: DrawingContentType,
+
+    /// Position and size on the worksheet, resolved from the anchor cells and offsets.
+    ///
+    /// None if the anchor does not specify the size, or the position for absolute anchors.
+    pub bounds: Option<DrawingBounds>,
 }
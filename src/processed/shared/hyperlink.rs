@@ -1,13 +1,14 @@
 use regex::Regex;
 
 #[cfg(feature = "serde")]
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 use crate::{
     common_types::{Coordinate, Dimension},
     packaging::relationship::{
         raw_target_for_id, rel_for_id, XlsxRelationships, EXTERNAL_TARGET_MODE,
     },
+    processed::spreadsheet::sheet_reference::quote_sheet_name,
     raw::{
         drawing::text::hyperlink_on_event::XlsxHyperlinkOnEvent,
         spreadsheet::{
@@ -17,7 +18,7 @@ use crate::{
 };
 
 #[derive(Debug, Clone, PartialEq)]
-#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum Hyperlink {
     Inernal(InternalHyperlink),
@@ -25,6 +26,28 @@ pub enum Hyperlink {
 }
 
 impl Hyperlink {
+    /// Target of the link as text.
+    ///
+    /// - external: url, or `mailto:` address
+    /// - internal: `Sheet1!A1:B2`, the sheet name quoted when needed, see `quote_sheet_name`
+    pub fn target(&self) -> String {
+        match self {
+            Self::External(ExternalHyperlink::Url(url)) => url.to_string(),
+            Self::External(ExternalHyperlink::Email(email)) => {
+                if email.subject.is_empty() {
+                    format!("mailto:{}", email.mail_to)
+                } else {
+                    format!("mailto:{}?subject={}", email.mail_to, email.subject)
+                }
+            }
+            Self::Inernal(internal) => format!(
+                "{}!{}",
+                quote_sheet_name(&internal.sheet_name),
+                internal.cell_range.to_a1()
+            ),
+        }
+    }
+
     /// worksheet_rel: (r_id: Target)
     pub(crate) fn from_raw(
         hyperlink: XlsxHyperlink,
@@ -105,7 +128,7 @@ impl Hyperlink {
 }
 
 #[derive(Debug, Clone, PartialEq)]
-#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct InternalHyperlink {
     pub sheet_name: String,
     pub cell_range: Dimension,
@@ -142,7 +165,8 @@ impl InternalHyperlink {
         };
 
         if let Some(caps) = re.captures(location) {
-            let name = caps["name"].to_string();
+            // quotes within quoted names are doubled
+            let name = caps["name"].replace("''", "'");
             let reference = caps["ref"].to_string().replace("$", "");
             // refer to a dimension
             let dimension = if let Some(d) = Dimension::from_r1c1(&reference) {
@@ -182,7 +206,7 @@ impl InternalHyperlink {
 }
 
 #[derive(Debug, Clone, PartialEq)]
-#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum ExternalHyperlink {
     Url(String),
@@ -234,7 +258,7 @@ impl ExternalHyperlink {
 }
 
 #[derive(Debug, Clone, PartialEq)]
-#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct EmailHyperlink {
     pub mail_to: String,
     pub subject: String,
@@ -0,0 +1,21 @@
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+/// Data model (Power Pivot) embedded in the workbook.
+///
+/// The model itself is an opaque Analysis Services backup and is not parsed.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct DataModel {
+    /// Path of the model part, usually `xl/model/item.data`.
+    pub path: String,
+
+    /// Uncompressed size of the model part in bytes.
+    pub size: u64,
+
+    /// Size of the model part within the file in bytes.
+    pub compressed_size: u64,
+
+    /// cacheId of the pivot caches whose data comes from the model.
+    pub pivot_cache_ids: Vec<u64>,
+}
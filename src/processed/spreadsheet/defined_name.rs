@@ -0,0 +1,182 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    common_types::{Coordinate, Dimension, MAX_COLUMN_COUNT, MAX_ROW_COUNT},
+    helper::a1_address_to_row_col,
+    processed::spreadsheet::sheet_name::sheet_names_equal,
+    raw::spreadsheet::workbook::defined_name::XlsxDefinedName,
+};
+
+/// Prefix of the names reserved by Excel, ie: `_xlnm.Print_Area`.
+const BUILT_IN_PREFIX: &str = "_xlnm.";
+
+/// A name of the workbook referring to a range, a formula or a constant, see `Excel::defined_names`.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct DefinedName {
+    /// Name as stored, ie: `Sales`, `_xlnm.Print_Area`.
+    pub name: String,
+
+    pub scope: DefinedNameScope,
+
+    /// Formula the name refers to, without the leading `=`, ie: `Sheet1!$A$1:$C$12`, `SUM(Sheet1!$B:$B)`, `0.2`.
+    pub refers_to: String,
+
+    pub comment: Option<String>,
+
+    /// The name is not listed in the name manager of Excel.
+    pub hidden: bool,
+}
+
+/// Where a defined name can be used.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum DefinedNameScope {
+    /// Anywhere in the workbook.
+    Workbook,
+
+    /// Only within a sheet, named here. A sheet level name takes precedence over a workbook level name.
+    Sheet(String),
+}
+
+/// A range of a worksheet referred to by a defined name.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct NamedRange {
+    pub sheet_name: String,
+
+    /// Whole rows and columns, ie: `$1:$3`, extend to the last column or row of the sheet.
+    pub range: Dimension,
+}
+
+impl DefinedName {
+    /// * sheet_names: names of the sheets of the workbook, in order, to resolve `localSheetId`
+    pub(crate) fn from_raw(raw: &XlsxDefinedName, sheet_names: &[String]) -> Option<Self> {
+        let name = raw.name.clone()?;
+        let scope = match raw.local_sheet_id.and_then(|i| usize::try_from(i).ok()) {
+            Some(index) => match sheet_names.get(index) {
+                Some(sheet_name) => DefinedNameScope::Sheet(sheet_name.clone()),
+                None => return None,
+            },
+            None => DefinedNameScope::Workbook,
+        };
+        let refers_to = raw.value.as_deref().unwrap_or("").trim();
+        return Some(Self {
+            name,
+            scope,
+            refers_to: refers_to.strip_prefix('=').unwrap_or(refers_to).to_string(),
+            comment: raw.comment.clone(),
+            hidden: raw.hidden.unwrap_or(false),
+        });
+    }
+
+    /// Name reserved by Excel, ie: `_xlnm.Print_Area`, `_xlnm.Print_Titles`, `_xlnm._FilterDatabase`.
+    pub fn is_built_in(&self) -> bool {
+        return self
+            .name
+            .get(..BUILT_IN_PREFIX.len())
+            .is_some_and(|p| p.eq_ignore_ascii_case(BUILT_IN_PREFIX));
+    }
+
+    /// Ranges the name refers to, ie: `'My Sheet'!$A$1:$C$10` or `Sheet1!$A:$A,Sheet1!$1:$1`.
+    ///
+    /// Empty for names that are not plain references: formulas, constants, names of other workbooks, `#REF!`.
+    pub fn ranges(&self) -> Vec<NamedRange> {
+        let refers_to = self.refers_to.trim();
+        let refers_to = match refers_to
+            .strip_prefix('(')
+            .and_then(|r| r.strip_suffix(')'))
+        {
+            Some(union) => union,
+            None => refers_to,
+        };
+
+        let mut ranges: Vec<NamedRange> = vec![];
+        for reference in split_references(refers_to) {
+            let Some((sheet, range)) = reference.rsplit_once('!') else {
+                return vec![];
+            };
+            // external workbook, ie: `[1]Sheet1!$A$1`
+            if sheet.contains('[') {
+                return vec![];
+            }
+            let Some(range) = parse_area(range, None) else {
+                return vec![];
+            };
+            ranges.push(NamedRange {
+                sheet_name: unquote_sheet_name(sheet),
+                range,
+            });
+        }
+        return ranges;
+    }
+
+    /// The range the name refers to, None if it does not refer to a single range.
+    pub fn range(&self) -> Option<NamedRange> {
+        let mut ranges = self.ranges();
+        if ranges.len() != 1 {
+            return None;
+        }
+        return ranges.pop();
+    }
+
+    pub(crate) fn is_visible_from(&self, sheet_name: Option<&str>) -> bool {
+        match (&self.scope, sheet_name) {
+            (DefinedNameScope::Workbook, _) => true,
+            (DefinedNameScope::Sheet(scope), Some(sheet_name)) => {
+                sheet_names_equal(scope, sheet_name)
+            }
+            (DefinedNameScope::Sheet(_), None) => false,
+        }
+    }
+}
+
+/// Split a list of references on commas outside of quoted sheet names.
+pub(crate) fn split_references(value: &str) -> Vec<&str> {
+    let mut references: Vec<&str> = vec![];
+    let mut quoted = false;
+    let mut start = 0;
+    for (index, c) in value.char_indices() {
+        match c {
+            '\'' => quoted = !quoted,
+            ',' if !quoted => {
+                references.push(value[start..index].trim());
+                start = index + 1;
+            }
+            _ => (),
+        }
+    }
+    references.push(value[start..].trim());
+    references
+}
+
+pub(crate) fn unquote_sheet_name(sheet: &str) -> String {
+    match sheet.strip_prefix('\'').and_then(|s| s.strip_suffix('\'')) {
+        Some(sheet) => sheet.replace("''", "'"),
+        None => sheet.to_string(),
+    }
+}
+
+/// A1 range, with optional `$`, whole rows (`$1:$5`) or whole columns (`$A:$D`).
+///
+/// Whole rows and columns are limited to `used_range`, or to the size of a sheet.
+pub(crate) fn parse_area(range: &str, used_range: Option<Dimension>) -> Option<Dimension> {
+    let range = range.replace('$', "");
+    let (start, end) = range.split_once(':').unwrap_or((&range, &range));
+    let (start_row, start_col) = a1_address_to_row_col(start.as_bytes()).ok()?;
+    let (end_row, end_col) = a1_address_to_row_col(end.as_bytes()).ok()?;
+    if (start_row.is_none() && start_col.is_none()) || (end_row.is_none() && end_col.is_none()) {
+        return None;
+    }
+
+    let last_row = used_range.map(|d| d.end.row).unwrap_or(MAX_ROW_COUNT);
+    let last_col = used_range.map(|d| d.end.col).unwrap_or(MAX_COLUMN_COUNT);
+    let start = Coordinate::from_point((start_row.unwrap_or(1), start_col.unwrap_or(1)));
+    let end = Coordinate::from_point((
+        end_row.unwrap_or(last_row).max(start.row),
+        end_col.unwrap_or(last_col).max(start.col),
+    ));
+    Some(Dimension { start, end })
+}
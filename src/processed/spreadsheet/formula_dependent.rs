@@ -0,0 +1,121 @@
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+use crate::common_types::Dimension;
+
+use super::{
+    defined_name::{DefinedName, DefinedNameScope},
+    sheet::worksheet::formula_cell::FormulaCell,
+    sheet_name::sheet_names_equal,
+    sheet_reference::{formula_precedents, FormulaPrecedent},
+};
+
+/// A formula cell reading a range, see `Excel::dependents_of`.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct FormulaDependent {
+    /// Sheet of the formula cell
+    pub sheet_name: String,
+
+    pub formula_cell: FormulaCell,
+
+    /// Defined names and tables the formula reads the range through, outermost first.
+    ///
+    /// Empty if the formula refers to the range itself.
+    pub via: Vec<String>,
+}
+
+/// What formulas can read ranges through.
+pub(crate) struct PrecedentTargets {
+    /// All sheets, in workbook order, to expand 3D references.
+    pub(crate) sheet_names: Vec<String>,
+    pub(crate) defined_names: Vec<DefinedName>,
+    /// (display name, sheet name, range)
+    pub(crate) tables: Vec<(String, String, Dimension)>,
+}
+
+impl PrecedentTargets {
+    /// Names read by a formula of a cell of `sheet_name` to reach a range of `target_sheet` overlapping `target`.
+    ///
+    /// None if the formula does not read the range, the shortest chain of names otherwise.
+    pub(crate) fn reads(
+        &self,
+        formula: &str,
+        sheet_name: &str,
+        target_sheet: &str,
+        target: Dimension,
+    ) -> Option<Vec<String>> {
+        let mut via: Vec<String> = vec![];
+        let mut found: Option<Vec<String>> = None;
+        self.find(formula, sheet_name, target_sheet, target, &mut via, &mut found);
+        return found;
+    }
+
+    fn find(
+        &self,
+        formula: &str,
+        sheet_name: &str,
+        target_sheet: &str,
+        target: Dimension,
+        via: &mut Vec<String>,
+        found: &mut Option<Vec<String>>,
+    ) {
+        for precedent in formula_precedents(formula) {
+            if found.as_ref().is_some_and(|f| f.len() <= via.len()) {
+                return;
+            }
+            match precedent {
+                FormulaPrecedent::Range { sheets, range } => {
+                    let overlaps = range.clamp_to(target).is_some();
+                    let on_target = match &sheets {
+                        None => sheet_names_equal(sheet_name, target_sheet),
+                        Some((first, last)) => self.spans(first, last, target_sheet),
+                    };
+                    if overlaps && on_target {
+                        *found = Some(via.clone());
+                    }
+                }
+                FormulaPrecedent::Name { sheet, name } => {
+                    // names refer to each other in circles in broken workbooks
+                    if via.iter().any(|v| v.eq_ignore_ascii_case(&name)) {
+                        continue;
+                    }
+                    via.push(name.clone());
+                    let scope = sheet.as_deref().unwrap_or(sheet_name);
+                    if let Some(defined_name) = self.defined_name(&name, scope) {
+                        self.find(&defined_name.refers_to, sheet_name, target_sheet, target, via, found);
+                    } else if let Some((_, table_sheet, range)) = self
+                        .tables
+                        .iter()
+                        .find(|(table, _, _)| table.eq_ignore_ascii_case(&name))
+                    {
+                        if sheet_names_equal(table_sheet, target_sheet) && range.clamp_to(target).is_some() {
+                            *found = Some(via.clone());
+                        }
+                    }
+                    via.pop();
+                }
+            }
+        }
+    }
+
+    /// Defined name as seen from a sheet, the name scoped to the sheet first.
+    fn defined_name(&self, name: &str, sheet_name: &str) -> Option<&DefinedName> {
+        return self
+            .defined_names
+            .iter()
+            .filter(|d| d.name.eq_ignore_ascii_case(name) && d.is_visible_from(Some(sheet_name)))
+            .max_by_key(|d| d.scope != DefinedNameScope::Workbook);
+    }
+
+    /// Whether the sheets from `first` to `last`, in workbook order, include `sheet_name`.
+    fn spans(&self, first: &str, last: &str, sheet_name: &str) -> bool {
+        let position = |name: &str| self.sheet_names.iter().position(|s| sheet_names_equal(s, name));
+        return match (position(first), position(last), position(sheet_name)) {
+            (Some(first), Some(last), Some(sheet)) => {
+                (first.min(last)..=first.max(last)).contains(&sheet)
+            }
+            _ => false,
+        };
+    }
+}
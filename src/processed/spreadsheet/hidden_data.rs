@@ -0,0 +1,152 @@
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+use crate::common_types::{Coordinate, Dimension, MAX_COLUMN_COUNT, MAX_ROW_COUNT};
+
+use super::{
+    sheet::worksheet::{
+        cell::cell_property::{
+            fill::{Fill, PatternFillTypeValue},
+            CellProperty,
+        },
+        Worksheet,
+    },
+    sheet_basic_info::SheetBasicInfo,
+};
+
+/// Color of the cells without a fill.
+const DEFAULT_BACKGROUND_RGB: &str = "ffffff";
+
+/// Data users may have tried to hide, see `Excel::hidden_data_report`.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct HiddenDataReport {
+    /// Sheets of any type that are hidden or very hidden, in workbook order.
+    pub hidden_sheets: Vec<SheetBasicInfo>,
+
+    /// Worksheets with hidden rows, columns or text, in workbook order.
+    pub worksheets: Vec<WorksheetHiddenData>,
+}
+
+impl HiddenDataReport {
+    /// Nothing is hidden.
+    pub fn is_empty(&self) -> bool {
+        return self.hidden_sheets.is_empty() && self.worksheets.is_empty();
+    }
+}
+
+/// Hidden parts of a worksheet.
+///
+/// Rows and columns are grouped into whole row and whole column ranges, ie: `3:5`, `E:XFD`.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct WorksheetHiddenData {
+    pub sheet_name: String,
+
+    pub hidden_rows: Vec<Dimension>,
+
+    /// Rows not hidden, but with a height of 0.
+    pub zero_height_rows: Vec<Dimension>,
+
+    pub hidden_columns: Vec<Dimension>,
+
+    /// Columns not hidden, but with a width of 0.
+    pub zero_width_columns: Vec<Dimension>,
+
+    /// Cells with a value written in the color of their background, ie: white text without a fill.
+    ///
+    /// Cells with a gradient or a patterned fill are left out.
+    pub invisible_text_cells: Vec<Coordinate>,
+}
+
+impl WorksheetHiddenData {
+    /// None if nothing is hidden.
+    pub(crate) fn from_worksheet(worksheet: &Worksheet) -> anyhow::Result<Option<Self>> {
+        let rows: Vec<_> = worksheet.customized_rows().collect();
+        let columns = worksheet.column_properties();
+        let invisible_text_cells = worksheet
+            .cells_with_style(text_matches_background)?
+            .into_iter()
+            .filter(|c| !c.value.to_string().trim().is_empty())
+            .map(|c| c.coordinate)
+            .collect();
+
+        let data = Self {
+            sheet_name: worksheet.name.clone(),
+            hidden_rows: row_ranges(rows.iter().filter(|r| r.hidden).map(|r| r.row)),
+            zero_height_rows: row_ranges(
+                rows.iter()
+                    .filter(|r| !r.hidden && r.height == Some(0.0))
+                    .map(|r| r.row),
+            ),
+            hidden_columns: column_ranges(columns.iter().filter(|c| c.hidden).map(|c| c.column)),
+            zero_width_columns: column_ranges(
+                columns
+                    .iter()
+                    .filter(|c| !c.hidden && c.width == Some(0.0))
+                    .map(|c| c.column),
+            ),
+            invisible_text_cells,
+        };
+        let is_empty = data.hidden_rows.is_empty()
+            && data.zero_height_rows.is_empty()
+            && data.hidden_columns.is_empty()
+            && data.zero_width_columns.is_empty()
+            && data.invisible_text_cells.is_empty();
+        return Ok(if is_empty { None } else { Some(data) });
+    }
+}
+
+/// Whether the font color is the color of a solid fill, or white without a fill.
+fn text_matches_background(property: &CellProperty) -> bool {
+    let Fill::PatternFill(fill) = &property.fill else {
+        return false;
+    };
+    let background = match fill.pattern_type {
+        PatternFillTypeValue::None => DEFAULT_BACKGROUND_RGB.to_string(),
+        PatternFillTypeValue::Solid => match fill.foreground_color.as_deref() {
+            Some(color) => rgb(color),
+            None => return false,
+        },
+        _ => return false,
+    };
+    return rgb(&property.font.color) == background;
+}
+
+/// rrggbb part of a color, as colors are stored either as `rrggbbaa` or `#rrggbbaa`.
+fn rgb(color: &str) -> String {
+    let color = color.trim_start_matches('#').to_ascii_lowercase();
+    return color.get(..6).unwrap_or(&color).to_string();
+}
+
+/// Consecutive indices grouped into (first, last), indices in ascending order.
+fn group(indices: impl Iterator<Item = u64>) -> Vec<(u64, u64)> {
+    let mut groups: Vec<(u64, u64)> = vec![];
+    for index in indices {
+        match groups.last_mut() {
+            Some((_, last)) if *last + 1 == index => *last = index,
+            _ => groups.push((index, index)),
+        }
+    }
+    return groups;
+}
+
+fn row_ranges(rows: impl Iterator<Item = u64>) -> Vec<Dimension> {
+    return group(rows)
+        .into_iter()
+        .map(|(first, last)| Dimension {
+            start: Coordinate::from_point((first, 1)),
+            end: Coordinate::from_point((last, MAX_COLUMN_COUNT)),
+        })
+        .collect();
+}
+
+fn column_ranges(columns: impl Iterator<Item = u64>) -> Vec<Dimension> {
+    return group(columns)
+        .into_iter()
+        .map(|(first, last)| Dimension {
+            start: Coordinate::from_point((1, first)),
+            end: Coordinate::from_point((MAX_ROW_COUNT, last)),
+        })
+        .collect();
+}
@@ -0,0 +1,154 @@
+use std::mem::{size_of, size_of_val};
+
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+use crate::{
+    common_types::Dimension,
+    raw::{
+        drawing::theme::XlsxTheme,
+        spreadsheet::{
+            shared_string::shared_string_table::XlsxSharedStringTable, string_item::XlsxStringItem,
+            stylesheet::XlsxStyleSheet, workbook::XlsxWorkbook,
+        },
+    },
+};
+
+use super::{
+    sheet::worksheet::cell::{cell_value::CellValueType, Cell},
+    workbook::WorkbookSheet,
+};
+
+/// Estimated number of bytes held by the parts an `Excel` has loaded and cached.
+///
+/// Estimates count the size of the structs and the heap allocations of their collections and texts.
+/// Allocator overhead and unused capacity are not included, so actual usage is somewhat higher.
+///
+/// Parts that are not loaded yet count as zero.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct MemoryFootprint {
+    /// xl/sharedStrings.xml
+    pub shared_strings: usize,
+    /// xl/styles.xml
+    pub stylesheet: usize,
+    /// xl/theme/theme1.xml
+    pub theme: usize,
+    /// xl/workbook.xml
+    pub workbook: usize,
+    /// Sheets cached by `to_processed_model`
+    pub processed_sheets: usize,
+}
+
+impl MemoryFootprint {
+    pub fn total(&self) -> usize {
+        self.shared_strings + self.stylesheet + self.theme + self.workbook + self.processed_sheets
+    }
+}
+
+pub(crate) fn shared_strings_size(table: &XlsxSharedStringTable) -> usize {
+    size_of::<XlsxSharedStringTable>()
+        + table
+            .string_item
+            .as_ref()
+            .map_or(0, |items| items.iter().map(string_item_size).sum())
+}
+
+fn string_item_size(item: &XlsxStringItem) -> usize {
+    let runs = item.rich_text_run.as_ref().map_or(0, |runs| {
+        runs.iter()
+            .map(|r| size_of_val(r) + option_text_size(&r.text))
+            .sum()
+    });
+    let phonetic_runs = item.phonetic_run.as_ref().map_or(0, |runs| {
+        runs.iter()
+            .map(|r| size_of_val(r) + option_text_size(&r.text))
+            .sum()
+    });
+    size_of::<XlsxStringItem>() + option_text_size(&item.text) + runs + phonetic_runs
+}
+
+pub(crate) fn stylesheet_size(stylesheet: &XlsxStyleSheet) -> usize {
+    size_of::<XlsxStyleSheet>()
+        + vec_size(&stylesheet.fills)
+        + vec_size(&stylesheet.borders)
+        + vec_size(&stylesheet.fonts)
+        + vec_size(&stylesheet.cell_styles)
+        + vec_size(&stylesheet.cell_style_xfs)
+        + vec_size(&stylesheet.cell_xfs)
+        + vec_size(&stylesheet.differential_xfs)
+        + vec_size(&stylesheet.numbering_formats)
+        + stylesheet.numbering_formats.as_ref().map_or(0, |formats| {
+            formats
+                .iter()
+                .map(|f| option_text_size(&f.format_code))
+                .sum()
+        })
+        + stylesheet.colors.as_ref().map_or(0, size_of_val)
+        + stylesheet.table_styles.as_ref().map_or(0, size_of_val)
+}
+
+pub(crate) fn theme_size(theme: &XlsxTheme) -> usize {
+    size_of::<XlsxTheme>()
+        + theme.custom_color_list.as_deref().map_or(0, size_of_val)
+        + theme
+            .extra_color_scheme_list
+            .as_deref()
+            .map_or(0, size_of_val)
+        + theme.object_defaults.as_deref().map_or(0, size_of_val)
+        + theme.theme_elements.as_deref().map_or(0, size_of_val)
+        + option_text_size(&theme.name)
+}
+
+pub(crate) fn workbook_size(workbook: &XlsxWorkbook) -> usize {
+    let sheets = workbook.sheets.as_ref().map_or(0, |sheets| {
+        sheets
+            .iter()
+            .map(|s| size_of_val(s) + option_text_size(&s.name) + option_text_size(&s.id))
+            .sum()
+    });
+    let defined_names = workbook.defined_names.as_ref().map_or(0, |names| {
+        names
+            .iter()
+            .map(|n| size_of_val(n) + option_text_size(&n.name) + option_text_size(&n.value))
+            .sum()
+    });
+    size_of::<XlsxWorkbook>() + sheets + defined_names
+}
+
+pub(crate) fn workbook_sheet_size(sheet: &WorkbookSheet) -> usize {
+    size_of::<WorkbookSheet>()
+        + sheet.name.len()
+        + sheet.merged_cells.len() * size_of::<Dimension>()
+        + sheet.tables.iter().map(size_of_val).sum::<usize>()
+        + sheet
+            .data_validations
+            .as_ref()
+            .map_or(0, |v| v.iter().map(size_of_val).sum())
+        + sheet.cells.iter().map(cell_size).sum::<usize>()
+}
+
+fn cell_size(cell: &Cell) -> usize {
+    let value = match &cell.value {
+        CellValueType::PlainText(text) => text.text.len(),
+        CellValueType::RichText(text) => text
+            .runs
+            .iter()
+            .map(|r| size_of_val(r) + r.text.len())
+            .sum(),
+        CellValueType::Formula(formula) => {
+            formula.formula.len() + option_text_size(&formula.last_calculated_value)
+        }
+        CellValueType::DateTime(text) => text.len(),
+        _ => 0,
+    };
+    size_of::<Cell>() + value + option_text_size(&cell.raw_type)
+}
+
+fn vec_size<T>(vec: &Option<Vec<T>>) -> usize {
+    vec.as_ref().map_or(0, |v| v.len() * size_of::<T>())
+}
+
+fn option_text_size(text: &Option<String>) -> usize {
+    text.as_ref().map_or(0, |t| t.len())
+}
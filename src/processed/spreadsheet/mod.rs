@@ -1,2 +1,18 @@
+pub mod data_model;
+pub mod defined_name;
+pub mod formula_dependent;
+pub mod hidden_data;
+pub mod memory_footprint;
+pub mod pivot_table;
+pub mod search;
 pub mod sheet;
 pub mod sheet_basic_info;
+pub mod sheet_name;
+pub mod sheet_reference;
+pub mod slicer;
+pub mod theme_font;
+pub mod validation_reference;
+pub mod web_extension;
+pub mod workbook;
+pub mod workbook_comparison;
+pub mod workbook_summary;
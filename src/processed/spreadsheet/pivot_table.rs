@@ -0,0 +1,229 @@
+use anyhow::bail;
+use std::io::{Read, Seek};
+
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+use crate::{
+    common_types::{Coordinate, Dimension},
+    excel::Excel,
+    processed::spreadsheet::{
+        sheet::worksheet::cell::Cell, sheet_basic_info::SheetType, sheet_name::sheet_names_equal,
+    },
+    raw::spreadsheet::{
+        pivot_cache_definition::XlsxPivotCacheDefinition, pivot_table::XlsxPivotTableDefinition,
+    },
+};
+
+/// A pivot table of a worksheet and the source of its data, see `Excel::get_pivot_tables`.
+///
+/// The layout of the pivot table (row, column and data fields) is not read.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct PivotTable {
+    pub name: String,
+
+    /// Worksheet holding the pivot table.
+    pub sheet_name: String,
+
+    /// Range of the pivot table, page fields excluded.
+    pub location: Option<Dimension>,
+
+    /// cacheId of the pivot cache the pivot table reads from, shared by pivot tables on the same source.
+    pub cache_id: Option<u64>,
+
+    /// Names of the fields of the pivot cache, the column headers of the source.
+    pub fields: Vec<String>,
+
+    pub source: PivotSource,
+}
+
+/// Where the data of a pivot cache comes from.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub enum PivotSource {
+    /// Range of a worksheet, header row included.
+    Range { sheet: String, range: Dimension },
+
+    /// Table or defined name, `sheet` is the scope of a sheet level name.
+    Name { name: String, sheet: Option<String> },
+
+    /// Range or name of another workbook.
+    ExternalWorkbook,
+
+    /// External connection in xl/connections.xml, or the data model.
+    Connection { connection_id: Option<u64> },
+
+    /// Consolidation ranges or scenarios, or a source that could not be read.
+    Other { r#type: Option<String> },
+}
+
+impl PivotTable {
+    /// `pivot_cache`: definition of the cache with the cacheId of the pivot table
+    pub(crate) fn from_raw(
+        raw: XlsxPivotTableDefinition,
+        sheet_name: &str,
+        pivot_cache: Option<&XlsxPivotCacheDefinition>,
+    ) -> Self {
+        let fields: Vec<String> = pivot_cache
+            .map(|c| {
+                c.cache_fields
+                    .iter()
+                    .map(|f| f.name.clone().unwrap_or_default())
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let cache_source = pivot_cache.and_then(|c| c.cache_source.as_ref());
+        let source = match cache_source {
+            Some(cache_source) => match (
+                cache_source.r#type.as_deref(),
+                &cache_source.worksheet_source,
+            ) {
+                (Some("worksheet"), Some(worksheet_source)) => {
+                    let range = worksheet_source
+                        .r#ref
+                        .as_deref()
+                        .and_then(|r| Dimension::from_sqref(r).first().copied());
+                    if worksheet_source.id.is_some() {
+                        PivotSource::ExternalWorkbook
+                    } else if let Some(name) = &worksheet_source.name {
+                        PivotSource::Name {
+                            name: name.clone(),
+                            sheet: worksheet_source.sheet.clone(),
+                        }
+                    } else if let (Some(range), Some(sheet)) = (range, &worksheet_source.sheet) {
+                        PivotSource::Range {
+                            sheet: sheet.clone(),
+                            range,
+                        }
+                    } else {
+                        PivotSource::Other {
+                            r#type: cache_source.r#type.clone(),
+                        }
+                    }
+                }
+                (Some("external"), _) => PivotSource::Connection {
+                    connection_id: cache_source.connection_id,
+                },
+                _ => PivotSource::Other {
+                    r#type: cache_source.r#type.clone(),
+                },
+            },
+            None => PivotSource::Other { r#type: None },
+        };
+
+        return Self {
+            name: raw.name.unwrap_or_default(),
+            sheet_name: sheet_name.to_string(),
+            location: raw.location.and_then(|l| l.r#ref),
+            cache_id: raw.cache_id,
+            fields,
+            source,
+        };
+    }
+
+    /// Worksheet and range holding the source data, header row included.
+    ///
+    /// Tables are resolved to their header and data rows, defined names to the range they refer to.
+    /// Fails for sources outside of the workbook.
+    pub fn source_range<RS: Read + Seek>(
+        &self,
+        excel: &mut Excel<RS>,
+    ) -> anyhow::Result<(String, Dimension)> {
+        let (name, scope) = match &self.source {
+            PivotSource::Range { sheet, range } => return Ok((sheet.clone(), *range)),
+            PivotSource::Name { name, sheet } => (name, sheet),
+            _ => bail!(
+                "Source of pivot table `{}` is not within the workbook.",
+                self.name
+            ),
+        };
+
+        if let Some(defined_name) = excel.defined_name(name, scope.as_deref())? {
+            let Some(named_range) = defined_name.range() else {
+                bail!(
+                    "Defined name `{}` of pivot table `{}` does not refer to a single range: `{}`.",
+                    name,
+                    self.name,
+                    defined_name.refers_to
+                );
+            };
+            return Ok((named_range.sheet_name, named_range.range));
+        }
+
+        let sheets = excel.get_sheets()?;
+
+        for sheet in sheets.iter().filter(|s| s.r#type == SheetType::WorkSheet) {
+            for table in excel.get_raw_tables_for_worksheet(sheet)? {
+                let table_name = table.display_name.as_deref().or(table.name.as_deref());
+                if !table_name.is_some_and(|n| n.eq_ignore_ascii_case(name)) {
+                    continue;
+                }
+                let Some(mut range) = table.r#ref else {
+                    continue;
+                };
+                // the totals row is not part of the source
+                let totals_row_count = table.totals_row_count.unwrap_or(0);
+                range.end.row = range
+                    .end
+                    .row
+                    .saturating_sub(totals_row_count)
+                    .max(range.start.row);
+                return Ok((sheet.name.clone(), range));
+            }
+        }
+
+        bail!(
+            "Source `{}` of pivot table `{}` is neither a defined name nor a table.",
+            name,
+            self.name
+        )
+    }
+
+    /// Rows of the source data, header row excluded, read from its worksheet.
+    ///
+    /// Cells of a row are in the order of the source columns, which match `fields`.
+    /// Only the part of the source within the dimension of its worksheet is returned.
+    pub fn source_records<RS: Read + Seek>(
+        &self,
+        excel: &mut Excel<RS>,
+    ) -> anyhow::Result<Vec<Vec<Cell>>> {
+        let (sheet_name, range) = self.source_range(excel)?;
+        if range.start.row >= range.end.row {
+            return Ok(vec![]);
+        }
+
+        let Some(sheet) = excel
+            .get_sheets()?
+            .into_iter()
+            .find(|s| sheet_names_equal(&s.name, &sheet_name))
+        else {
+            bail!(
+                "Source sheet `{}` of pivot table `{}` does not exist.",
+                sheet_name,
+                self.name
+            );
+        };
+        let worksheet = excel.get_worksheet(&sheet)?;
+        let data_range = Dimension {
+            start: Coordinate::from_point((range.start.row + 1, range.start.col)),
+            end: range.end,
+        };
+
+        let mut records: Vec<Vec<Cell>> = vec![];
+        for cell in worksheet.get_range_with_dimension(data_range)? {
+            match records.last_mut() {
+                Some(record)
+                    if record
+                        .first()
+                        .is_some_and(|c| c.coordinate.row == cell.coordinate.row) =>
+                {
+                    record.push(cell);
+                }
+                _ => records.push(vec![cell]),
+            }
+        }
+        return Ok(records);
+    }
+}
@@ -0,0 +1,88 @@
+use regex::{Regex, RegexBuilder};
+
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+use crate::common_types::Coordinate;
+
+/// Options for `Excel::search_all` and `Worksheet::search`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SearchOptions {
+    /// Default to false.
+    pub case_sensitive: bool,
+
+    /// Treat the pattern as a regular expression instead of plain text. Default to false.
+    pub regex: bool,
+
+    /// Also match the formula text of formula cells, not only their calculated value. Default to false.
+    pub include_formulas: bool,
+
+    /// Also search the values of defined names. Default to false.
+    pub include_defined_names: bool,
+
+    /// Also search headers and footers of the sheets. Default to false.
+    pub include_headers_footers: bool,
+
+    /// Also search the notes and threaded comments of the sheets. Default to false.
+    pub include_comments: bool,
+
+    /// Search the cells of a sheet in parallel, with the `rayon` feature. Default to true.
+    ///
+    /// `Excel::search_all_parallel` also loads the worksheets concurrently.
+    pub parallel: bool,
+}
+
+impl Default for SearchOptions {
+    fn default() -> Self {
+        Self {
+            case_sensitive: false,
+            regex: false,
+            include_formulas: false,
+            include_defined_names: false,
+            include_headers_footers: false,
+            include_comments: false,
+            parallel: true,
+        }
+    }
+}
+
+impl SearchOptions {
+    pub(crate) fn build_regex(&self, pattern: &str) -> anyhow::Result<Regex> {
+        let pattern = if self.regex {
+            pattern.to_string()
+        } else {
+            regex::escape(pattern)
+        };
+        Ok(RegexBuilder::new(&pattern)
+            .case_insensitive(!self.case_sensitive)
+            .build()?)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct SearchMatch {
+    /// None for defined names that are not scoped to a sheet.
+    pub sheet_name: Option<String>,
+
+    pub location: SearchLocation,
+
+    /// Text that matched: cell value, formula, comment, defined name value or header / footer text.
+    pub text: String,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum SearchLocation {
+    /// Value of a cell
+    Cell(Coordinate),
+    /// Formula of a cell
+    Formula(Coordinate),
+    /// Note or threaded comment of a cell, a conversation is matched as a whole
+    Comment(Coordinate),
+    /// Value of a defined name
+    DefinedName { name: String },
+    /// Header or footer, ie: `oddHeader`
+    HeaderFooter { kind: String },
+}
@@ -0,0 +1,312 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    common_types::{Dimension, XlsxDatetime},
+    raw::spreadsheet::filter::{
+        auto_filter::XlsxAutoFilter,
+        filter_column::XlsxFilterColumn,
+        filter_criteria::{XlsxDateGroupItem, XlsxFilterCriteriaGroup},
+        sort_state::{XlsxSortCondition, XlsxSortState},
+    },
+};
+
+/// AutoFilter of a worksheet or a table: the filtered range, the criteria of each filtered column, and the sort applied.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct AutoFilter {
+    /// Range the AutoFilter applies to, header row included
+    pub dimension: Dimension,
+
+    /// Columns with a `filterColumn` entry, in the order of the file.
+    ///
+    /// Columns without criteria are only listed when their filter button is hidden or shown explicitly.
+    pub columns: Vec<FilterColumn>,
+
+    pub sort_state: Option<SortState>,
+}
+
+impl AutoFilter {
+    pub(crate) fn from_raw(raw: &XlsxAutoFilter) -> Option<Self> {
+        let dimension = raw.r#ref?;
+        let columns = raw
+            .filter_colomn
+            .iter()
+            .flatten()
+            .map(|c| FilterColumn::from_raw(c, &dimension))
+            .collect();
+        return Some(Self {
+            dimension,
+            columns,
+            sort_state: raw.sort_state.as_ref().map(SortState::from_raw),
+        });
+    }
+
+    /// Columns with criteria hiding rows.
+    pub fn active_columns(&self) -> Vec<&FilterColumn> {
+        self.columns
+            .iter()
+            .filter(|c| c.criteria.is_some())
+            .collect()
+    }
+
+    /// Filter of a worksheet column (1-based), ie: 3 for column C.
+    pub fn column(&self, column: u64) -> Option<&FilterColumn> {
+        self.columns.iter().find(|c| c.column == column)
+    }
+}
+
+/// A column of the AutoFilter range with its filter.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct FilterColumn {
+    /// Zero-based index of the column in the AutoFilter range (`colId`)
+    pub column_id: u64,
+
+    /// Worksheet column (1-based)
+    pub column: u64,
+
+    /// None if the column is not filtered
+    pub criteria: Option<FilterCriteria>,
+
+    /// The filter button of the column is hidden.
+    pub button_hidden: bool,
+}
+
+impl FilterColumn {
+    fn from_raw(raw: &XlsxFilterColumn, dimension: &Dimension) -> Self {
+        let column_id = raw.col_id.unwrap_or(0);
+        return Self {
+            column_id,
+            column: dimension.start.col + column_id,
+            criteria: FilterCriteria::from_raw(raw),
+            button_hidden: raw.hidden_autofilter_button.unwrap_or(false)
+                || raw.show_filter_button == Some(false),
+        };
+    }
+}
+
+/// Criteria of a filtered column.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum FilterCriteria {
+    /// Rows whose value is one of the values, or within one of the date groups, are shown (`filters`).
+    Values {
+        values: Vec<String>,
+        date_groups: Vec<DateGroup>,
+        /// Blank cells are shown.
+        blank: bool,
+    },
+
+    /// Rows whose value matches one or both conditions are shown (`customFilters`).
+    Custom {
+        /// Both conditions must match, instead of either.
+        and: bool,
+        conditions: Vec<CustomFilterCondition>,
+    },
+
+    /// Criteria evaluated against the data or the current date, ie: `aboveAverage`, `thisMonth` (`dynamicFilter`).
+    Dynamic {
+        r#type: String,
+        /// Lower bound computed when the filter was applied, ie: `2024-03-01T00:00:00`
+        min: Option<String>,
+        /// Upper bound computed when the filter was applied
+        max: Option<String>,
+    },
+
+    /// Top or bottom items or percent (`top10`).
+    Top {
+        /// Top items, bottom items otherwise.
+        top: bool,
+        /// `value` is a percent, a number of items otherwise.
+        percent: bool,
+        value: f64,
+        /// Cell value the items are compared to
+        threshold: Option<f64>,
+    },
+
+    /// Rows are shown by cell fill or font color (`colorFilter`).
+    Color {
+        /// Index of the differential format (`dxfs` of the stylesheet) holding the color
+        dxf_id: Option<u64>,
+        /// Filter on the fill color, on the font color otherwise.
+        cell_color: bool,
+    },
+
+    /// Rows are shown by conditional formatting icon (`iconFilter`).
+    Icon {
+        icon_set: Option<String>,
+        icon_id: Option<u64>,
+    },
+}
+
+impl FilterCriteria {
+    fn from_raw(raw: &XlsxFilterColumn) -> Option<Self> {
+        if let Some(group) = &raw.grouped_filter {
+            return Some(Self::from_raw_group(group));
+        }
+        if let Some(custom) = &raw.custom_filters {
+            return Some(Self::Custom {
+                and: custom.and.unwrap_or(false),
+                conditions: custom
+                    .custom_filter
+                    .iter()
+                    .map(|f| CustomFilterCondition {
+                        operator: f.operator.clone().unwrap_or("equal".to_string()),
+                        value: f.val.clone().unwrap_or_default(),
+                    })
+                    .collect(),
+            });
+        }
+        if let Some(dynamic) = &raw.dynamic_filter {
+            return Some(Self::Dynamic {
+                r#type: dynamic.filter_type.clone().unwrap_or_default(),
+                min: dynamic.min_val_iso.as_ref().map(format_datetime),
+                max: dynamic.max_val_iso.as_ref().map(format_datetime),
+            });
+        }
+        if let Some(top) = &raw.top_n_filter {
+            return Some(Self::Top {
+                top: top.filter_by_top.unwrap_or(true),
+                percent: top.filter_by_percent.unwrap_or(false),
+                value: top.val.unwrap_or(0.0),
+                threshold: top.filter_value,
+            });
+        }
+        if let Some(color) = &raw.color_filter {
+            return Some(Self::Color {
+                dxf_id: color.dxf_id,
+                cell_color: color.cell_color.unwrap_or(true),
+            });
+        }
+        if let Some(icon) = &raw.icon_filter {
+            return Some(Self::Icon {
+                icon_set: icon.icon_set.clone(),
+                icon_id: icon.icon_id,
+            });
+        }
+        return None;
+    }
+
+    fn from_raw_group(group: &XlsxFilterCriteriaGroup) -> Self {
+        return Self::Values {
+            values: group
+                .value_filters
+                .iter()
+                .flatten()
+                .filter_map(|f| f.filter_value.clone())
+                .collect(),
+            date_groups: group
+                .date_group_item
+                .iter()
+                .flatten()
+                .map(DateGroup::from_raw)
+                .collect(),
+            blank: group.filter_by_blank.unwrap_or(false),
+        };
+    }
+}
+
+/// A condition of a custom filter, ie: `greaterThan` `0.5`.
+///
+/// Values of `equal` and `notEqual` conditions may use the `*` and `?` wildcards.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CustomFilterCondition {
+    /// ie: `equal`, `lessThan`, `greaterThanOrEqual`, `notEqual`
+    pub operator: String,
+    pub value: String,
+}
+
+/// Dates within a year, month, day... selected in a filter.
+///
+/// Fields below the grouping level are None, ie: only `year` and `month` for a `month` grouping.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct DateGroup {
+    /// ie: `year`, `month`, `day`, `hour`, `minute`, `second`
+    pub grouping: String,
+    pub year: Option<u64>,
+    pub month: Option<u64>,
+    pub day: Option<u64>,
+    pub hour: Option<u64>,
+    pub minute: Option<u64>,
+    pub second: Option<u64>,
+}
+
+impl DateGroup {
+    fn from_raw(raw: &XlsxDateGroupItem) -> Self {
+        return Self {
+            grouping: raw.grouping_level.clone().unwrap_or_default(),
+            year: raw.year,
+            month: raw.month,
+            day: raw.day,
+            hour: raw.hour,
+            minute: raw.minute,
+            second: raw.second,
+        };
+    }
+}
+
+/// Sort applied to the AutoFilter range.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SortState {
+    /// Range sorted, header row excluded
+    pub dimension: Option<Dimension>,
+
+    pub case_sensitive: bool,
+
+    /// Columns are sorted left to right, instead of rows top to bottom.
+    pub column_sort: bool,
+
+    /// Sort keys, the first one applied first
+    pub conditions: Vec<SortCondition>,
+}
+
+impl SortState {
+    pub(crate) fn from_raw(raw: &XlsxSortState) -> Self {
+        return Self {
+            dimension: raw.r#ref,
+            case_sensitive: raw.case_sensitive.unwrap_or(false),
+            column_sort: raw.column_sort.unwrap_or(false),
+            conditions: raw
+                .sort_condition
+                .iter()
+                .flatten()
+                .map(SortCondition::from_raw)
+                .collect(),
+        };
+    }
+}
+
+/// A sort key of a sort state.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SortCondition {
+    /// Range of the sort key, ie: the cells of a column
+    pub dimension: Option<Dimension>,
+
+    pub descending: bool,
+
+    /// ie: `value`, `cellColor`, `fontColor`, `icon`
+    pub sort_by: String,
+
+    /// Custom list of values defining the order, ie: `Low,Medium,High`
+    pub custom_list: Option<String>,
+}
+
+impl SortCondition {
+    fn from_raw(raw: &XlsxSortCondition) -> Self {
+        return Self {
+            dimension: raw.r#ref,
+            descending: raw.descending.unwrap_or(false),
+            sort_by: raw.sort_by.clone().unwrap_or("value".to_string()),
+            custom_list: raw.custom_list.clone(),
+        };
+    }
+}
+
+fn format_datetime(datetime: &XlsxDatetime) -> String {
+    return datetime.datetime.format("%Y-%m-%dT%H:%M:%S").to_string();
+}
@@ -1,5 +1,5 @@
 #[cfg(feature = "serde")]
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 use crate::{
     common_types::HexColor,
@@ -42,7 +42,7 @@ static DEFAULT_BORDER_COLOR: &str = "000000ff";
 /// </borders>
 /// ```
 #[derive(Debug, Clone, PartialEq)]
-#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Border {
     pub left: BorderStyle,
 
@@ -128,7 +128,7 @@ impl Border {
 }
 
 #[derive(Debug, Clone, PartialEq)]
-#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct BorderStyle {
     /// The line style for this border
     pub style: BorderStyleValue,
@@ -184,7 +184,7 @@ impl BorderStyle {
 /// * Thick
 /// * Thin
 #[derive(Debug, Clone, PartialEq)]
-#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum BorderStyleValue {
     DashDot,
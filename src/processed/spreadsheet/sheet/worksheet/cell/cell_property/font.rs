@@ -1,5 +1,5 @@
 #[cfg(feature = "serde")]
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 use crate::{
     common_types::HexColor,
@@ -38,7 +38,7 @@ static DEFAULT_FONT_SIZE: f64 = 11.0;
 /// </fonts>
 /// ```
 #[derive(Debug, Clone, PartialEq)]
-#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Font {
     /// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.bold?view=openxml-3.0.1
     pub bold: bool,
@@ -134,13 +134,19 @@ impl Font {
         };
     }
 
+    /// Font of a rich text run.
+    ///
+    /// Runs without properties take the base font, ie: the font of their cell.
+    /// Name, size, color, family and scheme left out of the properties are also taken from the base font,
+    /// while other flags default to false as Excel displays them.
     pub(crate) fn from_raw_run_properties(
         r_pr: Option<XlsxRunProperties>,
+        base: &Font,
         stylesheet_colors: Option<XlsxStyleSheetColors>,
         color_scheme: Option<XlsxColorScheme>,
     ) -> Self {
         let Some(r_pr) = r_pr else {
-            return Self::default();
+            return base.clone();
         };
         let color_hex: Option<HexColor> = match r_pr.color {
             Some(c) => c.to_hex(stylesheet_colors, color_scheme),
@@ -149,17 +155,23 @@ impl Font {
 
         return Self {
             bold: r_pr.bold.unwrap_or(false),
-            color: color_hex.unwrap_or(DEFAULT_TEXT_COLOR.to_string()),
+            color: color_hex.unwrap_or(base.color.clone()),
             condense: r_pr.condense.unwrap_or(false),
             extend: r_pr.extend.unwrap_or(false),
-            family: FontFamilyValue::from_index(r_pr.family),
+            family: match r_pr.family {
+                Some(_) => FontFamilyValue::from_index(r_pr.family),
+                None => base.family.clone(),
+            },
             italic: r_pr.italic.unwrap_or(false),
-            name: r_pr.run_font.unwrap_or(DEFAULT_FONT_NAME.to_string()),
+            name: r_pr.run_font.unwrap_or(base.name.clone()),
             outline: r_pr.outline.unwrap_or(false),
-            scheme: FontSchemeValue::from_string(r_pr.scheme),
+            scheme: match r_pr.scheme {
+                Some(_) => FontSchemeValue::from_string(r_pr.scheme),
+                None => base.scheme.clone(),
+            },
             shadow: r_pr.shadow.unwrap_or(false),
             strike: r_pr.strike.unwrap_or(false),
-            size: r_pr.size.unwrap_or(DEFAULT_FONT_SIZE),
+            size: r_pr.size.unwrap_or(base.size),
             underline: UnderlineValue::from_string(r_pr.underline),
             vertical_alignment: VerticalAlignmentRunValue::from_string(r_pr.vert_align),
         };
@@ -180,7 +192,7 @@ impl Font {
 /// * 4: Script
 /// * 5: Decorative
 #[derive(Debug, Clone, PartialEq)]
-#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum FontFamilyValue {
     NotApplicable,
@@ -216,7 +228,7 @@ impl FontFamilyValue {
 /// * Single
 /// * SingleAccounting
 #[derive(Debug, Clone, PartialEq)]
-#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum UnderlineValue {
     Double,
@@ -246,7 +258,7 @@ impl UnderlineValue {
 /// * Minor
 /// * None
 #[derive(Debug, Clone, PartialEq)]
-#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum FontSchemeValue {
     Major,
@@ -272,7 +284,7 @@ impl FontSchemeValue {
 /// * Subscript,
 /// * Superscript
 #[derive(Debug, Clone, PartialEq)]
-#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum VerticalAlignmentRunValue {
     Baseline,
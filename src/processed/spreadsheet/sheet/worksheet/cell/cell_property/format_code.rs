@@ -0,0 +1,1036 @@
+//! Interpreter of number format codes, displaying values the way Excel does.
+//!
+//! https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.numberingformat?view=openxml-3.0.1
+//!
+//! Values are displayed with the conventions of the `en-US` locale: `.` as decimal point, `,` as thousands separator, English month and day names.
+
+use chrono::{Datelike, Duration, NaiveDate, NaiveDateTime, NaiveTime};
+
+use crate::dates::to_serial;
+
+static MONTH_NAMES: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+static DAY_NAMES: [&str; 7] = [
+    "Sunday",
+    "Monday",
+    "Tuesday",
+    "Wednesday",
+    "Thursday",
+    "Friday",
+    "Saturday",
+];
+
+/// Serial number of 10000-01-01 in the 1900 date system, larger serial numbers are not dates.
+static MAX_DATE_SERIAL: f64 = 2_958_466.0;
+
+/// Serial number of 1900-03-01 in the 1900 date system, dates before count the fictitious 1900-02-29.
+static FIRST_SERIAL_AFTER_LEAP_BUG: i64 = 61;
+
+/// Largest denominator tried for fractions, ie: `# ????/????`.
+static MAX_FRACTION_DIGITS: usize = 4;
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    /// text displayed as is: quoted, escaped, bare symbols, spaces of `_x`, currency of `[$€-407]`
+    Literal(String),
+    /// digit placeholder: `0`, `#` or `?`
+    Digit(char),
+    DecimalPoint,
+    /// thousands separator, scaling by 1000, or literal depending on its position
+    Comma,
+    Percent,
+    /// `E+`, `E-`, `e+` or `e-` as written
+    Exponent(String),
+    Slash,
+    /// `@`
+    Text,
+    General,
+    Year(usize),
+    Month(usize),
+    Day(usize),
+    Hour(usize),
+    Minute(usize),
+    Second(usize),
+    /// digits of fractions of a second, ie: `ss.00`
+    SubSecond(usize),
+    /// `AM/PM` or `A/P`, with the text for the morning and for the afternoon as written
+    AmPm(String, String),
+    /// `[h]`, `[mm]` or `[ss]`: total hours, minutes or seconds
+    Elapsed(char, usize),
+}
+
+impl Token {
+    fn is_digit(&self) -> bool {
+        return matches!(self, Token::Digit(_));
+    }
+
+    fn is_date_time(&self) -> bool {
+        return matches!(
+            self,
+            Token::Year(_)
+                | Token::Month(_)
+                | Token::Day(_)
+                | Token::Hour(_)
+                | Token::Minute(_)
+                | Token::Second(_)
+                | Token::SubSecond(_)
+                | Token::AmPm(..)
+                | Token::Elapsed(..)
+        );
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Comparison {
+    Equal,
+    NotEqual,
+    Less,
+    LessOrEqual,
+    Greater,
+    GreaterOrEqual,
+}
+
+/// Condition of a section, ie: `[>=100]`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Condition {
+    comparison: Comparison,
+    value: f64,
+}
+
+impl Condition {
+    fn from_string(content: &str) -> Option<Self> {
+        let operators = [
+            ("<=", Comparison::LessOrEqual),
+            (">=", Comparison::GreaterOrEqual),
+            ("<>", Comparison::NotEqual),
+            ("<", Comparison::Less),
+            (">", Comparison::Greater),
+            ("=", Comparison::Equal),
+        ];
+        for (operator, comparison) in operators {
+            if let Some(value) = content.strip_prefix(operator) {
+                let value: f64 = value.trim().parse().ok()?;
+                return Some(Self { comparison, value });
+            }
+        }
+        return None;
+    }
+
+    fn matches(&self, value: f64) -> bool {
+        return match self.comparison {
+            Comparison::Equal => value == self.value,
+            Comparison::NotEqual => value != self.value,
+            Comparison::Less => value < self.value,
+            Comparison::LessOrEqual => value <= self.value,
+            Comparison::Greater => value > self.value,
+            Comparison::GreaterOrEqual => value >= self.value,
+        };
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Section {
+    tokens: Vec<Token>,
+    condition: Option<Condition>,
+}
+
+impl Section {
+    fn from_string(section: &str) -> Self {
+        let chars: Vec<char> = section.chars().collect();
+        let mut tokens: Vec<Token> = vec![];
+        let mut condition: Option<Condition> = None;
+        let mut index = 0;
+        while index < chars.len() {
+            let c = chars[index];
+            let rest = &chars[index..];
+            index += 1;
+            match c {
+                '"' => {
+                    let literal: String =
+                        chars[index..].iter().take_while(|c| **c != '"').collect();
+                    index += literal.chars().count() + 1;
+                    tokens.push(Token::Literal(literal));
+                }
+                '\\' => {
+                    if let Some(next) = chars.get(index) {
+                        tokens.push(Token::Literal(next.to_string()));
+                        index += 1;
+                    }
+                }
+                // space the width of the next character
+                '_' => {
+                    index += 1;
+                    tokens.push(Token::Literal(" ".to_string()));
+                }
+                // repeating the next character to fill the column has no width to fill here
+                '*' => index += 1,
+                '[' => {
+                    let content: String =
+                        chars[index..].iter().take_while(|c| **c != ']').collect();
+                    index += content.chars().count() + 1;
+                    if let Some(parsed) = Condition::from_string(&content) {
+                        condition = Some(parsed);
+                    } else if let Some(token) = bracket_token(&content) {
+                        tokens.push(token);
+                    }
+                }
+                '0' | '#' | '?' => tokens.push(Token::Digit(c)),
+                '.' => {
+                    let after_seconds = tokens
+                        .iter()
+                        .rev()
+                        .find(|t| t.is_date_time())
+                        .is_some_and(|t| matches!(t, Token::Second(_) | Token::Elapsed('s', _)));
+                    let zeros = chars[index..].iter().take_while(|c| **c == '0').count();
+                    if after_seconds && zeros > 0 {
+                        tokens.push(Token::SubSecond(zeros));
+                        index += zeros;
+                    } else {
+                        tokens.push(Token::DecimalPoint);
+                    }
+                }
+                ',' => tokens.push(Token::Comma),
+                '%' => tokens.push(Token::Percent),
+                '/' => tokens.push(Token::Slash),
+                '@' => tokens.push(Token::Text),
+                'E' | 'e' if matches!(chars.get(index), Some('+' | '-')) => {
+                    tokens.push(Token::Exponent(format!("{}{}", c, chars[index])));
+                    index += 1;
+                }
+                _ if starts_with_ignore_case(rest, "general") => {
+                    tokens.push(Token::General);
+                    index += "general".len() - 1;
+                }
+                _ if starts_with_ignore_case(rest, "am/pm") => {
+                    tokens.push(Token::AmPm(
+                        rest[..2].iter().collect(),
+                        rest[3..5].iter().collect(),
+                    ));
+                    index += "am/pm".len() - 1;
+                }
+                _ if starts_with_ignore_case(rest, "a/p") => {
+                    tokens.push(Token::AmPm(rest[0].to_string(), rest[2].to_string()));
+                    index += "a/p".len() - 1;
+                }
+                'y' | 'Y' | 'm' | 'M' | 'd' | 'D' | 'h' | 'H' | 's' | 'S' => {
+                    let lower = c.to_ascii_lowercase();
+                    let count = 1 + chars[index..]
+                        .iter()
+                        .take_while(|c| c.to_ascii_lowercase() == lower)
+                        .count();
+                    index += count - 1;
+                    tokens.push(match lower {
+                        'y' => Token::Year(count),
+                        'm' => Token::Month(count),
+                        'd' => Token::Day(count),
+                        'h' => Token::Hour(count),
+                        _ => Token::Second(count),
+                    });
+                }
+                _ => tokens.push(Token::Literal(c.to_string())),
+            }
+        }
+        resolve_minutes(&mut tokens);
+        return Self { tokens, condition };
+    }
+
+    fn is_date_time(&self) -> bool {
+        return self.tokens.iter().any(|t| t.is_date_time());
+    }
+
+    fn has_text(&self) -> bool {
+        return self.tokens.contains(&Token::Text);
+    }
+}
+
+/// A parsed number format code, made of up to 4 sections separated by `;`:
+/// positive numbers, negative numbers, zero and text.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct FormatCode {
+    sections: Vec<Section>,
+}
+
+impl FormatCode {
+    pub(crate) fn from_string(code: &str) -> Self {
+        let code = if code.trim().is_empty() {
+            "General"
+        } else {
+            code
+        };
+        return Self {
+            sections: split_sections(code)
+                .iter()
+                .map(|s| Section::from_string(s))
+                .collect(),
+        };
+    }
+
+    /// * is_1904: whether serial dates count from 1904-01-01 instead of 1900-01-01
+    pub(crate) fn format_number(&self, value: f64, is_1904: bool) -> String {
+        if !value.is_finite() {
+            return value.to_string();
+        }
+        let Some((section, value)) = self.choose_section(value) else {
+            return format_general(value);
+        };
+        if section.is_date_time() {
+            return format_date_time(&section.tokens, value, is_1904)
+                .unwrap_or_else(|| format_general(value));
+        }
+        let text = format_number_tokens(&section.tokens, value.abs());
+        if value < 0.0 {
+            return format!("-{}", text);
+        }
+        return text;
+    }
+
+    /// Whether numbers are displayed as dates or times, from the format of positive numbers.
+    pub(crate) fn is_date_time(&self) -> bool {
+        return self.sections.first().is_some_and(|s| s.is_date_time());
+    }
+
+    pub(crate) fn format_text(&self, text: &str) -> String {
+        let section = if self.sections.len() >= 4 {
+            self.sections.get(3)
+        } else {
+            self.sections.last().filter(|s| s.has_text())
+        };
+        let Some(section) = section else {
+            return text.to_string();
+        };
+        return section
+            .tokens
+            .iter()
+            .map(|t| match t {
+                Token::Text => text.to_string(),
+                t => literal_text(t, false),
+            })
+            .collect();
+    }
+
+    /// Sections applying to numbers: every section but the text section.
+    fn numeric_sections(&self) -> &[Section] {
+        let mut count = self.sections.len().min(3);
+        if self.sections.len() < 4 && self.sections.last().is_some_and(|s| s.has_text()) {
+            count = self.sections.len() - 1;
+        }
+        return &self.sections[..count];
+    }
+
+    /// Section displaying the value, with the value to display.
+    ///
+    /// Without conditions, the sections apply to positive numbers, negative numbers and zero.
+    /// With conditions, ie: `[>=100]0;[<0]0;0.00`, the first matching section applies, the last one otherwise.
+    /// Negative numbers are displayed without their sign when there are several sections.
+    fn choose_section(&self, value: f64) -> Option<(&Section, f64)> {
+        let sections = self.numeric_sections();
+        if sections.is_empty() {
+            return None;
+        }
+        let first = sections[0].condition;
+        let second = sections.get(1).and_then(|s| s.condition);
+        let index = if first.is_some() || second.is_some() {
+            if first.is_some_and(|c| c.matches(value)) {
+                0
+            } else if second.is_some_and(|c| c.matches(value)) {
+                1
+            } else if first.is_some() && second.is_some() {
+                2
+            } else {
+                1
+            }
+        } else if value < 0.0 && sections.len() >= 2 {
+            1
+        } else if value == 0.0 && sections.len() >= 3 {
+            2
+        } else {
+            0
+        };
+        let section = sections.get(index)?;
+        let value = if sections.len() > 1 {
+            value.abs()
+        } else {
+            value
+        };
+        return Some((section, value));
+    }
+}
+
+/// General format: up to 11 characters, scientific notation for very large and very small numbers.
+///
+/// ie: `1234.5` -> `1234.5`, `1/3` -> `0.333333333`, `123456789012` -> `1.23457E+11`
+pub(crate) fn format_general(value: f64) -> String {
+    if value == 0.0 || !value.is_finite() {
+        return value.abs().to_string();
+    }
+    let sign = if value < 0.0 { "-" } else { "" };
+    let value = value.abs();
+    let mut exponent = decimal_exponent(value);
+    if (-4..=10).contains(&exponent) {
+        let decimals = if exponent < 0 {
+            9
+        } else {
+            (9 - exponent).max(0)
+        };
+        let (integer, fraction) = round_decimal(value, 0, decimals as usize);
+        let integer = if integer.is_empty() {
+            "0".to_string()
+        } else {
+            integer
+        };
+        let fraction = fraction.trim_end_matches('0');
+        if fraction.is_empty() {
+            return format!("{}{}", sign, integer);
+        }
+        return format!("{}{}.{}", sign, integer, fraction);
+    }
+
+    let (mut integer, mut fraction) = round_decimal(value, -exponent, 5);
+    if integer.len() > 1 {
+        exponent += 1;
+        (integer, fraction) = round_decimal(value, -exponent, 5);
+    }
+    let fraction = fraction.trim_end_matches('0');
+    let mantissa = if fraction.is_empty() {
+        integer
+    } else {
+        format!("{}.{}", integer, fraction)
+    };
+    let exponent_sign = if exponent < 0 { '-' } else { '+' };
+    return format!(
+        "{}{}E{}{:02}",
+        sign,
+        mantissa,
+        exponent_sign,
+        exponent.abs()
+    );
+}
+
+/// Serial number of an ISO 8601 date, time or date time, ie: `2024-01-15T12:00:00` -> `45306.5`.
+pub(crate) fn date_time_to_serial(value: &str, is_1904: bool) -> Option<f64> {
+    let value = value.trim().trim_end_matches('Z');
+    let date_time = NaiveDateTime::parse_from_str(value, "%Y-%m-%dT%H:%M:%S%.f")
+        .ok()
+        .or_else(|| {
+            NaiveDate::parse_from_str(value, "%Y-%m-%d")
+                .ok()
+                .and_then(|d| d.and_hms_opt(0, 0, 0))
+        });
+    let Some(date_time) = date_time else {
+        let time = value.trim_start_matches('T');
+        let time = NaiveTime::parse_from_str(time, "%H:%M:%S%.f").ok()?;
+        let seconds = (time - NaiveTime::MIN).num_milliseconds() as f64 / 1000.0;
+        return Some(seconds / 86_400.0);
+    };
+    return to_serial(date_time, is_1904);
+}
+
+fn starts_with_ignore_case(chars: &[char], pattern: &str) -> bool {
+    let mut chars = chars.iter();
+    return pattern
+        .chars()
+        .all(|p| chars.next().is_some_and(|c| c.to_ascii_lowercase() == p));
+}
+
+/// Split a format code on `;` outside of quotes, escapes and brackets.
+fn split_sections(code: &str) -> Vec<String> {
+    let mut sections: Vec<String> = vec![];
+    let mut section = String::new();
+    let mut in_quotes = false;
+    let mut in_brackets = false;
+    let mut chars = code.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' => in_quotes = !in_quotes,
+            '[' if !in_quotes => in_brackets = true,
+            ']' if !in_quotes => in_brackets = false,
+            '\\' if !in_quotes => {
+                section.push(c);
+                if let Some(next) = chars.next() {
+                    section.push(next);
+                }
+                continue;
+            }
+            ';' if !in_quotes && !in_brackets => {
+                sections.push(std::mem::take(&mut section));
+                continue;
+            }
+            _ => {}
+        }
+        section.push(c);
+    }
+    sections.push(section);
+    return sections;
+}
+
+/// Token of the content of `[...]` other than a condition.
+///
+/// Colors, ie: `[Red]`, `[Color10]`, and locale identifiers, ie: `[$-409]`, are not displayed.
+fn bracket_token(content: &str) -> Option<Token> {
+    if let Some(currency) = content.strip_prefix('$') {
+        let symbol = currency.split('-').next().unwrap_or("");
+        if symbol.is_empty() {
+            return None;
+        }
+        return Some(Token::Literal(symbol.to_string()));
+    }
+    let lower = content.to_ascii_lowercase();
+    let unit = lower.chars().next()?;
+    if matches!(unit, 'h' | 'm' | 's') && lower.chars().all(|c| c == unit) {
+        return Some(Token::Elapsed(unit, lower.len()));
+    }
+    return None;
+}
+
+/// `m` and `mm` are minutes right after hours or right before seconds, months otherwise.
+fn resolve_minutes(tokens: &mut [Token]) {
+    let date_time_indices: Vec<usize> = (0..tokens.len())
+        .filter(|i| tokens[*i].is_date_time() && !matches!(tokens[*i], Token::AmPm(..)))
+        .collect();
+    for (position, index) in date_time_indices.iter().enumerate() {
+        let Token::Month(count) = tokens[*index] else {
+            continue;
+        };
+        if count > 2 {
+            continue;
+        }
+        let after_hours = position > 0
+            && matches!(
+                tokens[date_time_indices[position - 1]],
+                Token::Hour(_) | Token::Elapsed('h', _)
+            );
+        let before_seconds = date_time_indices
+            .get(position + 1)
+            .is_some_and(|next| matches!(tokens[*next], Token::Second(_) | Token::Elapsed('s', _)));
+        if after_hours || before_seconds {
+            tokens[*index] = Token::Minute(count);
+        }
+    }
+}
+
+/// Text of tokens without a value to display.
+fn literal_text(token: &Token, hidden_comma: bool) -> String {
+    return match token {
+        Token::Literal(literal) => literal.clone(),
+        Token::DecimalPoint => ".".to_string(),
+        Token::Comma if !hidden_comma => ",".to_string(),
+        Token::Percent => "%".to_string(),
+        Token::Slash => "/".to_string(),
+        _ => String::new(),
+    };
+}
+
+/// Exponent of the value in scientific notation, after rounding to 15 significant digits.
+fn decimal_exponent(value: f64) -> i32 {
+    let scientific = format!("{:.14e}", value.abs());
+    return scientific
+        .split_once('e')
+        .and_then(|(_, e)| e.parse().ok())
+        .unwrap_or(0);
+}
+
+/// Digits of `value * 10^shift` rounded half away from zero to `decimals` decimal places.
+///
+/// The value is first taken to 15 significant digits like Excel, avoiding binary floating point artifacts, ie: `1.005` -> `1.01`.
+///
+/// Returns the integer digits without leading zeros (empty for zero) and the decimal digits.
+fn round_decimal(value: f64, shift: i32, decimals: usize) -> (String, String) {
+    if value == 0.0 {
+        return (String::new(), "0".repeat(decimals));
+    }
+    let scientific = format!("{:.14e}", value.abs());
+    let (mantissa, exponent) = scientific.split_once('e').unwrap_or((&scientific, "0"));
+    let exponent: i32 = exponent.parse::<i32>().unwrap_or(0) + shift;
+
+    // a leading zero absorbs the carry of rounding up, ie: 9.9 -> 10
+    let mut digits: Vec<u8> = vec![0];
+    digits.extend(
+        mantissa
+            .bytes()
+            .filter(u8::is_ascii_digit)
+            .map(|b| b - b'0'),
+    );
+    let integer_count = exponent + 2;
+    let keep = integer_count + decimals as i32;
+    if keep <= 0 {
+        return (String::new(), "0".repeat(decimals));
+    }
+    let keep = keep as usize;
+    if keep < digits.len() {
+        let round_up = digits[keep] >= 5;
+        digits.truncate(keep);
+        if round_up {
+            for digit in digits.iter_mut().rev() {
+                if *digit == 9 {
+                    *digit = 0;
+                } else {
+                    *digit += 1;
+                    break;
+                }
+            }
+        }
+    } else {
+        digits.resize(keep, 0);
+    }
+
+    let to_string =
+        |digits: &[u8]| -> String { digits.iter().map(|d| (b'0' + d) as char).collect() };
+    if integer_count <= 0 {
+        let leading_zeros = "0".repeat(integer_count.unsigned_abs() as usize);
+        return (
+            String::new(),
+            format!("{}{}", leading_zeros, to_string(&digits)),
+        );
+    }
+    let integer_count = integer_count as usize;
+    let integer = to_string(&digits[..integer_count]);
+    return (
+        integer.trim_start_matches('0').to_string(),
+        to_string(&digits[integer_count..]),
+    );
+}
+
+/// Fill integer placeholders from the right: `0` displays a zero, `?` a space and `#` nothing when there are no more digits.
+///
+/// Digits in excess go to the first placeholder.
+fn fill_integer(placeholders: &[char], digits: &str, thousands: bool) -> Vec<String> {
+    let digits: Vec<char> = digits.chars().collect();
+    let mut remaining = digits.len();
+    let mut filled = vec![String::new(); placeholders.len()];
+    for (index, placeholder) in placeholders.iter().enumerate().rev() {
+        if remaining > 0 {
+            remaining -= 1;
+            filled[index] = digits[remaining].to_string();
+        } else {
+            filled[index] = match placeholder {
+                '0' => "0".to_string(),
+                '?' => " ".to_string(),
+                _ => String::new(),
+            };
+        }
+    }
+    if let Some(first) = filled.first_mut() {
+        let excess: String = digits[..remaining].iter().collect();
+        first.insert_str(0, &excess);
+    }
+    if !thousands || filled.is_empty() {
+        return filled;
+    }
+
+    let joined: String = filled.concat();
+    let mut grouped: Vec<char> = vec![];
+    let mut digit_count = 0;
+    for c in joined.chars().rev() {
+        if c.is_ascii_digit() {
+            if digit_count > 0 && digit_count % 3 == 0 {
+                grouped.push(',');
+            }
+            digit_count += 1;
+        }
+        grouped.push(c);
+    }
+    let mut filled = vec![String::new(); placeholders.len()];
+    filled[0] = grouped.into_iter().rev().collect();
+    return filled;
+}
+
+/// Fill decimal placeholders from the left, trailing zeros being displayed as nothing for `#` and as spaces for `?`.
+fn fill_fraction(placeholders: &[char], digits: &str) -> Vec<String> {
+    let mut filled: Vec<String> = digits.chars().map(|d| d.to_string()).collect();
+    filled.resize(placeholders.len(), "0".to_string());
+    for (index, placeholder) in placeholders.iter().enumerate().rev() {
+        if filled[index] != "0" || *placeholder == '0' {
+            break;
+        }
+        filled[index] = if *placeholder == '?' {
+            " ".to_string()
+        } else {
+            String::new()
+        };
+    }
+    return filled;
+}
+
+fn placeholders(tokens: &[Token], indices: &[usize]) -> Vec<char> {
+    return indices
+        .iter()
+        .map(|i| match tokens[*i] {
+            Token::Digit(c) => c,
+            _ => '0',
+        })
+        .collect();
+}
+
+/// Display a positive number with the tokens of a number section.
+fn format_number_tokens(tokens: &[Token], value: f64) -> String {
+    if tokens.contains(&Token::General) {
+        return tokens
+            .iter()
+            .map(|t| match t {
+                Token::General => format_general(value),
+                t => literal_text(t, false),
+            })
+            .collect();
+    }
+
+    let percent_count = tokens.iter().filter(|t| **t == Token::Percent).count() as i32;
+    let exponent_index = tokens.iter().position(|t| matches!(t, Token::Exponent(_)));
+    let number_end = exponent_index.unwrap_or(tokens.len());
+
+    // commas between integer placeholders group thousands, commas following the last placeholder scale by 1000
+    let mut thousands = false;
+    let mut scale_count = 0;
+    let mut hidden_commas = vec![false; tokens.len()];
+    for index in 0..number_end {
+        if tokens[index] != Token::Comma || !tokens[..index].iter().any(|t| t.is_digit()) {
+            continue;
+        }
+        hidden_commas[index] = true;
+        let digit_after = tokens[index + 1..number_end].iter().any(|t| t.is_digit());
+        let in_decimals = tokens[..index].contains(&Token::DecimalPoint);
+        if !digit_after {
+            scale_count += 1;
+        } else if !in_decimals {
+            thousands = true;
+        }
+    }
+    let shift = 2 * percent_count - 3 * scale_count;
+
+    if exponent_index.is_none() {
+        if let Some(slash) = fraction_slash(tokens) {
+            let scaled = value * 10f64.powi(shift);
+            return format_fraction(tokens, scaled, slash, &hidden_commas);
+        }
+    }
+
+    let point = tokens[..number_end]
+        .iter()
+        .position(|t| *t == Token::DecimalPoint)
+        .unwrap_or(number_end);
+    let integer_slots: Vec<usize> = (0..point).filter(|i| tokens[*i].is_digit()).collect();
+    let decimal_slots: Vec<usize> = (point..number_end)
+        .filter(|i| tokens[*i].is_digit())
+        .collect();
+
+    let mut exponent = 0;
+    let (mut integer, mut decimals) = round_decimal(value, shift, decimal_slots.len());
+    if exponent_index.is_some() && value != 0.0 {
+        let width = integer_slots.len().max(1) as i32;
+        let engineering = width > 1
+            && integer_slots
+                .iter()
+                .any(|i| tokens[*i] == Token::Digit('#'));
+        let magnitude = decimal_exponent(value) + shift;
+        exponent = if engineering {
+            magnitude.div_euclid(width) * width
+        } else {
+            magnitude - (width - 1)
+        };
+        (integer, decimals) = round_decimal(value, shift - exponent, decimal_slots.len());
+        if integer.len() > width as usize {
+            exponent += if engineering { width } else { 1 };
+            (integer, decimals) = round_decimal(value, shift - exponent, decimal_slots.len());
+        }
+    }
+
+    let mut output: Vec<String> = tokens
+        .iter()
+        .enumerate()
+        .map(|(i, t)| literal_text(t, hidden_commas[i]))
+        .collect();
+    if integer_slots.is_empty() {
+        // no integer placeholder, ie: `.00`: the integer part is still displayed
+        if let Some(first) = output.get_mut(point) {
+            first.insert_str(0, &integer);
+        }
+    } else {
+        let filled = fill_integer(&placeholders(tokens, &integer_slots), &integer, thousands);
+        for (slot, text) in integer_slots.iter().zip(filled) {
+            output[*slot] = text;
+        }
+    }
+    let filled = fill_fraction(&placeholders(tokens, &decimal_slots), &decimals);
+    for (slot, text) in decimal_slots.iter().zip(filled) {
+        output[*slot] = text;
+    }
+
+    if let Some(exponent_index) = exponent_index {
+        let Token::Exponent(symbol) = &tokens[exponent_index] else {
+            return output.concat();
+        };
+        let mut symbol = symbol.chars();
+        let e = symbol.next().unwrap_or('E');
+        let sign = match (symbol.next(), exponent < 0) {
+            (_, true) => "-",
+            (Some('+'), false) => "+",
+            _ => "",
+        };
+        output[exponent_index] = format!("{}{}", e, sign);
+        let exponent_slots: Vec<usize> = (exponent_index + 1..tokens.len())
+            .filter(|i| tokens[*i].is_digit())
+            .collect();
+        let filled = fill_integer(
+            &placeholders(tokens, &exponent_slots),
+            &exponent.unsigned_abs().to_string(),
+            false,
+        );
+        for (slot, text) in exponent_slots.iter().zip(filled) {
+            output[*slot] = text;
+        }
+    }
+    return output.concat();
+}
+
+/// Index of the `/` of a fraction: following a digit placeholder and followed by a placeholder or a digit.
+fn fraction_slash(tokens: &[Token]) -> Option<usize> {
+    let slash = tokens.iter().position(|t| *t == Token::Slash)?;
+    if slash == 0 || !tokens[slash - 1].is_digit() {
+        return None;
+    }
+    return match tokens.get(slash + 1)? {
+        Token::Digit(_) => Some(slash),
+        Token::Literal(literal) if literal.chars().all(|c| c.is_ascii_digit()) => Some(slash),
+        _ => None,
+    };
+}
+
+/// Display a positive number as a fraction, ie: `# ?/?`, `?/8`, `# ??/100`.
+///
+/// The denominator is either fixed, or the one giving the closest fraction with as many digits as placeholders.
+fn format_fraction(tokens: &[Token], value: f64, slash: usize, hidden_commas: &[bool]) -> String {
+    let numerator_start = (0..slash)
+        .rev()
+        .take_while(|i| tokens[*i].is_digit())
+        .last()
+        .unwrap_or(slash);
+    let numerator_slots: Vec<usize> = (numerator_start..slash).collect();
+    let integer_slots: Vec<usize> = (0..numerator_start)
+        .filter(|i| tokens[*i].is_digit())
+        .collect();
+
+    // denominator: placeholders, or a fixed number with `0` read as a digit, ie: `?/10`
+    let mut denominator_end = slash + 1;
+    let mut fixed_denominator = String::new();
+    if matches!(tokens.get(slash + 1), Some(Token::Literal(_))) {
+        while let Some(token) = tokens.get(denominator_end) {
+            match token {
+                Token::Literal(l) if l.chars().all(|c| c.is_ascii_digit()) => {
+                    fixed_denominator.push_str(l)
+                }
+                Token::Digit('0') => fixed_denominator.push('0'),
+                _ => break,
+            }
+            denominator_end += 1;
+        }
+    } else {
+        while tokens.get(denominator_end).is_some_and(|t| t.is_digit()) {
+            denominator_end += 1;
+        }
+    }
+    let denominator_slots: Vec<usize> = (slash + 1..denominator_end).collect();
+
+    let has_integer = !integer_slots.is_empty();
+    let (mut whole, fraction) = if has_integer {
+        (value.trunc(), value.fract())
+    } else {
+        (0.0, value)
+    };
+    let (mut numerator, denominator) = match fixed_denominator.parse::<f64>() {
+        Ok(denominator) if denominator > 0.0 => ((fraction * denominator).round(), denominator),
+        _ => {
+            let digit_count = denominator_slots.len().clamp(1, MAX_FRACTION_DIGITS);
+            closest_fraction(fraction, 10u32.pow(digit_count as u32) - 1)
+        }
+    };
+    if has_integer && numerator >= denominator {
+        whole += 1.0;
+        numerator = 0.0;
+    }
+
+    let mut output: Vec<String> = tokens
+        .iter()
+        .enumerate()
+        .map(|(i, t)| literal_text(t, hidden_commas[i]))
+        .collect();
+    if has_integer {
+        let integer = if whole == 0.0 && numerator == 0.0 {
+            "0".to_string()
+        } else if whole == 0.0 {
+            String::new()
+        } else {
+            format!("{:.0}", whole)
+        };
+        let filled = fill_integer(&placeholders(tokens, &integer_slots), &integer, false);
+        for (slot, text) in integer_slots.iter().zip(filled) {
+            output[*slot] = text;
+        }
+    }
+    let filled = fill_integer(
+        &placeholders(tokens, &numerator_slots),
+        &format!("{:.0}", numerator),
+        false,
+    );
+    for (slot, text) in numerator_slots.iter().zip(filled) {
+        output[*slot] = text;
+    }
+    if fixed_denominator.is_empty() {
+        let denominator = format!("{:.0}", denominator);
+        let filled = fill_fraction(&placeholders(tokens, &denominator_slots), "");
+        let mut digits = denominator.chars();
+        for (slot, blank) in denominator_slots.iter().zip(filled) {
+            output[*slot] = digits.next().map(|d| d.to_string()).unwrap_or(blank);
+        }
+        if let Some(last) = denominator_slots.last() {
+            output[*last].extend(digits);
+        }
+    }
+
+    // a whole number hides its fraction behind spaces of the same width
+    if has_integer && numerator == 0.0 {
+        for text in output[numerator_start..denominator_end].iter_mut() {
+            *text = " ".repeat(text.chars().count());
+        }
+    }
+    return output.concat();
+}
+
+/// Closest fraction to a value with a denominator up to `max_denominator`, the smallest denominator among equally close ones.
+fn closest_fraction(value: f64, max_denominator: u32) -> (f64, f64) {
+    let mut best = (value.round(), 1.0);
+    let mut best_error = (value - best.0).abs();
+    for denominator in 2..=max_denominator {
+        if best_error == 0.0 {
+            break;
+        }
+        let denominator = denominator as f64;
+        let numerator = (value * denominator).round();
+        let error = (value - numerator / denominator).abs();
+        if error < best_error - f64::EPSILON {
+            best = (numerator, denominator);
+            best_error = error;
+        }
+    }
+    return best;
+}
+
+/// Display a serial number with the tokens of a date or time section.
+///
+/// None for serial numbers that are not dates: negative or after year 9999.
+fn format_date_time(tokens: &[Token], value: f64, is_1904: bool) -> Option<String> {
+    if value < 0.0 || value >= MAX_DATE_SERIAL {
+        return None;
+    }
+
+    // rounded to the fractions of a second displayed
+    let sub_second_digits = tokens
+        .iter()
+        .filter_map(|t| match t {
+            Token::SubSecond(count) => Some((*count).min(3)),
+            _ => None,
+        })
+        .max()
+        .unwrap_or(0);
+    let ticks_per_second = 10i64.pow(sub_second_digits as u32);
+    let ticks_per_day = 86_400 * ticks_per_second;
+    let total_ticks = (value * ticks_per_day as f64).round() as i64;
+    let days = total_ticks.div_euclid(ticks_per_day);
+    let day_ticks = total_ticks.rem_euclid(ticks_per_day);
+    let day_seconds = day_ticks / ticks_per_second;
+    let sub_second = day_ticks % ticks_per_second;
+    let total_seconds = total_ticks / ticks_per_second;
+    let (hour, minute, second) = (day_seconds / 3600, day_seconds / 60 % 60, day_seconds % 60);
+
+    let (year, month, day) = serial_to_date(days, is_1904)?;
+    // 1900-01-01 is displayed as a Sunday, like the weekdays Excel computes
+    let weekday = (days + if is_1904 { 1462 } else { 0 } + 6).rem_euclid(7) as usize;
+    let twelve_hours = tokens.iter().any(|t| matches!(t, Token::AmPm(..)));
+
+    let pad = |number: i64, count: usize| -> String {
+        if count >= 2 {
+            format!("{:02}", number)
+        } else {
+            number.to_string()
+        }
+    };
+    let text = tokens
+        .iter()
+        .map(|token| match token {
+            Token::Year(count) if *count <= 2 => format!("{:02}", year % 100),
+            Token::Year(_) => format!("{:04}", year),
+            Token::Month(3) => MONTH_NAMES[month as usize - 1][..3].to_string(),
+            Token::Month(5) => MONTH_NAMES[month as usize - 1][..1].to_string(),
+            Token::Month(count) if *count >= 4 => MONTH_NAMES[month as usize - 1].to_string(),
+            Token::Month(count) => pad(month as i64, *count),
+            Token::Day(3) => DAY_NAMES[weekday][..3].to_string(),
+            Token::Day(count) if *count >= 4 => DAY_NAMES[weekday].to_string(),
+            Token::Day(count) => pad(day as i64, *count),
+            Token::Hour(count) if twelve_hours => pad((hour + 11) % 12 + 1, *count),
+            Token::Hour(count) => pad(hour, *count),
+            Token::Minute(count) => pad(minute, *count),
+            Token::Second(count) => pad(second, *count),
+            Token::SubSecond(count) => {
+                let digits = format!("{:0width$}", sub_second, width = sub_second_digits);
+                format!(
+                    ".{:0<width$}",
+                    &digits[..(*count).min(digits.len())],
+                    width = *count
+                )
+            }
+            Token::AmPm(morning, afternoon) => {
+                if hour < 12 {
+                    morning.clone()
+                } else {
+                    afternoon.clone()
+                }
+            }
+            Token::Elapsed(unit, count) => {
+                let elapsed = match unit {
+                    'h' => total_seconds / 3600,
+                    'm' => total_seconds / 60,
+                    _ => total_seconds,
+                };
+                format!("{:0width$}", elapsed, width = *count)
+            }
+            Token::Digit(c) => c.to_string(),
+            Token::Comma => ",".to_string(),
+            Token::General => format_general(value),
+            t => literal_text(t, false),
+        })
+        .collect();
+    return Some(text);
+}
+
+/// Year, month and day of a serial number.
+///
+/// In the 1900 date system, serial 0 is displayed as 1900-01-00 and serial 60 as the fictitious 1900-02-29.
+fn serial_to_date(days: i64, is_1904: bool) -> Option<(i32, u32, u32)> {
+    if is_1904 {
+        let date = NaiveDate::from_ymd_opt(1904, 1, 1)? + Duration::days(days);
+        return Some((date.year(), date.month(), date.day()));
+    }
+    match days {
+        0 => return Some((1900, 1, 0)),
+        60 => return Some((1900, 2, 29)),
+        _ => {}
+    }
+    let epoch = if days < FIRST_SERIAL_AFTER_LEAP_BUG {
+        NaiveDate::from_ymd_opt(1899, 12, 31)?
+    } else {
+        NaiveDate::from_ymd_opt(1899, 12, 30)?
+    };
+    let date = epoch + Duration::days(days);
+    return Some((date.year(), date.month(), date.day()));
+}
@@ -1,19 +1,21 @@
 pub mod border;
 pub mod fill;
 pub mod font;
+pub(crate) mod format_code;
 pub mod numbering_format;
 pub mod text_alignment;
 
 #[cfg(feature = "serde")]
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 use border::Border;
-use fill::Fill;
+use fill::{Fill, PatternFillTypeValue};
 use font::Font;
 use numbering_format::NumberingFormat;
 use text_alignment::TextAlignment;
 
 use crate::{
+    common_types::HexColor,
     processed::shared::hyperlink::Hyperlink,
     raw::{
         drawing::scheme::color_scheme::XlsxColorScheme,
@@ -35,17 +37,17 @@ use crate::{
 
 static DEFAULT_CELL_WIDTH: f64 = 48.75; // 65 px or a baseColumnWidth of 10
 static DEFAULT_BEST_FIT: bool = false;
-static DEFAULT_CELL_HEIGHT: f64 = 15.0;
+pub(crate) static DEFAULT_CELL_HEIGHT: f64 = 15.0;
 static DEFAULT_DY_DESCENT: f64 = 0.2;
 static DEFAULT_HIDDEN: bool = false;
 static DEFAULT_SHOW_PHONETIC: bool = true;
 
 #[derive(Debug, Clone, PartialEq)]
-#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CellProperty {
     /// cell width in points
     ///
-    /// Approximate with [MAX_DIGIT_WIDTH] using Aptos of font size 12 pt
+    /// Approximate with [DEFAULT_MAX_DIGIT_WIDTH](crate::units::DEFAULT_MAX_DIGIT_WIDTH) using Aptos of font size 12 pt
     pub width: f64,
 
     /// Flag indicating if the column width should automatically resize
@@ -61,7 +63,7 @@ pub struct CellProperty {
 
     pub show_phonetic: bool,
 
-    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    /// Always serialized, even when None, so that non self describing formats such as bincode can read it back.
     pub hyperlink: Option<Hyperlink>,
 
     // styles
@@ -90,6 +92,17 @@ impl CellProperty {
         };
     }
 
+    /// Background color of the cell: foreground color of a pattern fill, None without fill or for gradient fills.
+    pub fn fill_color(&self) -> Option<&HexColor> {
+        let Fill::PatternFill(pattern_fill) = &self.fill else {
+            return None;
+        };
+        if pattern_fill.pattern_type == PatternFillTypeValue::None {
+            return None;
+        }
+        return pattern_fill.foreground_color.as_ref();
+    }
+
     pub(crate) fn from_raw(
         cell: XlsxCell,
         row_info: XlsxRow,
@@ -161,7 +174,7 @@ impl CellProperty {
         return DEFAULT_DY_DESCENT;
     }
 
-    fn get_font(
+    pub(crate) fn get_font(
         font_id: Option<u64>,
         stylesheet: &XlsxStyleSheet,
         color_scheme: Option<XlsxColorScheme>,
@@ -176,7 +189,7 @@ impl CellProperty {
         return Font::default();
     }
 
-    fn get_border(
+    pub(crate) fn get_border(
         border_id: Option<u64>,
         stylesheet: &XlsxStyleSheet,
         color_scheme: Option<XlsxColorScheme>,
@@ -191,7 +204,7 @@ impl CellProperty {
         return Border::default();
     }
 
-    fn get_fill(
+    pub(crate) fn get_fill(
         fill_id: Option<u64>,
         stylesheet: &XlsxStyleSheet,
         color_scheme: Option<XlsxColorScheme>,
@@ -226,7 +239,7 @@ impl CellProperty {
         };
     }
 
-    fn cell_width(
+    pub(crate) fn cell_width(
         col_info: Option<XlsxColumnInformation>,
         sheet_format_properties: Option<XlsxSheetFormatProperties>,
     ) -> f64 {
@@ -1,6 +1,7 @@
 #[cfg(feature = "serde")]
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
+use super::format_code::FormatCode;
 use crate::raw::spreadsheet::stylesheet::{
     format::numbering_format::get_builtin_format_code, XlsxStyleSheet,
 };
@@ -8,9 +9,25 @@ use crate::raw::spreadsheet::stylesheet::{
 static DEFAULT_FORMAT_CODE: &str = "general";
 static DEFAULT_FORMAT_ID: u64 = 0;
 
-/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.numberingformat?view=openxml-3.0.1
+/// Symbols recognized as currency when they appear literally in a format code.
+pub(crate) static CURRENCY_SYMBOLS: &[char] = &[
+    '$', '¢', '£', '¥', '€', '₩', '₪', '₫', '₱', '₴', '₹', '₺', '₽', '฿', '₦',
+];
+
+/// Unit implied by a number format.
 #[derive(Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum NumberUnit {
+    /// Displayed value is the stored value multiplied by 100.
+    Percentage,
+    /// Currency with its symbol, ie: `$`, `€`, `USD`.
+    Currency(String),
+}
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.numberingformat?view=openxml-3.0.1
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct NumberingFormat {
     pub format_code: Option<String>,
     pub format_id: u64,
@@ -42,4 +59,165 @@ impl NumberingFormat {
             format_id: num_format_id,
         };
     }
+
+    /// Text of a number as Excel displays it with this format, ie: `1234.5` with `#,##0.00` -> `1,234.50`.
+    ///
+    /// Dates and times are displayed from their serial number, ie: `45306.5` with `yyyy-mm-dd hh:mm` -> `2024-01-15 12:00`.
+    ///
+    /// * is_1904: whether the workbook uses the 1904 date system, see `Worksheet::is_1904`
+    pub fn format_number(&self, value: f64, is_1904: bool) -> String {
+        let code = self.format_code.as_deref().unwrap_or("");
+        FormatCode::from_string(code).format_number(value, is_1904)
+    }
+
+    /// Text as Excel displays it with this format, ie: `abc` with `"Name: "@` -> `Name: abc`.
+    ///
+    /// Formats without a text section display the text as is.
+    pub fn format_text(&self, text: &str) -> String {
+        let code = self.format_code.as_deref().unwrap_or("");
+        FormatCode::from_string(code).format_text(text)
+    }
+
+    /// Whether the format displays numbers as dates or times, ie: `yyyy-mm-dd`, `h:mm AM/PM`.
+    pub fn is_date_time(&self) -> bool {
+        let code = self.format_code.as_deref().unwrap_or("");
+        FormatCode::from_string(code).is_date_time()
+    }
+
+    /// Unit implied by the format used for positive numbers (the first section).
+    ///
+    /// Percentage takes precedence over currency.
+    pub fn unit(&self) -> Option<NumberUnit> {
+        if self.is_percentage() {
+            return Some(NumberUnit::Percentage);
+        }
+        self.currency_symbol().map(NumberUnit::Currency)
+    }
+
+    /// Whether the format displays numbers as percentage, ie: `0.00%`.
+    ///
+    /// `%` within quotes or escaped is a literal and does not scale the value.
+    pub fn is_percentage(&self) -> bool {
+        format_tokens(&self.first_section())
+            .iter()
+            .any(|t| *t == FormatToken::Percent)
+    }
+
+    /// Currency symbol of the format.
+    ///
+    /// Either from a locale currency block, ie: `[$€-407]`, or a currency symbol displayed literally.
+    pub fn currency_symbol(&self) -> Option<String> {
+        for token in format_tokens(&self.first_section()) {
+            match token {
+                FormatToken::Bracket(content) => {
+                    let Some(currency) = content.strip_prefix('$') else {
+                        continue;
+                    };
+                    let symbol = currency.split('-').next().unwrap_or("");
+                    if !symbol.is_empty() {
+                        return Some(symbol.to_string());
+                    }
+                }
+                FormatToken::Literal(literal) => {
+                    if let Some(c) = literal.chars().find(|c| CURRENCY_SYMBOLS.contains(c)) {
+                        return Some(c.to_string());
+                    }
+                }
+                FormatToken::Percent => {}
+            }
+        }
+        None
+    }
+
+    /// Text displayed after the number by the format used for positive numbers, trimmed, ie: `0.0" kg"` -> `kg`.
+    ///
+    /// None for dates and times, and for formats ending with the number, a currency or a percentage, see `unit`.
+    pub fn unit_suffix(&self) -> Option<String> {
+        if self.is_date_time() {
+            return None;
+        }
+        let mut suffix = String::new();
+        let section = self.first_section();
+        let mut chars = section.chars();
+        while let Some(c) = chars.next() {
+            match c {
+                '0' | '#' | '?' => suffix.clear(),
+                '"' => suffix.extend(chars.by_ref().take_while(|c| *c != '"')),
+                '\\' => suffix.extend(chars.next()),
+                '[' => chars.by_ref().take_while(|c| *c != ']').for_each(drop),
+                '_' | '*' => {
+                    chars.next();
+                }
+                _ => {}
+            }
+        }
+        let suffix = suffix.trim();
+        if suffix.is_empty() || suffix.chars().any(|c| CURRENCY_SYMBOLS.contains(&c)) {
+            return None;
+        }
+        Some(suffix.to_string())
+    }
+
+    fn first_section(&self) -> String {
+        let code = self.format_code.clone().unwrap_or_default();
+        let mut section = String::new();
+        let mut in_quotes = false;
+        let mut chars = code.chars();
+        while let Some(c) = chars.next() {
+            match c {
+                '"' => in_quotes = !in_quotes,
+                '\\' => {
+                    section.push(c);
+                    if let Some(next) = chars.next() {
+                        section.push(next);
+                    }
+                    continue;
+                }
+                ';' if !in_quotes => break,
+                _ => {}
+            }
+            section.push(c);
+        }
+        section
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum FormatToken {
+    /// `%` scaling the value
+    Percent,
+    /// content of `[...]`
+    Bracket(String),
+    /// text displayed as is: quoted, escaped or bare symbols
+    Literal(String),
+}
+
+fn format_tokens(section: &str) -> Vec<FormatToken> {
+    let mut tokens: Vec<FormatToken> = vec![];
+    let mut chars = section.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '%' => tokens.push(FormatToken::Percent),
+            '"' => {
+                let literal: String = chars.by_ref().take_while(|c| *c != '"').collect();
+                tokens.push(FormatToken::Literal(literal));
+            }
+            '[' => {
+                let content: String = chars.by_ref().take_while(|c| *c != ']').collect();
+                tokens.push(FormatToken::Bracket(content));
+            }
+            '\\' => {
+                if let Some(next) = chars.next() {
+                    tokens.push(FormatToken::Literal(next.to_string()));
+                }
+            }
+            // padding and repeat characters are not displayed as is
+            '_' | '*' => {
+                chars.next();
+            }
+            c if CURRENCY_SYMBOLS.contains(&c) => tokens.push(FormatToken::Literal(c.to_string())),
+            _ => {}
+        }
+    }
+    tokens
 }
@@ -1,5 +1,5 @@
 #[cfg(feature = "serde")]
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 use crate::raw::spreadsheet::stylesheet::format::alignment::XlsxAlignment;
 
@@ -7,7 +7,7 @@ use crate::raw::spreadsheet::stylesheet::format::alignment::XlsxAlignment;
 ///
 /// Formatting information pertaining to text alignment in cells.
 #[derive(Debug, Clone, PartialEq)]
-#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct TextAlignment {
     /// Specifies the type of horizontal alignment in cells.
     pub horizontal_alignment: HorizontalAlignementValue,
@@ -98,7 +98,7 @@ impl TextAlignment {
 // * Left
 // * Right
 #[derive(Debug, Clone, PartialEq)]
-#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum HorizontalAlignementValue {
     Center,
@@ -136,7 +136,7 @@ impl HorizontalAlignementValue {
 /// * Justify
 /// * Top
 #[derive(Debug, Clone, PartialEq)]
-#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum VerticalAlignementValue {
     Bottom,
@@ -163,7 +163,7 @@ impl VerticalAlignementValue {
 /// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.alignment?view=openxml-3.0.1
 ///
 #[derive(Debug, Clone, PartialEq)]
-#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum ReadingOrderValue {
     ContextDependent,
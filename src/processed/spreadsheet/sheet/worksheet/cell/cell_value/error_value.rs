@@ -3,13 +3,13 @@ use std::fmt;
 use anyhow::bail;
 
 #[cfg(feature = "serde")]
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 /// https://msdn.microsoft.com/en-us/library/office/ff839168.aspx
 ///
 /// Errors that can appear as a value in a worksheet cell
 #[derive(Debug, Clone, PartialEq)]
-#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum CellErrorType {
     /// Division by 0 error
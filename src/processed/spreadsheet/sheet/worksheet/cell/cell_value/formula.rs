@@ -1,9 +1,26 @@
+use regex::Regex;
+use std::sync::OnceLock;
+
 #[cfg(feature = "serde")]
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, PartialEq)]
-#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Formula {
     pub formula: String,
     pub last_calculated_value: Option<String>,
 }
+
+impl Formula {
+    /// Link location of a `HYPERLINK("location", "friendly name")` formula.
+    ///
+    /// Only literal string locations are supported, None otherwise.
+    pub fn hyperlink_target(&self) -> Option<String> {
+        static HYPERLINK_RE: OnceLock<Regex> = OnceLock::new();
+        let re = HYPERLINK_RE.get_or_init(|| {
+            Regex::new(r#"(?i)^\s*=?\s*HYPERLINK\(\s*"((?:[^"]|"")*)""#).unwrap()
+        });
+        let caps = re.captures(&self.formula)?;
+        Some(caps[1].replace("\"\"", "\""))
+    }
+}
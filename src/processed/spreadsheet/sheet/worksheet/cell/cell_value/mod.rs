@@ -1,7 +1,9 @@
+use std::fmt;
+
 use anyhow::bail;
 
 #[cfg(feature = "serde")]
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 use error_value::CellErrorType;
 use formula::Formula;
@@ -14,10 +16,11 @@ use super::cell_property::font::Font;
 use crate::{
     common_types::Text,
     helper::string_to_bool,
+    processed::spreadsheet::sheet::worksheet::stored_as_text::parse_stored_as_text,
     raw::{
         drawing::scheme::color_scheme::XlsxColorScheme,
         spreadsheet::{
-            shared_string::shared_string_item::XlsxSharedStringItem,
+            shared_string::shared_string_table::SharedStrings,
             sheet::worksheet::cell::XlsxCell, string_item::XlsxStringItem,
             stylesheet::XlsxStyleSheet,
         },
@@ -30,12 +33,13 @@ pub mod phonetic_properties;
 pub mod phonetic_run;
 pub mod plain_text;
 pub mod rich_text;
+pub mod typed_value;
 
 /// ST_CellType: https://c-rex.net/samples/ooxml/e1/Part4/OOXML_P4_DOCX_ST_CellType_topic_ID0E6NEFB.html
 ///
 /// Different data types that can appear as a value in a worksheet cell
 #[derive(Debug, Clone, PartialEq, Default)]
-#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum CellValueType {
     Numeric(f64),
@@ -56,12 +60,67 @@ pub enum CellValueType {
     Empty,
 }
 
+/// Plain text of the value, without any formatting applied.
+///
+/// Formulas display their last calculated value.
+impl fmt::Display for CellValueType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        match self {
+            CellValueType::Numeric(n) => write!(f, "{}", n),
+            CellValueType::RichText(rich_text) => {
+                for run in rich_text.runs.iter() {
+                    write!(f, "{}", run.text)?;
+                }
+                Ok(())
+            }
+            CellValueType::Formula(formula) => write!(
+                f,
+                "{}",
+                formula.last_calculated_value.as_deref().unwrap_or("")
+            ),
+            CellValueType::PlainText(plain_text) => write!(f, "{}", plain_text.text),
+            CellValueType::Bool(b) => write!(f, "{}", if *b { "TRUE" } else { "FALSE" }),
+            CellValueType::DateTime(date_time) => write!(f, "{}", date_time),
+            CellValueType::Error(error) => write!(f, "{}", error),
+            CellValueType::Empty => Ok(()),
+        }
+    }
+}
+
 impl CellValueType {
+    /// `Numeric` or `DateTime` value for text that reads as a number or date, ie: `"1,234"`, `"2024-01-31"`.
+    ///
+    /// None for any other value.
+    pub fn stored_as_text_value(&self) -> Option<Self> {
+        let text = match self {
+            CellValueType::PlainText(_) | CellValueType::RichText(_) => self.to_string(),
+            _ => return None,
+        };
+        parse_stored_as_text(&text)
+    }
+
     pub(crate) fn from_raw(
         cell: XlsxCell,
-        shared_string_items: &Vec<XlsxSharedStringItem>,
+        shared_strings: &SharedStrings,
         stylesheet: &XlsxStyleSheet,
         color_scheme: Option<XlsxColorScheme>,
+    ) -> anyhow::Result<Self> {
+        return Self::from_raw_with_font(
+            cell,
+            shared_strings,
+            stylesheet,
+            color_scheme,
+            &Font::default(),
+        );
+    }
+
+    /// * cell_font: font of the cell, taken by rich text runs without their own properties
+    pub(crate) fn from_raw_with_font(
+        cell: XlsxCell,
+        shared_strings: &SharedStrings,
+        stylesheet: &XlsxStyleSheet,
+        color_scheme: Option<XlsxColorScheme>,
+        cell_font: &Font,
     ) -> anyhow::Result<Self> {
         if cell.formula.is_none() && cell.inline_string.is_none() && cell.cell_value.is_none() {
             return Ok(Self::Empty);
@@ -69,7 +128,7 @@ impl CellValueType {
 
         // inline string
         if let Some(is) = cell.inline_string {
-            return Self::from_string_item(is, stylesheet, color_scheme.clone());
+            return Self::from_string_item(is, stylesheet, color_scheme.clone(), cell_font);
         }
 
         // formula
@@ -101,14 +160,19 @@ impl CellValueType {
                 // shared string
                 "s" => {
                     let index: usize = v.raw_value.parse()?;
-                    if index >= shared_string_items.len() {
-                        bail!("Shared string index out of range.")
-                    }
-                    let string_item = shared_string_items[index].clone();
-                    Self::from_string_item(string_item, stylesheet, color_scheme.clone())
+                    let string_item = shared_strings.get(index)?;
+                    Self::from_string_item(string_item, stylesheet, color_scheme.clone(), cell_font)
                 }
-                // formula string
-                "str" => bail!("cell has type str (formula) without <f> elements"),
+                // formula string whose formula is not read, ie: binary workbooks
+                "str" => Self::from_string_item(
+                    XlsxStringItem {
+                        text: Some(v.raw_value),
+                        ..Default::default()
+                    },
+                    stylesheet,
+                    color_scheme.clone(),
+                    cell_font,
+                ),
                 // inline string
                 "is" | "inlineStr" => bail!("cell has type inline string without <is> elements"),
                 t => {
@@ -124,6 +188,7 @@ impl CellValueType {
         string_item: XlsxStringItem,
         stylesheet: &XlsxStyleSheet,
         color_scheme: Option<XlsxColorScheme>,
+        cell_font: &Font,
     ) -> anyhow::Result<Self> {
         let phonetic_runs: Option<Vec<PhoneticRun>> =
             if let Some(raw_run) = string_item.phonetic_run {
@@ -176,6 +241,7 @@ impl CellValueType {
                 };
                 let font = Font::from_raw_run_properties(
                     raw_run.run_properties,
+                    cell_font,
                     stylesheet.colors.clone(),
                     color_scheme.clone(),
                 );
@@ -0,0 +1,87 @@
+use std::fmt;
+
+use chrono::NaiveDateTime;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::error_value::CellErrorType;
+
+/// Value of a cell resolved to its type, see `Cell::typed_value`.
+///
+/// Unlike `CellValueType`, formulas are replaced by their last calculated value typed from the `t` attribute of the cell,
+/// texts lose their formatting, and numbers with a date or time format become dates.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum CellValue {
+    Number(f64),
+    /// Shared, inline or formula string
+    Text(String),
+    Bool(bool),
+    Error(CellErrorType),
+    /// ISO 8601 date, or number with a date or time format.
+    ///
+    /// Times without a date are on day 0 of the date system: 1899-12-31 or 1904-01-01.
+    DateTime(NaiveDateTime),
+    /// Empty cell, or formula without calculated value
+    #[default]
+    Blank,
+}
+
+/// Text of the value as `CellValueType` displays it, dates as ISO 8601.
+impl fmt::Display for CellValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        match self {
+            CellValue::Number(number) => write!(f, "{}", number),
+            CellValue::Text(text) => write!(f, "{}", text),
+            CellValue::Bool(b) => write!(f, "{}", if *b { "TRUE" } else { "FALSE" }),
+            CellValue::Error(error) => write!(f, "{}", error),
+            CellValue::DateTime(date_time) => write!(f, "{}", date_time.format("%Y-%m-%dT%H:%M:%S%.f")),
+            CellValue::Blank => Ok(()),
+        }
+    }
+}
+
+impl CellValue {
+    /// Number of a `Number` value, None for any other value, dates included.
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            CellValue::Number(number) => Some(*number),
+            _ => None,
+        }
+    }
+
+    /// Text of a `Text` value, None for any other value.
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            CellValue::Text(text) => Some(text),
+            _ => None,
+        }
+    }
+
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            CellValue::Bool(b) => Some(*b),
+            _ => None,
+        }
+    }
+
+    pub fn as_date_time(&self) -> Option<NaiveDateTime> {
+        match self {
+            CellValue::DateTime(date_time) => Some(*date_time),
+            _ => None,
+        }
+    }
+
+    pub fn as_error(&self) -> Option<&CellErrorType> {
+        match self {
+            CellValue::Error(error) => Some(error),
+            _ => None,
+        }
+    }
+
+    pub fn is_blank(&self) -> bool {
+        *self == CellValue::Blank
+    }
+}
@@ -2,27 +2,216 @@ pub mod cell_property;
 pub mod cell_value;
 
 #[cfg(feature = "serde")]
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
-use cell_property::CellProperty;
-use cell_value::CellValueType;
+use cell_property::{numbering_format::NumberUnit, CellProperty};
+use cell_value::{error_value::CellErrorType, typed_value::CellValue, CellValueType};
 
-use crate::common_types::Coordinate;
+use crate::{
+    common_types::{CellId, Coordinate, Text},
+    helper::string_to_bool,
+};
+use cell_property::format_code::date_time_to_serial;
+
+#[cfg(feature = "dates")]
+use chrono::NaiveDateTime;
+use crate::dates::from_serial;
 
 #[derive(Debug, Clone, PartialEq)]
-#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Cell {
     pub coordinate: Coordinate,
     pub value: CellValueType,
     pub property: CellProperty,
+
+    /// s (Style Index) as found on the cell element.
+    ///
+    /// 0 based index reference to `cellXfs` in stylesheet, before any row or column style fallback.
+    pub style_index: Option<u64>,
+
+    /// t (Cell Data Type) as found on the cell element, ie: `s`, `n`, `str`.
+    pub raw_type: Option<String>,
+
+    /// Deterministic id of the cell, only assigned with `ExcelOptions::cell_ids`.
+    ///
+    /// Always serialized, even when None, so that non self describing formats such as bincode can read it back.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub id: Option<CellId>,
+}
+
+/// How hyperlinks are reflected in the text extracted from a cell.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum HyperlinkTextMode {
+    /// Text of the value only
+    #[default]
+    Ignore,
+    /// Text of the value followed by the link target, ie: `Example (https://example.com)`
+    Append,
+    /// Link target in place of the text
+    Substitute,
 }
 
 impl Cell {
+    /// Unit implied by the number format for numeric values, None for other values.
+    pub fn number_unit(&self) -> Option<NumberUnit> {
+        if !matches!(self.value, CellValueType::Numeric(_)) {
+            return None;
+        }
+        self.property.numbering_format.unit()
+    }
+
+    /// Link target of the cell.
+    ///
+    /// Either the hyperlink attached to the cell, or the location of a `HYPERLINK()` formula.
+    pub fn hyperlink_target(&self) -> Option<String> {
+        if let Some(hyperlink) = &self.property.hyperlink {
+            return Some(hyperlink.target());
+        }
+        if let CellValueType::Formula(formula) = &self.value {
+            return formula.hyperlink_target();
+        }
+        None
+    }
+
+    /// Text of the cell value, with hyperlink targets included according to `mode`.
+    pub fn display_text(&self, mode: HyperlinkTextMode) -> Text {
+        let text = self.value.to_string();
+        if mode == HyperlinkTextMode::Ignore {
+            return text;
+        }
+        let Some(target) = self.hyperlink_target() else {
+            return text;
+        };
+        if mode == HyperlinkTextMode::Substitute || text.is_empty() || text == target {
+            return target;
+        }
+        format!("{} ({})", text, target)
+    }
+
+    /// Text of the cell value as Excel displays it, applying the number format of the cell.
+    ///
+    /// ie: `0.256` with `0.0%` -> `25.6%`, `45306` with `d-mmm-yy` -> `15-Jan-24`, `-1234.5` with `#,##0.00;(#,##0.00)` -> `(1,234.50)`.
+    ///
+    /// Formulas display their last calculated value, booleans `TRUE` or `FALSE` and errors their code.
+    ///
+    /// * is_1904: whether the workbook uses the 1904 date system, see `Worksheet::is_1904`
+    pub fn formatted_value(&self, is_1904: bool) -> Text {
+        let format = &self.property.numbering_format;
+        match &self.value {
+            CellValueType::Numeric(number) => format.format_number(*number, is_1904),
+            CellValueType::DateTime(date_time) => match date_time_to_serial(date_time, is_1904) {
+                Some(serial) => format.format_number(serial, is_1904),
+                None => date_time.clone(),
+            },
+            CellValueType::PlainText(_) | CellValueType::RichText(_) => {
+                format.format_text(&self.value.to_string())
+            }
+            CellValueType::Formula(formula) => {
+                let value = formula.last_calculated_value.as_deref().unwrap_or("");
+                match self.raw_type.as_deref() {
+                    Some("b") => match string_to_bool(value) {
+                        Some(b) => CellValueType::Bool(b).to_string(),
+                        None => value.to_string(),
+                    },
+                    Some("e") => value.to_string(),
+                    Some("str") | Some("inlineStr") => format.format_text(value),
+                    _ => match value.parse::<f64>() {
+                        Ok(number) => format.format_number(number, is_1904),
+                        Err(_) if value.is_empty() => String::new(),
+                        Err(_) => format.format_text(value),
+                    },
+                }
+            }
+            CellValueType::Bool(_) | CellValueType::Error(_) | CellValueType::Empty => {
+                self.value.to_string()
+            }
+        }
+    }
+
+    /// Value of the cell resolved to its type.
+    ///
+    /// Formulas take the type of their last calculated value from the `t` attribute of the cell,
+    /// numbers and formula results with a date or time format are dates, see `CellValue::DateTime`.
+    /// Numbers too large for a date stay numbers.
+    ///
+    /// * is_1904: whether the workbook uses the 1904 date system, see `Worksheet::is_1904`
+    pub fn typed_value(&self, is_1904: bool) -> CellValue {
+        let number = match &self.value {
+            CellValueType::Numeric(number) => *number,
+            CellValueType::PlainText(_) | CellValueType::RichText(_) => {
+                return CellValue::Text(self.value.to_string())
+            }
+            CellValueType::Bool(b) => return CellValue::Bool(*b),
+            CellValueType::Error(error) => return CellValue::Error(error.clone()),
+            CellValueType::Empty => return CellValue::Blank,
+            CellValueType::DateTime(date_time) => {
+                return date_time_to_serial(date_time, is_1904)
+                    .and_then(|serial| from_serial(serial, is_1904))
+                    .map_or(CellValue::Text(date_time.clone()), CellValue::DateTime);
+            }
+            CellValueType::Formula(formula) => {
+                let value = formula.last_calculated_value.as_deref().unwrap_or("");
+                match self.raw_type.as_deref() {
+                    Some("b") => match string_to_bool(value) {
+                        Some(b) => return CellValue::Bool(b),
+                        None => return CellValue::Text(value.to_string()),
+                    },
+                    Some("e") => match CellErrorType::from_string(value) {
+                        Ok(error) => return CellValue::Error(error),
+                        Err(_) => return CellValue::Text(value.to_string()),
+                    },
+                    Some("str") | Some("inlineStr") => return CellValue::Text(value.to_string()),
+                    _ if value.is_empty() => return CellValue::Blank,
+                    _ => match value.parse::<f64>() {
+                        Ok(number) => number,
+                        Err(_) => return CellValue::Text(value.to_string()),
+                    },
+                }
+            }
+        };
+        if !self.property.numbering_format.is_date_time() {
+            return CellValue::Number(number);
+        }
+        from_serial(number, is_1904).map_or(CellValue::Number(number), CellValue::DateTime)
+    }
+
+    /// Date and time of a cell holding a date or a time: a number or a formula result with a date or time format,
+    /// or an ISO 8601 date.
+    ///
+    /// None for other cells, and for serial numbers that are not dates, see `dates::from_serial`.
+    ///
+    /// * is_1904: whether the workbook uses the 1904 date system, see `Worksheet::is_1904`
+    #[cfg(feature = "dates")]
+    pub fn date_time(&self, is_1904: bool) -> Option<NaiveDateTime> {
+        self.typed_value(is_1904).as_date_time()
+    }
+
+    /// Whether the cell holds the number zero, or a formula calculated to zero.
+    ///
+    /// Displayed blank in sheets not showing zeros, see `Worksheet::show_zeros`.
+    pub(crate) fn is_zero(&self) -> bool {
+        match &self.value {
+            CellValueType::Numeric(number) => *number == 0.0,
+            CellValueType::Formula(formula) => {
+                matches!(self.raw_type.as_deref(), None | Some("n"))
+                    && formula
+                        .last_calculated_value
+                        .as_deref()
+                        .and_then(|value| value.parse::<f64>().ok())
+                        == Some(0.0)
+            }
+            _ => false,
+        }
+    }
+
     pub(crate) fn default(coordinate: Coordinate) -> Self {
         return Self {
             coordinate,
             value: CellValueType::Empty,
             property: CellProperty::default(),
+            style_index: None,
+            raw_type: None,
+            id: None,
         };
     }
 }
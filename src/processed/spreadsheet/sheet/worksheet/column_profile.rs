@@ -0,0 +1,439 @@
+use std::{
+    collections::{BTreeMap, HashMap, HashSet},
+    io::{Read, Seek},
+};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::{
+    cell::cell_property::numbering_format::{NumberUnit, NumberingFormat, CURRENCY_SYMBOLS},
+    row::Rows,
+    row_hash::{hash_values, normalized_value},
+};
+
+/// Distinct values counted exactly up to this number per column, estimated with HyperLogLog above.
+static EXACT_DISTINCT_LIMIT: usize = 65_536;
+
+/// HyperLogLog precision: 2^14 registers, a standard error of about 0.8%.
+static HLL_PRECISION: u32 = 14;
+
+/// Longest symbol recognized around the number of a text, in characters, ie: `km/h`, `sq ft`.
+static MAX_TEXT_UNIT_LENGTH: usize = 5;
+
+/// Profile of the columns of a worksheet, see `Worksheet::profile_columns`.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SheetProfile {
+    /// Rows profiled: from the first to the last row holding a value, header excluded.
+    ///
+    /// Rows left out of the file within that span count as empty.
+    pub row_count: u64,
+
+    /// One profile per column from the first to the last column holding a value.
+    pub columns: Vec<ColumnProfile>,
+}
+
+impl SheetProfile {
+    /// Get column profile by a given column index (1 based).
+    pub fn column(&self, col: u64) -> Option<&ColumnProfile> {
+        return self.columns.iter().find(|c| c.col == col);
+    }
+}
+
+/// Type of the values of a column.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum DetectedType {
+    /// No value.
+    Empty,
+    /// Numbers, or formulas calculating numbers.
+    Numeric,
+    /// Dates and times, or numbers formatted as dates or times.
+    DateTime,
+    Bool,
+    Text,
+    Error,
+    /// Values of different types.
+    Mixed,
+}
+
+/// Number of values of each type within a column.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TypeCounts {
+    pub numeric: u64,
+    pub date_time: u64,
+    pub bool: u64,
+    pub text: u64,
+    pub error: u64,
+}
+
+/// Kind of the symbol shared by the values of a column.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum UnitKind {
+    /// ie: `$`, `€`, `USD`
+    Currency,
+    /// `%`
+    Percentage,
+    /// Any other suffix, ie: `kg`, `m²`.
+    Unit,
+}
+
+/// Currency symbol or unit shared by the values of a column.
+///
+/// Numbers carry the symbol of their format: a currency, a percentage, or a literal suffix such as `0.0" kg"`.
+/// Texts carry the symbol written around a number: a currency before or after it, ie: `$12.50`, `12,50 €`, or a unit after it, ie: `12 kg`.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ColumnUnit {
+    pub kind: UnitKind,
+    pub symbol: String,
+
+    /// Number of values carrying the symbol, the other values of the column carry none.
+    pub count: u64,
+}
+
+/// Statistics of the values of a column.
+///
+/// Values are compared as with `Worksheet::row_hashes`: texts trimmed and normalized, formulas by their last calculated value.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ColumnProfile {
+    /// column index (1 based)
+    pub col: u64,
+
+    /// Text of the header cell, None without header or for an empty header cell.
+    pub name: Option<String>,
+
+    pub detected_type: DetectedType,
+    pub type_counts: TypeCounts,
+
+    /// Number of empty cells, rows left out of the file included.
+    pub null_count: u64,
+
+    /// Share of empty cells, between 0 and 1. 0 when no row is profiled.
+    pub null_rate: f64,
+
+    /// Number of distinct values, empty cells excluded.
+    pub distinct_count: u64,
+
+    /// False when `distinct_count` is estimated with HyperLogLog, for columns with many distinct values.
+    pub distinct_exact: bool,
+
+    /// Smallest numeric value, dates and times excluded.
+    pub min: Option<f64>,
+
+    /// Largest numeric value, dates and times excluded.
+    pub max: Option<f64>,
+
+    /// Average of the numeric values, dates and times excluded.
+    pub mean: Option<f64>,
+
+    /// Symbol shared by the values of the column.
+    ///
+    /// None when no value carries one, or when values carry different ones.
+    pub unit: Option<ColumnUnit>,
+}
+
+impl<RS: Read + Seek> Rows<'_, RS> {
+    /// Profile the columns in one pass over the rows, see `Worksheet::profile_columns`.
+    pub fn profile_columns(self, has_header: bool) -> anyhow::Result<SheetProfile> {
+        let mut header: BTreeMap<u64, String> = BTreeMap::new();
+        let mut header_read = !has_header;
+        let mut columns: BTreeMap<u64, ColumnStatistics> = BTreeMap::new();
+        let mut rows: Option<(u64, u64)> = None;
+        // number formats displaying dates, by format id
+        let mut date_formats: HashMap<u64, bool> = HashMap::new();
+        // symbols of number formats, by format id
+        let mut format_units: HashMap<u64, Option<(UnitKind, String)>> = HashMap::new();
+
+        for row in self {
+            let row = row?;
+            let values: Vec<(u64, (u8, String), Option<(UnitKind, String)>)> = row
+                .cells
+                .iter()
+                .map(|c| {
+                    let (mut kind, text) = normalized_value(&c.value, c.raw_type.as_deref());
+                    let format = &c.property.numbering_format;
+                    if kind == b'n'
+                        && *date_formats
+                            .entry(format.format_id)
+                            .or_insert_with(|| format.is_date_time())
+                    {
+                        kind = b'd';
+                    }
+                    let unit = match kind {
+                        b'n' => format_units
+                            .entry(format.format_id)
+                            .or_insert_with(|| format_unit(format))
+                            .clone(),
+                        b's' => text_unit(&text),
+                        _ => None,
+                    };
+                    (c.coordinate.col, (kind, text), unit)
+                })
+                .filter(|(_, (kind, _), _)| *kind != 0)
+                .collect();
+            if values.is_empty() {
+                continue;
+            }
+            if !header_read {
+                header_read = true;
+                header = values
+                    .into_iter()
+                    .map(|(col, (_, text), _)| (col, text))
+                    .collect();
+                continue;
+            }
+
+            rows = Some(match rows {
+                Some((first, _)) => (first, row.index),
+                None => (row.index, row.index),
+            });
+            for (col, value, unit) in values {
+                columns.entry(col).or_default().push(value, unit);
+            }
+        }
+
+        let row_count = rows.map_or(0, |(first, last)| last - first + 1);
+        let (Some(first), Some(last)) = (
+            columns.keys().chain(header.keys()).min().copied(),
+            columns.keys().chain(header.keys()).max().copied(),
+        ) else {
+            return Ok(SheetProfile {
+                row_count,
+                columns: vec![],
+            });
+        };
+
+        let profiles = (first..=last)
+            .map(|col| {
+                let statistics = columns.remove(&col).unwrap_or_default();
+                statistics.profile(col, header.remove(&col), row_count)
+            })
+            .collect();
+        return Ok(SheetProfile {
+            row_count,
+            columns: profiles,
+        });
+    }
+}
+
+/// Running statistics of a column.
+#[derive(Default)]
+struct ColumnStatistics {
+    type_counts: TypeCounts,
+    count: u64,
+    sum: f64,
+    min: Option<f64>,
+    max: Option<f64>,
+    distinct: DistinctCounter,
+    unit: Option<ColumnUnit>,
+    mixed_units: bool,
+}
+
+impl ColumnStatistics {
+    fn push(&mut self, (kind, text): (u8, String), unit: Option<(UnitKind, String)>) {
+        self.count += 1;
+        if let Some((unit_kind, symbol)) = unit {
+            match &mut self.unit {
+                Some(unit) if unit.kind == unit_kind && unit.symbol == symbol => unit.count += 1,
+                Some(_) => self.mixed_units = true,
+                None => {
+                    self.unit = Some(ColumnUnit {
+                        kind: unit_kind,
+                        symbol,
+                        count: 1,
+                    })
+                }
+            }
+        }
+        match kind {
+            b'n' => {
+                self.type_counts.numeric += 1;
+                if let Ok(number) = text.parse::<f64>() {
+                    self.sum += number;
+                    self.min = Some(self.min.map_or(number, |m| m.min(number)));
+                    self.max = Some(self.max.map_or(number, |m| m.max(number)));
+                }
+            }
+            b'd' => self.type_counts.date_time += 1,
+            b'b' => self.type_counts.bool += 1,
+            b'e' => self.type_counts.error += 1,
+            _ => self.type_counts.text += 1,
+        }
+        self.distinct.insert(hash_values(&[(kind, text)]));
+    }
+
+    fn profile(self, col: u64, name: Option<String>, row_count: u64) -> ColumnProfile {
+        let counts = self.type_counts;
+        let kinds = [
+            (counts.numeric, DetectedType::Numeric),
+            (counts.date_time, DetectedType::DateTime),
+            (counts.bool, DetectedType::Bool),
+            (counts.text, DetectedType::Text),
+            (counts.error, DetectedType::Error),
+        ];
+        let mut present = kinds.iter().filter(|(count, _)| *count > 0);
+        let detected_type = match (present.next(), present.next()) {
+            (None, _) => DetectedType::Empty,
+            (Some((_, detected_type)), None) => *detected_type,
+            _ => DetectedType::Mixed,
+        };
+
+        let null_count = row_count.saturating_sub(self.count);
+        return ColumnProfile {
+            col,
+            name: name.filter(|n| !n.is_empty()),
+            detected_type,
+            type_counts: counts,
+            null_count,
+            null_rate: if row_count == 0 {
+                0.0
+            } else {
+                null_count as f64 / row_count as f64
+            },
+            distinct_count: self.distinct.count(),
+            distinct_exact: matches!(self.distinct, DistinctCounter::Exact(_)),
+            min: self.min,
+            max: self.max,
+            mean: (counts.numeric > 0).then(|| self.sum / counts.numeric as f64),
+            unit: self.unit.filter(|_| !self.mixed_units),
+        };
+    }
+}
+
+/// Symbol of a number format: its currency or percentage, or else the text displayed after the number.
+fn format_unit(format: &NumberingFormat) -> Option<(UnitKind, String)> {
+    return match format.unit() {
+        Some(NumberUnit::Percentage) => Some((UnitKind::Percentage, "%".to_string())),
+        Some(NumberUnit::Currency(symbol)) => Some((UnitKind::Currency, symbol)),
+        None => format.unit_suffix().map(|suffix| (symbol_kind(&suffix), suffix)),
+    };
+}
+
+/// Symbol written around the number of a text, ie: `$12.50`, `-€3`, `(1,200 USD)`, `12 kg`, `30%`.
+///
+/// None for other texts, and for symbols before the number that are not currencies.
+fn text_unit(text: &str) -> Option<(UnitKind, String)> {
+    let start = text.find(|c: char| c.is_ascii_digit() || c == '.')?;
+    let end = text.rfind(|c: char| c.is_ascii_digit())? + 1;
+    if start >= end {
+        return None;
+    }
+    let number: String = text[start..end].chars().filter(|c| *c != ',').collect();
+    if number.parse::<f64>().is_err() {
+        return None;
+    }
+    let is_sign = |c: char| matches!(c, '-' | '+' | '(' | ')');
+    let prefix: String = text[..start].chars().filter(|c| !is_sign(*c)).collect();
+    let suffix = text[end..].trim_end_matches(is_sign);
+    let symbol = match (prefix.trim(), suffix.trim()) {
+        (prefix, "") if !prefix.is_empty() => {
+            let symbol = prefix.to_string();
+            if symbol_kind(&symbol) != UnitKind::Currency {
+                return None;
+            }
+            symbol
+        }
+        ("", suffix) if !suffix.is_empty() => suffix.to_string(),
+        _ => return None,
+    };
+    if symbol.chars().count() > MAX_TEXT_UNIT_LENGTH {
+        return None;
+    }
+    return Some((symbol_kind(&symbol), symbol));
+}
+
+/// Kind of a symbol: currencies hold a currency symbol or are three letter codes, ie: `US$`, `EUR`.
+fn symbol_kind(symbol: &str) -> UnitKind {
+    if symbol == "%" {
+        return UnitKind::Percentage;
+    }
+    if symbol.chars().any(|c| CURRENCY_SYMBOLS.contains(&c))
+        || (symbol.len() == 3 && symbol.chars().all(|c| c.is_ascii_uppercase()))
+    {
+        return UnitKind::Currency;
+    }
+    return UnitKind::Unit;
+}
+
+/// Distinct hashes, kept exactly until `EXACT_DISTINCT_LIMIT`, then estimated.
+enum DistinctCounter {
+    Exact(HashSet<u64>),
+    Estimated(HyperLogLog),
+}
+
+impl Default for DistinctCounter {
+    fn default() -> Self {
+        return DistinctCounter::Exact(HashSet::new());
+    }
+}
+
+impl DistinctCounter {
+    fn insert(&mut self, hash: u64) {
+        match self {
+            DistinctCounter::Exact(hashes) => {
+                hashes.insert(hash);
+                if hashes.len() > EXACT_DISTINCT_LIMIT {
+                    let mut estimate = HyperLogLog::new();
+                    hashes.iter().for_each(|h| estimate.insert(*h));
+                    *self = DistinctCounter::Estimated(estimate);
+                }
+            }
+            DistinctCounter::Estimated(estimate) => estimate.insert(hash),
+        }
+    }
+
+    fn count(&self) -> u64 {
+        return match self {
+            DistinctCounter::Exact(hashes) => hashes.len() as u64,
+            DistinctCounter::Estimated(estimate) => estimate.count(),
+        };
+    }
+}
+
+/// HyperLogLog cardinality estimate, with the small range correction of the original paper.
+struct HyperLogLog {
+    registers: Vec<u8>,
+}
+
+impl HyperLogLog {
+    fn new() -> Self {
+        return Self {
+            registers: vec![0; 1 << HLL_PRECISION],
+        };
+    }
+
+    fn insert(&mut self, hash: u64) {
+        // FNV-1a does not spread its bits evenly enough, mix them first (SplitMix64 finalizer)
+        let mut hash = hash;
+        hash = (hash ^ (hash >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        hash = (hash ^ (hash >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        hash ^= hash >> 31;
+
+        let index = (hash >> (64 - HLL_PRECISION)) as usize;
+        let rank = ((hash << HLL_PRECISION) | (1 << (HLL_PRECISION - 1))).leading_zeros() + 1;
+        self.registers[index] = self.registers[index].max(rank as u8);
+    }
+
+    fn count(&self) -> u64 {
+        let m = self.registers.len() as f64;
+        let alpha = 0.7213 / (1.0 + 1.079 / m);
+        let sum: f64 = self
+            .registers
+            .iter()
+            .map(|r| 2_f64.powi(-(*r as i32)))
+            .sum();
+        let estimate = alpha * m * m / sum;
+        let zeros = self.registers.iter().filter(|r| **r == 0).count();
+        if estimate <= 2.5 * m && zeros > 0 {
+            return (m * (m / zeros as f64).ln()).round() as u64;
+        }
+        return estimate.round() as u64;
+    }
+}
@@ -0,0 +1,66 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::raw::spreadsheet::sheet::worksheet::column_information::XlsxColumnInformation;
+
+/// Last column of a worksheet, XFD.
+const MAX_COLUMN: u64 = 16_384;
+
+/// Attributes of a column from a `col` element, see `Worksheet::column_properties`.
+///
+/// Unspecified attributes hold their schema defaults.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ColumnProperties {
+    /// column index (1 based)
+    pub column: u64,
+
+    /// Width in characters of the maximum digit width of the default font, as stored in the file.
+    /// None for the default width.
+    ///
+    /// Convert it to pixels with `units::column_width_to_px`.
+    pub width: Option<f64>,
+
+    /// The width differs from the default or was set manually.
+    pub custom_width: bool,
+
+    /// The width fits the content and grows with the numbers typed in.
+    pub best_fit: bool,
+
+    pub hidden: bool,
+
+    /// Outline (grouping) level, 0 for columns outside of any group.
+    pub outline_level: u64,
+
+    /// The outline group of the columns next to this one is collapsed.
+    pub collapsed: bool,
+
+    /// style (Style): 0 based index reference to `cellXfs` in stylesheet, applied to the cells of the column without a style of their own.
+    pub style_index: Option<u64>,
+
+    pub show_phonetic: bool,
+}
+
+impl ColumnProperties {
+    /// One entry per column within `min` and `max` of the `col` element.
+    pub(crate) fn from_raw(raw: &XlsxColumnInformation) -> Vec<Self> {
+        let Some(min) = raw.min_column.filter(|min| *min > 0) else {
+            return vec![];
+        };
+        let max = raw.max_column.unwrap_or(min).min(MAX_COLUMN);
+
+        return (min..=max)
+            .map(|column| Self {
+                column,
+                width: raw.width,
+                custom_width: raw.custom_width.unwrap_or(false),
+                best_fit: raw.best_fit.unwrap_or(false),
+                hidden: raw.hidden.unwrap_or(false),
+                outline_level: raw.outline_level.unwrap_or(0),
+                collapsed: raw.collapsed.unwrap_or(false),
+                style_index: raw.style,
+                show_phonetic: raw.show_phonetic.unwrap_or(false),
+            })
+            .collect();
+    }
+}
@@ -0,0 +1,464 @@
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+use std::io::{Read, Seek};
+
+use crate::{
+    common_types::{Coordinate, Dimension, Text},
+    excel::ExcelOptions,
+    raw::spreadsheet::sheet::worksheet::cell::XlsxCell,
+};
+
+use super::{
+    cell::{
+        cell_value::{plain_text::PlainText, CellValueType},
+        Cell,
+    },
+    row::Rows,
+    Worksheet,
+};
+
+/// Column oriented layout of the cell values within a worksheet.
+///
+/// Each column within the dimension is stored as a typed vector with one slot per row,
+/// together with a validity bitmap marking the non empty slots.
+/// `Worksheet::get_columns` fills the vectors from the raw cells and the shared strings as the rows are read,
+/// without building a `Cell` for each value.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct ColumnarSheet {
+    /// Range covered by the columns: from the start of the dimension, to the last row and column holding a cell.
+    pub dimension: Dimension,
+    /// One column for each column index from `dimension.start.col` to `dimension.end.col`.
+    pub columns: Vec<Column>,
+}
+
+impl ColumnarSheet {
+    /// Build columns from cells.
+    ///
+    /// Cells outside of the dimension are ignored.
+    pub fn from_cells(dimension: Dimension, cells: Vec<Cell>) -> Self {
+        let mut values = ColumnValues::new(dimension, &ExcelOptions::default());
+        for cell in cells {
+            values.push(cell.coordinate, cell.value);
+        }
+        values.finish()
+    }
+
+    /// Build columns from the raw cells of the rows of a worksheet, one row at a time.
+    ///
+    /// The dimension may be the one declared in the file, so slots are reserved from it within `ExcelOptions::capacity_for`.
+    pub(crate) fn from_rows<RS: Read + Seek>(
+        worksheet: &Worksheet,
+        dimension: Dimension,
+        mut rows: Rows<RS>,
+        options: &ExcelOptions,
+    ) -> anyhow::Result<Self> {
+        let mut values = ColumnValues::new(dimension, options);
+        while let Some(mut row) = rows.next_raw_row()? {
+            for cell in row.cells.take().unwrap_or_default() {
+                values.push_raw(worksheet, cell)?;
+            }
+        }
+        Ok(values.finish())
+    }
+
+    /// Build columns from the raw cells of a worksheet read with its cells, ie: binary worksheets.
+    pub(crate) fn from_raw_cells<'a>(
+        worksheet: &Worksheet,
+        dimension: Dimension,
+        cells: impl Iterator<Item = &'a XlsxCell>,
+    ) -> anyhow::Result<Self> {
+        let mut values = ColumnValues::new(dimension, &ExcelOptions::default());
+        for cell in cells {
+            values.push_raw(worksheet, cell.clone())?;
+        }
+        Ok(values.finish())
+    }
+
+    /// Get column by a given column index (1 based).
+    pub fn column(&self, col: u64) -> Option<&Column> {
+        let index = col.checked_sub(self.dimension.start.col)?;
+        self.columns.get(usize::try_from(index).ok()?)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct Column {
+    /// column index (1 based)
+    pub col: u64,
+    /// row index (1 based) of the first slot
+    pub start_row: u64,
+    /// Set for slots containing a value
+    pub validity: Bitmap,
+    pub data: ColumnData,
+}
+
+impl Column {
+    /// Number of slots (rows) within the column.
+    pub fn len(&self) -> usize {
+        self.validity.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.validity.len() == 0
+    }
+
+    /// Whether the slot at a given row index (1 based) contains a value.
+    pub fn is_valid(&self, row: u64) -> bool {
+        let Some(index) = row.checked_sub(self.start_row) else {
+            return false;
+        };
+        self.validity.get(index as usize)
+    }
+
+    /// Sum of the valid values of a numeric column.
+    pub fn sum(&self) -> Option<f64> {
+        let ColumnData::Numeric(values) = &self.data else {
+            return None;
+        };
+        Some(
+            values
+                .iter()
+                .enumerate()
+                .filter(|(index, _)| self.validity.get(*index))
+                .map(|(_, value)| value)
+                .sum(),
+        )
+    }
+}
+
+/// Values of a column.
+///
+/// Invalid (empty) slots hold the default value of the type, columns without any value are numeric.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum ColumnData {
+    /// All values are numeric, or formulas whose last calculated value is numeric.
+    Numeric(Vec<f64>),
+    /// All values are booleans.
+    Bool(Vec<bool>),
+    /// All values are plain or rich text.
+    Text(Vec<Text>),
+    /// Values of different types.
+    ///
+    /// Values a typed column would hold are kept as such: numbers, and formulas whose last calculated value is numeric, as `Numeric`,
+    /// plain and rich texts as `PlainText` without phonetic information.
+    Mixed(Vec<CellValueType>),
+}
+
+impl ColumnData {
+    /// Empty column of the type of a value.
+    fn for_value(value: &SlotValue, capacity: usize) -> Self {
+        match value {
+            SlotValue::Numeric(_) => Self::Numeric(Vec::with_capacity(capacity)),
+            SlotValue::Bool(_) => Self::Bool(Vec::with_capacity(capacity)),
+            SlotValue::Text(_) => Self::Text(Vec::with_capacity(capacity)),
+            SlotValue::Other(_) => Self::Mixed(Vec::with_capacity(capacity)),
+        }
+    }
+
+    fn accepts(&self, value: &SlotValue) -> bool {
+        matches!(
+            (self, value),
+            (Self::Numeric(_), SlotValue::Numeric(_))
+                | (Self::Bool(_), SlotValue::Bool(_))
+                | (Self::Text(_), SlotValue::Text(_))
+                | (Self::Mixed(_), _)
+        )
+    }
+
+    fn len(&self) -> usize {
+        match self {
+            Self::Numeric(values) => values.len(),
+            Self::Bool(values) => values.len(),
+            Self::Text(values) => values.len(),
+            Self::Mixed(values) => values.len(),
+        }
+    }
+
+    /// Add default values up to `len` slots.
+    fn grow(&mut self, len: usize) {
+        if self.len() >= len {
+            return;
+        }
+        match self {
+            Self::Numeric(values) => values.resize(len, 0.0),
+            Self::Bool(values) => values.resize(len, false),
+            Self::Text(values) => values.resize_with(len, Text::default),
+            Self::Mixed(values) => values.resize(len, CellValueType::Empty),
+        }
+    }
+
+    /// Set a slot, the column must accept the value and hold the slot.
+    fn set(&mut self, index: usize, value: SlotValue) {
+        match (self, value) {
+            (Self::Numeric(values), SlotValue::Numeric(n)) => values[index] = n,
+            (Self::Bool(values), SlotValue::Bool(b)) => values[index] = b,
+            (Self::Text(values), SlotValue::Text(text)) => values[index] = text,
+            (Self::Mixed(values), value) => values[index] = value.into_value(),
+            _ => (),
+        }
+    }
+
+    /// Values of a typed column as mixed values, for a value of another type.
+    fn into_mixed(self, validity: &Bitmap) -> Self {
+        let valid = |index: usize, value: CellValueType| match validity.get(index) {
+            true => value,
+            false => CellValueType::Empty,
+        };
+        let values = match self {
+            Self::Numeric(values) => values
+                .into_iter()
+                .enumerate()
+                .map(|(index, n)| valid(index, CellValueType::Numeric(n)))
+                .collect(),
+            Self::Bool(values) => values
+                .into_iter()
+                .enumerate()
+                .map(|(index, b)| valid(index, CellValueType::Bool(b)))
+                .collect(),
+            Self::Text(values) => values
+                .into_iter()
+                .enumerate()
+                .map(|(index, text)| valid(index, SlotValue::Text(text).into_value()))
+                .collect(),
+            Self::Mixed(values) => values,
+        };
+        Self::Mixed(values)
+    }
+}
+
+/// Value of a slot, typed as the columns store it.
+enum SlotValue {
+    Numeric(f64),
+    Bool(bool),
+    Text(Text),
+    /// Only held by mixed columns.
+    Other(CellValueType),
+}
+
+impl SlotValue {
+    /// None for empty values.
+    fn from_value(value: CellValueType) -> Option<Self> {
+        let slot = match value {
+            CellValueType::Empty => return None,
+            CellValueType::Numeric(n) => Self::Numeric(n),
+            CellValueType::Bool(b) => Self::Bool(b),
+            CellValueType::PlainText(text) => Self::Text(text.text),
+            CellValueType::RichText(text) => Self::Text(text.runs.into_iter().map(|run| run.text).collect()),
+            CellValueType::Formula(formula) => {
+                match formula.last_calculated_value.as_ref().and_then(|v| v.parse::<f64>().ok()) {
+                    Some(n) => Self::Numeric(n),
+                    None => Self::Other(CellValueType::Formula(formula)),
+                }
+            }
+            value => Self::Other(value),
+        };
+        Some(slot)
+    }
+
+    fn into_value(self) -> CellValueType {
+        match self {
+            Self::Numeric(n) => CellValueType::Numeric(n),
+            Self::Bool(b) => CellValueType::Bool(b),
+            Self::Text(text) => CellValueType::PlainText(PlainText {
+                text,
+                phonetic_properties: None,
+                phonetic_runs: None,
+            }),
+            Self::Other(value) => value,
+        }
+    }
+}
+
+/// Typed values and validity of a column, grown as values are added.
+struct ColumnBuilder {
+    validity: Bitmap,
+    data: ColumnData,
+}
+
+impl ColumnBuilder {
+    /// * capacity: slots reserved once the type of the column is known
+    fn set(&mut self, index: usize, value: SlotValue, capacity: usize) {
+        if !self.data.accepts(&value) {
+            let data = std::mem::replace(&mut self.data, ColumnData::Numeric(vec![]));
+            self.data = match self.validity.count() {
+                // no value yet, the column takes the type of the first one
+                0 => ColumnData::for_value(&value, capacity),
+                _ => data.into_mixed(&self.validity),
+            };
+        }
+        self.grow(index + 1);
+        self.data.set(index, value);
+        self.validity.set(index);
+    }
+
+    fn grow(&mut self, len: usize) {
+        self.data.grow(len);
+        self.validity.grow(len);
+    }
+}
+
+/// Columns of a sheet, grown as cells are added.
+struct ColumnValues {
+    dimension: Dimension,
+    /// slots reserved for each typed column
+    row_capacity: usize,
+    columns: Vec<ColumnBuilder>,
+    row_count: usize,
+}
+
+impl ColumnValues {
+    fn new(dimension: Dimension, options: &ExcelOptions) -> Self {
+        let (start, end) = (dimension.start, dimension.end);
+        let row_count = end.row.saturating_sub(start.row).saturating_add(1);
+        let col_count = end.col.saturating_sub(start.col).saturating_add(1);
+        Self {
+            dimension,
+            row_capacity: options.capacity_for(Some(row_count)),
+            columns: Vec::with_capacity(options.capacity_for(Some(col_count))),
+            row_count: 0,
+        }
+    }
+
+    /// Slot (row, column) of a coordinate, None outside of the dimension.
+    fn slot(&self, coordinate: Coordinate) -> Option<(usize, usize)> {
+        let (start, end) = (self.dimension.start, self.dimension.end);
+        if coordinate.row < start.row
+            || coordinate.row > end.row
+            || coordinate.col < start.col
+            || coordinate.col > end.col
+        {
+            return None;
+        }
+        Some((
+            (coordinate.row - start.row) as usize,
+            (coordinate.col - start.col) as usize,
+        ))
+    }
+
+    /// Add the value of a raw cell, read as `Worksheet::process_cell` reads it but without styles.
+    fn push_raw(&mut self, worksheet: &Worksheet, cell: XlsxCell) -> anyhow::Result<()> {
+        let Some(coordinate) = cell.coordinate() else {
+            return Ok(());
+        };
+        worksheet.cache_master_formula(&cell);
+        if self.slot(coordinate).is_none() {
+            return Ok(());
+        }
+        let value = worksheet.raw_cell_value(coordinate, cell)?;
+        self.push(coordinate, value);
+        Ok(())
+    }
+
+    fn push(&mut self, coordinate: Coordinate, value: CellValueType) {
+        let Some((row, col)) = self.slot(coordinate) else {
+            return;
+        };
+        if self.columns.len() <= col {
+            self.columns.resize_with(col + 1, || ColumnBuilder {
+                validity: Bitmap::new(0),
+                data: ColumnData::Numeric(vec![]),
+            });
+        }
+        self.row_count = self.row_count.max(row + 1);
+        if let Some(value) = SlotValue::from_value(value) {
+            self.columns[col].set(row, value, self.row_capacity);
+        }
+    }
+
+    fn finish(self) -> ColumnarSheet {
+        let start = self.dimension.start;
+        let row_count = self.row_count.max(1);
+        let mut builders = self.columns;
+        if builders.is_empty() {
+            builders.push(ColumnBuilder {
+                validity: Bitmap::new(0),
+                data: ColumnData::Numeric(vec![]),
+            });
+        }
+        let end = Coordinate::from_point((
+            start.row + row_count as u64 - 1,
+            start.col + builders.len() as u64 - 1,
+        ));
+
+        let columns = builders
+            .into_iter()
+            .enumerate()
+            .map(|(index, mut builder)| {
+                builder.grow(row_count);
+                Column {
+                    col: start.col + index as u64,
+                    start_row: start.row,
+                    validity: builder.validity,
+                    data: builder.data,
+                }
+            })
+            .collect();
+
+        ColumnarSheet {
+            dimension: Dimension { start, end },
+            columns,
+        }
+    }
+}
+
+/// Bit per slot, least significant bit first.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct Bitmap {
+    bits: Vec<u64>,
+    len: usize,
+}
+
+impl Bitmap {
+    pub(crate) fn new(len: usize) -> Self {
+        Self {
+            bits: vec![0; len.div_ceil(64)],
+            len,
+        }
+    }
+
+    /// Add unset bits up to `len` bits.
+    pub(crate) fn grow(&mut self, len: usize) {
+        if len > self.len {
+            self.bits.resize(len.div_ceil(64), 0);
+            self.len = len;
+        }
+    }
+
+    pub(crate) fn set(&mut self, index: usize) {
+        if index < self.len {
+            self.bits[index / 64] |= 1 << (index % 64);
+        }
+    }
+
+    /// false for indexes out of range.
+    pub fn get(&self, index: usize) -> bool {
+        if index >= self.len {
+            return false;
+        }
+        self.bits[index / 64] & (1 << (index % 64)) != 0
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Number of set bits.
+    pub fn count(&self) -> usize {
+        self.bits.iter().map(|b| b.count_ones() as usize).sum()
+    }
+
+    /// Underlying words, least significant bit first.
+    pub fn as_words(&self) -> &[u64] {
+        &self.bits
+    }
+}
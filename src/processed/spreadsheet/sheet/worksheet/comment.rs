@@ -0,0 +1,102 @@
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+use crate::{
+    common_types::Coordinate,
+    processed::spreadsheet::sheet::worksheet::cell::{
+        cell_property::font::Font, cell_value::rich_text::RichTextRun,
+    },
+    raw::{
+        drawing::scheme::color_scheme::XlsxColorScheme,
+        spreadsheet::{
+            comment::XlsxComments, stylesheet::XlsxStyleSheet, vml_drawing::XlsxVmlDrawing,
+        },
+    },
+};
+
+/// A cell comment (legacy note).
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct Comment {
+    /// Commented cell
+    pub coordinate: Coordinate,
+
+    pub author: Option<String>,
+
+    /// Text of the comment, the runs joined for rich text.
+    ///
+    /// Excel starts the text of a note with the name of its author, ie: `Reviewer:\nCheck this total`.
+    pub text: String,
+
+    /// Formatted runs of the text, empty if the comment is plain text.
+    pub runs: Vec<RichTextRun>,
+
+    /// The note is always shown, not only when hovering the cell.
+    pub visible: bool,
+}
+
+impl Comment {
+    /// Comments of a worksheet, in the order of the comments part.
+    ///
+    /// The visibility is read from the note shapes of the VML drawing, matched on the commented cell.
+    pub(crate) fn from_raw(
+        comments: &XlsxComments,
+        vml_drawing: Option<&XlsxVmlDrawing>,
+        stylesheet: &XlsxStyleSheet,
+        color_scheme: Option<XlsxColorScheme>,
+    ) -> Vec<Self> {
+        let mut processed: Vec<Self> = vec![];
+        for comment in &comments.comment_list {
+            let Some(coordinate) = comment
+                .r#ref
+                .as_ref()
+                .and_then(|r| Coordinate::from_a1(r.replace('$', "").as_bytes()))
+            else {
+                continue;
+            };
+            let author = comment
+                .author_id
+                .and_then(|id| comments.authors.get(id as usize))
+                .cloned();
+
+            let mut text = String::new();
+            let mut runs: Vec<RichTextRun> = vec![];
+            if let Some(item) = &comment.text {
+                if let Some(t) = &item.text {
+                    text.push_str(t);
+                }
+                for run in item.rich_text_run.iter().flatten() {
+                    let Some(t) = run.text.clone() else {
+                        continue;
+                    };
+                    text.push_str(&t);
+                    let font = Font::from_raw_run_properties(
+                        run.run_properties.clone(),
+                        &Font::default(),
+                        stylesheet.colors.clone(),
+                        color_scheme.clone(),
+                    );
+                    runs.push(RichTextRun { font, text: t });
+                }
+            }
+
+            let visible = vml_drawing
+                .and_then(|vml| {
+                    vml.notes.iter().find(|note| {
+                        note.row.map(|r| r + 1) == Some(coordinate.row)
+                            && note.column.map(|c| c + 1) == Some(coordinate.col)
+                    })
+                })
+                .is_some_and(|note| note.visible);
+
+            processed.push(Self {
+                coordinate,
+                author,
+                text,
+                runs,
+                visible,
+            });
+        }
+        return processed;
+    }
+}
@@ -0,0 +1,52 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::raw::spreadsheet::sheet::worksheet::conditional_formatting::XlsxConditionalFormatting;
+
+/// A conditional formatting rule with the cells it applies to.
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ConditionalFormattingRule {
+    /// Sequence of references (cell ranges) of the conditional formatting the rule belongs to
+    pub sqref: String,
+
+    /// Rule type, ie: `cellIs`, `expression`, `colorScale`, `dataBar`, `iconSet`, `top10`
+    pub r#type: String,
+
+    /// Rules are evaluated by increasing priority, 1 first.
+    pub priority: u64,
+
+    /// Operator of `cellIs` rules, ie: `between`, `greaterThan`
+    pub operator: Option<String>,
+
+    /// Formulas of the rule, without the leading `=`
+    pub formulas: Vec<String>,
+
+    /// Text of `containsText`, `beginsWith` and `endsWith` rules
+    pub text: Option<String>,
+
+    /// Rules with a lower priority are not evaluated when this rule applies.
+    pub stop_if_true: bool,
+
+    /// Index of the differential format (`dxfs` of the stylesheet) applied by the rule
+    pub dxf_id: Option<u64>,
+}
+
+impl ConditionalFormattingRule {
+    pub(crate) fn from_raw(raw: &XlsxConditionalFormatting) -> Vec<Self> {
+        let sqref = raw.sqref.clone().unwrap_or_default();
+        raw.rules
+            .iter()
+            .map(|rule| Self {
+                sqref: sqref.clone(),
+                r#type: rule.r#type.clone().unwrap_or_default(),
+                priority: rule.priority.unwrap_or(u64::MAX),
+                operator: rule.operator.clone(),
+                formulas: rule.formulas.clone(),
+                text: rule.text.clone(),
+                stop_if_true: rule.stop_if_true.unwrap_or(false),
+                dxf_id: rule.dxf_id,
+            })
+            .collect()
+    }
+}
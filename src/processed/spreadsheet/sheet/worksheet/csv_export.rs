@@ -0,0 +1,85 @@
+use crate::common_types::Dimension;
+
+/// When fields are enclosed in quotes by `Worksheet::to_csv`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CsvQuoting {
+    /// Fields holding the delimiter, a quote or a line break, as RFC 4180 requires.
+    #[default]
+    Necessary,
+
+    /// Every field, empty fields included.
+    Always,
+
+    /// Fields that are not numbers, ie: to keep `00123` or `1E5` as text when the file is opened again.
+    NonNumeric,
+
+    /// No field, written as is: the output cannot be read back if a value holds the delimiter or a line break.
+    Never,
+}
+
+/// Options for `Worksheet::to_csv`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CsvOptions {
+    /// Separator of the fields. Default to `,`, `;` is common in locales using the comma as decimal separator.
+    pub delimiter: char,
+
+    /// Default to `CsvQuoting::Necessary`.
+    pub quoting: CsvQuoting,
+
+    /// Write values as Excel displays them, applying the number formats, see `Cell::formatted_value`,
+    /// and zeros blank if the sheet does not show them, see `Worksheet::show_zeros`.
+    /// Otherwise the plain values are written: numbers unformatted, formulas with their last calculated value.
+    /// Default to true.
+    pub formatted: bool,
+
+    /// Cells written, clamped to the worksheet dimension. Default to None: every cell of the worksheet.
+    pub range: Option<Dimension>,
+
+    /// End records with `\r\n` instead of `\n`. Default to false.
+    pub crlf: bool,
+
+    /// Add a last field to each record with the comments of its cells, see `Worksheet::comments`
+    /// and `Worksheet::comment_threads`: `B2: text` for each commented cell, separated by line breaks.
+    /// Default to false.
+    pub include_comments: bool,
+}
+
+impl Default for CsvOptions {
+    fn default() -> Self {
+        Self {
+            delimiter: ',',
+            quoting: CsvQuoting::Necessary,
+            formatted: true,
+            range: None,
+            crlf: false,
+            include_comments: false,
+        }
+    }
+}
+
+impl CsvOptions {
+    /// Field quoted and escaped according to the options.
+    pub(crate) fn field(&self, value: &str) -> String {
+        let quoted = match self.quoting {
+            CsvQuoting::Necessary => value.contains([self.delimiter, '"', '\n', '\r']),
+            CsvQuoting::Always => true,
+            CsvQuoting::NonNumeric => value.parse::<f64>().is_err(),
+            CsvQuoting::Never => false,
+        };
+        if !quoted {
+            return value.to_string();
+        }
+        return format!("\"{}\"", value.replace('"', "\"\""));
+    }
+
+    /// Fields joined by the delimiter, with the line terminator.
+    pub(crate) fn record<S: AsRef<str>>(&self, values: &[S]) -> String {
+        let mut record = values
+            .iter()
+            .map(|value| self.field(value.as_ref()))
+            .collect::<Vec<String>>()
+            .join(&self.delimiter.to_string());
+        record.push_str(if self.crlf { "\r\n" } else { "\n" });
+        return record;
+    }
+}
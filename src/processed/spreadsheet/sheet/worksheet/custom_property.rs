@@ -0,0 +1,18 @@
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+/// Custom property of a worksheet, stored by add-ins as an opaque binary part.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct CustomProperty {
+    /// Property name
+    pub name: String,
+
+    /// Path of the part holding the data, ie: `xl/customProperty1.bin`.
+    pub path: String,
+
+    /// Content of the part.
+    ///
+    /// Usually UTF-16LE text, but the format is up to the add-in that wrote it.
+    pub data: Vec<u8>,
+}
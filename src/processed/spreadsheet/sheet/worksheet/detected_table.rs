@@ -0,0 +1,138 @@
+use std::collections::{BTreeSet, HashSet, VecDeque};
+
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+use crate::{
+    common_types::{Coordinate, Dimension},
+    raw::spreadsheet::sheet::worksheet::cell::XlsxCell,
+};
+
+/// A rectangular block of data found by `Worksheet::detect_tables`.
+///
+/// Unlike `Table`, this is not defined in the file, but guessed from the layout of the cells.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct DetectedTable {
+    /// Range of the block, bounded by empty rows and columns.
+    pub range: Dimension,
+
+    /// Row index (1 based) of the candidate header row.
+    ///
+    /// The first row is a header candidate if the block has more than one row
+    /// and every cell within the first row contains text.
+    pub header_row: Option<u64>,
+}
+
+impl DetectedTable {
+    /// Find blocks of non empty cells.
+    ///
+    /// Cells touching each other, including diagonally, belong to the same block.
+    /// Blocks whose bounding ranges overlap or touch are merged.
+    pub(crate) fn detect<'a>(cells: impl Iterator<Item = &'a XlsxCell>) -> Vec<Self> {
+        let mut occupied: BTreeSet<Coordinate> = BTreeSet::new();
+        let mut text: HashSet<Coordinate> = HashSet::new();
+
+        for cell in cells {
+            let Some(coordinate) = cell.coordinate() else {
+                continue;
+            };
+            let has_value = cell.formula.is_some()
+                || cell.inline_string.is_some()
+                || cell
+                    .cell_value
+                    .as_ref()
+                    .is_some_and(|v| !v.raw_value.is_empty());
+            if !has_value {
+                continue;
+            }
+            occupied.insert(coordinate);
+            if matches!(cell.r#type.as_deref(), Some("s") | Some("str") | Some("inlineStr")) {
+                text.insert(coordinate);
+            }
+        }
+
+        let mut ranges = Self::connected_ranges(&occupied);
+        Self::merge_touching(&mut ranges);
+        ranges.sort();
+
+        ranges
+            .into_iter()
+            .map(|range| {
+                let header_row = if range.end.row > range.start.row
+                    && (range.start.col..=range.end.col)
+                        .all(|col| text.contains(&Coordinate::from_point((range.start.row, col))))
+                {
+                    Some(range.start.row)
+                } else {
+                    None
+                };
+                Self { range, header_row }
+            })
+            .collect()
+    }
+
+    /// Bounding range of each group of connected cells.
+    fn connected_ranges(occupied: &BTreeSet<Coordinate>) -> Vec<Dimension> {
+        let mut visited: HashSet<Coordinate> = HashSet::new();
+        let mut ranges: Vec<Dimension> = vec![];
+
+        for start in occupied.iter() {
+            if !visited.insert(*start) {
+                continue;
+            }
+            let mut range = Dimension {
+                start: *start,
+                end: *start,
+            };
+            let mut queue = VecDeque::from([*start]);
+
+            while let Some(current) = queue.pop_front() {
+                range.start.row = range.start.row.min(current.row);
+                range.start.col = range.start.col.min(current.col);
+                range.end.row = range.end.row.max(current.row);
+                range.end.col = range.end.col.max(current.col);
+
+                for row in current.row.saturating_sub(1)..=current.row.saturating_add(1) {
+                    for col in current.col.saturating_sub(1)..=current.col.saturating_add(1) {
+                        let neighbor = Coordinate::from_point((row, col));
+                        if occupied.contains(&neighbor) && visited.insert(neighbor) {
+                            queue.push_back(neighbor);
+                        }
+                    }
+                }
+            }
+            ranges.push(range);
+        }
+
+        ranges
+    }
+
+    fn merge_touching(ranges: &mut Vec<Dimension>) {
+        let touching = |a: &Dimension, b: &Dimension| {
+            a.start.row <= b.end.row.saturating_add(1)
+                && b.start.row <= a.end.row.saturating_add(1)
+                && a.start.col <= b.end.col.saturating_add(1)
+                && b.start.col <= a.end.col.saturating_add(1)
+        };
+
+        let mut merged = true;
+        while merged {
+            merged = false;
+            'outer: for i in 0..ranges.len() {
+                for j in (i + 1)..ranges.len() {
+                    if touching(&ranges[i], &ranges[j]) {
+                        let other = ranges.remove(j);
+                        let range = &mut ranges[i];
+                        range.start.row = range.start.row.min(other.start.row);
+                        range.start.col = range.start.col.min(other.start.col);
+                        range.end.row = range.end.row.max(other.end.row);
+                        range.end.col = range.end.col.max(other.end.col);
+                        merged = true;
+                        break 'outer;
+                    }
+                }
+            }
+        }
+    }
+}
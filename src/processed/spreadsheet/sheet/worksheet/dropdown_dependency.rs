@@ -0,0 +1,270 @@
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+use crate::common_types::{Coordinate, Dimension};
+
+use super::data_validation::DataValidation;
+
+/// Function of a list validation that makes the list depend on another cell.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum DropdownFunction {
+    /// `INDIRECT($A2)`: the value of the controller cell is the name of the range listing the items.
+    Indirect,
+    /// `OFFSET(Items,MATCH($A2,Categories,0)-1,...)`: the value of the controller cell positions the range listing the items.
+    Offset,
+}
+
+/// A dropdown whose items depend on the value of another cell (cascading dropdowns).
+///
+/// Example: with `<formula1>INDIRECT($A2)</formula1>` on `B2:B10`, choosing a country in `A2` restricts the dropdown of `B2` to the cities of the range named after that country.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct DropdownDependency {
+    /// Cells the controller cells select the list of.
+    pub dependent: Dimension,
+
+    /// Cells whose values select the list, one per dependent cell along the relative axes of the reference: `A2:A10` for `$A2` on `B2:B10`.
+    pub controller: Dimension,
+
+    pub function: DropdownFunction,
+
+    /// List formula of the validation
+    pub formula: String,
+}
+
+impl DropdownDependency {
+    /// Controller cell of a dependent cell.
+    ///
+    /// None if the coordinate is not within the dependent cells.
+    pub fn controller_for(&self, coordinate: Coordinate) -> Option<Coordinate> {
+        if !self.dependent.contains(&coordinate) {
+            return None;
+        }
+        let row = if self.controller.start.row == self.controller.end.row {
+            self.controller.start.row
+        } else {
+            self.controller.start.row + coordinate.row - self.dependent.start.row
+        };
+        let col = if self.controller.start.col == self.controller.end.col {
+            self.controller.start.col
+        } else {
+            self.controller.start.col + coordinate.col - self.dependent.start.col
+        };
+        return Some(Coordinate::from_point((row, col)));
+    }
+
+    /// Dependencies of a list validation using `INDIRECT` or `OFFSET` keyed by cells of the same sheet.
+    ///
+    /// References of the formula are relative to the top left cell of the first range of the validation.
+    pub(crate) fn from_data_validation(validation: &DataValidation) -> Vec<Self> {
+        if validation.r#type != "list" {
+            return vec![];
+        }
+        let Some(formula) = validation.formula1.as_ref() else {
+            return vec![];
+        };
+        let formula = quick_xml::escape::unescape(formula)
+            .map(|f| f.to_string())
+            .unwrap_or(formula.clone());
+        let ranges = Dimension::from_sqref(&validation.sqref);
+        let Some(anchor) = ranges.first().map(|r| r.start) else {
+            return vec![];
+        };
+
+        let mut dependencies: Vec<Self> = vec![];
+        for (function, arguments) in keyed_calls(&formula) {
+            for reference in cell_references(&arguments) {
+                for range in &ranges {
+                    let dependency = Self {
+                        dependent: *range,
+                        controller: Dimension {
+                            start: reference.shift(anchor, range.start),
+                            end: reference.shift(anchor, range.end),
+                        },
+                        function,
+                        formula: formula.clone(),
+                    };
+                    if !dependencies.contains(&dependency) {
+                        dependencies.push(dependency);
+                    }
+                }
+            }
+        }
+        return dependencies;
+    }
+}
+
+/// Single cell reference of a formula, ie: `$A2`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct CellReference {
+    row: u64,
+    col: u64,
+    row_absolute: bool,
+    col_absolute: bool,
+}
+
+impl CellReference {
+    /// Cell referenced from `to` by a reference written relative to `from`.
+    fn shift(&self, from: Coordinate, to: Coordinate) -> Coordinate {
+        let row = match self.row_absolute {
+            true => self.row,
+            false => (self.row + to.row).saturating_sub(from.row).max(1),
+        };
+        let col = match self.col_absolute {
+            true => self.col,
+            false => (self.col + to.col).saturating_sub(from.col).max(1),
+        };
+        return Coordinate::from_point((row, col));
+    }
+}
+
+/// Arguments keying the list of the `INDIRECT` and `OFFSET` calls of a formula.
+///
+/// All arguments of `INDIRECT`, the arguments of `OFFSET` but the base reference.
+/// Strings are blanked out.
+fn keyed_calls(formula: &str) -> Vec<(DropdownFunction, String)> {
+    let chars = blank_strings(formula);
+    let mut calls: Vec<(DropdownFunction, String)> = vec![];
+    let mut index = 0;
+    while index < chars.len() {
+        let c = chars[index];
+        if !c.is_ascii_alphabetic() || (index > 0 && is_token_character(chars[index - 1])) {
+            index += 1;
+            continue;
+        }
+        let start = index;
+        while index < chars.len() && (chars[index].is_ascii_alphanumeric() || chars[index] == '.') {
+            index += 1;
+        }
+        if chars.get(index) != Some(&'(') {
+            continue;
+        }
+        let name: String = chars[start..index]
+            .iter()
+            .collect::<String>()
+            .to_uppercase();
+        let function = match name.as_str() {
+            "INDIRECT" => DropdownFunction::Indirect,
+            "OFFSET" => DropdownFunction::Offset,
+            _ => continue,
+        };
+
+        // arguments, up to the matching parenthesis
+        let mut depth = 0;
+        let mut end = index;
+        let mut first_separator: Option<usize> = None;
+        while end < chars.len() {
+            match chars[end] {
+                '(' => depth += 1,
+                ')' => {
+                    depth -= 1;
+                    if depth == 0 {
+                        break;
+                    }
+                }
+                ',' if depth == 1 && first_separator.is_none() => first_separator = Some(end),
+                _ => (),
+            }
+            end += 1;
+        }
+        let arguments_start = match function {
+            DropdownFunction::Indirect => index + 1,
+            DropdownFunction::Offset => match first_separator {
+                Some(separator) => separator + 1,
+                None => end,
+            },
+        };
+        let arguments: String = chars[arguments_start.min(end)..end].iter().collect();
+        calls.push((function, arguments));
+        // calls nested in the arguments are keyed by the same cells
+        index = end;
+    }
+    return calls;
+}
+
+/// Single cell references to the same sheet: neither qualified by a sheet, nor part of a range.
+fn cell_references(formula: &str) -> Vec<CellReference> {
+    let chars: Vec<char> = formula.chars().collect();
+    let mut references: Vec<CellReference> = vec![];
+    let mut index = 0;
+    while index < chars.len() {
+        let c = chars[index];
+        if !(c == '$' || c.is_ascii_alphabetic()) {
+            index += 1;
+            continue;
+        }
+        let previous = index.checked_sub(1).map(|i| chars[i]);
+        let start = index;
+        while index < chars.len() && (is_token_character(chars[index]) || chars[index] == '$') {
+            index += 1;
+        }
+        if matches!(previous, Some(p) if is_token_character(p) || matches!(p, '!' | ':' | '$' | '\'' | ']'))
+        {
+            continue;
+        }
+        if matches!(chars.get(index), Some('(' | '!' | ':')) {
+            continue;
+        }
+        let token: String = chars[start..index].iter().collect();
+        if let Some(reference) = parse_cell_reference(&token) {
+            if !references.contains(&reference) {
+                references.push(reference);
+            }
+        }
+    }
+    return references;
+}
+
+/// `$A$1`, `A1`, `$A1` or `A$1`
+fn parse_cell_reference(token: &str) -> Option<CellReference> {
+    let col_absolute = token.starts_with('$');
+    let token = token.trim_start_matches('$');
+    let letters = token
+        .chars()
+        .take_while(|c| c.is_ascii_alphabetic())
+        .count();
+    if letters == 0 || letters > 3 {
+        return None;
+    }
+    let (letters, rest) = token.split_at(letters);
+    let row_absolute = rest.starts_with('$');
+    let digits = rest.trim_start_matches('$');
+    if digits.is_empty() || !digits.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    let coordinate =
+        Coordinate::from_a1(format!("{}{}", letters.to_uppercase(), digits).as_bytes())?;
+    return Some(CellReference {
+        row: coordinate.row,
+        col: coordinate.col,
+        row_absolute,
+        col_absolute,
+    });
+}
+
+/// Characters of the formula, with the content of strings and quoted sheet names replaced by spaces.
+fn blank_strings(formula: &str) -> Vec<char> {
+    let mut delimiter: Option<char> = None;
+    return formula
+        .chars()
+        .map(|c| match delimiter {
+            Some(d) if c == d => {
+                delimiter = None;
+                c
+            }
+            Some(_) => ' ',
+            None => {
+                if c == '"' || c == '\'' {
+                    delimiter = Some(c);
+                }
+                c
+            }
+        })
+        .collect();
+}
+
+fn is_token_character(c: char) -> bool {
+    c.is_alphanumeric() || c == '_' || c == '.' || c == '\\'
+}
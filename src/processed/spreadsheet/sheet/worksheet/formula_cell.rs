@@ -0,0 +1,101 @@
+use std::collections::HashMap;
+
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+use crate::{
+    common_types::{Coordinate, Dimension},
+    raw::spreadsheet::sheet::worksheet::cell::cell_formula::XlsxCellFormula,
+};
+
+use super::cell::cell_property::numbering_format::NumberingFormat;
+
+/// Kind of a formula, from the `t` attribute of the `f` element.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum FormulaKind {
+    /// Formula of a single cell.
+    Normal,
+    /// Formula shared by a range of cells, with its shared group index.
+    /// The formula of the cells other than the master cell is expanded from the master formula.
+    Shared(u64),
+    /// Array formula, entered on a range of cells.
+    Array,
+    /// What-if analysis data table.
+    DataTable,
+}
+
+impl FormulaKind {
+    pub(crate) fn from_raw(formula: &XlsxCellFormula) -> Self {
+        return match (formula.r#type.as_deref(), formula.shared_group_index) {
+            (Some("shared"), Some(index)) => Self::Shared(index),
+            (Some("array"), _) => Self::Array,
+            (Some("dataTable"), _) => Self::DataTable,
+            _ => Self::Normal,
+        };
+    }
+}
+
+/// A cell holding a formula.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct FormulaCell {
+    pub coordinate: Coordinate,
+
+    /// formula without the leading `=`
+    pub formula: String,
+
+    /// value from the last calculation saved in the file, as stored
+    pub cached_value: Option<String>,
+
+    pub kind: FormulaKind,
+
+    /// Range the formula applies to, only set on the master cell of shared, array and data table formulas.
+    pub range: Option<Dimension>,
+
+    /// Number format of the cell, the format the cached value is displayed with.
+    pub numbering_format: NumberingFormat,
+}
+
+/// Cells calculated together: the cells of a shared formula group, an array formula, a data table,
+/// or a single cell for other formulas.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct FormulaFamily {
+    pub kind: FormulaKind,
+
+    /// Formula of the master cell, the first cell of the family.
+    pub formula: String,
+
+    /// Range of the family as declared by its master cell.
+    pub range: Option<Dimension>,
+
+    /// Cells of the family ordered row by row.
+    /// Array formulas and data tables only store their formula in the master cell.
+    pub cells: Vec<FormulaCell>,
+}
+
+impl FormulaFamily {
+    /// Group formula cells, ordered row by row, into families ordered by their first cell.
+    pub(crate) fn group(cells: Vec<FormulaCell>) -> Vec<FormulaFamily> {
+        let mut families: Vec<FormulaFamily> = vec![];
+        let mut shared_families: HashMap<u64, usize> = HashMap::new();
+        for cell in cells {
+            if let FormulaKind::Shared(index) = cell.kind {
+                if let Some(family) = shared_families.get(&index) {
+                    families[*family].cells.push(cell);
+                    continue;
+                }
+                shared_families.insert(index, families.len());
+            }
+            families.push(FormulaFamily {
+                kind: cell.kind.clone(),
+                formula: cell.formula.clone(),
+                range: cell.range,
+                cells: vec![cell],
+            });
+        }
+        return families;
+    }
+}
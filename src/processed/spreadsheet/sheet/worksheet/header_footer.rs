@@ -0,0 +1,257 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::raw::spreadsheet::sheet::worksheet::header_footer::XlsxHeaderFooter;
+
+/// Headers and footers printed on the pages of a worksheet, see `Worksheet::header_footer`.
+///
+/// The formatting codes of the text are decoded into left, center and right sections.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct HeaderFooter {
+    /// The first page has its own header and footer.
+    pub different_first: bool,
+
+    /// Even pages have their own header and footer.
+    pub different_odd_even: bool,
+
+    /// Header and footer of every page, or of odd pages when `different_odd_even` is set.
+    pub odd_header: Option<HeaderFooterText>,
+    pub odd_footer: Option<HeaderFooterText>,
+
+    pub even_header: Option<HeaderFooterText>,
+    pub even_footer: Option<HeaderFooterText>,
+
+    pub first_header: Option<HeaderFooterText>,
+    pub first_footer: Option<HeaderFooterText>,
+}
+
+/// A header or a footer, split in sections.
+///
+/// Text before any section code is in the center section, like Excel displays it.
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct HeaderFooterText {
+    pub left: Vec<HeaderFooterToken>,
+    pub center: Vec<HeaderFooterToken>,
+    pub right: Vec<HeaderFooterToken>,
+}
+
+/// A part of a header or footer section: text, a field replaced when printing, or a formatting code applying to what follows.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum HeaderFooterToken {
+    Text(String),
+
+    /// &P
+    PageNumber,
+    /// &N
+    PageCount,
+    /// &D
+    Date,
+    /// &T
+    Time,
+    /// &Z
+    FilePath,
+    /// &F
+    FileName,
+    /// &A
+    SheetName,
+    /// &G
+    Picture,
+
+    /// &"font name,font style", ie: `&"Arial,Bold"`. `-` stands for the current font name or the regular style.
+    Font {
+        name: String,
+        style: String,
+    },
+    /// &nn, size in points
+    FontSize(u64),
+    /// &K followed by a RRGGBB color, or a theme color and its tint or shade, ie: `01+000`
+    Color(String),
+
+    /// &B
+    Bold,
+    /// &I
+    Italic,
+    /// &U
+    Underline,
+    /// &E
+    DoubleUnderline,
+    /// &S
+    Strikethrough,
+    /// &X
+    Superscript,
+    /// &Y
+    Subscript,
+    /// &O
+    Outline,
+    /// &H
+    Shadow,
+}
+
+/// Values of the fields when rendering headers and footers as plain text, see `HeaderFooterText::render`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct HeaderFooterValues {
+    pub page_number: u64,
+    pub page_count: u64,
+    pub date: String,
+    pub time: String,
+    pub file_path: String,
+    pub file_name: String,
+    pub sheet_name: String,
+}
+
+impl HeaderFooter {
+    pub(crate) fn from_raw(raw: &XlsxHeaderFooter) -> Self {
+        let decode = |text: &Option<String>| text.as_deref().map(HeaderFooterText::decode);
+        return Self {
+            different_first: raw.different_first.unwrap_or(false),
+            different_odd_even: raw.different_odd_even.unwrap_or(false),
+            odd_header: decode(&raw.odd_header),
+            odd_footer: decode(&raw.odd_footer),
+            even_header: decode(&raw.even_header),
+            even_footer: decode(&raw.even_footer),
+            first_header: decode(&raw.first_header),
+            first_footer: decode(&raw.first_footer),
+        };
+    }
+
+    /// Header printed on a page (1 based), taking different first and odd/even pages into account.
+    pub fn header_for_page(&self, page_number: u64) -> Option<&HeaderFooterText> {
+        if self.different_first && page_number == 1 {
+            return self.first_header.as_ref();
+        }
+        if self.different_odd_even && page_number % 2 == 0 {
+            return self.even_header.as_ref();
+        }
+        return self.odd_header.as_ref();
+    }
+
+    /// Footer printed on a page (1 based), taking different first and odd/even pages into account.
+    pub fn footer_for_page(&self, page_number: u64) -> Option<&HeaderFooterText> {
+        if self.different_first && page_number == 1 {
+            return self.first_footer.as_ref();
+        }
+        if self.different_odd_even && page_number % 2 == 0 {
+            return self.even_footer.as_ref();
+        }
+        return self.odd_footer.as_ref();
+    }
+}
+
+impl HeaderFooterText {
+    /// Decode the formatting codes of a header or footer, ie: `&LPage &P of &N&R&"Arial,Bold"&D`.
+    ///
+    /// Unknown codes are dropped, `&&` is a literal ampersand.
+    pub fn decode(text: &str) -> Self {
+        let mut decoded = Self::default();
+        let mut current = &mut decoded.center;
+        let mut literal = String::new();
+        let mut chars = text.chars().peekable();
+
+        while let Some(c) = chars.next() {
+            if c != '&' {
+                literal.push(c);
+                continue;
+            }
+            let Some(&code) = chars.peek() else {
+                break;
+            };
+            if code == '&' {
+                chars.next();
+                literal.push('&');
+                continue;
+            }
+            if !literal.is_empty() {
+                current.push(HeaderFooterToken::Text(std::mem::take(&mut literal)));
+            }
+
+            if code.is_ascii_digit() {
+                let mut size = String::new();
+                while let Some(digit) = chars.peek().filter(|c| c.is_ascii_digit()) {
+                    size.push(*digit);
+                    chars.next();
+                }
+                if let Ok(size) = size.parse::<u64>() {
+                    current.push(HeaderFooterToken::FontSize(size));
+                }
+                continue;
+            }
+
+            chars.next();
+            let token = match code {
+                'L' => {
+                    current = &mut decoded.left;
+                    continue;
+                }
+                'C' => {
+                    current = &mut decoded.center;
+                    continue;
+                }
+                'R' => {
+                    current = &mut decoded.right;
+                    continue;
+                }
+                '"' => {
+                    let font: String = chars.by_ref().take_while(|c| *c != '"').collect();
+                    let (name, style) = font.split_once(',').unwrap_or((&font, "-"));
+                    HeaderFooterToken::Font {
+                        name: name.to_string(),
+                        style: style.to_string(),
+                    }
+                }
+                'K' => HeaderFooterToken::Color(chars.by_ref().take(6).collect()),
+                'P' => HeaderFooterToken::PageNumber,
+                'N' => HeaderFooterToken::PageCount,
+                'D' => HeaderFooterToken::Date,
+                'T' => HeaderFooterToken::Time,
+                'Z' => HeaderFooterToken::FilePath,
+                'F' => HeaderFooterToken::FileName,
+                'A' => HeaderFooterToken::SheetName,
+                'G' => HeaderFooterToken::Picture,
+                'B' => HeaderFooterToken::Bold,
+                'I' => HeaderFooterToken::Italic,
+                'U' => HeaderFooterToken::Underline,
+                'E' => HeaderFooterToken::DoubleUnderline,
+                'S' => HeaderFooterToken::Strikethrough,
+                'X' => HeaderFooterToken::Superscript,
+                'Y' => HeaderFooterToken::Subscript,
+                'O' => HeaderFooterToken::Outline,
+                'H' => HeaderFooterToken::Shadow,
+                _ => continue,
+            };
+            current.push(token);
+        }
+        if !literal.is_empty() {
+            current.push(HeaderFooterToken::Text(literal));
+        }
+
+        return decoded;
+    }
+
+    /// Text of the (left, center, right) sections with the fields replaced by their values, formatting dropped.
+    pub fn render(&self, values: &HeaderFooterValues) -> (String, String, String) {
+        let render = |tokens: &[HeaderFooterToken]| -> String {
+            tokens
+                .iter()
+                .map(|token| match token {
+                    HeaderFooterToken::Text(text) => text.clone(),
+                    HeaderFooterToken::PageNumber => values.page_number.to_string(),
+                    HeaderFooterToken::PageCount => values.page_count.to_string(),
+                    HeaderFooterToken::Date => values.date.clone(),
+                    HeaderFooterToken::Time => values.time.clone(),
+                    HeaderFooterToken::FilePath => values.file_path.clone(),
+                    HeaderFooterToken::FileName => values.file_name.clone(),
+                    HeaderFooterToken::SheetName => values.sheet_name.clone(),
+                    _ => String::new(),
+                })
+                .collect()
+        };
+        return (
+            render(&self.left),
+            render(&self.center),
+            render(&self.right),
+        );
+    }
+}
@@ -0,0 +1,88 @@
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+use crate::common_types::{Coordinate, Text};
+
+/// Characters that look like a space, or nothing at all, but do not compare equal to one.
+static INVISIBLE_CHARACTERS: &[char] = &[
+    // no-break space
+    '\u{00A0}',
+    // soft hyphen
+    '\u{00AD}',
+    // mongolian vowel separator
+    '\u{180E}',
+    // figure space
+    '\u{2007}',
+    // zero width space
+    '\u{200B}',
+    // zero width non-joiner
+    '\u{200C}',
+    // zero width joiner
+    '\u{200D}',
+    // left-to-right and right-to-left marks
+    '\u{200E}',
+    '\u{200F}',
+    // narrow no-break space
+    '\u{202F}',
+    // word joiner
+    '\u{2060}',
+    // zero width no-break space (byte order mark)
+    '\u{FEFF}',
+];
+
+/// A text cell containing characters that commonly break comparisons and joins.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct InvisibleCharacterReport {
+    pub coordinate: Coordinate,
+
+    pub text: Text,
+
+    /// non-breaking spaces, zero width and other invisible characters found in the text.
+    pub characters: Vec<InvisibleCharacter>,
+
+    /// Text starts with whitespace
+    pub leading_whitespace: bool,
+
+    /// Text ends with whitespace
+    pub trailing_whitespace: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct InvisibleCharacter {
+    /// Unicode code point, ie: 0xA0
+    pub code_point: u32,
+
+    /// Index of the character (not byte) within the text, 0 based.
+    pub position: usize,
+}
+
+impl InvisibleCharacterReport {
+    /// None if the text is clean.
+    pub(crate) fn from_text(coordinate: Coordinate, text: Text) -> Option<Self> {
+        let characters: Vec<InvisibleCharacter> = text
+            .chars()
+            .enumerate()
+            .filter(|(_, c)| INVISIBLE_CHARACTERS.contains(c))
+            .map(|(position, c)| InvisibleCharacter {
+                code_point: c as u32,
+                position,
+            })
+            .collect();
+        let leading_whitespace = text.starts_with(char::is_whitespace);
+        let trailing_whitespace = text.ends_with(char::is_whitespace);
+
+        if characters.is_empty() && !leading_whitespace && !trailing_whitespace {
+            return None;
+        }
+
+        Some(Self {
+            coordinate,
+            text,
+            characters,
+            leading_whitespace,
+            trailing_whitespace,
+        })
+    }
+}
@@ -0,0 +1,163 @@
+use serde::{ser::SerializeMap, Serialize, Serializer};
+use serde_json::Value;
+
+use super::cell::{cell_value::typed_value::CellValue, Cell};
+use crate::{common_types::Dimension, helper::col_to_letters};
+
+/// Largest integer represented exactly by a JavaScript number.
+const MAX_SAFE_INTEGER: f64 = 9_007_199_254_740_991.0;
+
+/// Layout of the rows written by `Worksheet::to_json`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum JsonShape {
+    /// An array of rows, each row an array of values: `[["name", "age"], ["Ann", 31]]`.
+    #[default]
+    Arrays,
+
+    /// An array of objects keyed by the first row of the range, the header: `[{"name": "Ann", "age": 31}]`.
+    ///
+    /// Header cells are trimmed, empty header cells are keyed by their column letter,
+    /// and repeated names are suffixed by their occurrence: `name`, `name_2`.
+    Objects,
+}
+
+/// How empty cells are written by `Worksheet::to_json`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum JsonNulls {
+    /// `null`.
+    #[default]
+    Null,
+
+    /// `""`, as formatted values of empty cells.
+    EmptyString,
+
+    /// Left out of objects, `null` in arrays where the position of the values matters.
+    Omit,
+}
+
+/// Options for `Worksheet::to_json`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct JsonOptions {
+    /// Default to `JsonShape::Arrays`.
+    pub shape: JsonShape,
+
+    /// Write values as Excel displays them, strings applying the number formats, see `Cell::formatted_value`,
+    /// and zeros blank if the sheet does not show them, see `Worksheet::show_zeros`.
+    /// Otherwise values are typed: numbers, booleans, strings, dates as ISO 8601 strings and errors as their code.
+    /// Default to true.
+    pub formatted: bool,
+
+    /// Default to `JsonNulls::Null`.
+    pub nulls: JsonNulls,
+
+    /// Leave out rows without any value. Default to false.
+    pub skip_empty_rows: bool,
+
+    /// Cells written, clamped to the worksheet dimension. Default to None: every cell of the worksheet.
+    pub range: Option<Dimension>,
+
+    /// Add the comments of the cells of each row, see `Worksheet::comments` and `Worksheet::comment_threads`,
+    /// as an object keyed by cell reference: `{"B2": "text"}`, empty for rows without comment.
+    /// The object is the last element of arrays, and the value of the `comments` key of objects,
+    /// suffixed as the header names if the header already has a `comments` column.
+    /// Default to false.
+    pub include_comments: bool,
+}
+
+impl Default for JsonOptions {
+    fn default() -> Self {
+        Self {
+            shape: JsonShape::Arrays,
+            formatted: true,
+            nulls: JsonNulls::Null,
+            skip_empty_rows: false,
+            range: None,
+            include_comments: false,
+        }
+    }
+}
+
+impl JsonOptions {
+    /// Value of a cell, None for empty cells.
+    pub(crate) fn value(&self, cell: &Cell, is_1904: bool, show_zeros: bool) -> Option<Value> {
+        let value = cell.typed_value(is_1904);
+        if value.is_blank() {
+            return None;
+        }
+        if self.formatted {
+            if !show_zeros && cell.is_zero() {
+                return Some(Value::String(String::new()));
+            }
+            return Some(Value::String(cell.formatted_value(is_1904)));
+        }
+        return Some(match value {
+            // integers without a fraction, as JavaScript prints them
+            CellValue::Number(number)
+                if number.fract() == 0.0 && number.abs() <= MAX_SAFE_INTEGER =>
+            {
+                Value::from(number as i64)
+            }
+            CellValue::Number(number) => serde_json::Number::from_f64(number)
+                .map_or(Value::String(number.to_string()), Value::Number),
+            CellValue::Bool(b) => Value::Bool(b),
+            CellValue::Text(text) => Value::String(text),
+            value => Value::String(value.to_string()),
+        });
+    }
+
+    /// Value written for an empty cell, None to leave it out.
+    pub(crate) fn null(&self, in_object: bool) -> Option<Value> {
+        match self.nulls {
+            JsonNulls::Null => Some(Value::Null),
+            JsonNulls::EmptyString => Some(Value::String(String::new())),
+            JsonNulls::Omit if in_object => None,
+            JsonNulls::Omit => Some(Value::Null),
+        }
+    }
+}
+
+/// Keys of the objects from the header cells, see `JsonShape::Objects`.
+pub(crate) fn header_keys(header: &[Cell], is_1904: bool) -> Vec<String> {
+    let mut keys: Vec<String> = vec![];
+    for cell in header {
+        let name = cell.formatted_value(is_1904).trim().to_string();
+        let name = match name.is_empty() {
+            true => col_to_letters(cell.coordinate.col),
+            false => name,
+        };
+        keys.push(unique_key(&keys, name));
+    }
+    return keys;
+}
+
+/// Name suffixed by its occurrence among the keys, if it is already one of them.
+pub(crate) fn unique_key(keys: &[String], name: String) -> String {
+    let mut key = name.clone();
+    let mut occurrence = 1;
+    while keys.contains(&key) {
+        occurrence += 1;
+        key = format!("{}_{}", name, occurrence);
+    }
+    return key;
+}
+
+/// Row serialized as an array, or as an object keeping the order of the columns.
+pub(crate) enum JsonRow<'a> {
+    Array(Vec<Value>),
+    Object(Vec<(&'a str, Value)>),
+}
+
+impl Serialize for JsonRow<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            JsonRow::Array(values) => values.serialize(serializer),
+            JsonRow::Object(fields) => {
+                let mut map = serializer.serialize_map(Some(fields.len()))?;
+                for (key, value) in fields {
+                    map.serialize_entry(key, value)?;
+                }
+                map.end()
+            }
+        }
+    }
+}
@@ -0,0 +1,98 @@
+use regex::Regex;
+
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+use crate::common_types::{Coordinate, Text};
+
+/// Characters ending a sentence rather than a link, ie: `see https://example.com.`
+static TRAILING_PUNCTUATION: &[char] = &['.', ',', ';', ':', '!', '?', ')', ']', '}', '\'', '"'];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum LinkKind {
+    /// `http://`, `https://`, `ftp://` or `www.` address
+    Url,
+
+    /// email address, with or without `mailto:`
+    Email,
+}
+
+/// URL-like or email-like text found in a cell, see `Worksheet::find_link_candidates`.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct LinkCandidate {
+    pub coordinate: Coordinate,
+
+    /// text of the cell
+    pub text: Text,
+
+    /// link as found in the text, ie: `www.example.com`
+    pub matched: String,
+
+    /// link ready to follow: `http://` prepended to `www.` addresses, `mailto:` prepended to email addresses.
+    pub target: String,
+
+    pub kind: LinkKind,
+
+    /// A hyperlink record is attached to the cell.
+    pub has_hyperlink: bool,
+
+    /// The cell uses the `Hyperlink` or `Followed Hyperlink` cell style, or the hyperlink colors of the theme.
+    ///
+    /// Excel styles typed in links this way, even when the hyperlink itself is not stored.
+    pub styled_as_hyperlink: bool,
+}
+
+/// Find the URLs and email addresses within a text, in order of appearance.
+///
+/// Bare domains without `www.` or a scheme, ie: `example.com`, are not detected.
+pub(crate) fn detect_links(text: &str) -> Vec<(String, String, LinkKind)> {
+    let Ok(url_re) = Regex::new(r"(?i)\b(?:(?:https?|ftp)://|www\.)[^\s<>]+") else {
+        return vec![];
+    };
+    let Ok(email_re) =
+        Regex::new(r"(?i)\b(?:mailto:)?[a-z0-9._%+-]+@[a-z0-9-]+(?:\.[a-z0-9-]+)*\.[a-z]{2,}\b")
+    else {
+        return vec![];
+    };
+
+    let mut found: Vec<(usize, String, String, LinkKind)> = vec![];
+    let mut url_spans: Vec<(usize, usize)> = vec![];
+    for m in url_re.find_iter(text) {
+        let matched = m.as_str().trim_end_matches(TRAILING_PUNCTUATION);
+        if matched.len() <= "www.".len() {
+            continue;
+        }
+        let target = if matched.to_lowercase().starts_with("www.") {
+            format!("http://{}", matched)
+        } else {
+            matched.to_string()
+        };
+        url_spans.push((m.start(), m.start() + matched.len()));
+        found.push((m.start(), matched.to_string(), target, LinkKind::Url));
+    }
+    for m in email_re.find_iter(text) {
+        // user info of a url, ie: `ftp://user@example.com`
+        if url_spans
+            .iter()
+            .any(|(start, end)| m.start() < *end && *start < m.end())
+        {
+            continue;
+        }
+        let matched = m.as_str();
+        let target = if matched.to_lowercase().starts_with("mailto:") {
+            matched.to_string()
+        } else {
+            format!("mailto:{}", matched)
+        };
+        found.push((m.start(), matched.to_string(), target, LinkKind::Email));
+    }
+
+    found.sort_by_key(|(start, ..)| *start);
+    return found
+        .into_iter()
+        .map(|(_, matched, target, kind)| (matched, target, kind))
+        .collect();
+}
@@ -1,7 +1,40 @@
+pub mod auto_filter;
 pub mod calculation_reference;
 pub mod cell;
+pub mod column_profile;
+pub mod column_properties;
+pub mod columnar;
+pub mod comment;
+pub mod conditional_formatting;
+pub mod csv_export;
+pub mod custom_property;
 pub mod data_validation;
+pub mod detected_table;
+pub mod dropdown_dependency;
+pub mod formula_cell;
+pub mod header_footer;
+pub mod invisible_character;
+#[cfg(feature = "serde")]
+pub mod json_export;
+pub mod link_candidate;
+pub mod page_setup;
+pub mod pagination;
+pub mod range_index;
+pub mod resolved_style;
+pub mod row;
+pub mod row_change;
+#[cfg(feature = "serde")]
+pub mod row_deserializer;
+pub mod row_hash;
+pub mod row_properties;
+pub mod row_sample;
+#[cfg(feature = "serde")]
+pub mod row_stream;
+pub mod sheet_protection;
+pub mod sheet_view;
+pub mod stored_as_text;
 pub mod table;
+pub mod threaded_comment;
 
 #[cfg(feature = "serde")]
 use serde::Serialize;
@@ -10,37 +43,91 @@ use serde::Serialize;
 use std::collections::BTreeMap;
 
 use anyhow::bail;
-use rayon::iter::{IntoParallelIterator, ParallelIterator};
+#[cfg(feature = "rayon")]
+use rayon::iter::{IntoParallelIterator, IntoParallelRefIterator, ParallelIterator};
+use regex::Regex;
 use std::{
     cmp::{max, min},
-    time::Instant,
+    collections::{BTreeSet, HashMap},
+    io::{Read, Seek, Write},
+    sync::Arc,
     u64,
 };
 
 #[cfg(feature = "drawing")]
 use crate::processed::drawing::worksheet_drawing::{
-    anchor_type::DrawingAnchorType, content_type::DrawingContentType, WorksheetDrawing,
+    anchor_type::DrawingAnchorType, bounds::DrawingBounds, cell_marker::CellMarker,
+    content_type::DrawingContentType, image_placeholder::ImagePlaceholder, WorksheetDrawing,
 };
 
+#[cfg(feature = "drawing")]
+use crate::units::{pt_to_px, DEFAULT_DPI};
+
 #[cfg(feature = "drawing")]
 use crate::raw::drawing::worksheet_drawing::{XlsxWorksheetDrawing, XlsxWorksheetDrawingType};
 
 use calculation_reference::CalculationReferenceMode;
-use cell::{cell_property::CellProperty, cell_value::CellValueType, Cell};
+use cell::{
+    cell_property::{
+        numbering_format::NumberingFormat, text_alignment::TextAlignment, CellProperty,
+        DEFAULT_CELL_HEIGHT,
+    },
+    cell_value::CellValueType,
+    Cell,
+};
+use auto_filter::AutoFilter;
+use sheet_protection::SheetProtection;
+use columnar::ColumnarSheet;
+use comment::Comment;
+use conditional_formatting::ConditionalFormattingRule;
+use csv_export::CsvOptions;
 use data_validation::DataValidation;
+use detected_table::DetectedTable;
+use dropdown_dependency::DropdownDependency;
+use formula_cell::{FormulaCell, FormulaFamily, FormulaKind};
+use invisible_character::InvisibleCharacterReport;
+#[cfg(feature = "serde")]
+use json_export::{header_keys, unique_key, JsonOptions, JsonRow, JsonShape};
+#[cfg(feature = "serde")]
+use serde_json::Value;
+use link_candidate::{detect_links, LinkCandidate};
+use header_footer::HeaderFooter;
+use column_profile::SheetProfile;
+use column_properties::ColumnProperties;
+use row_properties::RowProperties;
+use page_setup::PageSetup;
+#[cfg(feature = "serde")]
+use row_deserializer::DeserializeRows;
+use sheet_view::SheetView;
+use pagination::{manual_breaks, print_areas, PageLayout, PrintPage};
+use range_index::RangeIndex;
+use resolved_style::ResolvedStyle;
+use row::{Row, Rows};
+use row_change::RowChangeEvents;
+use row_hash::{normalized_value, RowHash, RowValues};
+use row_sample::SampleStrategy;
+use stored_as_text::NumberStoredAsText;
 use table::Table;
+use threaded_comment::CommentThread;
 
 use crate::{
-    common_types::{Coordinate, Dimension},
-    packaging::relationship::XlsxRelationships,
-    processed::shared::hyperlink::Hyperlink,
+    common_types::{CellId, CellReference, Coordinate, Dimension, MAX_COLUMN_COUNT, MAX_ROW_COUNT},
+    excel::Excel,
+    packaging::relationship::{WorksheetRelationships, XlsxRelationships},
+    processed::{
+        shared::hyperlink::Hyperlink,
+        spreadsheet::search::{SearchLocation, SearchMatch, SearchOptions},
+    },
     raw::{
         drawing::{scheme::color_scheme::XlsxColorScheme, theme::XlsxTheme},
         spreadsheet::{
-            shared_string::shared_string_item::XlsxSharedStringItem,
+            comment::XlsxComments,
+            person::XlsxPersons,
+            shared_string::shared_string_table::SharedStrings,
             sheet::worksheet::{
                 cell::XlsxCell, column_information::XlsxColumnInformation,
-                hyperlink::XlsxHyperlink, row::XlsxRow, XlsxWorksheet,
+                hyperlink::XlsxHyperlink, row::XlsxRow, sheet_view::XlsxSelection,
+                XlsxWorksheet,
             },
             stylesheet::{
                 format::{
@@ -50,6 +137,8 @@ use crate::{
                 XlsxStyleSheet,
             },
             table::XlsxTable,
+            threaded_comment::XlsxThreadedComments,
+            vml_drawing::XlsxVmlDrawing,
             workbook::defined_name::XlsxDefinedNames,
         },
     },
@@ -68,9 +157,26 @@ pub struct Worksheet {
 
     pub tables: Vec<Table>,
 
+    /// AutoFilter of the worksheet, tables have their own.
+    pub auto_filter: Option<AutoFilter>,
+
     /// Data validation rules applied to cells in this worksheet.
     pub data_validations: Option<Vec<DataValidation>>,
 
+    /// Conditional formatting rules, in the order of the worksheet.
+    pub conditional_formatting_rules: Vec<ConditionalFormattingRule>,
+
+    /// Cell comments (legacy notes)
+    pub comments: Vec<Comment>,
+
+    /// Threaded comment conversations, with their replies and authors
+    pub comment_threads: Vec<CommentThread>,
+
+    /// Protection settings, None if the worksheet has no `sheetProtection` element.
+    ///
+    /// Check `SheetProtection::sheet` to know whether the protection is enforced.
+    pub protection: Option<SheetProtection>,
+
     /// Value that indicates whether to use a 1900 or 1904 date base when converting serial values in the workbook to dates.
     ///
     /// - true: workbook uses the 1904 backward compatibility date system.
@@ -81,7 +187,22 @@ pub struct Worksheet {
     /// Calculation Reference Mode
     pub calculation_reference_mode: CalculationReferenceMode,
 
+    /// Convert text that reads as a number or date to `Numeric` or `DateTime` when getting cells.
+    ///
+    /// Cells for which the `numberStoredAsText` error is ignored are kept as text.
+    /// Default to false.
+    pub coerce_numbers_stored_as_text: bool,
+
+    /// Assign `Cell::id` and `Row::id` when getting cells and rows, see `ExcelOptions::cell_ids`.
+    ///
+    /// Set from the options the workbook is opened with.
+    pub cell_ids: bool,
+
     // private
+    /// part of the worksheet, ie: xl/worksheets/sheet1.xml
+    #[cfg_attr(feature = "serde", serde(skip_serializing))]
+    path: String,
+
     #[cfg_attr(feature = "serde", serde(skip_serializing))]
     raw_sheet: Box<XlsxWorksheet>,
 
@@ -89,17 +210,25 @@ pub struct Worksheet {
     worksheet_rels: Box<XlsxRelationships>,
 
     #[cfg_attr(feature = "serde", serde(skip_serializing))]
-    shared_string_items: Box<Vec<XlsxSharedStringItem>>,
+    shared_strings: SharedStrings,
 
     #[cfg_attr(feature = "serde", serde(skip_serializing))]
-    stylesheet: Box<XlsxStyleSheet>,
+    stylesheet: Arc<XlsxStyleSheet>,
 
     #[cfg_attr(feature = "serde", serde(skip_serializing))]
-    theme: Option<Box<XlsxTheme>>,
+    theme: Option<Arc<XlsxTheme>>,
 
     #[cfg_attr(feature = "serde", serde(skip_serializing))]
     defined_names: Box<XlsxDefinedNames>,
 
+    /// cells covered by `data_validations`, built when loading the worksheet
+    #[cfg_attr(feature = "serde", serde(skip_serializing))]
+    validation_index: RangeIndex,
+
+    /// cells covered by `conditional_formatting_rules`, built when loading the worksheet
+    #[cfg_attr(feature = "serde", serde(skip_serializing))]
+    conditional_formatting_index: RangeIndex,
+
     #[cfg(feature = "drawing")]
     #[cfg_attr(feature = "serde", serde(skip_serializing))]
     drawing_rels: Box<XlsxRelationships>,
@@ -128,38 +257,707 @@ impl Worksheet {
         let Some(dimension) = self.dimension else {
             return Ok(vec![]);
         };
+        self.get_range_with_dimension(dimension)
+    }
 
-        let (start, end) = (dimension.start, dimension.end);
-        
-        // Calculate total number of cells for progress reporting
-        let total_rows = end.row - start.row + 1;
-        let total_cols = end.col - start.col + 1;
-        let total_cells = total_rows * total_cols;
-        println!("Total cells to process: {}", total_cells);
-        
-        // Process rows in parallel
-        let start_time = Instant::now();
-        let cells: anyhow::Result<Vec<Cell>> = (start.row..=end.row)
-            .into_par_iter()
-            .flat_map(|row_index| {
-                let mut row_results = Vec::with_capacity(total_cols as usize);
-                let mut col_index = start.col;
-                while col_index <= end.col {
-                    let coord = Coordinate::from_point((row_index, col_index));
-                    row_results.push(self.get_cell(coord));
-                    col_index += 1;
+    /// get cells within a range in A1 style, ie: `A1:D100`, `$B$2:$C$3` or `B2`.
+    ///
+    /// Only the part of the range within the worksheet dimension is returned, row by row.
+    pub fn get_range(&self, range: &str) -> anyhow::Result<Vec<Cell>> {
+        let ranges = Dimension::from_sqref(range);
+        let (1, Some(dimension)) = (ranges.len(), ranges.first()) else {
+            bail!("Invalid range: `{}`.", range)
+        };
+        self.get_range_with_dimension(*dimension)
+    }
+
+    /// get cells within a range.
+    ///
+    /// Only the part of the range within the worksheet dimension is returned, row by row.
+    pub fn get_range_with_dimension(&self, range: Dimension) -> anyhow::Result<Vec<Cell>> {
+        let Some(Dimension { start, end }) = self.dimension.and_then(|dimension| range.clamp_to(dimension)) else {
+            return Ok(vec![]);
+        };
+
+        #[cfg(feature = "rayon")]
+        let rows = (start.row..=end.row).into_par_iter();
+        #[cfg(not(feature = "rayon"))]
+        let rows = start.row..=end.row;
+        rows.flat_map(|row_index| {
+            (start.col..=end.col)
+                .map(|col_index| self.get_cell(Coordinate::from_point((row_index, col_index))))
+                .collect::<Vec<anyhow::Result<Cell>>>()
+        })
+        .collect()
+    }
+
+    /// Write the cells as CSV, a record per row, from the top left cell of the data or of `options.range`.
+    ///
+    /// Empty cells and rows within the range are written as empty fields, a sheet without data writes nothing.
+    /// The worksheet must be obtained with its cells, ie: with `Excel::get_worksheet`.
+    ///
+    /// Returns the writer.
+    pub fn to_csv<W: Write>(&self, mut writer: W, options: &CsvOptions) -> anyhow::Result<W> {
+        let Some(range) = options.range.or(self.dimension) else {
+            return Ok(writer);
+        };
+        let show_zeros = self.show_zeros();
+        let comments = match options.include_comments {
+            true => self.comment_texts(),
+            false => HashMap::new(),
+        };
+        let cells = self.get_range_with_dimension(range)?;
+        for row in cells.chunk_by(|a, b| a.coordinate.row == b.coordinate.row) {
+            let mut values: Vec<String> = row
+                .iter()
+                .map(|cell| match options.formatted {
+                    true if !show_zeros && cell.is_zero() => String::new(),
+                    true => cell.formatted_value(self.is_1904),
+                    false => cell.value.to_string(),
+                })
+                .collect();
+            if options.include_comments {
+                let notes: Vec<String> = row
+                    .iter()
+                    .filter_map(|cell| Some(format!("{}: {}", cell.coordinate.to_a1(), comments.get(&cell.coordinate)?)))
+                    .collect();
+                values.push(notes.join("\n"));
+            }
+            writer.write_all(options.record(&values).as_bytes())?;
+        }
+        writer.flush()?;
+        return Ok(writer);
+    }
+
+    /// JSON text of the cells, a row per element: arrays of values, or objects keyed by the header row, see `JsonOptions`.
+    ///
+    /// Starts from the top left cell of the data or of `options.range`, a sheet without data gives `[]`.
+    /// The worksheet must be obtained with its cells, ie: with `Excel::get_worksheet`.
+    #[cfg(feature = "serde")]
+    pub fn to_json(&self, options: &JsonOptions) -> anyhow::Result<String> {
+        let Some(range) = options.range.or(self.dimension) else {
+            return Ok("[]".to_string());
+        };
+        let show_zeros = self.show_zeros();
+        let comments = match options.include_comments {
+            true => self.comment_texts(),
+            false => HashMap::new(),
+        };
+        let cells = self.get_range_with_dimension(range)?;
+        let mut rows = cells.chunk_by(|a, b| a.coordinate.row == b.coordinate.row);
+        let keys = match options.shape {
+            JsonShape::Objects => rows.next().map_or(vec![], |header| header_keys(header, self.is_1904)),
+            JsonShape::Arrays => vec![],
+        };
+        let comments_key = unique_key(&keys, "comments".to_string());
+        let mut json_rows: Vec<JsonRow> = vec![];
+        for row in rows {
+            let values: Vec<Option<Value>> = row
+                .iter()
+                .map(|cell| options.value(cell, self.is_1904, show_zeros))
+                .collect();
+            if options.skip_empty_rows && values.iter().all(|v| v.is_none()) {
+                continue;
+            }
+            let notes: serde_json::Map<String, Value> = row
+                .iter()
+                .filter_map(|cell| Some((cell.coordinate.to_a1(), Value::String(comments.get(&cell.coordinate)?.clone()))))
+                .collect();
+            json_rows.push(match options.shape {
+                JsonShape::Arrays => {
+                    let mut values: Vec<Value> = values
+                        .into_iter()
+                        .filter_map(|value| value.or_else(|| options.null(false)))
+                        .collect();
+                    if options.include_comments {
+                        values.push(Value::Object(notes));
+                    }
+                    JsonRow::Array(values)
                 }
-                row_results
-            })
-            .collect();
-        
-        // Print result
-        if let Ok(ref cells_vec) = cells {
-            let elapsed = start_time.elapsed();
-            println!("Processed {} cells successfully in {:?}", cells_vec.len(), elapsed);
+                JsonShape::Objects => {
+                    let mut fields: Vec<(&str, Value)> = keys
+                        .iter()
+                        .zip(values)
+                        .filter_map(|(key, value)| Some((key.as_str(), value.or_else(|| options.null(true))?)))
+                        .collect();
+                    if options.include_comments {
+                        fields.push((comments_key.as_str(), Value::Object(notes)));
+                    }
+                    JsonRow::Object(fields)
+                }
+            });
         }
-        
-        cells
+        Ok(serde_json::to_string(&json_rows)?)
+    }
+
+    /// Iterate the rows of the worksheet, reading `sheetData` from the file one row at a time.
+    ///
+    /// Unlike `get_cells`, only the rows written in the file are yielded, and only the current row is held in memory.
+    /// Use with `Excel::get_worksheet_without_cells` to process large sheets in constant memory.
+    ///
+    /// * excel: the file the worksheet is obtained from
+    pub fn rows<'a, RS: Read + Seek>(&'a self, excel: &'a mut Excel<RS>) -> anyhow::Result<Rows<'a, RS>> {
+        if excel.is_binary() {
+            bail!("Reading rows one at a time is not supported for binary workbooks (.xlsb), use `get_cells` instead.")
+        }
+        let options = excel.options();
+        Rows::new(self, excel.part_xml_reader(&self.path), options)
+    }
+
+    /// Pick n rows of the worksheet, ordered by row index, reading `sheetData` one row at a time as `rows` does.
+    ///
+    /// Meant for profiling large files without reading all their cells, see `SampleStrategy`.
+    ///
+    /// * excel: the file the worksheet is obtained from
+    pub fn sample_rows<RS: Read + Seek>(&self, excel: &mut Excel<RS>, n: usize, strategy: SampleStrategy) -> anyhow::Result<Vec<Row>> {
+        self.rows(excel)?.sample(n, strategy)
+    }
+
+    /// Records of type `T` deserialized from the rows of the worksheet, reading `sheetData` one row at a time as `rows` does.
+    ///
+    /// The first row holding a value is the header, its texts name the fields of the records, ie:
+    /// ```no_run
+    /// # use excel_reader::prelude::*;
+    /// # use serde::Deserialize;
+    /// #[derive(Deserialize)]
+    /// struct Sale {
+    ///     product: String,
+    ///     #[serde(rename = "Unit Price")]
+    ///     unit_price: f64,
+    ///     quantity: Option<u32>,
+    /// }
+    /// # fn main() -> anyhow::Result<()> {
+    /// # let mut excel = Excel::from_path("sales.xlsx")?;
+    /// # let worksheet = excel.get_worksheet_with_name("Sales")?;
+    /// for sale in worksheet.deserialize::<Sale, _>(&mut excel)? {
+    ///     let sale = sale?;
+    /// #   let _ = (sale.product, sale.unit_price, sale.quantity);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// Values are converted the way a CSV reader does: numbers from texts reading as numbers, texts from any value,
+    /// None from blank cells. Formulas are read by their last calculated value, dates as ISO 8601 texts or as serial numbers.
+    /// Tuples and vectors take the values of the header columns in order.
+    #[cfg(feature = "serde")]
+    pub fn deserialize<'a, T: serde::de::DeserializeOwned, RS: Read + Seek>(
+        &'a self,
+        excel: &'a mut Excel<RS>,
+    ) -> anyhow::Result<DeserializeRows<'a, RS, T>> {
+        Ok(DeserializeRows::new(self.rows(excel)?, self.is_1904))
+    }
+
+    /// Profile the columns of the worksheet in one pass, reading `sheetData` one row at a time as `rows` does:
+    /// detected type, null rate, distinct count, and min, max and mean of numeric values.
+    ///
+    /// Distinct values are counted exactly up to 65536 per column, and estimated with HyperLogLog above.
+    ///
+    /// * excel: the file the worksheet is obtained from
+    /// * has_header: the first row holding a value names the columns and is not profiled
+    pub fn profile_columns<RS: Read + Seek>(&self, excel: &mut Excel<RS>, has_header: bool) -> anyhow::Result<SheetProfile> {
+        self.rows(excel)?.profile_columns(has_header)
+    }
+
+    /// get all cell values within a worksheet in a column oriented layout, reading `sheetData` one row at a time as `rows` does.
+    ///
+    /// Values are added to the typed columns straight from the raw cells, without their styles.
+    ///
+    /// None if the sheet does not contain any data.
+    ///
+    /// * excel: the file the worksheet is obtained from
+    pub fn get_columns<RS: Read + Seek>(&self, excel: &mut Excel<RS>) -> anyhow::Result<Option<ColumnarSheet>> {
+        let Some(dimension) = self.dimension else {
+            return Ok(None);
+        };
+        if excel.is_binary() {
+            // binary worksheets are read with their cells
+            return Ok(Some(ColumnarSheet::from_raw_cells(self, dimension, self.raw_cells())?));
+        }
+        let options = excel.options();
+        let rows = self.rows(excel)?;
+        Ok(Some(ColumnarSheet::from_rows(self, dimension, rows, &options)?))
+    }
+
+    /// Cells of the column named `header`, one per row written in the file below the header row, ie:
+    /// ```no_run
+    /// # use excel_reader::prelude::*;
+    /// # fn main() -> anyhow::Result<()> {
+    /// # let worksheet = Excel::from_path("sales.xlsx")?.get_worksheet_with_name("Sales")?;
+    /// if let Some(amounts) = worksheet.column_by_header("Amount")? {
+    ///     let total: f64 = amounts.filter_map(|c| c.ok()?.typed_value(worksheet.is_1904).as_f64()).sum();
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// The first row holding a value is the header row, and the leftmost cell whose text, trimmed, equals `header` names the column.
+    /// Rows without a cell in the column yield an empty cell.
+    ///
+    /// None if no header cell is named `header`.
+    pub fn column_by_header(&self, header: &str) -> anyhow::Result<Option<impl Iterator<Item = anyhow::Result<Cell>> + '_>> {
+        let header = header.trim();
+        let mut rows = self.raw_rows();
+        let mut col = None;
+        for row in rows.by_ref() {
+            let mut header_row = false;
+            for cell in row.cells.as_deref().unwrap_or(&[]) {
+                let Some(coordinate) = cell.coordinate() else {
+                    continue;
+                };
+                let value = self.process_cell(coordinate, cell.clone(), row.clone())?.typed_value(self.is_1904);
+                if value.is_blank() {
+                    continue;
+                }
+                header_row = true;
+                if value.to_string().trim() == header {
+                    col = Some(coordinate.col);
+                    break;
+                }
+            }
+            if header_row {
+                break;
+            }
+        }
+        let Some(col) = col else {
+            return Ok(None);
+        };
+
+        let cells = rows.filter_map(move |row| {
+            let coordinate = Coordinate::from_point((row.row_index?, col));
+            return Some(match self.get_raw_cell(coordinate, row) {
+                Some(cell) => self.process_cell(coordinate, cell, row.clone()),
+                None => Ok(self.empty_cell(coordinate)),
+            });
+        });
+        Ok(Some(cells))
+    }
+
+    /// Find rectangular blocks of data bounded by empty rows and columns, with candidate header rows.
+    ///
+    /// Meant for sheets that lay out data informally instead of using tables.
+    pub fn detect_tables(&self) -> Vec<DetectedTable> {
+        DetectedTable::detect(self.raw_cells())
+    }
+
+    /// Find cells typed as text whose content reads as a number or date, ie: the "number stored as text" warning.
+    pub fn find_numbers_stored_as_text(&self) -> anyhow::Result<Vec<NumberStoredAsText>> {
+        let mut found: Vec<NumberStoredAsText> = vec![];
+        for cell in self.raw_cells() {
+            let (Some(coordinate), Some("s" | "inlineStr")) = (cell.coordinate(), cell.r#type.as_deref()) else {
+                continue;
+            };
+            if cell.formula.is_some() {
+                continue;
+            }
+            let text = CellValueType::from_raw(cell.clone(), &self.shared_strings, &self.stylesheet, None)?;
+            let Some(value) = text.stored_as_text_value() else {
+                continue;
+            };
+            found.push(NumberStoredAsText {
+                coordinate,
+                text: text.to_string(),
+                value,
+                ignored: self.number_stored_as_text_ignored(coordinate),
+            });
+        }
+        Ok(found)
+    }
+
+    /// Find text cells containing non-breaking spaces, zero width characters, or leading / trailing whitespace.
+    pub fn find_invisible_characters(&self) -> anyhow::Result<Vec<InvisibleCharacterReport>> {
+        let mut found: Vec<InvisibleCharacterReport> = vec![];
+        for cell in self.raw_cells() {
+            let (Some(coordinate), Some("s" | "inlineStr" | "str")) = (cell.coordinate(), cell.r#type.as_deref()) else {
+                continue;
+            };
+            let text = CellValueType::from_raw(cell.clone(), &self.shared_strings, &self.stylesheet, None)?;
+            if let Some(report) = InvisibleCharacterReport::from_text(coordinate, text.to_string()) {
+                found.push(report);
+            }
+        }
+        Ok(found)
+    }
+
+    /// Find URL-like and email-like text in cells, whether or not a hyperlink is stored for the cell.
+    ///
+    /// Links typed in a cell are often only styled as hyperlinks, without the hyperlink record.
+    /// A cell containing several links yields one candidate per link.
+    pub fn find_link_candidates(&self) -> anyhow::Result<Vec<LinkCandidate>> {
+        let mut found: Vec<LinkCandidate> = vec![];
+        for cell in self.raw_cells() {
+            let (Some(coordinate), Some("s" | "inlineStr" | "str")) = (cell.coordinate(), cell.r#type.as_deref()) else {
+                continue;
+            };
+            let text = CellValueType::from_raw(cell.clone(), &self.shared_strings, &self.stylesheet, None)?.to_string();
+            let links = detect_links(&text);
+            if links.is_empty() {
+                continue;
+            }
+            let has_hyperlink = self
+                .raw_sheet
+                .hyperlinks
+                .as_deref()
+                .unwrap_or(&[])
+                .iter()
+                .any(|h| h.r#ref == Some(coordinate));
+            let styled_as_hyperlink = self.is_hyperlink_style(cell.style);
+            for (matched, target, kind) in links {
+                found.push(LinkCandidate {
+                    coordinate,
+                    text: text.clone(),
+                    matched,
+                    target,
+                    kind,
+                    has_hyperlink,
+                    styled_as_hyperlink,
+                });
+            }
+        }
+        Ok(found)
+    }
+
+    /// Hash of the values of each row within a range, to detect rows inserted, changed or deleted between versions of a file with `RowChanges::between`.
+    ///
+    /// Values are normalized before hashing: formatting, number representation, text normalization and whitespace around text do not change the hash.
+    /// Rows without any value in `columns` are skipped.
+    ///
+    /// * range: rows to hash
+    /// * columns: 1 based indices of the columns to hash, in order, ie: the columns identifying a record. All the columns of `range` when empty.
+    pub fn row_hashes(&self, range: Dimension, columns: &[u64]) -> anyhow::Result<Vec<RowHash>> {
+        let columns: Vec<u64> = if columns.is_empty() {
+            (range.start.col..=range.end.col).collect()
+        } else {
+            columns.to_vec()
+        };
+        let mut hashes: Vec<RowHash> = vec![];
+        for row in self.raw_rows() {
+            let Some(values) = self.row_values(row, range, &columns)? else {
+                continue;
+            };
+            hashes.push(RowHash {
+                row: values.row,
+                hash: values.hash(),
+            });
+        }
+        Ok(hashes)
+    }
+
+    /// Compare the rows of a range with a previous version of the sheet, matching rows by the value of a key column.
+    ///
+    /// Inserted and updated rows are yielded in the order of this sheet, followed by deleted rows in the order of the previous sheet.
+    /// Rows of this sheet are read as the iteration goes, the rows of the previous sheet are read upfront.
+    /// Keys compare by their normalized text, so that `1` stored as a number or as text is the same key. Rows without a key are skipped.
+    ///
+    /// * previous: the same sheet in the previous version of the file
+    /// * range: rows and columns compared, header excluded
+    /// * key_column: 1 based index of the column identifying a row, within `range`
+    pub fn changes_by_key<'a>(&'a self, previous: &Worksheet, range: Dimension, key_column: u64) -> anyhow::Result<RowChangeEvents<'a>> {
+        if key_column < range.start.col || key_column > range.end.col {
+            bail!("Key column {} is outside of the range {}.", key_column, range.to_a1())
+        }
+        let columns: Vec<u64> = (range.start.col..=range.end.col).collect();
+        let mut previous_rows: Vec<RowValues> = vec![];
+        for row in previous.raw_rows() {
+            if let Some(values) = previous.row_values(row, range, &columns)? {
+                previous_rows.push(values);
+            }
+        }
+        Ok(RowChangeEvents::new(self, range, columns, key_column, previous_rows))
+    }
+
+    /// Values of a row in `columns`, None if the row is outside of `range` or has no value in `columns`.
+    pub(crate) fn row_values(&self, row: &XlsxRow, range: Dimension, columns: &[u64]) -> anyhow::Result<Option<RowValues>> {
+        let Some(row_index) = row.row_index else {
+            return Ok(None);
+        };
+        if row_index < range.start.row || row_index > range.end.row {
+            return Ok(None);
+        }
+        let mut values = RowValues::new(row_index, columns.len());
+        for cell in row.cells.as_deref().unwrap_or(&[]) {
+            let Some(coordinate) = cell.coordinate() else {
+                continue;
+            };
+            let Some(position) = columns.iter().position(|c| *c == coordinate.col) else {
+                continue;
+            };
+            let value = CellValueType::from_raw(cell.clone(), &self.shared_strings, &self.stylesheet, None)?;
+            values.normalized[position] = normalized_value(&value, cell.r#type.as_deref());
+            values.values[position] = value;
+        }
+        if values.is_empty() {
+            return Ok(None);
+        }
+        Ok(Some(values))
+    }
+
+    /// Find cells whose value matches a pattern, and optionally formulas, comments, headers and footers.
+    ///
+    /// Matches are ordered row by row, followed by comments, by commented cell, then headers and footers.
+    pub fn search(&self, pattern: &Regex, options: &SearchOptions) -> anyhow::Result<Vec<SearchMatch>> {
+        let search_cell = |cell: &&XlsxCell| -> anyhow::Result<Vec<SearchMatch>> {
+            let Some(coordinate) = cell.coordinate() else {
+                return Ok(vec![]);
+            };
+            let mut found: Vec<SearchMatch> = vec![];
+            let text = CellValueType::from_raw((*cell).clone(), &self.shared_strings, &self.stylesheet, None)?.to_string();
+            if pattern.is_match(&text) {
+                found.push(SearchMatch {
+                    sheet_name: Some(self.name.clone()),
+                    location: SearchLocation::Cell(coordinate),
+                    text,
+                });
+            }
+            if let (true, Some(formula)) = (options.include_formulas, cell.formula.as_ref()) {
+                if pattern.is_match(&formula.raw_value) {
+                    found.push(SearchMatch {
+                        sheet_name: Some(self.name.clone()),
+                        location: SearchLocation::Formula(coordinate),
+                        text: formula.raw_value.clone(),
+                    });
+                }
+            }
+            Ok(found)
+        };
+
+        let cells: Vec<&XlsxCell> = self.raw_cells().collect();
+        #[cfg(feature = "rayon")]
+        let found: anyhow::Result<Vec<Vec<SearchMatch>>> = if options.parallel {
+            cells.par_iter().map(search_cell).collect()
+        } else {
+            cells.iter().map(search_cell).collect()
+        };
+        #[cfg(not(feature = "rayon"))]
+        let found: anyhow::Result<Vec<Vec<SearchMatch>>> = cells.iter().map(search_cell).collect();
+        let mut found: Vec<SearchMatch> = found?.into_iter().flatten().collect();
+
+        if options.include_comments {
+            let mut comments: Vec<(Coordinate, String)> = self.comment_texts().into_iter().collect();
+            comments.sort_by_key(|(coordinate, _)| *coordinate);
+            for (coordinate, text) in comments {
+                if pattern.is_match(&text) {
+                    found.push(SearchMatch {
+                        sheet_name: Some(self.name.clone()),
+                        location: SearchLocation::Comment(coordinate),
+                        text,
+                    });
+                }
+            }
+        }
+
+        if let (true, Some(header_footer)) = (options.include_headers_footers, self.raw_sheet.header_footer.as_ref()) {
+            for (kind, text) in header_footer.texts() {
+                if pattern.is_match(text) {
+                    found.push(SearchMatch {
+                        sheet_name: Some(self.name.clone()),
+                        location: SearchLocation::HeaderFooter { kind: kind.to_string() },
+                        text: text.to_string(),
+                    });
+                }
+            }
+        }
+
+        Ok(found)
+    }
+
+    /// Find cells whose resolved formatting matches a predicate, ie: `|style| style.fill_color() == Some(&color)`.
+    ///
+    /// Cells are grouped by style index (the cell style, with the row and column styles it falls back to),
+    /// and the predicate is evaluated once per group, on the properties of its first cell.
+    /// It should therefore only look at the formatting: alignment, font, border, fill and numbering format.
+    ///
+    /// Only cells stored in the sheet data are returned, ordered row by row.
+    pub fn cells_with_style<F>(&self, predicate: F) -> anyhow::Result<Vec<Cell>>
+    where
+        F: Fn(&CellProperty) -> bool,
+    {
+        let rows = match self.raw_sheet.sheet_data.as_ref() {
+            Some(sheet_data) => sheet_data.rows.as_deref().unwrap_or(&[]),
+            None => &[],
+        };
+
+        // (cell style, row style, column style)
+        type StyleKey = (Option<u64>, Option<u64>, Option<u64>);
+        let mut styles: HashMap<StyleKey, Vec<(Coordinate, &XlsxCell, &XlsxRow)>> = HashMap::new();
+        for row in rows {
+            for cell in row.cells.as_deref().unwrap_or(&[]) {
+                let Some(coordinate) = cell.coordinate() else {
+                    continue;
+                };
+                let col_style = self.get_raw_col_info(coordinate).and_then(|c| c.style);
+                styles
+                    .entry((cell.style, row.style, col_style))
+                    .or_default()
+                    .push((coordinate, cell, row));
+            }
+        }
+
+        let mut found: Vec<Cell> = vec![];
+        for cells in styles.into_values() {
+            let mut cells = cells.into_iter();
+            let Some((coordinate, cell, row)) = cells.next() else {
+                continue;
+            };
+            let first = self.process_cell(coordinate, cell.clone(), row.clone())?;
+            if !predicate(&first.property) {
+                continue;
+            }
+            found.push(first);
+            for (coordinate, cell, row) in cells {
+                found.push(self.process_cell(coordinate, cell.clone(), row.clone())?);
+            }
+        }
+        found.sort_by_key(|c| (c.coordinate.row, c.coordinate.col));
+        Ok(found)
+    }
+
+    /// Cells holding a formula, with their cached value and number format, ordered row by row.
+    ///
+    /// Formulas of shared formula cells are expanded from their master cell.
+    /// Cells of an array formula or data table other than its master cell hold no formula and are not listed.
+    pub fn formula_cells(&self) -> Vec<FormulaCell> {
+        let rows = match self.raw_sheet.sheet_data.as_ref() {
+            Some(sheet_data) => sheet_data.rows.as_deref().unwrap_or(&[]),
+            None => &[],
+        };
+
+        // master cells come first within their shared group
+        let mut masters: HashMap<u64, (String, Coordinate)> = HashMap::new();
+        let mut found: Vec<FormulaCell> = vec![];
+        for row in rows {
+            for cell in row.cells.as_deref().unwrap_or(&[]) {
+                let (Some(coordinate), Some(formula)) = (cell.coordinate(), cell.formula.as_ref()) else {
+                    continue;
+                };
+                let kind = FormulaKind::from_raw(formula);
+                let text = match kind {
+                    FormulaKind::Shared(index) if formula.ref_range.is_some() => {
+                        masters.insert(index, (formula.raw_value.clone(), coordinate));
+                        formula.raw_value.clone()
+                    }
+                    FormulaKind::Shared(index) => match masters.get(&index).cloned().or_else(|| self.get_master_formula(index)) {
+                        Some((master_formula, master_coord)) => self.adjust_formula_references(&master_formula, master_coord, coordinate),
+                        None => formula.raw_value.clone(),
+                    },
+                    _ => formula.raw_value.clone(),
+                };
+                let col = self.get_raw_col_info(coordinate);
+                let num_format_id = self.get_id(cell, row, &col, &|x| self.get_number_format_id_helper(x));
+
+                found.push(FormulaCell {
+                    coordinate,
+                    formula: text,
+                    cached_value: cell.cell_value.as_ref().map(|v| v.raw_value.clone()),
+                    kind,
+                    range: formula.ref_range,
+                    numbering_format: NumberingFormat::from_id(num_format_id, &self.stylesheet),
+                });
+            }
+        }
+        found
+    }
+
+    /// Formula cells grouped by shared formula group, array formula and data table, ordered by their first cell.
+    ///
+    /// Other formulas form a family of their own.
+    pub fn formula_families(&self) -> Vec<FormulaFamily> {
+        FormulaFamily::group(self.formula_cells())
+    }
+
+    /// Relationships from the worksheet to its dependent parts, ie: drawing, comments, tables.
+    pub fn relationships(&self) -> WorksheetRelationships {
+        WorksheetRelationships::new(*self.worksheet_rels.clone())
+    }
+
+    /// Number of frozen rows at the top of the worksheet, 0 if none.
+    pub fn frozen_row_count(&self) -> u64 {
+        self.get_frozen_pane().map(|(rows, _)| rows).unwrap_or(0)
+    }
+
+    /// Number of frozen columns on the left of the worksheet, 0 if none.
+    pub fn frozen_column_count(&self) -> u64 {
+        self.get_frozen_pane().map(|(_, cols)| cols).unwrap_or(0)
+    }
+
+    /// Frozen rows spanning the columns of the worksheet dimension.
+    ///
+    /// Frozen rows usually hold the headers of the data below them.
+    /// None if no rows are frozen, or the sheet does not contain any data.
+    pub fn frozen_header_range(&self) -> Option<Dimension> {
+        let rows = self.frozen_row_count();
+        let dimension = self.dimension?;
+        if rows == 0 {
+            return None;
+        }
+        Some(Dimension {
+            start: Coordinate::from_point((1, dimension.start.col)),
+            end: Coordinate::from_point((rows, dimension.end.col)),
+        })
+    }
+
+    /// Frozen columns spanning the rows of the worksheet dimension.
+    ///
+    /// None if no columns are frozen, or the sheet does not contain any data.
+    pub fn frozen_column_range(&self) -> Option<Dimension> {
+        let cols = self.frozen_column_count();
+        let dimension = self.dimension?;
+        if cols == 0 {
+            return None;
+        }
+        Some(Dimension {
+            start: Coordinate::from_point((dimension.start.row, 1)),
+            end: Coordinate::from_point((dimension.end.row, cols)),
+        })
+    }
+
+    /// Cascading dropdowns: list validations whose items are selected by the value of other cells of the sheet with `INDIRECT` or `OFFSET`.
+    pub fn dropdown_dependencies(&self) -> Vec<DropdownDependency> {
+        self.data_validations
+            .iter()
+            .flatten()
+            .flat_map(DropdownDependency::from_data_validation)
+            .collect()
+    }
+
+    /// Data validation applied to a cell, None if the cell is not validated.
+    ///
+    /// Looked up in an index of the validation ranges built when loading the worksheet, in O(log n).
+    /// The index does not follow changes made to `data_validations` afterwards.
+    pub fn validation_for_cell(&self, coordinate: Coordinate) -> Option<&DataValidation> {
+        let index = self.validation_index.find(&coordinate)?;
+        self.data_validations.as_ref()?.get(index)
+    }
+
+    /// Conditional formatting rules applying to a cell, in priority order: the rule evaluated first comes first.
+    ///
+    /// Looked up in an index of the rule ranges built when loading the worksheet, without going through the ranges of every rule.
+    /// The index does not follow changes made to `conditional_formatting_rules` afterwards.
+    pub fn conditional_rules_for(&self, coordinate: Coordinate) -> Vec<&ConditionalFormattingRule> {
+        let mut rules: Vec<&ConditionalFormattingRule> = self
+            .conditional_formatting_index
+            .find_all(&coordinate)
+            .into_iter()
+            .filter_map(|index| self.conditional_formatting_rules.get(index))
+            .collect();
+        rules.sort_by_key(|rule| rule.priority);
+        rules
+    }
+
+    /// Table by name, ie: `Sales` for `Sales[Amount]`, ignoring case as formulas do.
+    pub fn table(&self, name: &str) -> Option<&Table> {
+        self.tables
+            .iter()
+            .find(|t| t.display_name.eq_ignore_ascii_case(name))
+    }
+
+    /// Merged region containing a coordinate, ie: `A1:C1` for `B1` when a header spans the first three columns.
+    ///
+    /// The value and styles of a merged region are those of its top left cell.
+    /// None if the cell is not merged.
+    pub fn merged_region_for(&self, coordinate: Coordinate) -> Option<Dimension> {
+        self.merged_cells
+            .iter()
+            .find(|merged| merged.contains(&coordinate))
+            .copied()
     }
 
     /// get cell value and styles for a specific coordinate.
@@ -175,55 +973,37 @@ impl Worksheet {
             )
         }
         let Some(row) = self.get_raw_row(coordinate) else {
-            return Ok(Cell::default(coordinate));
+            return Ok(self.empty_cell(coordinate));
         };
 
         let Some(cell) = self.get_raw_cell(coordinate, &row) else {
-            return Ok(Cell::default(coordinate));
-        };
-        let mut cell = cell.clone();
-
-        // Handle shared formula
-        if let Some(formula) = &cell.formula {
-            // Check if this is a shared formula
-            if formula.r#type == Some("shared".to_string()) {
-                // If it has a shared index, try to get the master formula
-                if let Some(shared_index) = formula.shared_group_index {
-                    if let Some((master_formula, master_coord)) = self.get_master_formula(shared_index) {
-                        // Adjust the master formula based on the relative offset between master cell and current cell
-                        let adjusted_formula = self.adjust_formula_references(&master_formula, master_coord, coordinate);
-                        // Create a new formula with the adjusted formula value
-                        let mut new_formula = formula.clone();
-                        new_formula.raw_value = adjusted_formula;
-                        cell.formula = Some(new_formula);
-                    }
-                }
-            }
-        }
+            return Ok(self.empty_cell(coordinate));
+        };
+        self.process_cell(coordinate, cell, row)
+    }
+
+    /// Value and styles of a raw cell within a raw row.
+    pub(crate) fn process_cell(&self, coordinate: Coordinate, mut cell: XlsxCell, row: XlsxRow) -> anyhow::Result<Cell> {
+        self.resolve_shared_formula(coordinate, &mut cell);
 
         // Get color scheme once
         let color_scheme = self.get_color_scheme();
         // Get column info once
         let col = self.get_raw_col_info(coordinate);
 
-        // Use references instead of cloning for large objects
-        let cell_value = CellValueType::from_raw(
-            cell.clone(),
-            &self.shared_string_items, // Use reference instead of dereference
-            &self.stylesheet,          // Use reference instead of dereference
-            color_scheme.clone(),
-        )?;
-
         // Get all styles in one pass
         let (num_format_id, fill_id, border_id, font_id, alignment, protection) = self.get_cell_styles(&cell, &row, &col);
 
         // Get hyperlink once
         let hyperlink = self.get_hyperlink(coordinate);
+        // Keep the raw style index and type before the cell is consumed
+        let style_index = cell.style;
+        let raw_type = cell.r#type.clone();
         // Get sheet format properties once
         let sheet_format_properties = self.raw_sheet.sheet_format_properties.clone();
 
         let cell_property = CellProperty::from_raw(
-            cell,                      // No clone needed
+            cell.clone(),
             row,                       // No clone needed
             col,                       // No clone needed
             fill_id,
@@ -235,16 +1015,82 @@ impl Worksheet {
             hyperlink,
             sheet_format_properties,
             &self.stylesheet,          // Use reference instead of dereference
-            color_scheme,              // No clone needed
+            color_scheme.clone(),
         );
 
+        // Rich text runs without their own properties take the font of the cell
+        let cell_value = CellValueType::from_raw_with_font(
+            cell,                      // No clone needed
+            &self.shared_strings,       // Use reference instead of dereference
+            &self.stylesheet,          // Use reference instead of dereference
+            color_scheme,              // No clone needed
+            &cell_property.font,
+        )?;
         Ok(Cell {
             coordinate,
-            value: cell_value,
+            value: self.coerce_stored_as_text(coordinate, cell_value),
             property: cell_property,
+            style_index,
+            raw_type,
+            id: self.cell_id(coordinate),
         })
     }
 
+    /// Value of a raw cell, without its styles: rich text runs only keep their own properties.
+    ///
+    /// Shared formulas and numbers stored as text are resolved as `process_cell` does.
+    pub(crate) fn raw_cell_value(&self, coordinate: Coordinate, mut cell: XlsxCell) -> anyhow::Result<CellValueType> {
+        self.resolve_shared_formula(coordinate, &mut cell);
+        let value = CellValueType::from_raw(cell, &self.shared_strings, &self.stylesheet, None)?;
+        Ok(self.coerce_stored_as_text(coordinate, value))
+    }
+
+    /// Replace the formula of a cell sharing the formula of a master cell by the master formula, adjusted to the cell.
+    fn resolve_shared_formula(&self, coordinate: Coordinate, cell: &mut XlsxCell) {
+        let Some(formula) = cell.formula.as_mut() else {
+            return;
+        };
+        let (Some("shared"), Some(shared_index)) = (formula.r#type.as_deref(), formula.shared_group_index) else {
+            return;
+        };
+        if let Some((master_formula, master_coord)) = self.get_master_formula(shared_index) {
+            // Adjust the master formula based on the relative offset between master cell and current cell
+            formula.raw_value = self.adjust_formula_references(&master_formula, master_coord, coordinate);
+        }
+    }
+
+    /// Number or date read from a text, with `coerce_numbers_stored_as_text`.
+    fn coerce_stored_as_text(&self, coordinate: Coordinate, value: CellValueType) -> CellValueType {
+        if !self.coerce_numbers_stored_as_text || self.number_stored_as_text_ignored(coordinate) {
+            return value;
+        }
+        value.stored_as_text_value().unwrap_or(value)
+    }
+
+    /// Cell without an element in the sheet data.
+    fn empty_cell(&self, coordinate: Coordinate) -> Cell {
+        return Cell {
+            id: self.cell_id(coordinate),
+            ..Cell::default(coordinate)
+        };
+    }
+
+    /// Id of a cell, None unless `cell_ids` is set.
+    pub(crate) fn cell_id(&self, coordinate: Coordinate) -> Option<CellId> {
+        if !self.cell_ids {
+            return None;
+        }
+        return CellId::new(self.sheet_id, coordinate);
+    }
+
+    /// Id of a row, None unless `cell_ids` is set.
+    pub(crate) fn row_id(&self, row: u64) -> Option<CellId> {
+        if !self.cell_ids {
+            return None;
+        }
+        return CellId::for_row(self.sheet_id, row);
+    }
+
     /// Get all cell styles in one pass to reduce redundant calculations
     fn get_cell_styles(&self, cell: &XlsxCell, row: &XlsxRow, col: &Option<XlsxColumnInformation>) -> (Option<u64>, Option<u64>, Option<u64>, Option<u64>, Option<XlsxAlignment>, Option<XlsxCellProtection>) {
         let num_format_id = self.get_id(cell, row, col, &|x| {
@@ -265,6 +1111,330 @@ impl Worksheet {
         (num_format_id, fill_id, border_id, font_id, alignment, protection)
     }
 
+    /// Formatting of a cell: font, fill, border, alignment, number format and protection.
+    ///
+    /// Unlike `get_cell`, cells not stored in the sheet data, within the dimension or not, are resolved as well:
+    /// they take the style of their row when it has a custom format, else the style of their column.
+    /// As with `get_cell`, table styles and conditional formatting are not applied.
+    pub fn cell_style(&self, coordinate: Coordinate) -> anyhow::Result<ResolvedStyle> {
+        if coordinate.row == 0 || coordinate.col == 0 {
+            bail!("Coordinate: {:?} is not a valid cell coordinate.", coordinate)
+        }
+        let col = self.get_raw_col_info(coordinate);
+        let row = self.get_raw_row(coordinate);
+        let cell = row.as_ref().and_then(|row| self.get_raw_cell(coordinate, row));
+        let row = match row {
+            Some(row) if cell.is_some() || row.custom_format == Some(true) => row,
+            _ => XlsxRow::default(),
+        };
+        let cell = cell.unwrap_or_default();
+
+        let style_index = cell
+            .style
+            .or(row.style)
+            .or(col.as_ref().and_then(|c| c.style));
+        let (num_format_id, fill_id, border_id, font_id, alignment, protection) =
+            self.get_cell_styles(&cell, &row, &col);
+        let color_scheme = self.get_color_scheme();
+
+        Ok(ResolvedStyle {
+            style_index,
+            font: CellProperty::get_font(font_id, &self.stylesheet, color_scheme.clone()),
+            fill: CellProperty::get_fill(fill_id, &self.stylesheet, color_scheme.clone()),
+            border: CellProperty::get_border(border_id, &self.stylesheet, color_scheme),
+            alignment: TextAlignment::from_raw(alignment),
+            numbering_format: NumberingFormat::from_id(num_format_id, &self.stylesheet),
+            locked: protection.as_ref().and_then(|p| p.locked).unwrap_or(true),
+            formula_hidden: protection.and_then(|p| p.hidden).unwrap_or(false),
+        })
+    }
+
+    /// Width of a column (1 based index) in points, 0 for hidden columns.
+    pub fn column_width(&self, col: u64) -> f64 {
+        let col_info = self.get_raw_col_info(Coordinate::from_point((1, col)));
+        if col_info.as_ref().is_some_and(|c| c.hidden == Some(true)) {
+            return 0.0;
+        }
+        CellProperty::cell_width(col_info, self.raw_sheet.sheet_format_properties.clone())
+    }
+
+    /// Attributes of the columns described in the `cols` element, one entry per column, from left to right.
+    ///
+    /// `col` elements spanning several columns are expanded, columns without a `col` element are left out.
+    /// Available on worksheets obtained without their cells as well.
+    pub fn column_properties(&self) -> Vec<ColumnProperties> {
+        let mut columns: Vec<ColumnProperties> = self
+            .raw_sheet
+            .column_infos
+            .as_deref()
+            .unwrap_or(&[])
+            .iter()
+            .flat_map(ColumnProperties::from_raw)
+            .collect();
+        // the first element describing a column applies, like `column_width`
+        columns.sort_by_key(|c| c.column);
+        columns.dedup_by_key(|c| c.column);
+        return columns;
+    }
+
+    /// Height of a row (1 based index) in points, 0 for hidden rows.
+    pub fn row_height(&self, row: u64) -> f64 {
+        let raw_row = self.get_raw_row(Coordinate::from_point((row, 1)));
+        self.raw_row_height(raw_row.as_ref())
+    }
+
+    /// Attributes of a row (1 based index), None if the row is not written in the file.
+    ///
+    /// Available on worksheets obtained with `Excel::get_worksheet_shape` as well.
+    pub fn row_properties(&self, row: u64) -> Option<RowProperties> {
+        self.raw_rows()
+            .find(|r| r.row_index == Some(row))
+            .and_then(RowProperties::from_raw)
+    }
+
+    /// Attributes of the rows differing from a default row, see `RowProperties::is_customized`, from top to bottom.
+    pub fn customized_rows(&self) -> impl Iterator<Item = RowProperties> + '_ {
+        self.raw_rows()
+            .filter_map(RowProperties::from_raw)
+            .filter(|r| r.is_customized())
+    }
+
+    /// Distance in points from the top left corner of the worksheet to the top left corner of a cell: (x, y).
+    ///
+    /// Hidden rows and columns take no space.
+    /// Coordinates beyond the last row or column of a sheet are at the end of the sheet.
+    pub fn cell_offset(&self, coordinate: Coordinate) -> (f64, f64) {
+        // the coordinate may come from a drawing anchor of the file
+        let col = coordinate.col.min(MAX_COLUMN_COUNT + 1);
+        let row = coordinate.row.min(MAX_ROW_COUNT + 1);
+        let x: f64 = (1..col).map(|col| self.column_width(col)).sum();
+
+        // rows not written in the file have the default height
+        let default_height = self.raw_row_height(None);
+        let mut y = default_height * row.saturating_sub(1) as f64;
+        let rows = match self.raw_sheet.sheet_data.as_ref() {
+            Some(sheet_data) => sheet_data.rows.as_deref().unwrap_or(&[]),
+            None => &[],
+        };
+        for raw_row in rows {
+            if raw_row.row_index.is_some_and(|index| index < row) {
+                y += self.raw_row_height(Some(raw_row)) - default_height;
+            }
+        }
+
+        (x, y)
+    }
+
+    /// Print settings: paper size, orientation, scale or fit to pages, margins and print options.
+    pub fn page_setup(&self) -> PageSetup {
+        PageSetup::from_raw(
+            self.raw_sheet.page_setup.as_ref(),
+            self.raw_sheet.page_margins.as_ref(),
+            self.raw_sheet.print_options.as_ref(),
+            self.fit_to_page(),
+        )
+    }
+
+    /// Display settings of the first sheet view: right to left mode, gridlines, headings, zoom.
+    ///
+    /// Available on worksheets obtained with `Excel::get_worksheet_shape` as well.
+    pub fn sheet_view(&self) -> SheetView {
+        SheetView::from_raw(self.raw_sheet.sheet_views.as_ref().and_then(|v| v.first()))
+    }
+
+    /// Whether column A is displayed on the right, columns increasing towards the left.
+    pub fn is_right_to_left(&self) -> bool {
+        self.sheet_view().right_to_left
+    }
+
+    /// Whether gridlines are displayed between the cells.
+    pub fn show_grid_lines(&self) -> bool {
+        self.sheet_view().show_grid_lines
+    }
+
+    /// Whether the row numbers and column letters are displayed.
+    pub fn show_row_col_headers(&self) -> bool {
+        self.sheet_view().show_headings
+    }
+
+    /// Whether cells holding zero display `0`, they are blank otherwise.
+    ///
+    /// Honored by `to_csv` when writing formatted values.
+    pub fn show_zeros(&self) -> bool {
+        self.sheet_view().show_zeros
+    }
+
+    /// Text of the comments by commented cell, for the exports.
+    ///
+    /// A conversation is written a comment per line prefixed by its author, and replaces the note Excel writes along with it.
+    pub(crate) fn comment_texts(&self) -> HashMap<Coordinate, String> {
+        let mut texts: HashMap<Coordinate, String> = self
+            .comments
+            .iter()
+            .map(|comment| (comment.coordinate, comment.text.clone()))
+            .collect();
+        for thread in &self.comment_threads {
+            let text = std::iter::once(&thread.comment)
+                .chain(thread.replies.iter())
+                .map(|comment| match &comment.author {
+                    Some(author) => format!("{}: {}", author.display_name, comment.text),
+                    None => comment.text.clone(),
+                })
+                .collect::<Vec<String>>()
+                .join("\n");
+            texts.insert(thread.coordinate, text);
+        }
+        return texts;
+    }
+
+    /// Columns of the sheet, from the first to the last column holding data, in the order they are displayed from left to right.
+    ///
+    /// Reversed in right to left mode. Empty if the sheet does not contain any data.
+    pub fn visual_column_order(&self) -> Vec<u64> {
+        let Some(dimension) = self.dimension else {
+            return vec![];
+        };
+        self.sheet_view()
+            .visual_column_order(dimension.start.col, dimension.end.col)
+    }
+
+    /// Cell with the focus when the workbook was saved, from the last sheet view, ie: to restore the position of the user.
+    ///
+    /// Selection of the active pane when the sheet is split or frozen. None if no selection is stored, Excel then selects `A1`.
+    pub fn active_cell(&self) -> Option<Coordinate> {
+        let selection = self.active_selection()?;
+        Coordinate::from_a1(selection.active_cell.as_ref()?.as_bytes())
+    }
+
+    /// Ranges selected when the workbook was saved, from the last sheet view, in the order they were selected.
+    ///
+    /// Empty if no selection is stored.
+    pub fn selected_ranges(&self) -> Vec<Dimension> {
+        self.active_selection()
+            .and_then(|s| s.sqref.as_ref())
+            .map_or(vec![], |sqref| Dimension::from_sqref(sqref))
+    }
+
+    /// Selection of the active pane of the last sheet view, or its last selection.
+    fn active_selection(&self) -> Option<&XlsxSelection> {
+        let sheet_view = self.raw_sheet.sheet_views.as_ref()?.last()?;
+        let active_pane = sheet_view
+            .pane
+            .as_ref()
+            .and_then(|p| p.active_pane.as_deref())
+            .unwrap_or("topLeft");
+        sheet_view
+            .selections
+            .iter()
+            .find(|s| s.pane.as_deref().unwrap_or("topLeft") == active_pane)
+            .or(sheet_view.selections.last())
+    }
+
+    /// Headers and footers with their formatting codes decoded, None if the worksheet has none.
+    pub fn header_footer(&self) -> Option<HeaderFooter> {
+        self.raw_sheet
+            .header_footer
+            .as_ref()
+            .map(HeaderFooter::from_raw)
+    }
+
+    /// Split the print areas, or the used range when no print area is defined, into printed pages.
+    ///
+    /// Combines the page setup (paper size, orientation, scale or fit to pages, page order), the page margins,
+    /// manual page breaks, column widths and row heights. Hidden rows and columns take no space.
+    /// Print titles, headers and footers are not taken into account.
+    pub fn paginate(&self) -> Vec<PrintPage> {
+        let Some(used_range) = self.dimension else {
+            return vec![];
+        };
+        let layout = PageLayout::from_raw(
+            self.raw_sheet.page_setup.as_ref(),
+            self.raw_sheet.page_margins.as_ref(),
+            self.fit_to_page(),
+        );
+
+        let mut areas = print_areas(&self.defined_names, &self.name, Some(used_range));
+        if areas.is_empty() {
+            areas.push(used_range);
+        }
+        let row_breaks = manual_breaks(self.raw_sheet.row_breaks.as_ref());
+        let column_breaks = manual_breaks(self.raw_sheet.column_breaks.as_ref());
+        let rows: HashMap<u64, &XlsxRow> = match self.raw_sheet.sheet_data.as_ref() {
+            Some(sheet_data) => sheet_data.rows.as_deref().unwrap_or(&[]),
+            None => &[],
+        }
+        .iter()
+        .filter_map(|row| Some((row.row_index?, row)))
+        .collect();
+        // breaks after the 1 based index `start` + n - 1 of the area, as n
+        let relative_breaks = |breaks: &BTreeSet<u64>, start: u64| -> BTreeSet<usize> {
+            breaks
+                .range(start..)
+                .map(|b| (b + 1 - start) as usize)
+                .collect()
+        };
+
+        let mut pages: Vec<PrintPage> = vec![];
+        let mut page_number = layout.first_page_number;
+        for area in areas {
+            let widths: Vec<f64> = (area.start.col..=area.end.col)
+                .map(|col| self.column_width(col))
+                .collect();
+            let heights: Vec<f64> = (area.start.row..=area.end.row)
+                .map(|row| self.raw_row_height(rows.get(&row).copied()))
+                .collect();
+            let (scale, column_pages, row_pages) = layout.split(
+                &widths,
+                &heights,
+                &relative_breaks(&column_breaks, area.start.col),
+                &relative_breaks(&row_breaks, area.start.row),
+            );
+
+            let ordered: Vec<((usize, usize), (usize, usize))> = if layout.down_then_over {
+                column_pages
+                    .iter()
+                    .flat_map(|c| row_pages.iter().map(move |r| (*c, *r)))
+                    .collect()
+            } else {
+                row_pages
+                    .iter()
+                    .flat_map(|r| column_pages.iter().map(move |c| (*c, *r)))
+                    .collect()
+            };
+            for ((first_col, last_col), (first_row, last_row)) in ordered {
+                let factor = scale as f64 / 100.0;
+                pages.push(PrintPage {
+                    page_number,
+                    range: Dimension {
+                        start: Coordinate::from_point((
+                            area.start.row + first_row as u64,
+                            area.start.col + first_col as u64,
+                        )),
+                        end: Coordinate::from_point((
+                            area.start.row + last_row as u64,
+                            area.start.col + last_col as u64,
+                        )),
+                    },
+                    width: widths[first_col..=last_col].iter().sum::<f64>() * factor,
+                    height: heights[first_row..=last_row].iter().sum::<f64>() * factor,
+                    scale,
+                });
+                page_number += 1;
+            }
+        }
+        pages
+    }
+
+    /// Position of a drawing anchor marker in pixels (96 dpi) from the top left corner of the worksheet: (x, y).
+    #[cfg(feature = "drawing")]
+    pub fn marker_position(&self, marker: &CellMarker) -> (f64, f64) {
+        let (x, y) = self.cell_offset(Coordinate::from_point((marker.row, marker.col)));
+        (
+            pt_to_px(x + marker.col_offset, DEFAULT_DPI),
+            pt_to_px(y + marker.row_offset, DEFAULT_DPI),
+        )
+    }
+
     /// get all drawings within a worksheet.
     #[cfg(feature = "drawing")]
     pub fn get_drawings(&self) -> Vec<WorksheetDrawing> {
@@ -285,14 +1455,18 @@ impl Worksheet {
                         *self.image_bytes.clone(),
                         *self.defined_names.clone(),
                         self.get_color_scheme(),
-                        self.theme.clone(),
+                        self.theme.as_deref().cloned().map(Box::new),
                     ) else {
                         continue;
                     };
 
                     let anchor = DrawingAnchorType::AbsoluteAnchor;
+                    let bounds = DrawingBounds::from_absolute(
+                        absolute_anchor.position.as_ref(),
+                        absolute_anchor.extent.as_ref(),
+                    );
 
-                    drawings.push(WorksheetDrawing { anchor, content });
+                    drawings.push(WorksheetDrawing { anchor, content, bounds });
                 }
                 XlsxWorksheetDrawingType::OneCellAnchor(one_cell_anchor_drawing) => {
                     let Some(content) = DrawingContentType::from_raw(
@@ -305,15 +1479,20 @@ impl Worksheet {
                         *self.image_bytes.clone(),
                         *self.defined_names.clone(),
                         self.get_color_scheme(),
-                        self.theme.clone(),
+                        self.theme.as_deref().cloned().map(Box::new),
                     ) else {
                         continue;
                     };
 
                     let anchor =
                         DrawingAnchorType::from_one_cell_anchor(one_cell_anchor_drawing.clone());
+                    let from = CellMarker::from_raw(one_cell_anchor_drawing.from.clone());
+                    let bounds = DrawingBounds::from_one_cell(
+                        self.marker_position(&from),
+                        one_cell_anchor_drawing.extent.as_ref(),
+                    );
 
-                    drawings.push(WorksheetDrawing { anchor, content });
+                    drawings.push(WorksheetDrawing { anchor, content, bounds });
                 }
                 XlsxWorksheetDrawingType::TwoCellAnchor(two_cell_anchor_drawing) => {
                     let Some(content) = DrawingContentType::from_raw(
@@ -326,42 +1505,62 @@ impl Worksheet {
                         *self.image_bytes.clone(),
                         *self.defined_names.clone(),
                         self.get_color_scheme(),
-                        self.theme.clone(),
+                        self.theme.as_deref().cloned().map(Box::new),
                     ) else {
                         continue;
                     };
 
                     let anchor =
                         DrawingAnchorType::from_two_cell_anchor(two_cell_anchor_drawing.clone());
-
-                    drawings.push(WorksheetDrawing { anchor, content });
+                    let from = CellMarker::from_raw(two_cell_anchor_drawing.from.clone());
+                    let to = CellMarker::from_raw(two_cell_anchor_drawing.to.clone());
+                    let bounds = Some(DrawingBounds::from_two_cell(
+                        self.marker_position(&from),
+                        self.marker_position(&to),
+                    ));
+
+                    drawings.push(WorksheetDrawing { anchor, content, bounds });
                 }
             }
         }
 
         return drawings;
     }
+
+    /// get the positions of all anchored pictures within a worksheet, without the image bytes.
+    #[cfg(feature = "drawing")]
+    pub fn get_image_placeholders(&self) -> Vec<ImagePlaceholder> {
+        self.get_drawings()
+            .iter()
+            .filter_map(ImagePlaceholder::from_drawing)
+            .collect()
+    }
 }
 
 impl Worksheet {
     pub(crate) fn from_raw(
         name: String,
         sheet_id: u64,
+        path: String,
         worksheet: Box<XlsxWorksheet>,
         worksheet_rels: Box<XlsxRelationships>,
         tables: Box<Vec<XlsxTable>>,
+        comments: Box<XlsxComments>,
+        vml_drawing: Box<XlsxVmlDrawing>,
+        threaded_comments: Box<XlsxThreadedComments>,
+        persons: Box<XlsxPersons>,
         defined_names: Box<XlsxDefinedNames>,
         is_1904: bool,
         calculation_reference_mode: Option<CalculationReferenceMode>,
-        shared_string_items: Box<Vec<XlsxSharedStringItem>>,
-        stylesheet: Box<XlsxStyleSheet>,
-        theme: Option<Box<XlsxTheme>>,
+        shared_strings: SharedStrings,
+        stylesheet: Arc<XlsxStyleSheet>,
+        theme: Option<Arc<XlsxTheme>>,
         #[cfg(feature = "drawing")] drawing_rels: Box<XlsxRelationships>,
         #[cfg(feature = "drawing")] raw_drawing: Option<Box<XlsxWorksheetDrawing>>,
         #[cfg(feature = "drawing")] image_bytes: Box<BTreeMap<String, Vec<u8>>>,
     ) -> Self {
-        let default_table_style_name = if let Some(style) = stylesheet.clone().table_styles {
-            style.default_table_style
+        let default_table_style_name = if let Some(style) = stylesheet.table_styles.as_ref() {
+            style.default_table_style.clone()
         } else {
             None
         };
@@ -378,22 +1577,56 @@ impl Worksheet {
                 .collect()
         });
 
+        let validation_index = RangeIndex::from_sqrefs(
+            data_validations.iter().flatten().map(|v: &DataValidation| v.sqref.as_str()),
+        );
+
+        let conditional_formatting_rules: Vec<ConditionalFormattingRule> = worksheet
+            .conditional_formattings
+            .iter()
+            .flatten()
+            .flat_map(ConditionalFormattingRule::from_raw)
+            .collect();
+        let conditional_formatting_index = RangeIndex::from_sqrefs(
+            conditional_formatting_rules.iter().map(|r| r.sqref.as_str()),
+        );
+
+        let color_scheme = theme
+            .as_ref()
+            .and_then(|t| t.theme_elements.as_ref())
+            .and_then(|e| e.color_scheme.clone());
+        let comments = Comment::from_raw(&comments, Some(&vml_drawing), &stylesheet, color_scheme);
+        let comment_threads = CommentThread::from_raw(&threaded_comments, &persons);
+
         Self {
             name,
             sheet_id,
             dimension: Self::get_dimension(*worksheet.clone()),
             merged_cells: worksheet.merge_cells.clone().unwrap_or(vec![]),
             tables,
+            auto_filter: worksheet.auto_filter.as_ref().and_then(AutoFilter::from_raw),
             data_validations,
+            conditional_formatting_rules,
+            comments,
+            comment_threads,
+            protection: worksheet
+                .sheet_protection
+                .as_ref()
+                .map(SheetProtection::from_raw),
             is_1904,
             calculation_reference_mode: calculation_reference_mode
                 .unwrap_or(CalculationReferenceMode::default()),
+            coerce_numbers_stored_as_text: false,
+            cell_ids: false,
+            path,
             raw_sheet: worksheet,
             worksheet_rels,
-            shared_string_items,
+            shared_strings,
             stylesheet,
             theme,
             defined_names,
+            validation_index,
+            conditional_formatting_index,
             #[cfg(feature = "drawing")]
             raw_drawing,
             #[cfg(feature = "drawing")]
@@ -408,6 +1641,87 @@ impl Worksheet {
 }
 
 impl Worksheet {
+    /// All rows stored in the sheet data.
+    pub(crate) fn raw_rows(&self) -> std::slice::Iter<'_, XlsxRow> {
+        let rows = match self.raw_sheet.sheet_data.as_ref() {
+            Some(sheet_data) => sheet_data.rows.as_deref().unwrap_or(&[]),
+            None => &[],
+        };
+        rows.iter()
+    }
+
+    /// All cells stored in the sheet data, row by row.
+    fn raw_cells(&self) -> impl Iterator<Item = &XlsxCell> {
+        self.raw_rows()
+            .flat_map(|row| row.cells.as_deref().unwrap_or(&[]).iter())
+    }
+
+    /// Whether a cell format (index to `cellXfs`) uses the `Hyperlink` or `Followed Hyperlink` cell style, or a font colored with the hyperlink colors of the theme.
+    fn is_hyperlink_style(&self, style: Option<u64>) -> bool {
+        let Some(format) = self
+            .stylesheet
+            .cell_xfs
+            .as_deref()
+            .and_then(|xfs| xfs.get(style.unwrap_or(0) as usize))
+        else {
+            return false;
+        };
+
+        // builtinId 8: Hyperlink, 9: Followed Hyperlink
+        let named_style = self.stylesheet.cell_styles.as_deref().unwrap_or(&[]).iter().any(|s| {
+            s.xf_id.is_some()
+                && s.xf_id == format.xf_id
+                && (matches!(s.builtin_id, Some(8 | 9))
+                    || matches!(s.name.as_deref(), Some("Hyperlink" | "Followed Hyperlink")))
+        });
+        if named_style {
+            return true;
+        }
+
+        // theme colors 10: hlink, 11: folHlink
+        let font = format
+            .font_id
+            .and_then(|id| self.stylesheet.fonts.as_deref().and_then(|fonts| fonts.get(id as usize)));
+        return font
+            .and_then(|f| f.color.as_ref())
+            .is_some_and(|c| matches!(c.theme, Some(10 | 11)));
+    }
+
+    /// Whether the `numberStoredAsText` error is ignored for a cell.
+    fn number_stored_as_text_ignored(&self, coordinate: Coordinate) -> bool {
+        let Some(ignored_errors) = self.raw_sheet.ignored_errors.as_ref() else {
+            return false;
+        };
+        ignored_errors.iter().any(|e| {
+            e.number_stored_as_text == Some(true) && e.sqref.iter().any(|d| d.contains(&coordinate))
+        })
+    }
+
+    /// (frozen rows, frozen columns) of the first sheet view.
+    ///
+    /// For frozen panes, `xSplit` and `ySplit` are the number of columns and rows instead of a position.
+    /// fitToPage of the page setup properties, scaling the printed sheet to a number of pages.
+    fn fit_to_page(&self) -> bool {
+        self.raw_sheet
+            .sheet_properties
+            .as_ref()
+            .and_then(|p| p.page_setup_properties.as_ref())
+            .and_then(|p| p.fit_to_page)
+            .unwrap_or(false)
+    }
+
+    fn get_frozen_pane(&self) -> Option<(u64, u64)> {
+        let sheet_view = self.raw_sheet.sheet_views.as_ref()?.first()?;
+        let pane = sheet_view.pane.as_ref()?;
+        match pane.state.as_deref() {
+            Some("frozen") | Some("frozenSplit") => {}
+            _ => return None,
+        }
+        let rows = pane.y_split.unwrap_or(0.0).max(0.0) as u64;
+        let cols = pane.x_split.unwrap_or(0.0).max(0.0) as u64;
+        Some((rows, cols))
+    }
+
     fn get_hyperlink(&self, cell_coordinate: Coordinate) -> Option<Hyperlink> {
         let hyperlinks = match self.raw_sheet.hyperlinks.as_ref() {
             Some(hyperlinks) => hyperlinks,
@@ -454,8 +1768,16 @@ impl Worksheet {
         if rows.is_empty() {
             return None;
         }
-        let first_row = rows[0].row_index.unwrap_or(1);
-        let last_row = rows[rows.len() - 1].row_index.unwrap_or(rows.len() as u64);
+        let first_row = rows
+            .iter()
+            .filter_map(|r| r.row_index)
+            .min()
+            .unwrap_or(1);
+        let last_row = rows
+            .iter()
+            .filter_map(|r| r.row_index)
+            .max()
+            .unwrap_or(rows.len() as u64);
 
         let mut first_col = u64::MAX;
         let mut last_col = u64::MIN;
@@ -465,7 +1787,7 @@ impl Worksheet {
             if cells.is_empty() {
                 continue;
             }
-            let f = if let Some(c) = cells[0].coordinate {
+            let f = if let Some(c) = cells[0].coordinate() {
                 c.col
             } else {
                 1
@@ -473,7 +1795,7 @@ impl Worksheet {
             if f < first_col {
                 first_col = f
             }
-            let l = if let Some(c) = cells[cells.len() - 1].coordinate {
+            let l = if let Some(c) = cells[cells.len() - 1].coordinate() {
                 c.col
             } else {
                 cells.len() as u64
@@ -815,7 +2137,7 @@ impl Worksheet {
 
         let raw_cell: Vec<&XlsxCell> = cells
             .iter()
-            .filter(|c| c.coordinate == Some(coordinate))
+            .filter(|c| c.coordinate() == Some(coordinate))
             .collect();
 
         raw_cell.first().cloned().cloned()
@@ -840,6 +2162,20 @@ impl Worksheet {
         None
     }
 
+    fn raw_row_height(&self, row: Option<&XlsxRow>) -> f64 {
+        let format = self.raw_sheet.sheet_format_properties.as_ref();
+        let hidden = match row.and_then(|r| r.hidden) {
+            Some(hidden) => hidden,
+            None => format.and_then(|f| f.zero_height).unwrap_or(false),
+        };
+        if hidden {
+            return 0.0;
+        }
+        row.and_then(|r| r.height)
+            .or(format.and_then(|f| f.default_row_height))
+            .unwrap_or(DEFAULT_CELL_HEIGHT)
+    }
+
     fn get_raw_row(&self, coordinate: Coordinate) -> Option<XlsxRow> {
         let Some(sheet_data) = self.raw_sheet.sheet_data.as_ref() else {
             return None;
@@ -887,12 +2223,11 @@ impl Worksheet {
     /// Get the master formula and its coordinate for a shared formula cell
     fn get_master_formula(&self, shared_index: u64) -> Option<(String, Coordinate)> {
         // Check cache first
-        {{
-            let cache = self.master_formula_cache.read().unwrap();
+        if let Ok(cache) = self.master_formula_cache.read() {
             if let Some(cached) = cache.get(&shared_index) {
                 return Some(cached.clone());
             }
-        }}
+        }
 
         // Iterate through all rows and cells to find the master formula
         let Some(sheet_data) = self.raw_sheet.sheet_data.as_ref() else {
@@ -917,22 +2252,24 @@ impl Worksheet {
                 if formula.shared_group_index == Some(shared_index) && formula.r#type == Some("shared".to_string()) {
                     // Check if this is the master formula (has ref attribute)
                     if formula.ref_range.is_some() {
-                        if let Some(coordinate) = cell.coordinate {
+                        if let Some(coordinate) = cell.coordinate() {
                             let result = (formula.raw_value.clone(), coordinate);
                             // Update cache
-                            let mut cache = self.master_formula_cache.write().unwrap();
-                            cache.insert(shared_index, result.clone());
+                            if let Ok(mut cache) = self.master_formula_cache.write() {
+                                cache.insert(shared_index, result.clone());
+                            }
                             return Some(result);
                         }
                     }
                 } else if formula.shared_group_index.is_none() && formula.r#type == Some("shared".to_string()) {
                     // This might be the master formula if it has ref attribute
                     if formula.ref_range.is_some() {
-                        if let Some(coordinate) = cell.coordinate {
+                        if let Some(coordinate) = cell.coordinate() {
                             let result = (formula.raw_value.clone(), coordinate);
                             // Update cache
-                            let mut cache = self.master_formula_cache.write().unwrap();
-                            cache.insert(shared_index, result.clone());
+                            if let Ok(mut cache) = self.master_formula_cache.write() {
+                                cache.insert(shared_index, result.clone());
+                            }
                             return Some(result);
                         }
                     }
@@ -943,11 +2280,28 @@ impl Worksheet {
         None
     }
 
+    /// Remember the formula of a shared formula master cell, for cells read without the sheet data being loaded.
+    pub(crate) fn cache_master_formula(&self, cell: &XlsxCell) {
+        let Some(formula) = cell.formula.as_ref() else {
+            return;
+        };
+        let (Some(shared_index), Some(_), Some(coordinate)) = (formula.shared_group_index, formula.ref_range.as_ref(), cell.coordinate()) else {
+            return;
+        };
+        if formula.r#type != Some("shared".to_string()) {
+            return;
+        }
+        if let Ok(mut cache) = self.master_formula_cache.write() {
+            cache.insert(shared_index, (formula.raw_value.clone(), coordinate));
+        }
+    }
+
     /// Adjust cell references in a formula based on the relative offset between master cell and current cell
     fn adjust_formula_references(&self, formula: &str, master_coord: Coordinate, current_coord: Coordinate) -> String {
         // Calculate offset between master cell and current cell
-        let row_offset = current_coord.row - master_coord.row;
-        let col_offset = current_coord.col - master_coord.col;
+        // Cells before the master cell result in negative offsets
+        let row_offset = current_coord.row as i64 - master_coord.row as i64;
+        let col_offset = current_coord.col as i64 - master_coord.col as i64;
         
         // If no offset, return the original formula
         if row_offset == 0 && col_offset == 0 {
@@ -955,85 +2309,18 @@ impl Worksheet {
         }
         
         // Use cached regex instead of creating a new one
-        // Replace all cell references with adjusted ones
+        // Replace all cell references with adjusted ones, anchored parts kept
+        // References that do not parse or move outside of the worksheet are kept as written
         let adjusted_formula = self.formula_regex.replace_all(formula, |caps: &regex::Captures| {
-            let col_str = &caps[1];
-            let row_str = &caps[2];
-            
-            // Check if column is absolute (has $ prefix)
-            let is_col_absolute = col_str.starts_with('$');
-            let col_letter = if is_col_absolute {
-                &col_str[1..]
-            } else {
-                col_str
+            return match CellReference::parse_a1(&caps[0])
+                .ok()
+                .and_then(|reference| reference.offset(row_offset, col_offset))
+            {
+                Some(reference) => reference.to_string(),
+                None => caps[0].to_string(),
             };
-            
-            // Check if row is absolute (has $ prefix)
-            let is_row_absolute = row_str.starts_with('$');
-            let row_num = if is_row_absolute {
-                &row_str[1..]
-            } else {
-                row_str
-            };
-            
-            // Convert column letter to number
-            let col_num = self.col_letter_to_number(col_letter);
-            // Convert row string to number
-            let row_num: u64 = row_num.parse().unwrap_or(0);
-            
-            // Adjust column if not absolute
-            let new_col_num = if is_col_absolute {
-                col_num
-            } else {
-                col_num + col_offset
-            };
-            
-            // Adjust row if not absolute
-            let new_row_num = if is_row_absolute {
-                row_num
-            } else {
-                row_num + row_offset
-            };
-            
-            // Convert new column number to letter
-            let new_col_letter = self.col_number_to_letter(new_col_num);
-            
-            // Reconstruct the cell reference with $ prefix if needed
-            let mut new_ref = String::new();
-            if is_col_absolute {
-                new_ref.push('$');
-            }
-            new_ref.push_str(&new_col_letter);
-            if is_row_absolute {
-                new_ref.push('$');
-            }
-            new_ref.push_str(&new_row_num.to_string());
-            
-            new_ref
         });
         
         adjusted_formula.to_string()
     }
-    
-    /// Convert column letter to number (A=1, B=2, ..., Z=26, AA=27, etc.)
-    fn col_letter_to_number(&self, col: &str) -> u64 {
-        let mut result = 0;
-        for c in col.chars() {
-            result = result * 26 + (c.to_ascii_uppercase() as u64 - 'A' as u64 + 1);
-        }
-        result
-    }
-    
-    /// Convert column number to letter (1=A, 2=B, ..., 26=Z, 27=AA, etc.)
-    fn col_number_to_letter(&self, mut num: u64) -> String {
-        let mut result = String::new();
-        
-        while num > 0 {
-            let remainder = (num - 1) % 26;
-            result.push((b'A' + remainder as u8) as char);
-            num = (num - 1) / 26;
-        }
-        
-        result.chars().rev().collect()
-    }
 }
@@ -0,0 +1,200 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    helper::length_to_pt,
+    raw::spreadsheet::sheet::worksheet::{
+        page_margins::XlsxPageMargins, page_setup::XlsxPageSetup, print_options::XlsxPrintOptions,
+    },
+};
+
+use super::pagination::paper_size;
+
+/// Print settings of a worksheet, from its `pageSetup`, `pageMargins` and `printOptions` elements, see `Worksheet::page_setup`.
+///
+/// Unspecified settings hold the defaults of Excel: Letter paper in portrait orientation, normal margins, 100% scale.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PageSetup {
+    /// Index of the predefined paper size, ie: 1 for Letter, 9 for A4.
+    /// None when the paper is given by its width and height.
+    pub paper_size: Option<u64>,
+
+    /// Width of the paper in points, in portrait orientation.
+    pub paper_width: f64,
+
+    /// Height of the paper in points, in portrait orientation.
+    pub paper_height: f64,
+
+    pub orientation: PageOrientation,
+
+    /// Print scale in percent, ignored when `fit_to_page` is set.
+    pub scale: u64,
+
+    /// Scale the sheet to fit on `fit_to_width` x `fit_to_height` pages.
+    pub fit_to_page: bool,
+
+    /// Number of pages to fit on horizontally, 0 for automatic.
+    pub fit_to_width: u64,
+
+    /// Number of pages to fit on vertically, 0 for automatic.
+    pub fit_to_height: u64,
+
+    pub margins: PageMargins,
+
+    pub page_order: PageOrder,
+
+    /// Number of the first page, None for automatic (1 unless the sheet continues a print job).
+    pub first_page_number: Option<u64>,
+
+    pub copies: u64,
+    pub black_and_white: bool,
+    pub draft: bool,
+
+    /// How comments are printed: `none`, `asDisplayed` or `atEnd`.
+    pub cell_comments: String,
+
+    /// How cell errors are printed: `displayed`, `blank`, `dash` or `NA`.
+    pub errors: String,
+
+    /// Print the gridlines.
+    pub print_grid_lines: bool,
+
+    /// Print the row and column headings.
+    pub print_headings: bool,
+
+    /// Center the printed area horizontally on the page.
+    pub horizontal_centered: bool,
+
+    /// Center the printed area vertically on the page.
+    pub vertical_centered: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum PageOrientation {
+    /// Orientation of the printer, usually portrait.
+    Default,
+    Portrait,
+    Landscape,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum PageOrder {
+    DownThenOver,
+    OverThenDown,
+}
+
+/// Page margins in inches.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PageMargins {
+    pub left: f64,
+    pub right: f64,
+    pub top: f64,
+    pub bottom: f64,
+
+    /// Distance from the top of the page to the header.
+    pub header: f64,
+
+    /// Distance from the bottom of the page to the footer.
+    pub footer: f64,
+}
+
+impl Default for PageMargins {
+    /// Normal margins of Excel.
+    fn default() -> Self {
+        Self {
+            left: 0.7,
+            right: 0.7,
+            top: 0.75,
+            bottom: 0.75,
+            header: 0.3,
+            footer: 0.3,
+        }
+    }
+}
+
+impl PageSetup {
+    pub(crate) fn from_raw(
+        page_setup: Option<&XlsxPageSetup>,
+        margins: Option<&XlsxPageMargins>,
+        print_options: Option<&XlsxPrintOptions>,
+        fit_to_page: bool,
+    ) -> Self {
+        let default_setup = XlsxPageSetup::default();
+        let page_setup = page_setup.unwrap_or(&default_setup);
+        let default_print_options = XlsxPrintOptions::default();
+        let print_options = print_options.unwrap_or(&default_print_options);
+
+        let (paper_width, paper_height) = paper_size(page_setup);
+        // paperWidth and paperHeight take precedence over paperSize when both are valid
+        let has_paper_dimensions = [&page_setup.paper_width, &page_setup.paper_height]
+            .iter()
+            .all(|length| length.as_deref().and_then(length_to_pt).is_some());
+
+        let default_margins = PageMargins::default();
+        let margins = match margins {
+            Some(margins) => PageMargins {
+                left: margins.left.unwrap_or(default_margins.left),
+                right: margins.right.unwrap_or(default_margins.right),
+                top: margins.top.unwrap_or(default_margins.top),
+                bottom: margins.bottom.unwrap_or(default_margins.bottom),
+                header: margins.header.unwrap_or(default_margins.header),
+                footer: margins.footer.unwrap_or(default_margins.footer),
+            },
+            None => default_margins,
+        };
+
+        return Self {
+            paper_size: if has_paper_dimensions {
+                None
+            } else {
+                Some(page_setup.paper_size.unwrap_or(1))
+            },
+            paper_width,
+            paper_height,
+            orientation: match page_setup.orientation.as_deref() {
+                Some("portrait") => PageOrientation::Portrait,
+                Some("landscape") => PageOrientation::Landscape,
+                _ => PageOrientation::Default,
+            },
+            scale: page_setup.scale.unwrap_or(100),
+            fit_to_page,
+            fit_to_width: page_setup.fit_to_width.unwrap_or(1),
+            fit_to_height: page_setup.fit_to_height.unwrap_or(1),
+            margins,
+            page_order: match page_setup.page_order.as_deref() {
+                Some("overThenDown") => PageOrder::OverThenDown,
+                _ => PageOrder::DownThenOver,
+            },
+            first_page_number: if page_setup.use_first_page_number == Some(true) {
+                Some(page_setup.first_page_number.unwrap_or(1))
+            } else {
+                None
+            },
+            copies: page_setup.copies.unwrap_or(1),
+            black_and_white: page_setup.black_and_white.unwrap_or(false),
+            draft: page_setup.draft.unwrap_or(false),
+            cell_comments: page_setup
+                .cell_comments
+                .clone()
+                .unwrap_or("none".to_string()),
+            errors: page_setup.errors.clone().unwrap_or("displayed".to_string()),
+            print_grid_lines: print_options.grid_lines.unwrap_or(false)
+                && print_options.grid_lines_set.unwrap_or(true),
+            print_headings: print_options.headings.unwrap_or(false),
+            horizontal_centered: print_options.horizontal_centered.unwrap_or(false),
+            vertical_centered: print_options.vertical_centered.unwrap_or(false),
+        };
+    }
+
+    /// Width and height of the page in points, in the orientation of the page.
+    pub fn page_size(&self) -> (f64, f64) {
+        match self.orientation {
+            PageOrientation::Landscape => (self.paper_height, self.paper_width),
+            _ => (self.paper_width, self.paper_height),
+        }
+    }
+}
@@ -0,0 +1,292 @@
+use std::collections::BTreeSet;
+
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+use crate::{
+    common_types::Dimension,
+    helper::length_to_pt,
+    processed::spreadsheet::{
+        defined_name::{parse_area, split_references, unquote_sheet_name},
+        sheet_name::sheet_names_equal,
+    },
+    raw::spreadsheet::{
+        sheet::worksheet::{
+            page_break::XlsxPageBreaks, page_margins::XlsxPageMargins, page_setup::XlsxPageSetup,
+        },
+        workbook::defined_name::XlsxDefinedNames,
+    },
+    units::inch_to_pt,
+};
+
+/// Defined name holding the print area of a sheet.
+const PRINT_AREA_NAME: &str = "_xlnm.Print_Area";
+
+/// Print scale limits of Excel, in percent.
+const MIN_SCALE: u64 = 10;
+const MAX_SCALE: u64 = 400;
+
+/// A printed page of a worksheet, see `Worksheet::paginate`.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct PrintPage {
+    /// Page number, starting at the first page number of the page setup.
+    pub page_number: u64,
+
+    /// Cells printed on the page.
+    pub range: Dimension,
+
+    /// Width of the printed cells in points, after scaling.
+    pub width: f64,
+
+    /// Height of the printed cells in points, after scaling.
+    pub height: f64,
+
+    /// Print scale in percent.
+    pub scale: u64,
+}
+
+/// (first, last) indexes of the columns or rows of a page.
+type PageSpan = (usize, usize);
+
+/// Page layout of a sheet, from its page setup and margins.
+///
+/// Sizes are in points.
+pub(crate) struct PageLayout {
+    /// Printable width and height: paper size without margins.
+    pub(crate) printable_width: f64,
+    pub(crate) printable_height: f64,
+    /// Scale in percent, None to fit the sheet to `fit_to_width` x `fit_to_height` pages.
+    pub(crate) scale: Option<u64>,
+    /// Number of pages to fit on, 0 for automatic.
+    pub(crate) fit_to_width: u64,
+    pub(crate) fit_to_height: u64,
+    /// Print the pages down, then over.
+    pub(crate) down_then_over: bool,
+    pub(crate) first_page_number: u64,
+}
+
+impl PageLayout {
+    /// Defaults are the ones of Excel: Letter paper in portrait orientation, normal margins, 100% scale.
+    pub(crate) fn from_raw(
+        page_setup: Option<&XlsxPageSetup>,
+        margins: Option<&XlsxPageMargins>,
+        fit_to_page: bool,
+    ) -> Self {
+        let default_setup = XlsxPageSetup::default();
+        let page_setup = page_setup.unwrap_or(&default_setup);
+
+        let (mut paper_width, mut paper_height) = paper_size(page_setup);
+        if page_setup.orientation.as_deref() == Some("landscape") {
+            (paper_width, paper_height) = (paper_height, paper_width);
+        }
+
+        let margin =
+            |value: Option<f64>, default: f64| inch_to_pt(value.unwrap_or(default).max(0.0));
+        let (left, right, top, bottom) = (
+            margin(margins.and_then(|m| m.left), 0.7),
+            margin(margins.and_then(|m| m.right), 0.7),
+            margin(margins.and_then(|m| m.top), 0.75),
+            margin(margins.and_then(|m| m.bottom), 0.75),
+        );
+
+        let first_page_number = if page_setup.use_first_page_number == Some(true) {
+            page_setup.first_page_number.unwrap_or(1)
+        } else {
+            1
+        };
+
+        Self {
+            printable_width: (paper_width - left - right).max(1.0),
+            printable_height: (paper_height - top - bottom).max(1.0),
+            scale: if fit_to_page {
+                None
+            } else {
+                Some(page_setup.scale.unwrap_or(100).clamp(MIN_SCALE, MAX_SCALE))
+            },
+            fit_to_width: page_setup.fit_to_width.unwrap_or(1),
+            fit_to_height: page_setup.fit_to_height.unwrap_or(1),
+            down_then_over: page_setup.page_order.as_deref() != Some("overThenDown"),
+            first_page_number,
+        }
+    }
+
+    /// Split the rows and columns of an area into pages.
+    ///
+    /// * column_widths, row_heights: sizes in points of the columns and rows of the area, in order.
+    /// * column_breaks, row_breaks: 1 based indexes of the columns and rows followed by a manual page break.
+    ///   Manual breaks are ignored when fitting to pages, like Excel does.
+    ///
+    /// Returns the scale, and the (first, last) indexes within the area of the columns and rows of each page.
+    pub(crate) fn split(
+        &self,
+        column_widths: &[f64],
+        row_heights: &[f64],
+        column_breaks: &BTreeSet<usize>,
+        row_breaks: &BTreeSet<usize>,
+    ) -> (u64, Vec<PageSpan>, Vec<PageSpan>) {
+        let Some(scale) = self.scale else {
+            let no_breaks = BTreeSet::new();
+            let scale = self.fit_scale(column_widths, row_heights);
+            return (
+                scale,
+                split_sizes(column_widths, self.printable_width, scale, &no_breaks),
+                split_sizes(row_heights, self.printable_height, scale, &no_breaks),
+            );
+        };
+        (
+            scale,
+            split_sizes(column_widths, self.printable_width, scale, column_breaks),
+            split_sizes(row_heights, self.printable_height, scale, row_breaks),
+        )
+    }
+
+    /// Largest scale, at most 100%, at which the area fits on `fit_to_width` x `fit_to_height` pages.
+    fn fit_scale(&self, column_widths: &[f64], row_heights: &[f64]) -> u64 {
+        let no_breaks = BTreeSet::new();
+        let estimate = |sizes: &[f64], available: f64, pages: u64| -> f64 {
+            let total: f64 = sizes.iter().sum();
+            if pages == 0 || total <= 0.0 {
+                return 100.0;
+            }
+            available * pages as f64 / total * 100.0
+        };
+        let estimated = estimate(column_widths, self.printable_width, self.fit_to_width)
+            .min(estimate(
+                row_heights,
+                self.printable_height,
+                self.fit_to_height,
+            ))
+            .min(100.0);
+        let mut scale = (estimated.floor() as u64).clamp(MIN_SCALE, 100);
+
+        // pages break between cells, the estimate may still need more pages than requested
+        let fits = |sizes: &[f64], available: f64, pages: u64, scale: u64| {
+            pages == 0 || split_sizes(sizes, available, scale, &no_breaks).len() as u64 <= pages
+        };
+        while scale > MIN_SCALE
+            && !(fits(
+                column_widths,
+                self.printable_width,
+                self.fit_to_width,
+                scale,
+            ) && fits(
+                row_heights,
+                self.printable_height,
+                self.fit_to_height,
+                scale,
+            ))
+        {
+            scale -= 1;
+        }
+        scale
+    }
+}
+
+/// Split consecutive sizes into pages of at most `available` points once scaled.
+///
+/// A row or column larger than a page gets a page of its own.
+fn split_sizes(
+    sizes: &[f64],
+    available: f64,
+    scale: u64,
+    breaks: &BTreeSet<usize>,
+) -> Vec<PageSpan> {
+    let available = available * 100.0 / scale as f64;
+    let mut pages: Vec<PageSpan> = vec![];
+    let mut start = 0;
+    let mut used = 0.0;
+    for (index, size) in sizes.iter().enumerate() {
+        if index > start && used + size > available + 1e-9 {
+            pages.push((start, index - 1));
+            start = index;
+            used = 0.0;
+        }
+        used += size;
+        if breaks.contains(&(index + 1)) && index + 1 < sizes.len() {
+            pages.push((start, index));
+            start = index + 1;
+            used = 0.0;
+        }
+    }
+    if start < sizes.len() {
+        pages.push((start, sizes.len() - 1));
+    }
+    pages
+}
+
+/// Paper size in points (width, height) in portrait orientation.
+///
+/// paperWidth and paperHeight take precedence over paperSize.
+/// Unknown paper sizes are printed on Letter paper.
+pub(crate) fn paper_size(page_setup: &XlsxPageSetup) -> (f64, f64) {
+    if let (Some(width), Some(height)) = (
+        page_setup.paper_width.as_deref().and_then(length_to_pt),
+        page_setup.paper_height.as_deref().and_then(length_to_pt),
+    ) {
+        return (width, height);
+    }
+    let mm = |width: f64, height: f64| (inch_to_pt(width / 25.4), inch_to_pt(height / 25.4));
+    let inch = |width: f64, height: f64| (inch_to_pt(width), inch_to_pt(height));
+    match page_setup.paper_size.unwrap_or(1) {
+        3 => inch(11.0, 17.0),
+        4 => inch(17.0, 11.0),
+        5 => inch(8.5, 14.0),
+        7 => inch(7.25, 10.5),
+        8 => mm(297.0, 420.0),
+        9 | 10 => mm(210.0, 297.0),
+        11 => mm(148.0, 210.0),
+        12 => mm(250.0, 353.0),
+        13 => mm(176.0, 250.0),
+        _ => inch(8.5, 11.0),
+    }
+}
+
+/// 1 based indexes of the rows or columns followed by a manual page break.
+pub(crate) fn manual_breaks(breaks: Option<&XlsxPageBreaks>) -> BTreeSet<u64> {
+    let Some(breaks) = breaks else {
+        return BTreeSet::new();
+    };
+    breaks
+        .breaks
+        .iter()
+        .filter(|b| b.manual != Some(false))
+        .filter_map(|b| b.id)
+        .filter(|id| *id > 0)
+        .collect()
+}
+
+/// Print areas of a sheet, from the `_xlnm.Print_Area` defined name.
+///
+/// Whole rows and columns, ie: `Sheet1!$A:$D`, are limited to `used_range`.
+pub(crate) fn print_areas(
+    defined_names: &XlsxDefinedNames,
+    sheet_name: &str,
+    used_range: Option<Dimension>,
+) -> Vec<Dimension> {
+    let mut areas: Vec<Dimension> = vec![];
+    for defined_name in defined_names {
+        if !defined_name
+            .name
+            .as_deref()
+            .is_some_and(|n| n.eq_ignore_ascii_case(PRINT_AREA_NAME))
+        {
+            continue;
+        }
+        let Some(value) = defined_name.value.as_deref() else {
+            continue;
+        };
+        for reference in split_references(value) {
+            let Some((sheet, range)) = reference.rsplit_once('!') else {
+                continue;
+            };
+            if !sheet_names_equal(&unquote_sheet_name(sheet), sheet_name) {
+                continue;
+            }
+            if let Some(area) = parse_area(range, used_range) {
+                areas.push(area);
+            }
+        }
+    }
+    areas
+}
@@ -0,0 +1,93 @@
+use crate::common_types::{Coordinate, Dimension};
+
+/// Index of the cells covered by the ranges of items, ie: data validations or conditional formattings,
+/// for looking up the items applying to a cell in O(log n).
+///
+/// Rows are split into bands at the first and after the last row of every range,
+/// each band holding the column spans of the ranges covering it, sorted by first column.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub(crate) struct RangeIndex {
+    /// (first row of the band, column spans)
+    bands: Vec<(u64, Vec<ColumnSpan>)>,
+}
+
+/// (first column, last column, index of the item)
+type ColumnSpan = (u64, u64, usize);
+
+impl RangeIndex {
+    /// * ranges: (range, index of the item the range belongs to)
+    pub(crate) fn new(ranges: Vec<(Dimension, usize)>) -> Self {
+        let mut boundaries: Vec<u64> = ranges
+            .iter()
+            .flat_map(|(range, _)| [range.start.row, range.end.row + 1])
+            .collect();
+        boundaries.sort_unstable();
+        boundaries.dedup();
+
+        let bands = boundaries
+            .iter()
+            .map(|row| {
+                let mut spans: Vec<ColumnSpan> = ranges
+                    .iter()
+                    .filter(|(range, _)| (range.start.row..=range.end.row).contains(row))
+                    .map(|(range, index)| (range.start.col, range.end.col, *index))
+                    .collect();
+                spans.sort_unstable();
+                (*row, spans)
+            })
+            .collect();
+        return Self { bands };
+    }
+
+    /// Index of the items from the sequence of references (`sqref`) of each item.
+    pub(crate) fn from_sqrefs<'a>(sqrefs: impl Iterator<Item = &'a str>) -> Self {
+        return Self::new(
+            sqrefs
+                .enumerate()
+                .flat_map(|(index, sqref)| {
+                    Dimension::from_sqref(sqref)
+                        .into_iter()
+                        .map(move |range| (range, index))
+                })
+                .collect(),
+        );
+    }
+
+    /// Indexes of the items covering a cell, in increasing order.
+    pub(crate) fn find_all(&self, coordinate: &Coordinate) -> Vec<usize> {
+        let Some(spans) = self.spans_starting_before(coordinate) else {
+            return vec![];
+        };
+        let mut indexes: Vec<usize> = spans
+            .iter()
+            .filter(|(_, end, _)| *end >= coordinate.col)
+            .map(|(_, _, index)| *index)
+            .collect();
+        indexes.sort_unstable();
+        indexes.dedup();
+        return indexes;
+    }
+
+    /// Index of the item covering a cell, for items whose ranges are not expected to overlap.
+    ///
+    /// The range starting the closest to the left of the cell is used if they do.
+    pub(crate) fn find(&self, coordinate: &Coordinate) -> Option<usize> {
+        return self
+            .spans_starting_before(coordinate)?
+            .iter()
+            .rev()
+            .find(|(_, end, _)| *end >= coordinate.col)
+            .map(|(_, _, index)| *index);
+    }
+
+    /// Spans of the band of the row of a cell, starting at or before its column.
+    fn spans_starting_before(&self, coordinate: &Coordinate) -> Option<&[ColumnSpan]> {
+        let band = self
+            .bands
+            .partition_point(|(row, _)| *row <= coordinate.row)
+            .checked_sub(1)?;
+        let spans = &self.bands[band].1;
+        let candidates = spans.partition_point(|(start, _, _)| *start <= coordinate.col);
+        return Some(&spans[..candidates]);
+    }
+}
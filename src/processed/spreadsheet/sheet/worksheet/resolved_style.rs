@@ -0,0 +1,48 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::cell::cell_property::{
+    border::Border,
+    fill::{Fill, PatternFillTypeValue},
+    font::Font,
+    numbering_format::NumberingFormat,
+    text_alignment::TextAlignment,
+};
+use crate::common_types::HexColor;
+
+/// Formatting of a cell, resolved from the cell, row and column styles, see `Worksheet::cell_style`.
+///
+/// Colors are resolved against the indexed colors and the theme of the workbook.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ResolvedStyle {
+    /// Index in cellXfs of the style applied: of the cell, else of its row or column.
+    ///
+    /// None for the default style.
+    pub style_index: Option<u64>,
+
+    pub font: Font,
+    pub fill: Fill,
+    pub border: Border,
+    pub alignment: TextAlignment,
+    pub numbering_format: NumberingFormat,
+
+    /// Cell cannot be edited once the sheet is protected. Cells are locked by default.
+    pub locked: bool,
+
+    /// Formula of the cell is not displayed once the sheet is protected.
+    pub formula_hidden: bool,
+}
+
+impl ResolvedStyle {
+    /// Background color: foreground color of a pattern fill, None without fill or for gradient fills.
+    pub fn fill_color(&self) -> Option<&HexColor> {
+        let Fill::PatternFill(pattern_fill) = &self.fill else {
+            return None;
+        };
+        if pattern_fill.pattern_type == PatternFillTypeValue::None {
+            return None;
+        }
+        return pattern_fill.foreground_color.as_ref();
+    }
+}
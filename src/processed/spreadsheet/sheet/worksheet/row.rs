@@ -0,0 +1,215 @@
+use anyhow::bail;
+use quick_xml::events::Event;
+use std::io::{Read, Seek};
+
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+use crate::{
+    common_types::{CellId, Coordinate, Dimension},
+    excel::{ExcelOptions, XmlReader},
+    raw::spreadsheet::sheet::worksheet::row::XlsxRow,
+};
+
+use super::{cell::Cell, Worksheet};
+
+/// A row of a worksheet, yielded by `Worksheet::rows`.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct Row {
+    /// row index (1 based)
+    pub index: u64,
+
+    /// Deterministic id of the row, only assigned with `ExcelOptions::cell_ids`.
+    pub id: Option<CellId>,
+
+    /// Height in points, None for the default height.
+    pub height: Option<f64>,
+
+    pub hidden: bool,
+
+    /// Cells written in the file for this row, from left to right.
+    ///
+    /// Columns without a cell element are left out.
+    /// With `Rows::propagate_merged_values`, cells covered by a vertically merged region are included with the value of the region.
+    pub cells: Vec<Cell>,
+}
+
+/// Lazy iterator over the rows of a worksheet, see `Worksheet::rows`.
+///
+/// `sheetData` is parsed one row at a time, so only the current row is held in memory.
+/// Iteration stops after the first error.
+pub struct Rows<'a, RS: Read + Seek> {
+    worksheet: &'a Worksheet,
+    reader: Option<XmlReader<'a, RS>>,
+    options: ExcelOptions,
+    buf: Vec<u8>,
+
+    /// Merged regions spanning several rows, empty unless merged values are propagated.
+    tall_merged_regions: Vec<Dimension>,
+    /// Merged regions started in a previous row, with their top left cell.
+    open_merged_regions: Vec<(Dimension, Cell)>,
+}
+
+impl<'a, RS: Read + Seek> Rows<'a, RS> {
+    /// Move the reader to the start of `sheetData`, no rows are yielded if the part has none.
+    pub(crate) fn new(
+        worksheet: &'a Worksheet,
+        reader: Option<XmlReader<'a, RS>>,
+        options: ExcelOptions,
+    ) -> anyhow::Result<Self> {
+        let mut rows = Self {
+            worksheet,
+            reader: None,
+            buf: Vec::with_capacity(options.xml_buffer_size),
+            options,
+            tall_merged_regions: vec![],
+            open_merged_regions: vec![],
+        };
+        let Some(mut reader) = reader else {
+            return Ok(rows);
+        };
+
+        loop {
+            rows.buf.clear();
+
+            match reader.read_event_into(&mut rows.buf) {
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"sheetData" => {
+                    rows.reader = Some(reader);
+                    break;
+                }
+                Ok(Event::End(ref e)) if e.local_name().as_ref() == b"worksheet" => break,
+                Ok(Event::Eof) => break,
+                Err(e) => bail!(e.to_string()),
+                _ => (),
+            }
+        }
+
+        Ok(rows)
+    }
+
+    /// Yield the value of a vertically merged region in every row it covers, not only in its first row.
+    ///
+    /// Meant for grouped report layouts, ie: a category merged down the rows of its items.
+    /// The top left cell of the region is repeated in the first column of the region for the following rows,
+    /// replacing the empty cell written in the file if any.
+    /// Rows left out of the file are not yielded, and so not filled either.
+    pub fn propagate_merged_values(mut self) -> Self {
+        self.tall_merged_regions = self
+            .worksheet
+            .merged_cells
+            .iter()
+            .filter(|region| region.end.row > region.start.row)
+            .copied()
+            .collect();
+        return self;
+    }
+
+    /// Next row as written in the file, with its raw cells, the reader is dropped after the last row.
+    pub(crate) fn next_raw_row(&mut self) -> anyhow::Result<Option<XlsxRow>> {
+        let Some(reader) = self.reader.as_mut() else {
+            return Ok(None);
+        };
+
+        loop {
+            self.buf.clear();
+
+            match reader.read_event_into(&mut self.buf) {
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"row" => {
+                    return Ok(Some(XlsxRow::load(reader, e, &self.options, true)?));
+                }
+                Ok(Event::End(ref e)) if e.local_name().as_ref() == b"sheetData" => {
+                    self.reader = None;
+                    return Ok(None);
+                }
+                Ok(Event::Eof) => bail!("unexpected end of file at `sheetData`."),
+                Err(e) => bail!(e.to_string()),
+                _ => (),
+            }
+        }
+    }
+
+    fn next_row(&mut self) -> anyhow::Result<Option<Row>> {
+        let Some(mut raw_row) = self.next_raw_row()? else {
+            return Ok(None);
+        };
+
+        let Some(index) = raw_row.row_index else {
+            bail!("row of unknwon index.")
+        };
+        // row information without its cells, cloned for every cell
+        let raw_cells = raw_row.cells.take().unwrap_or(vec![]);
+        let mut cells: Vec<Cell> = Vec::with_capacity(raw_cells.len());
+        for raw_cell in raw_cells {
+            let Some(coordinate) = raw_cell.coordinate() else {
+                continue;
+            };
+            self.worksheet.cache_master_formula(&raw_cell);
+            cells.push(
+                self.worksheet
+                    .process_cell(coordinate, raw_cell, raw_row.clone())?,
+            );
+        }
+
+        if !self.tall_merged_regions.is_empty() {
+            self.fill_merged_values(index, &mut cells);
+        }
+
+        Ok(Some(Row {
+            index,
+            id: self.worksheet.row_id(index),
+            height: raw_row.height,
+            hidden: raw_row.hidden.unwrap_or(false),
+            cells,
+        }))
+    }
+
+    /// Add the values of the merged regions covering a row, and open the regions starting on the row.
+    fn fill_merged_values(&mut self, index: u64, cells: &mut Vec<Cell>) {
+        self.open_merged_regions
+            .retain(|(region, _)| region.end.row >= index);
+        for (region, origin) in &self.open_merged_regions {
+            if region.start.row >= index {
+                continue;
+            }
+            let coordinate = Coordinate::from_point((index, region.start.col));
+            let filled = Cell {
+                coordinate,
+                id: self.worksheet.cell_id(coordinate),
+                ..origin.clone()
+            };
+            match cells.binary_search_by_key(&coordinate.col, |c| c.coordinate.col) {
+                Ok(position) => cells[position] = filled,
+                Err(position) => cells.insert(position, filled),
+            }
+        }
+
+        for region in &self.tall_merged_regions {
+            if region.start.row != index {
+                continue;
+            }
+            if let Some(origin) = cells.iter().find(|c| c.coordinate == region.start) {
+                self.open_merged_regions.push((*region, origin.clone()));
+            }
+        }
+    }
+}
+
+impl<RS: Read + Seek> Iterator for Rows<'_, RS> {
+    type Item = anyhow::Result<Row>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.next_row() {
+            Ok(row) => {
+                if row.is_none() {
+                    self.reader = None;
+                }
+                row.map(Ok)
+            }
+            Err(error) => {
+                self.reader = None;
+                Some(Err(error))
+            }
+        }
+    }
+}
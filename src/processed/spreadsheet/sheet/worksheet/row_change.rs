@@ -0,0 +1,143 @@
+use std::collections::{HashMap, VecDeque};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::{cell::cell_value::CellValueType, row_hash::RowValues, Worksheet};
+use crate::{common_types::Dimension, raw::spreadsheet::sheet::worksheet::row::XlsxRow};
+
+/// Change of a row between two versions of a sheet, matched by key, see `Worksheet::changes_by_key`.
+///
+/// Values are those of the columns of the compared range, in order.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum RowChangeEvent {
+    /// The key is new.
+    Insert {
+        key: String,
+        row: u64,
+        values: Vec<CellValueType>,
+    },
+
+    /// The key exists in both versions with different values.
+    Update {
+        key: String,
+        previous_row: u64,
+        row: u64,
+        previous_values: Vec<CellValueType>,
+        values: Vec<CellValueType>,
+    },
+
+    /// The key no longer exists.
+    Delete {
+        key: String,
+        previous_row: u64,
+        previous_values: Vec<CellValueType>,
+    },
+}
+
+impl RowChangeEvent {
+    /// Normalized text of the key column.
+    pub fn key(&self) -> &str {
+        return match self {
+            RowChangeEvent::Insert { key, .. }
+            | RowChangeEvent::Update { key, .. }
+            | RowChangeEvent::Delete { key, .. } => key,
+        };
+    }
+}
+
+/// Iterator over the changes of a sheet compared to a previous version, see `Worksheet::changes_by_key`.
+pub struct RowChangeEvents<'a> {
+    worksheet: &'a Worksheet,
+    rows: std::slice::Iter<'a, XlsxRow>,
+    range: Dimension,
+    columns: Vec<u64>,
+    key_position: usize,
+
+    /// Rows of the previous version by key, removed once matched.
+    previous: HashMap<String, VecDeque<RowValues>>,
+
+    /// Rows of the previous version left unmatched, once all the rows are read.
+    deleted: Option<std::vec::IntoIter<RowValues>>,
+}
+
+impl<'a> RowChangeEvents<'a> {
+    pub(crate) fn new(
+        worksheet: &'a Worksheet,
+        range: Dimension,
+        columns: Vec<u64>,
+        key_column: u64,
+        previous_rows: Vec<RowValues>,
+    ) -> Self {
+        let key_position = columns.iter().position(|c| *c == key_column).unwrap_or(0);
+        let mut previous: HashMap<String, VecDeque<RowValues>> = HashMap::new();
+        for values in previous_rows {
+            let key = values.normalized[key_position].1.clone();
+            if key.is_empty() {
+                continue;
+            }
+            previous.entry(key).or_default().push_back(values);
+        }
+        return Self {
+            worksheet,
+            rows: worksheet.raw_rows(),
+            range,
+            columns,
+            key_position,
+            previous,
+            deleted: None,
+        };
+    }
+
+    fn next_deleted(&mut self) -> Option<RowChangeEvent> {
+        let deleted = self.deleted.get_or_insert_with(|| {
+            let mut rows: Vec<RowValues> = self.previous.drain().flat_map(|(_, r)| r).collect();
+            rows.sort_by_key(|r| r.row);
+            rows.into_iter()
+        });
+        let values = deleted.next()?;
+        return Some(RowChangeEvent::Delete {
+            key: values.normalized[self.key_position].1.clone(),
+            previous_row: values.row,
+            previous_values: values.values,
+        });
+    }
+}
+
+impl Iterator for RowChangeEvents<'_> {
+    type Item = anyhow::Result<RowChangeEvent>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for row in self.rows.by_ref() {
+            let values = match self.worksheet.row_values(row, self.range, &self.columns) {
+                Ok(Some(values)) => values,
+                Ok(None) => continue,
+                Err(error) => return Some(Err(error)),
+            };
+            let key = values.normalized[self.key_position].1.clone();
+            if key.is_empty() {
+                continue;
+            }
+            let Some(previous) = self.previous.get_mut(&key).and_then(|r| r.pop_front()) else {
+                return Some(Ok(RowChangeEvent::Insert {
+                    key,
+                    row: values.row,
+                    values: values.values,
+                }));
+            };
+            if previous.hash() == values.hash() {
+                continue;
+            }
+            return Some(Ok(RowChangeEvent::Update {
+                key,
+                previous_row: previous.row,
+                row: values.row,
+                previous_values: previous.values,
+                values: values.values,
+            }));
+        }
+        self.next_deleted().map(Ok)
+    }
+}
@@ -0,0 +1,326 @@
+use anyhow::anyhow;
+use serde::de::{
+    self,
+    value::{Error, MapDeserializer, SeqDeserializer},
+    DeserializeOwned, Deserializer, IntoDeserializer, Visitor,
+};
+use std::{
+    io::{Read, Seek},
+    marker::PhantomData,
+};
+
+use super::{cell::cell_value::typed_value::CellValue, row::Rows};
+use crate::dates::to_serial;
+
+/// Iterator over the rows of a worksheet deserialized into records, see `Worksheet::deserialize`.
+///
+/// The first row holding a value is the header: its texts are the field names of the records.
+/// Rows without any value are skipped.
+pub struct DeserializeRows<'a, RS: Read + Seek, T> {
+    rows: Rows<'a, RS>,
+    is_1904: bool,
+    /// (column index, name) of the header cells, None until the header row is read
+    header: Option<Vec<(u64, String)>>,
+    record: PhantomData<T>,
+}
+
+impl<'a, RS: Read + Seek, T: DeserializeOwned> DeserializeRows<'a, RS, T> {
+    pub(crate) fn new(rows: Rows<'a, RS>, is_1904: bool) -> Self {
+        return Self {
+            rows,
+            is_1904,
+            header: None,
+            record: PhantomData,
+        };
+    }
+
+    /// Names of the header cells from left to right, None before the first record is read.
+    pub fn header(&self) -> Option<Vec<&str>> {
+        self.header
+            .as_ref()
+            .map(|header| header.iter().map(|(_, name)| name.as_str()).collect())
+    }
+}
+
+impl<RS: Read + Seek, T: DeserializeOwned> Iterator for DeserializeRows<'_, RS, T> {
+    type Item = anyhow::Result<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let row = match self.rows.next()? {
+                Ok(row) => row,
+                Err(error) => return Some(Err(error)),
+            };
+            let values: Vec<(u64, CellValue)> = row
+                .cells
+                .iter()
+                .map(|c| (c.coordinate.col, c.typed_value(self.is_1904)))
+                .filter(|(_, value)| !value.is_blank())
+                .collect();
+            if values.is_empty() {
+                continue;
+            }
+
+            let Some(header) = &self.header else {
+                self.header = Some(
+                    values
+                        .into_iter()
+                        .map(|(col, value)| (col, value.to_string().trim().to_string()))
+                        .collect(),
+                );
+                continue;
+            };
+
+            // header columns without a cell in this row are blank
+            let mut values = values.into_iter().peekable();
+            let fields: Vec<(String, CellValue)> = header
+                .iter()
+                .map(|(col, name)| {
+                    while values.next_if(|(c, _)| c < col).is_some() {}
+                    let value = values.next_if(|(c, _)| c == col).map(|(_, v)| v);
+                    (name.clone(), value.unwrap_or_default())
+                })
+                .collect();
+            let record = T::deserialize(RecordDeserializer {
+                fields,
+                is_1904: self.is_1904,
+            });
+            return Some(record.map_err(|error| anyhow!("row {}: {}", row.index, error)));
+        }
+    }
+}
+
+/// Deserializer of a record: a map from the header names to the values of a row,
+/// or a sequence of the values for tuples and vectors.
+struct RecordDeserializer {
+    fields: Vec<(String, CellValue)>,
+    is_1904: bool,
+}
+
+impl<'de> Deserializer<'de> for RecordDeserializer {
+    type Error = Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        let is_1904 = self.is_1904;
+        let fields = self.fields.into_iter().map(|(name, value)| {
+            (name, ValueDeserializer { value, is_1904 })
+        });
+        visitor.visit_map(MapDeserializer::new(fields))
+    }
+
+    fn deserialize_seq<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        let is_1904 = self.is_1904;
+        let values = self
+            .fields
+            .into_iter()
+            .map(|(_, value)| ValueDeserializer { value, is_1904 });
+        visitor.visit_seq(SeqDeserializer::new(values))
+    }
+
+    fn deserialize_tuple<V: Visitor<'de>>(self, _len: usize, visitor: V) -> Result<V::Value, Error> {
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_tuple_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _len: usize,
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf option unit unit_struct map struct enum identifier ignored_any
+    }
+}
+
+/// Deserializer of a cell value, converting between types the way a CSV reader does:
+/// numbers from texts reading as numbers, texts from any value, None from blank cells.
+struct ValueDeserializer {
+    value: CellValue,
+    is_1904: bool,
+}
+
+impl<'de> IntoDeserializer<'de, Error> for ValueDeserializer {
+    type Deserializer = Self;
+
+    fn into_deserializer(self) -> Self {
+        self
+    }
+}
+
+impl ValueDeserializer {
+    fn invalid_type(&self, expected: &dyn de::Expected) -> Error {
+        let unexpected = match &self.value {
+            CellValue::Number(number) => de::Unexpected::Float(*number),
+            CellValue::Text(text) => de::Unexpected::Str(text),
+            CellValue::Bool(b) => de::Unexpected::Bool(*b),
+            CellValue::Error(_) => de::Unexpected::Other("cell error"),
+            CellValue::DateTime(_) => de::Unexpected::Other("date"),
+            CellValue::Blank => de::Unexpected::Other("blank cell"),
+        };
+        de::Error::invalid_type(unexpected, expected)
+    }
+
+    /// Number of numbers, texts reading as numbers, and serial number of dates.
+    fn number(&self) -> Option<f64> {
+        match &self.value {
+            CellValue::Number(number) => Some(*number),
+            CellValue::Text(text) => text.trim().parse::<f64>().ok(),
+            CellValue::DateTime(date_time) => to_serial(*date_time, self.is_1904),
+            _ => None,
+        }
+    }
+
+    /// Integer of numbers without fraction, and of texts reading as integers.
+    fn integer(&self) -> Option<i128> {
+        if let CellValue::Text(text) = &self.value {
+            if let Ok(integer) = text.trim().parse::<i128>() {
+                return Some(integer);
+            }
+        }
+        let number = self.number().filter(|_| !matches!(self.value, CellValue::DateTime(_)))?;
+        if number.fract() != 0.0 || number.abs() >= 2_f64.powi(127) {
+            return None;
+        }
+        Some(number as i128)
+    }
+}
+
+macro_rules! deserialize_integer {
+    ($($method:ident => $visit:ident: $type:ty),*) => {
+        $(
+            fn $method<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+                match self.integer().and_then(|integer| <$type>::try_from(integer).ok()) {
+                    Some(integer) => visitor.$visit(integer),
+                    None => Err(self.invalid_type(&visitor)),
+                }
+            }
+        )*
+    };
+}
+
+impl<'de> Deserializer<'de> for ValueDeserializer {
+    type Error = Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        match self.value {
+            // whole numbers as integers, for untagged enums and flattened fields expecting integers
+            CellValue::Number(number) if number.fract() == 0.0 && number.abs() < 2_f64.powi(63) => {
+                visitor.visit_i64(number as i64)
+            }
+            CellValue::Number(number) => visitor.visit_f64(number),
+            CellValue::Bool(b) => visitor.visit_bool(b),
+            CellValue::Blank => visitor.visit_unit(),
+            CellValue::Text(text) => visitor.visit_string(text),
+            value => visitor.visit_string(value.to_string()),
+        }
+    }
+
+    fn deserialize_bool<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        let b = match &self.value {
+            CellValue::Bool(b) => Some(*b),
+            CellValue::Number(number) if *number == 0.0 || *number == 1.0 => Some(*number == 1.0),
+            CellValue::Text(text) => match text.trim().to_ascii_lowercase().as_str() {
+                "true" | "1" => Some(true),
+                "false" | "0" => Some(false),
+                _ => None,
+            },
+            _ => None,
+        };
+        match b {
+            Some(b) => visitor.visit_bool(b),
+            None => Err(self.invalid_type(&visitor)),
+        }
+    }
+
+    deserialize_integer! {
+        deserialize_i8 => visit_i8: i8,
+        deserialize_i16 => visit_i16: i16,
+        deserialize_i32 => visit_i32: i32,
+        deserialize_i64 => visit_i64: i64,
+        deserialize_i128 => visit_i128: i128,
+        deserialize_u8 => visit_u8: u8,
+        deserialize_u16 => visit_u16: u16,
+        deserialize_u32 => visit_u32: u32,
+        deserialize_u64 => visit_u64: u64,
+        deserialize_u128 => visit_u128: u128
+    }
+
+    fn deserialize_f32<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        self.deserialize_f64(visitor)
+    }
+
+    fn deserialize_f64<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        match self.number() {
+            Some(number) => visitor.visit_f64(number),
+            None => Err(self.invalid_type(&visitor)),
+        }
+    }
+
+    fn deserialize_char<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        self.deserialize_string(visitor)
+    }
+
+    fn deserialize_str<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        self.deserialize_string(visitor)
+    }
+
+    /// Text of the value as `CellValue` displays it, empty for blank cells.
+    fn deserialize_string<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        match self.value {
+            CellValue::Text(text) => visitor.visit_string(text),
+            value => visitor.visit_string(value.to_string()),
+        }
+    }
+
+    /// None for blank cells and texts of spaces only.
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        match &self.value {
+            CellValue::Blank => visitor.visit_none(),
+            CellValue::Text(text) if text.trim().is_empty() => visitor.visit_none(),
+            _ => visitor.visit_some(self),
+        }
+    }
+
+    fn deserialize_unit<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        visitor.visit_unit()
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    /// Unit variants named by the text of the value.
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        let text = self.value.to_string();
+        visitor.visit_enum(text.trim().to_string().into_deserializer())
+    }
+
+    fn deserialize_ignored_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        visitor.visit_unit()
+    }
+
+    serde::forward_to_deserialize_any! {
+        bytes byte_buf unit_struct seq tuple tuple_struct map struct identifier
+    }
+}
@@ -0,0 +1,211 @@
+use std::collections::{BTreeMap, HashMap, VecDeque};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use unicode_normalization::UnicodeNormalization;
+
+use super::cell::cell_value::CellValueType;
+use crate::helper::string_to_bool;
+
+/// FNV-1a 64 bit, fixed so that hashes stay comparable across versions and platforms.
+static FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+static FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+/// Separates the values of a row, so that `["ab", "c"]` and `["a", "bc"]` differ.
+static VALUE_SEPARATOR: u8 = 0x1f;
+
+/// Hash of the values of a row, see `Worksheet::row_hashes`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct RowHash {
+    /// 1 based row index
+    pub row: u64,
+
+    /// Hash of the normalized values, independent of the row index.
+    ///
+    /// The same values give the same hash in any file, version of the crate or platform, so hashes can be stored between runs.
+    pub hash: u64,
+}
+
+/// Rows inserted, deleted and changed between two versions of a sheet, see `RowChanges::between`.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct RowChanges {
+    /// Rows with the same values in both versions: (old row, new row).
+    pub unchanged: Vec<(u64, u64)>,
+
+    /// Rows of the new version without a counterpart in the old version.
+    pub inserted: Vec<u64>,
+
+    /// Rows of the old version without a counterpart in the new version.
+    pub deleted: Vec<u64>,
+
+    /// Rows whose values changed: (old row, new row).
+    pub changed: Vec<(u64, u64)>,
+}
+
+impl RowChanges {
+    /// Compare the row hashes of two versions of a sheet.
+    ///
+    /// Rows with the same hash are matched in order, wherever they moved.
+    /// The remaining rows between the same matched rows are paired in order as changed, the rest are inserted or deleted.
+    pub fn between(old: &[RowHash], new: &[RowHash]) -> Self {
+        let mut old_by_hash: HashMap<u64, VecDeque<usize>> = HashMap::new();
+        for (index, row) in old.iter().enumerate() {
+            old_by_hash.entry(row.hash).or_default().push_back(index);
+        }
+
+        let mut changes = Self::default();
+        let mut old_matched = vec![false; old.len()];
+        let mut new_matched = vec![false; new.len()];
+        for (index, row) in new.iter().enumerate() {
+            let Some(old_index) = old_by_hash
+                .get_mut(&row.hash)
+                .and_then(|indices| indices.pop_front())
+            else {
+                continue;
+            };
+            old_matched[old_index] = true;
+            new_matched[index] = true;
+            changes.unchanged.push((old[old_index].row, row.row));
+        }
+
+        // unmatched rows grouped by the number of matched rows before them
+        let mut gaps: BTreeMap<usize, (Vec<u64>, Vec<u64>)> = BTreeMap::new();
+        for (gap, row) in unmatched_rows(old, &old_matched) {
+            gaps.entry(gap).or_default().0.push(row);
+        }
+        for (gap, row) in unmatched_rows(new, &new_matched) {
+            gaps.entry(gap).or_default().1.push(row);
+        }
+        for (old_rows, new_rows) in gaps.into_values() {
+            let paired = old_rows.len().min(new_rows.len());
+            changes
+                .changed
+                .extend(old_rows.iter().copied().zip(new_rows.iter().copied()));
+            changes.deleted.extend_from_slice(&old_rows[paired..]);
+            changes.inserted.extend_from_slice(&new_rows[paired..]);
+        }
+
+        changes.unchanged.sort_by_key(|(_, new_row)| *new_row);
+        changes.changed.sort_by_key(|(_, new_row)| *new_row);
+        changes.inserted.sort();
+        changes.deleted.sort();
+        return changes;
+    }
+
+    /// No row was inserted, deleted or changed. Rows may have moved.
+    pub fn is_empty(&self) -> bool {
+        return self.inserted.is_empty() && self.deleted.is_empty() && self.changed.is_empty();
+    }
+}
+
+/// Rows not matched, with the number of matched rows before them.
+fn unmatched_rows<'a>(
+    rows: &'a [RowHash],
+    matched: &'a [bool],
+) -> impl Iterator<Item = (usize, u64)> + 'a {
+    let mut matched_count = 0;
+    return rows.iter().zip(matched).filter_map(move |(row, matched)| {
+        if *matched {
+            matched_count += 1;
+            return None;
+        }
+        Some((matched_count, row.row))
+    });
+}
+
+/// Values of a row, with their normalized form for hashing.
+pub(crate) struct RowValues {
+    pub(crate) row: u64,
+    pub(crate) values: Vec<CellValueType>,
+    pub(crate) normalized: Vec<(u8, String)>,
+}
+
+impl RowValues {
+    pub(crate) fn new(row: u64, column_count: usize) -> Self {
+        return Self {
+            row,
+            values: vec![CellValueType::Empty; column_count],
+            normalized: vec![(0, String::new()); column_count],
+        };
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        return self.normalized.iter().all(|(_, text)| text.is_empty());
+    }
+
+    pub(crate) fn hash(&self) -> u64 {
+        return hash_values(&self.normalized);
+    }
+}
+
+/// Value of a cell in a form that does not depend on how it is stored.
+///
+/// - numbers in their shortest form, ie: `1.50` and `1.5E0` -> `1.5`
+/// - texts in Unicode normalization form C, without surrounding whitespace, plain and rich text alike
+/// - formulas by their last calculated value, so that a value and a formula computing it are the same
+///
+/// * raw_type: `t` of the cell, to tell booleans and errors calculated by formulas
+pub(crate) fn normalized_value(value: &CellValueType, raw_type: Option<&str>) -> (u8, String) {
+    return match value {
+        CellValueType::Numeric(number) => normalized_number(*number),
+        CellValueType::Bool(b) => (b'b', value_text(*b)),
+        CellValueType::DateTime(date_time) => (b'd', date_time.trim().to_string()),
+        CellValueType::Error(error) => (b'e', error.to_string()),
+        CellValueType::Empty => (0, String::new()),
+        CellValueType::PlainText(_) | CellValueType::RichText(_) => {
+            normalized_text(&value.to_string())
+        }
+        CellValueType::Formula(formula) => {
+            let cached = formula.last_calculated_value.as_deref().unwrap_or("");
+            match raw_type {
+                Some("b") => match string_to_bool(cached) {
+                    Some(b) => (b'b', value_text(b)),
+                    None => normalized_text(cached),
+                },
+                Some("e") => (b'e', cached.to_string()),
+                Some("str" | "inlineStr") => normalized_text(cached),
+                _ if cached.is_empty() => (0, String::new()),
+                _ => match cached.parse::<f64>() {
+                    Ok(number) => normalized_number(number),
+                    Err(_) => normalized_text(cached),
+                },
+            }
+        }
+    };
+}
+
+fn value_text(b: bool) -> String {
+    return CellValueType::Bool(b).to_string();
+}
+
+fn normalized_number(number: f64) -> (u8, String) {
+    // -0 and 0 display the same
+    let number = if number == 0.0 { 0.0 } else { number };
+    return (b'n', number.to_string());
+}
+
+fn normalized_text(text: &str) -> (u8, String) {
+    let text: String = text.trim().nfc().collect();
+    if text.is_empty() {
+        return (0, text);
+    }
+    return (b's', text);
+}
+
+/// FNV-1a hash of normalized values, each tagged with its kind so that the number `1` and the text `"1"` differ.
+pub(crate) fn hash_values(values: &[(u8, String)]) -> u64 {
+    let mut hash = FNV_OFFSET_BASIS;
+    let mut write = |byte: u8| {
+        hash ^= byte as u64;
+        hash = hash.wrapping_mul(FNV_PRIME);
+    };
+    for (kind, text) in values {
+        write(*kind);
+        text.bytes().for_each(&mut write);
+        write(VALUE_SEPARATOR);
+    }
+    return hash;
+}
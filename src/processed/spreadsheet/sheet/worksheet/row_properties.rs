@@ -0,0 +1,69 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::raw::spreadsheet::sheet::worksheet::row::XlsxRow;
+
+/// Attributes of a `row` element, see `Worksheet::row_properties`.
+///
+/// Unspecified attributes hold their schema defaults.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct RowProperties {
+    /// row index (1 based)
+    pub row: u64,
+
+    /// Height in points, None for the default height.
+    pub height: Option<f64>,
+
+    /// The height was set manually rather than fitted to the content.
+    pub custom_height: bool,
+
+    pub hidden: bool,
+
+    /// Outline (grouping) level, 0 for rows outside of any group.
+    pub outline_level: u64,
+
+    /// The outline group of the rows next to this one is collapsed.
+    pub collapsed: bool,
+
+    /// `style_index` applies to the whole row.
+    pub custom_format: bool,
+
+    /// s (Style Index): 0 based index reference to `cellXfs` in stylesheet, only applied when `custom_format` is set.
+    pub style_index: Option<u64>,
+
+    pub thick_top: bool,
+    pub thick_bottom: bool,
+}
+
+impl RowProperties {
+    pub(crate) fn from_raw(raw: &XlsxRow) -> Option<Self> {
+        let row = raw.row_index?;
+        return Some(Self {
+            row,
+            height: raw.height,
+            custom_height: raw.custom_height.unwrap_or(false),
+            hidden: raw.hidden.unwrap_or(false),
+            outline_level: raw.outline_level.unwrap_or(0),
+            collapsed: raw.collapsed.unwrap_or(false),
+            custom_format: raw.custom_format.unwrap_or(false),
+            style_index: raw.style,
+            thick_top: raw.thick_top.unwrap_or(false),
+            thick_bottom: raw.thick_bottom.unwrap_or(false),
+        });
+    }
+
+    /// True if the row differs from a default row: explicit height, hidden, grouped, collapsed, formatted or with thick borders.
+    ///
+    /// Rows written only to hold cells are not customized.
+    pub fn is_customized(&self) -> bool {
+        return self.height.is_some()
+            || self.custom_height
+            || self.hidden
+            || self.outline_level > 0
+            || self.collapsed
+            || self.custom_format
+            || self.thick_top
+            || self.thick_bottom;
+    }
+}
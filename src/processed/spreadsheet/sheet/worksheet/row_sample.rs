@@ -0,0 +1,195 @@
+use std::{
+    collections::HashMap,
+    io::{Read, Seek},
+};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::{
+    row::{Row, Rows},
+    row_hash::normalized_value,
+};
+
+/// How rows are picked by `Worksheet::sample_rows`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum SampleStrategy {
+    /// First rows of the sheet. Reading stops once enough rows are read.
+    Head,
+
+    /// Rows picked uniformly at random, by reservoir sampling.
+    ///
+    /// The same seed picks the same rows of the same file.
+    Random { seed: u64 },
+
+    /// Rows picked at random among the rows of every distinct value of a column,
+    /// in proportion to the number of rows of each value.
+    ///
+    /// Every value gets at least one row while the sample size allows, values with the most rows first,
+    /// so that rare values are represented. Rows without a value in the column are grouped together.
+    ///
+    /// Up to n rows are held in memory per distinct value.
+    ///
+    /// * column: 1 based column index
+    Stratified { column: u64, seed: u64 },
+}
+
+impl<RS: Read + Seek> Rows<'_, RS> {
+    /// Pick n rows, ordered by row index. All the rows if there are fewer.
+    ///
+    /// Only n rows are held in memory, or n per distinct value for `SampleStrategy::Stratified`.
+    /// Rows left out of the file are not sampled.
+    pub fn sample(self, n: usize, strategy: SampleStrategy) -> anyhow::Result<Vec<Row>> {
+        if n == 0 {
+            return Ok(vec![]);
+        }
+        let mut sample = match strategy {
+            SampleStrategy::Head => self.take(n).collect::<anyhow::Result<Vec<Row>>>()?,
+            SampleStrategy::Random { seed } => {
+                let mut random = SplitMix64::new(seed);
+                let mut reservoir = Reservoir::default();
+                for row in self {
+                    reservoir.push(row?, n, &mut random);
+                }
+                reservoir.rows
+            }
+            SampleStrategy::Stratified { column, seed } => {
+                let mut random = SplitMix64::new(seed);
+                // reservoirs in the order their value is first seen
+                let mut strata: Vec<Reservoir> = vec![];
+                let mut positions: HashMap<String, usize> = HashMap::new();
+                for row in self {
+                    let row = row?;
+                    let key = row
+                        .cells
+                        .iter()
+                        .find(|c| c.coordinate.col == column)
+                        .map(|c| normalized_value(&c.value, c.raw_type.as_deref()).1)
+                        .unwrap_or_default();
+                    let position = *positions.entry(key).or_insert_with(|| {
+                        strata.push(Reservoir::default());
+                        strata.len() - 1
+                    });
+                    strata[position].push(row, n, &mut random);
+                }
+
+                let counts: Vec<u64> = strata.iter().map(|s| s.seen).collect();
+                let mut sample: Vec<Row> = vec![];
+                for (stratum, quota) in strata.into_iter().zip(allocate(n, &counts)) {
+                    sample.extend(stratum.pick(quota, &mut random));
+                }
+                sample
+            }
+        };
+        sample.sort_by_key(|r| r.index);
+        return Ok(sample);
+    }
+}
+
+/// Rows picked uniformly among the rows pushed, Algorithm R.
+#[derive(Default)]
+struct Reservoir {
+    rows: Vec<Row>,
+    seen: u64,
+}
+
+impl Reservoir {
+    fn push(&mut self, row: Row, capacity: usize, random: &mut SplitMix64) {
+        self.seen += 1;
+        if self.rows.len() < capacity {
+            self.rows.push(row);
+            return;
+        }
+        let index = random.below(self.seen);
+        if index < capacity as u64 {
+            self.rows[index as usize] = row;
+        }
+    }
+
+    /// n rows picked uniformly among the rows held.
+    fn pick(mut self, n: usize, random: &mut SplitMix64) -> Vec<Row> {
+        let n = n.min(self.rows.len());
+        for index in 0..n {
+            let remaining = (self.rows.len() - index) as u64;
+            let other = index + random.below(remaining) as usize;
+            self.rows.swap(index, other);
+        }
+        self.rows.truncate(n);
+        return self.rows;
+    }
+}
+
+/// Number of rows to pick from each stratum, given the number of rows of each.
+///
+/// In proportion to the rows of each stratum, remainders going to the largest fractions.
+/// Strata left without a row then take one from the stratum with the most rows picked,
+/// or with fewer rows picked than strata, one row goes to each of the largest strata.
+fn allocate(n: usize, counts: &[u64]) -> Vec<usize> {
+    let mut quotas = vec![0_usize; counts.len()];
+    let mut by_size: Vec<usize> = (0..counts.len()).collect();
+    by_size.sort_by_key(|i| std::cmp::Reverse(counts[*i]));
+    if n <= counts.len() {
+        for i in by_size.iter().take(n) {
+            quotas[*i] = 1;
+        }
+        return quotas;
+    }
+
+    let total: u128 = counts.iter().map(|c| *c as u128).sum();
+    if n as u128 >= total {
+        return counts.iter().map(|c| *c as usize).collect();
+    }
+    let mut remainders: Vec<(u128, usize)> = vec![];
+    let mut allocated = 0;
+    for (i, count) in counts.iter().enumerate() {
+        let share = n as u128 * *count as u128;
+        quotas[i] = (share / total) as usize;
+        allocated += quotas[i];
+        remainders.push((share % total, i));
+    }
+    // largest remainders, then the strata seen first
+    remainders.sort_by(|a, b| b.0.cmp(&a.0).then(a.1.cmp(&b.1)));
+    for (_, i) in remainders.into_iter().take(n - allocated) {
+        quotas[i] += 1;
+    }
+
+    for i in by_size {
+        if quotas[i] > 0 {
+            continue;
+        }
+        // more rows than strata: a stratum has more than one row while one has none
+        let Some(largest) = (0..quotas.len()).max_by_key(|j| (quotas[*j], std::cmp::Reverse(*j)))
+        else {
+            break;
+        };
+        quotas[largest] -= 1;
+        quotas[i] = 1;
+    }
+    return quotas;
+}
+
+/// SplitMix64 pseudo random numbers, so that samples are reproducible across platforms.
+struct SplitMix64 {
+    state: u64,
+}
+
+impl SplitMix64 {
+    fn new(seed: u64) -> Self {
+        return Self { state: seed };
+    }
+
+    fn next(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        return z ^ (z >> 31);
+    }
+
+    /// Number in `0..bound`.
+    fn below(&mut self, bound: u64) -> u64 {
+        return ((self.next() as u128 * bound as u128) >> 64) as u64;
+    }
+}
@@ -0,0 +1,143 @@
+use anyhow::bail;
+use serde::Serialize;
+use std::io::{Read, Seek, Write};
+
+use super::row::{Row, Rows};
+
+/// How the JSON chunks written by `RowChunkWriter` are delimited.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChunkFraming {
+    /// Byte length of the JSON text as a 4 bytes big endian unsigned integer, followed by the JSON text.
+    LengthPrefixed,
+
+    /// Server-sent events frames: `event: rows` with the JSON text as `data`,
+    /// and a final `event: end` frame once every row is written.
+    ServerSentEvents,
+}
+
+/// Content of a chunk: consecutive rows of the sheet.
+#[derive(Serialize)]
+struct RowChunk<'a> {
+    /// 0 based position of the chunk in the stream
+    chunk: u64,
+    rows: &'a [Row],
+}
+
+/// Summary sent in the `end` frame of server-sent events.
+#[derive(Serialize)]
+struct StreamEnd {
+    chunks: u64,
+    rows: u64,
+}
+
+/// Write rows as framed JSON chunks of a few rows each, so that a frontend grid can display them while the sheet is read.
+///
+/// A chunk is written every `rows_per_chunk` rows, call `finish` to write the remaining rows.
+/// Each chunk is a JSON object `{"chunk": 0, "rows": [...]}`, rows serialized the same way as `Row`.
+pub struct RowChunkWriter<W: Write> {
+    writer: W,
+    framing: ChunkFraming,
+    rows_per_chunk: usize,
+    pending: Vec<Row>,
+    chunk_count: u64,
+    row_count: u64,
+}
+
+impl<W: Write> RowChunkWriter<W> {
+    /// * rows_per_chunk: number of rows per chunk, at least 1
+    pub fn new(writer: W, framing: ChunkFraming, rows_per_chunk: usize) -> Self {
+        let rows_per_chunk = rows_per_chunk.max(1);
+        return Self {
+            writer,
+            framing,
+            rows_per_chunk,
+            pending: Vec::with_capacity(rows_per_chunk),
+            chunk_count: 0,
+            row_count: 0,
+        };
+    }
+
+    /// Add a row, writing a chunk if enough rows are pending.
+    pub fn push(&mut self, row: Row) -> anyhow::Result<()> {
+        self.pending.push(row);
+        self.row_count += 1;
+        if self.pending.len() >= self.rows_per_chunk {
+            self.write_pending()?;
+        }
+        Ok(())
+    }
+
+    /// Write the pending rows, and the `end` frame for server-sent events.
+    ///
+    /// Returns the underlying writer, flushed.
+    pub fn finish(mut self) -> anyhow::Result<W> {
+        if !self.pending.is_empty() {
+            self.write_pending()?;
+        }
+        if self.framing == ChunkFraming::ServerSentEvents {
+            let end = serde_json::to_vec(&StreamEnd {
+                chunks: self.chunk_count,
+                rows: self.row_count,
+            })?;
+            self.write_frame("end", &end)?;
+        }
+        self.writer.flush()?;
+        return Ok(self.writer);
+    }
+
+    /// Number of chunks written so far.
+    pub fn chunk_count(&self) -> u64 {
+        self.chunk_count
+    }
+
+    fn write_pending(&mut self) -> anyhow::Result<()> {
+        let json = serde_json::to_vec(&RowChunk {
+            chunk: self.chunk_count,
+            rows: &self.pending,
+        })?;
+        self.write_frame("rows", &json)?;
+        self.pending.clear();
+        self.chunk_count += 1;
+        // let the frontend receive the chunk right away
+        self.writer.flush()?;
+        Ok(())
+    }
+
+    fn write_frame(&mut self, event: &str, json: &[u8]) -> anyhow::Result<()> {
+        match self.framing {
+            ChunkFraming::LengthPrefixed => {
+                let Ok(length) = u32::try_from(json.len()) else {
+                    bail!("chunk of {} bytes is too large to be length prefixed, use fewer rows per chunk.", json.len());
+                };
+                self.writer.write_all(&length.to_be_bytes())?;
+                self.writer.write_all(json)?;
+            }
+            ChunkFraming::ServerSentEvents => {
+                // compact JSON escapes line breaks, the data fits on a single line
+                write!(self.writer, "event: {}\ndata: ", event)?;
+                self.writer.write_all(json)?;
+                self.writer.write_all(b"\n\n")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<RS: Read + Seek> Rows<'_, RS> {
+    /// Write the rows as framed JSON chunks, see `RowChunkWriter`.
+    ///
+    /// Stops at the first error, chunks written before are left as is.
+    /// Returns the underlying writer.
+    pub fn write_json_chunks<W: Write>(
+        self,
+        writer: W,
+        framing: ChunkFraming,
+        rows_per_chunk: usize,
+    ) -> anyhow::Result<W> {
+        let mut chunk_writer = RowChunkWriter::new(writer, framing, rows_per_chunk);
+        for row in self {
+            chunk_writer.push(row?)?;
+        }
+        return chunk_writer.finish();
+    }
+}
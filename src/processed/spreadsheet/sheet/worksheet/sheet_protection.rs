@@ -0,0 +1,110 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::raw::spreadsheet::sheet::sheet_protection::XlsxSheetProtection;
+
+/// Protection settings of a worksheet, from its `sheetProtection` element.
+///
+/// The operation flags are true when the operation is locked, and only apply while `sheet` is true.
+/// Unspecified attributes hold their schema defaults.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SheetProtection {
+    /// True if the sheet is protected.
+    pub sheet: bool,
+
+    /// Hashing algorithm of `hash_value`, ie: `SHA-512`.
+    pub algorithm_name: Option<String>,
+
+    /// base64 encoded hash of the password.
+    pub hash_value: Option<String>,
+
+    /// base64 encoded salt of the hash.
+    pub salt_value: Option<String>,
+
+    /// Number of hashing iterations.
+    pub spin_count: Option<u64>,
+
+    /// Legacy 16 bits password hash in hexadecimal, ie: `CC1A`.
+    pub legacy_password: Option<String>,
+
+    pub objects: bool,
+    pub scenarios: bool,
+    pub format_cells: bool,
+    pub format_columns: bool,
+    pub format_rows: bool,
+    pub insert_columns: bool,
+    pub insert_rows: bool,
+    pub insert_hyperlinks: bool,
+    pub delete_columns: bool,
+    pub delete_rows: bool,
+    pub select_locked_cells: bool,
+    pub select_unlocked_cells: bool,
+    pub sort: bool,
+    pub auto_filter: bool,
+    pub pivot_tables: bool,
+}
+
+impl SheetProtection {
+    pub(crate) fn from_raw(raw: &XlsxSheetProtection) -> Self {
+        return Self {
+            sheet: raw.sheet.unwrap_or(false),
+            algorithm_name: raw.algorithm_name.clone(),
+            hash_value: raw.hash_value.clone(),
+            salt_value: raw.salt_value.clone(),
+            spin_count: raw.spin_count,
+            legacy_password: raw.password.clone(),
+            objects: raw.objects.unwrap_or(false),
+            scenarios: raw.scenarios.unwrap_or(false),
+            format_cells: raw.format_cells.unwrap_or(true),
+            format_columns: raw.format_columns.unwrap_or(true),
+            format_rows: raw.format_rows.unwrap_or(true),
+            insert_columns: raw.insert_columns.unwrap_or(true),
+            insert_rows: raw.insert_rows.unwrap_or(true),
+            insert_hyperlinks: raw.insert_hyperlinks.unwrap_or(true),
+            delete_columns: raw.delete_columns.unwrap_or(true),
+            delete_rows: raw.delete_rows.unwrap_or(true),
+            select_locked_cells: raw.select_locked_cells.unwrap_or(false),
+            select_unlocked_cells: raw.select_unlocked_cells.unwrap_or(false),
+            sort: raw.sort.unwrap_or(true),
+            auto_filter: raw.auto_filter.unwrap_or(true),
+            pivot_tables: raw.pivot_tables.unwrap_or(true),
+        };
+    }
+
+    /// True if a password is needed to unprotect the sheet.
+    pub fn has_password(&self) -> bool {
+        return self.hash_value.is_some() || self.legacy_password.is_some();
+    }
+
+    /// Names of the operations locked while the sheet is protected, in the order of the schema, ie: `formatCells`.
+    ///
+    /// Empty if the sheet is not protected.
+    pub fn locked_operations(&self) -> Vec<&'static str> {
+        if !self.sheet {
+            return vec![];
+        }
+        let operations = [
+            ("objects", self.objects),
+            ("scenarios", self.scenarios),
+            ("formatCells", self.format_cells),
+            ("formatColumns", self.format_columns),
+            ("formatRows", self.format_rows),
+            ("insertColumns", self.insert_columns),
+            ("insertRows", self.insert_rows),
+            ("insertHyperlinks", self.insert_hyperlinks),
+            ("deleteColumns", self.delete_columns),
+            ("deleteRows", self.delete_rows),
+            ("selectLockedCells", self.select_locked_cells),
+            ("sort", self.sort),
+            ("autoFilter", self.auto_filter),
+            ("pivotTables", self.pivot_tables),
+            ("selectUnlockedCells", self.select_unlocked_cells),
+        ];
+        return operations
+            .into_iter()
+            .filter(|(_, locked)| *locked)
+            .map(|(name, _)| name)
+            .collect();
+    }
+}
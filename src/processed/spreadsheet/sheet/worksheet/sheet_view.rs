@@ -0,0 +1,122 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    common_types::Coordinate, raw::spreadsheet::sheet::worksheet::sheet_view::XlsxSheetView,
+};
+
+/// Zoom of Excel when a sheet view does not specify any, in percent.
+static DEFAULT_ZOOM_SCALE: u64 = 100;
+
+/// Display settings of a worksheet, from its first `sheetView` element, see `Worksheet::sheet_view`.
+///
+/// Unspecified settings hold the defaults of Excel.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SheetView {
+    /// Column A on the right, columns increasing towards the left, ie: for Arabic and Hebrew workbooks.
+    ///
+    /// See `visual_column_order` and `visual_x` to lay out columns the way they are displayed.
+    pub right_to_left: bool,
+
+    pub show_grid_lines: bool,
+
+    /// Display the row and column headings.
+    pub show_headings: bool,
+
+    /// Display cells holding zero as `0` instead of blank.
+    pub show_zeros: bool,
+
+    /// Display formulas instead of their calculated values.
+    pub show_formulas: bool,
+
+    pub show_outline_symbols: bool,
+
+    /// Whether the tab of the sheet is selected.
+    pub tab_selected: bool,
+
+    /// First visible cell of the view: the top left cell, or the top right cell in right to left mode.
+    pub top_left_cell: Option<Coordinate>,
+
+    pub view: SheetViewType,
+
+    /// Zoom of the view in percent.
+    pub zoom_scale: u64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum SheetViewType {
+    Normal,
+    PageBreakPreview,
+    PageLayout,
+}
+
+impl SheetView {
+    pub(crate) fn from_raw(sheet_view: Option<&XlsxSheetView>) -> Self {
+        let Some(sheet_view) = sheet_view else {
+            return Self {
+                right_to_left: false,
+                show_grid_lines: true,
+                show_headings: true,
+                show_zeros: true,
+                show_formulas: false,
+                show_outline_symbols: true,
+                tab_selected: false,
+                top_left_cell: None,
+                view: SheetViewType::Normal,
+                zoom_scale: DEFAULT_ZOOM_SCALE,
+            };
+        };
+
+        return Self {
+            right_to_left: sheet_view.right_to_left.unwrap_or(false),
+            show_grid_lines: sheet_view.show_grid_lines.unwrap_or(true),
+            show_headings: sheet_view.show_row_col_headers.unwrap_or(true),
+            show_zeros: sheet_view.show_zeros.unwrap_or(true),
+            show_formulas: sheet_view.show_formulas.unwrap_or(false),
+            show_outline_symbols: sheet_view.show_outline_symbols.unwrap_or(true),
+            tab_selected: sheet_view.tab_selected.unwrap_or(false),
+            top_left_cell: sheet_view
+                .top_left_cell
+                .as_deref()
+                .and_then(|a1| Coordinate::from_a1(a1.as_bytes())),
+            view: match sheet_view.view.as_deref() {
+                Some("pageBreakPreview") => SheetViewType::PageBreakPreview,
+                Some("pageLayout") => SheetViewType::PageLayout,
+                _ => SheetViewType::Normal,
+            },
+            // 0 stands for the default zoom
+            zoom_scale: sheet_view
+                .zoom_scale
+                .filter(|zoom| *zoom > 0)
+                .unwrap_or(DEFAULT_ZOOM_SCALE),
+        };
+    }
+
+    /// Columns from `first` to `last` (1 based, inclusive) in the order they are displayed from left to right.
+    ///
+    /// Reversed in right to left mode, ie: `[3, 2, 1]` for columns 1 to 3.
+    pub fn visual_column_order(&self, first: u64, last: u64) -> Vec<u64> {
+        if self.right_to_left {
+            (first..=last).rev().collect()
+        } else {
+            (first..=last).collect()
+        }
+    }
+
+    /// Distance from the left edge of the displayed area to the left edge of a shape or a cell.
+    ///
+    /// Mirrored in right to left mode, the left edge of column A being on the right of the area.
+    ///
+    /// * x: distance from the edge of column A to the near edge of the shape, ie: `Worksheet::cell_offset`
+    /// * width: width of the shape
+    /// * total_width: width of the displayed area
+    pub fn visual_x(&self, x: f64, width: f64, total_width: f64) -> f64 {
+        if self.right_to_left {
+            total_width - x - width
+        } else {
+            x
+        }
+    }
+}
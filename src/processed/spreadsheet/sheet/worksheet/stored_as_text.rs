@@ -0,0 +1,83 @@
+use chrono::NaiveDate;
+
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+use crate::common_types::{Coordinate, Text};
+
+use super::cell::cell_value::CellValueType;
+
+/// Date formats recognized when checking text for dates.
+static DATE_FORMATS: &[&str] = &["%Y-%m-%d", "%Y/%m/%d", "%m/%d/%Y", "%d.%m.%Y"];
+
+/// A cell typed as text whose content reads as a number or date.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct NumberStoredAsText {
+    pub coordinate: Coordinate,
+
+    /// text as stored
+    pub text: Text,
+
+    /// `Numeric` or `DateTime` value the text converts to
+    pub value: CellValueType,
+
+    /// The `numberStoredAsText` error is ignored for the cell within the worksheet, ie: the text is intended.
+    pub ignored: bool,
+}
+
+/// Convert text that reads as a number or date.
+///
+/// Accepts:
+/// - numbers with optional thousands separators, ie: `1,234.5`
+/// - percentages, ie: `12%` -> 0.12
+/// - dates, ie: `2024-01-31` -> DateTime in ISO 8601
+pub(crate) fn parse_stored_as_text(text: &str) -> Option<CellValueType> {
+    let trimmed = text.trim();
+    if trimmed.is_empty() || !trimmed.chars().any(|c| c.is_ascii_digit()) {
+        return None;
+    }
+
+    let (number, scale) = match trimmed.strip_suffix('%') {
+        Some(number) => (number.trim_end(), 0.01),
+        None => (trimmed, 1.0),
+    };
+    if number
+        .chars()
+        .all(|c| c.is_ascii_digit() || matches!(c, '+' | '-' | '.' | ',' | 'e' | 'E'))
+    {
+        if let Some(n) = parse_number(number) {
+            return Some(CellValueType::Numeric(n * scale));
+        }
+    }
+
+    for format in DATE_FORMATS {
+        if let Ok(date) = NaiveDate::parse_from_str(trimmed, format) {
+            return Some(CellValueType::DateTime(date.format("%Y-%m-%d").to_string()));
+        }
+    }
+
+    None
+}
+
+fn parse_number(number: &str) -> Option<f64> {
+    if !number.contains(',') {
+        return number.parse::<f64>().ok();
+    }
+    // thousands separators must group digits by 3 before the decimal point
+    let (integer, fraction) = match number.split_once('.') {
+        Some((integer, fraction)) => (integer, Some(fraction)),
+        None => (number, None),
+    };
+    let digits = integer.trim_start_matches(['+', '-']);
+    let mut groups = digits.split(',');
+    let first = groups.next()?;
+    if first.is_empty() || first.len() > 3 || groups.any(|g| g.len() != 3) {
+        return None;
+    }
+    let without_separators = match fraction {
+        Some(fraction) => format!("{}.{}", integer.replace(',', ""), fraction),
+        None => integer.replace(',', ""),
+    };
+    without_separators.parse::<f64>().ok()
+}
@@ -1,13 +1,24 @@
 pub mod table_style;
 
+use std::collections::HashMap;
+
 #[cfg(feature = "serde")]
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    common_types::{Coordinate, Dimension},
+    raw::spreadsheet::table::{table_column::XlsxTableColumn, XlsxTable},
+};
 
-use crate::{common_types::Dimension, raw::spreadsheet::table::XlsxTable};
+use super::{
+    auto_filter::{AutoFilter, SortState},
+    cell::cell_value::CellValueType,
+    Worksheet,
+};
 use table_style::TableStyle;
 
 #[derive(Debug, Clone, PartialEq)]
-#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Table {
     /// name of the table.
     ///
@@ -31,8 +42,14 @@ pub struct Table {
     /// the number of `totals rows` that is shown at the bottom of the table
     pub totals_row_count: u64,
 
+    /// Totals row content of the columns having one, in the order of the columns
+    pub totals: Vec<TableColumnTotal>,
+
     /// table style
     pub table_style: TableStyle,
+
+    /// Filters and sort of the table columns, None if the table has no filter buttons
+    pub auto_filter: Option<AutoFilter>,
 }
 
 impl Table {
@@ -51,8 +68,107 @@ impl Table {
             dimension: table.clone().r#ref.unwrap_or(Dimension::default()),
             columns: column_names,
             header_row_count: table.clone().header_row_count.unwrap_or(1),
-            totals_row_count: table.clone().totals_row_count.unwrap_or(1),
+            totals_row_count: table.clone().totals_row_count.unwrap_or(0),
+            totals: table
+                .table_columns
+                .iter()
+                .flatten()
+                .filter_map(TableColumnTotal::from_raw)
+                .collect(),
             table_style: TableStyle::from_raw(table.clone().table_style_info, default_table_style),
+            auto_filter: table
+                .auto_filter
+                .as_ref()
+                .and_then(AutoFilter::from_raw)
+                .map(|mut filter| {
+                    if filter.sort_state.is_none() {
+                        filter.sort_state = table.sort_state.as_ref().map(SortState::from_raw);
+                    }
+                    filter
+                }),
         };
     }
+
+    /// Range of the data rows, without the header and totals rows.
+    ///
+    /// None if the table has no data rows.
+    pub fn data_range(&self) -> Option<Dimension> {
+        let start_row = self.dimension.start.row + self.header_row_count;
+        let end_row = self.dimension.end.row.checked_sub(self.totals_row_count)?;
+        if start_row > end_row {
+            return None;
+        }
+        return Some(Dimension {
+            start: Coordinate::from_point((start_row, self.dimension.start.col)),
+            end: Coordinate::from_point((end_row, self.dimension.end.col)),
+        });
+    }
+
+    /// Values of the data rows, each keyed by column name.
+    ///
+    /// Every column is present in every row, `CellValueType::Empty` for empty cells.
+    ///
+    /// * worksheet: the worksheet the table belongs to, loaded with its cells
+    pub fn rows_as_maps(
+        &self,
+        worksheet: &Worksheet,
+    ) -> anyhow::Result<Vec<HashMap<String, CellValueType>>> {
+        let Some(range) = self.data_range() else {
+            return Ok(vec![]);
+        };
+        let mut values: HashMap<Coordinate, CellValueType> = worksheet
+            .get_range_with_dimension(range)?
+            .into_iter()
+            .map(|cell| (cell.coordinate, cell.value))
+            .collect();
+
+        let rows = (range.start.row..=range.end.row)
+            .map(|row| {
+                self.columns
+                    .iter()
+                    .enumerate()
+                    .map(|(index, name)| {
+                        let coordinate =
+                            Coordinate::from_point((row, range.start.col + index as u64));
+                        (name.clone(), values.remove(&coordinate).unwrap_or_default())
+                    })
+                    .collect()
+            })
+            .collect();
+        return Ok(rows);
+    }
+}
+
+/// Content of a column in the totals row of a table.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TableColumnTotal {
+    /// Column name
+    pub column: String,
+
+    /// Aggregation of the column, ie: `sum`, `average`, `count`, `custom`
+    pub function: Option<String>,
+
+    /// Formula of `custom` totals
+    pub formula: Option<String>,
+
+    /// Text shown instead of an aggregation, ie: `Total`
+    pub label: Option<String>,
+}
+
+impl TableColumnTotal {
+    fn from_raw(column: &XlsxTableColumn) -> Option<Self> {
+        if column.totals_row_function.is_none() && column.totals_row_label.is_none() {
+            return None;
+        }
+        return Some(Self {
+            column: column.name.clone().unwrap_or_default(),
+            function: column.totals_row_function.clone(),
+            formula: column
+                .totals_row_formula
+                .as_ref()
+                .map(|f| f.formula.clone()),
+            label: column.totals_row_label.clone(),
+        });
+    }
 }
@@ -1,10 +1,10 @@
 #[cfg(feature = "serde")]
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 use crate::raw::spreadsheet::table::table_style_info::XlsxTableStyleInfo;
 
 #[derive(Debug, Clone, PartialEq)]
-#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct TableStyle {
     /// A string representing the name of the table style to use with this table.
     ///
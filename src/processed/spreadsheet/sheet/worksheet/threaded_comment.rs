@@ -0,0 +1,144 @@
+use std::collections::HashMap;
+
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+use crate::{
+    common_types::Coordinate,
+    raw::spreadsheet::{
+        person::{XlsxPerson, XlsxPersons},
+        threaded_comment::{XlsxThreadedComment, XlsxThreadedComments},
+    },
+};
+
+/// Author of threaded comments, from the persons part of the workbook.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct Person {
+    /// Id referenced by the comments, ie: `{5B4A6E2D-...}`
+    pub id: String,
+
+    pub display_name: String,
+
+    /// Id in the identity provider, ie: an email address
+    pub user_id: Option<String>,
+
+    /// Identity provider, ie: `AD`, `Windows Live`, `None`
+    pub provider_id: Option<String>,
+}
+
+impl Person {
+    fn from_raw(raw: &XlsxPerson) -> Self {
+        return Self {
+            id: raw.id.clone().unwrap_or_default(),
+            display_name: raw.display_name.clone().unwrap_or_default(),
+            user_id: raw.user_id.clone(),
+            provider_id: raw.provider_id.clone(),
+        };
+    }
+}
+
+/// A comment of a conversation, either the one starting it or a reply.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct ThreadedComment {
+    pub id: String,
+
+    /// None if the author is missing from the persons part
+    pub author: Option<Person>,
+
+    /// Date time the comment was written, as stored, ie: `2024-03-01T09:15:00.00`
+    pub date_time: Option<String>,
+
+    pub text: String,
+}
+
+/// A threaded comment conversation on a cell, as written in recent Excel versions.
+///
+/// Excel also writes a legacy note for each thread, found in `Worksheet::comments` with a text prefixed by `[Threaded comment]`.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct CommentThread {
+    /// Commented cell
+    pub coordinate: Coordinate,
+
+    /// Comment starting the conversation
+    pub comment: ThreadedComment,
+
+    /// Replies, in the order they were written
+    pub replies: Vec<ThreadedComment>,
+
+    /// The conversation is marked as resolved.
+    pub resolved: bool,
+}
+
+impl CommentThread {
+    /// Conversations of a worksheet, in the order of their first comment in the threaded comments part.
+    ///
+    /// Replies are attached to the conversation of their parent.
+    /// A reply whose parent is missing starts its own conversation.
+    pub(crate) fn from_raw(
+        threaded_comments: &XlsxThreadedComments,
+        persons: &XlsxPersons,
+    ) -> Vec<Self> {
+        let persons: HashMap<&str, Person> = persons
+            .persons
+            .iter()
+            .filter_map(|p| Some((p.id.as_deref()?, Person::from_raw(p))))
+            .collect();
+
+        let mut threads: Vec<Self> = vec![];
+        // comment id -> index of its conversation
+        let mut thread_for_id: HashMap<&str, usize> = HashMap::new();
+        for raw in &threaded_comments.comments {
+            let comment = ThreadedComment::from_raw(raw, &persons);
+            let parent = raw
+                .parent_id
+                .as_deref()
+                .and_then(|id| thread_for_id.get(id))
+                .copied();
+
+            let index = match parent {
+                Some(index) => {
+                    threads[index].replies.push(comment);
+                    index
+                }
+                None => {
+                    let Some(coordinate) = raw
+                        .r#ref
+                        .as_ref()
+                        .and_then(|r| Coordinate::from_a1(r.replace('$', "").as_bytes()))
+                    else {
+                        continue;
+                    };
+                    threads.push(Self {
+                        coordinate,
+                        comment,
+                        replies: vec![],
+                        resolved: raw.done.unwrap_or(false),
+                    });
+                    threads.len() - 1
+                }
+            };
+            if let Some(id) = raw.id.as_deref() {
+                thread_for_id.insert(id, index);
+            }
+        }
+        return threads;
+    }
+}
+
+impl ThreadedComment {
+    fn from_raw(raw: &XlsxThreadedComment, persons: &HashMap<&str, Person>) -> Self {
+        return Self {
+            id: raw.id.clone().unwrap_or_default(),
+            author: raw
+                .person_id
+                .as_deref()
+                .and_then(|id| persons.get(id))
+                .cloned(),
+            date_time: raw.date_time.clone(),
+            text: raw.text.clone().unwrap_or_default(),
+        };
+    }
+}
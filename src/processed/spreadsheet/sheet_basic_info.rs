@@ -1,10 +1,10 @@
 use anyhow::bail;
 
 #[cfg(feature = "serde")]
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 use crate::{
-    packaging::relationship::{zip_path_for_id, XlsxRelationships},
+    packaging::relationship::{rel_for_id, zip_path_for_id, XlsxRelationships},
     raw::spreadsheet::workbook::sheet::XlsxSheet,
 };
 
@@ -40,15 +40,31 @@ impl SheetBasicInfo {
             bail!("neccessary properties for sheet are not present.")
         };
         let Some(path) = zip_path_for_id(relationships, &id) else {
-            bail!("Cannot find the xml file for the sheet.")
+            bail!(
+                "Cannot find the xml file for sheet `{}`: relationship `{}` does not exist in xl/_rels/workbook.xml.rels.",
+                name,
+                id
+            )
         };
 
-        let sheet_type = match path.split('/').nth(1) {
-            Some("worksheets") => SheetType::WorkSheet,
-            Some("chartsheets") => SheetType::ChartSheet,
-            Some("dialogsheets") => SheetType::DialogSheet,
-            Some(t) => bail!("Unsupported sheet type: {}", t),
-            None => bail!("sheet type not availalbe."),
+        // relationship type first, the folder of the part is only a naming convention
+        let relationship_type = rel_for_id(relationships, &id).map(|r| r.r#type);
+        let sheet_type = match relationship_type
+            .as_deref()
+            .and_then(|t| t.rsplit('/').next())
+        {
+            Some("worksheet") => SheetType::WorkSheet,
+            Some("chartsheet") => SheetType::ChartSheet,
+            Some("dialogsheet") => SheetType::DialogSheet,
+            Some("xlMacrosheet") | Some("xlIntlMacrosheet") => SheetType::MacroSheet,
+            _ => match path.split('/').nth(1) {
+                Some("worksheets") => SheetType::WorkSheet,
+                Some("chartsheets") => SheetType::ChartSheet,
+                Some("dialogsheets") => SheetType::DialogSheet,
+                Some("macrosheets") => SheetType::MacroSheet,
+                Some(t) => bail!("Unsupported sheet type: {}", t),
+                None => bail!("sheet type not availalbe."),
+            },
         };
 
         let visibility = match sheet.visible_state.unwrap_or("visible".to_owned()).as_ref() {
@@ -78,11 +94,16 @@ pub enum SheetType {
     DialogSheet,
     /// ChartSheet
     ChartSheet,
+    /// Excel 4.0 (XLM) macro sheet, including international macro sheets.
+    ///
+    /// XLM macros run without the VBA project and are commonly abused by malicious files,
+    /// check for this type before trusting a workbook.
+    MacroSheet,
 }
 
 /// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.sheetstatevalues?view=openxml-3.0.1
 #[derive(Debug, Clone, Copy, PartialEq)]
-#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum SheetVisibleState {
     /// Visible
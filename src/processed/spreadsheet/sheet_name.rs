@@ -0,0 +1,79 @@
+use anyhow::bail;
+use unicode_normalization::UnicodeNormalization;
+
+/// Maximum number of characters in a sheet name.
+pub const MAX_SHEET_NAME_LENGTH: usize = 31;
+
+/// Characters that cannot appear in a sheet name.
+pub const FORBIDDEN_SHEET_NAME_CHARACTERS: &[char] = &[':', '\\', '/', '?', '*', '[', ']'];
+
+/// Normalize a sheet name for comparison.
+///
+/// Names are compared by Excel case insensitively, regardless of how the characters are composed.
+/// ie: `Café` (precomposed é) and `CAFE\u{301}` (e followed by a combining accent) are the same sheet.
+pub fn normalize_sheet_name(name: &str) -> String {
+    name.nfc().collect::<String>().to_lowercase()
+}
+
+/// Whether two sheet names refer to the same sheet.
+pub fn sheet_names_equal(a: &str, b: &str) -> bool {
+    normalize_sheet_name(a) == normalize_sheet_name(b)
+}
+
+/// Check that a name is a valid sheet name.
+///
+/// A sheet name
+/// - is not empty and contains at most 31 characters,
+/// - does not contain `: \ / ? * [ ]`,
+/// - does not start or end with an apostrophe,
+/// - is not `History`, which is reserved by Excel.
+pub fn validate_sheet_name(name: &str) -> anyhow::Result<()> {
+    let length = name.nfc().count();
+    if length == 0 {
+        bail!("Sheet name is empty.")
+    }
+    if length > MAX_SHEET_NAME_LENGTH {
+        bail!(
+            "Sheet name `{}` is longer than {} characters.",
+            name,
+            MAX_SHEET_NAME_LENGTH
+        )
+    }
+    if let Some(c) = name.chars().find(|c| FORBIDDEN_SHEET_NAME_CHARACTERS.contains(c)) {
+        bail!("Sheet name `{}` contains forbidden character `{}`.", name, c)
+    }
+    if name.starts_with('\'') || name.ends_with('\'') {
+        bail!("Sheet name `{}` starts or ends with an apostrophe.", name)
+    }
+    if sheet_names_equal(name, "History") {
+        bail!("Sheet name `History` is reserved.")
+    }
+    Ok(())
+}
+
+/// Turn any text into a valid sheet name.
+///
+/// Forbidden characters are replaced with `_`, surrounding apostrophes removed and the result truncated to 31 characters.
+/// Empty or reserved names become `Sheet`.
+pub fn sanitize_sheet_name(name: &str) -> String {
+    let replaced: String = name
+        .nfc()
+        .map(|c| {
+            if FORBIDDEN_SHEET_NAME_CHARACTERS.contains(&c) {
+                '_'
+            } else {
+                c
+            }
+        })
+        .collect();
+    let truncated: String = replaced
+        .trim_matches('\'')
+        .chars()
+        .take(MAX_SHEET_NAME_LENGTH)
+        .collect();
+    let sanitized = truncated.trim_end_matches('\'').to_string();
+    if sanitized.is_empty() || sheet_names_equal(&sanitized, "History") {
+        return "Sheet".to_string();
+    }
+    sanitized
+}
@@ -0,0 +1,494 @@
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+use crate::common_types::{Coordinate, Dimension};
+
+use super::sheet_name::sheet_names_equal;
+
+/// A formula, data validation or defined name referring to a sheet that is to be renamed.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct SheetNameReference {
+    pub location: SheetReferenceLocation,
+
+    /// Sheet names referenced by the text that are to be renamed, as written in the text.
+    pub referenced_sheets: Vec<String>,
+
+    /// Formula or reference text as stored.
+    pub text: String,
+
+    /// Text after renaming the sheets.
+    pub renamed_text: String,
+}
+
+impl SheetNameReference {
+    /// None if the text does not refer to any renamed sheet.
+    pub(crate) fn find(
+        location: SheetReferenceLocation,
+        text: &str,
+        renames: &[(String, String)],
+    ) -> Option<Self> {
+        let (referenced_sheets, renamed_text) = rename_sheet_references(text, renames)?;
+        Some(Self {
+            location,
+            referenced_sheets,
+            text: text.to_string(),
+            renamed_text,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum SheetReferenceLocation {
+    /// Formula of a cell
+    Cell {
+        sheet_name: String,
+        coordinate: Coordinate,
+    },
+    /// `formula1` or `formula2` of a data validation
+    DataValidation { sheet_name: String, sqref: String },
+    /// Value of a defined name
+    DefinedName { name: String },
+}
+
+/// Find sheet references (`Sheet1!A1`, `'My Sheet'!A1`) in a formula and rename them.
+///
+/// renames: (old name, new name), old names are compared the way Excel compares sheet names.
+///
+/// Returns the referenced old names and the renamed text, None if no renamed sheet is referenced.
+/// String literals within the formula are left untouched.
+pub(crate) fn rename_sheet_references(
+    formula: &str,
+    renames: &[(String, String)],
+) -> Option<(Vec<String>, String)> {
+    let chars: Vec<char> = formula.chars().collect();
+    let mut referenced: Vec<String> = vec![];
+    let mut renamed = String::new();
+    let mut index = 0;
+
+    while index < chars.len() {
+        let c = chars[index];
+
+        // string literal
+        if c == '"' {
+            let end = find_closing(&chars, index, '"');
+            renamed.extend(&chars[index..end]);
+            index = end;
+            continue;
+        }
+
+        // quoted sheet name
+        if c == '\'' {
+            let end = find_closing(&chars, index, '\'');
+            if chars.get(end) == Some(&'!') {
+                let quoted: String = chars[index + 1..end - 1].iter().collect();
+                let name = quoted.replace("''", "'");
+                renamed.push_str(&rename_one(&name, renames, &mut referenced, &chars[index..end]));
+                index = end;
+                continue;
+            }
+            renamed.extend(&chars[index..end]);
+            index = end;
+            continue;
+        }
+
+        // unquoted sheet name
+        if is_name_character(c) && (index == 0 || !is_name_character(chars[index - 1])) {
+            let mut end = index;
+            while end < chars.len() && is_name_character(chars[end]) {
+                end += 1;
+            }
+            if chars.get(end) == Some(&'!') {
+                let name: String = chars[index..end].iter().collect();
+                renamed.push_str(&rename_one(&name, renames, &mut referenced, &chars[index..end]));
+            } else {
+                renamed.extend(&chars[index..end]);
+            }
+            index = end;
+            continue;
+        }
+
+        renamed.push(c);
+        index += 1;
+    }
+
+    if referenced.is_empty() {
+        return None;
+    }
+    Some((referenced, renamed))
+}
+
+/// Index after the closing delimiter, doubled delimiters are escapes.
+fn find_closing(chars: &[char], start: usize, delimiter: char) -> usize {
+    let mut index = start + 1;
+    while index < chars.len() {
+        if chars[index] == delimiter {
+            if chars.get(index + 1) == Some(&delimiter) {
+                index += 2;
+                continue;
+            }
+            return index + 1;
+        }
+        index += 1;
+    }
+    chars.len()
+}
+
+fn is_name_character(c: char) -> bool {
+    c.is_alphanumeric() || c == '_' || c == '.'
+}
+
+fn rename_one(
+    name: &str,
+    renames: &[(String, String)],
+    referenced: &mut Vec<String>,
+    original: &[char],
+) -> String {
+    let Some((_, new_name)) = renames.iter().find(|(old, _)| sheet_names_equal(old, name)) else {
+        return original.iter().collect();
+    };
+    if !referenced.contains(&name.to_string()) {
+        referenced.push(name.to_string());
+    }
+    quote_sheet_name(new_name)
+}
+
+/// Quote a sheet name for use in a formula when needed, ie: `My Sheet` -> `'My Sheet'`.
+///
+/// As Excel does, names are quoted if they start with a digit, hold other characters than letters, digits and `_`,
+/// or read as a cell reference, ie: `A1` or `R1C1`. Quotes within the name are doubled: `Bob's` -> `'Bob''s'`.
+pub fn quote_sheet_name(name: &str) -> String {
+    let needs_quotes = name.is_empty()
+        || name.starts_with(|c: char| c.is_ascii_digit())
+        || !name.chars().all(|c| c.is_alphanumeric() || c == '_')
+        || Coordinate::parse_a1(&name.to_ascii_uppercase()).is_ok()
+        || is_r1c1_like(name);
+    if needs_quotes {
+        format!("'{}'", name.replace('\'', "''"))
+    } else {
+        name.to_string()
+    }
+}
+
+/// Whether a name reads as a R1C1 style reference, ie: `R`, `C2`, `RC` or `R1C1`.
+fn is_r1c1_like(name: &str) -> bool {
+    let name = name.to_ascii_uppercase();
+    let is_digits = |text: &str| text.chars().all(|c| c.is_ascii_digit());
+    let is_row = |text: &str| text.strip_prefix('R').is_some_and(is_digits);
+    return match name.split_once('C') {
+        Some((row, col)) => (row.is_empty() || is_row(row)) && is_digits(col),
+        None => is_row(&name),
+    };
+}
+
+/// A reference found in a formula by `formula_references`.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum FormulaReference {
+    /// Sheet of a sheet qualified reference, ie: `Sheet1` in `Sheet1!A1`
+    Sheet(String),
+    /// Defined name, or table of a structured reference, ie: `Table1` in `Table1[Column]`
+    Name(String),
+    /// `#REF!`, a reference to deleted cells or sheets
+    Invalid,
+}
+
+/// Sheets, names and invalid references within a formula.
+///
+/// References to other workbooks (`[1]Sheet1!A1`), functions, cell references and string literals are skipped.
+pub(crate) fn formula_references(formula: &str) -> Vec<FormulaReference> {
+    let chars: Vec<char> = formula.chars().collect();
+    let mut references: Vec<FormulaReference> = vec![];
+    let mut index = 0;
+
+    while index < chars.len() {
+        let c = chars[index];
+
+        // string literal
+        if c == '"' {
+            index = find_closing(&chars, index, '"');
+            continue;
+        }
+
+        // quoted sheet name, or sheets of a 3D reference: 'Sheet1:Sheet3'!A1
+        if c == '\'' {
+            let end = find_closing(&chars, index, '\'');
+            if chars.get(end) == Some(&'!') {
+                let quoted: String = chars[index + 1..end - 1].iter().collect();
+                let name = quoted.replace("''", "'");
+                if !name.starts_with('[') {
+                    references.extend(name.split(':').map(|n| FormulaReference::Sheet(n.to_string())));
+                }
+            }
+            index = end;
+            continue;
+        }
+
+        // external workbook index or structured reference specifiers
+        if c == '[' {
+            index = find_closing_bracket(&chars, index);
+            continue;
+        }
+
+        if c == '#' {
+            let error: String = chars[index..].iter().take(5).collect();
+            if error.eq_ignore_ascii_case("#REF!") {
+                references.push(FormulaReference::Invalid);
+            }
+            index += 1;
+            while index < chars.len() && (chars[index].is_alphanumeric() || matches!(chars[index], '/' | '!' | '?')) {
+                index += 1;
+            }
+            continue;
+        }
+
+        // numbers, ie: 1.5E+3
+        if c.is_ascii_digit() || (c == '.' && chars.get(index + 1).is_some_and(|c| c.is_ascii_digit())) {
+            index += 1;
+            while index < chars.len() {
+                let exponent_sign = matches!(chars[index], '+' | '-') && matches!(chars[index - 1], 'e' | 'E');
+                if !(chars[index].is_ascii_alphanumeric() || chars[index] == '.' || exponent_sign) {
+                    break;
+                }
+                index += 1;
+            }
+            continue;
+        }
+
+        if is_name_character(c) || c == '$' || c == '\\' {
+            let start = index;
+            while index < chars.len() && (is_name_character(chars[index]) || matches!(chars[index], '$' | '\\')) {
+                index += 1;
+            }
+            let token: String = chars[start..index].iter().collect();
+            let previous = chars[..start].iter().rev().find(|c| !c.is_whitespace());
+            let next = chars.get(index);
+
+            // cell reference or sheet level name after a sheet
+            if previous == Some(&'!') {
+                continue;
+            }
+            if next == Some(&'!') {
+                // sheet of another workbook: [1]Sheet1!A1
+                if previous != Some(&']') {
+                    references.push(FormulaReference::Sheet(token));
+                }
+                continue;
+            }
+            // first sheet of an unquoted 3D reference: Sheet1:Sheet3!A1
+            if next == Some(&':') {
+                let mut end = index + 1;
+                while end < chars.len() && is_name_character(chars[end]) {
+                    end += 1;
+                }
+                if end > index + 1 && chars.get(end) == Some(&'!') {
+                    references.push(FormulaReference::Sheet(token));
+                    continue;
+                }
+            }
+            if next == Some(&'(') || !is_defined_name(&token, previous == Some(&':') || next == Some(&':')) {
+                continue;
+            }
+            references.push(FormulaReference::Name(token));
+            continue;
+        }
+
+        index += 1;
+    }
+
+    references
+}
+
+/// Index after the closing bracket, nested brackets included.
+fn find_closing_bracket(chars: &[char], start: usize) -> usize {
+    let mut depth = 0;
+    let mut index = start;
+    while index < chars.len() {
+        match chars[index] {
+            // escaped bracket within a structured reference column name
+            '\'' => index += 1,
+            '[' => depth += 1,
+            ']' => {
+                depth -= 1;
+                if depth == 0 {
+                    return index + 1;
+                }
+            }
+            _ => (),
+        }
+        index += 1;
+    }
+    chars.len()
+}
+
+/// Whether a token can be a defined name rather than a cell, row or column reference or a boolean.
+///
+/// in_range: the token is next to a `:`, ie: the column `A` in `A:C`
+fn is_defined_name(token: &str, in_range: bool) -> bool {
+    if token.contains('$') || token.eq_ignore_ascii_case("TRUE") || token.eq_ignore_ascii_case("FALSE") {
+        return false;
+    }
+    let letters = token.chars().take_while(|c| c.is_ascii_alphabetic()).count();
+    let digits = &token[letters..];
+    let is_cell = (1..=3).contains(&letters) && !digits.is_empty() && digits.chars().all(|c| c.is_ascii_digit());
+    let is_column = in_range && (1..=3).contains(&letters) && digits.is_empty();
+    let is_r1c1 = token
+        .to_ascii_uppercase()
+        .strip_prefix('R')
+        .map(|rest| rest.trim_start_matches(|c: char| c.is_ascii_digit()).to_string())
+        .and_then(|rest| rest.strip_prefix('C').map(|columns| columns.chars().all(|c| c.is_ascii_digit())))
+        .unwrap_or(false);
+    !(is_cell || is_column || is_r1c1)
+}
+
+/// A reference read by a formula, found by `formula_precedents`.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum FormulaPrecedent {
+    /// Cell or range, ie: `B2`, `Sheet1!$A$1:$C$3`, `A:A`, `'Sheet1:Sheet3'!B2`
+    ///
+    /// sheets: first and last sheet of the reference, None for the sheet of the formula
+    Range {
+        sheets: Option<(String, String)>,
+        range: Dimension,
+    },
+    /// Defined name, or table of a structured reference, ie: `Sales`, `Sheet1!Local`, `Table1` in `Table1[Column]`
+    ///
+    /// sheet: sheet qualifying the name, None if unqualified
+    Name { sheet: Option<String>, name: String },
+}
+
+/// Ranges and names read by a formula, with the sheets qualifying them.
+///
+/// References to other workbooks (`[1]Sheet1!A1`), functions, string literals, `#REF!`
+/// and structured references without a table name (`[@Price]`) are skipped.
+pub(crate) fn formula_precedents(formula: &str) -> Vec<FormulaPrecedent> {
+    let chars: Vec<char> = formula.chars().collect();
+    let mut precedents: Vec<FormulaPrecedent> = vec![];
+    // sheets and workbook qualifying the next reference
+    let mut sheets: Option<(String, String)> = None;
+    let mut external = false;
+    let mut index = 0;
+
+    let is_token_character = |c: char| is_name_character(c) || matches!(c, '$' | '\\');
+    let token_end = |start: usize| {
+        let mut end = start;
+        while end < chars.len() && is_token_character(chars[end]) {
+            end += 1;
+        }
+        end
+    };
+
+    while index < chars.len() {
+        let c = chars[index];
+
+        // string literal
+        if c == '"' {
+            index = find_closing(&chars, index, '"');
+            continue;
+        }
+
+        // quoted sheet name, or sheets of a 3D reference: 'Sheet1:Sheet3'!A1
+        if c == '\'' {
+            let end = find_closing(&chars, index, '\'');
+            if chars.get(end) == Some(&'!') {
+                let quoted: String = chars[index + 1..end - 1].iter().collect();
+                let name = quoted.replace("''", "'");
+                external = name.starts_with('[');
+                let (first, last) = name.split_once(':').unwrap_or((&name, &name));
+                sheets = Some((first.to_string(), last.to_string()));
+                index = end + 1;
+                continue;
+            }
+            index = end;
+            continue;
+        }
+
+        // external workbook index: [1]Sheet1!A1, [1]!Name
+        // structured reference specifiers following a table name are skipped the same way
+        if c == '[' {
+            let end = find_closing_bracket(&chars, index);
+            let follows_name = index > 0 && is_name_character(chars[index - 1]);
+            if !follows_name && chars.get(end).is_some_and(|c| is_token_character(*c) || matches!(c, '\'' | '!')) {
+                external = true;
+                if chars.get(end) == Some(&'!') {
+                    index = end + 1;
+                    continue;
+                }
+            }
+            index = end;
+            continue;
+        }
+
+        if c == '#' {
+            index += 1;
+            while index < chars.len() && (chars[index].is_alphanumeric() || matches!(chars[index], '/' | '!' | '?')) {
+                index += 1;
+            }
+            sheets = None;
+            external = false;
+            continue;
+        }
+
+        if !is_token_character(c) {
+            index += 1;
+            continue;
+        }
+
+        let start = index;
+        let end = token_end(start);
+        let token: String = chars[start..end].iter().collect();
+        index = end;
+
+        // sheet: Sheet1!A1
+        if chars.get(end) == Some(&'!') {
+            sheets = Some((token.clone(), token));
+            index = end + 1;
+            continue;
+        }
+
+        if chars.get(end) == Some(&':') {
+            let second_end = token_end(end + 1);
+            let second: String = chars[end + 1..second_end].iter().collect();
+            // sheets of an unquoted 3D reference: Sheet1:Sheet3!A1
+            if !second.is_empty() && chars.get(second_end) == Some(&'!') {
+                sheets = Some((token, second));
+                index = second_end + 1;
+                continue;
+            }
+            // A1:B3, A:C, 3:7
+            if let Ok(range) = Dimension::parse_a1(&format!("{}:{}", token, second)) {
+                if !external {
+                    precedents.push(FormulaPrecedent::Range {
+                        sheets: sheets.take(),
+                        range,
+                    });
+                }
+                sheets = None;
+                external = false;
+                index = second_end;
+                continue;
+            }
+        }
+
+        let reference = sheets.take();
+        let is_external = std::mem::take(&mut external);
+        // functions and numbers
+        if is_external || chars.get(end) == Some(&'(') || token.starts_with(|c: char| c.is_ascii_digit() || c == '.') {
+            continue;
+        }
+        if let Ok(range) = Dimension::parse_a1(&token) {
+            precedents.push(FormulaPrecedent::Range {
+                sheets: reference,
+                range,
+            });
+        } else if is_defined_name(&token, false) {
+            precedents.push(FormulaPrecedent::Name {
+                sheet: reference.map(|(first, _)| first),
+                name: token,
+            });
+        }
+    }
+
+    precedents
+}
@@ -0,0 +1,148 @@
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+use crate::raw::spreadsheet::{
+    pivot_cache_definition::XlsxPivotCacheDefinition, slicer_cache::XlsxSlicerCacheDefinition,
+    timeline_cache::XlsxTimelineCacheDefinition,
+};
+
+/// Items of a slicer and the selection the workbook was saved with.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct SlicerState {
+    /// Name of the slicer cache, ie: `Slicer_Region`.
+    pub name: Option<String>,
+
+    /// Field or column filtered by the slicer.
+    pub source_name: Option<String>,
+
+    /// Items in the order of the cache.
+    ///
+    /// Empty for slicers on tables, whose selection is stored in the auto filter of the table.
+    pub items: Vec<SlicerItem>,
+}
+
+impl SlicerState {
+    /// `pivot_caches`: all pivot cache definitions of the workbook, used to resolve item values.
+    pub(crate) fn from_raw(
+        raw: XlsxSlicerCacheDefinition,
+        pivot_caches: &[XlsxPivotCacheDefinition],
+    ) -> Self {
+        let mut items: Vec<SlicerItem> = vec![];
+
+        if let Some(tabular) = raw.tabular {
+            let shared_items = pivot_caches
+                .iter()
+                .find(|c| c.pivot_cache_id.is_some() && c.pivot_cache_id == tabular.pivot_cache_id)
+                .and_then(|c| {
+                    c.cache_fields
+                        .iter()
+                        .find(|f| f.name.is_some() && f.name == raw.source_name)
+                })
+                .map(|f| f.shared_items.clone())
+                .unwrap_or_default();
+
+            for item in tabular.items {
+                let value = match item.x {
+                    Some(x) => match shared_items.get(x as usize) {
+                        Some(Some(value)) => value.to_string(),
+                        // missing value in the source
+                        Some(None) => String::new(),
+                        // unresolved, fall back to the index
+                        None => x.to_string(),
+                    },
+                    None => String::new(),
+                };
+                items.push(SlicerItem {
+                    value,
+                    selected: item.selected.unwrap_or(false),
+                    has_data: !item.no_data.unwrap_or(false),
+                });
+            }
+        }
+
+        for item in raw.olap_items {
+            items.push(SlicerItem {
+                value: item.caption.or(item.name).unwrap_or_default(),
+                selected: item.selected.unwrap_or(false),
+                has_data: !item.no_data.unwrap_or(false),
+            });
+        }
+
+        Self {
+            name: raw.name,
+            source_name: raw.source_name,
+            items,
+        }
+    }
+
+    /// Values of the selected items.
+    pub fn selected_items(&self) -> Vec<&str> {
+        self.items
+            .iter()
+            .filter(|i| i.selected)
+            .map(|i| i.value.as_str())
+            .collect()
+    }
+
+    /// Whether the slicer filters anything, ie: some items are not selected.
+    pub fn is_filtered(&self) -> bool {
+        self.items.iter().any(|i| !i.selected)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct SlicerItem {
+    /// Value of the item as stored in the pivot cache, or the caption for OLAP slicers.
+    pub value: String,
+
+    pub selected: bool,
+
+    /// false if no data is left for the item after applying the other filters.
+    pub has_data: bool,
+}
+
+/// Date range selected in a timeline when the workbook was saved.
+///
+/// Dates are in ISO 8601, as stored.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct TimelineState {
+    /// Name of the timeline cache, ie: `NativeTimeline_Date`.
+    pub name: Option<String>,
+
+    /// Date field filtered by the timeline.
+    pub source_name: Option<String>,
+
+    /// ie: `dateBetween`. None or `none` if nothing is selected.
+    pub filter_type: Option<String>,
+
+    pub selection_start: Option<String>,
+    pub selection_end: Option<String>,
+
+    /// Range covered by the timeline.
+    pub bounds_start: Option<String>,
+    pub bounds_end: Option<String>,
+}
+
+impl TimelineState {
+    pub(crate) fn from_raw(raw: XlsxTimelineCacheDefinition) -> Self {
+        let selection = raw.selection.unwrap_or_default();
+        let bounds = raw.bounds.unwrap_or_default();
+        Self {
+            name: raw.name,
+            source_name: raw.source_name,
+            filter_type: raw.filter_type,
+            selection_start: selection.start_date,
+            selection_end: selection.end_date,
+            bounds_start: bounds.start_date,
+            bounds_end: bounds.end_date,
+        }
+    }
+
+    /// Whether a date range is selected.
+    pub fn is_filtered(&self) -> bool {
+        self.selection_start.is_some() && !matches!(self.filter_type.as_deref(), None | Some("none"))
+    }
+}
@@ -0,0 +1,85 @@
+use std::collections::BTreeMap;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::raw::drawing::{
+    scheme::font_scheme::XlsxFontScheme,
+    text::font::{base_font::XlsxBaseFont, XlsxFontBase},
+};
+
+/// Font scheme of the theme, see `Excel::get_theme_fonts`.
+///
+/// Fonts of the stylesheet with a `scheme` of `major` or `minor` follow the theme: changing the theme changes these fonts.
+/// The default font of a workbook is usually the minor font.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ThemeFonts {
+    /// Name of the font scheme, ie: `Office`.
+    pub name: Option<String>,
+
+    /// Font of the headings.
+    pub major: ThemeFont,
+
+    /// Font of the body text and cells.
+    pub minor: ThemeFont,
+}
+
+/// Typefaces of a major or minor theme font.
+///
+/// An empty typeface means the font of the application is used for the script.
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ThemeFont {
+    /// Typeface for latin text, ie: `Calibri`.
+    pub latin: Option<String>,
+
+    pub east_asian: Option<String>,
+
+    pub complex_script: Option<String>,
+
+    /// Typefaces for specific scripts, keyed by script tag, ie: `Jpan`, `Hang`, `Arab`.
+    pub scripts: BTreeMap<String, String>,
+}
+
+impl ThemeFonts {
+    pub(crate) fn from_raw(raw: &XlsxFontScheme) -> Self {
+        return Self {
+            name: raw.name.clone(),
+            major: ThemeFont::from_raw(raw.major_font.as_ref()),
+            minor: ThemeFont::from_raw(raw.minor_font.as_ref()),
+        };
+    }
+}
+
+impl ThemeFont {
+    pub(crate) fn from_raw(raw: Option<&XlsxFontBase>) -> Self {
+        let Some(raw) = raw else {
+            return Self::default();
+        };
+        let typeface = |font: Option<&XlsxBaseFont>| font.and_then(|f| f.typeface.clone());
+
+        let mut scripts: BTreeMap<String, String> = BTreeMap::new();
+        for font in raw.font.as_deref().unwrap_or(&[]) {
+            let (Some(script), Some(typeface)) = (&font.script, &font.typeface) else {
+                continue;
+            };
+            scripts.insert(script.clone(), typeface.clone());
+        }
+
+        return Self {
+            latin: typeface(raw.latin.as_ref()),
+            east_asian: typeface(raw.ea.as_ref()),
+            complex_script: typeface(raw.cs.as_ref()),
+            scripts,
+        };
+    }
+
+    /// Typeface for a script tag, ie: `Jpan`, falling back to the latin typeface.
+    pub fn typeface_for_script(&self, script: &str) -> Option<&str> {
+        match self.scripts.get(script).filter(|t| !t.is_empty()) {
+            Some(typeface) => Some(typeface),
+            None => self.latin.as_deref().filter(|t| !t.is_empty()),
+        }
+    }
+}
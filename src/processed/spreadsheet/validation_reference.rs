@@ -0,0 +1,118 @@
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+use super::{
+    sheet_name::sheet_names_equal,
+    sheet_reference::{formula_references, FormulaReference},
+};
+
+/// A data validation formula referring to something that does not exist, ie: the source of a dropdown list that was deleted or renamed.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct ValidationReferenceIssue {
+    /// Sheet of the data validation
+    pub sheet_name: String,
+
+    /// Cells the data validation applies to
+    pub sqref: String,
+
+    /// `formula1` or `formula2` as stored
+    pub formula: String,
+
+    pub problem: ValidationReferenceProblem,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum ValidationReferenceProblem {
+    /// The formula refers to a sheet that does not exist.
+    MissingSheet { sheet_name: String },
+
+    /// The formula refers to a name that is neither a defined name visible from the sheet nor a table.
+    MissingName { name: String },
+
+    /// The formula refers to a defined name whose value refers to deleted cells (`#REF!`) or to a sheet that does not exist.
+    BrokenName { name: String },
+
+    /// The formula refers to deleted cells: `#REF!`.
+    InvalidReference,
+}
+
+/// What validation formulas can refer to.
+pub(crate) struct ReferenceTargets {
+    pub(crate) sheet_names: Vec<String>,
+    /// (name, index of the sheet for sheet level names, value)
+    pub(crate) defined_names: Vec<(String, Option<usize>, String)>,
+    pub(crate) table_names: Vec<String>,
+}
+
+impl ReferenceTargets {
+    /// Problems of a formula of a data validation on the sheet at `sheet_index`.
+    pub(crate) fn check_formula(
+        &self,
+        formula: &str,
+        sheet_index: usize,
+    ) -> Vec<ValidationReferenceProblem> {
+        let mut problems: Vec<ValidationReferenceProblem> = vec![];
+        for reference in formula_references(formula) {
+            let problem = match reference {
+                FormulaReference::Sheet(sheet_name) => {
+                    if self.has_sheet(&sheet_name) {
+                        continue;
+                    }
+                    ValidationReferenceProblem::MissingSheet { sheet_name }
+                }
+                FormulaReference::Name(name) => {
+                    if self
+                        .table_names
+                        .iter()
+                        .any(|t| t.eq_ignore_ascii_case(&name))
+                    {
+                        continue;
+                    }
+                    match self.defined_name_value(&name, sheet_index) {
+                        Some(value) if self.is_broken(value) => {
+                            ValidationReferenceProblem::BrokenName { name }
+                        }
+                        Some(_) => continue,
+                        None => ValidationReferenceProblem::MissingName { name },
+                    }
+                }
+                FormulaReference::Invalid => ValidationReferenceProblem::InvalidReference,
+            };
+            if !problems.contains(&problem) {
+                problems.push(problem);
+            }
+        }
+        problems
+    }
+
+    fn has_sheet(&self, name: &str) -> bool {
+        self.sheet_names.iter().any(|s| sheet_names_equal(s, name))
+    }
+
+    /// Value of a name visible from a sheet, sheet level names taking precedence over workbook level names.
+    fn defined_name_value(&self, name: &str, sheet_index: usize) -> Option<&str> {
+        let mut candidates = self
+            .defined_names
+            .iter()
+            .filter(|(n, _, _)| n.eq_ignore_ascii_case(name));
+        let local = candidates
+            .clone()
+            .find(|(_, local, _)| *local == Some(sheet_index));
+        let global = candidates.find(|(_, local, _)| local.is_none());
+        local.or(global).map(|(_, _, value)| value.as_str())
+    }
+
+    /// Whether the value of a defined name refers to deleted cells or to a sheet that does not exist.
+    fn is_broken(&self, value: &str) -> bool {
+        formula_references(value)
+            .into_iter()
+            .any(|reference| match reference {
+                FormulaReference::Sheet(sheet_name) => !self.has_sheet(&sheet_name),
+                FormulaReference::Invalid => true,
+                FormulaReference::Name(_) => false,
+            })
+    }
+}
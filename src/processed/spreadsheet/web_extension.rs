@@ -0,0 +1,46 @@
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+use crate::raw::spreadsheet::web_extension::XlsxWebExtension;
+
+/// Office add-in embedded in the workbook, parsed from xl/webextensions/webextension{}.xml
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct WebExtension {
+    /// Identifier of the add-in instance within the workbook.
+    pub id: Option<String>,
+
+    /// Identifier of the add-in within the store, ie: `wa104380862`.
+    pub add_in_id: Option<String>,
+
+    /// Version of the add-in in the store.
+    pub store_version: Option<String>,
+
+    /// Store instance, ie: `en-US` for the Office Store.
+    pub store: Option<String>,
+
+    /// Kind of store, ie: `OMEX` for the Office Store.
+    pub store_type: Option<String>,
+
+    /// Whether the add-in is opened in a task pane (xl/webextensions/taskpanes.xml).
+    pub in_taskpane: bool,
+
+    /// xml path
+    #[cfg_attr(feature = "serde", serde(skip_serializing))]
+    pub(crate) path: String,
+}
+
+impl WebExtension {
+    pub(crate) fn from_raw(raw: XlsxWebExtension, path: String, in_taskpane: bool) -> Self {
+        let reference = raw.reference.unwrap_or_default();
+        Self {
+            id: raw.id,
+            add_in_id: reference.id,
+            store_version: reference.version,
+            store: reference.store,
+            store_type: reference.store_type,
+            in_taskpane,
+            path,
+        }
+    }
+}
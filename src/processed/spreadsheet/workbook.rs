@@ -0,0 +1,186 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::common_types::{Coordinate, Dimension};
+
+use super::{
+    sheet::worksheet::{
+        calculation_reference::CalculationReferenceMode, cell::Cell,
+        data_validation::DataValidation, table::Table, Worksheet,
+    },
+    sheet_basic_info::{SheetBasicInfo, SheetVisibleState},
+    sheet_name::sheet_names_equal,
+};
+
+/// Fully owned snapshot of the processed content of a workbook, see `Excel::to_processed_model`.
+///
+/// Does not depend on the xlsx file once built, and can be serialized to be cached.
+/// Use `from_json` / `from_bincode` to load a cached model, deserializing directly skips restoring the cell order.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Workbook {
+    /// Worksheets, in workbook order.
+    pub sheets: Vec<WorkbookSheet>,
+}
+
+impl Workbook {
+    /// Get sheet by name, compared the way Excel compares sheet names.
+    pub fn get_sheet(&self, name: &str) -> Option<&WorkbookSheet> {
+        self.sheets
+            .iter()
+            .find(|s| sheet_names_equal(&s.name, name))
+    }
+
+    pub fn get_sheet_with_sheet_id(&self, id: u64) -> Option<&WorkbookSheet> {
+        self.sheets.iter().find(|s| s.sheet_id == id)
+    }
+
+    #[cfg(feature = "serde")]
+    pub fn to_json(&self) -> anyhow::Result<String> {
+        Ok(serde_json::to_string(self)?)
+    }
+
+    /// Load a model serialized with `to_json`.
+    #[cfg(feature = "serde")]
+    pub fn from_json(json: &str) -> anyhow::Result<Self> {
+        let mut workbook: Self = serde_json::from_str(json)?;
+        workbook.build_indexes();
+        Ok(workbook)
+    }
+
+    #[cfg(feature = "bincode")]
+    pub fn to_bincode(&self) -> anyhow::Result<Vec<u8>> {
+        Ok(bincode::serialize(self)?)
+    }
+
+    /// Load a model serialized with `to_bincode`.
+    #[cfg(feature = "bincode")]
+    pub fn from_bincode(bytes: &[u8]) -> anyhow::Result<Self> {
+        let mut workbook: Self = bincode::deserialize(bytes)?;
+        workbook.build_indexes();
+        Ok(workbook)
+    }
+
+    /// Restore the cell order lookups rely on, in case the serialized data was edited or produced elsewhere.
+    #[cfg(feature = "serde")]
+    fn build_indexes(&mut self) {
+        for sheet in self.sheets.iter_mut() {
+            sheet.sort_cells();
+        }
+    }
+}
+
+/// Read only data of a processed workbook, see `Excel::into_shared`.
+///
+/// Owns everything it refers to and is `Send + Sync`, so an `Arc<WorkbookData>` can be queried
+/// from several threads at once without cloning the cells or parsing the file again.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WorkbookData {
+    /// All sheets of the workbook, including chart, dialog and macro sheets.
+    pub sheets: Vec<SheetBasicInfo>,
+
+    /// Processed worksheets.
+    pub model: Workbook,
+}
+
+impl WorkbookData {
+    /// Get worksheet by name, compared the way Excel compares sheet names.
+    pub fn get_sheet(&self, name: &str) -> Option<&WorkbookSheet> {
+        self.model.get_sheet(name)
+    }
+
+    /// Get cell of a worksheet at a coordinate, None if the sheet does not exist or the cell has neither a value nor a style.
+    pub fn get_cell(&self, sheet_name: &str, coordinate: Coordinate) -> Option<&Cell> {
+        self.get_sheet(sheet_name)?.get_cell(coordinate)
+    }
+}
+
+const _: fn() = || {
+    fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<WorkbookData>();
+};
+
+/// Processed content of a worksheet.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct WorkbookSheet {
+    pub name: String,
+    pub sheet_id: u64,
+    pub visible_state: SheetVisibleState,
+
+    /// None if the sheet does not contain any data
+    pub dimension: Option<Dimension>,
+
+    pub merged_cells: Vec<Dimension>,
+    pub tables: Vec<Table>,
+    pub data_validations: Option<Vec<DataValidation>>,
+    pub is_1904: bool,
+    pub calculation_reference_mode: CalculationReferenceMode,
+
+    /// Cells with a value or a style, row by row.
+    ///
+    /// Cells within the dimension that are neither are left out.
+    pub cells: Vec<Cell>,
+}
+
+impl WorkbookSheet {
+    pub(crate) fn from_worksheet(sheet: &SheetBasicInfo, worksheet: Worksheet) -> anyhow::Result<Self> {
+        let cells: Vec<Cell> = worksheet
+            .get_cells()?
+            .into_iter()
+            .filter(|c| {
+                *c != Cell {
+                    id: c.id,
+                    ..Cell::default(c.coordinate)
+                }
+            })
+            .collect();
+
+        let mut sheet = Self {
+            name: worksheet.name,
+            sheet_id: worksheet.sheet_id,
+            visible_state: sheet.visible_state,
+            dimension: worksheet.dimension,
+            merged_cells: worksheet.merged_cells,
+            tables: worksheet.tables,
+            data_validations: worksheet.data_validations,
+            is_1904: worksheet.is_1904,
+            calculation_reference_mode: worksheet.calculation_reference_mode,
+            cells,
+        };
+        sheet.sort_cells();
+        Ok(sheet)
+    }
+
+    /// Get cell at a coordinate, None if the cell has neither a value nor a style.
+    pub fn get_cell(&self, coordinate: Coordinate) -> Option<&Cell> {
+        let index = self
+            .cells
+            .binary_search_by_key(&coordinate, |c| c.coordinate)
+            .ok()?;
+        self.cells.get(index)
+    }
+
+    /// Get cells within a range, row by row. Cells with neither a value nor a style are left out.
+    pub fn get_range(&self, range: Dimension) -> Vec<&Cell> {
+        let mut cells: Vec<&Cell> = vec![];
+        let first = self
+            .cells
+            .partition_point(|c| c.coordinate < range.start);
+        for cell in &self.cells[first..] {
+            if cell.coordinate.row > range.end.row {
+                break;
+            }
+            if range.contains(&cell.coordinate) {
+                cells.push(cell);
+            }
+        }
+        cells
+    }
+
+    fn sort_cells(&mut self) {
+        if !self.cells.is_sorted_by_key(|c| c.coordinate) {
+            self.cells.sort_by_key(|c| c.coordinate);
+        }
+    }
+}
@@ -0,0 +1,307 @@
+use std::fmt;
+
+use regex::Regex;
+
+use crate::common_types::Coordinate;
+
+use super::{
+    sheet::worksheet::cell::{cell_value::CellValueType, Cell},
+    sheet_name::sheet_names_equal,
+    workbook::{Workbook, WorkbookSheet},
+};
+
+/// What `Workbook::differences` and `assert_workbook_eq` let through when comparing two workbooks.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Tolerance {
+    /// Largest absolute difference between two numbers considered equal,
+    /// for numeric values and numeric last calculated values of formulas.
+    pub float_epsilon: f64,
+
+    /// Skip properties a writer is free to change without changing the content:
+    /// sheet ids, table ids, sheet dimensions, style indexes, cell data types as written in the file,
+    /// and last calculated values of formulas calling volatile functions (ie: `NOW()`, `RAND()`).
+    pub ignore_volatile: bool,
+
+    /// Skip cell styles, and compare rich text by its text only.
+    pub ignore_styles: bool,
+}
+
+impl Default for Tolerance {
+    /// `1e-9` epsilon, volatile properties ignored, styles compared.
+    fn default() -> Self {
+        Self {
+            float_epsilon: 1e-9,
+            ignore_volatile: true,
+            ignore_styles: false,
+        }
+    }
+}
+
+impl Tolerance {
+    /// Nothing is let through.
+    pub fn exact() -> Self {
+        Self {
+            float_epsilon: 0.0,
+            ignore_volatile: false,
+            ignore_styles: false,
+        }
+    }
+}
+
+/// A difference found between two workbooks.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WorkbookDifference {
+    /// Name of the sheet in the first workbook, None for differences of the workbook itself.
+    pub sheet: Option<String>,
+
+    /// Cell the difference is found in, None for differences of the sheet itself.
+    pub coordinate: Option<Coordinate>,
+
+    /// ie: `value: Numeric(1.0) != Numeric(1.5)`
+    pub description: String,
+}
+
+/// ie: `Sheet1!B2: value: Numeric(1.0) != Numeric(1.5)`
+impl fmt::Display for WorkbookDifference {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        match (&self.sheet, &self.coordinate) {
+            (Some(sheet), Some(coordinate)) => {
+                write!(f, "{}!{}: {}", sheet, coordinate.to_a1(), self.description)
+            }
+            (Some(sheet), None) => write!(f, "{}: {}", sheet, self.description),
+            _ => write!(f, "{}", self.description),
+        }
+    }
+}
+
+impl Workbook {
+    /// Differences between two workbooks, sheets matched by name, cells by coordinate.
+    ///
+    /// A cell missing on one side only makes a difference if the other one has a value,
+    /// so that writers leaving out or adding empty cells do not make a difference.
+    /// Empty cells and cells with an empty string are both blank.
+    pub fn differences(&self, other: &Workbook, tolerance: Tolerance) -> Vec<WorkbookDifference> {
+        let mut differences = Comparison {
+            tolerance,
+            sheet: None,
+            differences: vec![],
+            volatile_regex: Regex::new(
+                r"(?i)\b(NOW|TODAY|RAND|RANDBETWEEN|RANDARRAY|OFFSET|INDIRECT|INFO|CELL)\s*\(",
+            )
+            .unwrap(),
+        };
+
+        let names: Vec<&str> = self.sheets.iter().map(|s| s.name.as_str()).collect();
+        let other_names: Vec<&str> = other.sheets.iter().map(|s| s.name.as_str()).collect();
+        for sheet in self.sheets.iter() {
+            match other.get_sheet(&sheet.name) {
+                Some(other_sheet) => differences.compare_sheets(sheet, other_sheet),
+                None => differences.push(
+                    None,
+                    format!("sheet `{}` missing in the second workbook", sheet.name),
+                ),
+            }
+        }
+        for sheet in other.sheets.iter() {
+            if self.get_sheet(&sheet.name).is_none() {
+                differences.push(
+                    None,
+                    format!("sheet `{}` missing in the first workbook", sheet.name),
+                );
+            }
+        }
+        let same_sheets = names.len() == other_names.len()
+            && names
+                .iter()
+                .all(|n| other_names.iter().any(|o| sheet_names_equal(n, o)));
+        let same_order = names
+            .iter()
+            .zip(other_names.iter())
+            .all(|(n, o)| sheet_names_equal(n, o));
+        if same_sheets && !same_order {
+            differences.push(
+                None,
+                format!("sheet order: {:?} != {:?}", names, other_names),
+            );
+        }
+        differences.differences
+    }
+}
+
+/// Panic listing the differences between two workbooks, if any.
+///
+/// Meant for test suites verifying that an export or a transformation preserves the content of a workbook.
+/// ```no_run
+/// # use excel_reader::prelude::*;
+/// # fn main() -> anyhow::Result<()> {
+/// let expected = Excel::from_path("tests/expected.xlsx")?.to_processed_model()?;
+/// let actual = Excel::from_path("target/exported.xlsx")?.to_processed_model()?;
+/// assert_workbook_eq(&expected, &actual, Tolerance::default());
+/// # Ok(())
+/// # }
+/// ```
+#[track_caller]
+pub fn assert_workbook_eq(a: &Workbook, b: &Workbook, tolerance: Tolerance) {
+    let differences = a.differences(b, tolerance);
+    if differences.is_empty() {
+        return;
+    }
+    let listed: Vec<String> = differences.iter().map(|d| format!("  {}", d)).collect();
+    panic!(
+        "workbooks differ ({} differences):\n{}",
+        differences.len(),
+        listed.join("\n")
+    );
+}
+
+struct Comparison {
+    tolerance: Tolerance,
+    sheet: Option<String>,
+    differences: Vec<WorkbookDifference>,
+    volatile_regex: Regex,
+}
+
+impl Comparison {
+    fn push(&mut self, coordinate: Option<Coordinate>, description: String) {
+        self.differences.push(WorkbookDifference {
+            sheet: self.sheet.clone(),
+            coordinate,
+            description,
+        });
+    }
+
+    fn check<T: PartialEq + fmt::Debug>(
+        &mut self,
+        coordinate: Option<Coordinate>,
+        property: &str,
+        a: &T,
+        b: &T,
+    ) {
+        if a != b {
+            self.push(coordinate, format!("{}: {:?} != {:?}", property, a, b));
+        }
+    }
+
+    fn compare_sheets(&mut self, a: &WorkbookSheet, b: &WorkbookSheet) {
+        self.sheet = Some(a.name.clone());
+        self.check(None, "name", &a.name, &b.name);
+        if !self.tolerance.ignore_volatile {
+            self.check(None, "sheet_id", &a.sheet_id, &b.sheet_id);
+            self.check(None, "dimension", &a.dimension, &b.dimension);
+        }
+        self.check(None, "visible_state", &a.visible_state, &b.visible_state);
+        self.check(None, "is_1904", &a.is_1904, &b.is_1904);
+        self.check(
+            None,
+            "calculation_reference_mode",
+            &a.calculation_reference_mode,
+            &b.calculation_reference_mode,
+        );
+
+        let mut merged_cells = a.merged_cells.clone();
+        let mut other_merged_cells = b.merged_cells.clone();
+        merged_cells.sort();
+        other_merged_cells.sort();
+        self.check(None, "merged_cells", &merged_cells, &other_merged_cells);
+
+        let mut tables = a.tables.clone();
+        let mut other_tables = b.tables.clone();
+        if self.tolerance.ignore_volatile {
+            tables.iter_mut().for_each(|t| t.table_id = 0);
+            other_tables.iter_mut().for_each(|t| t.table_id = 0);
+        }
+        self.check(None, "tables", &tables, &other_tables);
+        self.check(
+            None,
+            "data_validations",
+            &a.data_validations,
+            &b.data_validations,
+        );
+
+        let (mut i, mut j) = (0, 0);
+        while i < a.cells.len() || j < b.cells.len() {
+            let left = a.cells.get(i);
+            let right = b.cells.get(j);
+            match (left, right) {
+                (Some(l), Some(r)) if l.coordinate == r.coordinate => {
+                    self.compare_cells(l, r);
+                    i += 1;
+                    j += 1;
+                }
+                (Some(l), r) if r.is_none_or(|r| l.coordinate < r.coordinate) => {
+                    self.compare_missing_cell(l);
+                    i += 1;
+                }
+                (_, Some(r)) => {
+                    self.compare_missing_cell(r);
+                    j += 1;
+                }
+                _ => break,
+            }
+        }
+        self.sheet = None;
+    }
+
+    fn compare_cells(&mut self, a: &Cell, b: &Cell) {
+        let coordinate = Some(a.coordinate);
+        if !self.values_equal(&a.value, &b.value) {
+            self.check(coordinate, "value", &a.value, &b.value);
+        }
+        if !self.tolerance.ignore_styles {
+            self.check(coordinate, "property", &a.property, &b.property);
+        }
+        if !self.tolerance.ignore_volatile {
+            self.check(coordinate, "style_index", &a.style_index, &b.style_index);
+            self.check(coordinate, "raw_type", &a.raw_type, &b.raw_type);
+        }
+    }
+
+    /// Cells left out by one writer are blank, their style depending on the row and column styles only.
+    fn compare_missing_cell(&mut self, cell: &Cell) {
+        if !self.values_equal(&cell.value, &CellValueType::Empty) {
+            self.push(
+                Some(cell.coordinate),
+                format!("value: {:?} missing in one workbook", cell.value),
+            );
+        }
+    }
+
+    fn values_equal(&self, a: &CellValueType, b: &CellValueType) -> bool {
+        match (a, b) {
+            (CellValueType::Numeric(a), CellValueType::Numeric(b)) => self.numbers_equal(*a, *b),
+            (CellValueType::Formula(a), CellValueType::Formula(b)) => {
+                if a.formula != b.formula {
+                    return false;
+                }
+                if self.tolerance.ignore_volatile && self.volatile_regex.is_match(&a.formula) {
+                    return true;
+                }
+                match (&a.last_calculated_value, &b.last_calculated_value) {
+                    (Some(a), Some(b)) => match (a.parse::<f64>(), b.parse::<f64>()) {
+                        (Ok(a), Ok(b)) => self.numbers_equal(a, b),
+                        _ => a == b,
+                    },
+                    (a, b) => a == b,
+                }
+            }
+            (
+                CellValueType::RichText(_) | CellValueType::PlainText(_),
+                CellValueType::RichText(_) | CellValueType::PlainText(_),
+            ) if self.tolerance.ignore_styles => a.to_string() == b.to_string(),
+            (a, b) if is_blank(a) && is_blank(b) => true,
+            (a, b) => a == b,
+        }
+    }
+
+    fn numbers_equal(&self, a: f64, b: f64) -> bool {
+        a == b || (a - b).abs() <= self.tolerance.float_epsilon
+    }
+}
+
+fn is_blank(value: &CellValueType) -> bool {
+    match value {
+        CellValueType::Empty => true,
+        CellValueType::PlainText(_) | CellValueType::RichText(_) => value.to_string().is_empty(),
+        _ => false,
+    }
+}
@@ -0,0 +1,26 @@
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+use crate::packaging::content_type::WorkbookContentType;
+
+use super::{sheet_basic_info::SheetBasicInfo, web_extension::WebExtension};
+
+/// Overview of a workbook, meant for deciding how (or whether) to process a file.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct WorkbookSummary {
+    /// Declared content type of xl/workbook.xml, None if not declared.
+    pub content_type: Option<WorkbookContentType>,
+
+    /// All sheets, in workbook order.
+    pub sheets: Vec<SheetBasicInfo>,
+
+    /// Whether any Excel 4.0 (XLM) macro sheet is present.
+    pub has_macro_sheets: bool,
+
+    /// Embedded Office add-ins.
+    pub web_extensions: Vec<WebExtension>,
+
+    /// Whether a data model (Power Pivot) is present, see `Excel::get_data_model`.
+    pub has_data_model: bool,
+}
@@ -0,0 +1,199 @@
+//! Binary workbook (.xlsb) parts: https://learn.microsoft.com/en-us/openspecs/office_file_formats/ms-xlsb/acc8aa92-1f02-4167-99f5-84f9f676b95a
+//!
+//! The package of a binary workbook is the same as the one of a xlsx file, except that the workbook, shared strings, styles and worksheets
+//! are stored as sequences of records instead of xml.
+//! Those parts are parsed into the same raw structures as their xml counterparts, so that the processed layer works on both formats.
+//!
+//! Only records needed for cell values and cell formats are parsed:
+//! - workbook.bin: sheets and the 1904 date system
+//! - sharedStrings.bin: text of string items
+//! - styles.bin: number formats, fonts and cell formats
+//! - sheet{}.bin: dimension, rows, cells, column information and merged cells
+//!
+//! Formulas are stored as parsed tokens (Rgce) and are not decoded, formula cells hold their last calculated value only.
+
+use anyhow::bail;
+use std::io::{BufReader, ErrorKind, Read, Seek};
+use zip::{read::ZipFile, ZipArchive};
+
+use crate::excel::{get_actual_path, ExcelOptions};
+
+pub mod shared_strings;
+pub mod stylesheet;
+pub mod workbook;
+pub mod worksheet;
+
+pub(crate) const BINARY_WORKBOOK_PATH: &str = "xl/workbook.bin";
+
+/// Records larger than this are treated as corrupted, ie: 16 MB.
+const MAX_RECORD_SIZE: usize = 16 * 1024 * 1024;
+
+/// Whether the package contains a binary workbook part instead of xl/workbook.xml.
+pub(crate) fn is_binary_workbook<RS: Read + Seek>(zip: &mut ZipArchive<RS>) -> bool {
+    return get_actual_path(zip, "xl/workbook.xml").is_none()
+        && get_actual_path(zip, BINARY_WORKBOOK_PATH).is_some();
+}
+
+pub(crate) type ZipRecordReader<'a, RS> = RecordReader<BufReader<ZipFile<'a, RS>>>;
+
+/// Reader over the records of a part, None if the part does not exist.
+pub(crate) fn record_reader<'a, RS: Read + Seek>(
+    zip: &'a mut ZipArchive<RS>,
+    path: &str,
+    options: &ExcelOptions,
+) -> Option<ZipRecordReader<'a, RS>> {
+    let Some(path) = get_actual_path(zip, path) else {
+        return None;
+    };
+    let Ok(file) = zip.by_name(&path) else {
+        return None;
+    };
+    return Some(RecordReader::new(BufReader::with_capacity(
+        options.zip_read_buffer_size,
+        file,
+    )));
+}
+
+/// Reads the records of a part one at a time.
+///
+/// A record is made of
+/// - record type: 1 or 2 bytes, 7 bits per byte, the high bit set when another byte follows.
+/// - record size: 1 to 4 bytes, encoded the same way.
+/// - record data: `size` bytes.
+pub(crate) struct RecordReader<R: Read> {
+    reader: R,
+    buf: Vec<u8>,
+}
+
+impl<R: Read> RecordReader<R> {
+    pub(crate) fn new(reader: R) -> Self {
+        Self {
+            reader,
+            buf: vec![],
+        }
+    }
+
+    /// (record type, record data), None at the end of the part.
+    pub(crate) fn next_record(&mut self) -> anyhow::Result<Option<(u16, RecordData<'_>)>> {
+        let Some(first) = self.read_byte()? else {
+            return Ok(None);
+        };
+        let mut record_type = (first & 0x7F) as u16;
+        if first & 0x80 != 0 {
+            let Some(second) = self.read_byte()? else {
+                bail!("unexpected end of part in record type.")
+            };
+            record_type |= ((second & 0x7F) as u16) << 7;
+        }
+
+        let mut size: usize = 0;
+        for index in 0..4 {
+            let Some(byte) = self.read_byte()? else {
+                bail!("unexpected end of part in record size.")
+            };
+            size |= ((byte & 0x7F) as usize) << (7 * index);
+            if byte & 0x80 == 0 {
+                break;
+            }
+        }
+        if size > MAX_RECORD_SIZE {
+            bail!("record {} of size {} exceeds the maximum size.", record_type, size)
+        }
+
+        self.buf.resize(size, 0);
+        self.reader.read_exact(&mut self.buf)?;
+
+        return Ok(Some((record_type, RecordData::new(&self.buf))));
+    }
+
+    /// None at the end of the part.
+    fn read_byte(&mut self) -> anyhow::Result<Option<u8>> {
+        let mut byte = [0u8; 1];
+        match self.reader.read_exact(&mut byte) {
+            Ok(_) => Ok(Some(byte[0])),
+            Err(error) if error.kind() == ErrorKind::UnexpectedEof => Ok(None),
+            Err(error) => bail!(error.to_string()),
+        }
+    }
+}
+
+/// Little endian fields of a record, read in order.
+pub(crate) struct RecordData<'a> {
+    data: &'a [u8],
+    position: usize,
+}
+
+impl<'a> RecordData<'a> {
+    pub(crate) fn new(data: &'a [u8]) -> Self {
+        Self { data, position: 0 }
+    }
+
+    pub(crate) fn remaining(&self) -> usize {
+        return self.data.len() - self.position;
+    }
+
+    fn take(&mut self, count: usize) -> anyhow::Result<&'a [u8]> {
+        if self.remaining() < count {
+            bail!("unexpected end of record.")
+        }
+        let bytes = &self.data[self.position..self.position + count];
+        self.position += count;
+        return Ok(bytes);
+    }
+
+    pub(crate) fn skip(&mut self, count: usize) -> anyhow::Result<()> {
+        self.take(count)?;
+        return Ok(());
+    }
+
+    pub(crate) fn read_u8(&mut self) -> anyhow::Result<u8> {
+        return Ok(self.take(1)?[0]);
+    }
+
+    pub(crate) fn read_u16(&mut self) -> anyhow::Result<u16> {
+        let bytes = self.take(2)?;
+        return Ok(u16::from_le_bytes([bytes[0], bytes[1]]));
+    }
+
+    pub(crate) fn read_i16(&mut self) -> anyhow::Result<i16> {
+        return Ok(self.read_u16()? as i16);
+    }
+
+    pub(crate) fn read_u32(&mut self) -> anyhow::Result<u32> {
+        let bytes = self.take(4)?;
+        return Ok(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]));
+    }
+
+    pub(crate) fn read_f64(&mut self) -> anyhow::Result<f64> {
+        let mut bytes = [0u8; 8];
+        bytes.copy_from_slice(self.take(8)?);
+        return Ok(f64::from_le_bytes(bytes));
+    }
+
+    /// XLWideString: character count (u32) followed by the UTF-16 characters.
+    pub(crate) fn read_wide_string(&mut self) -> anyhow::Result<String> {
+        let count = self.read_u32()? as usize;
+        return self.read_utf16(count);
+    }
+
+    /// XLNullableWideString: XLWideString, None if the character count is 0xFFFFFFFF.
+    pub(crate) fn read_nullable_wide_string(&mut self) -> anyhow::Result<Option<String>> {
+        let count = self.read_u32()?;
+        if count == u32::MAX {
+            return Ok(None);
+        }
+        return Ok(Some(self.read_utf16(count as usize)?));
+    }
+
+    fn read_utf16(&mut self, count: usize) -> anyhow::Result<String> {
+        let Some(byte_count) = count.checked_mul(2) else {
+            bail!("string length {} out of range.", count)
+        };
+        let bytes = self.take(byte_count)?;
+        let units: Vec<u16> = bytes
+            .chunks_exact(2)
+            .map(|unit| u16::from_le_bytes([unit[0], unit[1]]))
+            .collect();
+        return Ok(String::from_utf16_lossy(&units));
+    }
+}
@@ -0,0 +1,57 @@
+use std::io::{Read, Seek};
+use zip::ZipArchive;
+
+use crate::{
+    excel::ExcelOptions,
+    raw::spreadsheet::{
+        shared_string::{
+            shared_string_item::XlsxSharedStringItem, shared_string_table::XlsxSharedStringTable,
+        },
+        string_item::XlsxStringItem,
+    },
+};
+
+use super::record_reader;
+
+/// BrtSSTItem: a string item
+const BRT_SST_ITEM: u16 = 19;
+/// BrtBeginSst: total and unique count of strings
+const BRT_BEGIN_SST: u16 = 159;
+
+/// Load the shared string table from xl/sharedStrings.bin.
+///
+/// Only the text of each item is parsed, formatting runs and phonetic information are left out.
+pub(crate) fn load_binary_shared_strings<RS: Read + Seek>(
+    zip: &mut ZipArchive<RS>,
+    options: &ExcelOptions,
+) -> anyhow::Result<XlsxSharedStringTable> {
+    let path = "xl/sharedStrings.bin";
+    let mut shared_string = XlsxSharedStringTable::default();
+    let Some(mut reader) = record_reader(zip, path, options) else {
+        return Ok(shared_string);
+    };
+
+    let mut items: Vec<XlsxSharedStringItem> = vec![];
+    while let Some((record_type, mut data)) = reader.next_record()? {
+        match record_type {
+            // cstTotal (4 bytes), cstUnique (4 bytes)
+            BRT_BEGIN_SST => {
+                shared_string.count = Some(data.read_u32()? as u64);
+                shared_string.unique_count = Some(data.read_u32()? as u64);
+                items.reserve(options.capacity_for(shared_string.unique_count));
+            }
+            // RichStr: flags (1 byte), str (XLWideString), followed by formatting runs and phonetic information
+            BRT_SST_ITEM => {
+                data.skip(1)?;
+                items.push(XlsxStringItem {
+                    text: Some(data.read_wide_string()?),
+                    ..Default::default()
+                });
+            }
+            _ => (),
+        }
+    }
+    shared_string.string_item = Some(items);
+
+    return Ok(shared_string);
+}
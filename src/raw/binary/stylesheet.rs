@@ -0,0 +1,283 @@
+use std::io::{Read, Seek};
+use zip::ZipArchive;
+
+use crate::{
+    excel::ExcelOptions,
+    raw::spreadsheet::stylesheet::{
+        color::XlsxColor,
+        font::{XlsxFont, XlsxFonts},
+        format::{
+            alignment::XlsxAlignment, cell_format::XlsxCellFormat, cell_xfs::XlsxCellFormats,
+            numbering_format::{XlsxNumberingFormat, XlsxNumberingFormats},
+            protection::XlsxCellProtection,
+        },
+        XlsxStyleSheet,
+    },
+};
+
+use super::{record_reader, RecordData};
+
+/// BrtFont: a font
+const BRT_FONT: u16 = 43;
+/// BrtFmt: a number format
+const BRT_FMT: u16 = 44;
+/// BrtXF: a cell format, within cellXfs or cellStyleXfs
+const BRT_XF: u16 = 47;
+/// BrtBeginCellXFs / BrtEndCellXFs
+const BRT_BEGIN_CELL_XFS: u16 = 617;
+const BRT_END_CELL_XFS: u16 = 618;
+/// BrtBeginCellStyleXFs / BrtEndCellStyleXFs
+const BRT_BEGIN_CELL_STYLE_XFS: u16 = 626;
+const BRT_END_CELL_STYLE_XFS: u16 = 627;
+
+/// The list a BrtXF record belongs to.
+#[derive(Clone, Copy, PartialEq)]
+enum XfList {
+    None,
+    CellXfs,
+    CellStyleXfs,
+}
+
+/// Load the stylesheet from xl/styles.bin.
+///
+/// Only number formats, fonts and cell formats are parsed.
+/// Fills, borders, named cell styles and differential formats are left out.
+pub(crate) fn load_binary_stylesheet<RS: Read + Seek>(
+    zip: &mut ZipArchive<RS>,
+    options: &ExcelOptions,
+) -> anyhow::Result<XlsxStyleSheet> {
+    let path = "xl/styles.bin";
+    let mut style_sheet = XlsxStyleSheet::default();
+    let Some(mut reader) = record_reader(zip, path, options) else {
+        return Ok(style_sheet);
+    };
+
+    let mut numbering_formats: XlsxNumberingFormats = vec![];
+    let mut fonts: XlsxFonts = vec![];
+    let mut cell_xfs: XlsxCellFormats = vec![];
+    let mut cell_style_xfs: XlsxCellFormats = vec![];
+    let mut xf_list = XfList::None;
+
+    while let Some((record_type, mut data)) = reader.next_record()? {
+        match record_type {
+            // ifmt (2 bytes), stFmtCode (XLWideString)
+            BRT_FMT => {
+                let id = data.read_u16()?;
+                numbering_formats.push(XlsxNumberingFormat {
+                    num_fmt_id: Some(id as u64),
+                    format_code: Some(data.read_wide_string()?),
+                });
+            }
+            BRT_FONT => fonts.push(load_font(&mut data)?),
+            BRT_BEGIN_CELL_XFS => xf_list = XfList::CellXfs,
+            BRT_BEGIN_CELL_STYLE_XFS => xf_list = XfList::CellStyleXfs,
+            BRT_END_CELL_XFS | BRT_END_CELL_STYLE_XFS => xf_list = XfList::None,
+            BRT_XF => match xf_list {
+                XfList::CellXfs => cell_xfs.push(load_cell_format(&mut data, true)?),
+                XfList::CellStyleXfs => cell_style_xfs.push(load_cell_format(&mut data, false)?),
+                XfList::None => (),
+            },
+            _ => (),
+        }
+    }
+
+    style_sheet.numbering_formats = Some(numbering_formats);
+    style_sheet.fonts = Some(fonts);
+    style_sheet.cell_xfs = Some(cell_xfs);
+    style_sheet.cell_style_xfs = Some(cell_style_xfs);
+
+    return Ok(style_sheet);
+}
+
+/// BrtFont
+/// - dyHeight (2 bytes): size in twips
+/// - grbit (2 bytes): bit 1 italic, 3 strike, 4 outline, 5 shadow, 6 condense, 7 extend
+/// - bls (2 bytes): weight, 700 for bold
+/// - sss (2 bytes): 0 baseline, 1 superscript, 2 subscript
+/// - uls (1 byte): underline
+/// - bFamily (1 byte), bCharSet (1 byte), unused (1 byte)
+/// - brtColor (8 bytes)
+/// - bFontScheme (1 byte): 0 none, 1 major, 2 minor
+/// - name (XLWideString)
+fn load_font(data: &mut RecordData) -> anyhow::Result<XlsxFont> {
+    let height = data.read_u16()?;
+    let flags = data.read_u16()?;
+    let weight = data.read_u16()?;
+    let script = data.read_u16()?;
+    let underline = data.read_u8()?;
+    let family = data.read_u8()?;
+    let charset = data.read_u8()?;
+    data.skip(1)?;
+    let color = load_color(data)?;
+    let scheme = data.read_u8()?;
+    let name = data.read_wide_string()?;
+
+    let scheme = match scheme {
+        1 => Some("major"),
+        2 => Some("minor"),
+        _ => None,
+    };
+
+    return Ok(XlsxFont {
+        bold: Some(weight >= 700),
+        charset: Some(charset.to_string()),
+        color: Some(color),
+        condense: Some(flags & 0x40 != 0),
+        extend: Some(flags & 0x80 != 0),
+        family: Some(family as u64),
+        italic: Some(flags & 0x2 != 0),
+        name: Some(name),
+        outline: Some(flags & 0x10 != 0),
+        scheme: scheme.map(|s| s.to_string()),
+        shadow: Some(flags & 0x20 != 0),
+        strike: Some(flags & 0x8 != 0),
+        size: Some(height as f64 / 20.0),
+        underline: underline_value(underline).map(|s| s.to_string()),
+        vert_align: vertical_align_run_value(script).map(|s| s.to_string()),
+    });
+}
+
+/// BrtColor
+/// - flags (1 byte): bit 0 fValidRGB, bits 1-7 xColorType: 0 auto, 1 indexed, 2 rgb, 3 theme
+/// - index (1 byte)
+/// - nTintAndShade (2 bytes): tint in 1/32767
+/// - bRed, bGreen, bBlue, bAlpha (1 byte each)
+fn load_color(data: &mut RecordData) -> anyhow::Result<XlsxColor> {
+    let flags = data.read_u8()?;
+    let index = data.read_u8()?;
+    let tint = data.read_i16()?;
+    let (red, green, blue, alpha) = (
+        data.read_u8()?,
+        data.read_u8()?,
+        data.read_u8()?,
+        data.read_u8()?,
+    );
+
+    let mut color = XlsxColor {
+        tint: if tint == 0 {
+            None
+        } else {
+            Some(tint as f64 / 32767.0)
+        },
+        ..Default::default()
+    };
+    match flags >> 1 {
+        0 => color.auto = Some(true),
+        1 => color.indexed = Some(index as u64),
+        3 => color.theme = Some(index as u64),
+        _ => {
+            color.rgb = Some(format!(
+                "{:02X}{:02X}{:02X}{:02X}",
+                alpha, red, green, blue
+            ))
+        }
+    }
+
+    return Ok(color);
+}
+
+/// BrtXF
+/// - ixfeParent (2 bytes): index within cellStyleXfs, 0xFFFF for cell style formats
+/// - iFmt, iFont, iFill, ixBorder (2 bytes each)
+/// - trot (1 byte): text rotation
+/// - indent (1 byte)
+/// - flags (2 bytes):
+///     - bits 0-2 alc: horizontal alignment
+///     - bits 3-5 alcv: vertical alignment
+///     - bit 6 fWrap, 7 fJustLast, 8 fShrinkToFit, 9 fMergeCell
+///     - bits 10-11 iReadingOrder
+///     - bit 12 fLocked, 13 fHidden, 14 fSxButton, 15 f123Prefix
+/// - xfGrbitAtr (1 byte): bit 0 number format, 1 font, 2 alignment, 3 border, 4 fill, 5 protection applied
+///
+/// * is_cell_xf: record in cellXfs, the format refers to a cell style format.
+fn load_cell_format(data: &mut RecordData, is_cell_xf: bool) -> anyhow::Result<XlsxCellFormat> {
+    let parent = data.read_u16()?;
+    let num_fmt_id = data.read_u16()?;
+    let font_id = data.read_u16()?;
+    let fill_id = data.read_u16()?;
+    let border_id = data.read_u16()?;
+    let text_rotation = data.read_u8()?;
+    let indent = data.read_u8()?;
+    let flags = data.read_u16()?;
+    let applied = data.read_u8()?;
+
+    return Ok(XlsxCellFormat {
+        alignment: Some(XlsxAlignment {
+            horizontal: Some(horizontal_alignment_value(flags & 0x7).to_string()),
+            indent: Some(indent as u64),
+            justify_last_line: Some(flags & 0x80 != 0),
+            reading_order: Some(((flags >> 10) & 0x3) as u64),
+            shrink_to_fit: Some(flags & 0x100 != 0),
+            text_rotation: Some(text_rotation as u64),
+            vertical: Some(vertical_alignment_value((flags >> 3) & 0x7).to_string()),
+            wrap_text: Some(flags & 0x40 != 0),
+            ..Default::default()
+        }),
+        protection: Some(XlsxCellProtection {
+            hidden: Some(flags & 0x2000 != 0),
+            locked: Some(flags & 0x1000 != 0),
+        }),
+        apply_alignment: Some(applied & 0x4 != 0),
+        apply_border: Some(applied & 0x8 != 0),
+        apply_fill: Some(applied & 0x10 != 0),
+        apply_font: Some(applied & 0x2 != 0),
+        apply_number_format: Some(applied & 0x1 != 0),
+        apply_protection: Some(applied & 0x20 != 0),
+        border_id: Some(border_id as u64),
+        fill_id: Some(fill_id as u64),
+        font_id: Some(font_id as u64),
+        num_fmt_id: Some(num_fmt_id as u64),
+        pivot_button: Some(flags & 0x4000 != 0),
+        quote_prefix: Some(flags & 0x8000 != 0),
+        xf_id: if is_cell_xf && parent != 0xFFFF {
+            Some(parent as u64)
+        } else {
+            None
+        },
+    });
+}
+
+/// uls: underline style of a font, also used by legacy xls files
+pub(crate) fn underline_value(underline: u8) -> Option<&'static str> {
+    return match underline {
+        0x01 => Some("single"),
+        0x02 => Some("double"),
+        0x21 => Some("singleAccounting"),
+        0x22 => Some("doubleAccounting"),
+        _ => None,
+    };
+}
+
+/// sss: script style of a font, also used by legacy xls files
+pub(crate) fn vertical_align_run_value(script: u16) -> Option<&'static str> {
+    return match script {
+        1 => Some("superscript"),
+        2 => Some("subscript"),
+        _ => None,
+    };
+}
+
+/// alc: horizontal alignment of a cell format, also used by legacy xls files
+pub(crate) fn horizontal_alignment_value(alignment: u16) -> &'static str {
+    return match alignment {
+        1 => "left",
+        2 => "center",
+        3 => "right",
+        4 => "fill",
+        5 => "justify",
+        6 => "centerContinuous",
+        7 => "distributed",
+        _ => "general",
+    };
+}
+
+/// alcv: vertical alignment of a cell format, also used by legacy xls files
+pub(crate) fn vertical_alignment_value(alignment: u16) -> &'static str {
+    return match alignment {
+        0 => "top",
+        1 => "center",
+        3 => "justify",
+        4 => "distributed",
+        _ => "bottom",
+    };
+}
@@ -0,0 +1,93 @@
+use std::io::{Read, Seek};
+use zip::ZipArchive;
+
+use crate::{
+    excel::ExcelOptions,
+    raw::spreadsheet::workbook::{
+        sheet::{XlsxSheet, XlsxSheets},
+        workbook_properties::XlsxWorkbookProperties,
+        XlsxWorkbook,
+    },
+};
+
+use super::{record_reader, RecordData, BINARY_WORKBOOK_PATH};
+
+/// BrtWbProp: workbook properties
+const BRT_WB_PROP: u16 = 153;
+/// BrtBundleSh: a sheet of the workbook
+const BRT_BUNDLE_SH: u16 = 156;
+
+/// Load the workbook from xl/workbook.bin.
+///
+/// Only sheets and workbook properties are parsed.
+pub(crate) fn load_binary_workbook<RS: Read + Seek>(
+    zip: &mut ZipArchive<RS>,
+    options: &ExcelOptions,
+) -> anyhow::Result<XlsxWorkbook> {
+    let mut workbook = XlsxWorkbook::default();
+    let Some(mut reader) = record_reader(zip, BINARY_WORKBOOK_PATH, options) else {
+        return Ok(workbook);
+    };
+
+    let mut sheets: XlsxSheets = vec![];
+    while let Some((record_type, mut data)) = reader.next_record()? {
+        match record_type {
+            BRT_WB_PROP => {
+                workbook.workbook_properties = Some(load_workbook_properties(&mut data)?);
+            }
+            BRT_BUNDLE_SH => {
+                sheets.push(load_sheet(&mut data)?);
+            }
+            _ => (),
+        }
+    }
+    workbook.sheets = Some(sheets);
+
+    return Ok(workbook);
+}
+
+/// BrtWbProp
+/// - flags (4 bytes): bit 0 f1904
+/// - dwThemeVersion (4 bytes)
+/// - strName (XLWideString): code name
+fn load_workbook_properties(data: &mut RecordData) -> anyhow::Result<XlsxWorkbookProperties> {
+    let flags = data.read_u32()?;
+    let theme_version = data.read_u32()?;
+    let code_name = data.read_wide_string()?;
+
+    return Ok(XlsxWorkbookProperties {
+        date1904: Some(flags & 0x1 != 0),
+        default_theme_version: Some(theme_version as i64),
+        code_name: if code_name.is_empty() {
+            None
+        } else {
+            Some(code_name)
+        },
+        ..Default::default()
+    });
+}
+
+/// BrtBundleSh
+/// - hsState (4 bytes): 0 visible, 1 hidden, 2 very hidden
+/// - iTabID (4 bytes): sheet id
+/// - strRelID (XLNullableWideString): relationship id of the sheet part
+/// - strName (XLWideString)
+fn load_sheet(data: &mut RecordData) -> anyhow::Result<XlsxSheet> {
+    let state = data.read_u32()?;
+    let sheet_id = data.read_u32()?;
+    let id = data.read_nullable_wide_string()?;
+    let name = data.read_wide_string()?;
+
+    let visible_state = match state {
+        1 => "hidden",
+        2 => "veryHidden",
+        _ => "visible",
+    };
+
+    return Ok(XlsxSheet {
+        id,
+        name: Some(name),
+        sheet_id: Some(sheet_id as u64),
+        visible_state: Some(visible_state.to_string()),
+    });
+}
@@ -0,0 +1,315 @@
+use std::io::{Read, Seek};
+use zip::ZipArchive;
+
+use crate::{
+    common_types::{Coordinate, Dimension},
+    excel::ExcelOptions,
+    raw::spreadsheet::{
+        sheet::{
+            sheet_format_properties::XlsxSheetFormatProperties,
+            worksheet::{
+                cell::{cell_value::XlsxCellValue, XlsxCell},
+                column_information::{XlsxColumnInformation, XlsxColumnInformations},
+                merge_cell::XlsxMergeCells,
+                row::XlsxRow,
+                sheet_data::{SheetDataMode, XlsxSheetData},
+                XlsxWorksheet,
+            },
+        },
+        string_item::XlsxStringItem,
+    },
+};
+
+use super::{record_reader, RecordData};
+
+/// BrtRowHdr: start of a row, followed by the cells of the row
+const BRT_ROW_HDR: u16 = 0;
+/// BrtCellBlank ... BrtShortIsst: cells, see `load_cell`
+const BRT_CELL_BLANK: u16 = 1;
+const BRT_SHORT_ISST: u16 = 18;
+/// BrtCellRString: cell of a string with formatting runs
+const BRT_CELL_RSTRING: u16 = 62;
+/// BrtColInfo: width and format of a range of columns
+const BRT_COL_INFO: u16 = 60;
+/// BrtBeginSheetData / BrtEndSheetData
+const BRT_BEGIN_SHEET_DATA: u16 = 145;
+const BRT_END_SHEET_DATA: u16 = 146;
+/// BrtWsDim: used range of the sheet
+const BRT_WS_DIM: u16 = 148;
+/// BrtMergeCell: a merged range
+const BRT_MERGE_CELL: u16 = 176;
+/// BrtWsFmtInfo: default row height and column width
+const BRT_WS_FMT_INFO: u16 = 485;
+
+/// Load a worksheet from xl/worksheets/sheet{}.bin.
+///
+/// Dimension, sheet format properties, column information, merged cells and cells are parsed.
+///
+/// * sheet_data: part of the cells table to load, see `Excel::get_worksheet_without_cells`.
+pub(crate) fn load_binary_worksheet<RS: Read + Seek>(
+    zip: &mut ZipArchive<RS>,
+    path: &str,
+    options: &ExcelOptions,
+    sheet_data: SheetDataMode,
+) -> anyhow::Result<XlsxWorksheet> {
+    let mut worksheet = XlsxWorksheet::default();
+    let Some(mut reader) = record_reader(zip, path, options) else {
+        return Ok(worksheet);
+    };
+
+    let mut column_infos: XlsxColumnInformations = vec![];
+    let mut merge_cells: XlsxMergeCells = vec![];
+    let mut rows: Vec<XlsxRow> = vec![];
+    let mut in_sheet_data = false;
+    // column of the last cell, short cell records are in the next column
+    let mut last_column: u64 = 0;
+
+    while let Some((record_type, mut data)) = reader.next_record()? {
+        match record_type {
+            BRT_WS_DIM => {
+                let dimension = load_range(&mut data)?;
+                rows.reserve(options.capacity_for(Some(
+                    dimension.end.row.saturating_sub(dimension.start.row) + 1,
+                )));
+                worksheet.dimension = Some(dimension);
+            }
+            BRT_WS_FMT_INFO => {
+                worksheet.sheet_format_properties = Some(load_sheet_format_properties(&mut data)?);
+            }
+            BRT_COL_INFO => column_infos.push(load_column_information(&mut data)?),
+            BRT_MERGE_CELL => merge_cells.push(load_range(&mut data)?),
+            BRT_BEGIN_SHEET_DATA => in_sheet_data = true,
+            BRT_END_SHEET_DATA => in_sheet_data = false,
+            BRT_ROW_HDR if in_sheet_data && sheet_data != SheetDataMode::Skip => {
+                rows.push(load_row(&mut data)?);
+                last_column = 0;
+            }
+            BRT_CELL_BLANK..=BRT_SHORT_ISST | BRT_CELL_RSTRING
+                if in_sheet_data && sheet_data == SheetDataMode::Cells =>
+            {
+                let Some(row) = rows.last_mut() else {
+                    continue;
+                };
+                let Some(row_index) = row.row_index else {
+                    continue;
+                };
+                let cell = load_cell(record_type, &mut data, row_index, &mut last_column)?;
+                row.cells.get_or_insert_with(Vec::new).push(cell);
+            }
+            _ => (),
+        }
+    }
+
+    if !column_infos.is_empty() {
+        worksheet.column_infos = Some(column_infos);
+    }
+    if !merge_cells.is_empty() {
+        worksheet.merge_cells = Some(merge_cells);
+    }
+    if sheet_data != SheetDataMode::Skip {
+        worksheet.sheet_data = Some(XlsxSheetData { rows: Some(rows) });
+    }
+
+    return Ok(worksheet);
+}
+
+/// RfX: rwFirst, rwLast, colFirst, colLast (4 bytes each, 0 based)
+fn load_range(data: &mut RecordData) -> anyhow::Result<Dimension> {
+    let first_row = data.read_u32()? as u64;
+    let last_row = data.read_u32()? as u64;
+    let first_col = data.read_u32()? as u64;
+    let last_col = data.read_u32()? as u64;
+
+    return Ok(Dimension {
+        start: Coordinate::from_point((first_row + 1, first_col + 1)),
+        end: Coordinate::from_point((last_row + 1, last_col + 1)),
+    });
+}
+
+/// BrtWsFmtInfo
+/// - dxGCol (4 bytes): default column width in 1/256 of a character, 0xFFFFFFFF if not set
+/// - cchDefColWidth (2 bytes): base column width in characters
+/// - miyDefRwHeight (2 bytes): default row height in twips
+/// - flags (2 bytes): bit 0 fUnsynced, 1 fDyZero, 2 fExAsc, 3 fExDsc
+/// - iOutLevelRw, iOutLevelCol (1 byte each)
+fn load_sheet_format_properties(data: &mut RecordData) -> anyhow::Result<XlsxSheetFormatProperties> {
+    let default_col_width = data.read_u32()?;
+    let base_col_width = data.read_u16()?;
+    let default_row_height = data.read_u16()?;
+    let flags = data.read_u16()?;
+    let outline_level_row = data.read_u8()?;
+    let outline_level_col = data.read_u8()?;
+
+    return Ok(XlsxSheetFormatProperties {
+        base_col_width: Some(base_col_width as u64),
+        custom_height: Some(flags & 0x1 != 0),
+        default_col_width: if default_col_width == u32::MAX {
+            None
+        } else {
+            Some(default_col_width as f64 / 256.0)
+        },
+        default_row_height: Some(default_row_height as f64 / 20.0),
+        outline_level_col: Some(outline_level_col as u64),
+        outline_level_row: Some(outline_level_row as u64),
+        thick_bottom: Some(flags & 0x8 != 0),
+        thick_top: Some(flags & 0x4 != 0),
+        zero_height: Some(flags & 0x2 != 0),
+        ..Default::default()
+    });
+}
+
+/// BrtColInfo
+/// - colFirst, colLast (4 bytes each, 0 based)
+/// - coldx (4 bytes): width in 1/256 of a character
+/// - ixfe (4 bytes): style index
+/// - flags (2 bytes): bit 0 fHidden, 1 fUserSet, 2 fBestFit, 3 fPhonetic, bits 8-10 iOutLevel, bit 12 fCollapsed
+fn load_column_information(data: &mut RecordData) -> anyhow::Result<XlsxColumnInformation> {
+    let first = data.read_u32()?;
+    let last = data.read_u32()?;
+    let width = data.read_u32()?;
+    let style = data.read_u32()?;
+    let flags = data.read_u16()?;
+
+    return Ok(XlsxColumnInformation {
+        best_fit: Some(flags & 0x4 != 0),
+        collapsed: Some(flags & 0x1000 != 0),
+        custom_width: Some(flags & 0x2 != 0),
+        hidden: Some(flags & 0x1 != 0),
+        max_column: Some(last as u64 + 1),
+        min_column: Some(first as u64 + 1),
+        outline_level: Some(((flags >> 8) & 0x7) as u64),
+        show_phonetic: Some(flags & 0x8 != 0),
+        style: Some(style as u64),
+        width: Some(width as f64 / 256.0),
+    });
+}
+
+/// BrtRowHdr
+/// - rw (4 bytes): 0 based row index
+/// - ixfe (4 bytes): style index
+/// - miyRw (2 bytes): height in twips
+/// - flags (2 bytes): bit 0 fExtraAsc, 1 fExtraDsc, bits 8-10 iOutLevel, bit 11 fCollapsed, 12 fDyZero, 13 fUnsynced, 14 fGhostDirty
+/// - flags (1 byte): bit 0 fPhonetic
+fn load_row(data: &mut RecordData) -> anyhow::Result<XlsxRow> {
+    let index = data.read_u32()?;
+    let style = data.read_u32()?;
+    let height = data.read_u16()?;
+    let flags = data.read_u16()?;
+    let phonetic = data.read_u8()?;
+    // custom format
+    let ghost_dirty = flags & 0x4000 != 0;
+
+    return Ok(XlsxRow {
+        collapsed: Some(flags & 0x800 != 0),
+        custom_format: Some(ghost_dirty),
+        custom_height: Some(flags & 0x2000 != 0),
+        height: Some(height as f64 / 20.0),
+        hidden: Some(flags & 0x1000 != 0),
+        outline_level: Some(((flags >> 8) & 0x7) as u64),
+        row_index: Some(index as u64 + 1),
+        show_phonetic: Some(phonetic & 0x1 != 0),
+        style: if ghost_dirty { Some(style as u64) } else { None },
+        thick_bottom: Some(flags & 0x2 != 0),
+        thick_top: Some(flags & 0x1 != 0),
+        ..Default::default()
+    });
+}
+
+/// Cell records start with
+/// - column (4 bytes, 0 based), left out by short cell records (BrtShort*), placed in the column next to the previous cell.
+/// - iStyleRef (24 bits): style index, fPhShow (1 bit), reserved (7 bits)
+///
+/// followed by the value:
+/// - BrtCellBlank (1), BrtShortBlank (12): none
+/// - BrtCellRk (2), BrtShortRk (13): RkNumber (4 bytes)
+/// - BrtCellError (3), BrtShortError (14), BrtFmlaError (11): error code (1 byte)
+/// - BrtCellBool (4), BrtShortBool (15), BrtFmlaBool (10): 1 byte
+/// - BrtCellReal (5), BrtShortReal (16), BrtFmlaNum (9): Xnum (8 bytes)
+/// - BrtCellSt (6), BrtShortSt (17), BrtFmlaString (8): XLWideString
+/// - BrtCellIsst (7), BrtShortIsst (18): shared string index (4 bytes)
+/// - BrtCellRString (62): RichStr, flags (1 byte) followed by XLWideString
+///
+/// Formula records (BrtFmla*) are followed by the parsed formula, which is not decoded.
+fn load_cell(
+    record_type: u16,
+    data: &mut RecordData,
+    row_index: u64,
+    last_column: &mut u64,
+) -> anyhow::Result<XlsxCell> {
+    let is_short = (12..=18).contains(&record_type);
+    let column = if is_short {
+        *last_column + 1
+    } else {
+        data.read_u32()? as u64 + 1
+    };
+    *last_column = column;
+    let style = data.read_u32()?;
+
+    let mut cell = XlsxCell::new(Coordinate::from_point((row_index, column)));
+    cell.style = Some((style & 0xFFFFFF) as u64);
+    cell.show_phonetic = Some(style & 0x1000000 != 0);
+
+    let (r#type, value) = match record_type {
+        2 | 13 => ("n", rk_to_string(data.read_u32()?)),
+        3 | 11 | 14 => {
+            let Some(error) = error_code_to_string(data.read_u8()?) else {
+                return Ok(cell);
+            };
+            ("e", error.to_string())
+        }
+        4 | 10 | 15 => {
+            let value = if data.read_u8()? != 0 { "1" } else { "0" };
+            ("b", value.to_string())
+        }
+        5 | 9 | 16 => ("n", data.read_f64()?.to_string()),
+        // string result of a formula, as `t="str"` cells of xlsx files
+        8 => ("str", data.read_wide_string()?),
+        6 | 17 | BRT_CELL_RSTRING => {
+            if record_type == BRT_CELL_RSTRING {
+                data.skip(1)?;
+            }
+            cell.r#type = Some("inlineStr".to_string());
+            cell.inline_string = Some(XlsxStringItem {
+                text: Some(data.read_wide_string()?),
+                ..Default::default()
+            });
+            return Ok(cell);
+        }
+        7 | 18 => ("s", data.read_u32()?.to_string()),
+        _ => return Ok(cell),
+    };
+    cell.r#type = Some(r#type.to_string());
+    cell.cell_value = Some(XlsxCellValue {
+        raw_value: value,
+        space: None,
+    });
+
+    return Ok(cell);
+}
+
+/// RkNumber, shared with legacy xls files
+/// - bit 0 fX100: value is divided by 100
+/// - bit 1 fInt: value is a 30 bits signed integer, otherwise the 30 most significant bits of a 64 bits float
+pub(crate) fn rk_to_string(rk: u32) -> String {
+    let value = if rk & 0x2 != 0 {
+        ((rk as i32) >> 2) as f64
+    } else {
+        f64::from_bits(((rk & 0xFFFFFFFC) as u64) << 32)
+    };
+    let value = if rk & 0x1 != 0 { value / 100.0 } else { value };
+    return value.to_string();
+}
+
+/// BErr, shared with legacy xls files. None for codes without a cell error type, ie: #GETTING_DATA.
+pub(crate) fn error_code_to_string(code: u8) -> Option<&'static str> {
+    return match code {
+        0x00 => Some("#NULL!"),
+        0x07 => Some("#DIV/0!"),
+        0x0F => Some("#VALUE!"),
+        0x17 => Some("#REF!"),
+        0x1D => Some("#NAME?"),
+        0x24 => Some("#NUM!"),
+        0x2A => Some("#N/A"),
+        _ => None,
+    };
+}
@@ -0,0 +1,247 @@
+use anyhow::bail;
+
+/// Compound File Binary format: https://learn.microsoft.com/en-us/openspecs/windows_protocols/ms-cfb/53989ce4-7b05-4f8d-829b-d08d6148375b
+///
+/// Container of legacy office files, a file system of storages and streams within a single file.
+/// The file is made of sectors, chained by the File Allocation Table (FAT).
+/// Streams smaller than the mini stream cutoff are stored in 64 bytes mini sectors within the mini stream, chained by the mini FAT.
+pub(crate) struct CompoundFile<'a> {
+    data: &'a [u8],
+    sector_size: usize,
+    mini_sector_size: usize,
+    mini_stream_cutoff: u64,
+    fat: Vec<u32>,
+    mini_fat: Vec<u32>,
+    /// stream of the root entry, holding the mini sectors
+    mini_stream: Vec<u8>,
+    entries: Vec<DirectoryEntry>,
+}
+
+struct DirectoryEntry {
+    name: String,
+    /// 1: storage, 2: stream, 5: root storage
+    object_type: u8,
+    start_sector: u32,
+    size: u64,
+}
+
+const SIGNATURE: [u8; 8] = [0xD0, 0xCF, 0x11, 0xE0, 0xA1, 0xB1, 0x1A, 0xE1];
+const END_OF_CHAIN: u32 = 0xFFFFFFFE;
+const FREE_SECTOR: u32 = 0xFFFFFFFF;
+const HEADER_DIFAT_COUNT: usize = 109;
+const DIRECTORY_ENTRY_SIZE: usize = 128;
+const STREAM_OBJECT: u8 = 2;
+
+impl<'a> CompoundFile<'a> {
+    pub(crate) fn load(data: &'a [u8]) -> anyhow::Result<Self> {
+        if data.len() < 512 || data[0..8] != SIGNATURE {
+            bail!("Not a compound file: signature mismatch.")
+        }
+
+        let sector_shift = read_u16(data, 0x1E)?;
+        let mini_sector_shift = read_u16(data, 0x20)?;
+        if !(sector_shift == 9 || sector_shift == 12) || mini_sector_shift != 6 {
+            bail!("Unsupported compound file sector size.")
+        }
+        let sector_size = 1usize << sector_shift;
+        let fat_sector_count = read_u32(data, 0x2C)? as usize;
+        let first_directory_sector = read_u32(data, 0x30)?;
+        let mini_stream_cutoff = read_u32(data, 0x38)? as u64;
+        let first_mini_fat_sector = read_u32(data, 0x3C)?;
+        let mut difat_sector = read_u32(data, 0x44)?;
+
+        let mut file = Self {
+            data,
+            sector_size,
+            mini_sector_size: 1usize << mini_sector_shift,
+            mini_stream_cutoff,
+            fat: vec![],
+            mini_fat: vec![],
+            mini_stream: vec![],
+            entries: vec![],
+        };
+
+        // DIFAT: locations of the FAT sectors, 109 in the header followed by a chain of DIFAT sectors
+        let mut fat_sectors: Vec<u32> = vec![];
+        for index in 0..HEADER_DIFAT_COUNT {
+            fat_sectors.push(read_u32(data, 0x4C + index * 4)?);
+        }
+        let entries_per_sector = sector_size / 4;
+        let mut visited = 0;
+        while difat_sector != END_OF_CHAIN && difat_sector != FREE_SECTOR {
+            visited += 1;
+            if visited > file.sector_count() {
+                bail!("Compound file DIFAT chain is cyclic.")
+            }
+            let sector = file.sector(difat_sector)?;
+            for index in 0..entries_per_sector - 1 {
+                fat_sectors.push(read_u32(sector, index * 4)?);
+            }
+            difat_sector = read_u32(sector, (entries_per_sector - 1) * 4)?;
+        }
+
+        // the count is read from the file: a FAT cannot have more sectors than the file
+        let fat_sector_count = fat_sector_count.min(file.sector_count());
+        let mut fat: Vec<u32> = Vec::with_capacity(fat_sector_count * entries_per_sector);
+        for &fat_sector in fat_sectors
+            .iter()
+            .filter(|s| **s != FREE_SECTOR)
+            .take(fat_sector_count)
+        {
+            let sector = file.sector(fat_sector)?;
+            for index in 0..entries_per_sector {
+                fat.push(read_u32(sector, index * 4)?);
+            }
+        }
+        file.fat = fat;
+
+        let directory = file.read_chain(first_directory_sector, None)?;
+        file.entries = directory
+            .chunks_exact(DIRECTORY_ENTRY_SIZE)
+            .map(|entry| DirectoryEntry::load(entry, sector_size == 512))
+            .collect::<anyhow::Result<Vec<DirectoryEntry>>>()?;
+
+        if first_mini_fat_sector != END_OF_CHAIN && first_mini_fat_sector != FREE_SECTOR {
+            let mini_fat = file.read_chain(first_mini_fat_sector, None)?;
+            file.mini_fat = mini_fat
+                .chunks_exact(4)
+                .map(|b| u32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+                .collect();
+        }
+        if let Some(root) = file.entries.first() {
+            let (start, size) = (root.start_sector, root.size);
+            if start != END_OF_CHAIN && start != FREE_SECTOR {
+                file.mini_stream = file.read_chain(start, Some(size))?;
+            }
+        }
+
+        Ok(file)
+    }
+
+    /// Content of the first stream with the name (case insensitive), None if there is no such stream.
+    pub(crate) fn read_stream(&self, name: &str) -> anyhow::Result<Option<Vec<u8>>> {
+        let Some(entry) = self
+            .entries
+            .iter()
+            .find(|e| e.object_type == STREAM_OBJECT && e.name.eq_ignore_ascii_case(name))
+        else {
+            return Ok(None);
+        };
+
+        if entry.size < self.mini_stream_cutoff {
+            return Ok(Some(self.read_mini_chain(entry.start_sector, entry.size)?));
+        }
+        Ok(Some(self.read_chain(entry.start_sector, Some(entry.size))?))
+    }
+
+    fn sector_count(&self) -> usize {
+        self.data.len() / self.sector_size
+    }
+
+    fn sector(&self, index: u32) -> anyhow::Result<&'a [u8]> {
+        let start = (index as usize + 1) * self.sector_size;
+        let Some(sector) = self.data.get(start..start + self.sector_size) else {
+            bail!("Compound file sector {} out of range.", index)
+        };
+        Ok(sector)
+    }
+
+    /// * size: None to read the whole chain
+    fn read_chain(&self, start: u32, size: Option<u64>) -> anyhow::Result<Vec<u8>> {
+        let mut bytes: Vec<u8> = vec![];
+        let mut current = start;
+        let mut visited = 0;
+        while current != END_OF_CHAIN {
+            visited += 1;
+            if visited > self.fat.len() {
+                bail!("Compound file sector chain is cyclic.")
+            }
+            bytes.extend_from_slice(self.sector(current)?);
+            if size.is_some_and(|size| bytes.len() as u64 >= size) {
+                break;
+            }
+            let Some(next) = self.fat.get(current as usize) else {
+                bail!("Compound file sector {} is not in the FAT.", current)
+            };
+            current = *next;
+        }
+        if let Some(size) = size {
+            if (bytes.len() as u64) < size {
+                bail!("Compound file stream is shorter than its size.")
+            }
+            bytes.truncate(size as usize);
+        }
+        Ok(bytes)
+    }
+
+    fn read_mini_chain(&self, start: u32, size: u64) -> anyhow::Result<Vec<u8>> {
+        let mut bytes: Vec<u8> = vec![];
+        let mut current = start;
+        let mut visited = 0;
+        while current != END_OF_CHAIN && (bytes.len() as u64) < size {
+            visited += 1;
+            if visited > self.mini_fat.len() {
+                bail!("Compound file mini sector chain is cyclic.")
+            }
+            let offset = current as usize * self.mini_sector_size;
+            let Some(sector) = self.mini_stream.get(offset..offset + self.mini_sector_size) else {
+                bail!("Compound file mini sector {} out of range.", current)
+            };
+            bytes.extend_from_slice(sector);
+            let Some(next) = self.mini_fat.get(current as usize) else {
+                bail!(
+                    "Compound file mini sector {} is not in the mini FAT.",
+                    current
+                )
+            };
+            current = *next;
+        }
+        if (bytes.len() as u64) < size {
+            bail!("Compound file stream is shorter than its size.")
+        }
+        bytes.truncate(size as usize);
+        Ok(bytes)
+    }
+}
+
+impl DirectoryEntry {
+    /// - name (64 bytes): UTF-16, including the terminating null character
+    /// - name length (2 bytes): in bytes
+    /// - object type (1 byte)
+    /// - starting sector (4 bytes) at 116
+    /// - stream size (8 bytes) at 120, only the low 4 bytes are used by version 3 files (512 bytes sectors)
+    fn load(entry: &[u8], is_version_3: bool) -> anyhow::Result<Self> {
+        let name_length = (read_u16(entry, 64)? as usize).min(64);
+        let name: Vec<u16> = entry[0..name_length]
+            .chunks_exact(2)
+            .map(|b| u16::from_le_bytes([b[0], b[1]]))
+            .take_while(|c| *c != 0)
+            .collect();
+
+        let mut size = read_u32(entry, 120)? as u64;
+        if !is_version_3 {
+            size |= (read_u32(entry, 124)? as u64) << 32;
+        }
+
+        Ok(Self {
+            name: String::from_utf16_lossy(&name),
+            object_type: entry[66],
+            start_sector: read_u32(entry, 116)?,
+            size,
+        })
+    }
+}
+
+fn read_u16(data: &[u8], offset: usize) -> anyhow::Result<u16> {
+    let Some(bytes) = data.get(offset..offset + 2) else {
+        bail!("unexpected end of compound file.")
+    };
+    Ok(u16::from_le_bytes([bytes[0], bytes[1]]))
+}
+
+fn read_u32(data: &[u8], offset: usize) -> anyhow::Result<u32> {
+    let Some(bytes) = data.get(offset..offset + 4) else {
+        bail!("unexpected end of compound file.")
+    };
+    Ok(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+}
@@ -118,7 +118,7 @@ pub type STCoordinate = i64;
 /// - 1 point = 12,700 EMUs
 #[allow(dead_code)]
 pub(crate) fn emu_to_pt(emu: i64) -> f64 {
-    return (emu as f64) / 12700.0;
+    return crate::units::emu_to_pt(emu);
 }
 
 /// https://c-rex.net/samples/ooxml/e1/Part4/OOXML_P4_DOCX_ST_AdjCoordinate_topic_ID0E14KNB.html
@@ -172,7 +172,7 @@ impl XlsxTheme {
         //     return None;
         // };
 
-        if index > line_style_list.len() - 1 {
+        if index >= line_style_list.len() {
             return None;
         };
 
@@ -227,7 +227,7 @@ impl XlsxTheme {
                 return None;
             };
 
-            if index > style_list.len() - 1 {
+            if index >= style_list.len() {
                 return None;
             };
 
@@ -244,7 +244,7 @@ impl XlsxTheme {
             return None;
         };
 
-        if index > style_list.len() - 1 {
+        if index >= style_list.len() {
             return None;
         };
 
@@ -282,7 +282,7 @@ impl XlsxTheme {
             return None;
         };
 
-        if index > style_list.len() - 1 {
+        if index >= style_list.len() {
             return None;
         };
 
@@ -0,0 +1,238 @@
+//! Agile encryption: https://learn.microsoft.com/en-us/openspecs/office_file_formats/ms-offcrypto/87020a34-e73f-4139-99bc-bbdf6cf6fa55
+//!
+//! The encryption is described by an xml descriptor.
+//! A secret key, encrypted with a key derived from the password, encrypts the package in CBC mode by segments of 4096 bytes.
+//!
+//! Example:
+//! ```
+//! <encryption xmlns="http://schemas.microsoft.com/office/2006/encryption" xmlns:p="http://schemas.microsoft.com/office/2006/keyEncryptor/password">
+//!     <keyData saltSize="16" blockSize="16" keyBits="256" hashSize="64" cipherAlgorithm="AES" cipherChaining="ChainingModeCBC" hashAlgorithm="SHA512" saltValue="..." />
+//!     <dataIntegrity encryptedHmacKey="..." encryptedHmacValue="..." />
+//!     <keyEncryptors>
+//!         <keyEncryptor uri="http://schemas.microsoft.com/office/2006/keyEncryptor/password">
+//!             <p:encryptedKey spinCount="100000" saltSize="16" blockSize="16" keyBits="256" hashSize="64" cipherAlgorithm="AES" cipherChaining="ChainingModeCBC" hashAlgorithm="SHA512" saltValue="..." encryptedVerifierHashInput="..." encryptedVerifierHashValue="..." encryptedKeyValue="..." />
+//!         </keyEncryptor>
+//!     </keyEncryptors>
+//! </encryption>
+//! ```
+
+use anyhow::bail;
+use quick_xml::{events::Event, Reader};
+
+use super::{fit_to_size, iterated_hash, AesDecryptor, HashAlgorithm, AES_BLOCK_SIZE};
+
+/// Size of the segments the package is encrypted by.
+const SEGMENT_SIZE: usize = 4096;
+
+/// Block keys of the keys derived from the password.
+const VERIFIER_HASH_INPUT_BLOCK_KEY: [u8; 8] = [0xfe, 0xa7, 0xd2, 0x76, 0x3b, 0x4b, 0x9e, 0x79];
+const VERIFIER_HASH_VALUE_BLOCK_KEY: [u8; 8] = [0xd7, 0xaa, 0x0f, 0x6d, 0x30, 0x61, 0x34, 0x4e];
+const ENCRYPTED_KEY_VALUE_BLOCK_KEY: [u8; 8] = [0x14, 0x6e, 0x0b, 0xe7, 0xab, 0xac, 0xd0, 0xd6];
+
+/// Uri of the key encryptor using a password.
+const PASSWORD_KEY_ENCRYPTOR: &str =
+    "http://schemas.microsoft.com/office/2006/keyEncryptor/password";
+
+/// keyData or p:encryptedKey: cipher and hash parameters.
+#[derive(Debug, Clone, Default)]
+struct AgileKeyData {
+    spin_count: u32,
+    salt_size: usize,
+    block_size: usize,
+    key_bits: usize,
+    hash_size: usize,
+    cipher_algorithm: String,
+    cipher_chaining: String,
+    hash_algorithm: String,
+    salt_value: Vec<u8>,
+    encrypted_verifier_hash_input: Vec<u8>,
+    encrypted_verifier_hash_value: Vec<u8>,
+    encrypted_key_value: Vec<u8>,
+}
+
+impl AgileKeyData {
+    fn check_cipher(&self) -> anyhow::Result<()> {
+        if self.cipher_algorithm != "AES" || self.block_size != AES_BLOCK_SIZE {
+            bail!("Unsupported cipher algorithm: {}", self.cipher_algorithm)
+        }
+        if self.cipher_chaining != "ChainingModeCBC" {
+            bail!("Unsupported cipher chaining: {}", self.cipher_chaining)
+        }
+        return Ok(());
+    }
+}
+
+/// Decrypt a package with agile encryption.
+///
+/// info: EncryptionInfo stream following the version: reserved (4 bytes) and the xml descriptor
+pub(crate) fn decrypt(info: &[u8], password: &str, encrypted: &[u8]) -> anyhow::Result<Vec<u8>> {
+    let Some(descriptor) = info.get(4..) else {
+        bail!("Invalid encryption info: the descriptor is missing.")
+    };
+    let (key_data, password_key) = load_descriptor(descriptor)?;
+    key_data.check_cipher()?;
+    password_key.check_cipher()?;
+
+    let hash_algorithm = HashAlgorithm::from_name(&password_key.hash_algorithm)?;
+    let hash = iterated_hash(
+        hash_algorithm,
+        &password_key.salt_value,
+        password,
+        password_key.spin_count,
+    );
+    let iv = fit_to_size(password_key.salt_value.clone(), password_key.block_size);
+    let decrypt_with_block_key = |block_key: &[u8], value: &[u8]| -> anyhow::Result<Vec<u8>> {
+        let key = fit_to_size(
+            hash_algorithm.hash(&[&hash, block_key]),
+            password_key.key_bits / 8,
+        );
+        let mut value = value.to_vec();
+        AesDecryptor::new(&key)?.decrypt_cbc(&iv, &mut value);
+        return Ok(value);
+    };
+
+    let mut verifier_hash_input = decrypt_with_block_key(
+        &VERIFIER_HASH_INPUT_BLOCK_KEY,
+        &password_key.encrypted_verifier_hash_input,
+    )?;
+    verifier_hash_input.truncate(password_key.salt_size);
+    let verifier_hash_value = decrypt_with_block_key(
+        &VERIFIER_HASH_VALUE_BLOCK_KEY,
+        &password_key.encrypted_verifier_hash_value,
+    )?;
+    let expected_hash = hash_algorithm.hash(&[&verifier_hash_input]);
+    let hash_size = password_key.hash_size.min(expected_hash.len());
+    if verifier_hash_value.len() < hash_size
+        || expected_hash[..hash_size] != verifier_hash_value[..hash_size]
+    {
+        bail!("The password is incorrect.")
+    }
+
+    let mut secret_key = decrypt_with_block_key(
+        &ENCRYPTED_KEY_VALUE_BLOCK_KEY,
+        &password_key.encrypted_key_value,
+    )?;
+    secret_key.truncate(key_data.key_bits / 8);
+    let decryptor = AesDecryptor::new(&secret_key)?;
+
+    let data_hash_algorithm = HashAlgorithm::from_name(&key_data.hash_algorithm)?;
+    let mut decrypted = encrypted.to_vec();
+    for (index, segment) in decrypted.chunks_mut(SEGMENT_SIZE).enumerate() {
+        let iv = data_hash_algorithm.hash(&[&key_data.salt_value, &(index as u32).to_le_bytes()]);
+        let iv = fit_to_size(iv, key_data.block_size);
+        decryptor.decrypt_cbc(&iv, segment);
+    }
+
+    return Ok(decrypted);
+}
+
+/// keyData and the password key encryptor of the xml descriptor.
+fn load_descriptor(descriptor: &[u8]) -> anyhow::Result<(AgileKeyData, AgileKeyData)> {
+    let mut reader = Reader::from_reader(descriptor);
+    let mut key_data: Option<AgileKeyData> = None;
+    let mut password_key: Option<AgileKeyData> = None;
+    let mut in_password_encryptor = false;
+
+    let mut buf = Vec::new();
+    loop {
+        buf.clear();
+
+        match reader.read_event_into(&mut buf) {
+            Ok(Event::Start(ref e) | Event::Empty(ref e)) => match e.local_name().as_ref() {
+                b"keyData" => key_data = Some(load_key_data(e)?),
+                b"keyEncryptor" => {
+                    in_password_encryptor = false;
+                    for a in e.attributes() {
+                        match a {
+                            Ok(a) => {
+                                if a.key.local_name().as_ref() == b"uri" {
+                                    in_password_encryptor =
+                                        a.value.as_ref() == PASSWORD_KEY_ENCRYPTOR.as_bytes();
+                                }
+                            }
+                            Err(error) => {
+                                bail!(error.to_string())
+                            }
+                        }
+                    }
+                }
+                b"encryptedKey" if in_password_encryptor => password_key = Some(load_key_data(e)?),
+                _ => (),
+            },
+            Ok(Event::Eof) => break,
+            Err(e) => bail!(e.to_string()),
+            _ => (),
+        }
+    }
+
+    let Some(key_data) = key_data else {
+        bail!("Invalid encryption info: keyData is missing.")
+    };
+    let Some(password_key) = password_key else {
+        bail!("Unsupported encryption: the workbook is not encrypted with a password.")
+    };
+    return Ok((key_data, password_key));
+}
+
+fn load_key_data(e: &quick_xml::events::BytesStart) -> anyhow::Result<AgileKeyData> {
+    let mut key_data = AgileKeyData::default();
+    for a in e.attributes() {
+        match a {
+            Ok(a) => {
+                let string_value = String::from_utf8(a.value.to_vec())?;
+                match a.key.local_name().as_ref() {
+                    b"spinCount" => key_data.spin_count = string_value.parse()?,
+                    b"saltSize" => key_data.salt_size = string_value.parse()?,
+                    b"blockSize" => key_data.block_size = string_value.parse()?,
+                    b"keyBits" => key_data.key_bits = string_value.parse()?,
+                    b"hashSize" => key_data.hash_size = string_value.parse()?,
+                    b"cipherAlgorithm" => key_data.cipher_algorithm = string_value,
+                    b"cipherChaining" => key_data.cipher_chaining = string_value,
+                    b"hashAlgorithm" => key_data.hash_algorithm = string_value,
+                    b"saltValue" => key_data.salt_value = decode_base64(&string_value)?,
+                    b"encryptedVerifierHashInput" => {
+                        key_data.encrypted_verifier_hash_input = decode_base64(&string_value)?
+                    }
+                    b"encryptedVerifierHashValue" => {
+                        key_data.encrypted_verifier_hash_value = decode_base64(&string_value)?
+                    }
+                    b"encryptedKeyValue" => {
+                        key_data.encrypted_key_value = decode_base64(&string_value)?
+                    }
+                    _ => (),
+                }
+            }
+            Err(error) => {
+                bail!(error.to_string())
+            }
+        }
+    }
+
+    return Ok(key_data);
+}
+
+/// Decode base64 (standard alphabet, with or without padding), whitespaces are ignored.
+fn decode_base64(value: &str) -> anyhow::Result<Vec<u8>> {
+    let mut decoded: Vec<u8> = Vec::with_capacity(value.len() * 3 / 4);
+    let mut buffer: u32 = 0;
+    let mut bits = 0;
+    for c in value.bytes() {
+        let sextet = match c {
+            b'A'..=b'Z' => c - b'A',
+            b'a'..=b'z' => c - b'a' + 26,
+            b'0'..=b'9' => c - b'0' + 52,
+            b'+' => 62,
+            b'/' => 63,
+            b'=' => break,
+            c if c.is_ascii_whitespace() => continue,
+            c => bail!("Invalid base64 character: {}", c as char),
+        };
+        buffer = (buffer << 6) | sextet as u32;
+        bits += 6;
+        if bits >= 8 {
+            bits -= 8;
+            decoded.push((buffer >> bits) as u8);
+        }
+    }
+    return Ok(decoded);
+}
@@ -0,0 +1,176 @@
+//! Encrypted workbooks: https://learn.microsoft.com/en-us/openspecs/office_file_formats/ms-offcrypto/3c34d72a-1a61-4b52-a893-196f9157f083
+//!
+//! A password protected workbook is a compound file (see `raw::cfb`) holding
+//! - `EncryptionInfo`: the encryption algorithms, and what is needed to derive and check the key from the password
+//! - `EncryptedPackage`: the size of the zip package (8 bytes), followed by the encrypted package
+//!
+//! Both encryptions used by Excel 2007 and later are supported, with AES only:
+//! - Standard encryption (version 3.2 or 4.2), see `standard`
+//! - Agile encryption (version 4.4), see `agile`
+//!
+//! The data integrity (HMAC) of agile encryption is not verified.
+
+use aes::{
+    cipher::{generic_array::GenericArray, BlockDecrypt, KeyInit},
+    Aes128, Aes192, Aes256,
+};
+use anyhow::bail;
+use sha1::{Digest, Sha1};
+use sha2::{Sha256, Sha384, Sha512};
+
+use super::cfb::CompoundFile;
+
+pub mod agile;
+pub mod standard;
+
+/// Size of the AES blocks.
+const AES_BLOCK_SIZE: usize = 16;
+
+/// Decrypt the zip package of an encrypted workbook.
+///
+/// data: content of the compound file
+pub(crate) fn decrypt_package(data: &[u8], password: &str) -> anyhow::Result<Vec<u8>> {
+    let file = CompoundFile::load(data)?;
+    let Some(info) = file.read_stream("EncryptionInfo")? else {
+        bail!("Not an encrypted workbook: the EncryptionInfo stream does not exist.")
+    };
+    let Some(package) = file.read_stream("EncryptedPackage")? else {
+        bail!("Not an encrypted workbook: the EncryptedPackage stream does not exist.")
+    };
+    if info.len() < 8 || package.len() < 8 {
+        bail!("Invalid encrypted workbook: streams are truncated.")
+    }
+
+    let major = u16::from_le_bytes([info[0], info[1]]);
+    let minor = u16::from_le_bytes([info[2], info[3]]);
+    let size = u64::from_le_bytes(package[0..8].try_into()?);
+    let encrypted = &package[8..];
+
+    let mut decrypted = match (major, minor) {
+        (4, 4) => agile::decrypt(&info[4..], password, encrypted)?,
+        (3 | 4, 2) => standard::decrypt(&info[4..], password, encrypted)?,
+        (major, minor) => bail!("Unsupported encryption version: {}.{}", major, minor),
+    };
+    if (decrypted.len() as u64) < size {
+        bail!("Invalid encrypted workbook: the package is truncated.")
+    }
+    decrypted.truncate(size as usize);
+
+    return Ok(decrypted);
+}
+
+/// Password as UTF-16LE bytes.
+fn password_bytes(password: &str) -> Vec<u8> {
+    return password
+        .encode_utf16()
+        .flat_map(|c| c.to_le_bytes())
+        .collect();
+}
+
+/// Hash algorithms of agile encryption, standard encryption uses SHA-1 only.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum HashAlgorithm {
+    Sha1,
+    Sha256,
+    Sha384,
+    Sha512,
+}
+
+impl HashAlgorithm {
+    fn from_name(name: &str) -> anyhow::Result<Self> {
+        return Ok(match name {
+            "SHA1" | "SHA-1" => Self::Sha1,
+            "SHA256" => Self::Sha256,
+            "SHA384" => Self::Sha384,
+            "SHA512" => Self::Sha512,
+            other => bail!("Unsupported hash algorithm: {}", other),
+        });
+    }
+
+    /// Hash of the concatenation of the parts.
+    fn hash(&self, parts: &[&[u8]]) -> Vec<u8> {
+        return match self {
+            Self::Sha1 => hash_parts::<Sha1>(parts),
+            Self::Sha256 => hash_parts::<Sha256>(parts),
+            Self::Sha384 => hash_parts::<Sha384>(parts),
+            Self::Sha512 => hash_parts::<Sha512>(parts),
+        };
+    }
+}
+
+fn hash_parts<D: Digest>(parts: &[&[u8]]) -> Vec<u8> {
+    let mut hasher = D::new();
+    for part in parts {
+        hasher.update(part);
+    }
+    return hasher.finalize().to_vec();
+}
+
+/// Password hash, iterated `spin_count` times: H0 = H(salt + password), Hn = H(n - 1 + Hn-1)
+fn iterated_hash(
+    algorithm: HashAlgorithm,
+    salt: &[u8],
+    password: &str,
+    spin_count: u32,
+) -> Vec<u8> {
+    let mut hash = algorithm.hash(&[salt, &password_bytes(password)]);
+    for iterator in 0..spin_count {
+        hash = algorithm.hash(&[&iterator.to_le_bytes(), &hash]);
+    }
+    return hash;
+}
+
+/// AES decryption with a 128, 192 or 256 bits key.
+enum AesDecryptor {
+    Aes128(Aes128),
+    Aes192(Aes192),
+    Aes256(Aes256),
+}
+
+impl AesDecryptor {
+    fn new(key: &[u8]) -> anyhow::Result<Self> {
+        return Ok(match key.len() {
+            16 => Self::Aes128(Aes128::new(GenericArray::from_slice(key))),
+            24 => Self::Aes192(Aes192::new(GenericArray::from_slice(key))),
+            32 => Self::Aes256(Aes256::new(GenericArray::from_slice(key))),
+            length => bail!("Unsupported key size: {} bits", length * 8),
+        });
+    }
+
+    fn decrypt_block(&self, block: &mut [u8]) {
+        let block = GenericArray::from_mut_slice(block);
+        match self {
+            Self::Aes128(cipher) => cipher.decrypt_block(block),
+            Self::Aes192(cipher) => cipher.decrypt_block(block),
+            Self::Aes256(cipher) => cipher.decrypt_block(block),
+        }
+    }
+
+    /// Decrypt in ECB mode, a trailing partial block is left as is.
+    fn decrypt_ecb(&self, data: &mut [u8]) {
+        for block in data.chunks_exact_mut(AES_BLOCK_SIZE) {
+            self.decrypt_block(block);
+        }
+    }
+
+    /// Decrypt in CBC mode, a trailing partial block is left as is.
+    fn decrypt_cbc(&self, iv: &[u8], data: &mut [u8]) {
+        let mut previous = [0u8; AES_BLOCK_SIZE];
+        previous.copy_from_slice(&iv[..AES_BLOCK_SIZE]);
+        for block in data.chunks_exact_mut(AES_BLOCK_SIZE) {
+            let mut encrypted = [0u8; AES_BLOCK_SIZE];
+            encrypted.copy_from_slice(block);
+            self.decrypt_block(block);
+            for (byte, previous) in block.iter_mut().zip(previous.iter()) {
+                *byte ^= previous;
+            }
+            previous = encrypted;
+        }
+    }
+}
+
+/// Truncate or pad (with 0x36) a value to a size, ie: hashes used as keys or initialization vectors.
+fn fit_to_size(mut value: Vec<u8>, size: usize) -> Vec<u8> {
+    value.resize(size, 0x36);
+    return value;
+}
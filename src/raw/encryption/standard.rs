@@ -0,0 +1,102 @@
+//! Standard encryption: https://learn.microsoft.com/en-us/openspecs/office_file_formats/ms-offcrypto/2895eba1-acb1-4624-9bde-2cdad3fea015
+//!
+//! The key is derived from the password with SHA-1, the package is encrypted with AES in ECB mode.
+
+use anyhow::bail;
+
+use super::{iterated_hash, AesDecryptor, HashAlgorithm, AES_BLOCK_SIZE};
+
+/// Number of iterations of the password hash.
+const SPIN_COUNT: u32 = 50_000;
+
+/// fAES flag of the encryption header: the cipher is AES.
+const AES_FLAG: u32 = 0x20;
+/// fExternal flag of the encryption header: an external (extensible) encryption is used.
+const EXTERNAL_FLAG: u32 = 0x10;
+
+/// AlgID of the AES ciphers
+const AES_128: u32 = 0x660E;
+const AES_192: u32 = 0x660F;
+const AES_256: u32 = 0x6610;
+
+/// Decrypt a package with standard encryption.
+///
+/// info: EncryptionInfo stream following the version
+pub(crate) fn decrypt(info: &[u8], password: &str, encrypted: &[u8]) -> anyhow::Result<Vec<u8>> {
+    // flags (4 bytes), header size (4 bytes), EncryptionHeader, EncryptionVerifier
+    let flags = read_u32(info, 0)?;
+    let header_size = read_u32(info, 4)? as usize;
+    if flags & EXTERNAL_FLAG != 0 || flags & AES_FLAG == 0 {
+        bail!("Unsupported encryption: only AES standard encryption is supported.")
+    }
+
+    // EncryptionHeader: flags, size extra, algorithm id, hash algorithm id, key size (bits), provider type, reserved, reserved, CSP name
+    let header = info.get(8..8 + header_size);
+    let Some(header) = header.filter(|h| h.len() >= 32) else {
+        bail!("Invalid encryption header.")
+    };
+    let algorithm_id = read_u32(header, 8)?;
+    let key_size = read_u32(header, 16)? as usize / 8;
+    if !matches!(algorithm_id, AES_128 | AES_192 | AES_256) {
+        bail!("Unsupported encryption algorithm: 0x{:04X}", algorithm_id)
+    }
+
+    // EncryptionVerifier: salt size, salt, encrypted verifier, verifier hash size, encrypted verifier hash
+    let verifier = &info[8 + header_size..];
+    let salt_size = read_u32(verifier, 0)? as usize;
+    let Some(salt) = verifier.get(4..4 + salt_size) else {
+        bail!("Invalid encryption verifier.")
+    };
+    let rest = &verifier[4 + salt_size..];
+    let (Some(encrypted_verifier), Ok(hash_size)) =
+        (rest.get(0..AES_BLOCK_SIZE), read_u32(rest, AES_BLOCK_SIZE))
+    else {
+        bail!("Invalid encryption verifier.")
+    };
+    let Some(encrypted_verifier_hash) = rest.get(AES_BLOCK_SIZE + 4..AES_BLOCK_SIZE + 4 + 32)
+    else {
+        bail!("Invalid encryption verifier.")
+    };
+
+    let key = derive_key(salt, password, key_size);
+    let decryptor = AesDecryptor::new(&key)?;
+
+    let mut verifier = encrypted_verifier.to_vec();
+    decryptor.decrypt_ecb(&mut verifier);
+    let mut verifier_hash = encrypted_verifier_hash.to_vec();
+    decryptor.decrypt_ecb(&mut verifier_hash);
+    let hash_size = (hash_size as usize).min(verifier_hash.len());
+    if HashAlgorithm::Sha1.hash(&[&verifier])[..] != verifier_hash[..hash_size] {
+        bail!("The password is incorrect.")
+    }
+
+    let mut decrypted = encrypted.to_vec();
+    decryptor.decrypt_ecb(&mut decrypted);
+    return Ok(decrypted);
+}
+
+/// Encryption key: https://learn.microsoft.com/en-us/openspecs/office_file_formats/ms-offcrypto/de8e0bc1-06f6-4c67-a5b8-43530e8d3a5b
+fn derive_key(salt: &[u8], password: &str, key_size: usize) -> Vec<u8> {
+    let sha1 = HashAlgorithm::Sha1;
+    let hash = iterated_hash(sha1, salt, password, SPIN_COUNT);
+    // block number 0
+    let hash = sha1.hash(&[&hash, &0u32.to_le_bytes()]);
+
+    let mut inner = [0x36u8; 64];
+    let mut outer = [0x5Cu8; 64];
+    for (index, byte) in hash.iter().enumerate() {
+        inner[index] ^= byte;
+        outer[index] ^= byte;
+    }
+    let mut key = sha1.hash(&[&inner]);
+    key.extend(sha1.hash(&[&outer]));
+    key.truncate(key_size);
+    return key;
+}
+
+fn read_u32(data: &[u8], offset: usize) -> anyhow::Result<u32> {
+    let Some(bytes) = data.get(offset..offset + 4) else {
+        bail!("Invalid encryption info: unexpected end of stream.")
+    };
+    return Ok(u32::from_le_bytes(bytes.try_into()?));
+}
@@ -1,2 +1,11 @@
+pub mod binary;
+#[cfg(any(feature = "xls", feature = "encryption"))]
+pub mod cfb;
 pub mod drawing;
+#[cfg(feature = "encryption")]
+pub mod encryption;
+#[cfg(feature = "ods")]
+pub mod ods;
 pub mod spreadsheet;
+#[cfg(feature = "xls")]
+pub mod xls;
@@ -0,0 +1,784 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    io::Read,
+};
+
+use anyhow::bail;
+use chrono::{NaiveDate, NaiveDateTime};
+use quick_xml::events::{BytesStart, Event};
+
+use crate::{
+    common_types::{Coordinate, Dimension, MAX_COLUMN_COUNT, MAX_ROW_COUNT},
+    excel::XmlReader,
+    helper::length_to_pt,
+    raw::spreadsheet::{
+        sheet::{
+            sheet_format_properties::XlsxSheetFormatProperties,
+            worksheet::{
+                cell::{cell_formula::XlsxCellFormula, cell_value::XlsxCellValue, XlsxCell},
+                column_information::XlsxColumnInformation,
+                row::XlsxRow,
+                sheet_data::XlsxSheetData,
+                XlsxWorksheet,
+            },
+        },
+        string_item::XlsxStringItem,
+    },
+    units::{pt_to_px, px_to_column_width, DEFAULT_DPI, DEFAULT_MAX_DIGIT_WIDTH},
+};
+
+use super::{load_attributes, repeat_count, styles::OdsStyles};
+
+/// Error values shared by ods and xlsx files, other errors (ie: `Err:502`) are read as `#VALUE!`.
+const ERROR_VALUES: [&str; 7] = [
+    "#DIV/0!", "#N/A", "#NAME?", "#NULL!", "#NUM!", "#REF!", "#VALUE!",
+];
+
+/// Cells a table may hold beyond the ones written in the file, from cells with a value repeated over rows and columns.
+///
+/// Repeats are placed one cell at a time, a few bytes could otherwise expand to the whole sheet.
+const MAX_REPEATED_CELLS: u64 = 1 << 20;
+
+/// A table (sheet) of `content.xml`.
+#[derive(Debug, Clone)]
+pub(crate) struct OdsTable {
+    /// table:name
+    pub(crate) name: String,
+    /// table:display of the table style
+    pub(crate) hidden: bool,
+    pub(crate) worksheet: XlsxWorksheet,
+}
+
+/// Load the tables of `content.xml`, and its automatic styles into `styles`.
+pub(crate) fn load_ods_content(
+    reader: &mut XmlReader<impl Read>,
+    styles: &mut OdsStyles,
+) -> anyhow::Result<Vec<OdsTable>> {
+    let mut tables: Vec<OdsTable> = vec![];
+    let mut buf = Vec::new();
+    loop {
+        buf.clear();
+
+        match reader.read_event_into(&mut buf) {
+            Ok(Event::Start(ref e)) if e.name().as_ref() == b"office:automatic-styles" => {
+                styles.load(reader, b"office:automatic-styles")?;
+            }
+            Ok(Event::Start(ref e)) if e.name().as_ref() == b"table:table" => {
+                tables.push(load_table(reader, e, styles)?);
+            }
+            Ok(Event::End(ref e)) if e.name().as_ref() == b"office:document-content" => break,
+            Ok(Event::Eof) => break,
+            Err(e) => bail!(e.to_string()),
+            _ => (),
+        }
+    }
+
+    return Ok(tables);
+}
+
+/// table:table-column, repeated over `first..=last`.
+struct OdsColumn {
+    first: u64,
+    last: u64,
+    /// width in points
+    width: Option<f64>,
+    hidden: bool,
+    /// table:default-cell-style-name: style of the cells of the column without style
+    default_cell_style: Option<String>,
+}
+
+/// table:table-row, repeated over `first..=last`.
+struct OdsRow {
+    first: u64,
+    last: u64,
+    /// height in points
+    height: Option<f64>,
+    custom_height: bool,
+    hidden: bool,
+}
+
+/// Cells, rows and columns of a table while it is read.
+#[derive(Default)]
+struct TableData {
+    columns: Vec<OdsColumn>,
+    rows: Vec<OdsRow>,
+    /// cells with a value or a formula, by row
+    cells: BTreeMap<u64, Vec<XlsxCell>>,
+    /// empty cells with a style: (first row, last row, first column, last column, style index)
+    styled_cells: Vec<(u64, u64, u64, u64, u64)>,
+    merge_cells: Vec<Dimension>,
+    /// (first row, first column, last row, last column) of the cells with a value and the merged cells
+    used_range: Option<(u64, u64, u64, u64)>,
+    /// cells placed by repeating a cell, see `MAX_REPEATED_CELLS`
+    repeated_cells: u64,
+}
+
+impl TableData {
+    fn extend_used_range(&mut self, dimension: Dimension) {
+        let (start, end) = (dimension.start, dimension.end);
+        self.used_range = Some(match self.used_range {
+            None => (start.row, start.col, end.row, end.col),
+            Some((first_row, first_col, last_row, last_col)) => (
+                first_row.min(start.row),
+                first_col.min(start.col),
+                last_row.max(end.row),
+                last_col.max(end.col),
+            ),
+        });
+    }
+
+    fn default_cell_style(&self, col: u64) -> Option<&str> {
+        return self
+            .columns
+            .iter()
+            .find(|c| c.first <= col && col <= c.last)
+            .and_then(|c| c.default_cell_style.as_deref());
+    }
+}
+
+/// table:table
+///
+/// Example:
+/// ```
+/// <table:table table:name="Sheet1" table:style-name="ta1">
+///     <table:table-column table:style-name="co1" table:number-columns-repeated="2" table:default-cell-style-name="Default" />
+///     <table:table-row table:style-name="ro1">
+///         <table:table-cell office:value-type="float" office:value="1" calcext:value-type="float">
+///             <text:p>1</text:p>
+///         </table:table-cell>
+///         <table:table-cell table:formula="of:=[.A1]*2" office:value-type="float" office:value="2">
+///             <text:p>2</text:p>
+///         </table:table-cell>
+///     </table:table-row>
+/// </table:table>
+/// ```
+///
+/// Only the cells within the range of the cells with a value (and merged cells) are kept,
+/// ods files usually repeat empty rows and columns up to the last row and column of the sheet.
+fn load_table(
+    reader: &mut XmlReader<impl Read>,
+    e: &BytesStart,
+    styles: &mut OdsStyles,
+) -> anyhow::Result<OdsTable> {
+    let attributes = load_attributes(e)?;
+    let name = attributes.get("table:name").cloned().unwrap_or_default();
+    let table_style = styles.resolve(
+        "table",
+        attributes.get("table:style-name").map(|s| s.as_str()),
+    );
+    let hidden = table_style
+        .properties
+        .get("table:display")
+        .map(|d| d.as_str())
+        == Some("false");
+
+    let mut data = TableData::default();
+    let mut next_col = 1;
+    let mut next_row = 1;
+    let mut depth = 0;
+    let mut buf = Vec::new();
+    loop {
+        buf.clear();
+
+        match reader.read_event_into(&mut buf) {
+            // sub tables are not supported
+            Ok(Event::Start(ref e)) if e.name().as_ref() == b"table:table" => depth += 1,
+            Ok(Event::End(ref e)) if e.name().as_ref() == b"table:table" => {
+                if depth == 0 {
+                    break;
+                }
+                depth -= 1;
+            }
+            Ok(Event::Eof) => bail!("unexpected end of file."),
+            Err(e) => bail!(e.to_string()),
+            _ if depth > 0 => (),
+            Ok(Event::Start(ref e)) if e.name().as_ref() == b"table:table-column" => {
+                let attributes = load_attributes(e)?;
+                let count = repeat_count(&attributes, "table:number-columns-repeated");
+                let style = styles.resolve(
+                    "table-column",
+                    attributes.get("table:style-name").map(|s| s.as_str()),
+                );
+                data.columns.push(OdsColumn {
+                    first: next_col,
+                    last: next_col.saturating_add(count - 1),
+                    width: style
+                        .properties
+                        .get("style:column-width")
+                        .and_then(|w| length_to_pt(w)),
+                    hidden: is_collapsed(&attributes),
+                    default_cell_style: attributes.get("table:default-cell-style-name").cloned(),
+                });
+                next_col = next_col.saturating_add(count);
+            }
+            Ok(Event::Start(ref e)) if e.name().as_ref() == b"table:table-row" => {
+                let count = load_row(reader, e, next_row, styles, &mut data)?;
+                next_row = next_row.saturating_add(count);
+            }
+            _ => (),
+        }
+    }
+
+    return Ok(OdsTable {
+        name,
+        hidden,
+        worksheet: build_worksheet(data, styles),
+    });
+}
+
+/// table:table-row starting at `row_index`, returns the number of rows it is repeated over.
+fn load_row(
+    reader: &mut XmlReader<impl Read>,
+    e: &BytesStart,
+    row_index: u64,
+    styles: &mut OdsStyles,
+    data: &mut TableData,
+) -> anyhow::Result<u64> {
+    let attributes = load_attributes(e)?;
+    let count = repeat_count(&attributes, "table:number-rows-repeated");
+    let last_row = row_index.saturating_add(count - 1).min(MAX_ROW_COUNT);
+    let style = styles.resolve(
+        "table-row",
+        attributes.get("table:style-name").map(|s| s.as_str()),
+    );
+    data.rows.push(OdsRow {
+        first: row_index,
+        last: last_row,
+        height: style
+            .properties
+            .get("style:row-height")
+            .and_then(|h| length_to_pt(h)),
+        custom_height: style
+            .properties
+            .get("style:use-optimal-row-height")
+            .map(|o| o.as_str())
+            == Some("false"),
+        hidden: is_collapsed(&attributes),
+    });
+
+    let mut col_index: u64 = 1;
+    let mut buf = Vec::new();
+    loop {
+        buf.clear();
+
+        match reader.read_event_into(&mut buf) {
+            Ok(Event::Start(ref e))
+                if e.name().as_ref() == b"table:table-cell"
+                    || e.name().as_ref() == b"table:covered-table-cell" =>
+            {
+                let attributes = load_attributes(e)?;
+                let columns = repeat_count(&attributes, "table:number-columns-repeated");
+                let cell = load_cell(reader, e, &attributes)?;
+                let last_col = col_index.saturating_add(columns - 1).min(MAX_COLUMN_COUNT);
+                if row_index <= MAX_ROW_COUNT && col_index <= MAX_COLUMN_COUNT {
+                    add_cell(
+                        &cell,
+                        &attributes,
+                        (row_index, last_row),
+                        (col_index, last_col),
+                        styles,
+                        data,
+                    );
+                }
+                col_index = col_index.saturating_add(columns);
+            }
+            Ok(Event::End(ref e)) if e.name().as_ref() == b"table:table-row" => break,
+            Ok(Event::Eof) => bail!("unexpected end of file."),
+            Err(e) => bail!(e.to_string()),
+            _ => (),
+        }
+    }
+
+    return Ok(count);
+}
+
+/// table:visibility: visible, collapse or filter.
+fn is_collapsed(attributes: &HashMap<String, String>) -> bool {
+    return matches!(
+        attributes.get("table:visibility").map(|v| v.as_str()),
+        Some("collapse" | "filter")
+    );
+}
+
+/// Value of a cell, before it is placed on the rows and columns it is repeated over.
+struct OdsCell {
+    /// (cell type, value) of the xlsx cell, ie: ("n", "1.5"), ("inlineStr", text)
+    value: Option<(&'static str, String)>,
+    formula: Option<String>,
+    /// office:value-type
+    value_type: Option<String>,
+    /// whether a date value has a time part
+    has_time: bool,
+}
+
+/// table:table-cell or table:covered-table-cell
+fn load_cell(
+    reader: &mut XmlReader<impl Read>,
+    e: &BytesStart,
+    attributes: &HashMap<String, String>,
+) -> anyhow::Result<OdsCell> {
+    let text = load_cell_text(reader, e)?;
+    let attribute = |name: &str| attributes.get(name).map(|v| v.as_str());
+    let value_type = attribute("office:value-type");
+    let mut has_time = false;
+
+    let value = if attribute("calcext:value-type") == Some("error") {
+        let error = if ERROR_VALUES.contains(&text.as_str()) {
+            text
+        } else {
+            "#VALUE!".to_string()
+        };
+        Some(("e", error))
+    } else {
+        match value_type {
+            Some("float" | "percentage" | "currency") => {
+                attribute("office:value").map(|v| ("n", v.to_string()))
+            }
+            Some("date") => {
+                attribute("office:date-value")
+                    .and_then(date_to_serial)
+                    .map(|(serial, time)| {
+                        has_time = time;
+                        ("n", serial.to_string())
+                    })
+            }
+            Some("time") => attribute("office:time-value")
+                .and_then(duration_to_days)
+                .map(|days| ("n", days.to_string())),
+            Some("boolean") => attribute("office:boolean-value")
+                .map(|v| ("b", if v == "true" { "1" } else { "0" }.to_string())),
+            Some("string") => Some((
+                "inlineStr",
+                attribute("office:string-value")
+                    .map(|s| s.to_string())
+                    .unwrap_or(text),
+            )),
+            _ if !text.is_empty() => Some(("inlineStr", text)),
+            _ => None,
+        }
+    };
+
+    let formula = attribute("table:formula").map(openformula_to_a1);
+    // string results of formulas are values, as `t="str"` cells of xlsx files
+    let value = match value {
+        Some(("inlineStr", text)) if formula.is_some() => Some(("str", text)),
+        value => value,
+    };
+
+    return Ok(OdsCell {
+        value,
+        formula,
+        value_type: value_type.map(|v| v.to_string()),
+        has_time,
+    });
+}
+
+/// Text of the paragraphs of a cell, separated by line breaks. Annotations are skipped.
+fn load_cell_text(reader: &mut XmlReader<impl Read>, e: &BytesStart) -> anyhow::Result<String> {
+    let end = e.name().as_ref().to_vec();
+    let mut text = String::new();
+    let mut paragraphs = 0;
+    let mut paragraph_depth = 0;
+    let mut annotation_depth = 0;
+    let mut buf = Vec::new();
+    loop {
+        buf.clear();
+
+        match reader.read_event_into(&mut buf) {
+            Ok(Event::Start(ref e)) if e.name().as_ref() == b"office:annotation" => {
+                annotation_depth += 1
+            }
+            Ok(Event::End(ref e)) if e.name().as_ref() == b"office:annotation" => {
+                annotation_depth -= 1
+            }
+            Ok(Event::End(ref e)) if e.name().as_ref() == end.as_slice() => break,
+            Ok(Event::Start(_) | Event::End(_) | Event::Text(_)) if annotation_depth > 0 => (),
+            Ok(Event::Start(ref e)) => match e.name().as_ref() {
+                b"text:p" | b"text:h" => {
+                    if paragraphs > 0 && paragraph_depth == 0 {
+                        text.push('\n');
+                    }
+                    paragraphs += 1;
+                    paragraph_depth += 1;
+                }
+                b"text:s" => {
+                    let attributes = load_attributes(e)?;
+                    let count = repeat_count(&attributes, "text:c").min(1024);
+                    text.push_str(&" ".repeat(count as usize));
+                }
+                b"text:tab" => text.push('\t'),
+                b"text:line-break" => text.push('\n'),
+                _ => (),
+            },
+            Ok(Event::End(ref e)) if matches!(e.name().as_ref(), b"text:p" | b"text:h") => {
+                paragraph_depth -= 1;
+            }
+            Ok(Event::Text(t)) if paragraph_depth > 0 => text.push_str(&t.unescape()?),
+            Ok(Event::Eof) => bail!("unexpected end of file."),
+            Err(e) => bail!(e.to_string()),
+            _ => (),
+        }
+    }
+
+    return Ok(text);
+}
+
+/// Place a cell on the rows and columns it is repeated over.
+fn add_cell(
+    cell: &OdsCell,
+    attributes: &HashMap<String, String>,
+    (first_row, last_row): (u64, u64),
+    (first_col, last_col): (u64, u64),
+    styles: &mut OdsStyles,
+    data: &mut TableData,
+) {
+    let columns_spanned = repeat_count(attributes, "table:number-columns-spanned");
+    let rows_spanned = repeat_count(attributes, "table:number-rows-spanned");
+    let has_content = cell.value.is_some() || cell.formula.is_some();
+    let explicit_style = attributes.get("table:style-name").map(|s| s.as_str());
+
+    // columns may have different default styles, cells without style are placed one column at a time
+    let mut placed = false;
+    let mut col = first_col;
+    while col <= last_col {
+        let (style, last) = match explicit_style {
+            Some(style) => (Some(style), last_col),
+            None => (data.default_cell_style(col), col),
+        };
+        let style = style.map(|s| s.to_string());
+
+        if has_content || columns_spanned > 1 || rows_spanned > 1 {
+            let style_index =
+                styles.cell_format(style.as_deref(), cell.value_type.as_deref(), cell.has_time);
+            for row in first_row..=last_row {
+                for col in col..=last {
+                    if placed {
+                        if data.repeated_cells >= MAX_REPEATED_CELLS {
+                            return;
+                        }
+                        data.repeated_cells += 1;
+                    }
+                    placed = true;
+                    if columns_spanned > 1 || rows_spanned > 1 {
+                        let merge = Dimension {
+                            start: Coordinate::from_point((row, col)),
+                            end: Coordinate::from_point((
+                                row.saturating_add(rows_spanned - 1).min(MAX_ROW_COUNT),
+                                col.saturating_add(columns_spanned - 1)
+                                    .min(MAX_COLUMN_COUNT),
+                            )),
+                        };
+                        data.merge_cells.push(merge);
+                        data.extend_used_range(merge);
+                    }
+                    if has_content {
+                        data.extend_used_range(Dimension {
+                            start: Coordinate::from_point((row, col)),
+                            end: Coordinate::from_point((row, col)),
+                        });
+                    }
+                    data.cells
+                        .entry(row)
+                        .or_default()
+                        .push(new_cell(cell, row, col, style_index));
+                }
+            }
+        } else if style.as_deref().is_some_and(|s| s != "Default") {
+            let style_index = styles.cell_format(style.as_deref(), None, false);
+            data.styled_cells
+                .push((first_row, last_row, col, last, style_index));
+        }
+        col = last + 1;
+    }
+}
+
+fn new_cell(cell: &OdsCell, row: u64, col: u64, style_index: u64) -> XlsxCell {
+    let mut xlsx_cell = XlsxCell::new(Coordinate::from_point((row, col)));
+    xlsx_cell.style = Some(style_index);
+    xlsx_cell.formula = cell.formula.as_ref().map(|formula| XlsxCellFormula {
+        raw_value: formula.clone(),
+        always_calculate_array: None,
+        assign_value_to_name: None,
+        recalculate_cell: None,
+        input_1_deleted: None,
+        input_2_deleted: None,
+        data_table_2d: None,
+        data_table_row: None,
+        data_table_cell1: None,
+        data_table_cell2: None,
+        ref_range: None,
+        shared_group_index: None,
+        r#type: None,
+    });
+    match &cell.value {
+        Some(("inlineStr", text)) => {
+            xlsx_cell.r#type = Some("inlineStr".to_string());
+            xlsx_cell.inline_string = Some(XlsxStringItem {
+                text: Some(text.clone()),
+                ..Default::default()
+            });
+        }
+        Some((r#type, value)) => {
+            xlsx_cell.r#type = Some(r#type.to_string());
+            xlsx_cell.cell_value = Some(XlsxCellValue {
+                raw_value: value.clone(),
+                space: None,
+            });
+        }
+        None => (),
+    }
+    return xlsx_cell;
+}
+
+/// Raw worksheet from the cells, rows and columns of a table, limited to its used range.
+fn build_worksheet(data: TableData, styles: &mut OdsStyles) -> XlsxWorksheet {
+    // trailing rows and columns repeated up to the end of the sheet hold the default sizes
+    let mut worksheet = XlsxWorksheet {
+        sheet_format_properties: Some(XlsxSheetFormatProperties {
+            default_row_height: data.rows.last().and_then(|r| r.height),
+            default_col_width: data
+                .columns
+                .last()
+                .and_then(|c| c.width)
+                .map(pt_to_column_width),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+
+    let columns: Vec<XlsxColumnInformation> = data
+        .columns
+        .iter()
+        .filter(|c| c.first <= MAX_COLUMN_COUNT)
+        .map(|c| XlsxColumnInformation {
+            best_fit: None,
+            collapsed: None,
+            custom_width: Some(c.width.is_some()),
+            hidden: Some(c.hidden),
+            max_column: Some(c.last.min(MAX_COLUMN_COUNT)),
+            min_column: Some(c.first),
+            outline_level: None,
+            show_phonetic: None,
+            style: c
+                .default_cell_style
+                .as_deref()
+                .filter(|s| *s != "Default")
+                .map(|s| styles.cell_format(Some(s), None, false)),
+            width: c.width.map(pt_to_column_width),
+        })
+        .collect();
+    if !columns.is_empty() {
+        worksheet.column_infos = Some(columns);
+    }
+    if !data.merge_cells.is_empty() {
+        worksheet.merge_cells = Some(data.merge_cells);
+    }
+
+    let Some((first_row, first_col, last_row, last_col)) = data.used_range else {
+        worksheet.sheet_data = Some(XlsxSheetData { rows: Some(vec![]) });
+        return worksheet;
+    };
+    worksheet.dimension = Some(Dimension {
+        start: Coordinate::from_point((first_row, first_col)),
+        end: Coordinate::from_point((last_row, last_col)),
+    });
+
+    let mut rows: BTreeMap<u64, XlsxRow> = BTreeMap::new();
+    for row in data.rows.iter() {
+        for index in row.first.max(first_row)..=row.last.min(last_row) {
+            let entry = row_entry(&mut rows, index);
+            entry.height = row.height;
+            entry.custom_height = Some(row.custom_height);
+            entry.hidden = Some(row.hidden);
+        }
+    }
+    for (index, cells) in data.cells {
+        row_entry(&mut rows, index)
+            .cells
+            .get_or_insert_with(Vec::new)
+            .extend(cells);
+    }
+    for (row_start, row_end, col_start, col_end, style) in data.styled_cells {
+        for index in row_start.max(first_row)..=row_end.min(last_row) {
+            let cells = row_entry(&mut rows, index)
+                .cells
+                .get_or_insert_with(Vec::new);
+            for col in col_start.max(first_col)..=col_end.min(last_col) {
+                let mut cell = XlsxCell::new(Coordinate::from_point((index, col)));
+                cell.style = Some(style);
+                cells.push(cell);
+            }
+        }
+    }
+
+    let rows = rows
+        .into_values()
+        .map(|mut row| {
+            if let Some(cells) = row.cells.as_mut() {
+                cells.sort_by_key(|c| c.coordinate().map(|c| c.col));
+            }
+            row
+        })
+        .collect();
+    worksheet.sheet_data = Some(XlsxSheetData { rows: Some(rows) });
+
+    return worksheet;
+}
+
+fn row_entry(rows: &mut BTreeMap<u64, XlsxRow>, index: u64) -> &mut XlsxRow {
+    return rows.entry(index).or_insert_with(|| XlsxRow {
+        row_index: Some(index),
+        ..Default::default()
+    });
+}
+
+fn pt_to_column_width(pt: f64) -> f64 {
+    return px_to_column_width(pt_to_px(pt, DEFAULT_DPI), DEFAULT_MAX_DIGIT_WIDTH);
+}
+
+/// office:date-value as a serial number of the 1900 date system, and whether it has a time part.
+///
+/// ie: `2024-01-15` -> 45306, `2024-01-15T12:00:00` -> 45306.5
+fn date_to_serial(value: &str) -> Option<(f64, bool)> {
+    let epoch = NaiveDate::from_ymd_opt(1899, 12, 30)?.and_hms_opt(0, 0, 0)?;
+    let value = value.trim_end_matches('Z');
+    let date_time = match NaiveDateTime::parse_from_str(value, "%Y-%m-%dT%H:%M:%S%.f") {
+        Ok(date_time) => date_time,
+        Err(_) => NaiveDate::parse_from_str(value, "%Y-%m-%d")
+            .ok()?
+            .and_hms_opt(0, 0, 0)?,
+    };
+    let milliseconds = (date_time - epoch).num_milliseconds();
+    let has_time = date_time.time() != epoch.time();
+    return Some((milliseconds as f64 / 86_400_000.0, has_time));
+}
+
+/// office:time-value (ISO 8601 duration) as a number of days, ie: `PT12H30M00S` -> 0.5208333
+fn duration_to_days(value: &str) -> Option<f64> {
+    let (sign, value) = match value.strip_prefix('-') {
+        Some(value) => (-1.0, value),
+        None => (1.0, value),
+    };
+    let value = value.strip_prefix('P')?;
+    let mut seconds = 0.0;
+    let mut number = String::new();
+    for c in value.chars() {
+        match c {
+            '0'..='9' | '.' => number.push(c),
+            'T' => (),
+            'D' | 'H' | 'M' | 'S' => {
+                let n: f64 = number.parse().ok()?;
+                number.clear();
+                seconds += n * match c {
+                    'D' => 86_400.0,
+                    'H' => 3_600.0,
+                    'M' => 60.0,
+                    _ => 1.0,
+                };
+            }
+            _ => return None,
+        }
+    }
+    return Some(sign * seconds / 86_400.0);
+}
+
+/// OpenFormula to the A1 syntax of xlsx formulas.
+///
+/// - the namespace and `=` are removed: `of:=SUM([.A1:.B2])` -> `SUM(A1:B2)`
+/// - references lose their brackets and use `!` after the sheet name: `[$Sheet2.A1]` -> `Sheet2!A1`, `['My sheet'.A1:.B2]` -> `'My sheet'!A1:B2`
+/// - arguments are separated by `,` instead of `;`
+pub(crate) fn openformula_to_a1(formula: &str) -> String {
+    let formula = match formula.split_once(":=") {
+        Some((namespace, formula)) if namespace.chars().all(|c| c.is_ascii_alphanumeric()) => {
+            formula
+        }
+        _ => formula.trim_start_matches('='),
+    };
+
+    let mut converted = String::with_capacity(formula.len());
+    let mut chars = formula.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            // string literal, with quotes escaped by doubling them
+            '"' => {
+                converted.push(c);
+                while let Some(c) = chars.next() {
+                    converted.push(c);
+                    if c == '"' {
+                        match chars.next_if_eq(&'"') {
+                            Some(quote) => converted.push(quote),
+                            None => break,
+                        }
+                    }
+                }
+            }
+            '[' => {
+                let mut reference = String::new();
+                let mut quoted = false;
+                for c in chars.by_ref() {
+                    if c == '\'' {
+                        quoted = !quoted;
+                    } else if c == ']' && !quoted {
+                        break;
+                    }
+                    reference.push(c);
+                }
+                converted.push_str(&reference_to_a1(&reference));
+            }
+            ';' => converted.push(','),
+            _ => converted.push(c),
+        }
+    }
+    return converted;
+}
+
+/// `.A1`, `$Sheet1.A1:.B2`, `'My sheet'.A1` to A1 references.
+fn reference_to_a1(reference: &str) -> String {
+    let mut parts: Vec<String> = vec![];
+    let mut first_sheet: Option<String> = None;
+    for part in split_unquoted(reference, ':') {
+        let (sheet, cell) = match rsplit_unquoted(part, '.') {
+            Some((sheet, cell)) => (sheet.trim_start_matches('$'), cell),
+            None => ("", part),
+        };
+        if sheet.is_empty() || first_sheet.as_deref() == Some(sheet) {
+            parts.push(cell.to_string());
+        } else {
+            parts.push(format!("{}!{}", sheet, cell));
+        }
+        if first_sheet.is_none() {
+            first_sheet = Some(sheet.to_string());
+        }
+    }
+    return parts.join(":");
+}
+
+fn split_unquoted(value: &str, separator: char) -> Vec<&str> {
+    let mut parts: Vec<&str> = vec![];
+    let mut quoted = false;
+    let mut start = 0;
+    for (index, c) in value.char_indices() {
+        if c == '\'' {
+            quoted = !quoted;
+        } else if c == separator && !quoted {
+            parts.push(&value[start..index]);
+            start = index + c.len_utf8();
+        }
+    }
+    parts.push(&value[start..]);
+    return parts;
+}
+
+fn rsplit_unquoted(value: &str, separator: char) -> Option<(&str, &str)> {
+    let mut quoted = false;
+    let mut found = None;
+    for (index, c) in value.char_indices() {
+        if c == '\'' {
+            quoted = !quoted;
+        } else if c == separator && !quoted {
+            found = Some(index);
+        }
+    }
+    let index = found?;
+    return Some((&value[..index], &value[index + separator.len_utf8()..]));
+}
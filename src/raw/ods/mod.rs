@@ -0,0 +1,48 @@
+//! OpenDocument Spreadsheet (.ods): https://docs.oasis-open.org/office/OpenDocument/v1.3/os/part3-schema/OpenDocument-v1.3-os-part3-schema.html
+//!
+//! An ods file is a zip package holding the sheets in `content.xml` and the named styles in `styles.xml`.
+//! Tables, cell styles and data styles are parsed into the same raw structures as their xlsx counterparts,
+//! so that the processed layer works on both formats:
+//! - styles: cell styles and their parents, with their font, fill, borders, alignment, protection and data style (number format)
+//! - tables: name, visibility, columns, rows, cells and merged cells
+//!
+//! Formulas are converted from OpenFormula to the A1 syntax of xlsx files, ie: `of:=SUM([.A1:.B2])` -> `SUM(A1:B2)`.
+//! Annotations, hyperlinks, conditional formats and content validations are not parsed.
+//!
+//! Elements and attributes are matched on their qualified name with the prefixes used by the specification, ie: `table:name`.
+
+use std::collections::HashMap;
+
+use anyhow::bail;
+use quick_xml::{escape::unescape, events::BytesStart};
+
+pub mod content;
+pub mod styles;
+
+/// Attributes of an element by qualified name, ie: `table:style-name`.
+pub(crate) fn load_attributes(e: &BytesStart) -> anyhow::Result<HashMap<String, String>> {
+    let mut attributes: HashMap<String, String> = HashMap::new();
+    for a in e.attributes() {
+        match a {
+            Ok(a) => {
+                let key = String::from_utf8(a.key.as_ref().to_vec())?;
+                let value = unescape(&String::from_utf8(a.value.to_vec())?)?.to_string();
+                attributes.insert(key, value);
+            }
+            Err(error) => {
+                bail!(error.to_string())
+            }
+        }
+    }
+
+    return Ok(attributes);
+}
+
+/// Repeat count, ie: `table:number-columns-repeated`, at least 1.
+pub(crate) fn repeat_count(attributes: &HashMap<String, String>, name: &str) -> u64 {
+    return attributes
+        .get(name)
+        .and_then(|v| v.parse::<u64>().ok())
+        .unwrap_or(1)
+        .max(1);
+}
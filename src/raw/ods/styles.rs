@@ -0,0 +1,672 @@
+use std::{collections::HashMap, io::Read};
+
+use anyhow::bail;
+use quick_xml::events::{BytesStart, Event};
+
+use crate::{
+    excel::XmlReader,
+    helper::{extract_text_contents, length_to_pt},
+    raw::spreadsheet::stylesheet::{
+        border::{XlsxBorder, XlsxBorderStyle},
+        color::XlsxColor,
+        fill::{pattern_fill::XlsxPatternFill, XlsxFill},
+        font::XlsxFont,
+        format::{
+            alignment::XlsxAlignment, cell_format::XlsxCellFormat,
+            numbering_format::XlsxNumberingFormat, protection::XlsxCellProtection,
+        },
+        XlsxStyleSheet,
+    },
+};
+
+use super::load_attributes;
+
+/// First id of the number formats defined by the file, lower ids are built-in formats.
+const FIRST_CUSTOM_NUMBER_FORMAT_ID: u64 = 164;
+
+/// Name of the cell style used by cells without style.
+const DEFAULT_CELL_STYLE: &str = "Default";
+
+/// Elements holding the properties of a style.
+const PROPERTIES_ELEMENTS: [&[u8]; 6] = [
+    b"style:text-properties",
+    b"style:paragraph-properties",
+    b"style:table-cell-properties",
+    b"style:table-column-properties",
+    b"style:table-row-properties",
+    b"style:table-properties",
+];
+
+/// Elements of the data styles.
+const DATA_STYLE_ELEMENTS: [&[u8]; 7] = [
+    b"number:number-style",
+    b"number:currency-style",
+    b"number:percentage-style",
+    b"number:date-style",
+    b"number:time-style",
+    b"number:boolean-style",
+    b"number:text-style",
+];
+
+/// style:style or style:default-style
+///
+/// Example:
+/// ```
+/// <style:style style:name="ce1" style:family="table-cell" style:parent-style-name="Default" style:data-style-name="N2">
+///     <style:table-cell-properties fo:background-color="#ffff00" />
+///     <style:text-properties fo:font-weight="bold" fo:color="#c9211e" />
+/// </style:style>
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub(crate) struct OdsStyle {
+    /// style:parent-style-name
+    pub(crate) parent: Option<String>,
+
+    /// style:data-style-name: name of the data style (number format)
+    pub(crate) data_style: Option<String>,
+
+    /// Attributes of the properties elements by qualified name, ie: `fo:background-color`.
+    pub(crate) properties: HashMap<String, String>,
+}
+
+/// Styles of `styles.xml` and `content.xml`, and the stylesheet of the cell formats used by the cells.
+///
+/// A cell format is added for each (cell style, number format) used, the number format depending on the value type of the cell
+/// when the style has no data style.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct OdsStyles {
+    /// (family, name) -> style
+    styles: HashMap<(String, String), OdsStyle>,
+    /// family -> default style
+    default_styles: HashMap<String, OdsStyle>,
+    /// data style name -> format code
+    data_styles: HashMap<String, String>,
+
+    fonts: Vec<XlsxFont>,
+    fills: Vec<XlsxFill>,
+    borders: Vec<XlsxBorder>,
+    numbering_formats: Vec<XlsxNumberingFormat>,
+    cell_formats: Vec<XlsxCellFormat>,
+    /// (cell style, number format id) -> index of the cell format
+    cell_format_indexes: HashMap<(Option<String>, u64), u64>,
+}
+
+impl OdsStyles {
+    /// Load the styles of a container (office:styles or office:automatic-styles) until its end.
+    pub(crate) fn load(
+        &mut self,
+        reader: &mut XmlReader<impl Read>,
+        end: &[u8],
+    ) -> anyhow::Result<()> {
+        let mut buf = Vec::new();
+        loop {
+            buf.clear();
+
+            match reader.read_event_into(&mut buf) {
+                Ok(Event::Start(ref e)) if e.name().as_ref() == b"style:style" => {
+                    let attributes = load_attributes(e)?;
+                    let style = load_style(reader, e, &attributes)?;
+                    let (Some(family), Some(name)) =
+                        (attributes.get("style:family"), attributes.get("style:name"))
+                    else {
+                        continue;
+                    };
+                    self.styles
+                        .insert((family.to_string(), name.to_string()), style);
+                }
+                Ok(Event::Start(ref e)) if e.name().as_ref() == b"style:default-style" => {
+                    let attributes = load_attributes(e)?;
+                    let style = load_style(reader, e, &attributes)?;
+                    if let Some(family) = attributes.get("style:family") {
+                        self.default_styles.insert(family.to_string(), style);
+                    }
+                }
+                Ok(Event::Start(ref e)) if DATA_STYLE_ELEMENTS.contains(&e.name().as_ref()) => {
+                    let (name, format_code) = load_data_style(reader, e)?;
+                    if let Some(name) = name {
+                        self.data_styles.insert(name, format_code);
+                    }
+                }
+                Ok(Event::End(ref e)) if e.name().as_ref() == end => break,
+                Ok(Event::Eof) => bail!("unexpected end of file."),
+                Err(e) => bail!(e.to_string()),
+                _ => (),
+            }
+        }
+
+        return Ok(());
+    }
+
+    /// Properties of a style merged with its parents and the default style of its family, the closest taking precedence.
+    pub(crate) fn resolve(&self, family: &str, name: Option<&str>) -> OdsStyle {
+        let mut chain: Vec<&OdsStyle> = vec![];
+        let mut next = name.map(|n| n.to_string());
+        while let Some(name) = next.take() {
+            // parents cannot loop back, stop on malformed files
+            if chain.len() > 32 {
+                break;
+            }
+            let Some(style) = self.styles.get(&(family.to_string(), name)) else {
+                break;
+            };
+            chain.push(style);
+            next = style.parent.clone();
+        }
+
+        let mut resolved = self.default_styles.get(family).cloned().unwrap_or_default();
+        for style in chain.iter().rev() {
+            resolved.properties.extend(style.properties.clone());
+            if style.data_style.is_some() {
+                resolved.data_style = style.data_style.clone();
+            }
+        }
+        resolved.parent = None;
+        return resolved;
+    }
+
+    /// Index of the cell format of a cell within the stylesheet, added when first used.
+    ///
+    /// * style: name of the cell style, None for the default cell style.
+    /// * value_type: office:value-type of the cell
+    /// * has_time: whether a date value has a time part
+    pub(crate) fn cell_format(
+        &mut self,
+        style: Option<&str>,
+        value_type: Option<&str>,
+        has_time: bool,
+    ) -> u64 {
+        let style = style.unwrap_or(DEFAULT_CELL_STYLE);
+        let resolved = self.resolve("table-cell", Some(style));
+        let num_fmt_id = match resolved
+            .data_style
+            .as_ref()
+            .and_then(|s| self.data_styles.get(s))
+            .cloned()
+        {
+            Some(format_code) => self.number_format_id(format_code),
+            None => default_number_format_id(value_type, has_time),
+        };
+
+        let key = (Some(style.to_string()), num_fmt_id);
+        if let Some(index) = self.cell_format_indexes.get(&key) {
+            return *index;
+        }
+
+        let properties = &resolved.properties;
+        let font = load_font(properties);
+        let fill = load_fill(properties);
+        let border = load_border(properties);
+        let font_id = index_of(&mut self.fonts, font);
+        let fill_id = match fill {
+            Some(fill) => {
+                self.ensure_default_fills();
+                index_of(&mut self.fills, fill)
+            }
+            None => 0,
+        };
+        let border_id = index_of(&mut self.borders, border);
+
+        let index = self.cell_formats.len() as u64;
+        self.cell_formats.push(XlsxCellFormat {
+            alignment: Some(load_alignment(properties)),
+            protection: Some(load_protection(properties)),
+            apply_alignment: Some(true),
+            apply_border: Some(true),
+            apply_fill: Some(true),
+            apply_font: Some(true),
+            apply_number_format: Some(true),
+            apply_protection: Some(true),
+            border_id: Some(border_id),
+            fill_id: Some(fill_id),
+            font_id: Some(font_id),
+            num_fmt_id: Some(num_fmt_id),
+            pivot_button: None,
+            quote_prefix: None,
+            xf_id: Some(0),
+        });
+        self.cell_format_indexes.insert(key, index);
+        return index;
+    }
+
+    /// Stylesheet of the cell formats added so far, the first one being the default cell style.
+    pub(crate) fn stylesheet(&mut self) -> XlsxStyleSheet {
+        if self.cell_formats.is_empty() {
+            self.cell_format(None, None, false);
+        }
+        self.ensure_default_fills();
+
+        return XlsxStyleSheet {
+            fills: Some(self.fills.clone()),
+            borders: Some(self.borders.clone()),
+            fonts: Some(self.fonts.clone()),
+            cell_style_xfs: Some(vec![self.cell_formats[0].clone()]),
+            cell_xfs: Some(self.cell_formats.clone()),
+            numbering_formats: Some(self.numbering_formats.clone()),
+            ..Default::default()
+        };
+    }
+
+    /// Fills 0 and 1 are reserved: none and gray125.
+    fn ensure_default_fills(&mut self) {
+        if !self.fills.is_empty() {
+            return;
+        }
+        for pattern_type in ["none", "gray125"] {
+            self.fills.push(XlsxFill::PatternFill(XlsxPatternFill {
+                pattern_type: Some(pattern_type.to_string()),
+                foreground_color: None,
+                background_color: None,
+            }));
+        }
+    }
+
+    fn number_format_id(&mut self, format_code: String) -> u64 {
+        if let Some(id) = builtin_number_format_id(&format_code) {
+            return id;
+        }
+        if let Some(format) = self
+            .numbering_formats
+            .iter()
+            .find(|f| f.format_code.as_ref() == Some(&format_code))
+        {
+            return format.num_fmt_id.unwrap_or(0);
+        }
+        let id = FIRST_CUSTOM_NUMBER_FORMAT_ID + self.numbering_formats.len() as u64;
+        self.numbering_formats.push(XlsxNumberingFormat {
+            num_fmt_id: Some(id),
+            format_code: Some(format_code),
+        });
+        return id;
+    }
+}
+
+/// Index of an item within a list, added at the end when not found.
+fn index_of<T: PartialEq>(list: &mut Vec<T>, item: T) -> u64 {
+    if let Some(index) = list.iter().position(|i| *i == item) {
+        return index as u64;
+    }
+    list.push(item);
+    return list.len() as u64 - 1;
+}
+
+/// Properties of style:style or style:default-style.
+fn load_style(
+    reader: &mut XmlReader<impl Read>,
+    e: &BytesStart,
+    attributes: &HashMap<String, String>,
+) -> anyhow::Result<OdsStyle> {
+    let mut style = OdsStyle {
+        parent: attributes.get("style:parent-style-name").cloned(),
+        data_style: attributes.get("style:data-style-name").cloned(),
+        properties: HashMap::new(),
+    };
+
+    let end = e.name().as_ref().to_vec();
+    let mut buf = Vec::new();
+    loop {
+        buf.clear();
+
+        match reader.read_event_into(&mut buf) {
+            Ok(Event::Start(ref e)) if PROPERTIES_ELEMENTS.contains(&e.name().as_ref()) => {
+                style.properties.extend(load_attributes(e)?);
+            }
+            Ok(Event::End(ref e)) if e.name().as_ref() == end => break,
+            Ok(Event::Eof) => bail!("unexpected end of file."),
+            Err(e) => bail!(e.to_string()),
+            _ => (),
+        }
+    }
+
+    return Ok(style);
+}
+
+/// Data style as a format code, ie: `<number:number number:decimal-places="2" number:grouping="true" />` -> `#,##0.00`.
+///
+/// Conditional formats (style:map) are not taken into account, only the format of the style itself.
+///
+/// Example:
+/// ```
+/// <number:date-style style:name="N37">
+///     <number:day number:style="long" />
+///     <number:text>.</number:text>
+///     <number:month number:style="long" />
+///     <number:text>.</number:text>
+///     <number:year />
+/// </number:date-style>
+/// ```
+fn load_data_style(
+    reader: &mut XmlReader<impl Read>,
+    e: &BytesStart,
+) -> anyhow::Result<(Option<String>, String)> {
+    let attributes = load_attributes(e)?;
+    let name = attributes.get("style:name").cloned();
+    let is_boolean = e.name().as_ref() == b"number:boolean-style";
+    // elapsed time: hours are not truncated to a day
+    let elapsed = attributes
+        .get("number:truncate-on-overflow")
+        .map(|v| v.as_str())
+        == Some("false");
+
+    let end = e.name().as_ref().to_vec();
+    let mut code = String::new();
+    let mut buf = Vec::new();
+    loop {
+        buf.clear();
+
+        match reader.read_event_into(&mut buf) {
+            Ok(Event::Start(ref e)) => {
+                let attributes = load_attributes(e)?;
+                let is_long = attributes.get("number:style").map(|v| v.as_str()) == Some("long");
+                let is_textual =
+                    attributes.get("number:textual").map(|v| v.as_str()) == Some("true");
+                let digits = |name: &str, default: usize| -> usize {
+                    attributes
+                        .get(name)
+                        .and_then(|v| v.parse::<usize>().ok())
+                        .unwrap_or(default)
+                        .min(30)
+                };
+                match e.name().as_ref() {
+                    b"number:number" => code.push_str(&number_code(
+                        digits("number:min-integer-digits", 1),
+                        digits("number:decimal-places", 0),
+                        attributes.get("number:grouping").map(|v| v.as_str()) == Some("true"),
+                    )),
+                    b"number:scientific-number" => {
+                        code.push_str(&number_code(
+                            digits("number:min-integer-digits", 1),
+                            digits("number:decimal-places", 0),
+                            false,
+                        ));
+                        code.push_str("E+");
+                        code.push_str(&"0".repeat(digits("number:min-exponent-digits", 2)));
+                    }
+                    b"number:fraction" => {
+                        code.push_str("# ");
+                        code.push_str(&"?".repeat(digits("number:min-numerator-digits", 1)));
+                        code.push('/');
+                        match attributes.get("number:denominator-value") {
+                            Some(denominator) => code.push_str(denominator),
+                            None => code
+                                .push_str(&"?".repeat(digits("number:min-denominator-digits", 1))),
+                        }
+                    }
+                    b"number:text" | b"number:currency-symbol" => {
+                        let text = extract_text_contents(reader, e.local_name().as_ref())?;
+                        code.push_str(&literal(&text));
+                    }
+                    b"number:text-content" => code.push('@'),
+                    b"number:day" => code.push_str(if is_long { "dd" } else { "d" }),
+                    b"number:day-of-week" => code.push_str(if is_long { "dddd" } else { "ddd" }),
+                    b"number:month" => code.push_str(match (is_textual, is_long) {
+                        (true, true) => "mmmm",
+                        (true, false) => "mmm",
+                        (false, true) => "mm",
+                        (false, false) => "m",
+                    }),
+                    b"number:year" => code.push_str(if is_long { "yyyy" } else { "yy" }),
+                    b"number:hours" => match (elapsed, is_long) {
+                        (true, true) => code.push_str("[hh]"),
+                        (true, false) => code.push_str("[h]"),
+                        (false, true) => code.push_str("hh"),
+                        (false, false) => code.push('h'),
+                    },
+                    b"number:minutes" => code.push_str(if is_long { "mm" } else { "m" }),
+                    b"number:seconds" => {
+                        code.push_str(if is_long { "ss" } else { "s" });
+                        let decimals = digits("number:decimal-places", 0);
+                        if decimals > 0 {
+                            code.push('.');
+                            code.push_str(&"0".repeat(decimals));
+                        }
+                    }
+                    b"number:am-pm" => code.push_str("AM/PM"),
+                    _ => (),
+                }
+            }
+            Ok(Event::End(ref e)) if e.name().as_ref() == end => break,
+            Ok(Event::Eof) => bail!("unexpected end of file."),
+            Err(e) => bail!(e.to_string()),
+            _ => (),
+        }
+    }
+
+    if is_boolean || code.is_empty() {
+        code = "General".to_string();
+    }
+    return Ok((name, code));
+}
+
+/// `0`, `#,##0.00`
+fn number_code(integer_digits: usize, decimal_places: usize, grouping: bool) -> String {
+    let mut code = "0".repeat(integer_digits);
+    if grouping {
+        code = format!("{:#>4}", code);
+        code.insert(code.len() - 3, ',');
+    } else if code.is_empty() {
+        code.push('#');
+    }
+    if decimal_places > 0 {
+        code.push('.');
+        code.push_str(&"0".repeat(decimal_places));
+    }
+    return code;
+}
+
+/// Literal text of a format code, quoted unless made of separators only.
+fn literal(text: &str) -> String {
+    if text.chars().all(|c| " -/:,.()%".contains(c)) {
+        return text.to_string();
+    }
+    return format!("\"{}\"", text.replace('"', ""));
+}
+
+/// Id of a built-in format for its format code.
+fn builtin_number_format_id(format_code: &str) -> Option<u64> {
+    return match format_code {
+        "General" => Some(0),
+        "0" => Some(1),
+        "0.00" => Some(2),
+        "#,##0" => Some(3),
+        "#,##0.00" => Some(4),
+        "0%" => Some(9),
+        "0.00%" => Some(10),
+        "0.00E+00" => Some(11),
+        "@" => Some(49),
+        _ => None,
+    };
+}
+
+/// Built-in format for cells without data style.
+fn default_number_format_id(value_type: Option<&str>, has_time: bool) -> u64 {
+    return match value_type {
+        Some("percentage") => 10,
+        Some("currency") => 4,
+        Some("date") if has_time => 22,
+        Some("date") => 14,
+        Some("time") => 46,
+        _ => 0,
+    };
+}
+
+/// `#rrggbb` as an ARGB color.
+fn load_color(value: Option<&String>) -> Option<XlsxColor> {
+    let hex = value?.strip_prefix('#')?;
+    if hex.len() != 6 || !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return None;
+    }
+    return Some(XlsxColor {
+        rgb: Some(format!("FF{}", hex.to_ascii_uppercase())),
+        ..Default::default()
+    });
+}
+
+fn load_font(properties: &HashMap<String, String>) -> XlsxFont {
+    let property = |name: &str| properties.get(name).map(|v| v.as_str());
+    let is_set = |name: &str| property(name).is_some_and(|v| v != "none");
+
+    let bold =
+        property("fo:font-weight").map(|w| matches!(w, "bold" | "600" | "700" | "800" | "900"));
+    let underline = if !is_set("style:text-underline-style") {
+        None
+    } else if property("style:text-underline-type") == Some("double") {
+        Some("double".to_string())
+    } else {
+        Some("single".to_string())
+    };
+    // super, sub, or a percentage: positive for superscript, negative for subscript
+    let vert_align = property("style:text-position").and_then(|p| {
+        let position = p.split_whitespace().next()?;
+        if position == "super"
+            || !position.starts_with('-') && position != "0%" && position.ends_with('%')
+        {
+            Some("superscript".to_string())
+        } else if position == "sub" || position.starts_with('-') {
+            Some("subscript".to_string())
+        } else {
+            None
+        }
+    });
+
+    return XlsxFont {
+        bold,
+        charset: None,
+        color: load_color(properties.get("fo:color")),
+        condense: None,
+        extend: None,
+        family: None,
+        italic: property("fo:font-style").map(|s| s == "italic" || s == "oblique"),
+        name: property("style:font-name")
+            .or(property("fo:font-family"))
+            .map(|n| n.trim_matches('\'').to_string()),
+        outline: property("style:text-outline").map(|o| o == "true"),
+        scheme: None,
+        shadow: property("fo:text-shadow").map(|s| s != "none"),
+        strike: property("style:text-line-through-style").map(|s| s != "none"),
+        size: property("fo:font-size").and_then(length_to_pt),
+        underline,
+        vert_align,
+    };
+}
+
+fn load_fill(properties: &HashMap<String, String>) -> Option<XlsxFill> {
+    let color = load_color(properties.get("fo:background-color"))?;
+    return Some(XlsxFill::PatternFill(XlsxPatternFill {
+        pattern_type: Some("solid".to_string()),
+        foreground_color: Some(color),
+        background_color: None,
+    }));
+}
+
+fn load_border(properties: &HashMap<String, String>) -> XlsxBorder {
+    let side = |name: &str| -> Option<XlsxBorderStyle> {
+        let value = properties.get(name).or(properties.get("fo:border"))?;
+        load_border_style(value)
+    };
+    let diagonal_down = properties
+        .get("style:diagonal-tl-br")
+        .and_then(|v| load_border_style(v));
+    let diagonal_up = properties
+        .get("style:diagonal-bl-tr")
+        .and_then(|v| load_border_style(v));
+
+    return XlsxBorder {
+        diagonal_down: diagonal_down.as_ref().map(|_| true),
+        diagonal_up: diagonal_up.as_ref().map(|_| true),
+        outline: None,
+        left: side("fo:border-left"),
+        right: side("fo:border-right"),
+        top: side("fo:border-top"),
+        bottom: side("fo:border-bottom"),
+        diagonal: diagonal_down.or(diagonal_up),
+    };
+}
+
+/// Border as `width style color`, ie: `0.74pt solid #000000`, None for `none`.
+fn load_border_style(value: &str) -> Option<XlsxBorderStyle> {
+    let mut width = 0.75;
+    let mut line = None;
+    let mut color = None;
+    for token in value.split_whitespace() {
+        if token.starts_with('#') {
+            color = load_color(Some(&token.to_string()));
+        } else if let Some(pt) = length_to_pt(token) {
+            width = pt;
+        } else {
+            line = Some(token);
+        }
+    }
+
+    let style = match line? {
+        "none" | "hidden" => return None,
+        "double" | "double-thin" => "double",
+        "dotted" => "dotted",
+        "dashed" | "dash-dot" | "dash-dot-dot" if width > 1.0 => "mediumDashed",
+        "dashed" => "dashed",
+        "dash-dot" => "dashDot",
+        "dash-dot-dot" => "dashDotDot",
+        _ if width < 1.0 => "thin",
+        _ if width < 2.0 => "medium",
+        _ => "thick",
+    };
+    return Some(XlsxBorderStyle {
+        style: Some(style.to_string()),
+        color,
+    });
+}
+
+fn load_alignment(properties: &HashMap<String, String>) -> XlsxAlignment {
+    let property = |name: &str| properties.get(name).map(|v| v.as_str());
+
+    // without a fixed alignment, the alignment depends on the value type, as `general` does
+    let horizontal = if property("style:text-align-source") == Some("value-type") {
+        None
+    } else {
+        property("fo:text-align").and_then(|a| match a {
+            "start" | "left" => Some("left"),
+            "end" | "right" => Some("right"),
+            "center" => Some("center"),
+            "justify" => Some("justify"),
+            _ => None,
+        })
+    };
+    let vertical = property("style:vertical-align").and_then(|a| match a {
+        "top" => Some("top"),
+        "middle" => Some("center"),
+        "bottom" => Some("bottom"),
+        _ => None,
+    });
+    // counterclockwise degrees, downwards text is stored by xlsx as 90 + degrees below the horizontal
+    let text_rotation = property("style:rotation-angle")
+        .and_then(|a| a.trim_end_matches("deg").parse::<f64>().ok())
+        .map(|a| a.rem_euclid(360.0).round() as u64)
+        .map(|a| match a {
+            0..=90 => a,
+            270..=359 => 90 + (360 - a),
+            _ => 0,
+        });
+
+    return XlsxAlignment {
+        horizontal: horizontal.map(|h| h.to_string()),
+        vertical: vertical.map(|v| v.to_string()),
+        wrap_text: property("fo:wrap-option").map(|w| w == "wrap"),
+        shrink_to_fit: property("style:shrink-to-fit").map(|s| s == "true"),
+        text_rotation,
+        ..Default::default()
+    };
+}
+
+/// style:cell-protect: none, protected, formula-hidden, hidden-and-protected, or a list of them.
+fn load_protection(properties: &HashMap<String, String>) -> XlsxCellProtection {
+    let Some(protect) = properties.get("style:cell-protect") else {
+        return XlsxCellProtection {
+            hidden: Some(false),
+            locked: Some(true),
+        };
+    };
+    let values: Vec<&str> = protect.split_whitespace().collect();
+    return XlsxCellProtection {
+        hidden: Some(values.iter().any(|v| v.contains("hidden"))),
+        locked: Some(values.iter().any(|v| v.contains("protected"))),
+    };
+}
@@ -0,0 +1,149 @@
+use anyhow::bail;
+use quick_xml::events::{BytesStart, Event};
+use std::io::{Read, Seek};
+use zip::ZipArchive;
+
+use crate::{
+    excel::{xml_reader, XmlReader},
+    helper::{extract_text_contents, string_to_unsignedint},
+};
+
+use super::string_item::XlsxStringItem;
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.comments?view=openxml-3.0.1
+///
+/// Root element of a comments part (legacy notes), ie: xl/comments1.xml
+///
+/// The position and visibility of the comment boxes are kept in the VML drawing of the worksheet.
+///
+/// Example
+/// ```xml
+/// <comments xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main">
+///   <authors>
+///     <author>Reviewer</author>
+///   </authors>
+///   <commentList>
+///     <comment ref="B2" authorId="0" shapeId="0">
+///       <text>
+///         <r><rPr><b/><sz val="9"/><rFont val="Tahoma"/></rPr><t>Reviewer:</t></r>
+///         <r><rPr><sz val="9"/><rFont val="Tahoma"/></rPr><t xml:space="preserve">Check this total</t></r>
+///       </text>
+///     </comment>
+///   </commentList>
+/// </comments>
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct XlsxComments {
+    // Child Elements
+    /// authors (Authors)
+    pub authors: Vec<String>,
+
+    /// commentList (List of Comments)
+    pub comment_list: Vec<XlsxComment>,
+}
+
+impl XlsxComments {
+    pub(crate) fn load(zip: &mut ZipArchive<impl Read + Seek>, path: &str) -> anyhow::Result<Self> {
+        let mut comments = Self::default();
+
+        let Some(mut reader) = xml_reader(zip, path) else {
+            return Ok(comments);
+        };
+
+        let mut buf = Vec::new();
+
+        loop {
+            buf.clear();
+
+            match reader.read_event_into(&mut buf) {
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"extLst" => {
+                    let _ = reader.read_to_end_into(e.to_end().to_owned().name(), &mut Vec::new());
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"author" => {
+                    comments
+                        .authors
+                        .push(extract_text_contents(&mut reader, b"author")?);
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"comment" => {
+                    let mut comment = XlsxComment::load(e)?;
+                    comment.load_text(&mut reader)?;
+                    comments.comment_list.push(comment);
+                }
+                Ok(Event::End(ref e)) if e.local_name().as_ref() == b"comments" => break,
+                Ok(Event::Eof) => break,
+                Err(e) => bail!(e.to_string()),
+                _ => (),
+            }
+        }
+
+        return Ok(comments);
+    }
+}
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.comment?view=openxml-3.0.1
+///
+/// Example
+/// ```xml
+/// <comment ref="B2" authorId="0" shapeId="0">
+///   <text><t>Check this total</t></text>
+/// </comment>
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct XlsxComment {
+    // Child Elements
+    /// text (Comment Text)
+    pub text: Option<XlsxStringItem>,
+
+    // Attributes
+    /// ref (Cell Reference)
+    pub r#ref: Option<String>,
+
+    /// authorId (Author Id): index in the authors of the comments part
+    pub author_id: Option<u64>,
+
+    /// shapeId (Shape ID)
+    pub shape_id: Option<u64>,
+
+    /// xr:uid (Unique Identifier), links a note to the threaded comment it is a fallback for.
+    pub uid: Option<String>,
+}
+
+impl XlsxComment {
+    fn load(e: &BytesStart) -> anyhow::Result<Self> {
+        let mut comment = Self::default();
+        for a in e.attributes() {
+            match a {
+                Ok(a) => {
+                    let string_value = String::from_utf8(a.value.to_vec())?;
+                    match a.key.local_name().as_ref() {
+                        b"ref" => comment.r#ref = Some(string_value),
+                        b"authorId" => comment.author_id = string_to_unsignedint(&string_value),
+                        b"shapeId" => comment.shape_id = string_to_unsignedint(&string_value),
+                        b"uid" => comment.uid = Some(string_value),
+                        _ => {}
+                    }
+                }
+                Err(error) => bail!(error.to_string()),
+            }
+        }
+        return Ok(comment);
+    }
+
+    fn load_text(&mut self, reader: &mut XmlReader<impl Read>) -> anyhow::Result<()> {
+        let mut buf = Vec::new();
+        loop {
+            buf.clear();
+
+            match reader.read_event_into(&mut buf) {
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"text" => {
+                    self.text = Some(XlsxStringItem::load(reader, b"text")?);
+                }
+                Ok(Event::End(ref e)) if e.local_name().as_ref() == b"comment" => break,
+                Ok(Event::Eof) => bail!("unexpected end of file at `comment`."),
+                Err(e) => bail!(e.to_string()),
+                _ => (),
+            }
+        }
+        return Ok(());
+    }
+}
@@ -0,0 +1,132 @@
+use anyhow::bail;
+use quick_xml::events::{BytesStart, Event};
+use std::io::{Read, Seek};
+use zip::ZipArchive;
+
+use crate::{
+    excel::xml_reader,
+    helper::{string_to_bool, string_to_unsignedint},
+};
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.connections?view=openxml-3.0.1
+///
+/// External data connections of the workbook parsed from xl/connections.xml
+///
+/// Example
+/// ```xml
+/// <connections xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main">
+///   <connection id="1" name="ThisWorkbookDataModel" description="Data Model" type="5" refreshedVersion="6" minRefreshableVersion="5" background="1">
+///     <dbPr connection="Data Model Connection" command="Model" commandType="1"/>
+///     <extLst>
+///       <ext uri="{DE250136-89BD-433C-8126-D09CA5730AF9}" xmlns:x15="http://schemas.microsoft.com/office/spreadsheetml/2010/11/main">
+///         <x15:connection id="" model="1"/>
+///       </ext>
+///     </extLst>
+///   </connection>
+/// </connections>
+/// ```
+pub type XlsxConnections = Vec<XlsxConnection>;
+
+pub(crate) fn load_connections(
+    zip: &mut ZipArchive<impl Read + Seek>,
+) -> anyhow::Result<XlsxConnections> {
+    let path = "xl/connections.xml";
+    let mut connections: XlsxConnections = vec![];
+
+    let Some(mut reader) = xml_reader(zip, path) else {
+        return Ok(connections);
+    };
+
+    let mut buf = Vec::new();
+    let mut depth = 0;
+
+    loop {
+        buf.clear();
+
+        match reader.read_event_into(&mut buf) {
+            Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"connection" => {
+                depth += 1;
+                if depth == 1 {
+                    connections.push(XlsxConnection::load(e)?);
+                } else if let Some(connection) = connections.last_mut() {
+                    // x15:connection within extLst
+                    connection.load_extension(e)?;
+                }
+            }
+            Ok(Event::End(ref e)) if e.local_name().as_ref() == b"connection" => {
+                depth -= 1;
+            }
+            Ok(Event::End(ref e)) if e.local_name().as_ref() == b"connections" => break,
+            Ok(Event::Eof) => break,
+            Err(e) => bail!(e.to_string()),
+            _ => (),
+        }
+    }
+
+    Ok(connections)
+}
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.connection?view=openxml-3.0.1
+///
+/// connection (Connection)
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct XlsxConnection {
+    // Attributes
+    /// id (Connection Id)
+    pub id: Option<u64>,
+
+    /// name (Connection Name)
+    pub name: Option<String>,
+
+    /// type (Database Source Type)
+    ///
+    /// 1: ODBC, 2: DAO, 3: file, 4: web query, 5: OLE DB, 6: text, 7: ADO, 8: DSP
+    pub r#type: Option<u64>,
+
+    /// model: from x15:connection, the connection is to the data model of the workbook
+    pub model: Option<bool>,
+}
+
+impl XlsxConnection {
+    pub(crate) fn load(e: &BytesStart) -> anyhow::Result<Self> {
+        let mut connection = Self::default();
+
+        for a in e.attributes() {
+            match a {
+                Ok(a) => {
+                    let string_value = String::from_utf8(a.value.to_vec())?;
+                    match a.key.local_name().as_ref() {
+                        b"id" => connection.id = string_to_unsignedint(&string_value),
+                        b"name" => connection.name = Some(string_value),
+                        b"type" => connection.r#type = string_to_unsignedint(&string_value),
+                        _ => {}
+                    }
+                }
+                Err(error) => {
+                    bail!(error.to_string())
+                }
+            }
+        }
+
+        Ok(connection)
+    }
+
+    fn load_extension(&mut self, e: &BytesStart) -> anyhow::Result<()> {
+        for a in e.attributes() {
+            match a {
+                Ok(a) => {
+                    let string_value = String::from_utf8(a.value.to_vec())?;
+                    match a.key.local_name().as_ref() {
+                        b"model" => self.model = string_to_bool(&string_value),
+                        _ => {}
+                    }
+                }
+                Err(error) => {
+                    bail!(error.to_string())
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -1,5 +1,4 @@
-/// MAX_DIGIT_WIDTH Appximate with Aptos of font size 12 pt
-static MAX_DIGIT_WIDTH: f64 = 8.2; // Max digit width: 7 pixel
+use crate::units::{self, DEFAULT_DPI, DEFAULT_MAX_DIGIT_WIDTH};
 
 /// Represent the following types:
 /// * [CT_Font](https://c-rex.net/samples/ooxml/e1/Part4/OOXML_P4_DOCX_sz_topic_ID0E6DU5.html)
@@ -26,12 +25,9 @@ pub type CTFontSize = f64;
 /// =Truncate((
 /// (256 * {width} + Truncate(128/{Maximum Digit Width}))/256)*{Maximum Digit Width})
 ///
-/// Appximate with [MAX_DIGIT_WIDTH]: Aptos of font size 12 pt. -> Max digit width: 8.2 pixel
+/// Appximate with [DEFAULT_MAX_DIGIT_WIDTH]: Aptos of font size 12 pt. -> Max digit width: 8.2 pixel
 pub(crate) fn column_width_to_pt(w: f64) -> f64 {
-    let px = (((256.0_f64 * w) + (128.0_f64 / MAX_DIGIT_WIDTH).trunc()) / 256.0_f64
-        * MAX_DIGIT_WIDTH)
-        .trunc();
-    return px_to_pt(px);
+    return units::column_width_to_pt(w, DEFAULT_MAX_DIGIT_WIDTH);
 }
 
 /// To conver the following two types to points.
@@ -44,16 +40,10 @@ pub(crate) fn column_width_to_pt(w: f64) -> f64 {
 /// To convert base column width to default column width:
 /// width = Truncate([{@baseColumnWidth} * {Maximum Digit Width} + {5 pixel padding (2 pixels on each side, totalling 4 pixels + gridline (1pixel))}]/{Maximum Digit Width}*256)/256
 ///
-/// Appximate with [MAX_DIGIT_WIDTH]: Aptos of font size 12 pt. -> Max digit width: 8.2 pixel
+/// Appximate with [DEFAULT_MAX_DIGIT_WIDTH]: Aptos of font size 12 pt. -> Max digit width: 8.2 pixel
 pub(crate) fn base_column_width_to_pt(base_column_width: u64) -> f64 {
-    let base_column_width = base_column_width as f64;
-    let default = ((base_column_width * MAX_DIGIT_WIDTH + 5.0_f64) / MAX_DIGIT_WIDTH * 256.0_f64)
-        .trunc()
-        / 256.0_f64;
+    let default =
+        units::characters_to_column_width(base_column_width as f64, DEFAULT_MAX_DIGIT_WIDTH);
     let px = column_width_to_pt(default);
-    return px_to_pt(px);
-}
-
-fn px_to_pt(px: f64) -> f64 {
-    return px * 72.0 / 96.0;
+    return units::px_to_pt(px, DEFAULT_DPI);
 }
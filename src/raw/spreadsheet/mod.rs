@@ -8,6 +8,26 @@ pub mod workbook;
 pub mod sheet;
 // root of table xmls
 pub mod table;
+// root of xl/comments{}.xml
+pub mod comment;
+// legacy notes of xl/drawings/vmlDrawing{}.vml
+pub mod vml_drawing;
+// root of xl/threadedComments/threadedComment{}.xml
+pub mod threaded_comment;
+// root of xl/persons/person.xml
+pub mod person;
+// root of xl/pivotCache/pivotCacheDefinition{}.xml
+pub mod pivot_cache_definition;
+// root of xl/pivotTables/pivotTable{}.xml
+pub mod pivot_table;
+// root of xl/connections.xml
+pub mod connection;
+// root of xl/slicerCaches/slicerCache{}.xml
+pub mod slicer_cache;
+// root of xl/timelineCaches/timelineCache{}.xml
+pub mod timeline_cache;
+// root of xl/webextensions/webextension{}.xml
+pub mod web_extension;
 
 // common
 pub mod ct_types;
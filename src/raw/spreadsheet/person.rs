@@ -0,0 +1,109 @@
+use anyhow::bail;
+use quick_xml::events::{BytesStart, Event};
+use std::io::{Read, Seek};
+use zip::ZipArchive;
+
+use crate::excel::xml_reader;
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.office2019.excel.threadedcomments.personlist?view=openxml-3.0.1
+///
+/// Root element of the persons part (xl/persons/person.xml): authors of the threaded comments of the workbook.
+///
+/// Example
+/// ```xml
+/// <personList xmlns="http://schemas.microsoft.com/office/spreadsheetml/2018/threadedcomments">
+///   <person displayName="Jane Doe" id="{5B4A...}" userId="jane@example.com" providerId="AD"/>
+/// </personList>
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct XlsxPersons {
+    // extLst (Future Feature Data Storage Area) Not supported
+
+    // Child Elements
+    // person (Person)
+    pub persons: Vec<XlsxPerson>,
+}
+
+impl XlsxPersons {
+    pub(crate) fn load(zip: &mut ZipArchive<impl Read + Seek>, path: &str) -> anyhow::Result<Self> {
+        let mut persons = Self::default();
+
+        let Some(mut reader) = xml_reader(zip, path) else {
+            return Ok(persons);
+        };
+
+        let mut buf = Vec::new();
+
+        loop {
+            buf.clear();
+
+            match reader.read_event_into(&mut buf) {
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"extLst" => {
+                    let _ = reader.read_to_end_into(e.to_end().to_owned().name(), &mut Vec::new());
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"person" => {
+                    persons.persons.push(XlsxPerson::load(e)?);
+                }
+                Ok(Event::End(ref e)) if e.local_name().as_ref() == b"personList" => break,
+                Ok(Event::Eof) => break,
+                Err(e) => bail!(e.to_string()),
+                _ => (),
+            }
+        }
+
+        return Ok(persons);
+    }
+}
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.office2019.excel.threadedcomments.person?view=openxml-3.0.1
+///
+/// Example
+/// ```xml
+/// <person displayName="Jane Doe" id="{5B4A...}" userId="jane@example.com" providerId="AD"/>
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct XlsxPerson {
+    // Child Elements
+    // extLst (Future Feature Data Storage Area) Not supported
+
+    // Attributes
+    /// displayName (Display Name)
+    pub display_name: Option<String>,
+
+    /// id (Id), referenced by the `personId` of threaded comments
+    pub id: Option<String>,
+
+    /// userId (User Id) in the identity provider, ie: an email address
+    pub user_id: Option<String>,
+
+    /// providerId (Provider Id), ie: `AD`, `Windows Live`, `None`
+    pub provider_id: Option<String>,
+}
+
+impl XlsxPerson {
+    fn load(e: &BytesStart) -> anyhow::Result<Self> {
+        let mut person = Self::default();
+        for a in e.attributes() {
+            match a {
+                Ok(a) => {
+                    let string_value = String::from_utf8(a.value.to_vec())?;
+                    match a.key.local_name().as_ref() {
+                        b"displayName" => {
+                            person.display_name = Some(
+                                quick_xml::escape::unescape(&string_value)
+                                    .map(|s| s.to_string())
+                                    .unwrap_or(string_value),
+                            )
+                        }
+                        b"id" => person.id = Some(string_value),
+                        b"userId" => person.user_id = Some(string_value),
+                        b"providerId" => person.provider_id = Some(string_value),
+                        _ => {}
+                    }
+                }
+                Err(error) => bail!(error.to_string()),
+            }
+        }
+        return Ok(person);
+    }
+}
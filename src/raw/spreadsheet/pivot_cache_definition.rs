@@ -0,0 +1,269 @@
+use anyhow::bail;
+use quick_xml::events::{BytesStart, Event};
+use std::io::{Read, Seek};
+use zip::ZipArchive;
+
+use crate::{
+    excel::{xml_reader, XmlReader},
+    helper::string_to_unsignedint,
+};
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.pivotcachedefinition?view=openxml-3.0.1
+///
+/// Root element of a pivot cache definition part.
+/// Only the source of the cache and the shared items of the fields are parsed.
+///
+/// Example
+/// ```xml
+/// <pivotCacheDefinition xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main" r:id="rId1" refreshOnLoad="1">
+///   <cacheSource type="worksheet">
+///     <worksheetSource ref="A1:B4" sheet="Sheet1"/>
+///   </cacheSource>
+///   <cacheFields count="2">
+///     <cacheField name="Region" numFmtId="0">
+///       <sharedItems count="2">
+///         <s v="East"/>
+///         <s v="West"/>
+///       </sharedItems>
+///     </cacheField>
+///     <cacheField name="Sales" numFmtId="0">
+///       <sharedItems containsSemiMixedTypes="0" containsString="0" containsNumber="1" minValue="1" maxValue="5"/>
+///     </cacheField>
+///   </cacheFields>
+///   <extLst>
+///     <ext uri="{725AE2AE-9491-48be-B2B4-4EB974FC3084}" xmlns:x14="http://schemas.microsoft.com/office/spreadsheetml/2009/9/main">
+///       <x14:pivotCacheDefinition pivotCacheId="1"/>
+///     </ext>
+///   </extLst>
+/// </pivotCacheDefinition>
+/// ```
+/// pivotCacheDefinition (PivotCache Definition)
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct XlsxPivotCacheDefinition {
+    // Child Elements
+    // cacheSource (Cache Source)	§18.10.1.4
+    pub cache_source: Option<XlsxCacheSource>,
+
+    // cacheFields (PivotCache Fields)	§18.10.1.4
+    pub cache_fields: Vec<XlsxCacheField>,
+
+    /// pivotCacheId of x14:pivotCacheDefinition, used by slicer caches to refer to the cache.
+    pub pivot_cache_id: Option<u64>,
+}
+
+impl XlsxPivotCacheDefinition {
+    pub(crate) fn load(zip: &mut ZipArchive<impl Read + Seek>, path: &str) -> anyhow::Result<Self> {
+        let mut definition = Self::default();
+
+        let Some(mut reader) = xml_reader(zip, path) else {
+            return Ok(definition);
+        };
+
+        let mut buf = Vec::new();
+
+        loop {
+            buf.clear();
+
+            match reader.read_event_into(&mut buf) {
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"cacheSource" => {
+                    definition.cache_source = Some(XlsxCacheSource::load(e)?);
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"worksheetSource" => {
+                    if let Some(source) = definition.cache_source.as_mut() {
+                        source.worksheet_source = Some(XlsxWorksheetSource::load(e)?);
+                    }
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"cacheField" => {
+                    definition.cache_fields.push(XlsxCacheField::load(&mut reader, e)?);
+                }
+                // root element, or x14:pivotCacheDefinition within extLst
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"pivotCacheDefinition" => {
+                    for a in e.attributes() {
+                        match a {
+                            Ok(a) if a.key.local_name().as_ref() == b"pivotCacheId" => {
+                                definition.pivot_cache_id =
+                                    string_to_unsignedint(&String::from_utf8(a.value.to_vec())?);
+                            }
+                            Ok(_) => {}
+                            Err(error) => bail!(error.to_string()),
+                        }
+                    }
+                }
+                Ok(Event::Eof) => break,
+                Err(e) => bail!(e.to_string()),
+                _ => (),
+            }
+        }
+
+        return Ok(definition);
+    }
+}
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.cachesource?view=openxml-3.0.1
+///
+/// Example
+/// ```xml
+/// <cacheSource type="external" connectionId="1"/>
+/// ```
+/// cacheSource (Cache Source)
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct XlsxCacheSource {
+    // Attributes
+    /// type (Cache Type): worksheet, external, consolidation, scenario
+    pub r#type: Option<String>,
+
+    /// connectionId (Connection Index)
+    pub connection_id: Option<u64>,
+
+    // Child Elements
+    // worksheetSource (Worksheet PivotCache Source)	§18.10.1.109
+    pub worksheet_source: Option<XlsxWorksheetSource>,
+}
+
+impl XlsxCacheSource {
+    pub(crate) fn load(e: &BytesStart) -> anyhow::Result<Self> {
+        let mut source = Self::default();
+
+        for a in e.attributes() {
+            match a {
+                Ok(a) => {
+                    let string_value = String::from_utf8(a.value.to_vec())?;
+                    match a.key.local_name().as_ref() {
+                        b"type" => source.r#type = Some(string_value),
+                        b"connectionId" => source.connection_id = string_to_unsignedint(&string_value),
+                        _ => {}
+                    }
+                }
+                Err(error) => {
+                    bail!(error.to_string())
+                }
+            }
+        }
+
+        Ok(source)
+    }
+}
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.cachefield?view=openxml-3.0.1
+///
+/// Example
+/// ```xml
+/// <cacheField name="Region" numFmtId="0">
+///   <sharedItems count="2">
+///     <s v="East"/>
+///     <m/>
+///   </sharedItems>
+/// </cacheField>
+/// ```
+/// cacheField (PivotCache Field)
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct XlsxCacheField {
+    // Attributes
+    /// name (Name)
+    pub name: Option<String>,
+
+    // Child Elements
+    /// sharedItems (Shared Items): v attribute of each item, None for missing (m) items.
+    pub shared_items: Vec<Option<String>>,
+}
+
+impl XlsxCacheField {
+    pub(crate) fn load(reader: &mut XmlReader<impl Read>, e: &BytesStart) -> anyhow::Result<Self> {
+        let mut field = Self::default();
+
+        for a in e.attributes() {
+            match a {
+                Ok(a) if a.key.local_name().as_ref() == b"name" => {
+                    field.name = Some(String::from_utf8(a.value.to_vec())?);
+                }
+                Ok(_) => {}
+                Err(error) => bail!(error.to_string()),
+            }
+        }
+
+        let mut buf = Vec::new();
+        let mut in_shared_items = false;
+        loop {
+            buf.clear();
+
+            match reader.read_event_into(&mut buf) {
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"sharedItems" => {
+                    in_shared_items = true;
+                }
+                Ok(Event::End(ref e)) if e.local_name().as_ref() == b"sharedItems" => {
+                    in_shared_items = false;
+                }
+                Ok(Event::Start(ref e)) if in_shared_items => {
+                    let mut value: Option<String> = None;
+                    for a in e.attributes() {
+                        match a {
+                            Ok(a) if a.key.local_name().as_ref() == b"v" => {
+                                value = Some(String::from_utf8(a.value.to_vec())?);
+                            }
+                            Ok(_) => {}
+                            Err(error) => bail!(error.to_string()),
+                        }
+                    }
+                    field.shared_items.push(value);
+                    let _ = reader.read_to_end_into(e.to_end().to_owned().name(), &mut Vec::new());
+                }
+                Ok(Event::End(ref e)) if e.local_name().as_ref() == b"cacheField" => break,
+                Ok(Event::Eof) => bail!("unexpected end of file."),
+                Err(e) => bail!(e.to_string()),
+                _ => (),
+            }
+        }
+
+        Ok(field)
+    }
+}
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.worksheetsource?view=openxml-3.0.1
+///
+/// Example
+/// ```xml
+/// <worksheetSource ref="A1:B4" sheet="Sheet1"/>
+/// <worksheetSource name="Table1"/>
+/// ```
+/// worksheetSource (Worksheet PivotCache Source)
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct XlsxWorksheetSource {
+    // Attributes
+    /// ref (Reference)
+    pub r#ref: Option<String>,
+
+    /// name (Named Range): defined name or table holding the source data
+    pub name: Option<String>,
+
+    /// sheet (Sheet Name)
+    pub sheet: Option<String>,
+
+    /// id (Relationship Id): relationship to the external workbook holding the source data
+    pub id: Option<String>,
+}
+
+impl XlsxWorksheetSource {
+    pub(crate) fn load(e: &BytesStart) -> anyhow::Result<Self> {
+        let mut source = Self::default();
+
+        for a in e.attributes() {
+            match a {
+                Ok(a) => {
+                    let string_value = String::from_utf8(a.value.to_vec())?;
+                    match a.key.local_name().as_ref() {
+                        b"ref" => source.r#ref = Some(string_value),
+                        b"name" => source.name = Some(string_value),
+                        b"sheet" => source.sheet = Some(string_value),
+                        b"id" => source.id = Some(string_value),
+                        _ => {}
+                    }
+                }
+                Err(error) => {
+                    bail!(error.to_string())
+                }
+            }
+        }
+
+        Ok(source)
+    }
+}
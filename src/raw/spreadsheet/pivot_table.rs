@@ -0,0 +1,151 @@
+use anyhow::bail;
+use quick_xml::events::{BytesStart, Event};
+use std::io::{Read, Seek};
+use zip::ZipArchive;
+
+use crate::{common_types::Dimension, excel::xml_reader, helper::string_to_unsignedint};
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.pivottabledefinition?view=openxml-3.0.1
+///
+/// Root element of a pivot table part.
+/// Only the name, cache and location of the pivot table are parsed, not its layout.
+///
+/// Example
+/// ```xml
+/// <pivotTableDefinition xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main" name="PivotTable1" cacheId="1" dataCaption="Values">
+///   <location ref="A3:B6" firstHeaderRow="1" firstDataRow="1" firstDataCol="1"/>
+///   <pivotFields count="2">
+///     <pivotField axis="axisRow" showAll="0"/>
+///     <pivotField dataField="1" showAll="0"/>
+///   </pivotFields>
+/// </pivotTableDefinition>
+/// ```
+/// pivotTableDefinition (PivotTable Definition)
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct XlsxPivotTableDefinition {
+    // Attributes
+    /// name (PivotTable Name)
+    pub name: Option<String>,
+
+    /// cacheId (PivotCache Definition Id): cacheId of the pivotCache element of the workbook
+    pub cache_id: Option<u64>,
+
+    // Child Elements
+    // location (PivotTable Location)	§18.10.1.49
+    pub location: Option<XlsxPivotTableLocation>,
+}
+
+impl XlsxPivotTableDefinition {
+    pub(crate) fn load(zip: &mut ZipArchive<impl Read + Seek>, path: &str) -> anyhow::Result<Self> {
+        let mut definition = Self::default();
+
+        let Some(mut reader) = xml_reader(zip, path) else {
+            return Ok(definition);
+        };
+
+        let mut buf = Vec::new();
+
+        loop {
+            buf.clear();
+
+            match reader.read_event_into(&mut buf) {
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"pivotTableDefinition" => {
+                    for a in e.attributes() {
+                        match a {
+                            Ok(a) => {
+                                let string_value = String::from_utf8(a.value.to_vec())?;
+                                match a.key.local_name().as_ref() {
+                                    b"name" => definition.name = Some(string_value),
+                                    b"cacheId" => {
+                                        definition.cache_id = string_to_unsignedint(&string_value)
+                                    }
+                                    _ => {}
+                                }
+                            }
+                            Err(error) => bail!(error.to_string()),
+                        }
+                    }
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"location" => {
+                    definition.location = Some(XlsxPivotTableLocation::load(e)?);
+                }
+                // the layout of the pivot table is not read
+                Ok(Event::Start(ref e))
+                    if matches!(
+                        e.local_name().as_ref(),
+                        b"pivotFields"
+                            | b"rowFields"
+                            | b"rowItems"
+                            | b"colFields"
+                            | b"colItems"
+                            | b"dataFields"
+                            | b"formats"
+                            | b"extLst"
+                    ) =>
+                {
+                    let _ = reader.read_to_end_into(e.to_end().to_owned().name(), &mut Vec::new());
+                }
+                Ok(Event::Eof) => break,
+                Err(e) => bail!(e.to_string()),
+                _ => (),
+            }
+        }
+
+        return Ok(definition);
+    }
+}
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.location?view=openxml-3.0.1
+///
+/// Example
+/// ```xml
+/// <location ref="A3:B6" firstHeaderRow="1" firstDataRow="1" firstDataCol="1"/>
+/// ```
+/// location (PivotTable Location)
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct XlsxPivotTableLocation {
+    // Attributes
+    /// ref (Reference): range of the pivot table, page fields excluded
+    pub r#ref: Option<Dimension>,
+
+    /// firstHeaderRow (First Header Row): 0 based row of the column headers, relative to `ref`
+    pub first_header_row: Option<u64>,
+
+    /// firstDataRow (PivotTable Data First Row): 0 based, relative to `ref`
+    pub first_data_row: Option<u64>,
+
+    /// firstDataCol (PivotTable Data First Column): 0 based, relative to `ref`
+    pub first_data_col: Option<u64>,
+}
+
+impl XlsxPivotTableLocation {
+    pub(crate) fn load(e: &BytesStart) -> anyhow::Result<Self> {
+        let mut location = Self::default();
+
+        for a in e.attributes() {
+            match a {
+                Ok(a) => {
+                    let string_value = String::from_utf8(a.value.to_vec())?;
+                    match a.key.local_name().as_ref() {
+                        b"ref" => location.r#ref = Dimension::from_a1(string_value.as_bytes()),
+                        b"firstHeaderRow" => {
+                            location.first_header_row = string_to_unsignedint(&string_value)
+                        }
+                        b"firstDataRow" => {
+                            location.first_data_row = string_to_unsignedint(&string_value)
+                        }
+                        b"firstDataCol" => {
+                            location.first_data_col = string_to_unsignedint(&string_value)
+                        }
+                        _ => {}
+                    }
+                }
+                Err(error) => {
+                    bail!(error.to_string())
+                }
+            }
+        }
+
+        Ok(location)
+    }
+}
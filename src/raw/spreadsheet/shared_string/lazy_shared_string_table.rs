@@ -0,0 +1,125 @@
+use std::io::{Read, Seek};
+
+use anyhow::bail;
+use quick_xml::{events::Event, name::QName};
+use zip::ZipArchive;
+
+use crate::{
+    excel::{get_actual_path, xml_reader_from_bytes, ExcelOptions},
+    helper::string_to_unsignedint,
+    raw::spreadsheet::string_item::XlsxStringItem,
+};
+
+use super::shared_string_item::XlsxSharedStringItem;
+
+/// Shared string table indexed without parsing its items, see `ExcelOptions::lazy_shared_strings`.
+///
+/// Holds xl/sharedStrings.xml as decompressed and the position of every `si` element within it,
+/// an item is parsed when a cell refers to it.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct XlsxLazySharedStringTable {
+    xml: Vec<u8>,
+
+    /// byte position of the start tag of each `si` element
+    positions: Vec<usize>,
+
+    /// An integer representing the total count of strings in the workbook.
+    // count (String Count)
+    pub count: Option<u64>,
+
+    /// An integer representing the total count of unique strings in the Shared String Table.
+    // uniqueCount (Unique String Count)
+    pub unique_count: Option<u64>,
+}
+
+impl XlsxLazySharedStringTable {
+    /// * path: the shared strings part related to the workbook, ie: xl/sharedStrings.xml
+    pub(crate) fn load(
+        zip: &mut ZipArchive<impl Read + Seek>,
+        path: &str,
+        options: &ExcelOptions,
+    ) -> anyhow::Result<Self> {
+        let mut table = Self::default();
+        let Some(path) = get_actual_path(zip, path) else {
+            return Ok(table);
+        };
+        zip.by_name(&path)?.read_to_end(&mut table.xml)?;
+
+        let mut reader = xml_reader_from_bytes(&table.xml);
+        let mut buf: Vec<u8> = Vec::with_capacity(options.xml_buffer_size);
+        loop {
+            buf.clear();
+            let position = reader.buffer_position() as usize;
+
+            match reader.read_event_into(&mut buf) {
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"sst" => {
+                    for a in e.attributes() {
+                        match a {
+                            Ok(a) => {
+                                let string_value = String::from_utf8(a.value.to_vec())?;
+                                match a.key.local_name().as_ref() {
+                                    b"count" => {
+                                        table.count = string_to_unsignedint(&string_value);
+                                    }
+                                    b"uniqueCount" => {
+                                        table.unique_count = string_to_unsignedint(&string_value);
+                                    }
+                                    _ => {}
+                                }
+                            }
+                            Err(error) => {
+                                bail!(error.to_string())
+                            }
+                        }
+                    }
+                    table
+                        .positions
+                        .reserve(options.capacity_for(table.unique_count));
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"si" => {
+                    table.positions.push(position);
+                    // skipped without parsing its content
+                    let name = e.name().as_ref().to_vec();
+                    reader.read_to_end_into(QName(&name), &mut buf)?;
+                }
+                Ok(Event::End(ref e)) if e.local_name().as_ref() == b"sst" => break,
+                Ok(Event::Eof) => break,
+                Err(e) => bail!(e.to_string()),
+                _ => (),
+            }
+        }
+
+        return Ok(table);
+    }
+
+    /// Number of string items.
+    pub fn len(&self) -> usize {
+        return self.positions.len();
+    }
+
+    pub fn is_empty(&self) -> bool {
+        return self.positions.is_empty();
+    }
+
+    /// Parse the string item at an index (0 based), None if out of range.
+    pub fn get(&self, index: usize) -> anyhow::Result<Option<XlsxSharedStringItem>> {
+        let Some(position) = self.positions.get(index) else {
+            return Ok(None);
+        };
+        let mut reader = xml_reader_from_bytes(&self.xml[*position..]);
+        let mut buf: Vec<u8> = vec![];
+        match reader.read_event_into(&mut buf) {
+            Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"si" => {}
+            Ok(_) => bail!("shared string {} is not at its indexed position.", index),
+            Err(e) => bail!(e.to_string()),
+        }
+        return Ok(Some(XlsxStringItem::load(&mut reader, b"si")?));
+    }
+
+    /// Bytes held by the table: the decompressed part and the positions of its items.
+    pub(crate) fn size(&self) -> usize {
+        return size_of::<Self>()
+            + self.xml.capacity()
+            + self.positions.capacity() * size_of::<usize>();
+    }
+}
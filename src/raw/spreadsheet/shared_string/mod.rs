@@ -1,2 +1,3 @@
+pub mod lazy_shared_string_table;
 pub mod shared_string_item;
 pub mod shared_string_table;
@@ -1,12 +1,21 @@
-use std::io::{Read, Seek};
+use std::{
+    io::{Read, Seek},
+    sync::Arc,
+};
 
 use anyhow::bail;
 use quick_xml::events::Event;
 use zip::ZipArchive;
 
-use crate::{excel::xml_reader, helper::string_to_unsignedint};
+use crate::{
+    excel::{xml_reader_with_options, ExcelOptions},
+    helper::string_to_unsignedint,
+};
 
-use super::shared_string_item::{load_shared_string_item, XlsxSharedStringItem};
+use super::{
+    lazy_shared_string_table::XlsxLazySharedStringTable,
+    shared_string_item::{load_shared_string_item, XlsxSharedStringItem},
+};
 
 /// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.sharedstringtable?view=openxml-3.0.1
 ///
@@ -40,7 +49,7 @@ use super::shared_string_item::{load_shared_string_item, XlsxSharedStringItem};
 ///     </si>
 /// </sst>
 /// ```
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, Default, PartialEq)]
 pub struct XlsxSharedStringTable {
     // Child Elements
     // extLst (Future Feature Data Storage Area)	§18.2.10
@@ -60,8 +69,12 @@ pub struct XlsxSharedStringTable {
 }
 
 impl XlsxSharedStringTable {
-    pub(crate) fn load(zip: &mut ZipArchive<impl Read + Seek>) -> anyhow::Result<Self> {
-        let path = "xl/sharedStrings.xml";
+    /// * path: the shared strings part related to the workbook, ie: xl/sharedStrings.xml
+    pub(crate) fn load(
+        zip: &mut ZipArchive<impl Read + Seek>,
+        path: &str,
+        options: &ExcelOptions,
+    ) -> anyhow::Result<Self> {
 
         let mut shared_string = Self {
             string_item: None,
@@ -69,13 +82,13 @@ impl XlsxSharedStringTable {
             unique_count: None,
         };
 
-        let Some(mut reader) = xml_reader(zip, path) else {
+        let Some(mut reader) = xml_reader_with_options(zip, path, options) else {
             return Ok(shared_string);
         };
 
         let mut items: Vec<XlsxSharedStringItem> = vec![];
 
-        let mut buf: Vec<u8> = Vec::new();
+        let mut buf: Vec<u8> = Vec::with_capacity(options.xml_buffer_size);
         loop {
             buf.clear();
 
@@ -102,6 +115,7 @@ impl XlsxSharedStringTable {
                             }
                         }
                     }
+                    items.reserve(options.capacity_for(shared_string.unique_count));
                 }
                 Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"si" => {
                     items.push(load_shared_string_item(&mut reader)?);
@@ -118,3 +132,32 @@ impl XlsxSharedStringTable {
         return Ok(shared_string);
     }
 }
+
+/// Shared strings a worksheet reads the values of its cells from.
+#[derive(Debug, Clone)]
+pub(crate) enum SharedStrings {
+    /// items parsed when the table is loaded
+    Loaded(Arc<XlsxSharedStringTable>),
+    /// items parsed when a cell refers to them, see `ExcelOptions::lazy_shared_strings`
+    Lazy(Arc<XlsxLazySharedStringTable>),
+}
+
+impl Default for SharedStrings {
+    fn default() -> Self {
+        return Self::Loaded(Arc::default());
+    }
+}
+
+impl SharedStrings {
+    /// String item at an index (0 based) of the table.
+    pub(crate) fn get(&self, index: usize) -> anyhow::Result<XlsxSharedStringItem> {
+        let item = match self {
+            Self::Loaded(table) => table.string_item.as_ref().and_then(|items| items.get(index)).cloned(),
+            Self::Lazy(table) => table.get(index)?,
+        };
+        let Some(item) = item else {
+            bail!("Shared string index out of range.")
+        };
+        return Ok(item);
+    }
+}
@@ -3,4 +3,5 @@ pub mod worksheet;
 // common for sheet
 pub mod drawing;
 pub mod sheet_format_properties;
+pub mod sheet_properties;
 pub mod sheet_protection;
@@ -11,7 +11,7 @@ use crate::helper::{string_to_bool, string_to_float, string_to_unsignedint};
 /// ```
 /// <sheetFormatPr defaultColWidth="16.3333" defaultRowHeight="19.9" customHeight="1" outlineLevelRow="0" outlineLevelCol="0" />
 /// ```
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, Default, PartialEq)]
 pub struct XlsxSheetFormatProperties {
     // Attributes
     /// baseColWidth (Base Column Width)
@@ -0,0 +1,122 @@
+use anyhow::bail;
+use quick_xml::events::{BytesStart, Event};
+use std::io::Read;
+
+use crate::{excel::XmlReader, helper::string_to_bool};
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.sheetproperties?view=openxml-3.0.1
+///
+/// Sheet-level properties.
+///
+/// Example:
+/// ```
+/// <sheetPr codeName="Sheet1" filterMode="0">
+///   <pageSetUpPr fitToPage="1" />
+/// </sheetPr>
+/// ```
+/// sheetPr (Sheet Properties)
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct XlsxSheetProperties {
+    // Attributes
+    /// codeName (Code Name)
+    pub code_name: Option<String>,
+
+    /// filterMode (Filter Mode)
+    pub filter_mode: Option<bool>,
+
+    /// published (Published)
+    pub published: Option<bool>,
+
+    // Child Elements
+    // outlinePr (Outline Properties)
+    // tabColor (Sheet Tab Color)
+    /// pageSetUpPr (Page Setup Properties)
+    pub page_setup_properties: Option<XlsxPageSetupProperties>,
+}
+
+impl XlsxSheetProperties {
+    pub(crate) fn load(reader: &mut XmlReader<impl Read>, e: &BytesStart) -> anyhow::Result<Self> {
+        let mut properties = Self::default();
+
+        for a in e.attributes() {
+            match a {
+                Ok(a) => {
+                    let string_value = String::from_utf8(a.value.to_vec())?;
+                    match a.key.local_name().as_ref() {
+                        b"codeName" => properties.code_name = Some(string_value),
+                        b"filterMode" => properties.filter_mode = string_to_bool(&string_value),
+                        b"published" => properties.published = string_to_bool(&string_value),
+                        _ => {}
+                    }
+                }
+                Err(error) => {
+                    bail!(error.to_string())
+                }
+            }
+        }
+
+        let mut buf = Vec::new();
+        loop {
+            buf.clear();
+
+            match reader.read_event_into(&mut buf) {
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"pageSetUpPr" => {
+                    properties.page_setup_properties = Some(XlsxPageSetupProperties::load(e)?);
+                }
+                Ok(Event::End(ref e)) if e.local_name().as_ref() == b"sheetPr" => break,
+                Ok(Event::Eof) => bail!("unexpected end of file."),
+                Err(e) => bail!(e.to_string()),
+                _ => (),
+            }
+        }
+
+        Ok(properties)
+    }
+}
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.pagesetupproperties?view=openxml-3.0.1
+///
+/// Page setup properties of the sheet.
+///
+/// Example:
+/// ```
+/// <pageSetUpPr fitToPage="1" />
+/// ```
+/// pageSetUpPr (Page Setup Properties)
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct XlsxPageSetupProperties {
+    // Attributes
+    /// autoPageBreaks (Show Auto Page Breaks)
+    pub auto_page_breaks: Option<bool>,
+
+    /// fitToPage (Fit To Page)
+    ///
+    /// Scale the sheet to fitToWidth and fitToHeight pages of the page setup, instead of its scale.
+    pub fit_to_page: Option<bool>,
+}
+
+impl XlsxPageSetupProperties {
+    pub(crate) fn load(e: &BytesStart) -> anyhow::Result<Self> {
+        let mut properties = Self::default();
+
+        for a in e.attributes() {
+            match a {
+                Ok(a) => {
+                    let string_value = String::from_utf8(a.value.to_vec())?;
+                    match a.key.local_name().as_ref() {
+                        b"autoPageBreaks" => {
+                            properties.auto_page_breaks = string_to_bool(&string_value)
+                        }
+                        b"fitToPage" => properties.fit_to_page = string_to_bool(&string_value),
+                        _ => {}
+                    }
+                }
+                Err(error) => {
+                    bail!(error.to_string())
+                }
+            }
+        }
+
+        Ok(properties)
+    }
+}
@@ -1,3 +1,8 @@
+use anyhow::bail;
+use quick_xml::events::BytesStart;
+
+use crate::helper::{string_to_bool, string_to_unsignedint};
+
 /// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.sheetprotection?view=openxml-3.0.1
 ///
 /// This collection expresses the sheet protection options to enforce when the sheet is protected.
@@ -6,12 +11,198 @@
 /// ```
 /// <sheetProtection sheet="1" objects="1" scenarios="1" formatCells="0"  selectLockedCells="1"/>
 /// ```
-#[derive(Debug, Clone, PartialEq)]
+///
+/// Operation attributes (formatCells, insertRows, ...) set to true mean that the operation is locked while the sheet is protected.
+#[derive(Debug, Clone, Default, PartialEq)]
 pub struct XlsxSheetProtection {
-    //     AlgorithmName
-    // Cryptographic Algorithm Name
-    // Represents the following attribute in the schema: algorithmName
-    // AutoFilter
-    // AutoFilter Locked
-    // Represents the following attribute in the schema: autoFilter
+    // Attributes
+    /// algorithmName (Cryptographic Algorithm Name)
+    ///
+    /// Hashing algorithm used for `hashValue`, ie: SHA-512.
+    pub algorithm_name: Option<String>,
+
+    /// autoFilter (AutoFilter Locked)
+    ///
+    /// default true
+    pub auto_filter: Option<bool>,
+
+    /// deleteColumns (Delete Columns Locked)
+    ///
+    /// default true
+    pub delete_columns: Option<bool>,
+
+    /// deleteRows (Delete Rows Locked)
+    ///
+    /// default true
+    pub delete_rows: Option<bool>,
+
+    /// formatCells (Format Cells Locked)
+    ///
+    /// default true
+    pub format_cells: Option<bool>,
+
+    /// formatColumns (Format Columns Locked)
+    ///
+    /// default true
+    pub format_columns: Option<bool>,
+
+    /// formatRows (Format Rows Locked)
+    ///
+    /// default true
+    pub format_rows: Option<bool>,
+
+    /// hashValue (Password Hash Value)
+    ///
+    /// base64 encoded hash of the password
+    pub hash_value: Option<String>,
+
+    /// insertColumns (Insert Columns Locked)
+    ///
+    /// default true
+    pub insert_columns: Option<bool>,
+
+    /// insertHyperlinks (Insert Hyperlinks Locked)
+    ///
+    /// default true
+    pub insert_hyperlinks: Option<bool>,
+
+    /// insertRows (Insert Rows Locked)
+    ///
+    /// default true
+    pub insert_rows: Option<bool>,
+
+    /// objects (Objects Locked)
+    ///
+    /// default false
+    pub objects: Option<bool>,
+
+    /// password (Legacy Password)
+    ///
+    /// 16 bits hash of the password in hexadecimal, written by older applications instead of `hashValue`.
+    pub password: Option<String>,
+
+    /// pivotTables (Pivot Tables Locked)
+    ///
+    /// default true
+    pub pivot_tables: Option<bool>,
+
+    /// saltValue (Salt Value for Password Verifier)
+    ///
+    /// base64 encoded salt prepended to the password before hashing
+    pub salt_value: Option<String>,
+
+    /// scenarios (Scenarios Locked)
+    ///
+    /// default false
+    pub scenarios: Option<bool>,
+
+    /// selectLockedCells (Select Locked Cells Locked)
+    ///
+    /// default false
+    pub select_locked_cells: Option<bool>,
+
+    /// selectUnlockedCells (Select Unlocked Cells Locked)
+    ///
+    /// default false
+    pub select_unlocked_cells: Option<bool>,
+
+    /// sheet (Sheet Locked)
+    ///
+    /// True if the sheet is protected, the other attributes only apply then.
+    ///
+    /// default false
+    pub sheet: Option<bool>,
+
+    /// sort (Sort Locked)
+    ///
+    /// default true
+    pub sort: Option<bool>,
+
+    /// spinCount (Iterations to Run Hashing Algorithm)
+    pub spin_count: Option<u64>,
+}
+
+impl XlsxSheetProtection {
+    pub(crate) fn load(e: &BytesStart) -> anyhow::Result<Self> {
+        let attributes = e.attributes();
+        let mut protection = Self::default();
+
+        for a in attributes {
+            match a {
+                Ok(a) => {
+                    let string_value = String::from_utf8(a.value.to_vec())?;
+                    match a.key.local_name().as_ref() {
+                        b"algorithmName" => {
+                            protection.algorithm_name = Some(string_value);
+                        }
+                        b"autoFilter" => {
+                            protection.auto_filter = string_to_bool(&string_value);
+                        }
+                        b"deleteColumns" => {
+                            protection.delete_columns = string_to_bool(&string_value);
+                        }
+                        b"deleteRows" => {
+                            protection.delete_rows = string_to_bool(&string_value);
+                        }
+                        b"formatCells" => {
+                            protection.format_cells = string_to_bool(&string_value);
+                        }
+                        b"formatColumns" => {
+                            protection.format_columns = string_to_bool(&string_value);
+                        }
+                        b"formatRows" => {
+                            protection.format_rows = string_to_bool(&string_value);
+                        }
+                        b"hashValue" => {
+                            protection.hash_value = Some(string_value);
+                        }
+                        b"insertColumns" => {
+                            protection.insert_columns = string_to_bool(&string_value);
+                        }
+                        b"insertHyperlinks" => {
+                            protection.insert_hyperlinks = string_to_bool(&string_value);
+                        }
+                        b"insertRows" => {
+                            protection.insert_rows = string_to_bool(&string_value);
+                        }
+                        b"objects" => {
+                            protection.objects = string_to_bool(&string_value);
+                        }
+                        b"password" => {
+                            protection.password = Some(string_value);
+                        }
+                        b"pivotTables" => {
+                            protection.pivot_tables = string_to_bool(&string_value);
+                        }
+                        b"saltValue" => {
+                            protection.salt_value = Some(string_value);
+                        }
+                        b"scenarios" => {
+                            protection.scenarios = string_to_bool(&string_value);
+                        }
+                        b"selectLockedCells" => {
+                            protection.select_locked_cells = string_to_bool(&string_value);
+                        }
+                        b"selectUnlockedCells" => {
+                            protection.select_unlocked_cells = string_to_bool(&string_value);
+                        }
+                        b"sheet" => {
+                            protection.sheet = string_to_bool(&string_value);
+                        }
+                        b"sort" => {
+                            protection.sort = string_to_bool(&string_value);
+                        }
+                        b"spinCount" => {
+                            protection.spin_count = string_to_unsignedint(&string_value);
+                        }
+                        _ => {}
+                    }
+                }
+                Err(error) => {
+                    bail!(error.to_string())
+                }
+            }
+        }
+        Ok(protection)
+    }
 }
@@ -32,7 +32,7 @@ use crate::excel::XmlReader;
 /// ```
 ///
 /// v (Cell value)
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, Default, PartialEq)]
 pub struct XlsxCellValue {
     pub raw_value: String,
 
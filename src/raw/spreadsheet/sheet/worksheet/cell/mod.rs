@@ -7,7 +7,7 @@ use cell_value::XlsxCellValue;
 use inline_string::{load_inline_string, XlsxInlineString};
 
 use crate::{
-    common_types::Coordinate,
+    common_types::{Coordinate, PackedCoordinate},
     excel::XmlReader,
     helper::{string_to_bool, string_to_unsignedint},
 };
@@ -43,7 +43,7 @@ pub mod inline_string;
 ///     <is><t>This is inline string example</t></is>
 /// </c>
 /// ```
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, Default, PartialEq)]
 pub struct XlsxCell {
     /// extLst (Future Feature Data Storage Area)	Not supporte
 
@@ -77,8 +77,9 @@ pub struct XlsxCell {
     ///
     /// An A1 style reference to the location of this cell, ie: "A1".
     ///
-    /// Converted to R1C1
-    pub coordinate: Option<Coordinate>,
+    /// Converted to R1C1, stored packed to keep the per cell memory small.
+    /// Use `coordinate()` to read it.
+    coordinate: Option<PackedCoordinate>,
 
     /// s (Style Index)
     ///
@@ -101,6 +102,19 @@ pub struct XlsxCell {
 }
 
 impl XlsxCell {
+    /// Cell at `coordinate`, without value, formula or style.
+    pub fn new(coordinate: Coordinate) -> Self {
+        Self {
+            coordinate: PackedCoordinate::new(coordinate),
+            ..Default::default()
+        }
+    }
+
+    /// r (Reference) of the cell.
+    pub fn coordinate(&self) -> Option<Coordinate> {
+        self.coordinate.map(Coordinate::from)
+    }
+
     pub(crate) fn load(reader: &mut XmlReader<impl Read>, e: &BytesStart) -> anyhow::Result<Self> {
         let mut cell = Self {
             formula: None,
@@ -127,7 +141,7 @@ impl XlsxCell {
                             cell.show_phonetic = string_to_bool(&string_value);
                         }
                         b"r" => {
-                            cell.coordinate = Coordinate::from_a1(&a.value);
+                            cell.coordinate = Coordinate::from_a1(&a.value).and_then(PackedCoordinate::new);
                         }
                         b"s" => {
                             cell.style = string_to_unsignedint(&string_value);
@@ -51,7 +51,7 @@ pub(crate) fn load_column_infos(reader: &mut XmlReader<impl Read>) -> anyhow::Re
 /// <col min="1" max="5" width="16.3516" style="1" customWidth="1" />
 /// ```
 /// col (Column Width & Formatting)
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, Default, PartialEq)]
 pub struct XlsxColumnInformation {
     /// Attributes
     /// bestFit (Best Fit Column Width)
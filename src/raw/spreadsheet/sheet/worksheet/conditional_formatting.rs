@@ -0,0 +1,206 @@
+use anyhow::bail;
+use quick_xml::events::{BytesStart, Event};
+use std::io::Read;
+
+use crate::{
+    excel::XmlReader,
+    helper::{extract_text_contents, string_to_bool, string_to_int, string_to_unsignedint},
+};
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.conditionalformatting?view=openxml-3.0.1
+///
+/// A conditional formatting on a range of cells, with its rules.
+///
+/// Example:
+/// ```
+/// <conditionalFormatting sqref="B2:B10 D2:D10">
+///     <cfRule type="cellIs" dxfId="0" priority="1" operator="greaterThan">
+///         <formula>100</formula>
+///     </cfRule>
+///     <cfRule type="colorScale" priority="2">
+///         <colorScale>
+///             <cfvo type="min"/>
+///             <cfvo type="max"/>
+///             <color rgb="FFF8696B"/>
+///             <color rgb="FF63BE7B"/>
+///         </colorScale>
+///     </cfRule>
+/// </conditionalFormatting>
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct XlsxConditionalFormatting {
+    // extLst (Future Feature Data Storage Area) Not supported
+
+    // Child Elements
+    // cfRule (Conditional Formatting Rule)
+    pub rules: Vec<XlsxConditionalFormattingRule>,
+
+    // Attributes
+    /// pivot (PivotTable Conditional Formatting)
+    pub pivot: Option<bool>,
+
+    /// sqref (Sequence of References)
+    pub sqref: Option<String>,
+}
+
+impl XlsxConditionalFormatting {
+    pub(crate) fn load(reader: &mut XmlReader<impl Read>, e: &BytesStart) -> anyhow::Result<Self> {
+        let mut formatting = Self::default();
+
+        for a in e.attributes() {
+            match a {
+                Ok(a) => {
+                    let string_value = String::from_utf8(a.value.to_vec())?;
+                    match a.key.local_name().as_ref() {
+                        b"pivot" => formatting.pivot = string_to_bool(&string_value),
+                        b"sqref" => formatting.sqref = Some(string_value),
+                        _ => {}
+                    }
+                }
+                Err(error) => bail!(error.to_string()),
+            }
+        }
+
+        let mut buf = Vec::new();
+        loop {
+            buf.clear();
+
+            match reader.read_event_into(&mut buf) {
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"extLst" => {
+                    let _ = reader.read_to_end_into(e.to_end().to_owned().name(), &mut Vec::new());
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"cfRule" => {
+                    formatting
+                        .rules
+                        .push(XlsxConditionalFormattingRule::load(reader, e)?);
+                }
+                Ok(Event::End(ref e)) if e.local_name().as_ref() == b"conditionalFormatting" => {
+                    break
+                }
+                Ok(Event::Eof) => bail!("unexpected end of file at `conditionalFormatting`."),
+                Err(e) => bail!(e.to_string()),
+                _ => (),
+            }
+        }
+
+        return Ok(formatting);
+    }
+}
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.conditionalformattingrule?view=openxml-3.0.1
+///
+/// Example:
+/// ```
+/// <cfRule type="cellIs" dxfId="0" priority="1" operator="between">
+///     <formula>1</formula>
+///     <formula>10</formula>
+/// </cfRule>
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct XlsxConditionalFormattingRule {
+    // Child Elements
+    // colorScale (Color Scale)	Not supported
+    // dataBar (Data Bar)	Not supported
+    // extLst (Future Feature Data Storage Area) Not supported
+    // iconSet (Icon Set)	Not supported
+
+    // formula (Formula)
+    pub formulas: Vec<String>,
+
+    // Attributes
+    /// aboveAverage (Above Or Below Average)
+    pub above_average: Option<bool>,
+
+    /// bottom (Bottom N)
+    pub bottom: Option<bool>,
+
+    /// dxfId (Differential Formatting Id)
+    pub dxf_id: Option<u64>,
+
+    /// equalAverage (Equal Average)
+    pub equal_average: Option<bool>,
+
+    /// operator (Operator), ie: `between`, `greaterThan`
+    pub operator: Option<String>,
+
+    /// percent (Top 10 Percent)
+    pub percent: Option<bool>,
+
+    /// priority (Priority): 1 for the rule applied first
+    pub priority: Option<u64>,
+
+    /// rank (Rank) of top 10 rules
+    pub rank: Option<u64>,
+
+    /// stdDev (StdDev): number of standard deviations of `aboveAverage` rules
+    pub std_dev: Option<i64>,
+
+    /// stopIfTrue (Stop If True)
+    pub stop_if_true: Option<bool>,
+
+    /// text (Text) of `containsText`, `beginsWith` and `endsWith` rules
+    pub text: Option<String>,
+
+    /// timePeriod (Time Period), ie: `today`, `lastWeek`
+    pub time_period: Option<String>,
+
+    /// type (Type), ie: `cellIs`, `expression`, `colorScale`, `dataBar`, `iconSet`, `top10`
+    pub r#type: Option<String>,
+}
+
+impl XlsxConditionalFormattingRule {
+    fn load(reader: &mut XmlReader<impl Read>, e: &BytesStart) -> anyhow::Result<Self> {
+        let mut rule = Self::default();
+
+        for a in e.attributes() {
+            match a {
+                Ok(a) => {
+                    let string_value = String::from_utf8(a.value.to_vec())?;
+                    match a.key.local_name().as_ref() {
+                        b"aboveAverage" => rule.above_average = string_to_bool(&string_value),
+                        b"bottom" => rule.bottom = string_to_bool(&string_value),
+                        b"dxfId" => rule.dxf_id = string_to_unsignedint(&string_value),
+                        b"equalAverage" => rule.equal_average = string_to_bool(&string_value),
+                        b"operator" => rule.operator = Some(string_value),
+                        b"percent" => rule.percent = string_to_bool(&string_value),
+                        b"priority" => rule.priority = string_to_unsignedint(&string_value),
+                        b"rank" => rule.rank = string_to_unsignedint(&string_value),
+                        b"stdDev" => rule.std_dev = string_to_int(&string_value),
+                        b"stopIfTrue" => rule.stop_if_true = string_to_bool(&string_value),
+                        b"text" => rule.text = Some(string_value),
+                        b"timePeriod" => rule.time_period = Some(string_value),
+                        b"type" => rule.r#type = Some(string_value),
+                        _ => {}
+                    }
+                }
+                Err(error) => bail!(error.to_string()),
+            }
+        }
+
+        let mut buf = Vec::new();
+        loop {
+            buf.clear();
+
+            match reader.read_event_into(&mut buf) {
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"formula" => {
+                    rule.formulas
+                        .push(extract_text_contents(reader, b"formula")?);
+                }
+                Ok(Event::Start(ref e))
+                    if matches!(
+                        e.local_name().as_ref(),
+                        b"colorScale" | b"dataBar" | b"iconSet" | b"extLst"
+                    ) =>
+                {
+                    let _ = reader.read_to_end_into(e.to_end().to_owned().name(), &mut Vec::new());
+                }
+                Ok(Event::End(ref e)) if e.local_name().as_ref() == b"cfRule" => break,
+                Ok(Event::Eof) => bail!("unexpected end of file at `cfRule`."),
+                Err(e) => bail!(e.to_string()),
+                _ => (),
+            }
+        }
+
+        return Ok(rule);
+    }
+}
@@ -0,0 +1,88 @@
+use anyhow::bail;
+use quick_xml::events::{BytesStart, Event};
+use std::io::Read;
+
+use crate::excel::XmlReader;
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.customproperties?view=openxml-3.0.1
+///
+/// Custom properties of the sheet, the data of each property is stored in a separate binary part.
+///
+/// Example
+/// ```xml
+/// <customProperties>
+///   <customPr name="_pios_id" r:id="rId2"/>
+/// </customProperties>
+/// ```
+pub type XlsxCustomProperties = Vec<XlsxCustomProperty>;
+
+pub(crate) fn load_custom_properties(
+    reader: &mut XmlReader<impl Read>,
+) -> anyhow::Result<XlsxCustomProperties> {
+    let mut properties: XlsxCustomProperties = vec![];
+
+    let mut buf = Vec::new();
+    loop {
+        buf.clear();
+
+        match reader.read_event_into(&mut buf) {
+            Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"customPr" => {
+                properties.push(XlsxCustomProperty::load(e)?);
+            }
+            Ok(Event::End(ref e)) if e.local_name().as_ref() == b"customProperties" => break,
+            Ok(Event::Eof) => bail!("unexpected end of file."),
+            Err(e) => bail!(e.to_string()),
+            _ => (),
+        }
+    }
+
+    Ok(properties)
+}
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.customproperty?view=openxml-3.0.1
+///
+/// A single custom property.
+///
+/// Example:
+/// ```xml
+/// <customPr name="_pios_id" r:id="rId2"/>
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct XlsxCustomProperty {
+    // attributes
+    /// name (Custom Property Name)
+    pub name: Option<String>,
+
+    /// id (Relationship Id)
+    ///
+    /// Relationship to the customProperty{}.bin part holding the data.
+    pub id: Option<String>,
+}
+
+impl XlsxCustomProperty {
+    pub(crate) fn load(e: &BytesStart) -> anyhow::Result<Self> {
+        let attributes = e.attributes();
+        let mut property = Self {
+            name: None,
+            id: None,
+        };
+
+        for a in attributes {
+            match a {
+                Ok(a) => {
+                    let string_value = String::from_utf8(a.value.to_vec())?;
+                    match a.key.local_name().as_ref() {
+                        b"name" => property.name = Some(string_value),
+                        b"id" => property.id = Some(string_value),
+                        _ => {}
+                    }
+                }
+                Err(error) => {
+                    bail!(error.to_string())
+                }
+            }
+        }
+
+        Ok(property)
+    }
+}
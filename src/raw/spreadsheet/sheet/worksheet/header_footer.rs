@@ -0,0 +1,117 @@
+use anyhow::bail;
+use quick_xml::events::{BytesStart, Event};
+use std::io::Read;
+
+use crate::{excel::XmlReader, helper::string_to_bool};
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.headerfooter?view=openxml-3.0.1
+///
+/// Header and footer text used when printing the sheet.
+/// The text contains formatting codes, ie: `&C` (center section), `&P` (page number).
+///
+/// Example
+/// ```xml
+/// <headerFooter differentFirst="1">
+///   <oddHeader>&amp;CQuarterly Report</oddHeader>
+///   <oddFooter>&amp;C&amp;"Helvetica Neue,Regular"&amp;12&amp;K000000&amp;P</oddFooter>
+/// </headerFooter>
+/// ```
+/// headerFooter (Header Footer Settings)
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct XlsxHeaderFooter {
+    // Attributes
+    /// differentFirst (Different First)
+    pub different_first: Option<bool>,
+
+    /// differentOddEven (Different Odd Even Header Footer)
+    pub different_odd_even: Option<bool>,
+
+    // Child Elements
+    /// oddHeader (Odd Header)
+    pub odd_header: Option<String>,
+
+    /// oddFooter (Odd Page Footer)
+    pub odd_footer: Option<String>,
+
+    /// evenHeader (Even Page Header)
+    pub even_header: Option<String>,
+
+    /// evenFooter (Even Page Footer)
+    pub even_footer: Option<String>,
+
+    /// firstHeader (First Header)
+    pub first_header: Option<String>,
+
+    /// firstFooter (First Footer)
+    pub first_footer: Option<String>,
+}
+
+impl XlsxHeaderFooter {
+    pub(crate) fn load(reader: &mut XmlReader<impl Read>, e: &BytesStart) -> anyhow::Result<Self> {
+        let mut header_footer = Self::default();
+
+        for a in e.attributes() {
+            match a {
+                Ok(a) => {
+                    let string_value = String::from_utf8(a.value.to_vec())?;
+                    match a.key.local_name().as_ref() {
+                        b"differentFirst" => {
+                            header_footer.different_first = string_to_bool(&string_value)
+                        }
+                        b"differentOddEven" => {
+                            header_footer.different_odd_even = string_to_bool(&string_value)
+                        }
+                        _ => {}
+                    }
+                }
+                Err(error) => {
+                    bail!(error.to_string())
+                }
+            }
+        }
+
+        let mut buf = Vec::new();
+        let mut text = String::new();
+        loop {
+            buf.clear();
+
+            match reader.read_event_into(&mut buf) {
+                Ok(Event::Start(_)) => text.clear(),
+                Ok(Event::Text(t)) => text.push_str(&t.unescape()?),
+                Ok(Event::End(ref e)) if e.local_name().as_ref() == b"headerFooter" => break,
+                Ok(Event::End(ref e)) => {
+                    let text = std::mem::take(&mut text);
+                    match e.local_name().as_ref() {
+                        b"oddHeader" => header_footer.odd_header = Some(text),
+                        b"oddFooter" => header_footer.odd_footer = Some(text),
+                        b"evenHeader" => header_footer.even_header = Some(text),
+                        b"evenFooter" => header_footer.even_footer = Some(text),
+                        b"firstHeader" => header_footer.first_header = Some(text),
+                        b"firstFooter" => header_footer.first_footer = Some(text),
+                        _ => {}
+                    }
+                }
+                Ok(Event::Eof) => bail!("unexpected end of file."),
+                Err(e) => bail!(e.to_string()),
+                _ => (),
+            }
+        }
+
+        Ok(header_footer)
+    }
+
+    /// (element name, text) of the headers and footers that are set.
+    pub(crate) fn texts(&self) -> Vec<(&'static str, &str)> {
+        [
+            ("oddHeader", &self.odd_header),
+            ("oddFooter", &self.odd_footer),
+            ("evenHeader", &self.even_header),
+            ("evenFooter", &self.even_footer),
+            ("firstHeader", &self.first_header),
+            ("firstFooter", &self.first_footer),
+        ]
+        .into_iter()
+        .filter_map(|(name, text)| Some((name, text.as_deref()?)))
+        .collect()
+    }
+}
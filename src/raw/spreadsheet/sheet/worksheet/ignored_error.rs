@@ -0,0 +1,157 @@
+use std::io::Read;
+use anyhow::bail;
+use quick_xml::events::{BytesStart, Event};
+
+use crate::{common_types::Dimension, excel::XmlReader, helper::string_to_bool};
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.ignorederrors?view=openxml-3.0.1
+///
+/// This collection represents the cell ranges for which specific cell errors should be ignored.
+///
+/// Example:
+/// ```
+/// <ignoredErrors>
+///   <ignoredError sqref="A1:B3 D5" numberStoredAsText="1"/>
+/// </ignoredErrors>
+/// ```
+pub type XlsxIgnoredErrors = Vec<XlsxIgnoredError>;
+
+pub(crate) fn load_ignored_errors(reader: &mut XmlReader<impl Read>) -> anyhow::Result<XlsxIgnoredErrors> {
+    let mut errors: XlsxIgnoredErrors = vec![];
+
+    let mut buf = Vec::new();
+    loop {
+        buf.clear();
+
+        match reader.read_event_into(&mut buf) {
+            Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"ignoredError" => {
+                errors.push(XlsxIgnoredError::load(e)?);
+            }
+            Ok(Event::End(ref e)) if e.local_name().as_ref() == b"ignoredErrors" => break,
+            Ok(Event::Eof) => bail!("unexpected end of file at `ignoredErrors`."),
+            Err(e) => bail!(e.to_string()),
+            _ => (),
+        }
+    }
+
+    Ok(errors)
+}
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.ignorederror?view=openxml-3.0.1
+///
+/// A single ignored error, applied to a sequence of references.
+#[derive(Debug, Clone, PartialEq)]
+pub struct XlsxIgnoredError {
+    // Attributes
+    /// calculatedColumn (Calculated Column)
+    ///
+    /// Ignore errors when cells contain a value different from a calculated column formula.
+    pub calculated_column: Option<bool>,
+
+    /// emptyCellReference (Empty Cell Reference)
+    ///
+    /// Ignore errors when formulas refer to empty cells.
+    pub empty_cell_reference: Option<bool>,
+
+    /// evalError (Evaluation Error)
+    ///
+    /// Ignore errors when cells contain formulas that result in an error.
+    pub eval_error: Option<bool>,
+
+    /// formula (Formula)
+    ///
+    /// Ignore errors when a formula in a region of your worksheet differs from other formulas in the same region.
+    pub formula: Option<bool>,
+
+    /// formulaRange (Formula Range)
+    ///
+    /// Ignore errors when formulas omit certain cells in a region.
+    pub formula_range: Option<bool>,
+
+    /// listDataValidation (Data Validation)
+    ///
+    /// Ignore errors when a cell's value in a Table does not comply with the Data Validation rules specified.
+    pub list_data_validation: Option<bool>,
+
+    /// numberStoredAsText (Number Stored As Text)
+    ///
+    /// Ignore errors when numbers are formatted as text or are preceded by an apostrophe.
+    pub number_stored_as_text: Option<bool>,
+
+    /// sqref (Sequence of References)
+    pub sqref: Vec<Dimension>,
+
+    /// twoDigitTextYear (Two Digit Text Year)
+    ///
+    /// Ignore errors when formulas contain text formatted cells with years represented as 2 digits.
+    pub two_digit_text_year: Option<bool>,
+
+    /// unlockedFormula (Unlocked Formula)
+    ///
+    /// Ignore errors when unlocked cells contain formulas.
+    pub unlocked_formula: Option<bool>,
+}
+
+impl XlsxIgnoredError {
+    pub(crate) fn load(e: &BytesStart) -> anyhow::Result<Self> {
+        let mut error = Self {
+            calculated_column: None,
+            empty_cell_reference: None,
+            eval_error: None,
+            formula: None,
+            formula_range: None,
+            list_data_validation: None,
+            number_stored_as_text: None,
+            sqref: vec![],
+            two_digit_text_year: None,
+            unlocked_formula: None,
+        };
+
+        let attributes = e.attributes();
+        for a in attributes {
+            match a {
+                Ok(a) => {
+                    let string_value = String::from_utf8(a.value.to_vec())?;
+                    match a.key.local_name().as_ref() {
+                        b"calculatedColumn" => {
+                            error.calculated_column = string_to_bool(&string_value);
+                        }
+                        b"emptyCellReference" => {
+                            error.empty_cell_reference = string_to_bool(&string_value);
+                        }
+                        b"evalError" => {
+                            error.eval_error = string_to_bool(&string_value);
+                        }
+                        b"formula" => {
+                            error.formula = string_to_bool(&string_value);
+                        }
+                        b"formulaRange" => {
+                            error.formula_range = string_to_bool(&string_value);
+                        }
+                        b"listDataValidation" => {
+                            error.list_data_validation = string_to_bool(&string_value);
+                        }
+                        b"numberStoredAsText" => {
+                            error.number_stored_as_text = string_to_bool(&string_value);
+                        }
+                        b"sqref" => {
+                            error.sqref = Dimension::from_sqref(&string_value);
+                        }
+                        b"twoDigitTextYear" => {
+                            error.two_digit_text_year = string_to_bool(&string_value);
+                        }
+                        b"unlockedFormula" => {
+                            error.unlocked_formula = string_to_bool(&string_value);
+                        }
+                        _ => {}
+                    }
+                }
+                Err(error) => {
+                    bail!(error.to_string())
+                }
+            }
+        }
+
+        Ok(error)
+    }
+}
@@ -1,8 +1,16 @@
 pub mod cell;
 pub mod column_information;
+pub mod conditional_formatting;
+pub mod custom_property;
 pub mod data_validation;
+pub mod header_footer;
 pub mod hyperlink;
+pub mod ignored_error;
 pub mod merge_cell;
+pub mod page_break;
+pub mod page_margins;
+pub mod page_setup;
+pub mod print_options;
 pub mod row;
 pub mod sheet_data;
 pub mod sheet_dimension;
@@ -11,20 +19,31 @@ pub mod table_part;
 
 use anyhow::bail;
 use column_information::{load_column_infos, XlsxColumnInformations};
+use conditional_formatting::XlsxConditionalFormatting;
+use custom_property::{load_custom_properties, XlsxCustomProperties};
 use data_validation::XlsxDataValidations;
+use header_footer::XlsxHeaderFooter;
 use hyperlink::{load_hyperlinks, XlsxHyperlinks};
+use ignored_error::{load_ignored_errors, XlsxIgnoredErrors};
 use merge_cell::{load_merge_cells, XlsxMergeCells};
+use page_break::XlsxPageBreaks;
+use page_margins::XlsxPageMargins;
+use page_setup::XlsxPageSetup;
+use print_options::XlsxPrintOptions;
 use quick_xml::events::Event;
-use sheet_data::XlsxSheetData;
+use sheet_data::{SheetDataMode, XlsxSheetData};
 use sheet_dimension::{load_sheet_dimension, XlsxSheetDimension};
 use std::io::{Read, Seek};
 use table_part::{load_table_parts, XlsxTableParts};
 use zip::ZipArchive;
 use sheet_view::{load_sheet_views, XlsxSheetView};
 
-use super::{drawing::XlsxDrawing, sheet_format_properties::XlsxSheetFormatProperties};
+use super::{
+    drawing::XlsxDrawing, sheet_format_properties::XlsxSheetFormatProperties,
+    sheet_properties::XlsxSheetProperties, sheet_protection::XlsxSheetProtection,
+};
 use crate::{
-    excel::xml_reader,
+    excel::{xml_reader_with_options, ExcelOptions},
     raw::spreadsheet::{
         filter::auto_filter::XlsxAutoFilter,
         string_item::phonetic_properties::XlsxPhoneticProperties,
@@ -80,12 +99,17 @@ pub struct XlsxWorksheet {
     pub auto_filter: Option<XlsxAutoFilter>,
     // cellWatches (Cell Watch Items)	§18.3.1.9
     // colBreaks (Vertical Page Breaks)	§18.3.1.14
+    pub column_breaks: Option<XlsxPageBreaks>,
 
     // cols (Column Information)	§18.3.1.17
     pub column_infos: Option<XlsxColumnInformations>,
     // conditionalFormatting (Conditional Formatting)	§18.3.1.18
+    pub conditional_formattings: Option<Vec<XlsxConditionalFormatting>>,
     // controls (Embedded Controls)	§18.3.1.21
+
     // customProperties (Custom Properties)	§18.3.1.23
+    pub custom_properties: Option<XlsxCustomProperties>,
+
     // customSheetViews (Custom Sheet Views)	§18.3.1.27
     // dataConsolidate (Data Consolidate)	§18.3.1.29
     // dataValidations (Data Validations)	§18.3.1.33
@@ -98,26 +122,37 @@ pub struct XlsxWorksheet {
     pub drawing: Option<XlsxDrawing>,
 
     // drawingHF (Drawing Reference in Header Footer)	§18.3.1.37
+
     // headerFooter (Header Footer Settings)	§18.3.1.46
+    pub header_footer: Option<XlsxHeaderFooter>,
 
     // hyperlinks (Hyperlinks)
     pub hyperlinks: Option<XlsxHyperlinks>,
 
     // ignoredErrors (Ignored Errors)	§18.3.1.51
+    pub ignored_errors: Option<XlsxIgnoredErrors>,
 
     // mergeCells (Merge Cells)	§18.3.1.55
     pub merge_cells: Option<XlsxMergeCells>,
 
     // oleObjects (Embedded Objects)	§18.3.1.60
     // pageMargins (Page Margins)	§18.3.1.62
+    pub page_margins: Option<XlsxPageMargins>,
+
     // pageSetup (Page Setup Settings)	§18.3.1.63
+    pub page_setup: Option<XlsxPageSetup>,
+
     // phoneticPr (Phonetic Properties)	§18.4.3
     pub phonetic_properties: Option<XlsxPhoneticProperties>,
 
     // picture (Background Image)	§18.3.1.67
     // printOptions (Print Options)	§18.3.1.70
+    pub print_options: Option<XlsxPrintOptions>,
+
     // protectedRanges (Protected Ranges)	§18.3.1.72
     // rowBreaks (Horizontal Page Breaks (Row))	§18.3.1.74
+    pub row_breaks: Option<XlsxPageBreaks>,
+
     // scenarios (Scenarios)	§18.3.1.76
     // sheetCalcPr (Sheet Calculation Properties)	§18.3.1.79
 
@@ -127,7 +162,11 @@ pub struct XlsxWorksheet {
     // sheetFormatPr (Sheet Format Properties)	§18.3.1.81
     pub sheet_format_properties: Option<XlsxSheetFormatProperties>,
     // sheetPr (Sheet Properties)	§18.3.1.82
+    pub sheet_properties: Option<XlsxSheetProperties>,
+
     // sheetProtection (Sheet Protection Options)	§18.3.1.85
+    pub sheet_protection: Option<XlsxSheetProtection>,
+
     // sheetViews (Sheet Views)	§18.3.1.88
     pub sheet_views: Option<Vec<XlsxSheetView>>,
     // smartTags (Smart Tags)	§18.3.1.90
@@ -138,27 +177,44 @@ pub struct XlsxWorksheet {
 }
 
 impl XlsxWorksheet {
-    pub(crate) fn load(zip: &mut ZipArchive<impl Read + Seek>, path: &str) -> anyhow::Result<Self> {
+    /// * sheet_data: part of the cells table (sheetData) to load, ie: skipped when the rows are read one by one later on
+    pub(crate) fn load(
+        zip: &mut ZipArchive<impl Read + Seek>,
+        path: &str,
+        options: &ExcelOptions,
+        sheet_data: SheetDataMode,
+    ) -> anyhow::Result<Self> {
         let mut worksheet = Self {
             auto_filter: None,
+            column_breaks: None,
             column_infos: None,
+            conditional_formattings: None,
+            custom_properties: None,
             data_validations: None,
             dimension: None,
             drawing: None,
+            header_footer: None,
             hyperlinks: None,
+            ignored_errors: None,
             merge_cells: None,
+            page_margins: None,
+            page_setup: None,
             phonetic_properties: None,
+            print_options: None,
+            row_breaks: None,
             sheet_data: None,
             sheet_format_properties: None,
+            sheet_properties: None,
+            sheet_protection: None,
             sheet_views: None,
             table_parts: None,
         };
 
-        let Some(mut reader) = xml_reader(zip, path) else {
+        let Some(mut reader) = xml_reader_with_options(zip, path, options) else {
             return Ok(worksheet);
         };
 
-        let mut buf = Vec::new();
+        let mut buf = Vec::with_capacity(options.xml_buffer_size);
 
         loop {
             buf.clear();
@@ -170,9 +226,21 @@ impl XlsxWorksheet {
                 Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"autoFilter" => {
                     worksheet.auto_filter = Some(XlsxAutoFilter::load(&mut reader, e)?);
                 }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"colBreaks" => {
+                    worksheet.column_breaks = Some(XlsxPageBreaks::load(&mut reader, e)?);
+                }
                 Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"cols" => {
                     worksheet.column_infos = Some(load_column_infos(&mut reader)?);
                 }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"conditionalFormatting" => {
+                    worksheet
+                        .conditional_formattings
+                        .get_or_insert_with(Vec::new)
+                        .push(XlsxConditionalFormatting::load(&mut reader, e)?);
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"customProperties" => {
+                    worksheet.custom_properties = Some(load_custom_properties(&mut reader)?);
+                }
                 Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"dataValidations" => {
                     worksheet.data_validations = Some(XlsxDataValidations::load(&mut reader)?);
                 }
@@ -182,21 +250,58 @@ impl XlsxWorksheet {
                 Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"drawing" => {
                     worksheet.drawing = Some(XlsxDrawing::load(e)?);
                 }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"headerFooter" => {
+                    worksheet.header_footer = Some(XlsxHeaderFooter::load(&mut reader, e)?);
+                }
                 Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"hyperlinks" => {
                     worksheet.hyperlinks = Some(load_hyperlinks(&mut reader)?);
                 }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"ignoredErrors" => {
+                    worksheet.ignored_errors = Some(load_ignored_errors(&mut reader)?);
+                }
                 Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"mergeCells" => {
                     worksheet.merge_cells = Some(load_merge_cells(&mut reader)?);
                 }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"pageMargins" => {
+                    worksheet.page_margins = Some(XlsxPageMargins::load(e)?);
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"pageSetup" => {
+                    worksheet.page_setup = Some(XlsxPageSetup::load(e)?);
+                }
                 Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"phoneticPr" => {
                     worksheet.phonetic_properties = Some(XlsxPhoneticProperties::load(e)?);
                 }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"printOptions" => {
+                    worksheet.print_options = Some(XlsxPrintOptions::load(e)?);
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"rowBreaks" => {
+                    worksheet.row_breaks = Some(XlsxPageBreaks::load(&mut reader, e)?);
+                }
+                Ok(Event::Start(ref e))
+                    if e.local_name().as_ref() == b"sheetData" && sheet_data == SheetDataMode::Skip =>
+                {
+                    let _ = reader.read_to_end_into(e.to_end().to_owned().name(), &mut Vec::new());
+                }
                 Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"sheetData" => {
-                    worksheet.sheet_data = Some(XlsxSheetData::load(&mut reader)?);
+                    let row_count = worksheet
+                        .dimension
+                        .map(|d| d.end.row.saturating_sub(d.start.row).saturating_add(1));
+                    worksheet.sheet_data = Some(XlsxSheetData::load(
+                        &mut reader,
+                        options,
+                        row_count,
+                        sheet_data == SheetDataMode::Cells,
+                    )?);
                 }
                 Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"sheetFormatPr" => {
                     worksheet.sheet_format_properties = Some(XlsxSheetFormatProperties::load(e)?);
                 }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"sheetPr" => {
+                    worksheet.sheet_properties = Some(XlsxSheetProperties::load(&mut reader, e)?);
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"sheetProtection" => {
+                    worksheet.sheet_protection = Some(XlsxSheetProtection::load(e)?);
+                }
                 Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"sheetViews" => {
                     worksheet.sheet_views = Some(load_sheet_views(&mut reader)?);
                 },
@@ -0,0 +1,126 @@
+use anyhow::bail;
+use quick_xml::events::{BytesStart, Event};
+use std::io::Read;
+
+use crate::{
+    excel::XmlReader,
+    helper::{string_to_bool, string_to_unsignedint},
+};
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.rowbreaks?view=openxml-3.0.1
+///
+/// Page breaks of a sheet, used by both rowBreaks (Horizontal Page Breaks) and colBreaks (Vertical Page Breaks).
+///
+/// Example:
+/// ```
+/// <rowBreaks count="1" manualBreakCount="1">
+///   <brk id="20" max="16383" man="1" />
+/// </rowBreaks>
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct XlsxPageBreaks {
+    // Attributes
+    /// count (Page Break Count)
+    pub count: Option<u64>,
+
+    /// manualBreakCount (Manual Break Count)
+    pub manual_break_count: Option<u64>,
+
+    // Child Elements
+    /// brk (Break)
+    pub breaks: Vec<XlsxBreak>,
+}
+
+impl XlsxPageBreaks {
+    pub(crate) fn load(reader: &mut XmlReader<impl Read>, e: &BytesStart) -> anyhow::Result<Self> {
+        let mut page_breaks = Self::default();
+        let tag = e.local_name().as_ref().to_vec();
+
+        for a in e.attributes() {
+            match a {
+                Ok(a) => {
+                    let string_value = String::from_utf8(a.value.to_vec())?;
+                    match a.key.local_name().as_ref() {
+                        b"count" => page_breaks.count = string_to_unsignedint(&string_value),
+                        b"manualBreakCount" => {
+                            page_breaks.manual_break_count = string_to_unsignedint(&string_value)
+                        }
+                        _ => {}
+                    }
+                }
+                Err(error) => {
+                    bail!(error.to_string())
+                }
+            }
+        }
+
+        let mut buf = Vec::new();
+        loop {
+            buf.clear();
+
+            match reader.read_event_into(&mut buf) {
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"brk" => {
+                    page_breaks.breaks.push(XlsxBreak::load(e)?);
+                }
+                Ok(Event::End(ref e)) if e.local_name().as_ref() == tag.as_slice() => break,
+                Ok(Event::Eof) => bail!("unexpected end of file."),
+                Err(e) => bail!(e.to_string()),
+                _ => (),
+            }
+        }
+
+        Ok(page_breaks)
+    }
+}
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.break?view=openxml-3.0.1
+///
+/// A page break, placed before the row or column `id` (0 based),
+/// ie: after the row or column `id` in 1 based indexes.
+///
+/// brk (Break)
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct XlsxBreak {
+    // Attributes
+    /// id (Id): 0 based index of the first row or column after the break
+    pub id: Option<u64>,
+
+    /// man (Manual Page Break)
+    pub manual: Option<bool>,
+
+    /// max (Maximum): last row or column (0 based) the break spans
+    pub max: Option<u64>,
+
+    /// min (Minimum): first row or column (0 based) the break spans
+    pub min: Option<u64>,
+
+    /// pt (Pivot-Created Page Break)
+    pub pivot: Option<bool>,
+}
+
+impl XlsxBreak {
+    pub(crate) fn load(e: &BytesStart) -> anyhow::Result<Self> {
+        let mut page_break = Self::default();
+
+        for a in e.attributes() {
+            match a {
+                Ok(a) => {
+                    let string_value = String::from_utf8(a.value.to_vec())?;
+                    match a.key.local_name().as_ref() {
+                        b"id" => page_break.id = string_to_unsignedint(&string_value),
+                        b"man" => page_break.manual = string_to_bool(&string_value),
+                        b"max" => page_break.max = string_to_unsignedint(&string_value),
+                        b"min" => page_break.min = string_to_unsignedint(&string_value),
+                        b"pt" => page_break.pivot = string_to_bool(&string_value),
+                        _ => {}
+                    }
+                }
+                Err(error) => {
+                    bail!(error.to_string())
+                }
+            }
+        }
+
+        Ok(page_break)
+    }
+}
@@ -0,0 +1,63 @@
+use anyhow::bail;
+use quick_xml::events::BytesStart;
+
+use crate::helper::string_to_float;
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.pagemargins?view=openxml-3.0.1
+///
+/// Page margins for a sheet or a custom sheet view, in inches.
+///
+/// Example:
+/// ```
+/// <pageMargins left="0.5" right="0.5" top="0.75" bottom="0.75" header="0.277778" footer="0.277778" />
+/// ```
+/// pageMargins (Page Margins)
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct XlsxPageMargins {
+    // Attributes
+    /// bottom (Bottom Page Margin)
+    pub bottom: Option<f64>,
+
+    /// footer (Footer Page Margin)
+    pub footer: Option<f64>,
+
+    /// header (Header Page Margin)
+    pub header: Option<f64>,
+
+    /// left (Left Page Margin)
+    pub left: Option<f64>,
+
+    /// right (Right Page Margin)
+    pub right: Option<f64>,
+
+    /// top (Top Page Margin)
+    pub top: Option<f64>,
+}
+
+impl XlsxPageMargins {
+    pub(crate) fn load(e: &BytesStart) -> anyhow::Result<Self> {
+        let mut margins = Self::default();
+
+        for a in e.attributes() {
+            match a {
+                Ok(a) => {
+                    let string_value = String::from_utf8(a.value.to_vec())?;
+                    match a.key.local_name().as_ref() {
+                        b"bottom" => margins.bottom = string_to_float(&string_value),
+                        b"footer" => margins.footer = string_to_float(&string_value),
+                        b"header" => margins.header = string_to_float(&string_value),
+                        b"left" => margins.left = string_to_float(&string_value),
+                        b"right" => margins.right = string_to_float(&string_value),
+                        b"top" => margins.top = string_to_float(&string_value),
+                        _ => {}
+                    }
+                }
+                Err(error) => {
+                    bail!(error.to_string())
+                }
+            }
+        }
+
+        Ok(margins)
+    }
+}
@@ -0,0 +1,143 @@
+use anyhow::bail;
+use quick_xml::events::BytesStart;
+
+use crate::helper::{string_to_bool, string_to_unsignedint};
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.pagesetup?view=openxml-3.0.1
+///
+/// Page setup settings for the worksheet.
+///
+/// Example:
+/// ```
+/// <pageSetup paperSize="9" firstPageNumber="1" fitToHeight="1" fitToWidth="1" scale="72" useFirstPageNumber="0" orientation="portrait" pageOrder="downThenOver" />
+/// ```
+/// pageSetup (Page Setup Settings)
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct XlsxPageSetup {
+    // Attributes
+    /// blackAndWhite (Black And White)
+    pub black_and_white: Option<bool>,
+
+    /// cellComments (Print Cell Comments): none, asDisplayed, atEnd
+    pub cell_comments: Option<String>,
+
+    /// copies (Number Of Copies)
+    pub copies: Option<u64>,
+
+    /// draft (Draft)
+    pub draft: Option<bool>,
+
+    /// errors (Print Error Handling): displayed, blank, dash, NA
+    pub errors: Option<String>,
+
+    /// firstPageNumber (First Page Number)
+    pub first_page_number: Option<u64>,
+
+    /// fitToHeight (Fit To Height)
+    ///
+    /// Number of vertical pages to fit on, when fitToPage of the sheet properties is set. 0 for automatic.
+    pub fit_to_height: Option<u64>,
+
+    /// fitToWidth (Fit To Width)
+    ///
+    /// Number of horizontal pages to fit on, when fitToPage of the sheet properties is set. 0 for automatic.
+    pub fit_to_width: Option<u64>,
+
+    /// horizontalDpi (Horizontal DPI)
+    pub horizontal_dpi: Option<u64>,
+
+    /// id (Id): relationship to the printer settings part
+    pub id: Option<String>,
+
+    /// orientation (Orientation): default, portrait, landscape
+    pub orientation: Option<String>,
+
+    /// pageOrder (Page Order): downThenOver, overThenDown
+    pub page_order: Option<String>,
+
+    /// paperHeight (Paper Height)
+    ///
+    /// Height of the paper with its unit, ie: 297mm. Takes precedence over paperSize.
+    pub paper_height: Option<String>,
+
+    /// paperSize (Paper Size)
+    ///
+    /// Index of a predefined paper size, ie: 1 for Letter, 9 for A4.
+    pub paper_size: Option<u64>,
+
+    /// paperWidth (Paper Width)
+    ///
+    /// Width of the paper with its unit, ie: 210mm. Takes precedence over paperSize.
+    pub paper_width: Option<String>,
+
+    /// scale (Print Scale): percentage, 10 to 400
+    pub scale: Option<u64>,
+
+    /// useFirstPageNumber (Use First Page Number)
+    pub use_first_page_number: Option<bool>,
+
+    /// usePrinterDefaults (Use Printer Defaults)
+    pub use_printer_defaults: Option<bool>,
+
+    /// verticalDpi (Vertical DPI)
+    pub vertical_dpi: Option<u64>,
+}
+
+impl XlsxPageSetup {
+    pub(crate) fn load(e: &BytesStart) -> anyhow::Result<Self> {
+        let mut page_setup = Self::default();
+
+        for a in e.attributes() {
+            match a {
+                Ok(a) => {
+                    let string_value = String::from_utf8(a.value.to_vec())?;
+                    match a.key.local_name().as_ref() {
+                        b"blackAndWhite" => {
+                            page_setup.black_and_white = string_to_bool(&string_value)
+                        }
+                        b"cellComments" => page_setup.cell_comments = Some(string_value),
+                        b"copies" => page_setup.copies = string_to_unsignedint(&string_value),
+                        b"draft" => page_setup.draft = string_to_bool(&string_value),
+                        b"errors" => page_setup.errors = Some(string_value),
+                        b"firstPageNumber" => {
+                            page_setup.first_page_number = string_to_unsignedint(&string_value)
+                        }
+                        b"fitToHeight" => {
+                            page_setup.fit_to_height = string_to_unsignedint(&string_value)
+                        }
+                        b"fitToWidth" => {
+                            page_setup.fit_to_width = string_to_unsignedint(&string_value)
+                        }
+                        b"horizontalDpi" => {
+                            page_setup.horizontal_dpi = string_to_unsignedint(&string_value)
+                        }
+                        b"id" => page_setup.id = Some(string_value),
+                        b"orientation" => page_setup.orientation = Some(string_value),
+                        b"pageOrder" => page_setup.page_order = Some(string_value),
+                        b"paperHeight" => page_setup.paper_height = Some(string_value),
+                        b"paperSize" => {
+                            page_setup.paper_size = string_to_unsignedint(&string_value)
+                        }
+                        b"paperWidth" => page_setup.paper_width = Some(string_value),
+                        b"scale" => page_setup.scale = string_to_unsignedint(&string_value),
+                        b"useFirstPageNumber" => {
+                            page_setup.use_first_page_number = string_to_bool(&string_value)
+                        }
+                        b"usePrinterDefaults" => {
+                            page_setup.use_printer_defaults = string_to_bool(&string_value)
+                        }
+                        b"verticalDpi" => {
+                            page_setup.vertical_dpi = string_to_unsignedint(&string_value)
+                        }
+                        _ => {}
+                    }
+                }
+                Err(error) => {
+                    bail!(error.to_string())
+                }
+            }
+        }
+
+        Ok(page_setup)
+    }
+}
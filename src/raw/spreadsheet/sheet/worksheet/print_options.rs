@@ -0,0 +1,65 @@
+use anyhow::bail;
+use quick_xml::events::BytesStart;
+
+use crate::helper::string_to_bool;
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.printoptions?view=openxml-3.0.1
+///
+/// Print options for the sheet.
+///
+/// Example:
+/// ```
+/// <printOptions horizontalCentered="1" headings="1" gridLines="1" />
+/// ```
+/// printOptions (Print Options)
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct XlsxPrintOptions {
+    // Attributes
+    /// gridLines (Print Grid Lines)
+    pub grid_lines: Option<bool>,
+
+    /// gridLinesSet (Grid Lines Set)
+    ///
+    /// True if the user has set gridLines, gridLines is only printed when both are true.
+    pub grid_lines_set: Option<bool>,
+
+    /// headings (Print Headings): row and column headings
+    pub headings: Option<bool>,
+
+    /// horizontalCentered (Horizontal Centered)
+    pub horizontal_centered: Option<bool>,
+
+    /// verticalCentered (Vertical Centered)
+    pub vertical_centered: Option<bool>,
+}
+
+impl XlsxPrintOptions {
+    pub(crate) fn load(e: &BytesStart) -> anyhow::Result<Self> {
+        let mut options = Self::default();
+
+        for a in e.attributes() {
+            match a {
+                Ok(a) => {
+                    let string_value = String::from_utf8(a.value.to_vec())?;
+                    match a.key.local_name().as_ref() {
+                        b"gridLines" => options.grid_lines = string_to_bool(&string_value),
+                        b"gridLinesSet" => options.grid_lines_set = string_to_bool(&string_value),
+                        b"headings" => options.headings = string_to_bool(&string_value),
+                        b"horizontalCentered" => {
+                            options.horizontal_centered = string_to_bool(&string_value)
+                        }
+                        b"verticalCentered" => {
+                            options.vertical_centered = string_to_bool(&string_value)
+                        }
+                        _ => {}
+                    }
+                }
+                Err(error) => {
+                    bail!(error.to_string())
+                }
+            }
+        }
+
+        Ok(options)
+    }
+}
@@ -3,7 +3,7 @@ use anyhow::bail;
 use quick_xml::events::{BytesStart, Event};
 
 use crate::{
-    excel::XmlReader,
+    excel::{ExcelOptions, XmlReader},
     helper::{string_to_bool, string_to_float, string_to_unsignedint},
 };
 
@@ -30,7 +30,7 @@ use super::cell::XlsxCell;
 ///     </c>
 /// </row>
 /// ```
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, Default, PartialEq)]
 pub struct XlsxRow {
     /// extLst (Future Feature Data Storage Area) Not Supported
 
@@ -99,7 +99,13 @@ pub struct XlsxRow {
 }
 
 impl XlsxRow {
-    pub(crate) fn load(reader: &mut XmlReader<impl Read>, e: &BytesStart) -> anyhow::Result<Self> {
+    /// * with_cells: false to skip the cells, `cells` is then empty
+    pub(crate) fn load(
+        reader: &mut XmlReader<impl Read>,
+        e: &BytesStart,
+        options: &ExcelOptions,
+        with_cells: bool,
+    ) -> anyhow::Result<Self> {
         let mut row = Self {
             cells: None,
             collapsed: None,
@@ -116,8 +122,6 @@ impl XlsxRow {
             thick_bottom: None,
             thick_top: None,
         };
-        let mut cells: Vec<XlsxCell> = vec![];
-
         let attributes = e.attributes();
         for a in attributes {
             match a {
@@ -179,7 +183,13 @@ impl XlsxRow {
             }
         }
 
-        let mut buf: Vec<u8> = Vec::new();
+        let span_count = row
+            .spans
+            .filter(|_| with_cells)
+            .map(|(first, last)| last.saturating_sub(first).saturating_add(1));
+        let mut cells: Vec<XlsxCell> = Vec::with_capacity(options.capacity_for(span_count));
+
+        let mut buf: Vec<u8> = Vec::with_capacity(options.xml_buffer_size);
         loop {
             buf.clear();
 
@@ -187,6 +197,9 @@ impl XlsxRow {
                 Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"extLst" => {
                     let _ = reader.read_to_end_into(e.to_end().to_owned().name(), &mut Vec::new());
                 }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"c" && !with_cells => {
+                    let _ = reader.read_to_end_into(e.to_end().to_owned().name(), &mut Vec::new());
+                }
                 Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"c" => {
                     cells.push(XlsxCell::load(reader, e)?);
                 }
@@ -2,10 +2,21 @@ use std::io::Read;
 use anyhow::bail;
 use quick_xml::events::Event;
 
-use crate::excel::XmlReader;
+use crate::excel::{ExcelOptions, XmlReader};
 
 use super::row::XlsxRow;
 
+/// Part of `sheetData` loaded with a worksheet.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum SheetDataMode {
+    /// Rows and their cells.
+    Cells,
+    /// Rows with their properties (height, hidden, ...) but without their cells.
+    Rows,
+    /// Nothing, ie: when the rows are read one by one later on.
+    Skip,
+}
+
 /// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.sheetdata?view=openxml-3.0.1
 ///
 /// This collection represents the cell table itself.
@@ -38,7 +49,7 @@ use super::row::XlsxRow;
 /// ```
 ///
 /// sheetData (Sheet Data)
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, Default, PartialEq)]
 pub struct XlsxSheetData {
     // Child Elements
     /// row (Row)
@@ -46,16 +57,23 @@ pub struct XlsxSheetData {
 }
 
 impl XlsxSheetData {
-    pub(crate) fn load(reader: &mut XmlReader<impl Read>) -> anyhow::Result<Self> {
-        let mut rows: Vec<XlsxRow> = vec![];
+    /// * row_count: number of rows declared by the sheet dimension, used to reserve the rows
+    /// * with_cells: false to skip the cells of the rows
+    pub(crate) fn load(
+        reader: &mut XmlReader<impl Read>,
+        options: &ExcelOptions,
+        row_count: Option<u64>,
+        with_cells: bool,
+    ) -> anyhow::Result<Self> {
+        let mut rows: Vec<XlsxRow> = Vec::with_capacity(options.capacity_for(row_count));
 
-        let mut buf: Vec<u8> = Vec::new();
+        let mut buf: Vec<u8> = Vec::with_capacity(options.xml_buffer_size);
         loop {
             buf.clear();
 
             match reader.read_event_into(&mut buf) {
                 Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"row" => {
-                    rows.push(XlsxRow::load(reader, e)?);
+                    rows.push(XlsxRow::load(reader, e, options, with_cells)?);
                 }
                 Ok(Event::End(ref e)) if e.local_name().as_ref() == b"sheetData" => break,
                 Ok(Event::Eof) => bail!("unexpected end of file at `row`."),
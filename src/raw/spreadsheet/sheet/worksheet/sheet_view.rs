@@ -2,7 +2,7 @@ use anyhow::bail;
 use quick_xml::events::{BytesStart, Event};
 use std::io::Read;
 
-use crate::{excel::XmlReader, helper::{string_to_float, string_to_unsignedint}};
+use crate::{excel::XmlReader, helper::{string_to_bool, string_to_float, string_to_unsignedint}};
 
 
 /// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.sheetview?view=openxml-3.0.1
@@ -26,19 +26,155 @@ use crate::{excel::XmlReader, helper::{string_to_float, string_to_unsignedint}};
 pub struct XlsxSheetView {
     // extLst (Future Feature Data Storage Area) Not supported
 
+    // Attributes
+    /// colorId (Color Id)
+    ///
+    /// Index to the color value for row/column text headings and gridlines, used when `defaultGridColor` is false.
+    pub color_id: Option<u64>,
+
+    /// defaultGridColor (Default Grid Color)
+    ///
+    /// Use the automatic grid line color.
+    pub default_grid_color: Option<bool>,
+
+    /// rightToLeft (Right To Left)
+    ///
+    /// Display the sheet in right to left mode: column A on the right, columns increasing towards the left.
+    pub right_to_left: Option<bool>,
+
+    /// showFormulas (Show Formulas)
+    ///
+    /// Display formulas instead of their calculated values.
+    pub show_formulas: Option<bool>,
+
+    /// showGridLines (Show Grid Lines)
+    pub show_grid_lines: Option<bool>,
+
+    /// showOutlineSymbols (Show Outline Symbols)
+    pub show_outline_symbols: Option<bool>,
+
+    /// showRowColHeaders (Show Headers)
+    ///
+    /// Display the row and column headings.
+    pub show_row_col_headers: Option<bool>,
+
+    /// showRuler (Show Ruler)
+    ///
+    /// Display the ruler in page layout view.
+    pub show_ruler: Option<bool>,
+
+    /// showWhiteSpace (Show White Space)
+    ///
+    /// Display the margins between pages in page layout view.
+    pub show_white_space: Option<bool>,
+
+    /// showZeros (Show Zero Values)
+    ///
+    /// Display cells holding zero as `0` instead of blank.
+    pub show_zeros: Option<bool>,
+
+    /// tabSelected (Sheet Tab Selected)
+    pub tab_selected: Option<bool>,
+
+    /// topLeftCell (Top Left Visible Cell)
+    ///
+    /// The cell at the top left corner of the view, in right to left mode, the cell at the top right corner.
+    pub top_left_cell: Option<String>,
+
+    /// view (View Type)
+    ///
+    /// Values are: normal, pageBreakPreview, pageLayout
+    pub view: Option<String>,
+
+    /// windowProtection (Window Protection)
+    pub window_protection: Option<bool>,
+
+    /// workbookViewId (Workbook View Index)
+    ///
+    /// Index of the workbook view, `bookViews` of the workbook, the sheet view belongs to.
+    pub workbook_view_id: Option<u64>,
+
+    /// zoomScale (Zoom Scale)
+    ///
+    /// Zoom of the current view in percent, from 10 to 400.
+    pub zoom_scale: Option<u64>,
+
+    /// zoomScaleNormal (Zoom Scale Normal View)
+    pub zoom_scale_normal: Option<u64>,
+
+    /// zoomScalePageLayoutView (Zoom Scale Page Layout View)
+    pub zoom_scale_page_layout_view: Option<u64>,
+
+    /// zoomScaleSheetLayoutView (Zoom Scale Page Break Preview)
+    pub zoom_scale_sheet_layout_view: Option<u64>,
+
     // Child Elements
     // pane (View Pane)	§18.3.1.66
     pub pane: Option<XlsxPane>,
     // pivotSelection (PivotTable Selection)	§18.3.1.69
-    // selection (Selection)
+    // selection (Selection)	§18.3.1.78
+    pub selections: Vec<XlsxSelection>,
 }
 
 impl XlsxSheetView {
-    pub(crate) fn load(reader: &mut XmlReader<impl Read>, _e: &BytesStart) -> anyhow::Result<Self> {
+    pub(crate) fn load(reader: &mut XmlReader<impl Read>, e: &BytesStart) -> anyhow::Result<Self> {
         let mut sheet_view = Self {
+            color_id: None,
+            default_grid_color: None,
+            right_to_left: None,
+            show_formulas: None,
+            show_grid_lines: None,
+            show_outline_symbols: None,
+            show_row_col_headers: None,
+            show_ruler: None,
+            show_white_space: None,
+            show_zeros: None,
+            tab_selected: None,
+            top_left_cell: None,
+            view: None,
+            window_protection: None,
+            workbook_view_id: None,
+            zoom_scale: None,
+            zoom_scale_normal: None,
+            zoom_scale_page_layout_view: None,
+            zoom_scale_sheet_layout_view: None,
             pane: None,
+            selections: vec![],
         };
 
+        for attr in e.attributes() {
+            let attr = attr?;
+            let local_name = attr.key.local_name();
+            let value = String::from_utf8(attr.value.to_vec())?;
+
+            match local_name.as_ref() {
+                b"colorId" => sheet_view.color_id = string_to_unsignedint(&value),
+                b"defaultGridColor" => sheet_view.default_grid_color = string_to_bool(&value),
+                b"rightToLeft" => sheet_view.right_to_left = string_to_bool(&value),
+                b"showFormulas" => sheet_view.show_formulas = string_to_bool(&value),
+                b"showGridLines" => sheet_view.show_grid_lines = string_to_bool(&value),
+                b"showOutlineSymbols" => sheet_view.show_outline_symbols = string_to_bool(&value),
+                b"showRowColHeaders" => sheet_view.show_row_col_headers = string_to_bool(&value),
+                b"showRuler" => sheet_view.show_ruler = string_to_bool(&value),
+                b"showWhiteSpace" => sheet_view.show_white_space = string_to_bool(&value),
+                b"showZeros" => sheet_view.show_zeros = string_to_bool(&value),
+                b"tabSelected" => sheet_view.tab_selected = string_to_bool(&value),
+                b"topLeftCell" => sheet_view.top_left_cell = Some(value),
+                b"view" => sheet_view.view = Some(value),
+                b"windowProtection" => sheet_view.window_protection = string_to_bool(&value),
+                b"workbookViewId" => sheet_view.workbook_view_id = string_to_unsignedint(&value),
+                b"zoomScale" => sheet_view.zoom_scale = string_to_unsignedint(&value),
+                b"zoomScaleNormal" => sheet_view.zoom_scale_normal = string_to_unsignedint(&value),
+                b"zoomScalePageLayoutView" => {
+                    sheet_view.zoom_scale_page_layout_view = string_to_unsignedint(&value)
+                }
+                b"zoomScaleSheetLayoutView" => {
+                    sheet_view.zoom_scale_sheet_layout_view = string_to_unsignedint(&value)
+                }
+                _ => {}
+            }
+        }
+
         let mut buf = Vec::new();
         loop {
             buf.clear();
@@ -49,6 +185,10 @@ impl XlsxSheetView {
                     // Read to end of pane element
                     reader.read_to_end_into(start_e.to_end().to_owned().name(), &mut Vec::new())?;
                 }
+                Ok(Event::Start(ref start_e)) if start_e.local_name().as_ref() == b"selection" => {
+                    sheet_view.selections.push(XlsxSelection::load(start_e)?);
+                    reader.read_to_end_into(start_e.to_end().to_owned().name(), &mut Vec::new())?;
+                }
                 Ok(Event::End(ref end_e)) if end_e.local_name().as_ref() == b"sheetView" => break,
                 Ok(Event::Eof) => bail!("unexpected end of file at `sheetView`"),
                 Err(err) => bail!(err.to_string()),
@@ -178,6 +318,62 @@ impl XlsxPane {
     }
 }
 
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.selection?view=openxml-3.0.1
+///
+/// Selection
+/// The selection of a pane, with its active cell.
+#[derive(Debug, Clone, PartialEq)]
+pub struct XlsxSelection {
+    // Attributes
+    /// activeCell (Active Cell Location)
+    ///
+    /// The cell with the focus, ie: `E13`.
+    pub active_cell: Option<String>,
+
+    /// activeCellId (Active Cell Index)
+    ///
+    /// 0 based index of the range of `sqref` holding the active cell.
+    pub active_cell_id: Option<u64>,
+
+    /// pane (Pane)
+    ///
+    /// The pane the selection belongs to.
+    /// Values are: topLeft (default), topRight, bottomLeft, bottomRight.
+    pub pane: Option<String>,
+
+    /// sqref (Sequence of References)
+    ///
+    /// Selected ranges, separated by spaces, ie: `A1:B3 D5`.
+    pub sqref: Option<String>,
+}
+
+impl XlsxSelection {
+    pub(crate) fn load(e: &BytesStart) -> anyhow::Result<Self> {
+        let mut selection = Self {
+            active_cell: None,
+            active_cell_id: None,
+            pane: None,
+            sqref: None,
+        };
+
+        for attr in e.attributes() {
+            let attr = attr?;
+            let local_name = attr.key.local_name();
+            let value = String::from_utf8(attr.value.to_vec())?;
+
+            match local_name.as_ref() {
+                b"activeCell" => selection.active_cell = Some(value),
+                b"activeCellId" => selection.active_cell_id = string_to_unsignedint(&value),
+                b"pane" => selection.pane = Some(value),
+                b"sqref" => selection.sqref = Some(value),
+                _ => {}
+            }
+        }
+
+        Ok(selection)
+    }
+}
+
 /// Load sheet views from XML
 pub(crate) fn load_sheet_views(reader: &mut XmlReader<impl Read>) -> anyhow::Result<Vec<XlsxSheetView>> {
     let mut sheet_views = Vec::new();
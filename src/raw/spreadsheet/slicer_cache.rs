@@ -0,0 +1,245 @@
+use anyhow::bail;
+use quick_xml::events::{BytesStart, Event};
+use std::io::{Read, Seek};
+use zip::ZipArchive;
+
+use crate::{
+    excel::xml_reader,
+    helper::{string_to_bool, string_to_unsignedint},
+};
+
+/// Root element of a slicer cache part, holding the items of a slicer and their selection state.
+///
+/// Example
+/// ```xml
+/// <slicerCacheDefinition xmlns="http://schemas.microsoft.com/office/spreadsheetml/2009/9/main" name="Slicer_Region" sourceName="Region">
+///   <pivotTables>
+///     <pivotTable tabId="1" name="PivotTable1"/>
+///   </pivotTables>
+///   <data>
+///     <tabular pivotCacheId="1">
+///       <items count="3">
+///         <i x="0" s="1"/>
+///         <i x="1"/>
+///         <i x="2" s="1" nd="1"/>
+///       </items>
+///     </tabular>
+///   </data>
+/// </slicerCacheDefinition>
+/// ```
+/// slicerCacheDefinition
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct XlsxSlicerCacheDefinition {
+    // Attributes
+    /// name: Name of the slicer cache, used in formulas
+    pub name: Option<String>,
+
+    /// sourceName: Name of the field or column the slicer filters on
+    pub source_name: Option<String>,
+
+    // Child Elements
+    /// data/tabular: slicer on a non OLAP pivot cache
+    pub tabular: Option<XlsxTabularSlicerCache>,
+
+    /// data/olap: slicer on an OLAP pivot cache or the data model
+    pub olap_items: Vec<XlsxOlapSlicerCacheItem>,
+
+    /// x15:tableSlicerCache: slicer on a table
+    pub table_slicer: Option<XlsxTableSlicerCache>,
+}
+
+impl XlsxSlicerCacheDefinition {
+    pub(crate) fn load(zip: &mut ZipArchive<impl Read + Seek>, path: &str) -> anyhow::Result<Self> {
+        let mut definition = Self::default();
+
+        let Some(mut reader) = xml_reader(zip, path) else {
+            return Ok(definition);
+        };
+
+        let mut buf = Vec::new();
+
+        loop {
+            buf.clear();
+
+            match reader.read_event_into(&mut buf) {
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"slicerCacheDefinition" => {
+                    for a in e.attributes() {
+                        match a {
+                            Ok(a) => {
+                                let string_value = String::from_utf8(a.value.to_vec())?;
+                                match a.key.local_name().as_ref() {
+                                    b"name" => definition.name = Some(string_value),
+                                    b"sourceName" => definition.source_name = Some(string_value),
+                                    _ => {}
+                                }
+                            }
+                            Err(error) => bail!(error.to_string()),
+                        }
+                    }
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"tabular" => {
+                    let mut tabular = XlsxTabularSlicerCache::default();
+                    for a in e.attributes() {
+                        match a {
+                            Ok(a) if a.key.local_name().as_ref() == b"pivotCacheId" => {
+                                tabular.pivot_cache_id =
+                                    string_to_unsignedint(&String::from_utf8(a.value.to_vec())?);
+                            }
+                            Ok(_) => {}
+                            Err(error) => bail!(error.to_string()),
+                        }
+                    }
+                    definition.tabular = Some(tabular);
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"i" => {
+                    // tabular items have an index (x), olap items a unique name (n)
+                    match definition.tabular.as_mut() {
+                        Some(tabular) => tabular.items.push(XlsxTabularSlicerCacheItem::load(e)?),
+                        None => definition.olap_items.push(XlsxOlapSlicerCacheItem::load(e)?),
+                    }
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"tableSlicerCache" => {
+                    let mut table_slicer = XlsxTableSlicerCache::default();
+                    for a in e.attributes() {
+                        match a {
+                            Ok(a) => {
+                                let string_value = String::from_utf8(a.value.to_vec())?;
+                                match a.key.local_name().as_ref() {
+                                    b"tableId" => {
+                                        table_slicer.table_id = string_to_unsignedint(&string_value)
+                                    }
+                                    b"column" => {
+                                        table_slicer.column = string_to_unsignedint(&string_value)
+                                    }
+                                    _ => {}
+                                }
+                            }
+                            Err(error) => bail!(error.to_string()),
+                        }
+                    }
+                    definition.table_slicer = Some(table_slicer);
+                }
+                Ok(Event::End(ref e)) if e.local_name().as_ref() == b"slicerCacheDefinition" => {
+                    break
+                }
+                Ok(Event::Eof) => break,
+                Err(e) => bail!(e.to_string()),
+                _ => (),
+            }
+        }
+
+        return Ok(definition);
+    }
+}
+
+/// tabular (Tabular Slicer Cache)
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct XlsxTabularSlicerCache {
+    /// pivotCacheId: pivotCacheId of the x14:pivotCacheDefinition the slicer is based on
+    pub pivot_cache_id: Option<u64>,
+
+    /// items
+    pub items: Vec<XlsxTabularSlicerCacheItem>,
+}
+
+/// Example
+/// ```xml
+/// <i x="2" s="1" nd="1"/>
+/// ```
+/// i (Tabular Slicer Cache Item)
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct XlsxTabularSlicerCacheItem {
+    /// x: index of the shared item within the cache field
+    pub x: Option<u64>,
+
+    /// s: selected
+    pub selected: Option<bool>,
+
+    /// nd: no data, the item has no data after applying the other filters
+    pub no_data: Option<bool>,
+}
+
+impl XlsxTabularSlicerCacheItem {
+    pub(crate) fn load(e: &BytesStart) -> anyhow::Result<Self> {
+        let mut item = Self::default();
+
+        for a in e.attributes() {
+            match a {
+                Ok(a) => {
+                    let string_value = String::from_utf8(a.value.to_vec())?;
+                    match a.key.local_name().as_ref() {
+                        b"x" => item.x = string_to_unsignedint(&string_value),
+                        b"s" => item.selected = string_to_bool(&string_value),
+                        b"nd" => item.no_data = string_to_bool(&string_value),
+                        _ => {}
+                    }
+                }
+                Err(error) => {
+                    bail!(error.to_string())
+                }
+            }
+        }
+
+        Ok(item)
+    }
+}
+
+/// Example
+/// ```xml
+/// <i n="[Sales].[Region].&amp;[East]" c="East" s="1"/>
+/// ```
+/// i (OLAP Slicer Cache Item)
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct XlsxOlapSlicerCacheItem {
+    /// n: unique name of the member
+    pub name: Option<String>,
+
+    /// c: display caption
+    pub caption: Option<String>,
+
+    /// s: selected
+    pub selected: Option<bool>,
+
+    /// nd: no data
+    pub no_data: Option<bool>,
+}
+
+impl XlsxOlapSlicerCacheItem {
+    pub(crate) fn load(e: &BytesStart) -> anyhow::Result<Self> {
+        let mut item = Self::default();
+
+        for a in e.attributes() {
+            match a {
+                Ok(a) => {
+                    let string_value = String::from_utf8(a.value.to_vec())?;
+                    match a.key.local_name().as_ref() {
+                        b"n" => item.name = Some(string_value),
+                        b"c" => item.caption = Some(string_value),
+                        b"s" => item.selected = string_to_bool(&string_value),
+                        b"nd" => item.no_data = string_to_bool(&string_value),
+                        _ => {}
+                    }
+                }
+                Err(error) => {
+                    bail!(error.to_string())
+                }
+            }
+        }
+
+        Ok(item)
+    }
+}
+
+/// Example
+/// ```xml
+/// <x15:tableSlicerCache tableId="1" column="2"/>
+/// ```
+/// tableSlicerCache (Table Slicer Cache)
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct XlsxTableSlicerCache {
+    /// tableId: id of the table
+    pub table_id: Option<u64>,
+
+    /// column: id of the table column
+    pub column: Option<u64>,
+}
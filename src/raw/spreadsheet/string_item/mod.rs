@@ -4,14 +4,14 @@ pub mod rich_text_run;
 pub mod run_properties;
 
 use anyhow::bail;
-use std::io::Read;
+use std::io::BufRead;
 
 use phonetic_properties::XlsxPhoneticProperties;
 use phonetic_run::XlsxPhoneticRun;
-use quick_xml::events::Event;
+use quick_xml::{events::Event, Reader};
 use rich_text_run::XlsxRichTextRun;
 
-use crate::{common_types::Text, excel::XmlReader, helper::extract_text_contents};
+use crate::{common_types::Text, helper::extract_text_contents};
 
 /// Example:
 /// ```
@@ -51,7 +51,7 @@ use crate::{common_types::Text, excel::XmlReader, helper::extract_text_contents}
 ///     <is><t>This is inline string example</t></is>
 /// </c>
 /// ```
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, Default, PartialEq)]
 pub struct XlsxStringItem {
     // Child Elements
     // phoneticPr (Phonetic Properties)	§18.4.3
@@ -65,7 +65,7 @@ pub struct XlsxStringItem {
 }
 
 impl XlsxStringItem {
-    pub(crate) fn load(reader: &mut XmlReader<impl Read>, tag: &[u8]) -> anyhow::Result<Self> {
+    pub(crate) fn load(reader: &mut Reader<impl BufRead>, tag: &[u8]) -> anyhow::Result<Self> {
         let mut item = Self {
             phonetic_properties: None,
             rich_text_run: None,
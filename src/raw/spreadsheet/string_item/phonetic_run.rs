@@ -1,10 +1,12 @@
 use anyhow::bail;
-use quick_xml::events::{BytesStart, Event};
-use std::io::Read;
+use quick_xml::{
+    events::{BytesStart, Event},
+    Reader,
+};
+use std::io::BufRead;
 
 use crate::{
     common_types::Text,
-    excel::XmlReader,
     helper::{extract_text_contents, string_to_unsignedint},
 };
 
@@ -51,7 +53,7 @@ pub struct XlsxPhoneticRun {
 }
 
 impl XlsxPhoneticRun {
-    pub(crate) fn load(reader: &mut XmlReader<impl Read>, e: &BytesStart) -> anyhow::Result<Self> {
+    pub(crate) fn load(reader: &mut Reader<impl BufRead>, e: &BytesStart) -> anyhow::Result<Self> {
         let mut run = Self {
             text: None,
             base_text_end_index: None,
@@ -1,8 +1,8 @@
 use anyhow::bail;
-use quick_xml::events::Event;
-use std::io::Read;
+use quick_xml::{events::Event, Reader};
+use std::io::BufRead;
 
-use crate::{common_types::Text, excel::XmlReader, helper::extract_text_contents};
+use crate::{common_types::Text, helper::extract_text_contents};
 
 use super::run_properties::XlsxRunProperties;
 
@@ -47,7 +47,7 @@ pub struct XlsxRichTextRun {
 }
 
 impl XlsxRichTextRun {
-    pub(crate) fn load(reader: &mut XmlReader<impl Read>) -> anyhow::Result<Self> {
+    pub(crate) fn load(reader: &mut Reader<impl BufRead>) -> anyhow::Result<Self> {
         let mut run = Self {
             run_properties: None,
             text: None,
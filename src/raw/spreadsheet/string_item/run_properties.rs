@@ -1,9 +1,8 @@
 use anyhow::bail;
-use quick_xml::events::Event;
-use std::io::Read;
+use quick_xml::{events::Event, Reader};
+use std::io::BufRead;
 
 use crate::{
-    excel::XmlReader,
     helper::{extract_val_attribute, string_to_bool, string_to_float, string_to_unsignedint},
     raw::spreadsheet::{ct_types::CTFontSize, stylesheet::color::XlsxColor},
 };
@@ -171,7 +170,7 @@ pub struct XlsxRunProperties {
 }
 
 impl XlsxRunProperties {
-    pub(crate) fn load(reader: &mut XmlReader<impl Read>) -> anyhow::Result<Self> {
+    pub(crate) fn load(reader: &mut Reader<impl BufRead>) -> anyhow::Result<Self> {
         let mut buf = Vec::new();
 
         let mut properties = Self {
@@ -24,7 +24,7 @@ pub type XlsxForegroundColor = XlsxColor;
 /// Color corresponding to the following classes
 ///
 /// DataBarColor: https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.color?view=openxml-3.0.1
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, Default, PartialEq)]
 pub struct XlsxColor {
     // attributes
     /// A boolean value (0: false, 1: true) indicating the color is automatic and system color dependent.
@@ -56,7 +56,7 @@ pub(crate) fn load_fonts(reader: &mut XmlReader<impl Read>) -> anyhow::Result<Xl
 ///     </font>
 /// </fonts>
 /// ```
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, Default, PartialEq)]
 pub struct XlsxFont {
     // children
     /// Bold: https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.bold?view=openxml-3.0.1
@@ -13,7 +13,7 @@ use crate::helper::{string_to_bool, string_to_int, string_to_unsignedint};
 ///     <alignment vertical="top" wrapText="1" />
 /// </xf>
 /// ```
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, Default, PartialEq)]
 pub struct XlsxAlignment {
     // attributes
     /// Specifies the type of horizontal alignment in cells
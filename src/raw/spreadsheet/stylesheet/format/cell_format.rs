@@ -26,7 +26,7 @@ use super::{alignment::XlsxAlignment, protection::XlsxCellProtection};
 ///     </xf>
 /// </cellXfs>
 /// ```
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, Default, PartialEq)]
 pub struct XlsxCellFormat {
     // children
     pub alignment: Option<XlsxAlignment>,
@@ -72,7 +72,7 @@ pub type FormatCode = String;
 /// For general formatting in cells, max overall length for cell display is 11, not including negative sign, but includes leading zeros and decimal separator.
 ///
 /// tag: numFmt
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, Default, PartialEq)]
 pub struct XlsxNumberingFormat {
     // attributes
     /// The number format code for this number format.
@@ -98,7 +98,13 @@ impl XlsxNumberingFormat {
                 Ok(a) => {
                     let string_value = String::from_utf8(a.value.to_vec())?;
                     match a.key.local_name().as_ref() {
-                        b"formatCode" => format.format_code = Some(string_value),
+                        b"formatCode" => {
+                            format.format_code = Some(
+                                quick_xml::escape::unescape(&string_value)
+                                    .map(|s| s.to_string())
+                                    .unwrap_or(string_value),
+                            )
+                        }
                         b"numFmtId" => format.num_fmt_id = string_to_unsignedint(&string_value),
                         _ => {}
                     }
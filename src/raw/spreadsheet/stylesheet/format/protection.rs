@@ -8,7 +8,7 @@ use crate::helper::string_to_bool;
 /// Contains protection properties associated with the cell.
 /// Each cell has protection properties that can be set.
 /// The cell protection properties do not take effect unless the sheet has been protected.
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, Default, PartialEq)]
 pub struct XlsxCellProtection {
     // attributes
     /// A boolean value indicating if the cell is hidden.
@@ -90,8 +90,8 @@ pub struct XlsxStyleSheet {
 }
 
 impl XlsxStyleSheet {
-    pub(crate) fn load(zip: &mut ZipArchive<impl Read + Seek>) -> anyhow::Result<Self> {
-        let path = "xl/styles.xml";
+    /// * path: the stylesheet part related to the workbook, ie: xl/styles.xml
+    pub(crate) fn load(zip: &mut ZipArchive<impl Read + Seek>, path: &str) -> anyhow::Result<Self> {
         let mut style_sheet = Self {
             fills: None,
             borders: None,
@@ -0,0 +1,151 @@
+use anyhow::bail;
+use quick_xml::events::{BytesStart, Event};
+use std::io::{Read, Seek};
+use zip::ZipArchive;
+
+use crate::{
+    excel::{xml_reader, XmlReader},
+    helper::{extract_text_contents, string_to_bool},
+};
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.office2019.excel.threadedcomments.threadedcomments?view=openxml-3.0.1
+///
+/// Root element of a threaded comments part, ie: xl/threadedComments/threadedComment1.xml
+///
+/// Replies are stored after the comment starting the thread, with a `parentId` pointing to it.
+///
+/// Example
+/// ```xml
+/// <ThreadedComments xmlns="http://schemas.microsoft.com/office/spreadsheetml/2018/threadedcomments">
+///   <threadedComment ref="B2" dT="2024-03-01T09:15:00.00" personId="{5B4A...}" id="{0A1B...}">
+///     <text>Is this total right?</text>
+///   </threadedComment>
+///   <threadedComment ref="B2" dT="2024-03-01T10:02:41.35" personId="{7C2D...}" id="{3E4F...}" parentId="{0A1B...}">
+///     <text>Yes, checked against the invoice.</text>
+///   </threadedComment>
+/// </ThreadedComments>
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct XlsxThreadedComments {
+    // extLst (Future Feature Data Storage Area) Not supported
+
+    // Child Elements
+    // threadedComment (Threaded Comment)
+    pub comments: Vec<XlsxThreadedComment>,
+}
+
+impl XlsxThreadedComments {
+    pub(crate) fn load(zip: &mut ZipArchive<impl Read + Seek>, path: &str) -> anyhow::Result<Self> {
+        let mut threaded_comments = Self::default();
+
+        let Some(mut reader) = xml_reader(zip, path) else {
+            return Ok(threaded_comments);
+        };
+
+        let mut buf = Vec::new();
+
+        loop {
+            buf.clear();
+
+            match reader.read_event_into(&mut buf) {
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"extLst" => {
+                    let _ = reader.read_to_end_into(e.to_end().to_owned().name(), &mut Vec::new());
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"threadedComment" => {
+                    threaded_comments
+                        .comments
+                        .push(XlsxThreadedComment::load(&mut reader, e)?);
+                }
+                Ok(Event::End(ref e)) if e.local_name().as_ref() == b"ThreadedComments" => break,
+                Ok(Event::Eof) => break,
+                Err(e) => bail!(e.to_string()),
+                _ => (),
+            }
+        }
+
+        return Ok(threaded_comments);
+    }
+}
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.office2019.excel.threadedcomments.threadedcomment?view=openxml-3.0.1
+///
+/// Example
+/// ```xml
+/// <threadedComment ref="B2" dT="2024-03-01T09:15:00.00" personId="{5B4A...}" id="{0A1B...}" done="1">
+///   <text>Is this total right?</text>
+/// </threadedComment>
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct XlsxThreadedComment {
+    // Child Elements
+    // extLst (Future Feature Data Storage Area) Not supported
+    // mentions (Mentions) Not supported
+
+    // text (Text)
+    pub text: Option<String>,
+
+    // Attributes
+    /// ref (Reference): commented cell
+    pub r#ref: Option<String>,
+
+    /// dT (Date Time) the comment was written, ie: `2024-03-01T09:15:00.00`
+    pub date_time: Option<String>,
+
+    /// personId (Person Id): id of the author in the persons part
+    pub person_id: Option<String>,
+
+    /// id (Id)
+    pub id: Option<String>,
+
+    /// parentId (Parent Id): id of the comment starting the thread, for replies
+    pub parent_id: Option<String>,
+
+    /// done (Done): the thread is resolved
+    pub done: Option<bool>,
+}
+
+impl XlsxThreadedComment {
+    fn load(reader: &mut XmlReader<impl Read>, e: &BytesStart) -> anyhow::Result<Self> {
+        let mut comment = Self::default();
+
+        for a in e.attributes() {
+            match a {
+                Ok(a) => {
+                    let string_value = String::from_utf8(a.value.to_vec())?;
+                    match a.key.local_name().as_ref() {
+                        b"ref" => comment.r#ref = Some(string_value),
+                        b"dT" => comment.date_time = Some(string_value),
+                        b"personId" => comment.person_id = Some(string_value),
+                        b"id" => comment.id = Some(string_value),
+                        b"parentId" => comment.parent_id = Some(string_value),
+                        b"done" => comment.done = string_to_bool(&string_value),
+                        _ => {}
+                    }
+                }
+                Err(error) => bail!(error.to_string()),
+            }
+        }
+
+        let mut buf = Vec::new();
+        loop {
+            buf.clear();
+
+            match reader.read_event_into(&mut buf) {
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"text" => {
+                    comment.text = Some(extract_text_contents(reader, b"text")?);
+                }
+                Ok(Event::Start(ref e))
+                    if matches!(e.local_name().as_ref(), b"mentions" | b"extLst") =>
+                {
+                    let _ = reader.read_to_end_into(e.to_end().to_owned().name(), &mut Vec::new());
+                }
+                Ok(Event::End(ref e)) if e.local_name().as_ref() == b"threadedComment" => break,
+                Ok(Event::Eof) => bail!("unexpected end of file at `threadedComment`."),
+                Err(e) => bail!(e.to_string()),
+                _ => (),
+            }
+        }
+
+        return Ok(comment);
+    }
+}
@@ -0,0 +1,139 @@
+use anyhow::bail;
+use quick_xml::events::{BytesStart, Event};
+use std::io::{Read, Seek};
+use zip::ZipArchive;
+
+use crate::excel::xml_reader;
+
+/// Root element of a timeline cache part, holding the date range selected in a timeline.
+///
+/// Example
+/// ```xml
+/// <timelineCacheDefinition xmlns="http://schemas.microsoft.com/office/spreadsheetml/2010/11/main" name="NativeTimeline_Date" sourceName="Date">
+///   <pivotTables>
+///     <pivotTable tabId="1" name="PivotTable1"/>
+///   </pivotTables>
+///   <state minimalRefreshVersion="6" lastRefreshVersion="6" pivotCacheId="1" filterType="dateBetween">
+///     <selection startDate="2019-01-01T00:00:00" endDate="2019-12-31T00:00:00"/>
+///     <bounds startDate="2018-01-01T00:00:00" endDate="2021-01-01T00:00:00"/>
+///   </state>
+/// </timelineCacheDefinition>
+/// ```
+/// timelineCacheDefinition
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct XlsxTimelineCacheDefinition {
+    // Attributes
+    /// name: Name of the timeline cache
+    pub name: Option<String>,
+
+    /// sourceName: Name of the date field the timeline filters on
+    pub source_name: Option<String>,
+
+    // state
+    /// filterType: ie: dateBetween, none (nothing selected)
+    pub filter_type: Option<String>,
+
+    /// selection: selected range
+    pub selection: Option<XlsxTimelineRange>,
+
+    /// bounds: range covered by the timeline
+    pub bounds: Option<XlsxTimelineRange>,
+}
+
+impl XlsxTimelineCacheDefinition {
+    pub(crate) fn load(zip: &mut ZipArchive<impl Read + Seek>, path: &str) -> anyhow::Result<Self> {
+        let mut definition = Self::default();
+
+        let Some(mut reader) = xml_reader(zip, path) else {
+            return Ok(definition);
+        };
+
+        let mut buf = Vec::new();
+
+        loop {
+            buf.clear();
+
+            match reader.read_event_into(&mut buf) {
+                Ok(Event::Start(ref e))
+                    if e.local_name().as_ref() == b"timelineCacheDefinition" =>
+                {
+                    for a in e.attributes() {
+                        match a {
+                            Ok(a) => {
+                                let string_value = String::from_utf8(a.value.to_vec())?;
+                                match a.key.local_name().as_ref() {
+                                    b"name" => definition.name = Some(string_value),
+                                    b"sourceName" => definition.source_name = Some(string_value),
+                                    _ => {}
+                                }
+                            }
+                            Err(error) => bail!(error.to_string()),
+                        }
+                    }
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"state" => {
+                    for a in e.attributes() {
+                        match a {
+                            Ok(a) if a.key.local_name().as_ref() == b"filterType" => {
+                                definition.filter_type = Some(String::from_utf8(a.value.to_vec())?);
+                            }
+                            Ok(_) => {}
+                            Err(error) => bail!(error.to_string()),
+                        }
+                    }
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"selection" => {
+                    definition.selection = Some(XlsxTimelineRange::load(e)?);
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"bounds" => {
+                    definition.bounds = Some(XlsxTimelineRange::load(e)?);
+                }
+                Ok(Event::End(ref e)) if e.local_name().as_ref() == b"timelineCacheDefinition" => {
+                    break
+                }
+                Ok(Event::Eof) => break,
+                Err(e) => bail!(e.to_string()),
+                _ => (),
+            }
+        }
+
+        return Ok(definition);
+    }
+}
+
+/// Example
+/// ```xml
+/// <selection startDate="2019-01-01T00:00:00" endDate="2019-12-31T00:00:00"/>
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct XlsxTimelineRange {
+    /// startDate
+    pub start_date: Option<String>,
+
+    /// endDate
+    pub end_date: Option<String>,
+}
+
+impl XlsxTimelineRange {
+    pub(crate) fn load(e: &BytesStart) -> anyhow::Result<Self> {
+        let mut range = Self::default();
+
+        for a in e.attributes() {
+            match a {
+                Ok(a) => {
+                    let string_value = String::from_utf8(a.value.to_vec())?;
+                    match a.key.local_name().as_ref() {
+                        b"startDate" => range.start_date = Some(string_value),
+                        b"endDate" => range.end_date = Some(string_value),
+                        _ => {}
+                    }
+                }
+                Err(error) => {
+                    bail!(error.to_string())
+                }
+            }
+        }
+
+        Ok(range)
+    }
+}
@@ -0,0 +1,127 @@
+use anyhow::bail;
+use quick_xml::events::Event;
+use std::io::{Read, Seek};
+use zip::ZipArchive;
+
+use crate::{
+    excel::{xml_reader, XmlReader},
+    helper::{extract_text_contents, string_to_unsignedint},
+};
+
+/// Legacy VML drawing of a worksheet, ie: xl/drawings/vmlDrawing1.vml
+///
+/// Only the shapes of the notes (`ObjectType="Note"`) are read: the boxes of the legacy comments.
+///
+/// Example
+/// ```xml
+/// <xml xmlns:v="urn:schemas-microsoft-com:vml" xmlns:o="urn:schemas-microsoft-com:office:office" xmlns:x="urn:schemas-microsoft-com:office:excel">
+///   <v:shape id="_x0000_s1025" type="#_x0000_t202" style="position:absolute;margin-left:59.25pt;margin-top:1.5pt;width:108pt;height:59.25pt;z-index:1;visibility:hidden" fillcolor="#ffffe1">
+///     <x:ClientData ObjectType="Note">
+///       <x:MoveWithCells/>
+///       <x:SizeWithCells/>
+///       <x:Anchor>2, 15, 0, 2, 4, 15, 4, 16</x:Anchor>
+///       <x:AutoFill>False</x:AutoFill>
+///       <x:Row>1</x:Row>
+///       <x:Column>1</x:Column>
+///     </x:ClientData>
+///   </v:shape>
+/// </xml>
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct XlsxVmlDrawing {
+    pub notes: Vec<XlsxVmlNote>,
+}
+
+impl XlsxVmlDrawing {
+    pub(crate) fn load(zip: &mut ZipArchive<impl Read + Seek>, path: &str) -> anyhow::Result<Self> {
+        let mut drawing = Self::default();
+
+        let Some(mut reader) = xml_reader(zip, path) else {
+            return Ok(drawing);
+        };
+
+        let mut buf = Vec::new();
+
+        loop {
+            buf.clear();
+
+            match reader.read_event_into(&mut buf) {
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"ClientData" => {
+                    let mut is_note = false;
+                    for a in e.attributes() {
+                        match a {
+                            Ok(a) if a.key.local_name().as_ref() == b"ObjectType" => {
+                                is_note = a.value.as_ref() == b"Note";
+                            }
+                            Ok(_) => {}
+                            Err(error) => bail!(error.to_string()),
+                        }
+                    }
+                    let note = XlsxVmlNote::load(&mut reader)?;
+                    if is_note {
+                        drawing.notes.push(note);
+                    }
+                }
+                Ok(Event::Eof) => break,
+                Err(e) => bail!(e.to_string()),
+                _ => (),
+            }
+        }
+
+        return Ok(drawing);
+    }
+}
+
+/// x:ClientData of a note
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct XlsxVmlNote {
+    /// x:Row: row of the commented cell, 0 based
+    pub row: Option<u64>,
+
+    /// x:Column: column of the commented cell, 0 based
+    pub column: Option<u64>,
+
+    /// x:Visible: the note is always shown, not only when hovering the cell
+    pub visible: bool,
+
+    /// x:Anchor: left column, left offset, top row, top offset, right column, right offset, bottom row, bottom offset of the box
+    pub anchor: Option<String>,
+}
+
+impl XlsxVmlNote {
+    fn load(reader: &mut XmlReader<impl Read>) -> anyhow::Result<Self> {
+        let mut note = Self::default();
+        let mut buf = Vec::new();
+        loop {
+            buf.clear();
+
+            match reader.read_event_into(&mut buf) {
+                Ok(Event::Start(ref e)) => match e.local_name().as_ref() {
+                    b"Row" => {
+                        note.row =
+                            string_to_unsignedint(extract_text_contents(reader, b"Row")?.trim())
+                    }
+                    b"Column" => {
+                        note.column =
+                            string_to_unsignedint(extract_text_contents(reader, b"Column")?.trim())
+                    }
+                    b"Visible" => {
+                        // empty, or `True` / `False`
+                        let value = extract_text_contents(reader, b"Visible")?;
+                        note.visible = !value.trim().eq_ignore_ascii_case("false");
+                    }
+                    b"Anchor" => {
+                        note.anchor =
+                            Some(extract_text_contents(reader, b"Anchor")?.trim().to_string())
+                    }
+                    _ => (),
+                },
+                Ok(Event::End(ref e)) if e.local_name().as_ref() == b"ClientData" => break,
+                Ok(Event::Eof) => bail!("unexpected end of file at `ClientData`."),
+                Err(e) => bail!(e.to_string()),
+                _ => (),
+            }
+        }
+        return Ok(note);
+    }
+}
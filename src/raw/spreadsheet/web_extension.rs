@@ -0,0 +1,146 @@
+use anyhow::bail;
+use quick_xml::events::{BytesStart, Event};
+use std::io::{Read, Seek};
+use zip::ZipArchive;
+
+use crate::excel::xml_reader;
+
+/// https://learn.microsoft.com/en-us/openspecs/office_standards/ms-owex/0d1fd05d-51b5-4462-a6d3-81ee5fe8f221
+///
+/// Root element of a web extension (Office add-in) part.
+///
+/// Example
+/// ```xml
+/// <we:webextension xmlns:we="http://schemas.microsoft.com/office/webextensions/webextension/2010/11" id="{52811C31-4593-43B8-A697-EB873422D156}">
+///   <we:reference id="wa104380862" version="1.1.0.0" store="en-US" storeType="OMEX"/>
+///   <we:alternateReferences/>
+///   <we:properties>
+///     <we:property name="Office.AutoShowTaskpaneWithDocument" value="true"/>
+///   </we:properties>
+///   <we:bindings/>
+///   <we:snapshot xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships"/>
+/// </we:webextension>
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct XlsxWebExtension {
+    // attributes
+    /// id: Unique identifier of this instance of the add-in within the workbook
+    pub id: Option<String>,
+
+    // Child Elements
+    /// reference: Add-in in the store
+    pub reference: Option<XlsxWebExtensionReference>,
+
+    /// properties: name, value pairs set by the add-in
+    pub properties: Vec<(String, String)>,
+}
+
+impl XlsxWebExtension {
+    pub(crate) fn load(zip: &mut ZipArchive<impl Read + Seek>, path: &str) -> anyhow::Result<Self> {
+        let mut extension = Self::default();
+
+        let Some(mut reader) = xml_reader(zip, path) else {
+            return Ok(extension);
+        };
+
+        let mut buf = Vec::new();
+
+        loop {
+            buf.clear();
+
+            match reader.read_event_into(&mut buf) {
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"webextension" => {
+                    for a in e.attributes() {
+                        match a {
+                            Ok(a) if a.key.local_name().as_ref() == b"id" => {
+                                extension.id = Some(String::from_utf8(a.value.to_vec())?);
+                            }
+                            Ok(_) => {}
+                            Err(error) => bail!(error.to_string()),
+                        }
+                    }
+                }
+                // alternateReferences contains references as well, only the primary one is kept
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"alternateReferences" => {
+                    let _ = reader.read_to_end_into(e.to_end().to_owned().name(), &mut Vec::new());
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"reference" => {
+                    extension.reference = Some(XlsxWebExtensionReference::load(e)?);
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"property" => {
+                    let mut name: Option<String> = None;
+                    let mut value: Option<String> = None;
+                    for a in e.attributes() {
+                        match a {
+                            Ok(a) => {
+                                let string_value = String::from_utf8(a.value.to_vec())?;
+                                match a.key.local_name().as_ref() {
+                                    b"name" => name = Some(string_value),
+                                    b"value" => value = Some(string_value),
+                                    _ => {}
+                                }
+                            }
+                            Err(error) => bail!(error.to_string()),
+                        }
+                    }
+                    if let Some(name) = name {
+                        extension.properties.push((name, value.unwrap_or_default()));
+                    }
+                }
+                Ok(Event::End(ref e)) if e.local_name().as_ref() == b"webextension" => break,
+                Ok(Event::Eof) => break,
+                Err(e) => bail!(e.to_string()),
+                _ => (),
+            }
+        }
+
+        return Ok(extension);
+    }
+}
+
+/// Reference to an add-in in a store.
+///
+/// Example
+/// ```xml
+/// <we:reference id="wa104380862" version="1.1.0.0" store="en-US" storeType="OMEX"/>
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct XlsxWebExtensionReference {
+    /// id: Add-in id within the store, ie: asset id of the Office Store
+    pub id: Option<String>,
+
+    /// version: Version of the add-in in the store
+    pub version: Option<String>,
+
+    /// store: Store instance, ie: a locale for the Office Store or a catalog url
+    pub store: Option<String>,
+
+    /// storeType: ie: OMEX (Office Store), SPCatalog, FileSystem, Registry, ExCatalog
+    pub store_type: Option<String>,
+}
+
+impl XlsxWebExtensionReference {
+    pub(crate) fn load(e: &BytesStart) -> anyhow::Result<Self> {
+        let mut reference = Self::default();
+
+        for a in e.attributes() {
+            match a {
+                Ok(a) => {
+                    let string_value = String::from_utf8(a.value.to_vec())?;
+                    match a.key.local_name().as_ref() {
+                        b"id" => reference.id = Some(string_value),
+                        b"version" => reference.version = Some(string_value),
+                        b"store" => reference.store = Some(string_value),
+                        b"storeType" => reference.store_type = Some(string_value),
+                        _ => {}
+                    }
+                }
+                Err(error) => {
+                    bail!(error.to_string())
+                }
+            }
+        }
+
+        Ok(reference)
+    }
+}
@@ -4,17 +4,19 @@ use anyhow::bail;
 use calculation_properties::XlsxCalculationProperties;
 use custom_workbook_view::{load_custom_bookviews, XlsxCustomWorkbookViews};
 use defined_name::{load_defined_names, XlsxDefinedNames};
+use pivot_cache::{load_pivot_caches, XlsxPivotCaches};
 use quick_xml::events::Event;
 use sheet::{load_sheets, XlsxSheets};
 use workbook_properties::XlsxWorkbookProperties;
 use workbook_view::{load_bookviews, XlsxWorkbookViews};
 use zip::ZipArchive;
 
-use crate::excel::xml_reader;
+use crate::{excel::xml_reader, packaging::relationship::load_workbook_path};
 
 pub mod calculation_properties;
 pub mod custom_workbook_view;
 pub mod defined_name;
+pub mod pivot_cache;
 pub mod sheet;
 pub mod workbook_properties;
 pub mod workbook_view;
@@ -56,7 +58,7 @@ pub mod workbook_view;
 /// </workbook>
 /// ```
 /// xml tag: workbook
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, Default, PartialEq)]
 pub struct XlsxWorkbook {
     // extLst (Future Feature Data Storage Area)	Not supported
 
@@ -79,6 +81,7 @@ pub struct XlsxWorkbook {
     // functionGroups (Function Groups)	§18.2.15
     // oleSize (Embedded Object Size)	§18.2.16
     // pivotCaches (PivotCaches)	§18.2.18
+    pub pivot_caches: Option<XlsxPivotCaches>,
     // sheets (Sheets)	§18.2.20
     pub sheets: Option<XlsxSheets>,
     // smartTagPr (Smart Tag Properties)	§18.2.21
@@ -92,17 +95,18 @@ pub struct XlsxWorkbook {
 
 impl XlsxWorkbook {
     pub(crate) fn load(zip: &mut ZipArchive<impl Read + Seek>) -> anyhow::Result<Self> {
-        let path = "xl/workbook.xml";
+        let path = load_workbook_path(zip);
         let mut workbook = Self {
             bookviews: None,
             calculation_propertis: None,
             custom_workbook_views: None,
             defined_names: None,
+            pivot_caches: None,
             sheets: None,
             workbook_properties: None,
         };
 
-        let Some(mut reader) = xml_reader(zip, path) else {
+        let Some(mut reader) = xml_reader(zip, &path) else {
             return Ok(workbook);
         };
 
@@ -127,6 +131,9 @@ impl XlsxWorkbook {
                 Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"definedNames" => {
                     workbook.defined_names = Some(load_defined_names(&mut reader)?);
                 }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"pivotCaches" => {
+                    workbook.pivot_caches = Some(load_pivot_caches(&mut reader)?);
+                }
                 Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"sheets" => {
                     workbook.sheets = Some(load_sheets(&mut reader)?);
                 }
@@ -50,7 +50,7 @@ pub(crate) fn load_sheets(reader: &mut XmlReader<impl Read>) -> anyhow::Result<X
 /// <sheet name="Sheet 1" sheetId="3" r:id="rId1" />
 /// ```
 /// sheet (Sheet Information)
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, Default, PartialEq)]
 pub struct XlsxSheet {
     // Attributes
     /// id (Relationship Id)
@@ -13,7 +13,7 @@ use crate::helper::{string_to_bool, string_to_int};
 /// <workbookPr dateCompatibility="false" showObjects="none" saveExternalLinkValues="0"  defaultThemeVersion="123820"/>
 /// ```
 /// workbookPr (Workbook Properties)
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, Default, PartialEq)]
 pub struct XlsxWorkbookProperties {
     //  Attributes	Description
     /// allowRefreshQuery (Allow Refresh Query)
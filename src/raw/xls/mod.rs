@@ -0,0 +1,255 @@
+//! Legacy binary workbook (.xls, BIFF8): https://learn.microsoft.com/en-us/openspecs/office_file_formats/ms-xls/cd03cb5f-ca02-4934-a391-bb674cb8aa06
+//!
+//! A xls file is a compound file (see `raw::cfb`) holding the workbook in its `Workbook` stream.
+//! The stream is a sequence of records, starting with the globals substream (sheets, shared strings, formats, fonts and cell formats),
+//! followed by one substream per sheet.
+//! Records are parsed into the same raw structures as their xml counterparts, so that the processed layer works on both formats.
+//!
+//! Only records needed for cell values and cell formats are parsed:
+//! - globals: sheets, the 1904 date system, shared strings, number formats, fonts and cell formats
+//! - sheets: dimension, rows, cells, column information, default sizes and merged cells
+//!
+//! Formulas are stored as parsed tokens (Rgce) and are not decoded, formula cells hold their last calculated value only.
+//! Encrypted files and files older than BIFF8 (Excel 97) are not supported.
+
+use anyhow::bail;
+
+pub mod workbook;
+pub mod worksheet;
+
+/// BOF: beginning of a substream
+const BOF: u16 = 0x0809;
+/// EOF: end of a substream
+const EOF: u16 = 0x000A;
+/// CONTINUE: data of the previous record exceeding the maximum record size
+const CONTINUE: u16 = 0x003C;
+/// vers of the BOF record for BIFF8
+const BIFF8_VERSION: u16 = 0x0600;
+
+/// Reads the records of the workbook stream one at a time.
+///
+/// A record is made of
+/// - record type (2 bytes)
+/// - record size (2 bytes)
+/// - record data: `size` bytes, at most 8224.
+pub(crate) struct RecordReader<'a> {
+    stream: &'a [u8],
+    position: usize,
+}
+
+impl<'a> RecordReader<'a> {
+    /// * position: offset of the first record, ie: the BOF record of a sheet substream.
+    pub(crate) fn new(stream: &'a [u8], position: usize) -> Self {
+        Self { stream, position }
+    }
+
+    /// (record type, record data), None at the end of the stream.
+    pub(crate) fn next_record(&mut self) -> anyhow::Result<Option<(u16, RecordData<'a>)>> {
+        let Some(record) = self.read_record()? else {
+            return Ok(None);
+        };
+        let (record_type, data) = record;
+        let mut segments = vec![data];
+        while let Some((CONTINUE, _)) = self.peek_header() {
+            let Some((_, data)) = self.read_record()? else {
+                break;
+            };
+            segments.push(data);
+        }
+        return Ok(Some((record_type, RecordData::new(segments))));
+    }
+
+    /// Read the BOF record starting a substream, bail for versions other than BIFF8.
+    ///
+    /// - vers (2 bytes): 0x0600 for BIFF8
+    /// - dt (2 bytes): substream type, 0x0005 globals, 0x0010 worksheet, 0x0020 chart sheet, 0x0040 macro sheet
+    pub(crate) fn read_bof(&mut self) -> anyhow::Result<u16> {
+        let Some((BOF, mut data)) = self.next_record()? else {
+            bail!("xls stream does not start with a BOF record.")
+        };
+        let version = data.read_u16()?;
+        if version != BIFF8_VERSION {
+            bail!(
+                "Unsupported xls version {:#06x}, only BIFF8 (Excel 97 and later) files are supported.",
+                version
+            )
+        }
+        return data.read_u16();
+    }
+
+    fn peek_header(&self) -> Option<(u16, usize)> {
+        let header = self.stream.get(self.position..self.position + 4)?;
+        return Some((
+            u16::from_le_bytes([header[0], header[1]]),
+            u16::from_le_bytes([header[2], header[3]]) as usize,
+        ));
+    }
+
+    fn read_record(&mut self) -> anyhow::Result<Option<(u16, &'a [u8])>> {
+        let Some((record_type, size)) = self.peek_header() else {
+            return Ok(None);
+        };
+        let start = self.position + 4;
+        let Some(data) = self.stream.get(start..start + size) else {
+            bail!("unexpected end of xls stream.")
+        };
+        self.position = start + size;
+        return Ok(Some((record_type, data)));
+    }
+}
+
+/// Little endian cursor over the data of a record and its CONTINUE records.
+pub(crate) struct RecordData<'a> {
+    segments: Vec<&'a [u8]>,
+    segment: usize,
+    position: usize,
+}
+
+impl<'a> RecordData<'a> {
+    fn new(segments: Vec<&'a [u8]>) -> Self {
+        Self {
+            segments,
+            segment: 0,
+            position: 0,
+        }
+    }
+
+    /// Bytes left in the current segment.
+    pub(crate) fn remaining(&self) -> usize {
+        return self.segments[self.segment].len() - self.position;
+    }
+
+    pub(crate) fn read_u8(&mut self) -> anyhow::Result<u8> {
+        return Ok(self.read_bytes(1)?[0]);
+    }
+
+    pub(crate) fn read_u16(&mut self) -> anyhow::Result<u16> {
+        let bytes = self.read_bytes(2)?;
+        return Ok(u16::from_le_bytes([bytes[0], bytes[1]]));
+    }
+
+    pub(crate) fn read_i16(&mut self) -> anyhow::Result<i16> {
+        return Ok(self.read_u16()? as i16);
+    }
+
+    pub(crate) fn read_u32(&mut self) -> anyhow::Result<u32> {
+        let bytes = self.read_bytes(4)?;
+        return Ok(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]));
+    }
+
+    pub(crate) fn read_f64(&mut self) -> anyhow::Result<f64> {
+        let bytes = self.read_bytes(8)?;
+        let mut buf = [0u8; 8];
+        buf.copy_from_slice(bytes);
+        return Ok(f64::from_le_bytes(buf));
+    }
+
+    pub(crate) fn skip(&mut self, count: usize) -> anyhow::Result<()> {
+        let mut count = count;
+        while count > 0 {
+            if self.remaining() == 0 {
+                self.next_segment()?;
+            }
+            let step = count.min(self.remaining());
+            self.position += step;
+            count -= step;
+        }
+        return Ok(());
+    }
+
+    /// ShortXLUnicodeString: cch (1 byte), fHighByte (1 byte), characters
+    pub(crate) fn read_short_string(&mut self) -> anyhow::Result<String> {
+        let count = self.read_u8()? as usize;
+        let flags = self.read_u8()?;
+        return self.read_characters(count, flags & 0x1 != 0);
+    }
+
+    /// XLUnicodeString: cch (2 bytes), fHighByte (1 byte), characters
+    pub(crate) fn read_string(&mut self) -> anyhow::Result<String> {
+        let count = self.read_u16()? as usize;
+        let flags = self.read_u8()?;
+        return self.read_characters(count, flags & 0x1 != 0);
+    }
+
+    /// XLUnicodeRichExtendedString
+    /// - cch (2 bytes)
+    /// - flags (1 byte): bit 0 fHighByte, 2 fExtSt, 3 fRichSt
+    /// - cRun (2 bytes): number of formatting runs, if fRichSt
+    /// - cbExtRst (4 bytes): size of the phonetic information, if fExtSt
+    /// - characters, formatting runs (4 bytes each) and phonetic information
+    ///
+    /// Only the text is returned, formatting runs and phonetic information are skipped.
+    pub(crate) fn read_rich_extended_string(&mut self) -> anyhow::Result<String> {
+        let count = self.read_u16()? as usize;
+        let flags = self.read_u8()?;
+        let runs = if flags & 0x8 != 0 {
+            self.read_u16()? as usize
+        } else {
+            0
+        };
+        let extension_size = if flags & 0x4 != 0 {
+            self.read_u32()? as usize
+        } else {
+            0
+        };
+        let text = self.read_characters(count, flags & 0x1 != 0)?;
+        self.skip(runs * 4 + extension_size)?;
+        return Ok(text);
+    }
+
+    /// Characters are either single bytes (the low byte of UTF-16 code units) or UTF-16.
+    ///
+    /// When the characters continue in a CONTINUE record, the record starts with a fHighByte flag (1 byte) for the remaining characters.
+    fn read_characters(&mut self, count: usize, high_byte: bool) -> anyhow::Result<String> {
+        let mut units: Vec<u16> = Vec::with_capacity(count);
+        let mut high_byte = high_byte;
+        while units.len() < count {
+            if self.remaining() == 0 {
+                self.next_segment()?;
+                high_byte = self.read_u8()? & 0x1 != 0;
+            }
+            let width = if high_byte { 2 } else { 1 };
+            let available = (self.remaining() / width).min(count - units.len());
+            if available == 0 {
+                bail!("unexpected end of xls record.")
+            }
+            let bytes = self.read_bytes(available * width)?;
+            if high_byte {
+                units.extend(
+                    bytes
+                        .chunks_exact(2)
+                        .map(|b| u16::from_le_bytes([b[0], b[1]])),
+                );
+            } else {
+                units.extend(bytes.iter().map(|b| *b as u16));
+            }
+        }
+        return Ok(String::from_utf16_lossy(&units));
+    }
+
+    fn read_bytes(&mut self, count: usize) -> anyhow::Result<&'a [u8]> {
+        self.next_segment_if_exhausted()?;
+        let segment = self.segments[self.segment];
+        let Some(bytes) = segment.get(self.position..self.position + count) else {
+            bail!("unexpected end of xls record.")
+        };
+        self.position += count;
+        return Ok(bytes);
+    }
+
+    fn next_segment_if_exhausted(&mut self) -> anyhow::Result<()> {
+        if self.remaining() == 0 && self.segment + 1 < self.segments.len() {
+            self.next_segment()?;
+        }
+        return Ok(());
+    }
+
+    fn next_segment(&mut self) -> anyhow::Result<()> {
+        if self.segment + 1 >= self.segments.len() {
+            bail!("unexpected end of xls record.")
+        }
+        self.segment += 1;
+        self.position = 0;
+        return Ok(());
+    }
+}
@@ -0,0 +1,273 @@
+use anyhow::bail;
+
+use crate::raw::{
+    binary::stylesheet::{
+        horizontal_alignment_value, underline_value, vertical_align_run_value,
+        vertical_alignment_value,
+    },
+    spreadsheet::{
+        shared_string::shared_string_table::XlsxSharedStringTable,
+        string_item::XlsxStringItem,
+        stylesheet::{
+            color::XlsxColor,
+            font::{XlsxFont, XlsxFonts},
+            format::{
+                alignment::XlsxAlignment,
+                cell_format::XlsxCellFormat,
+                cell_xfs::XlsxCellFormats,
+                numbering_format::{XlsxNumberingFormat, XlsxNumberingFormats},
+                protection::XlsxCellProtection,
+            },
+            XlsxStyleSheet,
+        },
+        workbook::{sheet::XlsxSheet, workbook_properties::XlsxWorkbookProperties, XlsxWorkbook},
+    },
+};
+
+use super::{RecordData, RecordReader, EOF};
+
+/// DATEMODE: bit 0 fDate1904
+const DATE_MODE: u16 = 0x0022;
+/// FILEPASS: the workbook is encrypted
+const FILE_PASS: u16 = 0x002F;
+/// FONT: a font
+const FONT: u16 = 0x0031;
+/// BOUNDSHEET8: a sheet and the position of its substream
+const BOUND_SHEET: u16 = 0x0085;
+/// XF: a cell format or cell style format
+const XF: u16 = 0x00E0;
+/// SST: shared string table
+const SST: u16 = 0x00FC;
+/// FORMAT: a number format
+const FORMAT: u16 = 0x041E;
+
+/// Automatic color index of a font.
+const AUTOMATIC_COLOR: u16 = 0x7FFF;
+
+/// A sheet of the globals substream.
+#[derive(Debug, Clone)]
+pub(crate) struct XlsSheet {
+    pub(crate) sheet: XlsxSheet,
+    /// dt: 0 worksheet or dialog sheet, 1 macro sheet, 2 chart sheet, 6 VBA module
+    pub(crate) sheet_type: u8,
+    /// lbPlyPos: offset of the BOF record of the sheet substream within the workbook stream
+    pub(crate) position: usize,
+}
+
+/// Parts of the workbook held by the globals substream.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct XlsGlobals {
+    pub(crate) workbook: XlsxWorkbook,
+    pub(crate) shared_strings: XlsxSharedStringTable,
+    pub(crate) stylesheet: XlsxStyleSheet,
+    pub(crate) sheets: Vec<XlsSheet>,
+}
+
+/// Load the globals substream, at the start of the workbook stream.
+///
+/// Only sheets, the date system, shared strings, number formats, fonts and cell formats are parsed.
+pub(crate) fn load_xls_globals(stream: &[u8]) -> anyhow::Result<XlsGlobals> {
+    let mut reader = RecordReader::new(stream, 0);
+    reader.read_bof()?;
+
+    let mut globals = XlsGlobals::default();
+    let mut is_1904 = false;
+    let mut strings: Vec<XlsxStringItem> = vec![];
+    let mut numbering_formats: XlsxNumberingFormats = vec![];
+    let mut fonts: XlsxFonts = vec![];
+    let mut cell_xfs: XlsxCellFormats = vec![];
+
+    while let Some((record_type, mut data)) = reader.next_record()? {
+        match record_type {
+            EOF => break,
+            FILE_PASS => bail!("Encrypted xls files are not supported."),
+            DATE_MODE => is_1904 = data.read_u16()? & 0x1 != 0,
+            BOUND_SHEET => {
+                let sheet_id = globals.sheets.len() as u64 + 1;
+                globals.sheets.push(load_sheet(&mut data, sheet_id)?);
+            }
+            // cstTotal (4 bytes), cstUnique (4 bytes), XLUnicodeRichExtendedString for each unique string
+            SST => {
+                globals.shared_strings.count = Some(data.read_u32()? as u64);
+                let unique_count = data.read_u32()?;
+                globals.shared_strings.unique_count = Some(unique_count as u64);
+                for _ in 0..unique_count {
+                    strings.push(XlsxStringItem {
+                        text: Some(data.read_rich_extended_string()?),
+                        ..Default::default()
+                    });
+                }
+            }
+            // ifmt (2 bytes), stFormat (XLUnicodeString)
+            FORMAT => {
+                let id = data.read_u16()?;
+                numbering_formats.push(XlsxNumberingFormat {
+                    num_fmt_id: Some(id as u64),
+                    format_code: Some(data.read_string()?),
+                });
+            }
+            FONT => fonts.push(load_font(&mut data)?),
+            XF => cell_xfs.push(load_cell_format(&mut data)?),
+            _ => (),
+        }
+    }
+
+    globals.workbook.workbook_properties = Some(XlsxWorkbookProperties {
+        date1904: Some(is_1904),
+        ..Default::default()
+    });
+    globals.workbook.sheets = Some(globals.sheets.iter().map(|s| s.sheet.clone()).collect());
+    globals.shared_strings.string_item = Some(strings);
+
+    // cell formats and cell style formats share the same list, ixfParent and cell ixfe are indexes into it
+    globals.stylesheet.numbering_formats = Some(numbering_formats);
+    globals.stylesheet.fonts = Some(fonts);
+    globals.stylesheet.cell_style_xfs = Some(cell_xfs.clone());
+    globals.stylesheet.cell_xfs = Some(cell_xfs);
+
+    return Ok(globals);
+}
+
+/// BOUNDSHEET8
+/// - lbPlyPos (4 bytes): position of the BOF record of the sheet substream
+/// - hsState (1 byte): bits 0-1, 0 visible, 1 hidden, 2 very hidden
+/// - dt (1 byte): sheet type
+/// - stName (ShortXLUnicodeString)
+fn load_sheet(data: &mut RecordData, sheet_id: u64) -> anyhow::Result<XlsSheet> {
+    let position = data.read_u32()? as usize;
+    let state = data.read_u8()?;
+    let sheet_type = data.read_u8()?;
+    let name = data.read_short_string()?;
+
+    let visible_state = match state & 0x3 {
+        1 => "hidden",
+        2 => "veryHidden",
+        _ => "visible",
+    };
+
+    return Ok(XlsSheet {
+        sheet: XlsxSheet {
+            id: None,
+            name: Some(name),
+            sheet_id: Some(sheet_id),
+            visible_state: Some(visible_state.to_string()),
+        },
+        sheet_type,
+        position,
+    });
+}
+
+/// FONT
+/// - dyHeight (2 bytes): size in twips
+/// - grbit (2 bytes): bit 1 italic, 3 strike, 4 outline, 5 shadow, 6 condense, 7 extend
+/// - icv (2 bytes): indexed color, 0x7FFF for automatic
+/// - bls (2 bytes): weight, 700 for bold
+/// - sss (2 bytes): 0 baseline, 1 superscript, 2 subscript
+/// - uls (1 byte): underline
+/// - bFamily (1 byte), bCharSet (1 byte), unused (1 byte)
+/// - fontName (ShortXLUnicodeString)
+fn load_font(data: &mut RecordData) -> anyhow::Result<XlsxFont> {
+    let height = data.read_u16()?;
+    let flags = data.read_u16()?;
+    let color_index = data.read_u16()?;
+    let weight = data.read_u16()?;
+    let script = data.read_u16()?;
+    let underline = data.read_u8()?;
+    let family = data.read_u8()?;
+    let charset = data.read_u8()?;
+    data.skip(1)?;
+    let name = data.read_short_string()?;
+
+    let color = if color_index == AUTOMATIC_COLOR {
+        XlsxColor {
+            auto: Some(true),
+            ..Default::default()
+        }
+    } else {
+        XlsxColor {
+            indexed: Some(color_index as u64),
+            ..Default::default()
+        }
+    };
+
+    return Ok(XlsxFont {
+        bold: Some(weight >= 700),
+        charset: Some(charset.to_string()),
+        color: Some(color),
+        condense: Some(flags & 0x40 != 0),
+        extend: Some(flags & 0x80 != 0),
+        family: Some(family as u64),
+        italic: Some(flags & 0x2 != 0),
+        name: Some(name),
+        outline: Some(flags & 0x10 != 0),
+        scheme: None,
+        shadow: Some(flags & 0x20 != 0),
+        strike: Some(flags & 0x8 != 0),
+        size: Some(height as f64 / 20.0),
+        underline: underline_value(underline).map(|s| s.to_string()),
+        vert_align: vertical_align_run_value(script).map(|s| s.to_string()),
+    });
+}
+
+/// XF
+/// - ifnt (2 bytes): font index, the font at index 4 does not exist, indexes above are one more than the position of the FONT record
+/// - ifmt (2 bytes): number format id
+/// - flags (2 bytes): bit 0 fLocked, 1 fHidden, 2 fStyle, 3 f123Prefix, bits 4-15 ixfParent
+/// - alignment (1 byte): bits 0-2 alc, bit 3 fWrap, bits 4-6 alcV, bit 7 fJustLast
+/// - trot (1 byte): text rotation
+/// - flags (1 byte): bits 0-3 cIndent, bit 4 fShrinkToFit, bits 6-7 iReadOrder
+/// - flags (1 byte): bit 2 number format, 3 font, 4 alignment, 5 border, 6 fill, 7 protection.
+///   Set for a cell format when it does not use the attribute of its style, cleared for a style format when it defines the attribute.
+///
+/// followed by borders and fill, which are not parsed.
+fn load_cell_format(data: &mut RecordData) -> anyhow::Result<XlsxCellFormat> {
+    let font_index = data.read_u16()?;
+    let num_fmt_id = data.read_u16()?;
+    let flags = data.read_u16()?;
+    let alignment = data.read_u8()? as u16;
+    let text_rotation = data.read_u8()?;
+    let indent = data.read_u8()?;
+    let is_style = flags & 0x4 != 0;
+    let applied = if is_style {
+        !data.read_u8()?
+    } else {
+        data.read_u8()?
+    };
+    let parent = flags >> 4;
+    let font_id = if font_index >= 4 {
+        font_index - 1
+    } else {
+        font_index
+    };
+
+    return Ok(XlsxCellFormat {
+        alignment: Some(XlsxAlignment {
+            horizontal: Some(horizontal_alignment_value(alignment & 0x7).to_string()),
+            indent: Some((indent & 0xF) as u64),
+            justify_last_line: Some(alignment & 0x80 != 0),
+            reading_order: Some(((indent >> 6) & 0x3) as u64),
+            shrink_to_fit: Some(indent & 0x10 != 0),
+            text_rotation: Some(text_rotation as u64),
+            vertical: Some(vertical_alignment_value((alignment >> 4) & 0x7).to_string()),
+            wrap_text: Some(alignment & 0x8 != 0),
+            ..Default::default()
+        }),
+        protection: Some(XlsxCellProtection {
+            hidden: Some(flags & 0x2 != 0),
+            locked: Some(flags & 0x1 != 0),
+        }),
+        apply_alignment: Some(applied & 0x10 != 0),
+        apply_border: Some(applied & 0x20 != 0),
+        apply_fill: Some(applied & 0x40 != 0),
+        apply_font: Some(applied & 0x8 != 0),
+        apply_number_format: Some(applied & 0x4 != 0),
+        apply_protection: Some(applied & 0x80 != 0),
+        border_id: None,
+        fill_id: None,
+        font_id: Some(font_id as u64),
+        num_fmt_id: Some(num_fmt_id as u64),
+        pivot_button: None,
+        quote_prefix: Some(flags & 0x8 != 0),
+        xf_id: if is_style { None } else { Some(parent as u64) },
+    });
+}
@@ -0,0 +1,387 @@
+use std::collections::BTreeMap;
+
+use crate::{
+    common_types::{Coordinate, Dimension},
+    raw::{
+        binary::worksheet::{error_code_to_string, rk_to_string},
+        spreadsheet::{
+            sheet::{
+                sheet_format_properties::XlsxSheetFormatProperties,
+                worksheet::{
+                    cell::{cell_value::XlsxCellValue, XlsxCell},
+                    column_information::{XlsxColumnInformation, XlsxColumnInformations},
+                    merge_cell::XlsxMergeCells,
+                    row::XlsxRow,
+                    sheet_data::XlsxSheetData,
+                    XlsxWorksheet,
+                },
+            },
+            string_item::XlsxStringItem,
+        },
+    },
+};
+
+use super::{RecordData, RecordReader, BOF, EOF};
+
+/// FORMULA: a formula cell with its last calculated value
+const FORMULA: u16 = 0x0006;
+/// DEFCOLWIDTH: base column width in characters
+const DEFAULT_COLUMN_WIDTH: u16 = 0x0055;
+/// COLINFO: width and format of a range of columns
+const COLUMN_INFO: u16 = 0x007D;
+/// MULRK: RK cells of consecutive columns
+const MUL_RK: u16 = 0x00BD;
+/// MULBLANK: blank cells of consecutive columns
+const MUL_BLANK: u16 = 0x00BE;
+/// MERGECELLS: merged ranges
+const MERGE_CELLS: u16 = 0x00E5;
+/// LABELSST: cell of a shared string
+const LABEL_SST: u16 = 0x00FD;
+/// DIMENSIONS: used range of the sheet
+const DIMENSIONS: u16 = 0x0200;
+/// BLANK: a blank cell
+const BLANK: u16 = 0x0201;
+/// NUMBER: cell of a floating point number
+const NUMBER: u16 = 0x0203;
+/// LABEL: cell of a string
+const LABEL: u16 = 0x0204;
+/// BOOLERR: cell of a boolean or an error
+const BOOL_ERR: u16 = 0x0205;
+/// STRING: string result of the preceding FORMULA record
+const STRING: u16 = 0x0207;
+/// ROW: properties of a row
+const ROW: u16 = 0x0208;
+/// DEFAULTROWHEIGHT: default row height
+const DEFAULT_ROW_HEIGHT: u16 = 0x0225;
+/// RK: cell of a RK number
+const RK: u16 = 0x027E;
+
+/// Load the sheet substream starting at `position` within the workbook stream.
+///
+/// Dimension, sheet format properties, column information, merged cells and cells are parsed.
+/// Substreams embedded within the sheet, ie: charts, are skipped.
+///
+/// * with_sheet_data: false to skip the cells.
+pub(crate) fn load_xls_worksheet(
+    stream: &[u8],
+    position: usize,
+    with_sheet_data: bool,
+) -> anyhow::Result<XlsxWorksheet> {
+    let mut reader = RecordReader::new(stream, position);
+    reader.read_bof()?;
+
+    let mut worksheet = XlsxWorksheet::default();
+    let mut format_properties = XlsxSheetFormatProperties::default();
+    let mut column_infos: XlsxColumnInformations = vec![];
+    let mut merge_cells: XlsxMergeCells = vec![];
+    let mut rows: BTreeMap<u64, XlsxRow> = BTreeMap::new();
+    // formula cell waiting for its string result: (row index, position within the row)
+    let mut pending_string: Option<(u64, usize)> = None;
+    let mut depth = 1;
+
+    while let Some((record_type, mut data)) = reader.next_record()? {
+        match record_type {
+            BOF => depth += 1,
+            EOF => {
+                depth -= 1;
+                if depth == 0 {
+                    break;
+                }
+            }
+            _ if depth > 1 => (),
+            DIMENSIONS => worksheet.dimension = load_dimension(&mut data)?,
+            DEFAULT_COLUMN_WIDTH => {
+                format_properties.base_col_width = Some(data.read_u16()? as u64);
+            }
+            // flags (2 bytes): bit 0 fUnsynced, 1 fDyZero, 2 fExAsc, 3 fExDsc, miyRw (2 bytes): height in twips
+            DEFAULT_ROW_HEIGHT => {
+                let flags = data.read_u16()?;
+                let height = data.read_i16()?;
+                format_properties.custom_height = Some(flags & 0x1 != 0);
+                format_properties.zero_height = Some(flags & 0x2 != 0);
+                format_properties.thick_top = Some(flags & 0x4 != 0);
+                format_properties.thick_bottom = Some(flags & 0x8 != 0);
+                format_properties.default_row_height = Some(height as f64 / 20.0);
+            }
+            COLUMN_INFO => column_infos.push(load_column_information(&mut data)?),
+            // cmcs (2 bytes), Ref8 for each range: rwFirst, rwLast, colFirst, colLast (2 bytes each, 0 based)
+            MERGE_CELLS => {
+                let count = data.read_u16()?;
+                for _ in 0..count {
+                    let first_row = data.read_u16()? as u64;
+                    let last_row = data.read_u16()? as u64;
+                    let first_col = data.read_u16()? as u64;
+                    let last_col = data.read_u16()? as u64;
+                    merge_cells.push(Dimension {
+                        start: Coordinate::from_point((first_row + 1, first_col + 1)),
+                        end: Coordinate::from_point((last_row + 1, last_col + 1)),
+                    });
+                }
+            }
+            ROW if with_sheet_data => {
+                let row = load_row(&mut data)?;
+                let Some(index) = row.row_index else {
+                    continue;
+                };
+                let cells = rows.remove(&index).and_then(|r| r.cells);
+                rows.insert(index, XlsxRow { cells, ..row });
+            }
+            STRING if with_sheet_data => {
+                let Some((row_index, position)) = pending_string.take() else {
+                    continue;
+                };
+                let text = data.read_string()?;
+                let cell = rows
+                    .get_mut(&row_index)
+                    .and_then(|r| r.cells.as_mut())
+                    .and_then(|c| c.get_mut(position));
+                if let Some(cell) = cell {
+                    cell.inline_string = Some(XlsxStringItem {
+                        text: Some(text),
+                        ..Default::default()
+                    });
+                }
+            }
+            FORMULA | MUL_RK | MUL_BLANK | LABEL_SST | BLANK | NUMBER | LABEL | BOOL_ERR | RK
+                if with_sheet_data =>
+            {
+                let (row_index, cells, is_string_formula) = load_cells(record_type, &mut data)?;
+                let row = rows.entry(row_index).or_insert_with(|| XlsxRow {
+                    row_index: Some(row_index),
+                    ..Default::default()
+                });
+                let row_cells = row.cells.get_or_insert_with(Vec::new);
+                row_cells.extend(cells);
+                pending_string = if is_string_formula {
+                    Some((row_index, row_cells.len() - 1))
+                } else {
+                    None
+                };
+            }
+            _ => (),
+        }
+    }
+
+    worksheet.sheet_format_properties = Some(format_properties);
+    if !column_infos.is_empty() {
+        worksheet.column_infos = Some(column_infos);
+    }
+    if !merge_cells.is_empty() {
+        worksheet.merge_cells = Some(merge_cells);
+    }
+    if with_sheet_data {
+        let rows = rows
+            .into_values()
+            .map(|mut row| {
+                if let Some(cells) = row.cells.as_mut() {
+                    cells.sort_by_key(|c| c.coordinate().map(|c| c.col));
+                }
+                row
+            })
+            .collect();
+        worksheet.sheet_data = Some(XlsxSheetData { rows: Some(rows) });
+    }
+
+    return Ok(worksheet);
+}
+
+/// DIMENSIONS
+/// - rwMic (4 bytes), rwMac (4 bytes): first row, one past the last row (0 based)
+/// - colMic (2 bytes), colMac (2 bytes): first column, one past the last column (0 based)
+///
+/// None for an empty sheet.
+fn load_dimension(data: &mut RecordData) -> anyhow::Result<Option<Dimension>> {
+    let first_row = data.read_u32()? as u64;
+    let last_row = data.read_u32()? as u64;
+    let first_col = data.read_u16()? as u64;
+    let last_col = data.read_u16()? as u64;
+    if last_row <= first_row || last_col <= first_col {
+        return Ok(None);
+    }
+
+    return Ok(Some(Dimension {
+        start: Coordinate::from_point((first_row + 1, first_col + 1)),
+        end: Coordinate::from_point((last_row, last_col)),
+    }));
+}
+
+/// COLINFO
+/// - colFirst, colLast (2 bytes each, 0 based)
+/// - coldx (2 bytes): width in 1/256 of a character
+/// - ixfe (2 bytes): style index
+/// - flags (2 bytes): bit 0 fHidden, 1 fUserSet, 2 fBestFit, 3 fPhonetic, bits 8-10 iOutLevel, bit 12 fCollapsed
+fn load_column_information(data: &mut RecordData) -> anyhow::Result<XlsxColumnInformation> {
+    let first = data.read_u16()?;
+    let last = data.read_u16()?;
+    let width = data.read_u16()?;
+    let style = data.read_u16()?;
+    let flags = data.read_u16()?;
+
+    return Ok(XlsxColumnInformation {
+        best_fit: Some(flags & 0x4 != 0),
+        collapsed: Some(flags & 0x1000 != 0),
+        custom_width: Some(flags & 0x2 != 0),
+        hidden: Some(flags & 0x1 != 0),
+        max_column: Some(last as u64 + 1),
+        min_column: Some(first as u64 + 1),
+        outline_level: Some(((flags >> 8) & 0x7) as u64),
+        show_phonetic: Some(flags & 0x8 != 0),
+        style: Some(style as u64),
+        width: Some(width as f64 / 256.0),
+    });
+}
+
+/// ROW
+/// - rw (2 bytes): 0 based row index
+/// - colMic, colMac (2 bytes each)
+/// - miyRw (2 bytes): bits 0-14 height in twips
+/// - reserved (4 bytes)
+/// - flags (1 byte): bits 0-2 iOutLevel, bit 4 fCollapsed, 5 fDyZero, 6 fUnsynced, 7 fGhostDirty
+/// - reserved (1 byte)
+/// - flags (2 bytes): bits 0-11 ixfe_val, bit 12 fExAsc, 13 fExDsc, 14 fPhonetic
+fn load_row(data: &mut RecordData) -> anyhow::Result<XlsxRow> {
+    let index = data.read_u16()?;
+    data.skip(4)?;
+    let height = data.read_u16()? & 0x7FFF;
+    data.skip(4)?;
+    let flags = data.read_u8()?;
+    data.skip(1)?;
+    let style = data.read_u16()?;
+    // custom format
+    let ghost_dirty = flags & 0x80 != 0;
+
+    return Ok(XlsxRow {
+        collapsed: Some(flags & 0x10 != 0),
+        custom_format: Some(ghost_dirty),
+        custom_height: Some(flags & 0x40 != 0),
+        height: Some(height as f64 / 20.0),
+        hidden: Some(flags & 0x20 != 0),
+        outline_level: Some((flags & 0x7) as u64),
+        row_index: Some(index as u64 + 1),
+        show_phonetic: Some(style & 0x4000 != 0),
+        style: if ghost_dirty {
+            Some((style & 0xFFF) as u64)
+        } else {
+            None
+        },
+        thick_bottom: Some(style & 0x2000 != 0),
+        thick_top: Some(style & 0x1000 != 0),
+        ..Default::default()
+    });
+}
+
+/// Cell records start with
+/// - rw (2 bytes): 0 based row index
+/// - col (2 bytes): 0 based column index, the first column for MULRK and MULBLANK
+///
+/// followed by
+/// - BLANK: ixfe (2 bytes)
+/// - MULBLANK: ixfe (2 bytes) for each column, colLast (2 bytes)
+/// - RK: ixfe (2 bytes), RkNumber (4 bytes)
+/// - MULRK: ixfe (2 bytes) and RkNumber (4 bytes) for each column, colLast (2 bytes)
+/// - NUMBER: ixfe (2 bytes), Xnum (8 bytes)
+/// - LABELSST: ixfe (2 bytes), shared string index (4 bytes)
+/// - LABEL: ixfe (2 bytes), XLUnicodeString
+/// - BOOLERR: ixfe (2 bytes), bBoolErr (1 byte), fError (1 byte)
+/// - FORMULA: ixfe (2 bytes), FormulaValue (8 bytes), flags (2 bytes), chn (4 bytes), parsed formula (not decoded).
+///   FormulaValue is a Xnum, unless the last 2 bytes are 0xFFFF: the first byte is then 0 for a string held by the next STRING record,
+///   1 for a boolean, 2 for an error and 3 for an empty string, with the value in the third byte.
+///
+/// Returns the 1 based row index, the cells, and whether the cell is a formula waiting for its STRING record.
+fn load_cells(
+    record_type: u16,
+    data: &mut RecordData,
+) -> anyhow::Result<(u64, Vec<XlsxCell>, bool)> {
+    let row_index = data.read_u16()? as u64 + 1;
+    let column = data.read_u16()? as u64 + 1;
+    let new_cell = |column: u64, style: u16| {
+        let mut cell = XlsxCell::new(Coordinate::from_point((row_index, column)));
+        cell.style = Some(style as u64);
+        cell
+    };
+
+    match record_type {
+        MUL_BLANK => {
+            let count = data.remaining().saturating_sub(2) / 2;
+            let mut cells = Vec::with_capacity(count);
+            for offset in 0..count {
+                cells.push(new_cell(column + offset as u64, data.read_u16()?));
+            }
+            return Ok((row_index, cells, false));
+        }
+        MUL_RK => {
+            let count = data.remaining().saturating_sub(2) / 6;
+            let mut cells = Vec::with_capacity(count);
+            for offset in 0..count {
+                let mut cell = new_cell(column + offset as u64, data.read_u16()?);
+                set_value(&mut cell, "n", rk_to_string(data.read_u32()?));
+                cells.push(cell);
+            }
+            return Ok((row_index, cells, false));
+        }
+        _ => (),
+    }
+
+    let mut cell = new_cell(column, data.read_u16()?);
+    let mut is_string_formula = false;
+    match record_type {
+        RK => set_value(&mut cell, "n", rk_to_string(data.read_u32()?)),
+        NUMBER => set_value(&mut cell, "n", data.read_f64()?.to_string()),
+        LABEL_SST => set_value(&mut cell, "s", data.read_u32()?.to_string()),
+        LABEL => {
+            cell.r#type = Some("inlineStr".to_string());
+            cell.inline_string = Some(XlsxStringItem {
+                text: Some(data.read_string()?),
+                ..Default::default()
+            });
+        }
+        BOOL_ERR => {
+            let value = data.read_u8()?;
+            let is_error = data.read_u8()? != 0;
+            set_bool_or_error(&mut cell, value, is_error);
+        }
+        FORMULA => {
+            let value = data.read_f64()?;
+            let bits = value.to_bits();
+            if bits >> 48 != 0xFFFF {
+                set_value(&mut cell, "n", value.to_string());
+            } else {
+                let value_type = (bits & 0xFF) as u8;
+                let value = ((bits >> 16) & 0xFF) as u8;
+                match value_type {
+                    0 | 3 => {
+                        cell.r#type = Some("inlineStr".to_string());
+                        cell.inline_string = Some(XlsxStringItem {
+                            text: Some(String::new()),
+                            ..Default::default()
+                        });
+                        is_string_formula = value_type == 0;
+                    }
+                    1 => set_bool_or_error(&mut cell, value, false),
+                    2 => set_bool_or_error(&mut cell, value, true),
+                    _ => (),
+                }
+            }
+        }
+        _ => (),
+    }
+
+    return Ok((row_index, vec![cell], is_string_formula));
+}
+
+fn set_value(cell: &mut XlsxCell, r#type: &str, value: String) {
+    cell.r#type = Some(r#type.to_string());
+    cell.cell_value = Some(XlsxCellValue {
+        raw_value: value,
+        space: None,
+    });
+}
+
+fn set_bool_or_error(cell: &mut XlsxCell, value: u8, is_error: bool) {
+    if !is_error {
+        let value = if value != 0 { "1" } else { "0" };
+        set_value(cell, "b", value.to_string());
+    } else if let Some(error) = error_code_to_string(value) {
+        set_value(cell, "e", error.to_string());
+    }
+}
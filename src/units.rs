@@ -0,0 +1,147 @@
+//! Conversions between the units used within a spreadsheet package.
+//!
+//! - Points (pt): 1/72 of an inch, used for row heights, font sizes and processed drawing sizes.
+//! - English Metric Unit (EMU): 1/360,000 of a centimeter, used for drawing positions and sizes.
+//! - Twips: 1/20 of a point, used by some legacy measurements.
+//! - Pixels (px): depend on the dots per inch (dpi) of the device, 96 dpi by default.
+//! - Characters: column widths, measured in the maximum digit width of the normal style's font.
+
+/// Dots per inch used by Excel for pixel measurements.
+pub const DEFAULT_DPI: f64 = 96.0;
+
+pub const POINTS_PER_INCH: f64 = 72.0;
+
+/// - 1 inch = 914,400 EMUs
+pub const EMUS_PER_INCH: f64 = 914_400.0;
+
+/// - 1 point = 12,700 EMUs
+pub const EMUS_PER_POINT: f64 = 12_700.0;
+
+/// - 1 cm = 360,000 EMUs
+pub const EMUS_PER_CM: f64 = 360_000.0;
+
+/// - 1 point = 20 twips
+pub const TWIPS_PER_POINT: f64 = 20.0;
+
+/// Maximum digit width in pixels of the normal style's font.
+///
+/// Appximate with Aptos of font size 12 pt.
+pub const DEFAULT_MAX_DIGIT_WIDTH: f64 = 8.2;
+
+/// Padding in pixels of a column: 2 pixels of margin on each side, plus 1 pixel for the gridline.
+pub const COLUMN_PADDING: f64 = 5.0;
+
+/// Font size in points [DEFAULT_MAX_DIGIT_WIDTH] is measured with.
+const DEFAULT_MAX_DIGIT_WIDTH_FONT_SIZE: f64 = 12.0;
+
+pub fn emu_to_pt(emu: i64) -> f64 {
+    return (emu as f64) / EMUS_PER_POINT;
+}
+
+/// Rounded to the nearest EMU.
+pub fn pt_to_emu(pt: f64) -> i64 {
+    return (pt * EMUS_PER_POINT).round() as i64;
+}
+
+pub fn emu_to_cm(emu: i64) -> f64 {
+    return (emu as f64) / EMUS_PER_CM;
+}
+
+/// Rounded to the nearest EMU.
+pub fn cm_to_emu(cm: f64) -> i64 {
+    return (cm * EMUS_PER_CM).round() as i64;
+}
+
+pub fn emu_to_inch(emu: i64) -> f64 {
+    return (emu as f64) / EMUS_PER_INCH;
+}
+
+/// Rounded to the nearest EMU.
+pub fn inch_to_emu(inch: f64) -> i64 {
+    return (inch * EMUS_PER_INCH).round() as i64;
+}
+
+pub fn emu_to_px(emu: i64, dpi: f64) -> f64 {
+    return pt_to_px(emu_to_pt(emu), dpi);
+}
+
+/// Rounded to the nearest EMU.
+pub fn px_to_emu(px: f64, dpi: f64) -> i64 {
+    return pt_to_emu(px_to_pt(px, dpi));
+}
+
+pub fn pt_to_px(pt: f64, dpi: f64) -> f64 {
+    return pt * dpi / POINTS_PER_INCH;
+}
+
+pub fn px_to_pt(px: f64, dpi: f64) -> f64 {
+    return px * POINTS_PER_INCH / dpi;
+}
+
+pub fn pt_to_inch(pt: f64) -> f64 {
+    return pt / POINTS_PER_INCH;
+}
+
+pub fn inch_to_pt(inch: f64) -> f64 {
+    return inch * POINTS_PER_INCH;
+}
+
+pub fn twips_to_pt(twips: i64) -> f64 {
+    return (twips as f64) / TWIPS_PER_POINT;
+}
+
+/// Rounded to the nearest twip.
+pub fn pt_to_twips(pt: f64) -> i64 {
+    return (pt * TWIPS_PER_POINT).round() as i64;
+}
+
+/// Approximate the maximum digit width in pixels of a font from its size in points, scaling [DEFAULT_MAX_DIGIT_WIDTH].
+///
+/// Digit widths differ between fonts, use the measured width of the font if available, ie: 7 pixels for Calibri of font size 11 pt.
+pub fn max_digit_width(font_size: f64) -> f64 {
+    return DEFAULT_MAX_DIGIT_WIDTH * font_size / DEFAULT_MAX_DIGIT_WIDTH_FONT_SIZE;
+}
+
+/// Convert the width of a column, as stored in the file, to pixels.
+///
+/// - Column width: https://c-rex.net/samples/ooxml/e1/Part4/OOXML_P4_DOCX_col_topic_ID0ELFQ4.html
+///
+/// =Truncate(((256 * {width} + Truncate(128/{Maximum Digit Width}))/256)*{Maximum Digit Width})
+pub fn column_width_to_px(width: f64, max_digit_width: f64) -> f64 {
+    return (((256.0 * width) + (128.0 / max_digit_width).trunc()) / 256.0 * max_digit_width)
+        .trunc();
+}
+
+/// Convert pixels to the width of a column, as stored in the file.
+///
+/// =Truncate(({pixels}-5)/{Maximum Digit Width} * 100+0.5)/100, then rounded to 1/256 of a character.
+pub fn px_to_column_width(px: f64, max_digit_width: f64) -> f64 {
+    if px <= 0.0 {
+        return 0.0;
+    }
+    let characters = ((px - COLUMN_PADDING) / max_digit_width * 100.0 + 0.5).trunc() / 100.0;
+    return characters_to_column_width(characters.max(0.0), max_digit_width);
+}
+
+/// Convert a number of characters to the width of a column, as stored in the file, including the padding.
+///
+/// width = Truncate([{Number of Characters} * {Maximum Digit Width} + {5 pixel padding}]/{Maximum Digit Width}*256)/256
+///
+/// Ex: 8 characters of Calibri 11 pt (maximum digit width of 7 pixels) -> 8.7109375
+pub fn characters_to_column_width(characters: f64, max_digit_width: f64) -> f64 {
+    return ((characters * max_digit_width + COLUMN_PADDING) / max_digit_width * 256.0).trunc()
+        / 256.0;
+}
+
+/// Convert the width of a column, as stored in the file, to the number of characters displayed by Excel.
+///
+/// =Truncate(({pixels}-5)/{Maximum Digit Width} * 100+0.5)/100
+pub fn column_width_to_characters(width: f64, max_digit_width: f64) -> f64 {
+    let px = column_width_to_px(width, max_digit_width);
+    return (((px - COLUMN_PADDING) / max_digit_width * 100.0 + 0.5).trunc() / 100.0).max(0.0);
+}
+
+/// Convert the width of a column, as stored in the file, to points at [DEFAULT_DPI].
+pub fn column_width_to_pt(width: f64, max_digit_width: f64) -> f64 {
+    return px_to_pt(column_width_to_px(width, max_digit_width), DEFAULT_DPI);
+}
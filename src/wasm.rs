@@ -0,0 +1,77 @@
+//! Browser bindings, enabled with the `wasm` feature, to parse workbooks chosen by the user client-side.
+//!
+//! Built with `wasm-pack build --target web -- --features wasm`:
+//! ```js
+//! import init, { Workbook } from "./pkg/excel_reader.js";
+//!
+//! await init();
+//! const workbook = new Workbook(new Uint8Array(await file.arrayBuffer()));
+//! for (const name of workbook.sheetNames()) {
+//!     const rows = JSON.parse(workbook.sheetRows(name));
+//! }
+//! ```
+use std::io::Cursor;
+
+use wasm_bindgen::prelude::*;
+
+use crate::{excel::Excel, processed::spreadsheet::sheet_basic_info::SheetType};
+
+/// A workbook parsed from the bytes of a xlsx file, `Workbook` in JavaScript.
+#[wasm_bindgen(js_name = Workbook)]
+pub struct WasmWorkbook {
+    excel: Excel<Cursor<Vec<u8>>>,
+}
+
+#[wasm_bindgen(js_class = Workbook)]
+impl WasmWorkbook {
+    /// * bytes: content of the file, ie: a `Uint8Array` from `File.arrayBuffer()`
+    #[wasm_bindgen(constructor)]
+    pub fn new(bytes: &[u8]) -> Result<WasmWorkbook, JsError> {
+        let excel = Excel::from_bytes(bytes.to_vec()).map_err(js_error)?;
+        return Ok(Self { excel });
+    }
+
+    /// Names of the worksheets, in workbook order.
+    #[wasm_bindgen(js_name = sheetNames)]
+    pub fn sheet_names(&mut self) -> Result<Vec<String>, JsError> {
+        let sheets = self.excel.get_sheets().map_err(js_error)?;
+        return Ok(sheets
+            .into_iter()
+            .filter(|s| s.r#type == SheetType::WorkSheet)
+            .map(|s| s.name)
+            .collect());
+    }
+
+    /// Values of a worksheet as displayed by Excel, as a JSON array of rows of strings.
+    ///
+    /// Rows and columns start at the top left cell of the data of the worksheet, ie: `[["Name", "Price"], ["apple", "1.50"]]`.
+    #[wasm_bindgen(js_name = sheetRows)]
+    pub fn sheet_rows(&mut self, name: &str) -> Result<String, JsError> {
+        let worksheet = self.excel.get_worksheet_with_name(name).map_err(js_error)?;
+        let Some(dimension) = worksheet.dimension else {
+            return Ok("[]".to_string());
+        };
+        let width = (dimension.end.col - dimension.start.col + 1) as usize;
+        let cells = worksheet.get_cells().map_err(js_error)?;
+        let rows: Vec<Vec<String>> = cells
+            .chunks(width)
+            .map(|row| {
+                row.iter()
+                    .map(|c| c.formatted_value(worksheet.is_1904))
+                    .collect()
+            })
+            .collect();
+        return serde_json::to_string(&rows).map_err(|e| JsError::new(&e.to_string()));
+    }
+
+    /// Processed model of all worksheets as JSON: cells with their styles, merged cells, tables and data validations.
+    #[wasm_bindgen(js_name = toJson)]
+    pub fn to_json(&mut self) -> Result<String, JsError> {
+        let model = self.excel.to_processed_model().map_err(js_error)?;
+        return model.to_json().map_err(js_error);
+    }
+}
+
+fn js_error(error: anyhow::Error) -> JsError {
+    return JsError::new(&error.to_string());
+}
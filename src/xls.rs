@@ -0,0 +1,196 @@
+//! Legacy binary workbooks (.xls), enabled with the `xls` feature.
+//!
+//! Only BIFF8 files (Excel 97 and later) are supported, see `raw::xls` for the parts that are parsed.
+use anyhow::bail;
+use std::{io::Read, sync::Arc};
+#[cfg(not(target_arch = "wasm32"))]
+use std::{fs::File, path::Path};
+
+use crate::{
+    processed::spreadsheet::{
+        sheet::worksheet::Worksheet,
+        sheet_basic_info::{SheetBasicInfo, SheetType, SheetVisibleState},
+        sheet_name::sheet_names_equal,
+    },
+    raw::{
+        cfb::CompoundFile,
+        spreadsheet::{
+            shared_string::shared_string_table::{SharedStrings, XlsxSharedStringTable},
+            sheet::worksheet::XlsxWorksheet, stylesheet::XlsxStyleSheet, workbook::XlsxWorkbook,
+        },
+        xls::{
+            workbook::{load_xls_globals, XlsGlobals, XlsSheet},
+            worksheet::load_xls_worksheet,
+        },
+    },
+};
+
+/// A legacy binary workbook (.xls).
+///
+/// The whole workbook stream is kept in memory, sheets are parsed when requested.
+pub struct Xls {
+    stream: Vec<u8>,
+    globals: XlsGlobals,
+}
+
+impl Xls {
+    /// Open a xls file.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn from_path<P: AsRef<Path>>(path: P) -> anyhow::Result<Xls> {
+        let file = File::open(path)?;
+        return Self::from_reader(file);
+    }
+
+    /// Read a xls file from a reader.
+    pub fn from_reader<R: Read>(mut reader: R) -> anyhow::Result<Xls> {
+        let mut data: Vec<u8> = vec![];
+        reader.read_to_end(&mut data)?;
+        return Self::from_bytes(&data);
+    }
+
+    /// Read a xls file from its bytes.
+    pub fn from_bytes(data: &[u8]) -> anyhow::Result<Xls> {
+        let file = CompoundFile::load(data)?;
+        let Some(stream) = file.read_stream("Workbook")? else {
+            if file.read_stream("Book")?.is_some() {
+                bail!("Unsupported xls version, only BIFF8 (Excel 97 and later) files are supported.")
+            }
+            bail!("Not a xls file: the Workbook stream does not exist.")
+        };
+        let globals = load_xls_globals(&stream)?;
+
+        return Ok(Self { stream, globals });
+    }
+
+    /// Get raw workbook: sheets and workbook properties only.
+    pub fn get_raw_workbook(&self) -> XlsxWorkbook {
+        return self.globals.workbook.clone();
+    }
+
+    /// Get raw stylesheet: number formats, fonts and cell formats only.
+    pub fn get_raw_stylesheet(&self) -> XlsxStyleSheet {
+        return self.globals.stylesheet.clone();
+    }
+
+    /// Get raw shared strings
+    pub fn get_raw_shared_strings(&self) -> XlsxSharedStringTable {
+        return self.globals.shared_strings.clone();
+    }
+
+    /// Get raw worksheet
+    pub fn get_raw_worksheet(&self, sheet: &SheetBasicInfo) -> anyhow::Result<XlsxWorksheet> {
+        let sheet = self.get_xls_sheet(sheet)?;
+        return load_xls_worksheet(&self.stream, sheet.position, true);
+    }
+
+    /// Get a list of sheets in the workbook.
+    ///
+    /// VBA modules, stored as sheets by xls files, are not listed.
+    pub fn get_sheets(&self) -> anyhow::Result<Vec<SheetBasicInfo>> {
+        return self
+            .globals
+            .sheets
+            .iter()
+            .filter(|s| s.sheet_type != 6)
+            .map(sheet_basic_info)
+            .collect();
+    }
+
+    /// Get worksheet (processed)
+    ///
+    /// name: Worksheet name
+    pub fn get_worksheet_with_name(&self, name: &str) -> anyhow::Result<Worksheet> {
+        let sheets = self.get_sheets()?;
+        let Some(sheet) = sheets.iter().find(|s| sheet_names_equal(&s.name, name)) else {
+            bail!("Sheet with name: `{}` does not exist.", name)
+        };
+        return self.get_worksheet(sheet);
+    }
+
+    /// Get worksheet (processed)
+    ///
+    /// id: Worksheet sheet id
+    pub fn get_worksheet_with_sheet_id(&self, id: &u64) -> anyhow::Result<Worksheet> {
+        let sheets = self.get_sheets()?;
+        let Some(sheet) = sheets.iter().find(|s| s.sheet_id.eq(id)) else {
+            bail!("Worksheet with id: `{}` does not exist.", id)
+        };
+        return self.get_worksheet(sheet);
+    }
+
+    /// Get worksheet (processed)
+    ///
+    /// Tables, data validations, drawings and other parts xls files do not share with xlsx files are empty.
+    pub fn get_worksheet(&self, sheet: &SheetBasicInfo) -> anyhow::Result<Worksheet> {
+        let raw_worksheet = self.get_raw_worksheet(sheet)?;
+        let is_1904 = self
+            .globals
+            .workbook
+            .workbook_properties
+            .as_ref()
+            .and_then(|p| p.date1904)
+            .unwrap_or(false);
+
+        let worksheet = Worksheet::from_raw(
+            sheet.name.clone(),
+            sheet.sheet_id,
+            sheet.path.clone(),
+            Box::new(raw_worksheet),
+            Box::default(),
+            Box::default(),
+            Box::default(),
+            Box::default(),
+            Box::default(),
+            Box::default(),
+            Box::default(),
+            is_1904,
+            None,
+            SharedStrings::Loaded(Arc::new(self.globals.shared_strings.clone())),
+            Arc::new(self.globals.stylesheet.clone()),
+            None,
+            #[cfg(feature = "drawing")]
+            Box::default(),
+            #[cfg(feature = "drawing")]
+            None,
+            #[cfg(feature = "drawing")]
+            Box::default(),
+        );
+
+        Ok(worksheet)
+    }
+
+    fn get_xls_sheet(&self, sheet: &SheetBasicInfo) -> anyhow::Result<&XlsSheet> {
+        let Some(target) = self
+            .globals
+            .sheets
+            .iter()
+            .find(|s| s.sheet.sheet_id == Some(sheet.sheet_id))
+        else {
+            bail!("Worksheet with id: `{}` does not exist.", sheet.sheet_id)
+        };
+        return Ok(target);
+    }
+}
+
+fn sheet_basic_info(sheet: &XlsSheet) -> anyhow::Result<SheetBasicInfo> {
+    let r#type = match sheet.sheet_type {
+        0 => SheetType::WorkSheet,
+        1 => SheetType::MacroSheet,
+        2 => SheetType::ChartSheet,
+        t => bail!("Unsupported sheet type: {}", t),
+    };
+    let visible_state = match sheet.sheet.visible_state.as_deref() {
+        Some("hidden") => SheetVisibleState::Hidden,
+        Some("veryHidden") => SheetVisibleState::VeryHidden,
+        _ => SheetVisibleState::Visible,
+    };
+
+    return Ok(SheetBasicInfo {
+        r_id: String::new(),
+        name: sheet.sheet.name.clone().unwrap_or_default(),
+        sheet_id: sheet.sheet.sheet_id.unwrap_or_default(),
+        visible_state,
+        r#type,
+        path: format!("Workbook#{}", sheet.position),
+    });
+}